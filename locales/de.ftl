@@ -0,0 +1,1540 @@
+# German UI strings. Anything missing here is shown in English.
+
+## Navigation
+
+nav-servers = Server
+nav-logs = Protokolle
+nav-docker-logs = Docker-Protokolle
+nav-backups = Sicherungen
+nav-ports = Ports
+nav-diagnostics = Diagnose
+nav-settings = Einstellungen
+nav-help = Hilfe
+
+## Status bar
+
+status-docker = Docker v{ $version }
+status-docker-disconnected = Docker nicht verbunden
+status-storage = Speicher: { $used } · { $free } frei
+status-storage-low = Wenig Speicherplatz: { $free } frei
+status-storage-low-hint = Mit weniger als 2 GB frei starten keine Server. Lösche alte Backups oder ungenutzte Server oder verschiebe den Datenordner unter Einstellungen → Speicherort.
+status-storage-refresh = Klicken, um neu zu messen
+status-ddns-updated = Aktualisiert um { $time }
+status-ddns-failed = DNS-Aktualisierung für { $hostname } fehlgeschlagen
+status-ddns-refresh = Klicken, um jetzt zu aktualisieren
+status-storage-server = { $name }: { $data } + { $backups } Backups
+
+## Shared buttons
+
+button-cancel = Abbrechen
+button-delete = Löschen
+button-refresh = Aktualisieren
+button-back = Zurück
+button-add = Hinzufügen
+button-remove = Entfernen
+button-reload = Neu laden
+button-save = Speichern
+button-yes = Ja
+button-no = Nein
+
+## Close confirmation
+
+close-title = Server laufen noch
+close-running =
+    { $count ->
+        [one] 1 Server läuft noch:
+       *[other] { $count } Server laufen noch:
+    }
+close-left-running = Beim Schließen laufen sie in Docker weiter.
+close-stop-later = Sie lassen sich später mit 'docker stop' anhalten
+close-anyway = Trotzdem schließen
+close-minimize = Stattdessen minimieren
+close-minimize-hint = DrakonixAnvil überwacht sie im Hintergrund weiter (Zeitpläne, Ankündigungen, Absturzprüfung)
+close-minimized = Minimiert: { $count } laufende(r) Server werden weiter überwacht. Über das Tray-Symbol lassen sich Server starten und stoppen und das Fenster zurückholen; zum Beenden erneut schließen (oder mit gedrückter Umschalttaste).
+
+## Tray icon
+
+tray-show = DrakonixAnvil anzeigen
+tray-start = { $name } starten
+tray-stop = { $name } stoppen
+tray-checking = Prüfe { $name }…
+tray-quit = Beenden
+tray-tooltip =
+    { $count ->
+        [0] DrakonixAnvil: kein Server läuft
+        [one] DrakonixAnvil: 1 Server läuft
+       *[other] DrakonixAnvil: { $count } Server laufen
+    }
+
+## Profiles
+
+profile-label = Profil: { $name }
+profile-hint = Jedes Profil hat eigene Server, Einstellungen und Backups
+profile-new = + Neues Profil…
+profile-new-title = Neues Profil
+profile-new-hint = Beginnt leer, mit eigenen Servern, Einstellungen und Backups.
+profile-new-placeholder = z. B. LAN-Party-Laptop
+profile-create = Erstellen
+profile-busy = Vor dem Profilwechsel müssen laufende Aufgaben abgeschlossen sein
+profile-switched = Zu Profil { $name } gewechselt
+
+## Orphaned directory deletion
+
+orphan-delete-title = Serverordner löschen
+orphan-delete-warning = Folgendes wird endgültig gelöscht:
+orphan-delete-if-any = (falls vorhanden)
+orphan-delete-undo = Das kann nicht rückgängig gemacht werden.
+legacy-containers-title = Container aus einer älteren Version
+legacy-containers-explain = Die Container dieser Server wurden erstellt, bevor diese Installation ihre Container markiert hat. Andere Installationen auf diesem Rechner erkennen sie daher nicht als unsere:
+legacy-containers-data-kept = Beim Neuerstellen werden nur die Container entfernt; Weltdaten und Einstellungen bleiben, und jeder Server bekommt beim nächsten Start einen neuen Container.
+legacy-containers-keep = Vorerst behalten
+legacy-containers-recreate = Neu erstellen
+
+## Dashboard
+
+dashboard-new-server = + Neuer Server
+dashboard-import = Server importieren
+dashboard-empty = Noch keine Server eingerichtet.
+dashboard-empty-hint = Klicke auf '+ Neuer Server', um loszulegen!
+dashboard-orphans = Verwaiste Serverordner ({ $count })
+dashboard-orphans-hint = Diese Ordner liegen unter servers/ im Datenordner, gehören aber zu keinem Server. Vielleicht stammen sie von gelöschten Servern.
+dashboard-adopt = Übernehmen
+dashboard-open-details = Serverdetails öffnen
+dashboard-modpack-port = { $modpack } - Port { $port }
+dashboard-status = Status: { $status }
+dashboard-copy-address = 📋 Adresse kopieren
+dashboard-copy-lan = LAN: { $address }
+dashboard-copy-local = Dieser Rechner: { $address }
+dashboard-copy-public = Internet: { $address }
+dashboard-copy-public-hint = Für Spieler außerhalb deines Netzwerks. Der Port muss im Router weitergeleitet sein.
+dashboard-copy-bound = Gebunden an: { $address }
+dashboard-copy-bound-hint = Der Server nimmt nur Spieler über diese Schnittstelle an (in seiner Bind-Adresse festgelegt).
+dashboard-public-ip-off = Öffentliche IP-Erkennung in den Einstellungen einschalten, um die Internetadresse zu sehen
+dashboard-live = { $online }/{ $max } Spieler · { $version }
+dashboard-disk = Speicher: { $data } · Backups { $backups }
+dashboard-uptime = Läuft seit { $duration }
+dashboard-error = Fehler: { $error }
+dashboard-likely-cause = Wahrscheinliche Ursache: { $title }
+dashboard-fix = Lösung: { $fix }
+dashboard-new-crash = ⚠ Neuer Absturzbericht
+dashboard-open-crashes = Absturzberichte öffnen
+dashboard-uploading = Sicherung wird hochgeladen { $sent } / { $total }
+dashboard-search-hint = Name, Modpack oder Version suchen
+dashboard-any-status = Jeder Status
+dashboard-filter-busy = Startet oder stoppt
+dashboard-filter-problem = Fehler oder eingefroren
+dashboard-any-loader = Jeder Loader
+dashboard-any-version = Jede Version
+dashboard-any-group = Jede Gruppe
+dashboard-sort = Sortierung:
+dashboard-sort-added = Reihenfolge der Erstellung
+dashboard-sort-name = Name
+dashboard-sort-status = Status
+dashboard-sort-port = Port
+dashboard-sort-memory = Arbeitsspeicher
+dashboard-showing = { $shown } von { $total } angezeigt
+dashboard-clear-filters = Filter zurücksetzen
+dashboard-no-matches = Keine Server passen zu Suche und Filtern.
+dashboard-compact = Kompakte Liste
+dashboard-compact-hint = Server als kompakte Tabelle statt als Karten anzeigen
+dashboard-col-server = Server
+dashboard-col-status = Status
+dashboard-col-players = Spieler
+dashboard-col-port = Port
+dashboard-col-memory = Speicher
+dashboard-col-uptime = Laufzeit
+
+server-status-running = Läuft
+server-status-installing-pack = Pack wird installiert
+server-status-pulling = Image wird geladen
+server-status-starting = Startet
+server-status-initializing = Initialisiert
+server-status-stopping = Wird gestoppt
+server-status-frozen = Eingefroren
+server-status-stopped = Gestoppt
+server-status-checking = Prüfe…
+server-status-error = Fehler
+
+action-start = Starten
+action-stop = Stoppen
+action-console = Konsole
+action-players = Spieler
+action-logs = Protokolle
+action-backup = Sichern
+action-backup-live-hint = Weltspeicherung pausieren, auf die Festplatte schreiben und sichern, während die Spieler online bleiben
+action-panic = Notfall
+action-panic-hint = Welt speichern, Server pausieren und seine Daten sichern (bei Griefing oder Dupe-Exploits)
+action-unfreeze = Auftauen
+action-backups = Sicherungen
+action-edit = Bearbeiten
+action-mods = Mods
+action-export = Exportieren
+action-export-bundle = Server-Paket...
+action-export-bundle-hint = Einstellungen und alle Daten, zum Importieren in ein anderes DrakonixAnvil
+action-export-world = Nur die Welt...
+action-export-world-hint = Die Karte als Einzelspieler-Spielstand, zum Öffnen in Minecraft
+action-export-client = Client-Pack...
+action-export-client-hint = Mods und Konfiguration des Servers als .mrpack oder CurseForge-Zip, zum Importieren in den Launcher der Spieler
+action-open-folder = Ordner öffnen
+
+progress-snapshot = Schnappschuss { $current }/{ $total }
+progress-restoring = Wiederherstellen { $current }/{ $total }
+progress-exporting = Exportieren { $current }/{ $total }
+progress-startup = { $percent } % · { $stage }
+progress-startup-eta = { $percent } % · { $stage } · noch ~{ $eta }
+progress-startup-usual = Meist nach ~{ $eta } bereit
+progress-startup-usual-hint = Median der letzten { $count } Starts
+pack-downloading = Pack wird geladen { $done } / { $total }
+pack-downloading-unknown = Pack wird geladen { $done }
+pack-installing-mods = Mods werden installiert { $current } / { $total }
+pack-extracting = Entpacken { $current } / { $total } Dateien
+
+## Settings
+
+settings-title = Einstellungen
+settings-save = Einstellungen speichern
+settings-saved = Einstellungen gespeichert!
+settings-language = Sprache
+settings-language-hint = Noch nicht übersetzte Teile der App bleiben auf Englisch.
+settings-background = Hintergrundmodus
+settings-minimize-on-close = Minimieren statt schließen, solange Server laufen
+settings-minimize-on-close-hint = Geplante Backups, Ankündigungen und Absturzprüfungen laufen nur, solange die App geöffnet ist. Erneutes Schließen oder Schließen mit gedrückter Umschalttaste beendet die App.
+settings-connect-address = Verbindungsadresse
+settings-detect-public-ip = Öffentliche IP dieses Netzwerks erkennen
+settings-detect-public-ip-hint = Fragt api.ipify.org nach der Adresse, mit der sich Spieler außerhalb deines Netzwerks verbinden. Beim Kopieren wird der Port über api.mcsrvstat.us geprüft.
+settings-metrics = Metrik-Endpunkt
+settings-metrics-hint = Stellt Status, Spieler, TPS, CPU und Speicher des Containers sowie das Backup-Alter jedes Servers im Prometheus-Format bereit, zum Abrufen durch Prometheus oder Grafana.
+settings-metrics-enable = Metriken bereitstellen
+settings-metrics-bind = Lauschen auf:
+settings-metrics-bind-hint = 127.0.0.1 antwortet nur diesem Rechner. Mit 0.0.0.0 kann ein Scraper auf einem anderen Rechner zugreifen.
+settings-metrics-apply = Übernehmen
+settings-metrics-serving = Erreichbar unter
+settings-ddns = Dynamisches DNS
+settings-ddns-hint = Hält einen Hostnamen auf der öffentlichen IP dieses Netzwerks, damit die gespeicherte Adresse der Spieler auch nach einem IP-Wechsel funktioniert.
+settings-ddns-enable = DNS-Eintrag aktualisieren
+settings-ddns-provider = Anbieter:
+settings-ddns-hostname = Hostname:
+settings-ddns-token = Token:
+settings-ddns-zone = Zonen-ID:
+settings-ddns-interval = Aktualisieren alle:
+settings-ddns-interval-hint = Die öffentliche IP wird alle 5 Minuten geprüft und der Eintrag sofort aktualisiert, wenn sie sich ändert.
+settings-ddns-update-now = Jetzt aktualisieren
+settings-alerts = Warnungen
+settings-alerts-hint = Benachrichtigung, wenn ein Server von selbst stoppt, laggt, kaum noch Speicher hat oder zu lange kein Backup bekommen hat.
+settings-alerts-desktop = Desktop-Benachrichtigungen
+settings-alerts-webhook = Webhook-URL:
+settings-alerts-test = Testwarnung senden
+settings-alerts-server = Regeln für:
+settings-alerts-no-servers = Lege einen Server an, um seine Warnungen einzurichten.
+settings-alerts-copy = Auf alle Server übertragen
+settings-alerts-copy-hint = Diese Regeln für jeden Server verwenden
+settings-webhooks = Webhooks
+settings-webhooks-hint = Server-, Spieler- und Backup-Ereignisse an Discord, Slack oder einen kompatiblen Webhook senden.
+settings-webhooks-add = Webhook hinzufügen
+settings-webhooks-test = Testen
+settings-webhooks-remove = Diesen Webhook entfernen
+settings-discord-bot = Discord-Bot
+settings-discord-bot-hint = Vertrauenswürdige Discord-Nutzer können Server aus einem Kanal starten, stoppen und abfragen. Erstelle einen Bot auf discord.com/developers, aktiviere den Message-Content-Intent und lade ihn in den Kanal ein.
+settings-discord-bot-enable = Bot aktivieren
+settings-discord-bot-token = Bot-Token:
+settings-discord-bot-channel = Kanal-ID:
+settings-discord-bot-users = Erlaubte Nutzer-IDs:
+settings-discord-bot-users-hint = Numerische Nutzer-IDs, eine pro Zeile (Entwicklermodus → Rechtsklick auf einen Nutzer → Nutzer-ID kopieren)
+settings-discord-bot-users-ignored = Einträge ignoriert, die keine Nutzer-IDs sind: { $entries }
+settings-discord-bot-apply = Übernehmen
+settings-discord-bot-running = Wartet auf Befehle
+settings-curseforge = CurseForge-API-Schlüssel
+settings-modrinth = Modrinth-Token (optional)
+settings-downloads = Downloads
+settings-template-index = Vorlagenkatalog
+settings-backups = Sicherungen
+settings-remote-backups = Externe Sicherungen
+settings-proxy = Netzwerk-Proxy
+settings-storage = Speicherort
+settings-shortcuts = Tastenkürzel
+settings-eula = Minecraft-EULA
+settings-public-ip = Öffentliche IP: { $ip }
+settings-curseforge-hint = Wird zum Herunterladen von CurseForge-Modpacks benötigt.
+settings-curseforge-get-key = Schlüssel erhalten:
+settings-curseforge-key = API-Schlüssel:
+settings-curseforge-key-hint = CurseForge-API-Schlüssel hier einfügen
+settings-curseforge-show-key = Schlüssel anzeigen/verbergen
+settings-curseforge-key-set = ✓ API-Schlüssel eingerichtet
+settings-curseforge-key-unset = ○ Kein API-Schlüssel gesetzt
+settings-curseforge-saved-restart = Einstellungen gespeichert! DrakonixAnvil neu starten, damit der CurseForge-API-Schlüssel wirksam wird.
+settings-modrinth-hint = Modrinth funktioniert ohne Schlüssel. Ein persönlicher Zugriffstoken erhöht das Anfragelimit für intensives Stöbern.
+settings-modrinth-create = Token erstellen:
+settings-downloads-hint = Begrenzt die Bandbreite für Serverpaket-Downloads. Unterbrochene Downloads werden automatisch fortgesetzt.
+settings-downloads-limit = Geschwindigkeitslimit:
+settings-downloads-unlimited = (unbegrenzt)
+settings-download-cache = Download-Cache: { $size }
+settings-download-cache-hint = Fertige Paket-Downloads werden aufbewahrt, damit neue Server mit demselben Paket es nicht erneut herunterladen
+settings-clear-cache = Cache leeren
+settings-api-cache = API-Cache: { $size }
+settings-api-cache-hint = Aktuelle CurseForge- und Modrinth-Ergebnisse werden einige Minuten wiederverwendet, damit das Stöbern keine Anfragelimits verbraucht
+settings-clear-api-cache = API-Cache leeren
+settings-template-index-hint = Empfohlene Vorlagen werden beim Start aus diesem Katalog aktualisiert, sodass neue Pakete und korrigierte Paket-URLs ohne neue App-Version ankommen.
+settings-template-index-enable = Empfohlene Vorlagen beim Start abrufen
+settings-template-index-url = Index-URL:
+settings-reset = Zurücksetzen
+settings-template-index-count =
+    { $count ->
+    [one] { $count } Vorlage im Index
+    *[other] { $count } Vorlagen im Index
+    }
+settings-check-templates = Vorlagen prüfen
+settings-check-templates-hint = Prüft, ob Paket-URLs, Forge-Versionen und CurseForge-/Modrinth-/FTB-Dateien noch auflösbar sind
+settings-templates-resolve = Alle Vorlagen sind auflösbar
+settings-templates-broken = { $count } defekt, markiert unter Server erstellen
+settings-backups-hint = Kompression für neue Backups. Server mit Backup-Zeitplan können sie überschreiben.
+settings-backups-zstd-hint = Zstandard-Backups lassen sich in der App wiederherstellen, brauchen zum manuellen Öffnen aber 7-Zip oder ein anderes zstd-fähiges Werkzeug.
+settings-verify-backups = Backups nach dem Erstellen prüfen
+settings-verify-backups-hint = Liest jedes Archiv zurück, prüft seine CRCs und vergleicht Dateianzahl und -größen mit den Serverdateien. Dauert etwa noch einmal so lange wie das Backup.
+settings-remote-hint = Lädt eine Kopie jedes Backups extern hoch. Pro Server unter Bearbeiten → Automatische Backups einschalten.
+settings-remote-disabled = Deaktiviert
+settings-remote-s3 = S3-kompatibel
+settings-remote-endpoint = Endpunkt:
+settings-remote-region = Region:
+settings-remote-bucket = Bucket:
+settings-remote-access-key = Zugriffsschlüssel:
+settings-remote-secret-key = Geheimer Schlüssel:
+settings-remote-folder-url = Ordner-URL:
+settings-remote-username = Benutzername:
+settings-remote-password = Passwort:
+settings-remote-no-sftp = Dieser Build unterstützt kein SFTP; mit `--features sftp` neu bauen.
+settings-remote-host = Host:
+settings-remote-private-key = Privater Schlüssel:
+settings-remote-private-key-hint = keiner, Passwort verwenden
+settings-remote-key-dialog = Privater SSH-Schlüssel
+settings-remote-passphrase = Schlüssel-Passphrase:
+settings-remote-dir = Entfernter Ordner:
+settings-remote-dir-hint = backups oder /srv/minecraft-backups
+settings-remote-host-key = Der Host-Schlüssel wird bei der ersten Verbindung gespeichert; ändert er sich später, werden Uploads gestoppt.
+settings-remote-keep = Neueste behalten:
+settings-remote-keep-unit = Uploads pro Server
+settings-remote-keep-all = (alle behalten)
+settings-remote-incomplete = Die Felder oben ausfüllen, um Uploads zu aktivieren.
+settings-proxy-hint = Wird für CurseForge, Modrinth und Serverpaket-Downloads verwendet.
+settings-proxy-system = System (Umgebungsvariablen)
+settings-proxy-none = Kein Proxy
+settings-proxy-manual = Manuell
+settings-proxy-using = Verwendet { $var }={ $value }
+settings-proxy-no-env = Keine Proxy-Umgebungsvariablen gesetzt; Verbindung erfolgt direkt.
+settings-proxy-url = Proxy-URL:
+settings-proxy-url-hint = http://proxy:3128 oder socks5://127.0.0.1:1080
+settings-proxy-bypass = Umgehen für:
+settings-proxy-test = Verbindung testen
+settings-proxy-test-hint = Die Modrinth-API mit diesen Einstellungen erreichen, ohne sie zu speichern
+settings-proxy-connected = ✓ Verbunden ({ $ms } ms)
+settings-proxy-docker = Docker-Images werden vom Docker-Daemon geladen, der diese Einstellung nicht verwendet. Stattdessen den Proxy des Daemons einrichten:
+settings-proxy-docker-desktop = Docker Desktop: Settings → Resources → Proxies. Linux:
+settings-proxy-docker-guide = Anleitung zum Daemon-Proxy
+settings-storage-hint = Server, Backups, Download-Caches und Logs liegen im Datenordner. Auf ein größeres Laufwerk verschieben, wenn die Welten diesem entwachsen.
+settings-storage-folder = Datenordner:
+settings-storage-free = { $size } frei auf diesem Laufwerk
+settings-storage-portable = Portabler Modus: Die Daten liegen neben der DrakonixAnvil-Programmdatei und wandern mit ihr.
+settings-storage-portable-hint = Für den portablen Modus (Daten neben der Programmdatei, z. B. auf einem USB-Stick) mit --portable starten oder eine { $marker } neben die Programmdatei legen.
+settings-storage-move = In einen anderen Ordner verschieben...
+settings-storage-move-stopped = Zuerst alle Server stoppen
+settings-shortcuts-hint = Auf ein Tastenkürzel klicken und dann die neue Tastenkombination drücken. Esc bricht ab.
+settings-shortcuts-press = Tasten drücken...
+settings-eula-accepted = ✓ Akzeptiert { $at }
+settings-eula-not-accepted = ○ Noch nicht akzeptiert (Sie werden beim Starten eines Servers gefragt)
+settings-instance-id = Instanz-ID:
+settings-instance-id-hint = Wird den Containernamen und Labels dieser Installation vorangestellt, damit andere DrakonixAnvil-Installationen auf demselben Docker-Host sie in Ruhe lassen
+settings-curseforge-recreate-note = Hinweis: Nach dem Setzen des API-Schlüssels müssen CurseForge-Server neu erstellt werden, damit der Schlüssel wirksam wird.
+
+## Alerts
+
+alerts-container-exited = Container wurde beendet, ohne gestoppt zu werden
+alerts-low-tps = TPS unter
+alerts-low-tps-for = für
+alerts-low-tps-hint = Forge, NeoForge und Paper melden TPS; andere Server lösen das nie aus
+alerts-high-memory = Speicher über
+alerts-high-memory-of = des Container-Limits
+alerts-no-backup = Kein Backup seit
+
+## Backup compression
+
+compression-store = Speichern (ohne Kompression)
+compression-deflate = Deflate
+compression-zstd = Zstandard
+compression-level = Stufe
+compression-zstd-hint = 3 ist schnell mit gutem Verhältnis; über 19 wird es sehr langsam
+
+## Diagnostics
+
+diagnostics-title = Diagnose
+diagnostics-run = Prüfungen starten
+diagnostics-hint = Prüft alles, worauf die App angewiesen ist. Läuft automatisch, wenn sich Fehler häufen.
+diagnostics-none = Noch keine Prüfungen gelaufen.
+diagnostics-last-run = Zuletzt gelaufen { $time }
+diagnostics-last-run-auto = Zuletzt gelaufen { $time } (automatisch nach wiederholten Fehlern gestartet)
+diag-docker = Docker
+diag-docker-hint = Starte Docker Desktop (oder `sudo systemctl start docker`) und stelle sicher, dass dein Benutzer auf den Docker-Socket zugreifen darf (z. B. in der Gruppe `docker` ist); starte danach die App neu.
+diag-docker-not-connected = Beim Start nicht verbunden
+diag-docker-ok = Daemon erreichbar (v{ $version })
+diag-docker-no-answer = Daemon hat auf keinen Ping geantwortet
+diag-docker-timeout = Zeitüberschreitung beim Ping an den Daemon
+diag-docker-timeout-hint = Docker startet vielleicht gerade oder ist überlastet; warte eine Minute und prüfe erneut.
+diag-data-dir = Datenverzeichnis
+diag-data-dir-ok = { $path } ist beschreibbar
+diag-data-dir-fail = Schreiben in { $path } nicht möglich: { $error }
+diag-data-dir-hint = Starte die App aus einem Ordner, der dir gehört, oder korrigiere die Berechtigungen des Ordners.
+diag-disk = Speicherplatz
+diag-disk-hint = Lösche alte Backups oder ungenutzte Server, oder verschiebe die App auf einen größeren Datenträger.
+diag-disk-free = { $free } frei
+diag-disk-unknown = Prüfung nicht möglich: { $error }
+diag-disk-unknown-hint = Stelle sicher, dass das Datenverzeichnis auf einem lokalen, eingebundenen Datenträger liegt.
+diag-clock = Uhr
+diag-clock-no-client = HTTP-Client konnte nicht erstellt werden: { $error }
+diag-clock-no-client-hint = Prüfe die Proxy-Einstellungen.
+diag-clock-unreachable = Keine Zeitquelle zum Vergleich erreichbar
+diag-clock-unreachable-hint = Prüfe deine Internetverbindung; die Uhr selbst ist vielleicht in Ordnung.
+diag-clock-skew = { $seconds } s Abweichung von { $source }
+diag-clock-hint = Schalte die automatische Zeitsynchronisierung (NTP) in den Systemeinstellungen ein. Eine falsche Uhr verhindert HTTPS-Downloads und Minecraft-Anmeldungen.
+diag-dns = DNS: { $service }
+diag-dns-empty = { $host } hat keine Adressen
+diag-dns-empty-hint = Dein DNS-Server hat eine leere Antwort geliefert; versuche einen anderen Resolver (z. B. 1.1.1.1).
+diag-dns-error-hint = Prüfe deine Netzwerkverbindung und DNS-Einstellungen. Wenn ein Proxy die Namen für dich auflöst, kannst du das ignorieren.
+diag-dns-timeout = { $host }: Zeitüberschreitung bei der Namensauflösung
+diag-dns-timeout-hint = Dein DNS-Server ist langsam oder nicht erreichbar; versuche einen anderen Resolver (z. B. 1.1.1.1).
+diag-port-bind = Port-Bindung
+diag-port-bind-ok = Test-Listener auf Port { $port } geöffnet
+diag-port-bind-fail = Kein lauschender Socket möglich: { $error }
+diag-port-bind-hint = Eine Firewall oder Sicherheitsrichtlinie blockiert lauschende Sockets; lass die App und Docker durch.
+diag-keyring = Schlüsselbund
+diag-keyring-available = System-Schlüsselbund verfügbar
+diag-keyring-missing = kein System-Schlüsselbund gefunden
+diag-keyring-no-secrets = Keine API-Schlüssel oder Tokens gespeichert ({ $keyring })
+diag-keyring-exposed = API-Schlüssel und Tokens liegen in { $path } und sind für andere Benutzer lesbar
+diag-keyring-exposed-hint = Führe `chmod 600 { $path }` aus, um die Schlüssel zu schützen.
+diag-keyring-ok = API-Schlüssel und Tokens liegen in { $path } ({ $keyring })
+
+## Known errors
+
+known-cf-key-title = CurseForge-API-Schlüssel fehlt
+known-cf-key-explanation = Das Image braucht einen CurseForge-API-Schlüssel, um die Dateien dieses Modpacks herunterzuladen.
+known-cf-key-fix = Trage deinen Schlüssel unter Einstellungen → CurseForge-API-Schlüssel ein und starte den Server erneut.
+known-cf-install-title = CurseForge-Autoinstallation fehlgeschlagen
+known-cf-install-explanation = Das Image konnte dieses Pack nicht von CurseForge installieren; das passiert bei älteren Packs und solchen ohne Serverdateien.
+known-cf-install-fix = Bearbeite den Server, setze unter Modpack den Haken bei „Auf dem Host aus dem Client-Manifest installieren“ und erstelle den Container neu.
+known-eula-title = Minecraft-EULA nicht akzeptiert
+known-eula-explanation = Der Server startet erst, wenn die Minecraft-EULA akzeptiert ist.
+known-eula-fix = Starte den Server über das Dashboard und akzeptiere die EULA, wenn du gefragt wirst.
+known-java-title = Falsche Java-Version
+known-java-explanation = Das Pack oder eine seiner Mods wurde für eine neuere Java-Version gebaut, als der Container verwendet.
+known-java-fix = Bearbeite den Server, wähle eine neuere Java-Version und erstelle den Container neu.
+known-java-fix-version = Bearbeite den Server, wähle Java { $java } (oder neuer, wenn das Pack es erlaubt) und erstelle den Container neu.
+known-duplicate-mods-title = Doppelte Mods
+known-duplicate-mods-explanation = Zwei JARs im mods-Ordner stellen dieselbe Mod bereit, meist zwei Versionen davon.
+known-duplicate-mods-fix = Öffne die Mod-Ansicht oder den Serverordner und lösche die ältere Kopie aus mods/.
+known-client-mod-title = Nur-Client-Mod auf dem Server
+known-client-mod-explanation = Eine Mod, die nur im Spielclient funktioniert (Shader, Minimaps, Menüs), wurde vom dedizierten Server geladen.
+known-client-mod-fix = Suche die Mod, die in den Container-Logs neben dem Fehler genannt wird, und entferne sie aus mods/.
+known-out-of-memory-title = Kein Speicher mehr
+known-out-of-memory-explanation = Der JVM ist der Heap ausgegangen. Große Modpacks brauchen oft 6–10 GB.
+known-out-of-memory-fix = Bearbeite den Server, erhöhe seinen Arbeitsspeicher und starte ihn erneut.
+
+## Memory
+
+memory-host = Host-RAM: { $mb } MB
+memory-use-suggested = Vorschlag übernehmen ({ $mb } MB)
+memory-bar-hint =
+    Andere aktive Server: { $others } MB
+    Dieser Server: { $this } MB
+    Systemreserve: { $reserve } MB
+    Host gesamt: { $total } MB
+
+## Metrics history
+
+history-title = Verlauf
+history-empty = Noch keine Messwerte. Sie werden einmal pro Minute erfasst, solange der Server läuft.
+history-players = Spieler
+history-tps = TPS
+history-tps-unreported = TPS: von diesem Server nicht gemeldet (Forge, NeoForge und Paper melden sie).
+history-memory = Speicher
+history-now-peak = { $title }: jetzt { $now }, Spitze { $peak }
+
+## Ports
+
+ports-title = Ports
+ports-hint = Jeder Host-Port, den DrakonixAnvil freigibt, über alle Server hinweg (laufend oder nicht).
+ports-problems =
+    { $count ->
+        [one] 1 Portzuweisung braucht Aufmerksamkeit
+       *[other] { $count } Portzuweisungen brauchen Aufmerksamkeit
+    }
+ports-ok = ✓ Keine Portkonflikte gefunden
+ports-col-port = Port
+ports-col-server = Server
+ports-col-purpose = Zweck
+ports-col-state = Zustand
+ports-col-issues = Probleme
+ports-kind-game = Spiel
+ports-kind-rcon = RCON
+ports-kind-extra = Zusätzlich
+ports-in-use = Belegt
+ports-reserved = Reserviert
+ports-overlaps = Überschneidet sich mit { $servers }
+ports-host-in-use = Port { $port } wird auf diesem Host bereits verwendet
+ports-host-denied = Zugriff auf Port { $port } verweigert. Ports unter 1024 erfordern Root-Rechte.
+ports-host-bind-failed = Port { $port } kann nicht gebunden werden: { $error }
+ports-used-by-server = Port { $port } wird bereits vom laufenden Server '{ $server }' verwendet
+ports-used-by-app = Port { $port } wird bereits von einer anderen Anwendung verwendet. Versuche stattdessen Port { $suggested }.
+
+## Preflight
+
+preflight-title = Startprüfung: { $server }
+preflight-failed = Einige Prüfungen sind fehlgeschlagen. Behebe sie und prüfe erneut, bevor du startest.
+preflight-warnings = Einige Prüfungen brauchen Aufmerksamkeit. Du kannst den Server trotzdem starten.
+preflight-recheck = Erneut prüfen
+preflight-start-anyway = Trotzdem starten
+preflight-disk-data = Speicherplatz (Serverdaten)
+preflight-disk-docker = Speicherplatz (Docker-Speicher)
+preflight-disk-fail = Nur { $free } frei auf { $path }
+preflight-disk-low = { $free } frei auf { $path }; Modpacks und Welten können schnell wachsen
+preflight-disk-ok = { $free } frei
+preflight-disk-unknown = Freier Speicherplatz konnte nicht geprüft werden: { $error }
+preflight-docker-in-vm = { $path } liegt in Dockers VM; prüfe die Speichernutzung in Docker Desktop, falls Downloads fehlschlagen
+preflight-docker-no-root = Docker hat seinen Speicherort nicht gemeldet
+preflight-memory = Arbeitsspeicher
+preflight-memory-unknown = Arbeitsspeicher des Hosts konnte nicht ermittelt werden
+preflight-memory-over-host = { $requested } MB angefordert, aber der Host hat nur { $total } MB
+preflight-memory-low = { $requested } MB angefordert, { $free } MB derzeit frei ({ $others } MB von anderen Servern belegt)
+preflight-memory-ok = { $requested } MB angefordert, { $free } MB frei
+preflight-port = Port { $port }
+preflight-port-free = Verfügbar
+preflight-jvm = JVM-Optionen
+preflight-jvm-ok = { $gc } auf Java { $java }
+jvm-several-gcs = Mehr als ein Garbage Collector ausgewählt ({ $collectors }); die JVM startet nicht
+jvm-gc-needs-java = { $gc } braucht Java { $needed }+, dieser Server verwendet Java { $java }
+jvm-generational-without-zgc = -XX:+ZGenerational wirkt nur zusammen mit -XX:+UseZGC
+jvm-generational-needs-java = Generational ZGC braucht Java 21+, dieser Server verwendet Java { $java }
+jvm-xlog-needs-java = -Xlog-Optionen brauchen Java 9+; Java 8 startet damit nicht
+preflight-cf-key = CurseForge-API-Schlüssel
+preflight-cf-key-set = Eingerichtet
+preflight-cf-key-missing = CurseForge-Modpacks brauchen einen API-Schlüssel. Füge ihn in den Einstellungen hinzu.
+preflight-local = Lokales Modpack
+preflight-local-outside = '{ $path }' muss ein Pfad im Datenordner des Servers sein ({ $folder })
+preflight-local-missing = '{ $path }' nicht gefunden in { $folder }; prüfe den Pfad auf Tippfehler
+preflight-local-unrecognized = Kein mods/-Ordner, keine Server-JAR und kein Pack-Manifest gefunden
+preflight-local-ok = { $found }-Pack, { $count } Einträge
+preflight-local-vanilla = Das Pack sieht nach { $found } aus, aber der Server ist auf Vanilla gestellt; Mods werden nicht geladen
+preflight-local-wrong-loader = Das Pack sieht nach { $found } aus, aber der Server ist auf { $declared } gestellt; ändere den Loader unter Bearbeiten
+preflight-local-unknown-loader = { $count } Einträge (Loader im Pack nicht angegeben)
+
+## Whitelist
+
+whitelist-not-enforced = ⚠ Die Whitelist ist in den Einstellungen dieses Servers ausgeschaltet und wird daher nicht durchgesetzt.
+whitelist-running = Der Server läuft: Änderungen erfolgen mit `whitelist add/remove` über RCON.
+whitelist-stopped = Der Server ist gestoppt: Änderungen werden direkt in whitelist.json geschrieben.
+whitelist-add-label = Spieler hinzufügen (ein Name pro Zeile oder durch Kommas getrennt):
+whitelist-unreadable = whitelist.json konnte nicht gelesen werden
+whitelist-empty = Noch niemand steht auf der Whitelist.
+whitelist-count = { $count } auf der Whitelist
+whitelist-invalid-names = Keine gültigen Minecraft-Namen: { $names }
+whitelist-offline-stopped = Server im Offline-Modus: zum Hinzufügen von Spielern starten (nutzt RCON)
+whitelist-removed = { $player } von der Whitelist entfernt
+whitelist-added =
+    { $count ->
+        [one] 1 Spieler auf die Whitelist gesetzt
+       *[other] { $count } Spieler auf die Whitelist gesetzt
+    }
+whitelist-added-unknown =
+    { $count ->
+        [one] 1 Spieler auf die Whitelist gesetzt; unbekannte Namen: { $names }
+       *[other] { $count } Spieler auf die Whitelist gesetzt; unbekannte Namen: { $names }
+    }
+
+## Backups overview
+
+backups-title = Backups
+backups-total = Backups aller Server belegen insgesamt { $total }.
+backups-total-free = Backups aller Server belegen insgesamt { $total }, { $free } frei auf dem Backup-Laufwerk.
+backups-stale =
+    { $count ->
+        [one] 1 Server ohne aktuelles Backup
+       *[other] { $count } Server ohne aktuelles Backup
+    }
+backups-all-recent = ✓ Jeder Server hat ein aktuelles Backup
+backups-col-server = Server
+backups-col-backups = Backups
+backups-col-size = Größe
+backups-col-newest = Neuestes
+backups-col-schedule = Zeitplan
+backups-col-issues = Probleme
+backups-deleted-hint = Dieser Server wurde gelöscht; seine Backups liegen noch auf der Festplatte
+backups-ago = vor { $duration }
+backups-deleted = Gelöschter Server
+backups-schedule-on = An
+backups-schedule-off = Aus
+
+## Backup contents
+
+contents-title = Inhalt von { $file }
+contents-hint = { $files } Dateien, insgesamt { $size }. Textdateien mit 🔍 ansehen oder ankreuzen, was auf '{ $server }' zurückgespielt werden soll; alles andere bleibt, wie es ist.
+contents-stop-first = Stoppe zuerst den Server. Ersetzte Dateien werden in replaced-files/ aufbewahrt.
+contents-restore-selected = Auswahl wiederherstellen ({ $count } Dateien)
+contents-clear-selection = Auswahl aufheben
+contents-empty = Dieses Backup ist leer.
+contents-close-preview = Vorschau schließen
+contents-truncated = Zeigt die ersten { $size }.
+contents-preview = Vorschau
+contents-folder-size = { $files } Dateien, { $size }
+
+## Crash reports
+
+crash-title = Absturzberichte: { $server }
+crash-none = Dieser Server hat keine Absturzberichte.
+crash-count =
+    { $count ->
+        [one] 1 Bericht
+       *[other] { $count } Berichte
+    }
+crash-select = Wähle einen Bericht.
+crash-open-file = Datei öffnen
+crash-unknown = unbekannt
+crash-time = Zeit:
+crash-description = Beschreibung:
+crash-suspected-mods = Verdächtige Mods:
+crash-no-suspects = keine erkannt
+crash-exception = Ausnahme
+crash-registry-names = Beteiligte Blöcke, Entitäten und Items
+crash-full-report = Vollständiger Bericht
+
+## Log filter
+
+log-search = Suche:
+log-search-regex-hint = regulärer Ausdruck
+log-search-text-hint = Text
+log-regex = Regex
+log-only-matching = Nur Treffer zeigen
+log-colors = Farben
+log-colors-hint = Rot für Fehler, gelb für Warnungen, lila für Stacktraces
+log-follow = Mitlaufen
+log-follow-hint = Laufend aktualisieren und zu den neuesten Zeilen scrollen
+log-invalid-regex = Ungültiger regulärer Ausdruck: { $error }
+log-matching = { $matching } von { $total } Zeilen passen
+log-lines = { $total } Zeilen
+log-copy = Kopieren
+log-copy-hint = Angezeigte Zeilen kopieren
+
+## MOTD editor
+
+motd-color-black = Schwarz
+motd-color-dark-blue = Dunkelblau
+motd-color-dark-green = Dunkelgrün
+motd-color-dark-aqua = Dunkeltürkis
+motd-color-dark-red = Dunkelrot
+motd-color-dark-purple = Dunkellila
+motd-color-gold = Gold
+motd-color-gray = Grau
+motd-color-dark-gray = Dunkelgrau
+motd-color-blue = Blau
+motd-color-green = Grün
+motd-color-aqua = Türkis
+motd-color-red = Rot
+motd-color-light-purple = Helllila
+motd-color-yellow = Gelb
+motd-color-white = Weiß
+motd-format-bold = Fett
+motd-format-italic = Kursiv
+motd-format-underline = Unterstrichen
+motd-format-strikethrough = Durchgestrichen
+motd-format-obfuscated = Verschleiert
+motd-format-reset = Zurücksetzen
+motd-hint = Tippe Codes wie &6 oder nutze die Knöpfe. Fett und verschleiert werden hier normal angezeigt.
+
+## Players
+
+players-start-server = Starte den Server, um zu sehen, wer online ist.
+players-message = Nachricht / Grund:
+players-message-hint = Flüstertext oder Grund für Kick/Bann
+players-asking = Frage den Server, wer online ist...
+players-nobody = Niemand ist online.
+players-online = { $count } online
+players-whisper = Flüstern
+players-whisper-hint = Gib zuerst eine Nachricht ein
+players-kick = Kicken
+players-ban = Bannen
+players-ban-hint = Rückgängig mit `pardon <name>` in der Konsole
+players-deop = Deop
+players-op = Op
+players-operators = Operatoren
+players-playtime = Spielzeit
+players-playtime-empty = Noch keine Spielzeit erfasst. Sie wird jede Minute gemessen, solange der Server läuft.
+players-col-player = Spieler
+players-col-playtime = Spielzeit
+players-col-sessions = Sitzungen
+players-col-last-seen = Zuletzt gesehen
+players-is-online = online
+
+## Local import
+
+local-import-hint = Importiere ein CurseForge-Client- oder Server-Pack als Zip, ein Modrinth-.mrpack oder einen Serverordner von diesem Rechner. Er wird in den Ordner des neuen Servers kopiert.
+local-import-choose-file = Datei wählen...
+local-import-filter = Modpack
+local-import-choose-folder = Ordner wählen...
+local-import-nothing = Noch nichts gewählt.
+local-import-detected = Erkannt:
+local-import-name = Pack-Name:
+local-import-mc-version = Minecraft-Version:
+local-import-mc-version-hint = z. B. 1.20.1
+local-import-loader = Loader:
+local-import-choose-loader = Wählen...
+local-import-cf-client-hint = Die Mods des Packs werden beim ersten Serverstart von CurseForge geladen, ohne reine Client-Mods. Dafür ist ein CurseForge-API-Schlüssel nötig.
+local-import-mrpack-hint = Die Dateien des Packs werden beim ersten Serverstart von Modrinth geladen, ohne reine Client-Dateien.
+local-import-incomplete = Nicht alles wurde erkannt; trage Minecraft-Version und Loader ein.
+
+## Files
+
+files-up = ⬆ Hoch
+files-upload-files = Dateien hochladen...
+files-upload-folder = Ordner hochladen...
+files-open-in-os = Im Dateimanager öffnen
+files-running-warning = ⚠ Der Server läuft: Er kann geänderte Dateien überschreiben, und das Löschen von Welt- oder Mod-Dateien kann ihn beschädigen.
+files-empty = Dieser Ordner ist leer.
+files-col-name = Name
+files-col-size = Größe
+files-col-modified = Geändert
+files-delete-confirm = Löschen?
+files-download = Herunterladen...
+files-rename = Umbenennen
+files-op-uploaded =
+    { $count ->
+        [one] 1 Element nach { $folder }/ hochgeladen
+       *[other] { $count } Elemente nach { $folder }/ hochgeladen
+    }
+files-op-saved = { $name } nach { $path } gespeichert
+files-op-renamed = { $from } in { $to } umbenannt
+files-op-deleted = { $path } gelöscht
+files-op-failed = Fehlgeschlagen: { $error }
+
+## Browsing
+
+browse-search = Suche:
+browse-search-button = Suchen
+browse-mc-version = MC-Version:
+browse-mc-version-hint = z. B. 1.20.1
+browse-loader = Loader:
+browse-sort = Sortierung:
+browse-any = Alle
+browse-searching = Suche auf { $site }...
+browse-error = Fehler: { $error }
+browse-no-results = Keine Ergebnisse gefunden.
+browse-enter-term = Gib einen Suchbegriff ein und klicke auf Suchen, um Modpacks zu finden.
+browse-downloads = { $count } Downloads
+browse-downloads-short = ({ $count } Downloads)
+browse-installs = { $count } Installationen
+browse-installs-short = ({ $count } Installationen)
+browse-prev = < Zurück
+browse-next = Weiter >
+browse-page = Seite { $page } / { $pages }
+browse-loading-description = Beschreibung wird geladen...
+browse-version-selection = Versionsauswahl
+browse-loading-versions = Versionen werden geladen...
+browse-no-versions = Keine Versionen gefunden.
+browse-minecraft-version = Minecraft-Version:
+browse-select = Auswählen...
+browse-pack-version = Pack-Version:
+mr-search-hint = z. B. Cobblemon
+mr-categories = Kategorien:
+mr-no-versions-for-mc = Keine Versionen für diese Minecraft-Version.
+cf-key-required = CurseForge-API-Schlüssel erforderlich
+cf-key-required-hint = Hinterlege deinen CurseForge-API-Schlüssel in den Einstellungen, um nach Modpacks zu suchen.
+cf-key-get = Einen kostenlosen Schlüssel gibt es unter
+cf-search-hint = z. B. All The Mods
+cf-mc-versions = MC-Versionen:
+cf-no-files-for-mc = Keine Dateien für diese Minecraft-Version.
+cf-has-server-pack = Diese Version hat ein Server-Pack (Datei { $file }).
+cf-no-server-pack = Diese Version hat kein Server-Pack.
+cf-install-from = Installieren aus:
+cf-client-manifest = Client-Manifest
+cf-client-manifest-hint = Das Image lädt die Mods des Packs selbst herunter (AUTO_CURSEFORGE)
+cf-server-pack = Server-Pack
+cf-server-pack-hint = Die App lädt das Server-Pack herunter und entpackt es in den Serverordner; der Container installiert den darin gefundenen Loader
+cf-server-pack-missing = Der Pack-Autor hat für diese Version kein Server-Pack veröffentlicht
+unified-no-cf-key = Kein CurseForge-API-Schlüssel gesetzt; es wird nur Modrinth durchsucht.
+unified-empty = Durchsuche beide Plattformen auf einmal. Packs, die auf beiden gelistet sind, erscheinen nur einmal.
+unified-install-cf = Von CurseForge installieren
+unified-install-mr = Von Modrinth installieren
+
+## FTB browsing
+
+ftb-search-hint = (leer lassen für die beliebtesten Packs)
+ftb-empty = Suche nach einem Pack oder klicke einfach auf Suchen, um die beliebtesten FTB-Packs zu sehen.
+
+## Adding mods
+
+mod-browse-title = Mods zu { $server } hinzufügen
+mod-browse-hint = Nur Mods mit einem { $target }-Build, der auf Servern läuft, werden aufgelistet. Benötigte Abhängigkeiten, die dem Server fehlen, werden mitinstalliert.
+mod-browse-no-cf-key = Hinterlege einen CurseForge-API-Schlüssel in den Einstellungen
+mod-browse-search-hint = z. B. JourneyMap
+mod-browse-empty = Suche eine Mod nach Namen oder lass das Feld leer, um beliebte zu sehen.
+mod-browse-installed = Installiert
+mod-browse-no-build = Kein Build dieser Mod passt zu Loader und Minecraft-Version des Servers.
+mod-browse-dependencies =
+    { $count ->
+        [one] Benötigt 1 weitere Mod; fehlt sie dem Server, wird sie mitinstalliert.
+       *[other] Benötigt { $count } weitere Mods; fehlende werden mitinstalliert.
+    }
+mod-browse-client-only = Diese Datei ist als reine Client-Mod markiert und funktioniert auf einem Server womöglich nicht.
+mod-browse-already-installed = Schon auf dem Server. Lösche die alte Jar im Mods-Tab, damit nicht beide Versionen geladen werden.
+mod-browse-install = Installieren
+mod-browse-installing = Wird installiert...
+
+## Console
+
+console-players = Spieler:
+console-tab-hint = (Tab zum Vervollständigen)
+console-input-hint = Befehl eingeben...
+console-send = Senden
+console-quick-edit = Schnellbefehle bearbeiten
+console-quick-title = Schnellbefehle
+console-quick-label = Beschriftung
+console-quick-command = Befehl
+console-quick-add = + Hinzufügen
+console-quick-reset = Auf Standard zurücksetzen
+console-rcon-info = RCON-Port: { $port } | Passwort: { $password }
+console-connected = ● Verbunden
+console-not-connected = ○ Nicht verbunden
+console-clear = Leeren
+console-opening = RCON-Konsole für '{ $name }' wird geöffnet
+console-intro-send = Befehle eingeben und mit Enter senden.
+console-intro-commands = Häufige Befehle: list, say <Nachricht>, op <Spieler>, whitelist add <Spieler>
+console-intro-tab = Tab vervollständigt die Namen von Spielern, die online sind.
+console-server-not-found = Fehler: Server '{ $name }' nicht gefunden
+console-session-ended = RCON-Sitzung beendet; die Konsole zum erneuten Verbinden wieder öffnen.
+console-connected-line = Verbunden.
+console-no-response = (keine Antwort)
+console-rcon-error = RCON-Fehler: { $error }
+console-rcon-auth-hint = Prüfen, ob RCON aktiviert und das Passwort richtig ist.
+console-rcon-port-hint = Läuft der Server auf RCON-Port { $port }?
+
+## Mods
+
+mods-title = Mods: { $server }
+mods-rescan = Neu einlesen
+mods-reading = Mod-Metadaten werden gelesen...
+mods-none = Noch keine Mods installiert.
+mods-none-hint = Mods erscheinen hier, nachdem der Server einmal gestartet wurde.
+mods-summary =
+    { $mods } Mods, { $issues ->
+        [one] 1 Problem
+       *[other] { $issues } Probleme
+    }
+mods-issues = Probleme
+mods-all-satisfied = ✓ Alle Abhängigkeiten erfüllt
+mods-install-all-missing = Alle { $count } fehlenden installieren
+mods-remove-all-unused = Alle { $count } ungenutzten Bibliotheken entfernen
+mods-remove-all-unused-hint = Jars werden nach removed-mods/ neben dem Datenordner verschoben
+mods-unreadable = Keine Mod-Metadaten gefunden in: { $files }
+mods-graph = Abhängigkeitsgraph
+mods-depends-on-nothing = Hängt ab von: nichts
+mods-depends-on = Hängt ab von:
+mods-any-version = beliebig
+mods-dep-missing = fehlt
+mods-dep-installed = { $version } installiert
+mods-dep-via = über { $file }
+mods-required-by = Benötigt von: { $mods }
+mods-browse = Mods durchsuchen...
+mods-add-jars = Jars hinzufügen...
+mods-jar-filter = Mod-Jar
+mods-dependencies = Abhängigkeiten...
+mods-dependencies-hint = Fehlende und veraltete Abhängigkeiten, ungenutzte Bibliotheken
+mods-drop-hint = Zieh .jar-Dateien irgendwo auf dieses Fenster, um sie hinzuzufügen.
+mods-running-hint = Änderungen wirken beim nächsten Serverstart; stoppe ihn, um Mods zu löschen.
+mods-drop-here = Loslassen, um zu mods/ hinzuzufügen
+mods-folder-empty = Der Mods-Ordner ist leer.
+mods-jar-count = { $count } Jars, { $size }
+mods-col-name = Name
+mods-col-version = Version
+mods-col-file = Datei
+mods-col-size = Größe
+mods-delete-hint = Wird nach removed-mods/ neben dem Datenordner verschoben
+mods-stop-first = Stoppe zuerst den Server
+mods-issue-missing = Fehlt: { $id }
+mods-issue-required-by = benötigt von { $mods }
+mods-issue-outdated = Veraltet: { $id }
+mods-issue-needs = { $installed } installiert, benötigt { $needed }
+mods-issue-unused = Ungenutzte Bibliothek: { $id }
+mods-issue-unused-file = { $file } (nichts hängt davon ab)
+
+## Server details
+
+details-tab-overview = Übersicht
+details-tab-console = Konsole
+details-tab-logs = Logs
+details-tab-players = Spieler
+details-tab-whitelist = Whitelist
+details-tab-mods = Mods
+details-tab-files = Dateien
+details-tab-backups = Backups
+details-tab-schedules = Zeitpläne
+details-tab-settings = Einstellungen
+details-title = Server: { $server }
+details-status = Status:
+details-modpack = Modpack:
+details-minecraft = Minecraft:
+details-port = Port:
+details-rcon-port = RCON-Port:
+details-memory = Speicher:
+details-memory-mb = { $mb } MB
+details-java = Java:
+details-startup = Start:
+details-startup-usual = meist ~{ $minutes } min { $seconds } s (letzte { $count } Starts)
+details-last-week = Letzte 7 Tage:
+details-last-week-summary =
+    { $percent } % online · { $starts ->
+        [one] 1 Start
+       *[other] { $starts } Starts
+    }, { $crashes ->
+        [one] 1 Absturz
+       *[other] { $crashes } Abstürze
+    } · längste Laufzeit { $longest }
+details-last-week-hint = Insgesamt { $up } online. Starts und Abstürze werden gezählt, solange DrakonixAnvil geöffnet ist.
+details-disk = Festplatte:
+details-disk-usage = { $data } (Backups { $backups })
+details-online = Online:
+details-nobody = niemand
+details-software = Software:
+details-plugins = Plugins ({ $count }):
+details-container = Container:
+details-no-container = (nicht erstellt)
+details-crash-reports = Absturzberichte ({ $count })
+
+## Create server
+
+create-title = Neuen Server erstellen
+create-name = Servername:
+create-port = Port:
+create-memory = Speicher (MB):
+create-jvm-preset = JVM-Voreinstellung:
+create-jvm-template-default = Vorgabe der Vorlage
+create-jvm-unsupported = Mit der Java-Version dieses Packs nicht verfügbar
+create-tab-featured = Empfohlen
+create-tab-search-all = Alle durchsuchen
+create-tab-search = { $site } durchsuchen
+create-tab-local = Aus lokaler Datei/Ordner
+create-selected = Ausgewählt:
+create-button = Server erstellen
+create-export-template = Vorlage exportieren...
+create-export-template-hint = Diese Vorlage als Datei speichern, die andere importieren können
+create-remove-template = Vorlage entfernen
+create-import-template = Vorlage importieren...
+create-import-template-hint = Eine Vorlagendatei hinzufügen, die jemand anderes geteilt hat
+create-add-from-url = Von URL hinzufügen
+create-check-templates = Vorlagen prüfen
+create-check-templates-hint = Prüfen, ob sich das Pack jeder Vorlage noch herunterladen lässt
+create-yours = (deine)
+create-template-warning = ⚠ Warnung
+create-template-broken = ⚠ Defekt
+
+## Edit server
+
+edit-modpack = Modpack
+edit-current = Aktuell: { $name } v{ $version } (MC { $mc }, { $loader })
+edit-source = Quelle: { $source }
+edit-source-cf-latest = CurseForge: { $slug } (neueste)
+edit-source-cf-file = CurseForge: { $slug } (Datei { $file })
+edit-source-cf-host-latest = CurseForge: { $slug } (neueste, auf dem Host installiert)
+edit-source-cf-host-file = CurseForge: { $slug } (Datei { $file }, auf dem Host installiert)
+edit-source-cf-server-pack = CurseForge: { $slug } (Server-Pack { $file }, auf dem Host installiert)
+edit-source-forge = Forge { $forge } + Pack ({ $url })
+edit-source-ftb = FTB (Pack { $pack }, Version { $version })
+edit-source-direct = Direkt: { $url }
+edit-source-local = Lokal: { $path }
+edit-install-on-host = Auf dem Host aus dem Client-Manifest installieren
+edit-install-on-host-hint = Ausweichlösung für Packs, die AUTO_CURSEFORGE nicht installieren kann: Die App lädt jede Mod selbst herunter, lässt reine Client-Mods weg und wendet die Overrides des Packs an, bevor der Container startet. Nach einer Änderung den Container neu erstellen.
+edit-apply-builtin = Eingebaute Vorlage anwenden:
+edit-select-template = — auswählen —
+edit-apply-template = Vorlage anwenden
+edit-search-curseforge = CurseForge durchsuchen
+edit-apply-curseforge = CurseForge-Pack anwenden
+edit-search-modrinth = Modrinth durchsuchen
+edit-apply-modrinth = Modrinth-Pack anwenden
+edit-install-mrpack = Aus .mrpack installieren
+edit-extra-ports = Zusätzliche Ports:
+edit-extra-ports-example = z. B. 8123, 24454
+edit-bind-address = Bind-Adresse:
+edit-bind-address-all = alle Schnittstellen
+edit-extra-ports-hint = Zusätzliche Ports werden 1:1 auf dem Host freigegeben (Webkarten, Sprachchat usw.); 25565 und 25575 belegen Spiel und RCON im Container.
+edit-bind-address-hint = Eine Bind-Adresse (z. B. eine VPN- oder Tailscale-IP) lässt Spieler nur über diese Schnittstelle zu. RCON und Query bleiben auf localhost.
+edit-jvm-custom = Benutzerdefiniert
+edit-jvm-unsupported = Für diese Java-Version nicht verfügbar
+edit-jvm-preset-hint = Füllt die Optionen unten passend zur gewählten Java-Version und zum Speicher aus.
+edit-java-options = Java-Optionen (eine pro Zeile):
+edit-java-options-hint = Übliche Optionen: -XX:+UseG1GC, -XX:MaxGCPauseMillis=200 usw.
+edit-gc = Garbage Collector:
+edit-gc-logging = GC-Pausenzeiten aufzeichnen
+edit-gc-logging-hint = Schreibt logs/gc.log; die Pausenstatistik wird bei jedem Stopp des Servers gespeichert
+edit-gc-logging-unsupported = GC-Logging braucht Java 9+
+edit-gc-history = GC-Pausenverlauf:
+edit-gc-collector = Collector
+edit-gc-runs = Läufe
+edit-gc-pauses = Pausen
+edit-gc-avg = Ø
+edit-gc-max = Max
+edit-java-version = Java-Version:
+edit-extra-env = Zusätzliche Umgebungsvariablen (eine pro Zeile, KEY=VALUE):
+edit-extra-env-example = z. B. CF_EXCLUDE_MODS=optifine, CF_FORCE_SYNCHRONIZE=true
+edit-world-url = Welt-Download-URL:
+edit-world-url-hint = Zip einer Startkarte, die vor dem ersten Start entpackt wird. Wird ignoriert, sobald der Server eine Welt hat.
+edit-server-properties = Servereigenschaften
+edit-motd = MOTD:
+edit-max-players = Max. Spieler:
+edit-invalid = Ungültig
+edit-difficulty = Schwierigkeit:
+edit-gamemode = Spielmodus:
+edit-pvp = PVP:
+edit-online-mode = Online-Modus:
+edit-whitelist = Whitelist:
+edit-view-distance = Sichtweite:
+edit-chunks = Chunks
+edit-backup-excludes = Backup-Ausschlüsse
+edit-backup-excludes-hint = Pfade, die in keinem Backup landen, ein Muster pro Zeile, relativ zum Serverordner:
+edit-backup-excludes-syntax = * passt innerhalb eines Ordners, ** über Ordner hinweg; ein Name ohne / passt überall (z. B. *.log).
+edit-backup-excludes-suggested = Vorschläge hinzufügen
+edit-backup-excludes-restore = Beim Wiederherstellen bleiben die aktuellen Kopien ausgeschlossener Pfade auf dem Server erhalten.
+edit-backup-group = Backup-Gruppe:
+edit-backup-group-none = keine
+edit-backup-group-hint = Server derselben Gruppe (z. B. ein Proxy-Netzwerk mit gemeinsamer Wirtschaft) werden im selben Moment gesichert und gemeinsam wiederhergestellt
+edit-revert = Verwerfen
+edit-revert-hint = Ungespeicherte Änderungen verwerfen
+edit-save = Änderungen speichern
+edit-invalid-port = Ungültige Portnummer
+edit-invalid-memory = Ungültiger Speicherwert
+edit-invalid-extra-ports = Ungültige zusätzliche Ports (keine Wiederholungen, nicht 25565 oder 25575)
+edit-invalid-bind-address = Ungültige Bind-Adresse
+edit-invalid-java-options = Ungültige Java-Optionen
+edit-note-next-start = Hinweis: Änderungen werden beim nächsten Start des Servers wirksam.
+edit-note-recreate = Der Container wird mit den neuen Einstellungen neu erstellt.
+edit-mrpack-hint = Für Modrinth-Packs ohne Server-Pack. Die App lädt die Dateien des Packs ohne reine Client-Dateien herunter, bevor der Container startet.
+edit-mrpack-input = Datei oder URL:
+edit-mrpack-input-hint = C:\packs\pack.mrpack oder https://...
+edit-browse = Durchsuchen...
+edit-mrpack-filter = Modrinth-Pack
+edit-use-mrpack = .mrpack verwenden
+edit-broadcasts = Geplante Durchsagen
+edit-broadcasts-hint = Wird per RCON gesendet, solange der Server läuft, z. B. `say Lies die Regeln am /spawn`.
+edit-broadcast-every = alle
+edit-add-broadcast = + Durchsage hinzufügen
+edit-auto-backups = Automatische Backups
+edit-remote-backup = Backups in den Remote-Speicher hochladen
+edit-remote-backup-hint = Das S3-, WebDAV- oder SFTP-Ziel unter Einstellungen → Externe Sicherungen einrichten
+edit-schedules-note = Zeitpläne gelten sofort; der Server muss nicht neu starten.
+edit-save-schedules = Zeitpläne speichern
+edit-backup-auto = Automatisch sichern
+edit-backup-every = Alle
+edit-backup-daily = Täglich um
+edit-keep-last = Die letzten behalten:
+edit-keep-last-unit = Backups
+edit-keep-daily = Eines pro Tag behalten für:
+edit-keep-daily-unit = Tage
+edit-keep-weekly = Eines pro Woche behalten für:
+edit-keep-weekly-unit = Wochen
+edit-prune-note = Nur geplante Backups werden aufgeräumt. Manuelle, eingefrorene und Gruppen-Backups bleiben erhalten.
+edit-own-compression = Eigene Komprimierung
+edit-own-compression-hint = Sonst verwenden geplante Backups die Komprimierung aus den Einstellungen
+
+## Status messages
+
+msg-docker-not-connected = Docker nicht verbunden
+msg-server-not-found = Server '{ $name }' nicht gefunden
+msg-no-container = Server '{ $name }' hat keinen Container
+msg-backup-busy = Es läuft bereits ein Backup
+msg-restore-busy = Es läuft bereits eine Wiederherstellung
+msg-restore-or-import-busy = Es läuft bereits eine Wiederherstellung oder ein Import
+msg-export-busy = Es läuft bereits ein Export
+msg-settings-save-failed = Einstellungen konnten nicht gespeichert werden: { $error }
+msg-server-created = Server '{ $name }' wurde erstellt!
+msg-server-updated = Einstellungen von '{ $name }' aktualisiert!
+msg-schedules-updated = Zeitpläne von '{ $name }' aktualisiert
+msg-server-deleted = Server '{ $name }' gelöscht
+msg-server-adopted = Server '{ $name }' übernommen
+msg-server-crashed = WARNUNG: { $name } ist abgestürzt, siehe { $report }
+msg-data-dir-failed = Datenordner konnte nicht erstellt werden: { $error }
+msg-orphan-delete-failed = '{ $name }' konnte nicht gelöscht werden: { $error }
+msg-orphan-deleted = Verwaister Ordner '{ $name }' gelöscht
+msg-template-filter = Vorlage
+msg-template-import-failed = Vorlage konnte nicht importiert werden: { $error }
+msg-template-exported = Vorlage '{ $name }' nach { $path } exportiert
+msg-template-removed = Vorlage '{ $name }' entfernt
+msg-template-save-failed = Vorlage konnte nicht gespeichert werden: { $error }
+msg-template-added = Vorlage '{ $name }' hinzugefügt
+msg-log-filter = Logdatei
+msg-logs-saved = Logs nach { $path } gespeichert
+msg-backup-read-failed = Backup konnte nicht gelesen werden: { $error }
+msg-backup-list-failed = Backups konnten nicht aufgelistet werden: { $error }
+msg-backup-deleted = Backup gelöscht
+msg-backup-delete-failed = Backup konnte nicht gelöscht werden: { $error }
+msg-restore-failed = Wiederherstellung fehlgeschlagen: { $error }
+msg-import-failed = Import fehlgeschlagen: { $error }
+msg-whitelist-save-failed = Whitelist konnte nicht gespeichert werden: { $error }
+msg-group-restore-running = { $servers } vor dem Wiederherstellen der Gruppe stoppen
+msg-group-snapshot-missing = Kein Mitglied hat diesen Snapshot
+msg-new-name-empty = Einen Namen eingeben
+msg-new-name-slashes = Namen dürfen keine Schrägstriche enthalten
+msg-new-name-taken = Ein Server namens '{ $name }' existiert bereits
+msg-new-name-folder = Ein Ordner für '{ $name }' existiert bereits auf der Festplatte
+msg-new-port-taken = Port { $port } oder { $rcon } wird von '{ $server }' verwendet
+msg-restore-new-no-settings = Keine Einstellungen von '{ $name }' gefunden, die in den neuen Server übernommen werden könnten
+msg-restore-files-running = Den Server vor dem Wiederherstellen von Dateien stoppen
+msg-restore-player-running = Den Server vor dem Wiederherstellen von Spielerdaten stoppen
+msg-player-restored = { $player } aus dem Backup wiederhergestellt
+msg-player-restore-failed = Wiederherstellen des Spielers fehlgeschlagen: { $error }
+msg-mods-only-jars = Nur .jar-Dateien können zu mods/ hinzugefügt werden
+msg-mods-added =
+    { $count ->
+        [one] 1 Jar hinzugefügt
+       *[other] { $count } Jars hinzugefügt
+    }
+msg-mods-add-failed = Mods konnten nicht hinzugefügt werden: { $error }
+msg-mods-remove-running = Den Server vor dem Entfernen von Mods stoppen
+msg-mods-removed =
+    { $count ->
+        [one] 1 Jar nach removed-mods/ verschoben
+       *[other] { $count } Jars nach removed-mods/ verschoben
+    }
+msg-mods-remove-failed = Mods konnten nicht entfernt werden: { $error }
+msg-bundle-filter = DrakonixAnvil-Server
+msg-curseforge-pack-filter = CurseForge-Pack
+msg-world-filter = Minecraft-Welt
+progress-starting-restore = Wiederherstellung startet...
+progress-counting-files = Dateien werden gezählt...
+progress-copying-files = Dateien werden kopiert...
+progress-freezing = Wird eingefroren...
+progress-pausing-saves = Weltspeicherung wird pausiert...
+progress-verifying = Wird geprüft...
+progress-checking-backups = Backups werden geprüft...
+progress-looking-up-mods = Mods werden nachgeschlagen...
+msg-data-move-running = Vor dem Verschieben des Datenordners alle Server stoppen
+msg-data-move-busy = Warte, bis Backups, Downloads und andere laufende Aufgaben fertig sind, bevor du den Datenordner verschiebst
+msg-data-moving = Der Datenordner wird gerade verschoben; starte den Server, sobald das erledigt ist
+msg-data-move-failed = Verschieben fehlgeschlagen, die Daten liegen noch in { $path }: { $error }
+msg-data-moved = Daten nach { $path } verschoben
+msg-data-moved-partly = Daten nach { $path } verschoben. Einige alte Dateien konnten nicht gelöscht werden: { $files }
+msg-drop-jars-no-server = Vor dem Ablegen von Mod-Jars im Fenster die Seite eines Servers öffnen
+msg-drop-unknown = Unbekannter Dateityp { $file }: ein Server-Bundle, ein Pack-Zip oder .mrpack oder Mod-Jars ablegen
+msg-importing-server = Server wird importiert...
+progress-moving = Wird verschoben...
+msg-server-started = Server '{ $name }' gestartet!
+msg-server-stopped = Server '{ $name }' gestoppt
+msg-server-frozen = Server '{ $name }' eingefroren; Snapshot wird erstellt
+msg-backup-created = Backup erstellt: { $file }
+msg-backup-failed = Backup fehlgeschlagen: { $error }
+msg-backup-verified = { $file } geprüft
+msg-backup-verify-failed = { $file } hat die Prüfung nicht bestanden: { $error }
+msg-backup-restored = Backup für '{ $name }' wiederhergestellt
+msg-restored-as-new = Server '{ $name }' aus dem Backup wiederhergestellt
+msg-local-imported = Server '{ $name }' importiert
+msg-server-imported = Server '{ $name }' wurde importiert!
+msg-files-restored =
+    { $count ->
+        [one] 1 Datei für '{ $name }' wiederhergestellt
+       *[other] { $count } Dateien für '{ $name }' wiederhergestellt
+    }
+msg-upload-failed = Hochladen des Backups fehlgeschlagen: { $error }
+msg-group-backed-up = Gruppe '{ $group }' gesichert ({ $count } Server)
+msg-group-backup-failed = Gruppen-Backup fehlgeschlagen: { $error }
+msg-group-restored = Gruppe '{ $group }' wiederhergestellt ({ $count } Server)
+msg-group-restore-failed = Wiederherstellen der Gruppe fehlgeschlagen: { $error }
+msg-command-sent = Gesendet: { $command }
+msg-command-failed = '{ $command }' fehlgeschlagen: { $error }
+msg-username-lookup-failed = Nachschlagen der Benutzernamen fehlgeschlagen: { $error }
+msg-diagnostics-problems =
+    { $count ->
+        [one] Die Diagnose hat 1 Problem gefunden, siehe Reiter Diagnose
+       *[other] Die Diagnose hat { $count } Probleme gefunden, siehe Reiter Diagnose
+    }
+msg-mods-read-failed = Mods konnten nicht gelesen werden: { $error }
+msg-installed =
+    { $dependencies ->
+        [0] { $name } installiert
+        [one] { $name } und 1 Abhängigkeit installiert
+       *[other] { $name } und { $dependencies } Abhängigkeiten installiert
+    }
+msg-install-failed = { $name } konnte nicht installiert werden: { $error }
+msg-exported = Exportiert: { $file }
+msg-export-failed = Export fehlgeschlagen: { $error }
+msg-unreachable = { $address } antwortet nicht aus dem Internet; Portweiterleitung und Firewalls prüfen
+msg-templates-fine = Alle { $count } Vorlagen sehen gut aus
+msg-templates-broken = { $broken } von { $count } Vorlagen sind defekt
+msg-template-add-failed = Vorlage konnte nicht hinzugefügt werden: { $error }
+progress-verifying-file = { $file } wird geprüft
+msg-copied = { $address } kopiert
+progress-copying-file = Kopiere { $current }/{ $total }: { $file }
+msg-download-cache-cleared = Download-Cache geleert ({ $size } freigegeben)
+msg-api-cache-cleared = API-Cache geleert ({ $size } freigegeben)
+msg-invalid-proxy = Ungültiger Proxy: { $error }
+msg-shortcut-taken = { $keys } wird bereits von "{ $action }" verwendet
+msg-container-remove-failed = Container '{ $container }' konnte nicht entfernt werden: { $error }
+
+## Activity log
+
+log-save-servers-failed = Server konnten nicht gespeichert werden: { $error }
+log-discord-start = Discord: { $user } möchte '{ $name }' starten
+log-discord-stop = Discord: { $user } möchte '{ $name }' stoppen
+log-discord-whitelist = Discord: { $user } möchte { $player } auf '{ $name }' whitelisten
+log-importing-pack = Importiere { $source } in den neuen Server '{ $name }'...
+log-start-time = '{ $name }' hat { $seconds } s zum Starten gebraucht
+log-eula-accepted = Minecraft-EULA akzeptiert
+log-preflight = Startprüfung für '{ $name }': { $check } - { $detail }
+log-eula-recorded = EULA-Zustimmung für Server '{ $name }' gespeichert
+log-pulling = Lade Image für Server '{ $name }' herunter...
+log-starting = Starte Server '{ $name }'...
+log-checking-image = Prüfe Docker-Image { $image }...
+log-pull-failed = Image konnte nicht heruntergeladen werden: { $error }
+log-image-ready = Docker-Image { $image } bereit
+log-installing-server-pack = Installiere Server-Pack auf dem Host...
+log-pack-unverified = '{ $name }': Prüfsumme des Packs nicht gefunden, installiere ungeprüft: { $error }
+log-server-pack-failed = Server-Pack konnte nicht installiert werden: { $error }
+log-server-pack-installed = Server-Pack erfolgreich installiert
+log-installing-cf-pack = Installiere CurseForge-Pack auf dem Host...
+log-installing-cf-server-pack = Installiere CurseForge-Server-Pack auf dem Host...
+log-installing-mrpack = Installiere .mrpack auf dem Host...
+log-modpack-installed = Modpack installiert (Minecraft { $minecraft }, { $loader } { $version })
+log-modpack-installed-unknown-loader = Modpack installiert; sein Loader wurde nicht erkannt, daher gelten die Loader-Einstellungen des Servers
+log-modpack-failed = Modpack konnte nicht installiert werden: { $error }
+log-creating-container = Erstelle Container { $container }...
+log-created-container = Container { $container } erstellt
+log-container-start-failed = Container konnte nicht gestartet werden: { $error }
+log-container-started = Container gestartet, warte auf die Initialisierung des MC-Servers...
+log-container-name-conflict = Container-Namenskonflikt für '{ $name }' — der alte Container existiert noch
+log-container-create-failed = Container konnte nicht erstellt werden: { $error }
+log-stopping = Stoppe Server '{ $name }'...
+log-unpause-failed = Fortsetzen vor dem Stoppen fehlgeschlagen: { $error }
+log-stopped = Server '{ $name }' erfolgreich gestoppt!
+log-stop-failed = Stoppen fehlgeschlagen: { $error }
+log-save-logs-failed = Logs konnten nicht in { $path } gespeichert werden: { $error }
+log-error-spike = { $count } Fehler in der letzten Minute, starte Diagnose...
+log-freezing = Friere Server '{ $name }' ein...
+log-world-saved = '{ $name }': Welt gespeichert
+log-save-all-failed = save-all auf '{ $name }' fehlgeschlagen ({ $error }); friere trotzdem ein
+log-pause-failed = '{ $name }' konnte nicht pausiert werden: { $error }
+log-frozen-backup-busy = '{ $name }' eingefroren, aber ein anderes Backup läuft; erstelle einen Snapshot, sobald es fertig ist
+log-unfrozen = Server '{ $name }' aufgetaut
+log-unfreeze-failed = '{ $name }' konnte nicht aufgetaut werden: { $error }
+log-removing-container = Entferne alten Container '{ $container }'...
+log-creating-backup = Erstelle Backup für '{ $name }'...
+log-pruned-backups = { $count } alte geplante Backup(s) von '{ $name }' gelöscht
+log-prune-failed = Löschen alter Backups von '{ $name }' fehlgeschlagen: { $error }
+log-save-on-failed = Weltspeicherung für '{ $name }' konnte nicht wieder eingeschaltet werden ({ $error }); führe `save-on` in seiner Konsole aus
+log-creating-group-backup = Erstelle Gruppen-Backup '{ $group }' ({ $members })...
+log-save-off-failed = Speichern auf '{ $name }' konnte nicht pausiert werden ({ $error }); sein Snapshot ist eventuell etwas neuer als der Rest
+log-group-member-newer = '{ $member }' ist der Gruppe '{ $group }' nach der Aufnahme von { $file } beigetreten; er bleibt unverändert
+log-restoring-group = Stelle Gruppe '{ $group }' aus { $file } wieder her...
+log-restoring-into-new = Stelle { $backup } von '{ $source }' im neuen Server '{ $name }' wieder her...
+log-restoring-files = Stelle { $paths } aus { $backup } für '{ $name }' wieder her...
+log-player-restored = { $count } Datei(en) für { $player } ({ $uuid }) auf '{ $name }' aus { $backup } wiederhergestellt
+log-player-restore-failed = Wiederherstellen von { $uuid } fehlgeschlagen: { $error }
+log-installing-mod = Installiere { $file } in '{ $name }'...
+log-mods-added = { $mods } zu '{ $name }' hinzugefügt
+log-add-mods-failed = Hinzufügen von Mods zu '{ $name }' fehlgeschlagen: { $error }
+log-mods-moved = { $count } JAR(s) von '{ $name }' nach { $folder } verschoben
+log-remove-mods-failed = Entfernen der Mods fehlgeschlagen: { $error }
+log-restoring-backup = Stelle Backup für '{ $name }' wieder her...
+log-exporting = Exportiere Server '{ $name }'...
+log-exporting-world = Exportiere die Welt von '{ $name }'...
+log-exporting-client-pack = Exportiere ein { $format }-Client-Pack von '{ $name }'...
+log-client-loader-unknown = Unklar, welche { $loader }-Version '{ $name }' verwendet; Spieler müssen sie in ihrem Launcher auswählen
+log-moving-data = Verschiebe Daten von { $from } nach { $to }...
+log-data-move-failed = Verschieben des Datenordners fehlgeschlagen: { $error }
+log-data-folder-save-failed = Der neue Datenordner konnte nicht gespeichert werden: { $error }
+log-data-moved = Daten nach { $path } verschoben
+log-data-left-behind = Konnte nicht aus { $path } löschen: { $files }
+log-importing-server = Importiere Server aus { $path }...
+log-whitelist-read-failed = Lesen der Whitelist für '{ $name }': { $error }
+log-scheduled-backup = Starte geplantes Backup von '{ $name }'...
+log-broadcast-failed = Geplante Durchsage auf '{ $name }' fehlgeschlagen: { $error }
+log-webhook-failed = Webhook für '{ $event }' fehlgeschlagen: { $error }
+log-notification-failed = Desktop-Benachrichtigung fehlgeschlagen: { $error }
+log-alert-webhook-failed = Alarm-Webhook fehlgeschlagen: { $error }
+log-likely-cause = '{ $name }' ist wahrscheinlich fehlgeschlagen, weil: { $causes }
+log-backup-saved = Backup gespeichert unter { $path }
+log-backup-failed = Backup fehlgeschlagen: { $error }
+log-deleted-container-remove-failed = Container des gelöschten Servers '{ $name }' konnte nicht entfernt werden: { $error }
+log-container-recreating = Alten Container '{ $container }' entfernt, erstelle neu...
+log-file-op-failed = Dateioperation auf '{ $name }' fehlgeschlagen: { $error }
+log-diagnostics-problem = Diagnose: { $problem }
+log-backup-verified = Backup { $file } von '{ $name }' geprüft: { $files } Dateien, { $size }
+log-backup-verify-failed = Backup { $file } von '{ $name }' hat die Prüfung nicht bestanden: { $error }
+log-remote-uploaded = Backup von '{ $name }' in den Fernspeicher hochgeladen
+log-remote-pruned = { $count } alte entfernte Backup(s) von '{ $name }' gelöscht
+log-remote-upload-failed = Hochladen des Backups von '{ $name }' fehlgeschlagen: { $error }
+log-group-backup-saved = Gruppen-Backup gespeichert unter { $path }
+log-group-backup-failed = Gruppen-Backup '{ $group }' fehlgeschlagen: { $error }
+log-group-restored = Gruppe '{ $group }' aus dem Snapshot wiederhergestellt
+log-group-restore-failed = Wiederherstellung der Gruppe '{ $group }' fehlgeschlagen: { $error }. Mitglieder, die vor dem Fehler wiederhergestellt wurden, behalten die Daten des Snapshots.
+log-restored = Backup für '{ $name }' erfolgreich wiederhergestellt
+log-restore-failed = Wiederherstellung fehlgeschlagen: { $error }
+log-restored-into-new = Backup im neuen Server '{ $name }' wiederhergestellt
+log-restore-into-new-failed = Wiederherstellen im neuen Server '{ $name }' fehlgeschlagen: { $error }
+log-local-pack-copied = Lokales Pack in den neuen Server '{ $name }' kopiert
+log-import-into-new-failed = Import in den neuen Server '{ $name }' fehlgeschlagen: { $error }
+log-selective-restored = Teilweise Wiederherstellung für '{ $name }' abgeschlossen: { $count } Datei(en)
+log-selective-restore-failed = Teilweise Wiederherstellung fehlgeschlagen: { $error }
+log-dependency-installed = { $file } in '{ $name }' für die fehlende Abhängigkeit { $dependency } installiert
+log-install-failed = Installation von { $item } fehlgeschlagen: { $error }
+log-installed = { $files } in '{ $name }' installiert
+log-exported = '{ $name }' exportiert nach { $path }
+log-export-failed = Export fehlgeschlagen: { $error }
+log-templates-updated = Vorlagenindex aktualisiert ({ $count } Vorlagen)
+log-templates-not-updated = Vorlagenindex nicht aktualisiert: { $error }
+log-unlabeled-container-removed = Unmarkierten Container für '{ $name }' entfernt; er wird beim nächsten Start neu erstellt
+log-old-container-remove-failed = Alter Container für '{ $name }' konnte nicht entfernt werden: { $error }
+log-ddns-error = Dynamisches DNS: { $error }
+log-ddns-updated = Dynamisches DNS: { $host } zeigt jetzt auf { $ip }
+log-ddns-failed = Aktualisierung des dynamischen DNS fehlgeschlagen: { $error }
+log-public-ip-failed = Öffentliche IP konnte nicht ermittelt werden: { $error }
+log-container-stopped = Der Container von '{ $name }' wurde beendet. Prüfe die Container-Logs auf Fehler.
+log-discord-bot-stopped = Discord-Bot gestoppt: { $error }
+log-metrics-error = Metrik-Endpunkt: { $error }
+log-reachable = { $address } ist aus dem Internet erreichbar
+log-unreachable = { $address } antwortet nicht aus dem Internet
+log-reachability-failed = Konnte nicht prüfen, ob { $address } erreichbar ist: { $error }
+log-template-broken = Vorlage '{ $name }' ist defekt: { $detail }
+log-import-failed = Import fehlgeschlagen: { $error }
+log-container-status-failed = Container-Status konnte nicht geprüft werden: { $error }
+log-cf-key-needed = Zum Installieren eines CurseForge-Packs wird ein API-Schlüssel benötigt; füge ihn in den Einstellungen hinzu
+log-accepting-connections = Server '{ $name }' nimmt jetzt Verbindungen an! (Latenz: { $latency } ms)
+log-ping-version = Version: { $version } (Protokoll { $protocol })
+log-ping-motd = MOTD: { $motd }
+log-ping-players = Spieler: { $online }/{ $max } online
+log-ping-software = Software: { $software }
+log-ping-mods = Mods: { $count } geladen
+log-ping-plugins = Plugins: { $count } geladen
+log-ping-map = Karte: { $map }
+log-not-ready = Server '{ $name }' noch nicht bereit (Versuch { $attempt }/{ $max })
+log-waiting-init = Warte auf die Initialisierung von '{ $name }' (Versuch { $attempt }/{ $max })
+log-still-initializing = Server '{ $name }' initialisiert nach 10 Minuten immer noch. Prüfe die Container-Logs auf Fortschritt.
+log-cancelling-restore = Breche Wiederherstellung ab...
+log-open-failed = { $path } konnte nicht geöffnet werden: { $error }
+log-start-despite-warnings = Starte '{ $name }' trotz Warnungen der Startprüfung
+log-docker-connected = Docker verbunden (v{ $version })
+log-docker-connect-failed = Verbindung zu Docker fehlgeschlagen: { $error }
+log-servers-loaded = { $count } Server von der Festplatte geladen
+log-servers-load-failed = Server konnten nicht geladen werden: { $error }
+log-host-memory = Host-RAM: { $total } MB gesamt, { $free } MB verfügbar
+log-gc-benchmark = { $collector }-Lauf auf '{ $name }': { $pauses } Pausen, Ø { $avg } ms, p99 { $p99 } ms, max. { $max } ms
+log-client-pack = Client-Pack für '{ $name }': { $linked } Mods mit { $site } verknüpft, { $bundled } mitgeliefert
+log-client-pack-left-out = Client-Pack für '{ $name }': { $linked } Mods mit { $site } verknüpft, { $bundled } mitgeliefert, { $skipped } Nur-Server-Mods ausgelassen ({ $mods })
+log-legacy-check-failed = Prüfung auf unmarkierte Container fehlgeschlagen: { $error }
+log-legacy-renamed = Unmarkierten Container für '{ $name }' in { $container } umbenannt
+log-legacy-rename-failed = Container für '{ $name }' konnte nicht umbenannt werden: { $error }
+log-test-webhook-sent = Test-Webhook-Nachricht gesendet
+log-test-webhook-failed = Test-Webhook fehlgeschlagen: { $error }
+
+## Server logs
+
+logs-save = Logs speichern…
+logs-auto-refresh = (automatisch aktualisiert: { $seconds } s)
+logs-source = Quelle:
+docker-logs-auto-refresh = (automatisch aktualisieren: 5 s)
+docker-logs-hint = Zusammengeführte Logs aller von DrakonixAnvil verwalteten Container
+logs-no-container = Kein Container gefunden. Zuerst den Server starten, um Logs zu sehen.
+logs-fetch-failed = Fehler beim Abrufen der Logs: { $error }
+docker-logs-none = Keine von DrakonixAnvil verwalteten Container gefunden.
+
+## Backups tab
+
+backups-incremental = Inkrementelles Backup
+backups-incremental-hint = Nur die seit dem neuesten Voll-Backup geänderten Dateien speichern
+backups-full = Voll-Backup
+backups-group = Gruppe '{ $group }' sichern
+backups-group-hint = { $servers } im selben Moment sichern
+backups-none = Für diesen Server wurden keine Backups gefunden.
+backups-none-hint = Mit 'Voll-Backup' oben eines erstellen.
+backups-size = Größe: { $size }
+backups-builds-on = Inkrementell, baut auf { $base } auf
+backups-verified = ✔ Geprüft
+backups-verified-compared = { $files } Dateien, { $size }; CRCs und Größen geprüft am { $time }
+backups-verified-crc = { $files } Dateien, { $size }; CRCs geprüft am { $time }
+backups-days-ago =
+    { $count ->
+        [one] vor 1 Tag
+       *[other] vor { $count } Tagen
+    }
+backups-hours-ago =
+    { $count ->
+        [one] vor 1 Stunde
+       *[other] vor { $count } Stunden
+    }
+backups-just-now = Gerade eben
+backups-restore = Wiederherstellen
+backups-restore-new = Als neuen Server wiederherstellen...
+backups-restore-new-hint = Die Einstellungen dieses Servers in einen neuen Server kopieren und das Backup dort wiederherstellen; dieser Server bleibt unverändert
+backups-contents = Inhalt...
+backups-contents-hint = Die Dateien dieses Backups durchsehen und ansehen oder nur einige davon wiederherstellen
+backups-verify = Prüfen
+backups-verify-hint = Das Archiv zurücklesen und mit den Dateien vergleichen, aus denen es erstellt wurde
+backups-restore-set = Satz wiederherstellen
+backups-restore-set-hint = Alle Gruppenmitglieder aus diesem Snapshot wiederherstellen
+backups-players = Spieler...
+backups-players-hint = Die Daten eines einzelnen Spielers aus diesem Backup wiederherstellen
+backup-players-title = Spieler in { $file }
+backup-players-hint = Stellt Inventar, Statistiken und Fortschritte eines Spielers wieder her. Der Rest der Welt bleibt unverändert.
+backup-players-stop-first = Zuerst den Server stoppen. Ersetzte Dateien werden in replaced-playerdata/ aufbewahrt.
+backup-players-none = In diesem Backup wurden keine Spielerdaten gefunden.
+restore-new-title = Als neuen Server wiederherstellen
+restore-new-explain = Erstellt eine Kopie von '{ $name }' mit der Welt und den Dateien aus { $file }.
+restore-new-untouched = Der ursprüngliche Server und seine Daten bleiben unberührt.
+restore-new-name = Name des neuen Servers:
+restore-new-rcon = RCON verwendet Port { $port }.
+restore-new-not-copied = Zusätzliche Ports, Backup-Zeitplan, Gruppe und Remote-Uploads werden nicht übernommen.
+
+## Confirmation dialogs
+
+confirm-unknown = Unbekannt
+confirm-server = Server: { $name }
+confirm-modpack = Modpack: { $name }
+confirm-port = Port: { $port }
+confirm-delete-server = Server löschen?
+confirm-docker-container = Docker-Container
+confirm-container-removed = Der Container existiert und wird entfernt
+confirm-no-container = Kein Container (nur Konfiguration)
+confirm-delete-server-data-kept = Serverdaten in DrakonixAnvilData/servers/ werden NICHT gelöscht.
+confirm-delete-server-recreate = Der Server kann später mit denselben Daten neu erstellt werden.
+confirm-restore = Backup wiederherstellen?
+confirm-restore-question = '{ $file }' auf Server '{ $name }' wiederherstellen?
+confirm-restore-warning = WARNUNG: Dadurch werden alle aktuellen Serverdaten überschrieben!
+confirm-restore-stop-first = Vor dem Wiederherstellen sicherstellen, dass der Server gestoppt ist.
+confirm-restore-group = Gruppen-Snapshot wiederherstellen?
+confirm-restore-group-question = '{ $file }' auf jedem Server der Gruppe '{ $group }' wiederherstellen:
+confirm-restore-group-missing = • { $name } (nicht in diesem Snapshot, bleibt unverändert)
+confirm-restore-group-warning = WARNUNG: Dadurch werden alle aktuellen Daten auf diesen Servern überschrieben!
+confirm-restore-group-stop-first = Vor dem Wiederherstellen müssen alle Mitglieder gestoppt sein.
+confirm-restore-all = Alle wiederherstellen
+confirm-delete-backup = Backup löschen?
+confirm-backup-file = Backup-Datei
+confirm-unknown-size = unbekannte Größe
+confirm-cannot-undo = Diese Aktion kann nicht rückgängig gemacht werden.
+confirm-container-exists = Container existiert bereits
+confirm-old-container = Alter Container
+confirm-container-recreate = Die Einstellungen wurden geändert, daher muss der alte Container entfernt und neu erstellt werden.
+confirm-container-safe = Das ist unbedenklich! Alle Serverdaten liegen in DrakonixAnvilData/servers/, nicht im Container. Den Container zu entfernen ist wie das Löschen einer Verknüpfung — Welten, Konfigurationen und Mods bleiben unberührt.
+confirm-remove-restart = Entfernen & neu starten
+
+## EULA
+
+eula-required = Der Betrieb eines Minecraft-Servers setzt die Zustimmung zum Minecraft-Endbenutzer-Lizenzvertrag (EULA) voraus.
+eula-read = Hier nachlesen:
+eula-agree-explain = Mit 'Ich stimme zu' akzeptieren Sie die EULA in eigenem Namen. DrakonixAnvil übergibt dann EULA=TRUE an die Server, die Sie starten.
+eula-asked-once = Sie werden nur einmal gefragt. Jeder Server vermerkt, wann die Zustimmung auf ihn angewendet wurde.
+eula-agree = Ich stimme zu
+
+## Import server
+
+import-title = Server importieren
+import-preview = Servervorschau
+import-name = Name: { $name }
+import-version = Version: { $version }
+import-minecraft = Minecraft: { $version }
+import-loader = Loader: { $loader }
+import-memory = Arbeitsspeicher: { $mb } MB
+import-name-conflict = Ein Server namens '{ $name }' existiert bereits. Der Import überschreibt seine Daten.
+import-read-failed = Exportpaket konnte nicht gelesen werden: { $error }
+import-button = Importieren
+
+## Move data folder
+
+data-move-title = Datenordner verschieben
+data-move-from = Von:
+data-move-to = Nach:
+data-move-moves = Verschiebt:
+data-move-nothing = noch nichts
+data-move-size = Größe:
+data-move-free = Dort frei:
+data-move-unknown = unbekannt
+data-move-settings-stay = Die Einstellungen bleiben in { $path }. Server-Container werden beim nächsten Start entfernt und neu erstellt, damit sie den neuen Ordner einbinden; Welten, Konfigurationen und Mods wandern mit den Daten.
+data-move-how = Auf demselben Laufwerk geht das sofort. Zwischen Laufwerken wird zuerst alles kopiert, und die alte Kopie wird erst gelöscht, wenn das Kopieren abgeschlossen ist.
+data-move-no-space = Am Ziel ist nicht genug freier Speicherplatz für die Daten.
+data-move-button = Verschieben
+data-move-unsaved-title = Datenordner nicht gespeichert
+data-move-unsaved-explain = Die Daten liegen jetzt in { $path }, aber das konnte nicht in den Einstellungen gespeichert werden: { $error }. Solange es nicht gespeichert ist, sucht der nächste Start im alten, leeren Ordner. Behebe das Problem (z. B. Speicherplatz freigeben oder die Berechtigungen der Einstellungsdatei prüfen) und versuche es erneut.
+data-move-unsaved-retry = Erneut speichern
+
+## Help
+
+help-title = Hilfe & FAQ
+help-shortcuts = Tastenkürzel
+help-shortcut-enter = Konsolenbefehl senden (beim Tippen)
+help-shortcut-tab = Spielernamen in der Konsole vervollständigen
+help-shortcuts-change = Änderbar unter Einstellungen → Tastenkürzel.
+help-docker-what = Was ist Docker?
+help-docker-what-body =
+    Docker ist ein Werkzeug, das Anwendungen in isolierten 'Containern' ausführt.
+    Man kann es sich wie eine leichtgewichtige virtuelle Maschine vorstellen.
+    Jeder Minecraft-Server läuft in seinem eigenen Container mit seiner eigenen Java-Version.
+help-docker-why = Wozu brauche ich Docker?
+help-docker-why-body =
+    Docker macht die Serververwaltung deutlich einfacher:
+      • Java muss nicht selbst installiert werden
+      • Jeder Server ist isoliert (sie können sich nicht gegenseitig beschädigen)
+      • Mehrere Server mit verschiedenen Versionen lassen sich leicht betreiben
+      • Funktioniert gleich unter Windows, Mac und Linux
+      • Saubere Deinstallation - einfach den Container löschen
+help-docker-install = Wie installiere ich Docker?
+help-docker-install-desktop = Windows/Mac:
+help-docker-install-download = Docker Desktop herunterladen:
+help-docker-install-linux = Linux (Ubuntu/Debian):
+help-docker-install-relog = (Danach ab- und wieder anmelden)
+help-files = Wo sind meine Serverdateien?
+help-files-stored = Alle Serverdaten werden gespeichert in:
+help-files-move = Der Datenordner kann unter Einstellungen → Speicherort auf ein anderes Laufwerk verschoben werden.
+help-files-includes =
+    Dazu gehören:
+      • world/ - Weltspeicherstände
+      • mods/ - Installierte Mods
+      • config/ - Mod-Konfigurationen
+      • server.properties - Servereinstellungen
+help-properties = Wie bearbeite ich server.properties?
+help-properties-steps =
+    1. Den Server stoppen
+    2. Die Datei mit einem beliebigen Texteditor bearbeiten:
+help-mods = Wie füge ich Mods hinzu?
+help-mods-steps =
+    1. Den Server stoppen
+    2. .jar-Dateien kopieren nach:
+help-start-again = 3. Den Server wieder starten
+help-delete = Was passiert, wenn ich einen Server lösche?
+help-delete-body =
+    Nur der Docker-Container wird entfernt.
+    Weltdaten, Mods und Konfigurationen bleiben erhalten.
+    Der Server kann neu erstellt werden, um sie wieder zu verwenden.
+help-backups = Wie funktionieren Backups?
+help-backups-body =
+    Backups sind Zip-Dateien des gesamten data/-Ordners.
+    Sie enthalten: Welt, Mods, Konfigurationen, Skripte usw.
+    Gespeichert in: DrakonixAnvilData/backups/<servername>/
+help-rcon = RCON-Konsole
+help-rcon-body =
+    Mit RCON lassen sich Serverbefehle aus der Ferne ausführen.
+    Jeder Server hat ein automatisch erzeugtes Passwort.
+    Häufige Befehle: list, say, op, whitelist, stop
+help-port-forwarding = Grundlagen der Portweiterleitung
+help-port-forwarding-body =
+    Mit Portweiterleitung können sich Spieler außerhalb des lokalen Netzwerks mit dem Server verbinden.
+    Ohne sie können nur Geräte im eigenen WLAN beitreten.
+help-port-forwarding-steps =
+    So wird die Portweiterleitung eingerichtet:
+    1. Die Verwaltungsseite des Routers öffnen (meist 192.168.1.1 oder 192.168.0.1)
+    2. Anmelden (Standardzugangsdaten stehen oft auf dem Router)
+    3. 'Portweiterleitung' oder 'Virtuelle Server' in den Einstellungen suchen
+    4. TCP-Port 25565 (oder den gewählten Port) an die lokale IP des PCs weiterleiten
+help-port-forwarding-guides = Anleitungen für bestimmte Router:
+help-testing = Serververbindung testen
+help-testing-body =
+    Nach der Portweiterleitung mit einem externen Werkzeug prüfen, ob der Server erreichbar ist.
+    Zuerst sicherstellen, dass der Server läuft, dann die öffentliche IP:Port eingeben.
+help-testing-checkers = Kostenlose Serverstatus-Prüfer:
+help-sharing = Den Server mit Freunden teilen
+help-sharing-find-ip = Öffentliche IP herausfinden:
+help-sharing-search-ip = oder nach 'wie ist meine ip' suchen
+help-sharing-body =
+    Die Adresse so weitergeben: <öffentliche-ip>:<port>
+    Beim Standardport (25565) reicht Freunden die IP allein.
+help-sharing-ddns = Wenn sich die IP häufig ändert, lohnt sich ein kostenloser Dynamic-DNS-Dienst:
+help-more = Weitere Hilfe nötig?
+help-report-issues = Probleme melden:
@@ -0,0 +1,1541 @@
+# English UI strings. Message IDs are used from the code via i18n::tr; other
+# languages fall back to these for anything they don't translate.
+
+## Navigation
+
+nav-servers = Servers
+nav-logs = Logs
+nav-docker-logs = Docker Logs
+nav-backups = Backups
+nav-ports = Ports
+nav-diagnostics = Diagnostics
+nav-settings = Settings
+nav-help = Help
+
+## Status bar
+
+status-docker = Docker v{ $version }
+status-docker-disconnected = Docker disconnected
+status-storage = Storage: { $used } · { $free } free
+status-storage-low = Low disk space: { $free } free
+status-storage-low-hint = Servers won't start with less than 2 GB free. Delete old backups or unused servers, or move the data folder in Settings → Storage.
+status-storage-refresh = Click to measure again
+status-ddns-updated = Updated at { $time }
+status-ddns-failed = DNS update failed for { $hostname }
+status-ddns-refresh = Click to update now
+status-storage-server = { $name }: { $data } + { $backups } backups
+
+## Shared buttons
+
+button-cancel = Cancel
+button-delete = Delete
+button-refresh = Refresh
+button-back = Back
+button-add = Add
+button-remove = Remove
+button-reload = Reload
+button-save = Save
+button-yes = Yes
+button-no = No
+
+## Close confirmation
+
+close-title = Servers Still Running
+close-running =
+    { $count ->
+        [one] You have 1 server still running:
+       *[other] You have { $count } servers still running:
+    }
+close-left-running = Closing will leave them running in Docker.
+close-stop-later = You can stop them later with 'docker stop'
+close-anyway = Close Anyway
+close-minimize = Minimize Instead
+close-minimize-hint = Keep DrakonixAnvil supervising them (schedules, broadcasts, crash checks) in the background
+close-minimized = Minimized: still supervising { $count } running server(s). The tray icon starts and stops servers and brings the window back; close again (or Shift-close) to quit.
+
+## Tray icon
+
+tray-show = Show DrakonixAnvil
+tray-start = Start { $name }
+tray-stop = Stop { $name }
+tray-checking = Checking { $name }…
+tray-quit = Quit
+tray-tooltip =
+    { $count ->
+        [0] DrakonixAnvil: no servers running
+        [one] DrakonixAnvil: 1 server running
+       *[other] DrakonixAnvil: { $count } servers running
+    }
+
+## Profiles
+
+profile-label = Profile: { $name }
+profile-hint = Each profile has its own servers, settings and backups
+profile-new = + New profile…
+profile-new-title = New Profile
+profile-new-hint = Starts empty, with its own servers, settings and backups.
+profile-new-placeholder = e.g. LAN party laptop
+profile-create = Create
+profile-busy = Wait for running tasks to finish before switching profiles
+profile-switched = Switched to profile { $name }
+
+## Orphaned directory deletion
+
+orphan-delete-title = Delete Server Directory
+orphan-delete-warning = This will permanently delete:
+orphan-delete-if-any = (if any)
+orphan-delete-undo = This cannot be undone.
+legacy-containers-title = Containers from an Older Version
+legacy-containers-explain = These servers' containers were created before this install labeled its containers, so other installs on this host can't tell they're ours:
+legacy-containers-data-kept = Recreating removes only the containers; world data and settings stay, and each server gets a new container on its next start.
+legacy-containers-keep = Keep for now
+legacy-containers-recreate = Recreate
+
+## Dashboard
+
+dashboard-new-server = + New Server
+dashboard-import = Import Server
+dashboard-empty = No servers configured yet.
+dashboard-empty-hint = Click '+ New Server' to get started!
+dashboard-orphans = Orphaned Server Directories ({ $count })
+dashboard-orphans-hint = These folders exist in the data folder's servers/ but aren't tracked. They may be from deleted servers.
+dashboard-adopt = Adopt
+dashboard-open-details = Open server details
+dashboard-modpack-port = { $modpack } - Port { $port }
+dashboard-status = Status: { $status }
+dashboard-copy-address = 📋 Copy address
+dashboard-copy-lan = LAN: { $address }
+dashboard-copy-local = This machine: { $address }
+dashboard-copy-public = Internet: { $address }
+dashboard-copy-public-hint = For players outside your network. Needs the port forwarded on your router.
+dashboard-copy-bound = Bound to: { $address }
+dashboard-copy-bound-hint = The server only accepts players on this interface (set in its Bind Address).
+dashboard-public-ip-off = Turn on public IP detection in Settings for the internet address
+dashboard-live = { $online }/{ $max } players · { $version }
+dashboard-disk = Disk: { $data } · backups { $backups }
+dashboard-uptime = Up { $duration }
+dashboard-error = Error: { $error }
+dashboard-likely-cause = Likely cause: { $title }
+dashboard-fix = Fix: { $fix }
+dashboard-new-crash = ⚠ New crash report
+dashboard-open-crashes = Open the crash report viewer
+dashboard-uploading = Uploading backup { $sent } / { $total }
+dashboard-search-hint = Search name, modpack or version
+dashboard-any-status = Any status
+dashboard-filter-busy = Starting or stopping
+dashboard-filter-problem = Error or frozen
+dashboard-any-loader = Any loader
+dashboard-any-version = Any version
+dashboard-any-group = Any group
+dashboard-sort = Sort:
+dashboard-sort-added = Order added
+dashboard-sort-name = Name
+dashboard-sort-status = Status
+dashboard-sort-port = Port
+dashboard-sort-memory = Memory
+dashboard-showing = Showing { $shown } of { $total }
+dashboard-clear-filters = Clear filters
+dashboard-no-matches = No servers match the search and filters.
+dashboard-compact = Compact list
+dashboard-compact-hint = Show servers as a dense table instead of cards
+dashboard-col-server = Server
+dashboard-col-status = Status
+dashboard-col-players = Players
+dashboard-col-port = Port
+dashboard-col-memory = Memory
+dashboard-col-uptime = Uptime
+
+server-status-running = Running
+server-status-installing-pack = Installing Pack
+server-status-pulling = Pulling Image
+server-status-starting = Starting
+server-status-initializing = Initializing
+server-status-stopping = Stopping
+server-status-frozen = Frozen
+server-status-stopped = Stopped
+server-status-checking = Checking…
+server-status-error = Error
+
+action-start = Start
+action-stop = Stop
+action-console = Console
+action-players = Players
+action-logs = Logs
+action-backup = Backup
+action-backup-live-hint = Pause world saves, flush them to disk, and back up while players stay online
+action-panic = Panic
+action-panic-hint = Save the world, pause the server, and snapshot its data (for griefing or dupe exploits)
+action-unfreeze = Unfreeze
+action-backups = Backups
+action-edit = Edit
+action-mods = Mods
+action-export = Export
+action-export-bundle = Server bundle...
+action-export-bundle-hint = Settings and all data, for importing into DrakonixAnvil elsewhere
+action-export-world = World only...
+action-export-world-hint = The map as a singleplayer save, to open in Minecraft
+action-export-client = Client pack...
+action-export-client-hint = The server's mods and configs as a .mrpack or CurseForge zip, for players to import into their launcher
+action-open-folder = Open Folder
+
+progress-snapshot = Snapshot { $current }/{ $total }
+progress-restoring = Restoring { $current }/{ $total }
+progress-exporting = Exporting { $current }/{ $total }
+progress-startup = { $percent }% · { $stage }
+progress-startup-eta = { $percent }% · { $stage } · ~{ $eta } left
+progress-startup-usual = Usually ready in ~{ $eta }
+progress-startup-usual-hint = Median of the last { $count } start(s)
+pack-downloading = Downloading pack { $done } / { $total }
+pack-downloading-unknown = Downloading pack { $done }
+pack-installing-mods = Installing mods { $current } / { $total }
+pack-extracting = Extracting { $current } / { $total } files
+
+## Settings
+
+settings-title = Settings
+settings-save = Save Settings
+settings-saved = Settings saved!
+settings-language = Language
+settings-language-hint = Parts of the app that aren't translated yet stay in English.
+settings-background = Background Mode
+settings-minimize-on-close = Minimize instead of closing while servers are running
+settings-minimize-on-close-hint = Scheduled backups, broadcasts and crash checks only run while the app is open. Closing a second time, or holding Shift while closing, quits.
+settings-connect-address = Connect Address
+settings-detect-public-ip = Detect this network's public IP
+settings-detect-public-ip-hint = Asks api.ipify.org for the address players outside your network connect to. Copying it checks the port through api.mcsrvstat.us.
+settings-metrics = Metrics Endpoint
+settings-metrics-hint = Serves per-server status, players, TPS, container CPU and memory, and backup age in Prometheus format, for Prometheus or Grafana to scrape.
+settings-metrics-enable = Serve metrics
+settings-metrics-bind = Listen on:
+settings-metrics-bind-hint = 127.0.0.1 only answers this machine. Use 0.0.0.0 to let a scraper on another machine in.
+settings-metrics-apply = Apply
+settings-metrics-serving = Serving at
+settings-ddns = Dynamic DNS
+settings-ddns-hint = Keep a hostname pointed at this network's public IP, so players' saved address keeps working when your connection's IP changes.
+settings-ddns-enable = Update a DNS record
+settings-ddns-provider = Provider:
+settings-ddns-hostname = Hostname:
+settings-ddns-token = Token:
+settings-ddns-zone = Zone ID:
+settings-ddns-interval = Update every:
+settings-ddns-interval-hint = The public IP is checked every 5 minutes and the record updated as soon as it changes.
+settings-ddns-update-now = Update now
+settings-alerts = Alerts
+settings-alerts-hint = Get notified when a server stops on its own, lags, runs out of memory or goes too long without a backup.
+settings-alerts-desktop = Desktop notifications
+settings-alerts-webhook = Webhook URL:
+settings-alerts-test = Send test alert
+settings-alerts-server = Rules for:
+settings-alerts-no-servers = Create a server to set up its alerts.
+settings-alerts-copy = Copy to all servers
+settings-alerts-copy-hint = Use these rules for every server
+settings-webhooks = Webhooks
+settings-webhooks-hint = Post server, player and backup events to Discord, Slack or any compatible webhook.
+settings-webhooks-add = Add webhook
+settings-webhooks-test = Test
+settings-webhooks-remove = Remove this webhook
+settings-discord-bot = Discord Bot
+settings-discord-bot-hint = Let trusted Discord users start, stop and check servers from a channel. Create a bot at discord.com/developers, turn on its Message Content intent and invite it to the channel.
+settings-discord-bot-enable = Enable bot
+settings-discord-bot-token = Bot token:
+settings-discord-bot-channel = Channel ID:
+settings-discord-bot-users = Allowed user IDs:
+settings-discord-bot-users-hint = Numeric user IDs, one per line (Developer Mode → right-click a user → Copy User ID)
+settings-discord-bot-users-ignored = Ignored entries that aren't user IDs: { $entries }
+settings-discord-bot-apply = Apply
+settings-discord-bot-running = Listening for commands
+settings-curseforge = CurseForge API Key
+settings-modrinth = Modrinth Token (optional)
+settings-downloads = Downloads
+settings-template-index = Template Index
+settings-backups = Backups
+settings-remote-backups = Remote Backups
+settings-proxy = Network Proxy
+settings-storage = Storage
+settings-shortcuts = Keyboard Shortcuts
+settings-eula = Minecraft EULA
+settings-public-ip = Public IP: { $ip }
+settings-curseforge-hint = Required for downloading CurseForge modpacks.
+settings-curseforge-get-key = Get your key:
+settings-curseforge-key = API Key:
+settings-curseforge-key-hint = Paste your CurseForge API key here
+settings-curseforge-show-key = Show/hide key
+settings-curseforge-key-set = ✓ API key configured
+settings-curseforge-key-unset = ○ No API key set
+settings-curseforge-saved-restart = Settings saved! Restart DrakonixAnvil for the CurseForge API key to take effect.
+settings-modrinth-hint = Modrinth works without a key. A personal access token raises the rate limit for heavy browsing.
+settings-modrinth-create = Create one:
+settings-downloads-hint = Limit bandwidth used for server pack downloads. Interrupted downloads resume automatically.
+settings-downloads-limit = Speed limit:
+settings-downloads-unlimited = (unlimited)
+settings-download-cache = Download cache: { $size }
+settings-download-cache-hint = Finished pack downloads are kept so new servers from the same pack don't download it again
+settings-clear-cache = Clear cache
+settings-api-cache = API cache: { $size }
+settings-api-cache-hint = Recent CurseForge and Modrinth results are reused for a few minutes, so browsing doesn't use up rate limits
+settings-clear-api-cache = Clear API cache
+settings-template-index-hint = Featured templates are updated from this catalog at startup, so new packs and fixed pack URLs arrive without a new version of the app.
+settings-template-index-enable = Fetch featured templates at startup
+settings-template-index-url = Index URL:
+settings-reset = Reset
+settings-template-index-count =
+    { $count ->
+    [one] { $count } template in the index
+    *[other] { $count } templates in the index
+    }
+settings-check-templates = Check templates
+settings-check-templates-hint = Confirm pack URLs, Forge versions and CurseForge/Modrinth/FTB files still resolve
+settings-templates-resolve = All templates resolve
+settings-templates-broken = { $count } broken, flagged in Create Server
+settings-backups-hint = Compression for new backups. Servers with a backup schedule can override it.
+settings-backups-zstd-hint = Zstandard backups restore in the app, but need 7-Zip or another zstd-aware tool to open by hand.
+settings-verify-backups = Verify backups after creating them
+settings-verify-backups-hint = Read each archive back, check its CRCs, and compare file counts and sizes with the server's files. Takes about as long again as the backup.
+settings-remote-hint = Upload a copy of each backup offsite. Turn it on per server under Edit → Automatic Backups.
+settings-remote-disabled = Disabled
+settings-remote-s3 = S3-compatible
+settings-remote-endpoint = Endpoint:
+settings-remote-region = Region:
+settings-remote-bucket = Bucket:
+settings-remote-access-key = Access key:
+settings-remote-secret-key = Secret key:
+settings-remote-folder-url = Folder URL:
+settings-remote-username = Username:
+settings-remote-password = Password:
+settings-remote-no-sftp = This build has no SFTP support; rebuild with `--features sftp`.
+settings-remote-host = Host:
+settings-remote-private-key = Private key:
+settings-remote-private-key-hint = none, use password
+settings-remote-key-dialog = SSH private key
+settings-remote-passphrase = Key passphrase:
+settings-remote-dir = Remote folder:
+settings-remote-dir-hint = backups or /srv/minecraft-backups
+settings-remote-host-key = The host's key is remembered on first connect; uploads stop if it later changes.
+settings-remote-keep = Keep newest:
+settings-remote-keep-unit = uploads per server
+settings-remote-keep-all = (keep all)
+settings-remote-incomplete = Fill in the fields above to enable uploads.
+settings-proxy-hint = Used for CurseForge, Modrinth, and server pack downloads.
+settings-proxy-system = System (environment variables)
+settings-proxy-none = No proxy
+settings-proxy-manual = Manual
+settings-proxy-using = Using { $var }={ $value }
+settings-proxy-no-env = No proxy environment variables set; connecting directly.
+settings-proxy-url = Proxy URL:
+settings-proxy-url-hint = http://proxy:3128 or socks5://127.0.0.1:1080
+settings-proxy-bypass = Bypass for:
+settings-proxy-test = Test connection
+settings-proxy-test-hint = Reach the Modrinth API with these settings, without saving them
+settings-proxy-connected = ✓ Connected ({ $ms } ms)
+settings-proxy-docker = Docker image pulls are made by the Docker daemon and don't use this setting. Configure the daemon's proxy instead:
+settings-proxy-docker-desktop = Docker Desktop: Settings → Resources → Proxies. Linux:
+settings-proxy-docker-guide = daemon proxy guide
+settings-storage-hint = Servers, backups, download caches and logs live in the data folder. Move it to a bigger disk if worlds outgrow this one.
+settings-storage-folder = Data folder:
+settings-storage-free = { $size } free on this disk
+settings-storage-portable = Portable mode: data is kept next to the DrakonixAnvil executable, so it travels with it.
+settings-storage-portable-hint = For portable mode (data next to the executable, e.g. on a USB stick), start with --portable or put a { $marker } beside the executable.
+settings-storage-move = Move to another folder...
+settings-storage-move-stopped = Stop all servers first
+settings-shortcuts-hint = Click a shortcut, then press the new key combination. Esc cancels.
+settings-shortcuts-press = Press keys...
+settings-eula-accepted = ✓ Accepted { $at }
+settings-eula-not-accepted = ○ Not accepted yet (you'll be asked when starting a server)
+settings-instance-id = Instance ID:
+settings-instance-id-hint = Prefixes this install's container names and labels so other DrakonixAnvil installs on the same Docker host leave them alone
+settings-curseforge-recreate-note = Note: After setting the API key, you'll need to recreate any CurseForge servers for the key to take effect.
+
+## Alerts
+
+alerts-container-exited = Container exited without being stopped
+alerts-low-tps = TPS below
+alerts-low-tps-for = for
+alerts-low-tps-hint = Forge, NeoForge and Paper report TPS; other servers never trigger this
+alerts-high-memory = Memory above
+alerts-high-memory-of = of the container's limit
+alerts-no-backup = No backup in
+
+## Backup compression
+
+compression-store = Store (no compression)
+compression-deflate = Deflate
+compression-zstd = Zstandard
+compression-level = level
+compression-zstd-hint = 3 is fast with a good ratio; above 19 gets very slow
+
+## Diagnostics
+
+diagnostics-title = Diagnostics
+diagnostics-run = Run Checks
+diagnostics-hint = Checks the things the app depends on. Runs automatically when errors pile up.
+diagnostics-none = No checks run yet.
+diagnostics-last-run = Last run { $time }
+diagnostics-last-run-auto = Last run { $time } (started automatically after repeated errors)
+diag-docker = Docker
+diag-docker-hint = Start Docker Desktop (or `sudo systemctl start docker`) and make sure your user can access the Docker socket (e.g. is in the `docker` group), then restart the app.
+diag-docker-not-connected = Not connected at startup
+diag-docker-ok = Daemon reachable (v{ $version })
+diag-docker-no-answer = Daemon did not answer a ping
+diag-docker-timeout = Daemon ping timed out
+diag-docker-timeout-hint = Docker may be starting up or overloaded; wait a minute and re-run.
+diag-data-dir = Data directory
+diag-data-dir-ok = { $path } is writable
+diag-data-dir-fail = Cannot write to { $path }: { $error }
+diag-data-dir-hint = Run the app from a folder you own, or fix the folder's permissions.
+diag-disk = Disk space
+diag-disk-hint = Delete old backups or unused servers, or move the app to a larger disk.
+diag-disk-free = { $free } free
+diag-disk-unknown = Could not check: { $error }
+diag-disk-unknown-hint = Make sure the data directory is on a local, mounted disk.
+diag-clock = Clock
+diag-clock-no-client = Could not build HTTP client: { $error }
+diag-clock-no-client-hint = Check the proxy settings.
+diag-clock-unreachable = Could not reach a time source to compare against
+diag-clock-unreachable-hint = Check your internet connection; the clock itself may be fine.
+diag-clock-skew = { $seconds }s off from { $source }
+diag-clock-hint = Turn on automatic time sync (NTP) in your OS settings. A wrong clock breaks HTTPS downloads and Minecraft logins.
+diag-dns = DNS: { $service }
+diag-dns-empty = { $host } has no addresses
+diag-dns-empty-hint = Your DNS server returned an empty answer; try another resolver (e.g. 1.1.1.1).
+diag-dns-error-hint = Check your network connection and DNS settings. If a proxy resolves names for you, this can be ignored.
+diag-dns-timeout = { $host }: lookup timed out
+diag-dns-timeout-hint = Your DNS server is slow or unreachable; try another resolver (e.g. 1.1.1.1).
+diag-port-bind = Port binding
+diag-port-bind-ok = Opened a test listener on port { $port }
+diag-port-bind-fail = Cannot open a listening socket: { $error }
+diag-port-bind-hint = A firewall or security policy is blocking listening sockets; allow the app and Docker through it.
+diag-keyring = Keyring
+diag-keyring-available = system keyring available
+diag-keyring-missing = no system keyring detected
+diag-keyring-no-secrets = No API keys or tokens stored ({ $keyring })
+diag-keyring-exposed = API keys and tokens are in { $path }, readable by other users
+diag-keyring-exposed-hint = Run `chmod 600 { $path }` to keep the keys private.
+diag-keyring-ok = API keys and tokens are in { $path } ({ $keyring })
+
+## Known errors
+
+known-cf-key-title = CurseForge API key missing
+known-cf-key-explanation = The image needs a CurseForge API key to download this modpack's files.
+known-cf-key-fix = Add your key under Settings → CurseForge API key, then start the server again.
+known-cf-install-title = CurseForge auto-install failed
+known-cf-install-explanation = The image couldn't install this pack from CurseForge, which happens with older packs and ones without server files.
+known-cf-install-fix = Edit the server, tick "Install on host from the client manifest" under Modpack, then recreate the container.
+known-eula-title = Minecraft EULA not accepted
+known-eula-explanation = The server refuses to start until the Minecraft EULA is accepted.
+known-eula-fix = Start the server from the dashboard and accept the EULA when asked.
+known-java-title = Wrong Java version
+known-java-explanation = The pack or one of its mods was built for a newer Java than the container runs.
+known-java-fix = Edit the server and pick a newer Java version, then recreate the container.
+known-java-fix-version = Edit the server and pick Java { $java } (or newer if the pack allows), then recreate the container.
+known-duplicate-mods-title = Duplicate mods
+known-duplicate-mods-explanation = Two jars in the mods folder provide the same mod, usually two versions of it.
+known-duplicate-mods-fix = Open the Mods view or the server folder and delete the older copy from mods/.
+known-client-mod-title = Client-only mod on the server
+known-client-mod-explanation = A mod that only works in the game client (shaders, minimaps, menus) was loaded by the dedicated server.
+known-client-mod-fix = Find the mod named near the error in the container logs and remove it from mods/.
+known-out-of-memory-title = Out of memory
+known-out-of-memory-explanation = The JVM ran out of heap. Large modpacks often need 6-10 GB.
+known-out-of-memory-fix = Edit the server and raise its memory, then start it again.
+
+## Memory
+
+memory-host = Host RAM: { $mb } MB
+memory-use-suggested = Use suggested ({ $mb } MB)
+memory-bar-hint =
+    Other active servers: { $others } MB
+    This server: { $this } MB
+    System reserve: { $reserve } MB
+    Host total: { $total } MB
+
+## Metrics history
+
+history-title = History
+history-empty = No samples yet. They're taken once a minute while the server is running.
+history-players = Players
+history-tps = TPS
+history-tps-unreported = TPS: not reported by this server (Forge, NeoForge and Paper report it).
+history-memory = Memory
+history-now-peak = { $title }: { $now } now, peak { $peak }
+
+## Ports
+
+ports-title = Ports
+ports-hint = Every host port DrakonixAnvil will publish, across all servers (running or not).
+ports-problems =
+    { $count ->
+        [one] 1 port assignment needs attention
+       *[other] { $count } port assignments need attention
+    }
+ports-ok = ✓ No port conflicts detected
+ports-col-port = Port
+ports-col-server = Server
+ports-col-purpose = Purpose
+ports-col-state = State
+ports-col-issues = Issues
+ports-kind-game = Game
+ports-kind-rcon = RCON
+ports-kind-extra = Extra
+ports-in-use = In use
+ports-reserved = Reserved
+ports-overlaps = Overlaps with { $servers }
+ports-host-in-use = Port { $port } is already in use on this host
+ports-host-denied = Permission denied for port { $port }. Ports below 1024 require root privileges.
+ports-host-bind-failed = Cannot bind to port { $port }: { $error }
+ports-used-by-server = Port { $port } is already used by running server '{ $server }'
+ports-used-by-app = Port { $port } is already in use by another application. Try port { $suggested } instead.
+
+## Preflight
+
+preflight-title = Preflight: { $server }
+preflight-failed = Some checks failed. Fix them and re-run the checks before starting.
+preflight-warnings = Some checks need attention. You can still start the server.
+preflight-recheck = Re-run Checks
+preflight-start-anyway = Start Anyway
+preflight-disk-data = Disk space (server data)
+preflight-disk-docker = Disk space (Docker storage)
+preflight-disk-fail = Only { $free } free on { $path }
+preflight-disk-low = { $free } free on { $path }; modpacks and worlds can grow quickly
+preflight-disk-ok = { $free } free
+preflight-disk-unknown = Couldn't check free space: { $error }
+preflight-docker-in-vm = { $path } is inside Docker's VM; check Docker Desktop's disk usage if pulls fail
+preflight-docker-no-root = Docker didn't report its storage location
+preflight-memory = Memory
+preflight-memory-unknown = Couldn't detect host memory
+preflight-memory-over-host = { $requested } MB requested but the host only has { $total } MB
+preflight-memory-low = { $requested } MB requested, { $free } MB currently free ({ $others } MB used by other servers)
+preflight-memory-ok = { $requested } MB requested, { $free } MB free
+preflight-port = Port { $port }
+preflight-port-free = Available
+preflight-jvm = JVM flags
+preflight-jvm-ok = { $gc } on Java { $java }
+jvm-several-gcs = More than one garbage collector selected ({ $collectors }); the JVM refuses to start
+jvm-gc-needs-java = { $gc } needs Java { $needed }+, this server uses Java { $java }
+jvm-generational-without-zgc = -XX:+ZGenerational only applies with -XX:+UseZGC
+jvm-generational-needs-java = Generational ZGC needs Java 21+, this server uses Java { $java }
+jvm-xlog-needs-java = -Xlog options need Java 9+; Java 8 won't start with them
+preflight-cf-key = CurseForge API key
+preflight-cf-key-set = Configured
+preflight-cf-key-missing = CurseForge modpacks need an API key. Add one in Settings.
+preflight-local = Local modpack
+preflight-local-outside = '{ $path }' must be a path inside the server's data folder ({ $folder })
+preflight-local-missing = '{ $path }' not found in { $folder }; check the path for typos
+preflight-local-unrecognized = No mods/ folder, server jar, or pack manifest found
+preflight-local-ok = { $found } pack, { $count } entries
+preflight-local-vanilla = Pack looks like { $found } but the server is set to Vanilla; mods won't load
+preflight-local-wrong-loader = Pack looks like { $found } but the server is set to { $declared }; change the loader in Edit
+preflight-local-unknown-loader = { $count } entries (loader not recorded in the pack)
+
+## Whitelist
+
+whitelist-not-enforced = ⚠ The whitelist is turned off in this server's settings, so it isn't enforced.
+whitelist-running = The server is running: changes are made with `whitelist add/remove` over RCON.
+whitelist-stopped = The server is stopped: changes are written to whitelist.json directly.
+whitelist-add-label = Add players (paste one name per line, or separate with commas):
+whitelist-unreadable = Could not read whitelist.json
+whitelist-empty = Nobody is whitelisted yet.
+whitelist-count = { $count } whitelisted
+whitelist-invalid-names = Not valid Minecraft names: { $names }
+whitelist-offline-stopped = Offline-mode server: start it to add players (uses RCON)
+whitelist-removed = Removed { $player } from whitelist
+whitelist-added =
+    { $count ->
+        [one] Whitelisted 1 player
+       *[other] Whitelisted { $count } players
+    }
+whitelist-added-unknown =
+    { $count ->
+        [one] Whitelisted 1 player; unknown names: { $names }
+       *[other] Whitelisted { $count } players; unknown names: { $names }
+    }
+
+## Backups overview
+
+backups-title = Backups
+backups-total = Backups of every server use { $total } in total.
+backups-total-free = Backups of every server use { $total } in total, { $free } free on the backup drive.
+backups-stale =
+    { $count ->
+        [one] 1 server without a recent backup
+       *[other] { $count } servers without a recent backup
+    }
+backups-all-recent = ✓ Every server has a recent backup
+backups-col-server = Server
+backups-col-backups = Backups
+backups-col-size = Size
+backups-col-newest = Newest
+backups-col-schedule = Schedule
+backups-col-issues = Issues
+backups-deleted-hint = This server was deleted; its backups are still on disk
+backups-ago = { $duration } ago
+backups-deleted = Deleted server
+backups-schedule-on = On
+backups-schedule-off = Off
+
+## Backup contents
+
+contents-title = Contents of { $file }
+contents-hint = { $files } files, { $size } in total. Preview text files with 🔍, or tick what to put back on '{ $server }'; everything else stays as it is.
+contents-stop-first = Stop the server first. Replaced files are kept in replaced-files/.
+contents-restore-selected = Restore selected ({ $count } files)
+contents-clear-selection = Clear selection
+contents-empty = This backup is empty.
+contents-close-preview = Close preview
+contents-truncated = Showing the first { $size }.
+contents-preview = Preview
+contents-folder-size = { $files } files, { $size }
+
+## Crash reports
+
+crash-title = Crash Reports: { $server }
+crash-none = This server has no crash reports.
+crash-count =
+    { $count ->
+        [one] 1 report
+       *[other] { $count } reports
+    }
+crash-select = Select a report.
+crash-open-file = Open File
+crash-unknown = unknown
+crash-time = Time:
+crash-description = Description:
+crash-suspected-mods = Suspected mods:
+crash-no-suspects = none identified
+crash-exception = Exception
+crash-registry-names = Involved blocks, entities and items
+crash-full-report = Full report
+
+## Log filter
+
+log-search = Search:
+log-search-regex-hint = regular expression
+log-search-text-hint = text
+log-regex = Regex
+log-only-matching = Only show matching
+log-colors = Colors
+log-colors-hint = Red for errors, yellow for warnings, purple for stack traces
+log-follow = Follow
+log-follow-hint = Keep refreshing and scrolled to the newest lines
+log-invalid-regex = Invalid regex: { $error }
+log-matching = { $matching } of { $total } lines match
+log-lines = { $total } lines
+log-copy = Copy
+log-copy-hint = Copy the lines shown
+
+## MOTD editor
+
+motd-color-black = Black
+motd-color-dark-blue = Dark blue
+motd-color-dark-green = Dark green
+motd-color-dark-aqua = Dark aqua
+motd-color-dark-red = Dark red
+motd-color-dark-purple = Dark purple
+motd-color-gold = Gold
+motd-color-gray = Gray
+motd-color-dark-gray = Dark gray
+motd-color-blue = Blue
+motd-color-green = Green
+motd-color-aqua = Aqua
+motd-color-red = Red
+motd-color-light-purple = Light purple
+motd-color-yellow = Yellow
+motd-color-white = White
+motd-format-bold = Bold
+motd-format-italic = Italic
+motd-format-underline = Underline
+motd-format-strikethrough = Strikethrough
+motd-format-obfuscated = Obfuscated
+motd-format-reset = Reset
+motd-hint = Type &6 style codes or use the buttons. Bold and obfuscated text show plain here.
+
+## Players
+
+players-start-server = Start the server to see who's online.
+players-message = Message / reason:
+players-message-hint = whisper text, or kick/ban reason
+players-asking = Asking the server who's online...
+players-nobody = Nobody is online.
+players-online = { $count } online
+players-whisper = Whisper
+players-whisper-hint = Type a message first
+players-kick = Kick
+players-ban = Ban
+players-ban-hint = Undo with `pardon <name>` in the console
+players-deop = Deop
+players-op = Op
+players-operators = Operators
+players-playtime = Playtime
+players-playtime-empty = No playtime recorded yet. It's sampled every minute while the server runs.
+players-col-player = Player
+players-col-playtime = Playtime
+players-col-sessions = Sessions
+players-col-last-seen = Last seen
+players-is-online = online
+
+## Local import
+
+local-import-hint = Import a CurseForge client or server pack zip, a Modrinth .mrpack, or a server folder from this machine. It's copied into the new server's folder.
+local-import-choose-file = Choose file...
+local-import-filter = Modpack
+local-import-choose-folder = Choose folder...
+local-import-nothing = Nothing chosen yet.
+local-import-detected = Detected:
+local-import-name = Pack name:
+local-import-mc-version = Minecraft version:
+local-import-mc-version-hint = e.g. 1.20.1
+local-import-loader = Loader:
+local-import-choose-loader = Choose...
+local-import-cf-client-hint = The pack's mods are downloaded from CurseForge when the server first starts, leaving out client-only ones. This needs a CurseForge API key.
+local-import-mrpack-hint = The pack's files are downloaded from Modrinth when the server first starts, leaving out client-only ones.
+local-import-incomplete = Couldn't detect everything; fill in the Minecraft version and loader.
+
+## Files
+
+files-up = ⬆ Up
+files-upload-files = Upload files...
+files-upload-folder = Upload folder...
+files-open-in-os = Open in file manager
+files-running-warning = ⚠ The server is running: it may overwrite files you change, and deleting world or mod files can corrupt it.
+files-empty = This folder is empty.
+files-col-name = Name
+files-col-size = Size
+files-col-modified = Modified
+files-delete-confirm = Delete?
+files-download = Download...
+files-rename = Rename
+files-op-uploaded =
+    { $count ->
+        [one] Uploaded 1 item to { $folder }/
+       *[other] Uploaded { $count } items to { $folder }/
+    }
+files-op-saved = Saved { $name } to { $path }
+files-op-renamed = Renamed { $from } to { $to }
+files-op-deleted = Deleted { $path }
+files-op-failed = Failed: { $error }
+
+## Browsing
+
+browse-search = Search:
+browse-search-button = Search
+browse-mc-version = MC Version:
+browse-mc-version-hint = e.g. 1.20.1
+browse-loader = Loader:
+browse-sort = Sort:
+browse-any = Any
+browse-searching = Searching { $site }...
+browse-error = Error: { $error }
+browse-no-results = No results found.
+browse-enter-term = Enter a search term and click Search to find modpacks.
+browse-downloads = { $count } downloads
+browse-downloads-short = ({ $count } downloads)
+browse-installs = { $count } installs
+browse-installs-short = ({ $count } installs)
+browse-prev = < Prev
+browse-next = Next >
+browse-page = Page { $page } / { $pages }
+browse-loading-description = Loading description...
+browse-version-selection = Version Selection
+browse-loading-versions = Loading versions...
+browse-no-versions = No versions found.
+browse-minecraft-version = Minecraft Version:
+browse-select = Select...
+browse-pack-version = Pack Version:
+mr-search-hint = e.g. Cobblemon
+mr-categories = Categories:
+mr-no-versions-for-mc = No versions for this Minecraft version.
+cf-key-required = CurseForge API key required
+cf-key-required-hint = Set your CurseForge API key in Settings to search for modpacks.
+cf-key-get = Get a free key at
+cf-search-hint = e.g. All The Mods
+cf-mc-versions = MC Versions:
+cf-no-files-for-mc = No files for this Minecraft version.
+cf-has-server-pack = This version has a server pack (file { $file }).
+cf-no-server-pack = This version has no server pack.
+cf-install-from = Install from:
+cf-client-manifest = Client manifest
+cf-client-manifest-hint = The image downloads the pack's mods itself (AUTO_CURSEFORGE)
+cf-server-pack = Server pack
+cf-server-pack-hint = The app downloads the server pack and extracts it into the server's folder; the container installs the loader found in it
+cf-server-pack-missing = The pack author didn't publish a server pack for this version
+unified-no-cf-key = No CurseForge API key set; only Modrinth will be searched.
+unified-empty = Search both platforms at once. Packs listed on both are shown once.
+unified-install-cf = Install from CurseForge
+unified-install-mr = Install from Modrinth
+
+## FTB browsing
+
+ftb-search-hint = (leave empty for the most popular packs)
+ftb-empty = Search for a pack, or just click Search to see the most popular FTB packs.
+
+## Adding mods
+
+mod-browse-title = Add mods to { $server }
+mod-browse-hint = Only mods with a { $target } build that runs on servers are listed. Required dependencies the server lacks are installed too.
+mod-browse-no-cf-key = Add a CurseForge API key in Settings
+mod-browse-search-hint = e.g. JourneyMap
+mod-browse-empty = Search for a mod by name, or leave the box empty to see popular ones.
+mod-browse-installed = Installed
+mod-browse-no-build = No build of this mod fits the server's loader and Minecraft version.
+mod-browse-dependencies =
+    { $count ->
+        [one] Requires 1 other mod; if the server lacks it, it is installed too.
+       *[other] Requires { $count } other mods; any the server lacks are installed too.
+    }
+mod-browse-client-only = This file is tagged client-only and may not work on a server.
+mod-browse-already-installed = Already on the server. Delete the old jar in the Mods tab so both versions don't load.
+mod-browse-install = Install
+mod-browse-installing = Installing...
+
+## Console
+
+console-players = Players:
+console-tab-hint = (Tab to complete)
+console-input-hint = Enter command...
+console-send = Send
+console-quick-edit = Edit quick commands
+console-quick-title = Quick Commands
+console-quick-label = Label
+console-quick-command = Command
+console-quick-add = + Add
+console-quick-reset = Reset to defaults
+console-rcon-info = RCON Port: { $port } | Password: { $password }
+console-connected = ● Connected
+console-not-connected = ○ Not connected
+console-clear = Clear
+console-opening = Opening RCON console for '{ $name }'
+console-intro-send = Type commands and press Enter to send.
+console-intro-commands = Common commands: list, say <msg>, op <player>, whitelist add <player>
+console-intro-tab = Press Tab to complete online player names.
+console-server-not-found = Error: Server '{ $name }' not found
+console-session-ended = RCON session ended; reopen the console to reconnect.
+console-connected-line = Connected.
+console-no-response = (no response)
+console-rcon-error = RCON error: { $error }
+console-rcon-auth-hint = Check that RCON is enabled and password is correct.
+console-rcon-port-hint = Is the server running on RCON port { $port }?
+
+## Mods
+
+mods-title = Mods: { $server }
+mods-rescan = Rescan
+mods-reading = Reading mod metadata...
+mods-none = No mods installed yet.
+mods-none-hint = Mods appear here after the server has been started once.
+mods-summary =
+    { $mods } mods, { $issues ->
+        [one] 1 issue
+       *[other] { $issues } issues
+    }
+mods-issues = Issues
+mods-all-satisfied = ✓ All dependencies satisfied
+mods-install-all-missing = Install all { $count } missing
+mods-remove-all-unused = Remove all { $count } unused libraries
+mods-remove-all-unused-hint = Jars are moved to removed-mods/ next to the data folder
+mods-unreadable = No mod metadata found in: { $files }
+mods-graph = Dependency Graph
+mods-depends-on-nothing = Depends on: nothing
+mods-depends-on = Depends on:
+mods-any-version = any
+mods-dep-missing = missing
+mods-dep-installed = { $version } installed
+mods-dep-via = via { $file }
+mods-required-by = Required by: { $mods }
+mods-browse = Browse mods...
+mods-add-jars = Add jars...
+mods-jar-filter = Mod jar
+mods-dependencies = Dependencies...
+mods-dependencies-hint = Missing and outdated dependencies, unused libraries
+mods-drop-hint = Drop .jar files anywhere on this window to add them.
+mods-running-hint = Changes take effect the next time the server starts; stop it to delete mods.
+mods-drop-here = Drop to add to mods/
+mods-folder-empty = The mods folder is empty.
+mods-jar-count = { $count } jars, { $size }
+mods-col-name = Name
+mods-col-version = Version
+mods-col-file = File
+mods-col-size = Size
+mods-delete-hint = Moved to removed-mods/ next to the data folder
+mods-stop-first = Stop the server first
+mods-issue-missing = Missing: { $id }
+mods-issue-required-by = required by { $mods }
+mods-issue-outdated = Outdated: { $id }
+mods-issue-needs = { $installed } installed, needs { $needed }
+mods-issue-unused = Unused library: { $id }
+mods-issue-unused-file = { $file } (nothing depends on it)
+
+## Server details
+
+details-tab-overview = Overview
+details-tab-console = Console
+details-tab-logs = Logs
+details-tab-players = Players
+details-tab-whitelist = Whitelist
+details-tab-mods = Mods
+details-tab-files = Files
+details-tab-backups = Backups
+details-tab-schedules = Schedules
+details-tab-settings = Settings
+details-title = Server: { $server }
+details-status = Status:
+details-modpack = Modpack:
+details-minecraft = Minecraft:
+details-port = Port:
+details-rcon-port = RCON port:
+details-memory = Memory:
+details-memory-mb = { $mb } MB
+details-java = Java:
+details-startup = Startup:
+details-startup-usual = usually ~{ $minutes }m { $seconds }s (last { $count } starts)
+details-last-week = Last 7 days:
+details-last-week-summary =
+    { $percent }% up · { $starts ->
+        [one] 1 start
+       *[other] { $starts } starts
+    }, { $crashes ->
+        [one] 1 crash
+       *[other] { $crashes } crashes
+    } · longest run { $longest }
+details-last-week-hint = Up { $up } in total. Starts and crashes are counted while DrakonixAnvil is open.
+details-disk = Disk:
+details-disk-usage = { $data } (backups { $backups })
+details-online = Online:
+details-nobody = nobody
+details-software = Software:
+details-plugins = Plugins ({ $count }):
+details-container = Container:
+details-no-container = (not created)
+details-crash-reports = Crash Reports ({ $count })
+
+## Create server
+
+create-title = Create New Server
+create-name = Server Name:
+create-port = Port:
+create-memory = Memory (MB):
+create-jvm-preset = JVM Preset:
+create-jvm-template-default = Template default
+create-jvm-unsupported = Not available on this pack's Java version
+create-tab-featured = Featured
+create-tab-search-all = Search All
+create-tab-search = Search { $site }
+create-tab-local = From Local File/Folder
+create-selected = Selected:
+create-button = Create Server
+create-export-template = Export template...
+create-export-template-hint = Save this template as a file others can import
+create-remove-template = Remove template
+create-import-template = Import template...
+create-import-template-hint = Add a template file shared by someone else
+create-add-from-url = Add from URL
+create-check-templates = Check templates
+create-check-templates-hint = Confirm every template's pack can still be downloaded
+create-yours = (yours)
+create-template-warning = ⚠ Warning
+create-template-broken = ⚠ Broken
+
+## Edit server
+
+edit-modpack = Modpack
+edit-current = Current: { $name } v{ $version } (MC { $mc }, { $loader })
+edit-source = Source: { $source }
+edit-source-cf-latest = CurseForge: { $slug } (latest)
+edit-source-cf-file = CurseForge: { $slug } (file { $file })
+edit-source-cf-host-latest = CurseForge: { $slug } (latest, installed on host)
+edit-source-cf-host-file = CurseForge: { $slug } (file { $file }, installed on host)
+edit-source-cf-server-pack = CurseForge: { $slug } (server pack { $file }, installed on host)
+edit-source-forge = Forge { $forge } + pack ({ $url })
+edit-source-ftb = FTB (pack { $pack }, version { $version })
+edit-source-direct = Direct: { $url }
+edit-source-local = Local: { $path }
+edit-install-on-host = Install on host from the client manifest
+edit-install-on-host-hint = Fallback for packs AUTO_CURSEFORGE can't install: the app downloads each mod itself, leaves out client-only ones, and applies the pack's overrides before the container starts. Recreate the container after changing this.
+edit-apply-builtin = Apply builtin template:
+edit-select-template = — select —
+edit-apply-template = Apply Template
+edit-search-curseforge = Search CurseForge
+edit-apply-curseforge = Apply CurseForge Pack
+edit-search-modrinth = Search Modrinth
+edit-apply-modrinth = Apply Modrinth Pack
+edit-install-mrpack = Install from .mrpack
+edit-extra-ports = Extra Ports:
+edit-extra-ports-example = e.g. 8123, 24454
+edit-bind-address = Bind Address:
+edit-bind-address-all = all interfaces
+edit-extra-ports-hint = Extra ports are published 1:1 on the host (web maps, voice chat, etc.); 25565 and 25575 are taken by the game and RCON inside the container.
+edit-bind-address-hint = Set a bind address (e.g. a VPN or Tailscale IP) to only accept players on that interface. RCON and query stay on localhost.
+edit-jvm-custom = Custom
+edit-jvm-unsupported = Not available on this Java version
+edit-jvm-preset-hint = Fills the options below for the selected Java version and memory.
+edit-java-options = Java Options (one per line):
+edit-java-options-hint = Common options: -XX:+UseG1GC, -XX:MaxGCPauseMillis=200, etc.
+edit-gc = Garbage collector:
+edit-gc-logging = Record GC pause times
+edit-gc-logging-hint = Writes logs/gc.log; pause stats are saved each time the server stops
+edit-gc-logging-unsupported = GC logging needs Java 9+
+edit-gc-history = GC pause history:
+edit-gc-collector = Collector
+edit-gc-runs = Runs
+edit-gc-pauses = Pauses
+edit-gc-avg = Avg
+edit-gc-max = Max
+edit-java-version = Java Version:
+edit-extra-env = Extra Environment Variables (one per line, KEY=VALUE):
+edit-extra-env-example = e.g. CF_EXCLUDE_MODS=optifine, CF_FORCE_SYNCHRONIZE=true
+edit-world-url = World download URL:
+edit-world-url-hint = Zip of a starting map, extracted before the first start. Ignored once the server has a world.
+edit-server-properties = Server Properties
+edit-motd = MOTD:
+edit-max-players = Max Players:
+edit-invalid = Invalid
+edit-difficulty = Difficulty:
+edit-gamemode = Game Mode:
+edit-pvp = PVP:
+edit-online-mode = Online Mode:
+edit-whitelist = Whitelist:
+edit-view-distance = View Distance:
+edit-chunks = chunks
+edit-backup-excludes = Backup Exclusions
+edit-backup-excludes-hint = Paths left out of every backup, one pattern per line, relative to the server folder:
+edit-backup-excludes-syntax = * matches within a folder, ** across folders; a name without / matches anywhere (e.g. *.log).
+edit-backup-excludes-suggested = Add suggested
+edit-backup-excludes-restore = Restoring a backup keeps the server's current copies of excluded paths.
+edit-backup-group = Backup group:
+edit-backup-group-none = none
+edit-backup-group-hint = Servers in the same group (e.g. a proxy network with a shared economy) are backed up at the same moment and restored together
+edit-revert = Revert
+edit-revert-hint = Discard unsaved changes
+edit-save = Save Changes
+edit-invalid-port = Invalid port number
+edit-invalid-memory = Invalid memory value
+edit-invalid-extra-ports = Invalid extra ports (no repeats, and not 25565 or 25575)
+edit-invalid-bind-address = Invalid bind address
+edit-invalid-java-options = Invalid Java options
+edit-note-next-start = Note: Changes will take effect the next time the server starts.
+edit-note-recreate = The container will be recreated with the new settings.
+edit-mrpack-hint = For Modrinth packs without a server pack. The app downloads the pack's files, leaving out client-only ones, before the container starts.
+edit-mrpack-input = File or URL:
+edit-mrpack-input-hint = C:\packs\pack.mrpack or https://...
+edit-browse = Browse...
+edit-mrpack-filter = Modrinth pack
+edit-use-mrpack = Use .mrpack
+edit-broadcasts = Scheduled Broadcasts
+edit-broadcasts-hint = Sent via RCON while the server is running, e.g. `say Read the rules at /spawn`.
+edit-broadcast-every = every
+edit-add-broadcast = + Add Broadcast
+edit-auto-backups = Automatic Backups
+edit-remote-backup = Upload backups to remote storage
+edit-remote-backup-hint = Set up the S3, WebDAV or SFTP destination in Settings → Remote Backups
+edit-schedules-note = Schedules apply right away; the server doesn't need a restart.
+edit-save-schedules = Save Schedules
+edit-backup-auto = Back up automatically
+edit-backup-every = Every
+edit-backup-daily = Daily at
+edit-keep-last = Keep the last:
+edit-keep-last-unit = backups
+edit-keep-daily = Keep one a day for:
+edit-keep-daily-unit = days
+edit-keep-weekly = Keep one a week for:
+edit-keep-weekly-unit = weeks
+edit-prune-note = Only scheduled backups are pruned. Manual, frozen and group backups are kept.
+edit-own-compression = Own compression
+edit-own-compression-hint = Otherwise scheduled backups use the compression set in Settings
+
+## Status messages
+
+msg-docker-not-connected = Docker not connected
+msg-server-not-found = Server '{ $name }' not found
+msg-no-container = Server '{ $name }' has no container
+msg-backup-busy = A backup is already in progress
+msg-restore-busy = A restore is already in progress
+msg-restore-or-import-busy = A restore or import is already in progress
+msg-export-busy = An export is already in progress
+msg-settings-save-failed = Failed to save settings: { $error }
+msg-server-created = Server '{ $name }' created successfully!
+msg-server-updated = Server '{ $name }' settings updated!
+msg-schedules-updated = Server '{ $name }' schedules updated
+msg-server-deleted = Server '{ $name }' deleted
+msg-server-adopted = Adopted server '{ $name }'
+msg-server-crashed = WARNING: { $name } crashed, see { $report }
+msg-data-dir-failed = Failed to create data directory: { $error }
+msg-orphan-delete-failed = Failed to delete '{ $name }': { $error }
+msg-orphan-deleted = Deleted orphaned directory '{ $name }'
+msg-template-filter = Template
+msg-template-import-failed = Couldn't import template: { $error }
+msg-template-exported = Exported template '{ $name }' to { $path }
+msg-template-removed = Removed template '{ $name }'
+msg-template-save-failed = Couldn't save template: { $error }
+msg-template-added = Added template '{ $name }'
+msg-log-filter = Log file
+msg-logs-saved = Saved logs to { $path }
+msg-backup-read-failed = Failed to read backup: { $error }
+msg-backup-list-failed = Failed to list backups: { $error }
+msg-backup-deleted = Backup deleted
+msg-backup-delete-failed = Failed to delete backup: { $error }
+msg-restore-failed = Restore failed: { $error }
+msg-import-failed = Import failed: { $error }
+msg-whitelist-save-failed = Failed to save whitelist: { $error }
+msg-group-restore-running = Stop { $servers } before restoring the group
+msg-group-snapshot-missing = No members have this snapshot
+msg-new-name-empty = Enter a name
+msg-new-name-slashes = Names can't contain slashes
+msg-new-name-taken = A server named '{ $name }' already exists
+msg-new-name-folder = A folder for '{ $name }' already exists on disk
+msg-new-port-taken = Port { $port } or { $rcon } is used by '{ $server }'
+msg-restore-new-no-settings = No settings found for '{ $name }' to copy into the new server
+msg-restore-files-running = Stop the server before restoring files
+msg-restore-player-running = Stop the server before restoring player data
+msg-player-restored = Restored { $player } from backup
+msg-player-restore-failed = Player restore failed: { $error }
+msg-mods-only-jars = Only .jar files can be added to mods/
+msg-mods-added =
+    { $count ->
+        [one] Added 1 jar
+       *[other] Added { $count } jars
+    }
+msg-mods-add-failed = Failed to add mods: { $error }
+msg-mods-remove-running = Stop the server before removing mods
+msg-mods-removed =
+    { $count ->
+        [one] Moved 1 jar to removed-mods/
+       *[other] Moved { $count } jars to removed-mods/
+    }
+msg-mods-remove-failed = Failed to remove mods: { $error }
+msg-bundle-filter = DrakonixAnvil Server
+msg-curseforge-pack-filter = CurseForge pack
+msg-world-filter = Minecraft World
+progress-starting-restore = Starting restore...
+progress-counting-files = Counting files...
+progress-copying-files = Copying files...
+progress-freezing = Freezing...
+progress-pausing-saves = Pausing world saves...
+progress-verifying = Verifying...
+progress-checking-backups = Checking backups...
+progress-looking-up-mods = Looking up mods...
+msg-data-move-running = Stop all servers before moving the data folder
+msg-data-move-busy = Wait for backups, downloads and other running tasks to finish before moving the data folder
+msg-data-moving = The data folder is being moved; start the server once it's done
+msg-data-move-failed = Move failed, data is still in { $path }: { $error }
+msg-data-moved = Data moved to { $path }
+msg-data-moved-partly = Data moved to { $path }. Some old files couldn't be deleted: { $files }
+msg-drop-jars-no-server = Open a server's page before dropping mod jars onto the window
+msg-drop-unknown = Don't know what to do with { $file }: drop a server bundle, a pack zip or .mrpack, or mod jars
+msg-importing-server = Importing server...
+progress-moving = Moving...
+msg-server-started = Server '{ $name }' started!
+msg-server-stopped = Server '{ $name }' stopped
+msg-server-frozen = Server '{ $name }' frozen; taking a snapshot
+msg-backup-created = Backup created: { $file }
+msg-backup-failed = Backup failed: { $error }
+msg-backup-verified = { $file } verified
+msg-backup-verify-failed = { $file } failed verification: { $error }
+msg-backup-restored = Backup restored for '{ $name }'
+msg-restored-as-new = Server '{ $name }' restored from backup
+msg-local-imported = Server '{ $name }' imported
+msg-server-imported = Server '{ $name }' imported successfully!
+msg-files-restored =
+    { $count ->
+        [one] Restored 1 file for '{ $name }'
+       *[other] Restored { $count } files for '{ $name }'
+    }
+msg-upload-failed = Backup upload failed: { $error }
+msg-group-backed-up = Group '{ $group }' backed up ({ $count } servers)
+msg-group-backup-failed = Group backup failed: { $error }
+msg-group-restored = Group '{ $group }' restored ({ $count } servers)
+msg-group-restore-failed = Group restore failed: { $error }
+msg-command-sent = Sent: { $command }
+msg-command-failed = '{ $command }' failed: { $error }
+msg-username-lookup-failed = Username lookup failed: { $error }
+msg-diagnostics-problems =
+    { $count ->
+        [one] Diagnostics found 1 problem, see the Diagnostics tab
+       *[other] Diagnostics found { $count } problems, see the Diagnostics tab
+    }
+msg-mods-read-failed = Failed to read mods: { $error }
+msg-installed =
+    { $dependencies ->
+        [0] Installed { $name }
+        [one] Installed { $name } and 1 dependency
+       *[other] Installed { $name } and { $dependencies } dependencies
+    }
+msg-install-failed = Couldn't install { $name }: { $error }
+msg-exported = Exported: { $file }
+msg-export-failed = Export failed: { $error }
+msg-unreachable = { $address } doesn't answer from the internet; check port forwarding and firewalls
+msg-templates-fine = All { $count } templates look fine
+msg-templates-broken = { $broken } of { $count } templates are broken
+msg-template-add-failed = Couldn't add template: { $error }
+progress-verifying-file = Verifying { $file }
+msg-copied = Copied { $address }
+progress-copying-file = Copying { $current }/{ $total }: { $file }
+msg-download-cache-cleared = Cleared download cache ({ $size } freed)
+msg-api-cache-cleared = Cleared API cache ({ $size } freed)
+msg-invalid-proxy = Invalid proxy: { $error }
+msg-shortcut-taken = { $keys } is already used by "{ $action }"
+msg-container-remove-failed = Failed to remove container '{ $container }': { $error }
+
+## Activity log
+
+log-save-servers-failed = Failed to save servers: { $error }
+log-discord-start = Discord: { $user } asked to start '{ $name }'
+log-discord-stop = Discord: { $user } asked to stop '{ $name }'
+log-discord-whitelist = Discord: { $user } asked to whitelist { $player } on '{ $name }'
+log-importing-pack = Importing { $source } into new server '{ $name }'...
+log-start-time = '{ $name }' took { $seconds }s to start
+log-eula-accepted = Minecraft EULA accepted
+log-preflight = Preflight for '{ $name }': { $check } - { $detail }
+log-eula-recorded = Recorded EULA acceptance for server '{ $name }'
+log-pulling = Pulling image for server '{ $name }'...
+log-starting = Starting server '{ $name }'...
+log-checking-image = Checking Docker image { $image }...
+log-pull-failed = Failed to pull image: { $error }
+log-image-ready = Docker image { $image } ready
+log-installing-server-pack = Installing server pack on host...
+log-pack-unverified = '{ $name }': couldn't look up the pack's checksum, installing unverified: { $error }
+log-server-pack-failed = Failed to install server pack: { $error }
+log-server-pack-installed = Server pack installed successfully
+log-installing-cf-pack = Installing CurseForge pack on host...
+log-installing-cf-server-pack = Installing CurseForge server pack on host...
+log-installing-mrpack = Installing .mrpack on host...
+log-modpack-installed = Modpack installed (Minecraft { $minecraft }, { $loader } { $version })
+log-modpack-installed-unknown-loader = Modpack installed; its loader wasn't recognized, so the server's own loader settings apply
+log-modpack-failed = Failed to install modpack: { $error }
+log-creating-container = Creating container { $container }...
+log-created-container = Created container { $container }
+log-container-start-failed = Failed to start container: { $error }
+log-container-started = Container started, waiting for MC server to initialize...
+log-container-name-conflict = Container name conflict for '{ $name }' — old container still exists
+log-container-create-failed = Failed to create container: { $error }
+log-stopping = Stopping server '{ $name }'...
+log-unpause-failed = Failed to unpause before stopping: { $error }
+log-stopped = Server '{ $name }' stopped successfully!
+log-stop-failed = Failed to stop: { $error }
+log-save-logs-failed = Failed to save logs to { $path }: { $error }
+log-error-spike = { $count } errors in the last minute, running diagnostics...
+log-freezing = Freezing server '{ $name }'...
+log-world-saved = '{ $name }': world saved
+log-save-all-failed = save-all failed on '{ $name }' ({ $error }); freezing anyway
+log-pause-failed = Failed to pause '{ $name }': { $error }
+log-frozen-backup-busy = '{ $name }' frozen, but another backup is running; take a snapshot once it finishes
+log-unfrozen = Server '{ $name }' unfrozen
+log-unfreeze-failed = Failed to unfreeze '{ $name }': { $error }
+log-removing-container = Removing old container '{ $container }'...
+log-creating-backup = Creating backup for '{ $name }'...
+log-pruned-backups = Pruned { $count } old scheduled backup(s) of '{ $name }'
+log-prune-failed = Pruning backups of '{ $name }' failed: { $error }
+log-save-on-failed = Could not switch world saves back on for '{ $name }' ({ $error }); run `save-on` in its console
+log-creating-group-backup = Creating group backup '{ $group }' ({ $members })...
+log-save-off-failed = Could not pause saving on '{ $name }' ({ $error }); its snapshot may be slightly newer than the rest
+log-group-member-newer = '{ $member }' joined group '{ $group }' after { $file } was taken; it is left as is
+log-restoring-group = Restoring group '{ $group }' from { $file }...
+log-restoring-into-new = Restoring { $backup } from '{ $source }' into new server '{ $name }'...
+log-restoring-files = Restoring { $paths } from { $backup } for '{ $name }'...
+log-player-restored = Restored { $count } file(s) for { $player } ({ $uuid }) on '{ $name }' from { $backup }
+log-player-restore-failed = Restoring { $uuid } failed: { $error }
+log-installing-mod = Installing { $file } into '{ $name }'...
+log-mods-added = Added { $mods } to '{ $name }'
+log-add-mods-failed = Adding mods to '{ $name }' failed: { $error }
+log-mods-moved = Moved { $count } jar(s) from '{ $name }' to { $folder }
+log-remove-mods-failed = Removing mods failed: { $error }
+log-restoring-backup = Restoring backup for '{ $name }'...
+log-exporting = Exporting server '{ $name }'...
+log-exporting-world = Exporting the world of '{ $name }'...
+log-exporting-client-pack = Exporting a { $format } client pack of '{ $name }'...
+log-client-loader-unknown = Couldn't tell which { $loader } version '{ $name }' runs; players need to pick it in their launcher
+log-moving-data = Moving data from { $from } to { $to }...
+log-data-move-failed = Data folder move failed: { $error }
+log-data-folder-save-failed = Failed to save the new data folder: { $error }
+log-data-moved = Data moved to { $path }
+log-data-left-behind = Couldn't delete from { $path }: { $files }
+log-importing-server = Importing server from { $path }...
+log-whitelist-read-failed = Reading whitelist for '{ $name }': { $error }
+log-scheduled-backup = Starting scheduled backup of '{ $name }'...
+log-broadcast-failed = Scheduled broadcast on '{ $name }' failed: { $error }
+log-webhook-failed = Webhook for '{ $event }' failed: { $error }
+log-notification-failed = Desktop notification failed: { $error }
+log-alert-webhook-failed = Alert webhook failed: { $error }
+log-likely-cause = '{ $name }' likely failed because: { $causes }
+log-backup-saved = Backup saved to { $path }
+log-backup-failed = Backup failed: { $error }
+log-deleted-container-remove-failed = Couldn't remove the container of deleted server '{ $name }': { $error }
+log-container-recreating = Removed old container '{ $container }', recreating...
+log-file-op-failed = File operation on '{ $name }' failed: { $error }
+log-diagnostics-problem = Diagnostics: { $problem }
+log-backup-verified = Verified backup { $file } of '{ $name }': { $files } files, { $size }
+log-backup-verify-failed = Backup { $file } of '{ $name }' failed verification: { $error }
+log-remote-uploaded = Uploaded backup of '{ $name }' to remote storage
+log-remote-pruned = Pruned { $count } old remote backup(s) of '{ $name }'
+log-remote-upload-failed = Uploading backup of '{ $name }' failed: { $error }
+log-group-backup-saved = Group backup saved to { $path }
+log-group-backup-failed = Group backup '{ $group }' failed: { $error }
+log-group-restored = Group '{ $group }' restored from snapshot
+log-group-restore-failed = Group restore '{ $group }' failed: { $error }. Members restored before the failure keep the snapshot's data.
+log-restored = Backup restored successfully for '{ $name }'
+log-restore-failed = Restore failed: { $error }
+log-restored-into-new = Backup restored into new server '{ $name }'
+log-restore-into-new-failed = Restoring into new server '{ $name }' failed: { $error }
+log-local-pack-copied = Local pack copied into new server '{ $name }'
+log-import-into-new-failed = Importing into new server '{ $name }' failed: { $error }
+log-selective-restored = Selective restore finished for '{ $name }': { $count } file(s)
+log-selective-restore-failed = Selective restore failed: { $error }
+log-dependency-installed = Installed { $file } into '{ $name }' for missing dependency { $dependency }
+log-install-failed = Installing { $item } failed: { $error }
+log-installed = Installed { $files } into '{ $name }'
+log-exported = '{ $name }' exported to { $path }
+log-export-failed = Export failed: { $error }
+log-templates-updated = Template index updated ({ $count } templates)
+log-templates-not-updated = Template index not updated: { $error }
+log-unlabeled-container-removed = Removed unlabeled container for '{ $name }'; it's recreated on next start
+log-old-container-remove-failed = Failed to remove old container for '{ $name }': { $error }
+log-ddns-error = Dynamic DNS: { $error }
+log-ddns-updated = Dynamic DNS: { $host } now points at { $ip }
+log-ddns-failed = Dynamic DNS update failed: { $error }
+log-public-ip-failed = Couldn't detect the public IP: { $error }
+log-container-stopped = Container for '{ $name }' has stopped. Check container logs for errors.
+log-discord-bot-stopped = Discord bot stopped: { $error }
+log-metrics-error = Metrics endpoint: { $error }
+log-reachable = { $address } is reachable from the internet
+log-unreachable = { $address } doesn't answer from the internet
+log-reachability-failed = Couldn't check whether { $address } is reachable: { $error }
+log-template-broken = Template '{ $name }' is broken: { $detail }
+log-import-failed = Import failed: { $error }
+log-container-status-failed = Failed to check container status: { $error }
+log-cf-key-needed = Installing a CurseForge pack needs an API key; add one in Settings
+log-accepting-connections = Server '{ $name }' is now accepting connections! (latency: { $latency }ms)
+log-ping-version = Version: { $version } (protocol { $protocol })
+log-ping-motd = MOTD: { $motd }
+log-ping-players = Players: { $online }/{ $max } online
+log-ping-software = Software: { $software }
+log-ping-mods = Mods: { $count } loaded
+log-ping-plugins = Plugins: { $count } loaded
+log-ping-map = Map: { $map }
+log-not-ready = Server '{ $name }' not ready yet (attempt { $attempt }/{ $max })
+log-waiting-init = Waiting for '{ $name }' to initialize (attempt { $attempt }/{ $max })
+log-still-initializing = Server '{ $name }' still initializing after 10 minutes. Check container logs for progress.
+log-cancelling-restore = Cancelling restore...
+log-open-failed = Failed to open { $path }: { $error }
+log-start-despite-warnings = Starting '{ $name }' despite preflight warnings
+log-docker-connected = Docker connected (v{ $version })
+log-docker-connect-failed = Failed to connect to Docker: { $error }
+log-servers-loaded = Loaded { $count } server(s) from disk
+log-servers-load-failed = Failed to load servers: { $error }
+log-host-memory = Host RAM: { $total } MB total, { $free } MB available
+log-gc-benchmark = { $collector } run on '{ $name }': { $pauses } pauses, avg { $avg } ms, p99 { $p99 } ms, max { $max } ms
+log-client-pack = Client pack for '{ $name }': { $linked } mods linked to { $site }, { $bundled } bundled
+log-client-pack-left-out = Client pack for '{ $name }': { $linked } mods linked to { $site }, { $bundled } bundled, { $skipped } server-only left out ({ $mods })
+log-legacy-check-failed = Couldn't check for unlabeled containers: { $error }
+log-legacy-renamed = Renamed unlabeled container for '{ $name }' to { $container }
+log-legacy-rename-failed = Failed to rename container for '{ $name }': { $error }
+log-test-webhook-sent = Test webhook message sent
+log-test-webhook-failed = Test webhook failed: { $error }
+
+## Server logs
+
+logs-save = Save logs…
+logs-auto-refresh = (auto-refresh: { $seconds }s)
+logs-source = Source:
+docker-logs-auto-refresh = (auto-refresh: 5s)
+docker-logs-hint = Combined logs from all DrakonixAnvil-managed containers
+logs-no-container = No container found. Start the server first to see logs.
+logs-fetch-failed = Error fetching logs: { $error }
+docker-logs-none = No DrakonixAnvil-managed containers found.
+
+## Backups tab
+
+backups-incremental = Incremental backup
+backups-incremental-hint = Store only the files changed since the newest full backup
+backups-full = Full backup
+backups-group = Back up group '{ $group }'
+backups-group-hint = Snapshot { $servers } at the same moment
+backups-none = No backups found for this server.
+backups-none-hint = Use 'Full backup' above to create one.
+backups-size = Size: { $size }
+backups-builds-on = Incremental, builds on { $base }
+backups-verified = ✔ Verified
+backups-verified-compared = { $files } files, { $size }; CRCs and sizes checked at { $time }
+backups-verified-crc = { $files } files, { $size }; CRCs checked at { $time }
+backups-days-ago =
+    { $count ->
+        [one] 1 day ago
+       *[other] { $count } days ago
+    }
+backups-hours-ago =
+    { $count ->
+        [one] 1 hour ago
+       *[other] { $count } hours ago
+    }
+backups-just-now = Just now
+backups-restore = Restore
+backups-restore-new = Restore as new server...
+backups-restore-new-hint = Copy this server's settings into a new server and restore the backup there, leaving this one untouched
+backups-contents = Contents...
+backups-contents-hint = Browse and preview the files in this backup, or restore only some of them
+backups-verify = Verify
+backups-verify-hint = Read the archive back and check it against the files it was made from
+backups-restore-set = Restore set
+backups-restore-set-hint = Restore every group member from this snapshot
+backups-players = Players...
+backups-players-hint = Restore a single player's data from this backup
+backup-players-title = Players in { $file }
+backup-players-hint = Restores one player's inventory, stats and advancements. The rest of the world is left as is.
+backup-players-stop-first = Stop the server first. Replaced files are kept in replaced-playerdata/.
+backup-players-none = No player data found in this backup.
+restore-new-title = Restore as New Server
+restore-new-explain = Creates a copy of '{ $name }' with the world and files from { $file }.
+restore-new-untouched = The original server and its data are not touched.
+restore-new-name = New server name:
+restore-new-rcon = RCON will use port { $port }.
+restore-new-not-copied = Extra ports, backup schedule, group and remote uploads aren't copied.
+
+## Confirmation dialogs
+
+confirm-unknown = Unknown
+confirm-server = Server: { $name }
+confirm-modpack = Modpack: { $name }
+confirm-port = Port: { $port }
+confirm-delete-server = Delete Server?
+confirm-docker-container = Docker Container
+confirm-container-removed = Container exists and will be removed
+confirm-no-container = No container (config only)
+confirm-delete-server-data-kept = Server data in DrakonixAnvilData/servers/ will NOT be deleted.
+confirm-delete-server-recreate = You can recreate the server later using the same data.
+confirm-restore = Restore Backup?
+confirm-restore-question = Restore '{ $file }' to server '{ $name }'?
+confirm-restore-warning = WARNING: This will overwrite all current server data!
+confirm-restore-stop-first = Make sure the server is stopped before restoring.
+confirm-restore-group = Restore Group Snapshot?
+confirm-restore-group-question = Restore '{ $file }' to every server in group '{ $group }':
+confirm-restore-group-missing = • { $name } (not in this snapshot, left as is)
+confirm-restore-group-warning = WARNING: This will overwrite all current data on these servers!
+confirm-restore-group-stop-first = All members must be stopped before restoring.
+confirm-restore-all = Restore All
+confirm-delete-backup = Delete Backup?
+confirm-backup-file = Backup File
+confirm-unknown-size = unknown size
+confirm-cannot-undo = This action cannot be undone.
+confirm-container-exists = Container Already Exists
+confirm-old-container = Old Container
+confirm-container-recreate = Settings were changed, so the old container needs to be removed and recreated.
+confirm-container-safe = This is safe! All server data lives in DrakonixAnvilData/servers/, not inside the container. Removing the container is like deleting a shortcut — your worlds, configs, and mods are untouched.
+confirm-remove-restart = Remove & Restart
+
+## EULA
+
+eula-required = Running a Minecraft server requires agreeing to the Minecraft End User License Agreement.
+eula-read = Read it here:
+eula-agree-explain = By clicking 'I Agree' you accept the EULA on your own behalf. DrakonixAnvil will then pass EULA=TRUE to the servers you start.
+eula-asked-once = You will only be asked once. Each server records when acceptance was applied to it.
+eula-agree = I Agree
+
+## Import server
+
+import-title = Import Server
+import-preview = Server Preview
+import-name = Name: { $name }
+import-version = Version: { $version }
+import-minecraft = Minecraft: { $version }
+import-loader = Loader: { $loader }
+import-memory = Memory: { $mb } MB
+import-name-conflict = A server named '{ $name }' already exists. Importing will overwrite its data.
+import-read-failed = Failed to read export bundle: { $error }
+import-button = Import
+
+## Move data folder
+
+data-move-title = Move Data Folder
+data-move-from = From:
+data-move-to = To:
+data-move-moves = Moves:
+data-move-nothing = nothing yet
+data-move-size = Size:
+data-move-free = Free there:
+data-move-unknown = unknown
+data-move-settings-stay = Settings stay in { $path }. Server containers are removed and recreated on their next start so they mount the new folder; worlds, configs and mods move with the data.
+data-move-how = On the same disk this is instant. Across disks everything is copied first, and the old copy is only deleted once the copy has finished.
+data-move-no-space = The destination doesn't have enough free space for the data.
+data-move-button = Move
+data-move-unsaved-title = Data Folder Not Saved
+data-move-unsaved-explain = The data is now in { $path }, but saving that in the settings failed: { $error }. Until it's saved, the next launch looks in the old, empty folder. Fix the problem (e.g. free some disk space or the settings file's permissions) and retry.
+data-move-unsaved-retry = Retry Saving
+
+## Help
+
+help-title = Help & FAQ
+help-shortcuts = Keyboard shortcuts
+help-shortcut-enter = Send console command (while typing)
+help-shortcut-tab = Complete a player name in the console
+help-shortcuts-change = Change these in Settings → Keyboard Shortcuts.
+help-docker-what = What is Docker?
+help-docker-what-body =
+    Docker is a tool that runs applications in isolated 'containers'.
+    Think of it like a lightweight virtual machine.
+    Each Minecraft server runs in its own container with its own Java version.
+help-docker-why = Why do I need Docker?
+help-docker-why-body =
+    Docker makes server management much easier:
+      • No need to install Java yourself
+      • Each server is isolated (can't break each other)
+      • Easy to run multiple servers with different versions
+      • Works the same on Windows, Mac, and Linux
+      • Clean uninstall - just delete the container
+help-docker-install = How do I install Docker?
+help-docker-install-desktop = Windows/Mac:
+help-docker-install-download = Download Docker Desktop:
+help-docker-install-linux = Linux (Ubuntu/Debian):
+help-docker-install-relog = (Log out and back in after)
+help-files = Where are my server files?
+help-files-stored = All server data is stored in:
+help-files-move = The data folder can be moved to another disk in Settings → Storage.
+help-files-includes =
+    This includes:
+      • world/ - World saves
+      • mods/ - Installed mods
+      • config/ - Mod configurations
+      • server.properties - Server settings
+help-properties = How do I edit server.properties?
+help-properties-steps =
+    1. Stop the server
+    2. Edit the file with any text editor:
+help-mods = How do I add mods?
+help-mods-steps =
+    1. Stop the server
+    2. Copy .jar files to:
+help-start-again = 3. Start the server again
+help-delete = What happens when I delete a server?
+help-delete-body =
+    Only the Docker container is removed.
+    Your world data, mods, and configs are preserved.
+    You can recreate the server to use them again.
+help-backups = How do backups work?
+help-backups-body =
+    Backups are zip files of the entire data/ folder.
+    They include: world, mods, configs, scripts, etc.
+    Stored in: DrakonixAnvilData/backups/<server-name>/
+help-rcon = RCON Console
+help-rcon-body =
+    RCON lets you run server commands remotely.
+    Each server has an auto-generated password.
+    Common commands: list, say, op, whitelist, stop
+help-port-forwarding = Port Forwarding Basics
+help-port-forwarding-body =
+    Port forwarding lets players outside your local network connect to your server.
+    Without it, only devices on your home Wi-Fi can join.
+help-port-forwarding-steps =
+    Steps to set up port forwarding:
+    1. Find your router's admin page (usually 192.168.1.1 or 192.168.0.1)
+    2. Log in (check your router for default credentials)
+    3. Find 'Port Forwarding' or 'Virtual Servers' in the settings
+    4. Forward TCP port 25565 (or your chosen port) to your PC's local IP
+help-port-forwarding-guides = Router-specific guides:
+help-testing = Testing Your Server Connection
+help-testing-body =
+    After port forwarding, use an external tool to verify your server is reachable.
+    Make sure your server is running first, then enter your public IP:port.
+help-testing-checkers = Free server status checkers:
+help-sharing = Sharing Your Server with Friends
+help-sharing-find-ip = Find your public IP:
+help-sharing-search-ip = or Google 'what is my ip'
+help-sharing-body =
+    Share your address as: <public-ip>:<port>
+    If using the default port (25565), friends can connect with just the IP.
+help-sharing-ddns = If your IP changes frequently, consider a free dynamic DNS service:
+help-more = Need more help?
+help-report-issues = Report issues:
@@ -0,0 +1,54 @@
+//! Connect addresses for players: this machine's LAN IP, its public IP, and whether
+//! a port answers from outside the network.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::net::{IpAddr, UdpSocket};
+
+/// Answers with the caller's public IP as plain text
+const PUBLIC_IP_URL: &str = "https://api.ipify.org";
+/// Pings a Minecraft server from the internet and reports whether it answered
+const STATUS_CHECK_URL: &str = "https://api.mcsrvstat.us/3";
+
+/// The address other machines on the LAN reach this one at. Connecting a UDP
+/// socket sends nothing; it only makes the OS pick the outgoing interface.
+pub fn lan_ip() -> Option<IpAddr> {
+    let socket = UdpSocket::bind("0.0.0.0:0").ok()?;
+    socket.connect("8.8.8.8:80").ok()?;
+    let ip = socket.local_addr().ok()?.ip();
+    (!ip.is_loopback() && !ip.is_unspecified()).then_some(ip)
+}
+
+/// This network's public IP, as seen from the internet
+pub async fn public_ip() -> Result<String> {
+    let ip = crate::http::client()
+        .get(PUBLIC_IP_URL)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await
+        .context("Failed to read public IP")?;
+    let ip = ip.trim();
+    ip.parse::<IpAddr>()
+        .with_context(|| format!("Unexpected public IP answer: {}", ip))?;
+    Ok(ip.to_string())
+}
+
+#[derive(Deserialize)]
+struct StatusCheck {
+    online: bool,
+}
+
+/// Whether `address` (`host:port`) answers a status ping from outside this network
+pub async fn reachable_from_outside(address: &str) -> Result<bool> {
+    let check: StatusCheck = crate::http::client()
+        .get(format!("{}/{}", STATUS_CHECK_URL, address))
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await
+        .context("Failed to read status check answer")?;
+    Ok(check.online)
+}
@@ -0,0 +1,209 @@
+//! Alert rules per server and the bookkeeping that decides when they fire: the
+//! container exiting on its own, TPS staying low, memory close to the container's
+//! limit, or no recent backup. An alert fires once when its condition starts and
+//! can fire again only after the condition has cleared.
+
+use crate::metrics::Sample;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
+
+/// Which alerts a server raises, and their thresholds. All off until chosen.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct AlertRules {
+    /// The container stopped without being asked to
+    pub container_exited: bool,
+    pub low_tps: bool,
+    pub tps_below: f64,
+    /// How long TPS has to stay low before the alert fires
+    pub tps_minutes: u32,
+    pub high_memory: bool,
+    /// Percent of the container's memory limit
+    pub memory_percent: u8,
+    pub no_backup: bool,
+    pub backup_hours: u32,
+}
+
+impl Default for AlertRules {
+    fn default() -> Self {
+        Self {
+            container_exited: false,
+            low_tps: false,
+            tps_below: 15.0,
+            tps_minutes: 5,
+            high_memory: false,
+            memory_percent: 95,
+            no_backup: false,
+            backup_hours: 48,
+        }
+    }
+}
+
+impl AlertRules {
+    pub fn any(&self) -> bool {
+        self.container_exited || self.low_tps || self.high_memory || self.no_backup
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AlertKind {
+    ContainerExited,
+    LowTps,
+    HighMemory,
+    NoBackup,
+}
+
+#[derive(Debug, Clone)]
+pub struct Alert {
+    pub server_name: String,
+    pub kind: AlertKind,
+    pub message: String,
+}
+
+impl Alert {
+    /// Short title for a desktop notification
+    pub fn title(&self) -> String {
+        let what = match self.kind {
+            AlertKind::ContainerExited => "stopped unexpectedly",
+            AlertKind::LowTps => "is lagging",
+            AlertKind::HighMemory => "is low on memory",
+            AlertKind::NoBackup => "needs a backup",
+        };
+        format!("{} {}", self.server_name, what)
+    }
+}
+
+#[derive(Debug, Default)]
+struct ServerState {
+    /// When TPS first dropped below the threshold, while it stays there
+    low_tps_since: Option<Instant>,
+    /// Alerts that have fired and whose condition hasn't cleared yet
+    active: HashSet<AlertKind>,
+}
+
+/// Tracks each server's conditions between checks
+#[derive(Debug, Default)]
+pub struct AlertMonitor {
+    servers: HashMap<String, ServerState>,
+}
+
+impl AlertMonitor {
+    /// `Some(alert)` if the condition has just started; clears the alert when it's over
+    fn transition(
+        &mut self,
+        server_name: &str,
+        kind: AlertKind,
+        firing: bool,
+        message: impl FnOnce() -> String,
+    ) -> Option<Alert> {
+        let state = self.servers.entry(server_name.to_string()).or_default();
+        if !firing {
+            state.active.remove(&kind);
+            return None;
+        }
+        state.active.insert(kind).then(|| Alert {
+            server_name: server_name.to_string(),
+            kind,
+            message: message(),
+        })
+    }
+
+    /// Check a running server's latest TPS and memory against its rules
+    pub fn check_sample(
+        &mut self,
+        server_name: &str,
+        rules: &AlertRules,
+        sample: &Sample,
+    ) -> Vec<Alert> {
+        let mut alerts = Vec::new();
+
+        let low = rules.low_tps && sample.tps.is_some_and(|tps| tps < rules.tps_below);
+        let state = self.servers.entry(server_name.to_string()).or_default();
+        let since = if low {
+            *state.low_tps_since.get_or_insert_with(Instant::now)
+        } else {
+            state.low_tps_since = None;
+            Instant::now()
+        };
+        let sustained = Duration::from_secs(u64::from(rules.tps_minutes) * 60);
+        let tps = sample.tps.unwrap_or_default();
+        alerts.extend(self.transition(
+            server_name,
+            AlertKind::LowTps,
+            low && since.elapsed() >= sustained,
+            || {
+                format!(
+                    "'{}' has been running at {:.1} TPS for {} minutes (alert below {:.1})",
+                    server_name, tps, rules.tps_minutes, rules.tps_below
+                )
+            },
+        ));
+
+        let percent = sample
+            .stats
+            .filter(|s| s.memory_limit_bytes > 0)
+            .map(|s| s.memory_bytes as f64 / s.memory_limit_bytes as f64 * 100.0);
+        let high =
+            rules.high_memory && percent.is_some_and(|p| p >= f64::from(rules.memory_percent));
+        alerts.extend(
+            self.transition(server_name, AlertKind::HighMemory, high, || {
+                format!(
+                    "'{}' is using {:.0}% of its memory limit (alert at {}%)",
+                    server_name,
+                    percent.unwrap_or_default(),
+                    rules.memory_percent
+                )
+            }),
+        );
+        alerts
+    }
+
+    /// Check the age of a server's newest backup against its rule
+    pub fn check_backup(
+        &mut self,
+        server_name: &str,
+        rules: &AlertRules,
+        newest_age: Option<Duration>,
+    ) -> Option<Alert> {
+        let limit = Duration::from_secs(u64::from(rules.backup_hours) * 3600);
+        let overdue = rules.no_backup && newest_age.is_none_or(|age| age > limit);
+        self.transition(
+            server_name,
+            AlertKind::NoBackup,
+            overdue,
+            || match newest_age {
+                Some(age) => format!(
+                    "'{}' hasn't been backed up for {} hours",
+                    server_name,
+                    age.as_secs() / 3600
+                ),
+                None => format!("'{}' has never been backed up", server_name),
+            },
+        )
+    }
+
+    /// A server's container stopped on its own. Fires every time.
+    pub fn container_exited(
+        &mut self,
+        server_name: &str,
+        rules: &AlertRules,
+        reason: &str,
+    ) -> Option<Alert> {
+        self.server_stopped(server_name);
+        rules.container_exited.then(|| Alert {
+            server_name: server_name.to_string(),
+            kind: AlertKind::ContainerExited,
+            message: format!("'{}' stopped unexpectedly: {}", server_name, reason),
+        })
+    }
+
+    /// Forget TPS and memory conditions of a server that's no longer running
+    pub fn server_stopped(&mut self, server_name: &str) {
+        if let Some(state) = self.servers.get_mut(server_name) {
+            state.low_tps_since = None;
+            state.active.remove(&AlertKind::LowTps);
+            state.active.remove(&AlertKind::HighMemory);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+//! Short-lived on-disk cache of CurseForge and Modrinth API responses, so reopening
+//! a browse tab or paging back shows the same results without spending rate limit.
+
+use crate::config::get_api_cache_path;
+use anyhow::{Context, Result};
+use sha1::{Digest, Sha1};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// How long a response is reused before the API is asked again
+const TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Cache key for a request: its method and full URL with the query. Headers are
+/// left out since API keys and tokens don't change the answer. None when the
+/// request can't be built (it will fail on send anyway).
+pub fn key(req: &reqwest::RequestBuilder) -> Option<String> {
+    let req = req.try_clone()?.build().ok()?;
+    Some(format!("{} {}", req.method(), req.url()))
+}
+
+fn path_for(key: &str) -> PathBuf {
+    let hash: String = Sha1::digest(key.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    get_api_cache_path().join(format!("{}.json", hash))
+}
+
+fn is_fresh(path: &std::path::Path) -> bool {
+    std::fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|age| age < TTL)
+}
+
+/// Response body cached for `key`, if it's younger than the TTL
+pub fn get(key: &str) -> Option<String> {
+    let path = path_for(key);
+    if !is_fresh(&path) {
+        return None;
+    }
+    std::fs::read_to_string(path).ok()
+}
+
+/// Remember a successful response body. Failing to write only costs a refetch.
+pub fn put(key: &str, body: &str) {
+    let result = std::fs::create_dir_all(get_api_cache_path())
+        .and_then(|_| std::fs::write(path_for(key), body));
+    if let Err(e) = result {
+        tracing::debug!("Couldn't cache API response: {}", e);
+    }
+}
+
+/// Delete responses past the TTL, which would never be read again
+pub fn prune() {
+    let Ok(entries) = std::fs::read_dir(get_api_cache_path()) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_file() && !is_fresh(&path) {
+            let _ = std::fs::remove_file(&path);
+        }
+    }
+}
+
+/// Bytes of cached responses
+pub fn size() -> u64 {
+    crate::pack_installer::cache_size(&get_api_cache_path())
+}
+
+/// Delete every cached response, so the next request goes to the API; returns
+/// the bytes freed
+pub fn clear() -> Result<u64> {
+    let size = size();
+    let dir = get_api_cache_path();
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir).context("Failed to clear the API cache")?;
+    }
+    Ok(size)
+}
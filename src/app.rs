@@ -1,26 +1,115 @@
 use eframe::egui;
 use rust_mc_status::{models::ServerData, McClient, ServerEdition};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{mpsc, Arc};
 use std::time::Duration;
 use tokio::runtime::Runtime;
 
-use crate::backup::{self, BackupInfo};
+use crate::alerts::{Alert, AlertKind, AlertMonitor, AlertRules};
+use crate::backup::{self, BackupInfo, BackupPlayer};
+use crate::backup_overview::BackupOverview;
+use crate::client_pack::{self, ClientPackFormat};
 use crate::config::{
-    find_orphaned_server_dirs, get_backup_path, get_container_name, get_server_data_path,
-    get_server_path, load_servers, load_settings, save_servers, save_settings, AppSettings,
+    find_orphaned_server_dirs, get_backup_path, get_container_name, get_download_cache_path,
+    get_server_data_path, get_server_path, load_servers, load_settings, save_servers,
+    save_settings, AppSettings, BackupCompression, ProxyMode, RemoteBackupKind,
+    RemoteBackupSettings,
 };
+use crate::crash::{self, CrashReport};
 use crate::curseforge::{self, CfFile, CfMod};
-use crate::docker::DockerManager;
+use crate::ddns::{self, DdnsProvider};
+use crate::diagnostics::{self, DiagnosticsReport};
+use crate::discord_bot::{self, BotCommand, BotRequest, ReplyTarget};
+use crate::disk_usage::{DiskUsage, SpaceLevel};
+use crate::docker::{DockerManager, LogChunk};
+use crate::heads::PlayerHeads;
+use crate::i18n::{self, tr, tr_args, Language};
+use crate::jvm::{self, GarbageCollector, GcBenchmark};
+use crate::known_errors::{self, Diagnosis};
+use crate::logfile::{self, LogSource};
+use crate::memory::{self, HostMemory, MemoryBudget};
+use crate::metrics;
+use crate::metrics_history::{self, HistoryPoint, HistoryRange};
+use crate::mod_browse::{self, ModHit, ModQuery, ModRelease};
 use crate::modrinth::{self, MrProject, MrVersion};
-use crate::server::{ModpackInfo, ServerConfig, ServerInstance, ServerStatus};
+use crate::mods::{self, DependencyIssue, ModGraph};
+use crate::notify;
+use crate::playtime::PlaytimeStore;
+use crate::ports::PortUsage;
+use crate::preflight::{self, CheckStatus, PreflightReport};
+use crate::query;
+use crate::rcon::{self, RconEvent, RconSession};
+use crate::remote_backup;
+use crate::search::{self, UnifiedResult};
+use crate::server::{
+    BackupSchedule, LiveStatus, ModpackInfo, QuickCommand, ServerConfig, ServerInstance,
+    ServerStatus,
+};
+use crate::shortcuts::{KeyBinding, Shortcut};
+use crate::startup::{self, StartupProgress};
+use crate::storage::{self, MovePlan};
 use crate::templates::ModpackTemplate;
+use crate::tray::{Tray, TrayAction, TrayServer};
 use crate::ui::{
-    CfBrowseWidget, CfCallbacks, CfSearchState, CreateViewCallbacks, DashboardCallbacks,
-    DashboardView, MrBrowseWidget, MrCallbacks, MrSearchState, ServerCreateView, ServerEditResult,
-    ServerEditView, View,
+    alert_rules_editor, compression_picker, formatted_line, BackupContentsAction,
+    BackupContentsView, BackupOverviewAction, BackupOverviewView, CfBrowseWidget, CfCallbacks,
+    CfSearchState, CommandLine, CrashAction, CrashReportView, CreateViewCallbacks,
+    DashboardCallbacks, DashboardFilter, DashboardView, DetailsTab, DiagnosticsView, FilesAction,
+    FilesView, LogFilter, MetricsHistoryView, ModBrowseAction, ModBrowseView, ModsAction, ModsView,
+    MrBrowseWidget, MrCallbacks, MrSearchState, PlayerAction, PlayersView, PortsView,
+    PreflightAction, PreflightView, QuickCommandAction, QuickCommandBar, ScheduleResult,
+    ServerCreateView, ServerDetailsView, ServerEditResult, ServerEditView, TemplateAction,
+    UnifiedCallbacks, UnifiedSearchState, View, WhitelistAction, WhitelistView,
 };
+use crate::uptime::UptimeLog;
+use crate::webhooks::{EventMessage, Webhook, WebhookEvent};
+use crate::whitelist::{self, WhitelistEntry};
 
 const MAX_LOG_LINES: usize = 500;
+/// Lines kept per container in the combined Docker logs view
+const DOCKER_LOG_MAX_LINES: usize = 2000;
+
+/// How often the players tab asks the server who's online
+const PLAYER_POLL_INTERVAL: Duration = Duration::from_secs(10);
+/// How often running servers are sampled for playtime stats
+const PLAYTIME_SAMPLE_INTERVAL: Duration = Duration::from_secs(60);
+/// How often running servers are pinged for the dashboard's player count and MOTD
+const STATUS_PING_INTERVAL: Duration = Duration::from_secs(15);
+/// How often the metrics endpoint's figures are refreshed while it's on
+const METRICS_SAMPLE_INTERVAL: Duration = Duration::from_secs(15);
+/// How often running servers' players, TPS and memory are added to their history
+const HISTORY_SAMPLE_INTERVAL: Duration = Duration::from_secs(60);
+/// How often servers' crash-reports/ directories are checked for new reports
+const CRASH_CHECK_INTERVAL: Duration = Duration::from_secs(15);
+/// How often the public IP is checked for changes while dynamic DNS is on
+const DDNS_CHECK_INTERVAL: Duration = Duration::from_secs(300);
+/// How often servers with a no-backup alert have their newest backup checked
+const BACKUP_ALERT_CHECK_INTERVAL: Duration = Duration::from_secs(600);
+/// How often server and backup folder sizes are measured again
+const DISK_USAGE_INTERVAL: Duration = Duration::from_secs(600);
+/// How often backup schedules are checked for a backup that's due
+const BACKUP_SCHEDULE_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+/// Wait before retrying a scheduled backup that failed, doubling with each failure
+/// in a row up to SCHEDULED_BACKUP_RETRY_MAX
+const SCHEDULED_BACKUP_RETRY_MIN: Duration = Duration::from_secs(300);
+const SCHEDULED_BACKUP_RETRY_MAX: Duration = Duration::from_secs(6 * 3600);
+
+/// This many errors within ERROR_SPIKE_WINDOW triggers an automatic diagnostics run
+const ERROR_SPIKE_COUNT: usize = 5;
+const ERROR_SPIKE_WINDOW: Duration = Duration::from_secs(60);
+/// Don't re-run diagnostics automatically more often than this
+const AUTO_DIAGNOSTICS_COOLDOWN: Duration = Duration::from_secs(600);
+
+/// What kind of archive a backup writes
+#[derive(Clone, Copy)]
+enum BackupKind {
+    Full,
+    /// Only files changed since the newest full backup
+    Incremental,
+    /// Full backup from a schedule, pruned afterwards by its retention rules
+    Scheduled(BackupSchedule),
+}
 
 /// Messages sent from background tasks to the UI
 enum TaskMessage {
@@ -30,6 +119,16 @@ enum TaskMessage {
         status: ServerStatus,
         container_id: Option<String>,
     },
+    /// Answer to a dashboard status ping (None when the server didn't answer)
+    LiveStatus {
+        server_name: String,
+        status: Option<LiveStatus>,
+    },
+    /// Start time of a container that was already running when the app opened
+    ContainerStartedAt {
+        name: String,
+        started_at: chrono::DateTime<chrono::Utc>,
+    },
     BackupProgress {
         server_name: String,
         current: usize,
@@ -50,7 +149,111 @@ enum TaskMessage {
         server_name: String,
         result: Result<(), String>,
     },
-    DockerLogs(String),
+    /// A backup was extracted into a newly added server
+    RestoreAsNewComplete {
+        server_name: String,
+        result: Result<(), String>,
+    },
+    /// A local pack was copied into a newly added server
+    LocalImportComplete {
+        server_name: String,
+        result: Result<(), String>,
+    },
+    /// Selected paths were restored; `Ok` holds the number of files put back
+    SelectiveRestoreComplete {
+        server_name: String,
+        result: Result<usize, String>,
+    },
+    DiagnosticsComplete(DiagnosticsReport),
+    OnlinePlayers {
+        server_name: String,
+        result: Result<Vec<String>, String>,
+    },
+    PlayerCommandDone {
+        server_name: String,
+        command: String,
+        result: Result<String, String>,
+    },
+    PlaytimeSample {
+        server_name: String,
+        players: Vec<String>,
+    },
+    /// Known problems found in the log of a server that went into Error
+    LogDiagnosis {
+        server_name: String,
+        diagnoses: Vec<Diagnosis>,
+    },
+    /// Furthest startup milestone seen in an initializing server's log
+    StartupProgress {
+        server_name: String,
+        milestone: startup::Milestone,
+    },
+    /// Mojang lookup for names being added to a stopped server's whitelist
+    WhitelistLookup {
+        server_name: String,
+        result: Result<(Vec<WhitelistEntry>, Vec<String>), String>,
+    },
+    /// Listing of a folder on a server's Files tab
+    FilesListed {
+        server_name: String,
+        path: std::path::PathBuf,
+        result: Result<Vec<crate::files::FileEntry>, String>,
+    },
+    /// Upload, download, rename or delete on the Files tab finished
+    FileOpDone {
+        server_name: String,
+        result: Result<String, String>,
+    },
+    /// A deleted server's container was stopped and removed, or why it couldn't be
+    DeletedContainerRemoved {
+        name: String,
+        result: Result<(), String>,
+    },
+    /// An old container in the way of a recreate was removed, or why it couldn't be
+    OldContainerRemoved {
+        name: String,
+        container_name: String,
+        result: Result<(), String>,
+    },
+    /// Whitelist changed over RCON; the summary is shown as a status message
+    WhitelistUpdated {
+        server_name: String,
+        summary: String,
+    },
+    /// A backup was checked by hand from the backups list
+    BackupVerified {
+        server_name: String,
+        path: std::path::PathBuf,
+        result: Result<backup::BackupVerification, String>,
+    },
+    /// Bytes of a backup sent to remote storage so far
+    UploadProgress {
+        server_name: String,
+        sent: u64,
+        total: u64,
+    },
+    /// Upload finished; `Ok` holds how many old uploads were pruned
+    UploadComplete {
+        server_name: String,
+        result: Result<usize, String>,
+    },
+    GroupBackupComplete {
+        group: String,
+        result: Result<Vec<std::path::PathBuf>, String>,
+    },
+    GroupRestoreComplete {
+        group: String,
+        result: Result<usize, String>,
+    },
+    PackInstallProgress {
+        server_name: String,
+        progress: crate::pack_installer::PackProgress,
+    },
+    DockerLogs {
+        /// Fetches from before the view was last reopened are ignored
+        generation: u64,
+        chunks: Result<Vec<LogChunk>, String>,
+    },
     ContainerLogs(String),
     CfSearchResults {
         results: Vec<CfMod>,
@@ -78,6 +281,9 @@ enum TaskMessage {
         total_count: u64,
     },
     MrSearchError(String),
+    FtbSearchComplete {
+        result: Result<Vec<crate::ftb::FtbPack>, String>,
+    },
     MrVersionResults {
         project_id: String,
         versions: Vec<MrVersion>,
@@ -94,6 +300,33 @@ enum TaskMessage {
         project_id: String,
         error: String,
     },
+    UnifiedSearchResults {
+        results: Vec<UnifiedResult>,
+        errors: Vec<String>,
+    },
+    ModScanComplete {
+        server_name: String,
+        result: Result<ModGraph, String>,
+    },
+    ModInstallComplete {
+        server_name: String,
+        mod_id: String,
+        result: Result<String, String>,
+    },
+    ModSearchResults(Result<(Vec<ModHit>, u64), String>),
+    ModReleasesResult {
+        id: String,
+        result: Result<Vec<ModRelease>, String>,
+    },
+    ModBrowseInstallComplete {
+        server_name: String,
+        title: String,
+        result: Result<Vec<String>, String>,
+    },
+    Rcon {
+        server_name: String,
+        event: RconEvent,
+    },
     ContainerConflict {
         server_name: String,
     },
@@ -110,6 +343,79 @@ enum TaskMessage {
     ImportComplete {
         result: Result<Box<crate::server::ServerConfig>, String>,
     },
+    /// Round trip in ms through the proxy settings being edited
+    ProxyTestComplete(Result<u128, String>),
+    DataMoveProgress {
+        current: usize,
+        total: usize,
+        current_file: String,
+    },
+    /// Entries whose old copy couldn't be deleted, or why the move failed
+    DataMoveComplete {
+        result: Result<Vec<String>, String>,
+    },
+    TemplateFetched {
+        result: Result<ModpackTemplate, String>,
+    },
+    /// How many templates the remote index has
+    TemplateIndexFetched {
+        result: Result<usize, String>,
+    },
+    TemplateHealthChecked {
+        results: HashMap<String, crate::template_check::TemplateHealth>,
+    },
+    PublicIpDetected {
+        result: Result<String, String>,
+    },
+    /// Public IP seen by the dynamic DNS check, and the DNS update if one was due
+    DynamicDnsChecked {
+        ip: Result<String, String>,
+        update: Option<Result<(), String>>,
+    },
+    /// Whether a copied public address answered a status ping from the internet
+    ReachabilityChecked {
+        address: String,
+        result: Result<bool, String>,
+    },
+    /// The metrics endpoint couldn't listen on its address
+    MetricsEndpointFailed {
+        error: String,
+    },
+    /// A command from an allowed user in the Discord bot's channel
+    BotCommand(BotRequest),
+    /// The Discord bot stopped because of its settings (token, channel)
+    DiscordBotFailed {
+        error: String,
+    },
+    DiskUsageMeasured {
+        usage: DiskUsage,
+    },
+    /// A running server's container was found stopped
+    ContainerExited {
+        name: String,
+    },
+    /// Newest backup age of servers with a no-backup alert
+    BackupAges {
+        ages: Vec<(String, Option<Duration>)>,
+    },
+    /// Samples of the running servers due to go into their history
+    MetricsHistorySample {
+        samples: Vec<(String, metrics::Sample)>,
+    },
+    MetricsHistoryLoaded {
+        server_name: String,
+        points: Vec<HistoryPoint>,
+    },
+    /// Preflight checklist for a server the user asked to start
+    PreflightDone {
+        name: String,
+        report: PreflightReport,
+    },
+    /// A container from before instance IDs was removed so it can be recreated
+    LegacyContainerRemoved {
+        name: String,
+        result: Result<(), String>,
+    },
 }
 
 pub struct DrakonixApp {
@@ -123,6 +429,8 @@ pub struct DrakonixApp {
     settings: AppSettings,
 
     current_view: View,
+    /// Search, filters and sort order of the dashboard's server list
+    dashboard_filter: DashboardFilter,
     create_view: ServerCreateView,
     edit_view: ServerEditView,
 
@@ -130,26 +438,147 @@ pub struct DrakonixApp {
     container_logs: String,
     /// Last time container logs were refreshed (for auto-refresh)
     container_logs_last_refresh: Option<std::time::Instant>,
+    container_log_filter: LogFilter,
+    /// Docker stdout or a log file in the server's data directory
+    container_log_source: LogSource,
 
     /// Combined Docker logs from all managed containers
     all_docker_logs: String,
+    /// Per-container logs read so far, with each container's read cursor
+    docker_log_chunks: Vec<LogChunk>,
+    docker_logs_generation: u64,
     /// Last time Docker logs were refreshed (for auto-refresh)
     docker_logs_last_refresh: Option<std::time::Instant>,
+    docker_log_filter: LogFilter,
 
     /// Cached backup list for the backups view
     backup_list: Vec<BackupInfo>,
 
+    /// Players found in the backup open in the player restore view
+    backup_players: Vec<BackupPlayer>,
+    /// File tree of the backup open in the contents view
+    backup_contents: Option<BackupContentsView>,
+    /// Name and port typed into the "Restore as new server" form
+    restore_new_name: String,
+    restore_new_port: u16,
+    backup_schedule_checked: Option<std::time::Instant>,
+    /// Scheduled backups started per server since its last successful backup, and
+    /// when the newest started or failed, for backing off after failures
+    scheduled_backup_attempts: HashMap<String, (u32, std::time::Instant)>,
+
+    /// Dependency graph for the mods view (None while the first scan runs)
+    mod_graph: Option<ModGraph>,
+
+    /// Newest crash report file per server that hasn't been looked at yet
+    crash_unread: HashMap<String, String>,
+    crash_checked: Option<std::time::Instant>,
+    /// Crash report files of the server being viewed, newest first
+    crash_reports: Vec<std::path::PathBuf>,
+    crash_selected: Option<CrashReport>,
+
+    /// Startup progress of servers that are Initializing, parsed from their logs
+    startup_progress: HashMap<String, StartupProgress>,
+    /// When each starting server entered Starting, for its startup history
+    starting_since: HashMap<String, std::time::Instant>,
+    /// When each running server's container started, for the uptime column
+    running_since: HashMap<String, chrono::DateTime<chrono::Utc>>,
+    /// Player count, MOTD and version from each running server's last status ping,
+    /// and when each was last pinged
+    live_status: HashMap<String, LiveStatus>,
+    status_pinged: HashMap<String, std::time::Instant>,
+    /// Likely causes of a server's Error status, from its container log
+    log_diagnoses: HashMap<String, Vec<Diagnosis>>,
+    mods_scanning: bool,
+    /// Mod IDs currently being downloaded from Modrinth
+    mods_installing: Vec<String>,
+    mod_browse: ModBrowseView,
+
+    /// Host RAM detected at startup, used by the memory advisor
+    host_memory: HostMemory,
+
+    /// Latest preflight checklist, shown when a start was held back
+    preflight_report: Option<PreflightReport>,
+    /// Servers whose preflight checks are still running
+    preflight_running: HashSet<String>,
+    /// Discord `!start` messages waiting on a server's preflight, answered when it finishes
+    bot_start_replies: HashMap<String, String>,
+
+    /// Latest self-diagnostics run
+    diagnostics: Option<DiagnosticsReport>,
+    diagnostics_running: bool,
+    /// When recent ERROR log lines were written, for spotting error spikes
+    recent_errors: VecDeque<std::time::Instant>,
+    last_auto_diagnostics: Option<std::time::Instant>,
+
+    /// Cached port table for the ports view (host probing is too slow to run every frame)
+    port_report: Vec<PortUsage>,
+    /// Backup usage per server, refreshed when the Backups overview is opened
+    backup_overview: BackupOverview,
+
     /// Backup in progress tracking (server_name -> (current, total, current_file))
     backup_progress: Option<(String, usize, usize, String)>,
     /// Restore in progress tracking (server_name -> (current, total, current_file))
     restore_progress: Option<(String, usize, usize, String)>,
+    /// Set to abort the in-flight restore; checked between archive entries
+    restore_cancel: Option<Arc<AtomicBool>>,
+    /// Server pack download or extraction in progress, by server name
+    pack_install_progress: Option<(String, crate::pack_installer::PackProgress)>,
+    /// Backup upload in progress (server_name, sent, total)
+    upload_progress: Option<(String, u64, Option<u64>)>,
     /// Export in progress tracking (server_name -> (current, total, current_file))
     export_progress: Option<(String, usize, usize, String)>,
+    /// Data folder move shown in Settings → Storage, once a destination is picked
+    data_move_plan: Option<MovePlan>,
+    /// Data folder move in progress (current, total, current_file)
+    data_move_progress: Option<(usize, usize, String)>,
+    /// Shortcut waiting for a key press in Settings
+    rebinding_shortcut: Option<Shortcut>,
+    /// Proxy connection test in flight
+    proxy_testing: bool,
+    /// Outcome of the last proxy connection test (round trip in ms)
+    proxy_test_result: Option<Result<u128, String>>,
 
     /// Console command input buffer
     console_input: String,
     /// Console output history
     console_output: Vec<String>,
+    /// RCON connection for the open console (dropped when leaving it)
+    rcon_session: Option<RconSession>,
+    rcon_connected: bool,
+    /// Commands sent but not yet answered, in order; `true` marks a background
+    /// command whose output shouldn't be printed
+    console_pending: VecDeque<(String, bool)>,
+    /// Online players from the last `list`, used for name completion
+    console_players: Vec<String>,
+    console_players_refreshed: Option<std::time::Instant>,
+    /// Selected tab on the server details page
+    details_tab: DetailsTab,
+    /// Players tab state: last `list` answer (None until the first one), ops from
+    /// ops.json, and the last RCON error
+    online_players: Option<Vec<String>>,
+    online_ops: Vec<String>,
+    online_players_error: Option<String>,
+    online_players_polled: Option<std::time::Instant>,
+    /// Whisper text or kick/ban reason
+    player_message: String,
+    /// Whitelist tab state (None when whitelist.json couldn't be read)
+    whitelist_entries: Option<Vec<WhitelistEntry>>,
+    whitelist_input: String,
+    whitelist_busy: bool,
+    /// Files tab state
+    files_view: FilesView,
+    /// Cached player head avatars for player lists
+    player_heads: PlayerHeads,
+    /// Playtime stats per server (loaded on first use) and when each running server
+    /// was last sampled
+    playtime: HashMap<String, PlaytimeStore>,
+    playtime_sampled: HashMap<String, std::time::Instant>,
+    /// Uptime sessions per server (loaded on first use)
+    uptime: HashMap<String, UptimeLog>,
+    /// When each scheduled broadcast last ran, keyed by server name and command
+    broadcasts_last_sent: HashMap<(String, String), std::time::Instant>,
+    /// Quick-command buttons being edited in the console
+    quick_command_draft: Option<Vec<QuickCommand>>,
 
     /// Temp buffer for settings UI
     settings_cf_key_input: String,
@@ -160,16 +589,82 @@ pub struct DrakonixApp {
 
     status_message: Option<(String, std::time::Instant)>,
     log_buffer: Vec<String>,
+    app_log_filter: LogFilter,
 
     /// Show close confirmation dialog when servers are running
     show_close_confirmation: bool,
 
+    /// The window was already minimized instead of closing once, so the next
+    /// close request quits. Cleared once the window is back in front.
+    minimized_on_close: bool,
+    /// The window minimized on close has since been seen minimized or unfocused
+    left_after_minimize: bool,
+
+    /// Icon in the system tray, where the desktop has one; kept across profile switches
+    tray: Option<Tray>,
+
     /// Orphaned server directories (exist on disk but not in servers.json)
     orphaned_dirs: Vec<String>,
 
     /// When set, shows a confirmation dialog before deleting this orphaned directory
     confirm_delete_orphan: Option<String>,
 
+    /// Stopped servers whose containers predate instance IDs; the user is asked
+    /// whether to recreate them with this install's label
+    legacy_containers: Vec<String>,
+
+    /// The data moved to this folder but saving the setting that points there
+    /// failed, with the error; shown until a retry saves it
+    data_root_unsaved: Option<(std::path::PathBuf, String)>,
+
+    /// Name typed into the new profile dialog; the dialog is open while set
+    new_profile_name: Option<String>,
+
+    /// This machine's LAN IP and the network's public IP (when detection is on),
+    /// for copying connect addresses
+    lan_ip: Option<String>,
+    public_ip: Option<String>,
+
+    /// Page served by the metrics endpoint, refreshed every METRICS_SAMPLE_INTERVAL
+    metrics_body: Arc<std::sync::Mutex<String>>,
+    /// The endpoint's listener task while it's on
+    metrics_endpoint: Option<tokio::task::JoinHandle<()>>,
+    metrics_sampled: Option<std::time::Instant>,
+    /// Why the endpoint couldn't start
+    metrics_error: Option<String>,
+    /// Dynamic DNS: when the public IP was last checked, the IP the hostname was
+    /// last pointed at (and when), and the latest outcome for the status bar
+    ddns_checked: Option<std::time::Instant>,
+    ddns_pushed: Option<(String, std::time::Instant)>,
+    ddns_status: Option<(Result<String, String>, chrono::DateTime<chrono::Local>)>,
+    ddns_busy: bool,
+    /// The Discord bot's polling task while it's on, and why it last stopped
+    discord_bot: Option<tokio::task::JoinHandle<()>>,
+    discord_bot_error: Option<String>,
+    /// Allowed users being edited in Settings, one per line
+    discord_users_input: String,
+    /// Size of each server's folder and backups, and free space on the data drive,
+    /// from the last measurement; `disk_usage_measured` is cleared when it's due again
+    disk_usage: DiskUsage,
+    disk_usage_measured: Option<std::time::Instant>,
+    disk_usage_measuring: bool,
+    /// Conditions behind servers' alerts, and when backup ages were last checked
+    alert_monitor: AlertMonitor,
+    backup_alerts_checked: Option<std::time::Instant>,
+    /// Server whose alert rules are shown in Settings → Alerts
+    alerts_server: Option<String>,
+    /// When running servers were last sampled for their history
+    history_sampled: Option<std::time::Instant>,
+    /// History charted on the Overview tab, and for which server; `None` points
+    /// while it loads
+    metrics_history: Option<(String, Option<Vec<HistoryPoint>>)>,
+    history_range: HistoryRange,
+
+    /// Remote template index download in flight
+    template_index_fetching: bool,
+    /// Template count of the last index fetch, or why it failed
+    template_index_result: Option<Result<usize, String>>,
+
     /// Channel receiver for background task messages
     task_rx: mpsc::Receiver<TaskMessage>,
     /// Channel sender (cloned for each background task)
@@ -178,8 +673,14 @@ pub struct DrakonixApp {
 
 impl DrakonixApp {
     pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
+        let mut app = Self::load(&cc.egui_ctx);
+        app.tray = Tray::new(&cc.egui_ctx);
+        app
+    }
+
+    /// Build the app for the open profile: its settings, servers and Docker namespace
+    fn load(ctx: &egui::Context) -> Self {
         // Set up custom fonts/style if needed
-        let ctx = &cc.egui_ctx;
         ctx.set_visuals(egui::Visuals::dark());
         egui_extras::install_image_loaders(ctx);
 
@@ -189,41 +690,56 @@ impl DrakonixApp {
         let mut log_buffer = Vec::new();
         log_buffer.push(format!("[{}] DrakonixAnvil starting...", Self::timestamp()));
 
+        // Load global settings (the instance ID namespaces our containers)
+        let settings = load_settings();
+        i18n::set_language(settings.language);
+        let settings_error = crate::config::take_settings_error();
+        if let Some(error) = &settings_error {
+            log_buffer.push(format!("[{}] ERROR: {}", Self::timestamp(), error));
+        }
+
         // Try to connect to Docker
-        let (docker, docker_connected, docker_version) = match DockerManager::new() {
-            Ok(dm) => {
-                let version = runtime.block_on(async {
-                    match dm.get_version().await {
-                        Ok(v) => v,
-                        Err(_) => "unknown".to_string(),
-                    }
-                });
-                let connected =
-                    runtime.block_on(async { dm.check_connection().await.unwrap_or(false) });
-                log_buffer.push(format!(
-                    "[{}] Docker connected (v{})",
-                    Self::timestamp(),
-                    version
-                ));
-                (Some(Arc::new(dm)), connected, version)
-            }
-            Err(e) => {
-                log_buffer.push(format!(
-                    "[{}] ERROR: Failed to connect to Docker: {}",
-                    Self::timestamp(),
-                    e
-                ));
-                (None, false, "N/A".to_string())
-            }
-        };
+        let (docker, docker_connected, docker_version) =
+            match DockerManager::new(&settings.instance_id) {
+                Ok(dm) => {
+                    let version = runtime.block_on(async {
+                        match dm.get_version().await {
+                            Ok(v) => v,
+                            Err(_) => "unknown".to_string(),
+                        }
+                    });
+                    let connected =
+                        runtime.block_on(async { dm.check_connection().await.unwrap_or(false) });
+                    log_buffer.push(format!(
+                        "[{}] {}",
+                        Self::timestamp(),
+                        tr_args(
+                            "log-docker-connected",
+                            &[("version", version.as_str().into())]
+                        )
+                    ));
+                    (Some(Arc::new(dm)), connected, version)
+                }
+                Err(e) => {
+                    log_buffer.push(format!(
+                        "[{}] ERROR: {}",
+                        Self::timestamp(),
+                        tr_args(
+                            "log-docker-connect-failed",
+                            &[("error", e.to_string().into())]
+                        )
+                    ));
+                    (None, false, "N/A".to_string())
+                }
+            };
 
         // Load saved servers
         let servers = match load_servers() {
             Ok(mut servers) => {
                 log_buffer.push(format!(
-                    "[{}] Loaded {} server(s) from disk",
+                    "[{}] {}",
                     Self::timestamp(),
-                    servers.len()
+                    tr_args("log-servers-loaded", &[("count", servers.len().into())])
                 ));
                 // Reset any transient states to Stopped
                 for server in &mut servers {
@@ -241,53 +757,223 @@ impl DrakonixApp {
             }
             Err(e) => {
                 log_buffer.push(format!(
-                    "[{}] ERROR: Failed to load servers: {}",
+                    "[{}] ERROR: {}",
                     Self::timestamp(),
-                    e
+                    tr_args(
+                        "log-servers-load-failed",
+                        &[("error", e.to_string().into())]
+                    )
                 ));
                 Vec::new()
             }
         };
 
-        // Load global settings
-        let settings = load_settings();
+        crate::http::set_proxy_settings(&settings.proxy);
         let settings_cf_key_input = settings.curseforge_api_key.clone().unwrap_or_default();
+        let discord_users_input = settings.discord_bot.allowed_user_ids.join("\n");
         let settings_cf_key_was_set = settings.curseforge_api_key.is_some();
 
+        // Claim this install's containers from before instance IDs existed
+        let legacy_containers = match &docker {
+            Some(docker) => {
+                let (messages, stopped) = runtime.block_on(Self::migrate_legacy_containers(
+                    docker,
+                    &servers,
+                    &settings.instance_id,
+                ));
+                for message in messages {
+                    log_buffer.push(format!("[{}] {}", Self::timestamp(), message));
+                }
+                stopped
+            }
+            None => Vec::new(),
+        };
+
         let orphaned_dirs = find_orphaned_server_dirs(&servers);
 
-        Self {
+        let host_memory = memory::host_memory();
+        log_buffer.push(format!(
+            "[{}] {}",
+            Self::timestamp(),
+            tr_args(
+                "log-host-memory",
+                &[
+                    ("total", host_memory.total_mb.into()),
+                    ("free", host_memory.available_mb.into())
+                ]
+            )
+        ));
+        let player_heads = PlayerHeads::new(runtime.handle().clone());
+
+        let mut app = Self {
             runtime,
             docker,
             docker_connected,
             docker_version,
             servers,
-            templates: ModpackTemplate::builtin_templates(),
+            templates: ModpackTemplate::all_templates(settings.template_index.enabled),
             settings,
             current_view: View::Dashboard,
-            create_view: ServerCreateView::default(),
+            dashboard_filter: DashboardFilter::default(),
+            create_view: ServerCreateView {
+                user_templates: crate::templates::user_template_names(),
+                ..ServerCreateView::default()
+            },
             edit_view: ServerEditView::default(),
             container_logs: String::new(),
             container_logs_last_refresh: None,
+            container_log_filter: LogFilter::default(),
+            container_log_source: LogSource::default(),
             all_docker_logs: String::new(),
+            docker_log_chunks: Vec::new(),
+            docker_logs_generation: 0,
             docker_logs_last_refresh: None,
+            docker_log_filter: LogFilter::default(),
             backup_list: Vec::new(),
+            backup_players: Vec::new(),
+            backup_contents: None,
+            restore_new_name: String::new(),
+            restore_new_port: 25565,
+            backup_schedule_checked: None,
+            scheduled_backup_attempts: HashMap::new(),
+            mod_graph: None,
+            crash_unread: HashMap::new(),
+            crash_checked: None,
+            crash_reports: Vec::new(),
+            crash_selected: None,
+            startup_progress: HashMap::new(),
+            starting_since: HashMap::new(),
+            running_since: HashMap::new(),
+            live_status: HashMap::new(),
+            status_pinged: HashMap::new(),
+            log_diagnoses: HashMap::new(),
+            mods_scanning: false,
+            mods_installing: Vec::new(),
+            mod_browse: ModBrowseView::default(),
+            host_memory,
+            preflight_report: None,
+            preflight_running: HashSet::new(),
+            bot_start_replies: HashMap::new(),
+            diagnostics: None,
+            diagnostics_running: false,
+            recent_errors: VecDeque::new(),
+            last_auto_diagnostics: None,
+            port_report: Vec::new(),
+            backup_overview: BackupOverview::default(),
             backup_progress: None,
             restore_progress: None,
+            restore_cancel: None,
+            pack_install_progress: None,
+            upload_progress: None,
             export_progress: None,
+            data_move_plan: None,
+            data_move_progress: None,
+            rebinding_shortcut: None,
+            proxy_testing: false,
+            proxy_test_result: None,
             console_input: String::new(),
             console_output: Vec::new(),
+            rcon_session: None,
+            rcon_connected: false,
+            console_pending: VecDeque::new(),
+            console_players: Vec::new(),
+            console_players_refreshed: None,
+            details_tab: DetailsTab::default(),
+            online_players: None,
+            online_ops: Vec::new(),
+            online_players_error: None,
+            online_players_polled: None,
+            player_message: String::new(),
+            whitelist_entries: None,
+            whitelist_input: String::new(),
+            whitelist_busy: false,
+            files_view: FilesView::default(),
+            player_heads,
+            playtime: HashMap::new(),
+            uptime: HashMap::new(),
+            playtime_sampled: HashMap::new(),
+            quick_command_draft: None,
+            broadcasts_last_sent: HashMap::new(),
             settings_cf_key_input,
             settings_cf_key_was_set,
             settings_cf_key_visible: false,
-            status_message: None,
+            status_message: settings_error.map(|e| (e, std::time::Instant::now())),
             log_buffer,
+            app_log_filter: LogFilter::default(),
             show_close_confirmation: false,
+            minimized_on_close: false,
+            left_after_minimize: false,
+            tray: None,
             orphaned_dirs,
             confirm_delete_orphan: None,
+            legacy_containers,
+            data_root_unsaved: None,
+            new_profile_name: None,
+            lan_ip: crate::address::lan_ip().map(|ip| ip.to_string()),
+            public_ip: None,
+            metrics_body: Arc::new(std::sync::Mutex::new(String::new())),
+            metrics_endpoint: None,
+            metrics_sampled: None,
+            metrics_error: None,
+            ddns_checked: None,
+            ddns_pushed: None,
+            ddns_status: None,
+            ddns_busy: false,
+            discord_bot: None,
+            discord_bot_error: None,
+            discord_users_input,
+            disk_usage: DiskUsage::default(),
+            disk_usage_measured: None,
+            disk_usage_measuring: false,
+            alert_monitor: AlertMonitor::default(),
+            backup_alerts_checked: None,
+            alerts_server: None,
+            history_sampled: None,
+            metrics_history: None,
+            history_range: HistoryRange::default(),
+            template_index_fetching: false,
+            template_index_result: None,
             task_rx,
             task_tx,
+        };
+        // Newer featured templates and fixed pack URLs, without a new release
+        app.fetch_template_index();
+        app.load_running_since();
+        app.detect_public_ip();
+        app.start_metrics_endpoint();
+        app.start_discord_bot();
+        app
+    }
+
+    /// Swap the whole app over to another profile (None for the default). Servers
+    /// of the old one keep running in Docker and show up again when it's reopened.
+    fn switch_profile(&mut self, ctx: &egui::Context, profile: Option<String>) {
+        if self.has_active_tasks() {
+            self.show_status_message(tr("profile-busy"));
+            return;
+        }
+        if let Err(e) = crate::profiles::switch(profile) {
+            self.show_status_message(format!("{:#}", e));
+            return;
         }
+        // The new profile binds the same metrics address and polls the same bot
+        // channel, so the old ones must be gone first
+        let services = [self.metrics_endpoint.take(), self.discord_bot.take()];
+        self.runtime.block_on(async {
+            for task in services.into_iter().flatten() {
+                task.abort();
+                let _ = task.await;
+            }
+        });
+        let mut previous = std::mem::replace(self, Self::load(ctx));
+        self.tray = previous.tray.take();
+        // Idle background work of the old profile (heads, RCON) is dropped, not awaited
+        previous.runtime.shutdown_background();
+        self.show_status_message(tr_args(
+            "profile-switched",
+            &[("name", crate::profiles::active_label().into())],
+        ));
+        ctx.request_repaint();
     }
 
     fn timestamp() -> String {
@@ -297,6 +983,9 @@ impl DrakonixApp {
     fn log(&mut self, msg: String) {
         let line = format!("[{}] {}", Self::timestamp(), msg);
         tracing::info!("{}", msg);
+        if msg.starts_with("ERROR") {
+            self.recent_errors.push_back(std::time::Instant::now());
+        }
         self.log_buffer.push(line);
         if self.log_buffer.len() > MAX_LOG_LINES {
             self.log_buffer.remove(0);
@@ -310,54 +999,58 @@ impl DrakonixApp {
 
     fn save_servers(&mut self) {
         if let Err(e) = save_servers(&self.servers) {
-            self.log(format!("ERROR: Failed to save servers: {}", e));
+            self.log(format!(
+                "ERROR: {}",
+                tr_args(
+                    "log-save-servers-failed",
+                    &[("error", e.to_string().into())]
+                )
+            ));
         }
     }
 
     /// Check if a port is already in use
     /// Returns Some(error_message) if there's a conflict, None if port is available
     fn check_port_conflict(&self, port: u16, server_name: &str) -> Option<String> {
-        // First, check if another DrakonixAnvil server is configured with this port and running
+        // First, check if another DrakonixAnvil server is using this port and running
         for server in &self.servers {
             if server.config.name != server_name
-                && server.config.port == port
+                && server.config.all_ports().contains(&port)
                 && matches!(
                     server.status,
-                    ServerStatus::Running | ServerStatus::Starting | ServerStatus::Initializing
+                    ServerStatus::Running
+                        | ServerStatus::Starting
+                        | ServerStatus::Initializing
+                        | ServerStatus::Frozen
                 )
             {
-                return Some(format!(
-                    "Port {} is already used by running server '{}'",
-                    port, server.config.name
+                return Some(tr_args(
+                    "ports-used-by-server",
+                    &[
+                        ("port", port.to_string().into()),
+                        ("server", server.config.name.as_str().into()),
+                    ],
                 ));
             }
         }
 
         // Then, check if any process is listening on this port
-        match std::net::TcpListener::bind(format!("0.0.0.0:{}", port)) {
-            Ok(_listener) => {
-                // Port is available (listener is dropped immediately)
-                None
-            }
-            Err(e) => {
-                match e.kind() {
-                    std::io::ErrorKind::AddrInUse => {
-                        // Find a suggested available port
-                        let suggested = Self::find_available_port(port);
-                        Some(format!(
-                            "Port {} is already in use by another application. Try port {} instead.",
-                            port,
-                            suggested.unwrap_or(port + 1)
-                        ))
-                    }
-                    std::io::ErrorKind::PermissionDenied => Some(format!(
-                        "Permission denied for port {}. Ports below 1024 require root privileges.",
-                        port
-                    )),
-                    _ => Some(format!("Cannot bind to port {}: {}", port, e)),
-                }
-            }
+        let error = crate::ports::bind_error(port)?;
+        if error.kind() == std::io::ErrorKind::AddrInUse {
+            // Find a suggested available port
+            let suggested = Self::find_available_port(port);
+            return Some(tr_args(
+                "ports-used-by-app",
+                &[
+                    ("port", port.to_string().into()),
+                    (
+                        "suggested",
+                        suggested.unwrap_or(port + 1).to_string().into(),
+                    ),
+                ],
+            ));
         }
+        Some(crate::ports::describe_bind_error(port, &error))
     }
 
     /// Find an available port starting from the given port
@@ -391,6 +1084,10 @@ impl DrakonixApp {
         config.java_args = template.default_java_args.clone();
         config.java_version = template.java_version;
         config.extra_env = template.default_extra_env.clone();
+        config.world_url = template.world_url.clone();
+        if let Some(sp) = &template.server_properties {
+            config.server_properties = sp.clone();
+        }
 
         let instance = ServerInstance {
             config,
@@ -400,2759 +1097,10158 @@ impl DrakonixApp {
 
         self.servers.push(instance);
         self.save_servers();
-        self.show_status_message(format!("Server '{}' created successfully!", name));
+        self.show_status_message(tr_args(
+            "msg-server-created",
+            &[("name", name.clone().into())],
+        ));
         self.current_view = View::Dashboard;
         self.create_view.reset();
     }
 
-    fn start_edit_server(&mut self, name: &str) {
-        if let Some(server) = self.servers.iter().find(|s| s.config.name == name) {
-            self.edit_view.load_from_config(&server.config);
-            self.current_view = View::EditServer(name.to_string());
+    fn handle_template_action(&mut self, action: TemplateAction) {
+        match action {
+            TemplateAction::ImportFile => {
+                let Some(path) = rfd::FileDialog::new()
+                    .add_filter(tr("msg-template-filter"), &["json"])
+                    .pick_file()
+                else {
+                    return; // User cancelled
+                };
+                match ModpackTemplate::import(&path) {
+                    Ok(template) => self.add_user_template(template),
+                    Err(e) => self.show_status_message(tr_args(
+                        "msg-template-import-failed",
+                        &[("error", format!("{:#}", e).into())],
+                    )),
+                }
+            }
+            TemplateAction::AddFromUrl(url) => {
+                self.create_view.template_url_loading = true;
+                let tx = self.task_tx.clone();
+                self.runtime.spawn(async move {
+                    let result = ModpackTemplate::fetch(&url)
+                        .await
+                        .map_err(|e| format!("{:#}", e));
+                    tx.send(TaskMessage::TemplateFetched { result }).ok();
+                });
+            }
+            TemplateAction::Export(template) => {
+                let Some(path) = rfd::FileDialog::new()
+                    .set_file_name(format!("{}.json", template.name))
+                    .add_filter(tr("msg-template-filter"), &["json"])
+                    .save_file()
+                else {
+                    return; // User cancelled
+                };
+                match template.export(&path) {
+                    Ok(()) => self.show_status_message(tr_args(
+                        "msg-template-exported",
+                        &[
+                            ("name", template.name.clone().into()),
+                            ("path", path.display().to_string().into()),
+                        ],
+                    )),
+                    Err(e) => self.show_status_message(format!("{:#}", e)),
+                }
+            }
+            TemplateAction::CheckHealth => self.check_templates(),
+            TemplateAction::Remove(name) => match crate::templates::remove_user_template(&name) {
+                Ok(()) => {
+                    self.reload_templates();
+                    self.show_status_message(tr_args(
+                        "msg-template-removed",
+                        &[("name", name.into())],
+                    ));
+                }
+                Err(e) => self.show_status_message(format!("{:#}", e)),
+            },
         }
     }
 
-    fn save_server_edit(&mut self, name: &str, result: ServerEditResult) {
-        if let Some(server) = self.servers.iter_mut().find(|s| s.config.name == name) {
-            let port_changed = server.config.port != result.port;
-            let memory_changed = server.config.memory_mb != result.memory_mb;
-            let args_changed = server.config.java_args != result.java_args;
-            let props_changed = server.config.server_properties != result.server_properties;
-            let modpack_changed = server.config.modpack != result.modpack;
-            let java_ver_changed = server.config.java_version != result.java_version;
-            let env_changed = server.config.extra_env != result.extra_env;
-
-            server.config.port = result.port;
-            server.config.memory_mb = result.memory_mb;
-            server.config.java_args = result.java_args;
-            server.config.server_properties = result.server_properties;
-            server.config.modpack = result.modpack;
-            server.config.java_version = result.java_version;
-            server.config.extra_env = result.extra_env;
-
-            // If any settings changed, we need to recreate the container
-            if port_changed
-                || memory_changed
-                || args_changed
-                || props_changed
-                || modpack_changed
-                || java_ver_changed
-                || env_changed
-            {
-                // Clear container_id to force recreation on next start
-                server.container_id = None;
-            }
-
-            self.save_servers();
-            self.show_status_message(format!("Server '{}' settings updated!", name));
+    /// Keep an imported template with the user's own and select it
+    fn add_user_template(&mut self, template: ModpackTemplate) {
+        if let Err(e) = template.save_user() {
+            self.show_status_message(tr_args(
+                "msg-template-save-failed",
+                &[("error", format!("{:#}", e).into())],
+            ));
+            return;
         }
-        self.current_view = View::Dashboard;
-        self.edit_view.reset();
+        self.reload_templates();
+        self.create_view.selected_template_idx =
+            self.templates.iter().position(|t| t.name == template.name);
+        self.create_view.memory_mb = template.recommended_memory_mb.to_string();
+        self.show_status_message(tr_args(
+            "msg-template-added",
+            &[("name", template.name.into())],
+        ));
     }
 
-    fn start_server(&mut self, name: &str) {
+    /// Ask Docker when servers that were already running started, so their
+    /// uptime survives restarting the app
+    fn load_running_since(&self) {
         let Some(docker) = self.docker.clone() else {
-            self.show_status_message("Docker not connected".to_string());
-            return;
-        };
-
-        // Find server index
-        let server_idx = self.servers.iter().position(|s| s.config.name == name);
-        let Some(idx) = server_idx else {
-            self.show_status_message(format!("Server '{}' not found", name));
             return;
         };
+        let running: Vec<(String, String)> = self
+            .servers
+            .iter()
+            .filter(|s| matches!(s.status, ServerStatus::Running | ServerStatus::Frozen))
+            .filter_map(|s| Some((s.config.name.clone(), s.container_id.clone()?)))
+            .collect();
+        let tx = self.task_tx.clone();
+        self.runtime.spawn(async move {
+            for (name, container_id) in running {
+                if let Ok(Some(started_at)) = docker.container_started_at(&container_id).await {
+                    tx.send(TaskMessage::ContainerStartedAt { name, started_at })
+                        .ok();
+                }
+            }
+        });
+    }
 
-        let port = self.servers[idx].config.port;
-        let rcon_port = self.servers[idx].config.rcon_port();
-
-        // Check for port conflicts
-        if let Some(conflict) = self.check_port_conflict(port, name) {
-            self.show_status_message(conflict);
+    fn detect_public_ip(&self) {
+        if !self.settings.detect_public_ip {
             return;
         }
+        let tx = self.task_tx.clone();
+        self.runtime.spawn(async move {
+            let result = crate::address::public_ip()
+                .await
+                .map_err(|e| format!("{:#}", e));
+            tx.send(TaskMessage::PublicIpDetected { result }).ok();
+        });
+    }
 
-        // Create data directory if needed
-        let data_path = get_server_data_path(name);
-        if let Err(e) = std::fs::create_dir_all(&data_path) {
-            self.servers[idx].status =
-                ServerStatus::Error(format!("Failed to create data dir: {}", e));
-            self.show_status_message(format!("Failed to create data directory: {}", e));
+    /// Every DDNS_CHECK_INTERVAL, look up the public IP and point the dynamic DNS
+    /// hostname at it if it changed or the update interval has passed
+    fn check_dynamic_dns(&mut self) {
+        if !self.settings.ddns.enabled
+            || self.ddns_busy
+            || self
+                .ddns_checked
+                .is_some_and(|t| t.elapsed() < DDNS_CHECK_INTERVAL)
+        {
             return;
         }
+        self.ddns_checked = Some(std::time::Instant::now());
+        self.ddns_busy = true;
+        let settings = self.settings.ddns.clone();
+        let interval = Duration::from_secs(u64::from(settings.interval_minutes.max(5)) * 60);
+        let pushed = self.ddns_pushed.clone();
+        let tx = self.task_tx.clone();
+        self.runtime.spawn(async move {
+            let ip = match crate::address::public_ip().await {
+                Ok(ip) => ip,
+                Err(e) => {
+                    let ip = Err(tr_args(
+                        "log-public-ip-failed",
+                        &[("error", format!("{:#}", e).into())],
+                    ));
+                    tx.send(TaskMessage::DynamicDnsChecked { ip, update: None })
+                        .ok();
+                    return;
+                }
+            };
+            let due = pushed.is_none_or(|(last, at)| last != ip || at.elapsed() >= interval);
+            let update = if due {
+                Some(
+                    ddns::update(&settings, &ip)
+                        .await
+                        .map_err(|e| format!("{:#}", e)),
+                )
+            } else {
+                None
+            };
+            tx.send(TaskMessage::DynamicDnsChecked { ip: Ok(ip), update })
+                .ok();
+        });
+    }
 
-        // Determine if we need to pull/create or just start
-        let needs_container = self.servers[idx].container_id.is_none();
-        let container_id = self.servers[idx].container_id.clone();
-        let container_name = get_container_name(name);
-        let mut env_vars = self.servers[idx].config.build_docker_env();
-
-        // Add CurseForge API key if configured
-        if let Some(cf_key) = &self.settings.curseforge_api_key {
-            if !cf_key.is_empty() {
-                env_vars.push(format!("CF_API_KEY={}", cf_key));
-            }
-        }
+    /// Update the dynamic DNS record on the next frame, whatever the schedule
+    fn force_dynamic_dns_update(&mut self) {
+        self.ddns_checked = None;
+        self.ddns_pushed = None;
+    }
 
-        let memory_mb = self.servers[idx].config.memory_mb;
-        let docker_image = self.servers[idx].config.docker_image();
-        let modpack_source = self.servers[idx].config.modpack.source.clone();
-        let server_name = name.to_string();
+    /// After a public address is copied, make sure players outside can reach it
+    fn check_reachability(&self, address: String) {
         let tx = self.task_tx.clone();
+        self.runtime.spawn(async move {
+            let result = crate::address::reachable_from_outside(&address)
+                .await
+                .map_err(|e| format!("{:#}", e));
+            tx.send(TaskMessage::ReachabilityChecked { address, result })
+                .ok();
+        });
+    }
 
-        // Set initial status
-        if needs_container {
-            self.servers[idx].status = ServerStatus::Pulling;
-            self.log(format!("Pulling image for server '{}'...", name));
-        } else {
-            self.servers[idx].status = ServerStatus::Starting;
-            self.log(format!("Starting server '{}'...", name));
+    /// (Re)start the metrics endpoint on the configured address, or stop it if
+    /// it's switched off
+    fn start_metrics_endpoint(&mut self) {
+        if let Some(task) = self.metrics_endpoint.take() {
+            task.abort();
+        }
+        self.metrics_error = None;
+        if !self.settings.metrics.enabled {
+            return;
         }
+        // Sample straight away rather than serving an empty page until the next round
+        self.metrics_sampled = None;
+        let address = self.settings.metrics.bind.trim().to_string();
+        let body = self.metrics_body.clone();
+        let tx = self.task_tx.clone();
+        self.metrics_endpoint = Some(self.runtime.spawn(async move {
+            match metrics::bind(&address).await {
+                Ok(listener) => {
+                    tracing::info!("Serving metrics at http://{}/metrics", address);
+                    metrics::serve(listener, body).await;
+                }
+                Err(e) => {
+                    let error = format!("{:#}", e);
+                    tx.send(TaskMessage::MetricsEndpointFailed { error }).ok();
+                }
+            }
+        }));
+    }
 
-        // Spawn background task
+    /// (Re)start the Discord bot with the current settings, or stop it if it's off
+    fn start_discord_bot(&mut self) {
+        if let Some(task) = self.discord_bot.take() {
+            task.abort();
+        }
+        self.discord_bot_error = None;
+        if !self.settings.discord_bot.enabled {
+            return;
+        }
+        let settings = self.settings.discord_bot.clone();
+        let tx = self.task_tx.clone();
+        self.discord_bot = Some(self.runtime.spawn(async move {
+            let commands = tx.clone();
+            let result = discord_bot::run(settings, move |request| {
+                commands.send(TaskMessage::BotCommand(request)).ok();
+            })
+            .await;
+            if let Err(e) = result {
+                let error = format!("{:#}", e);
+                tx.send(TaskMessage::DiscordBotFailed { error }).ok();
+            }
+        }));
+    }
+
+    /// Reply in the bot's channel to the message that gave a command
+    fn bot_reply(&self, message_id: String, text: String) {
+        let target = ReplyTarget::new(&self.settings.discord_bot);
         self.runtime.spawn(async move {
-            let name = server_name.clone();
+            if let Err(e) = discord_bot::reply(&target, &message_id, &text).await {
+                tracing::warn!("Discord bot reply failed: {:#}", e);
+            }
+        });
+    }
 
-            // Pull image if needed
-            if needs_container {
-                tx.send(TaskMessage::Log(format!(
-                    "Checking Docker image {}...",
-                    docker_image
-                )))
-                .ok();
+    /// Reply to a Discord `!start` according to where the server ended up
+    fn bot_start_text(&self, name: &str) -> String {
+        let started = self.servers.iter().any(|s| {
+            s.config.name == name
+                && matches!(s.status, ServerStatus::Pulling | ServerStatus::Starting)
+        });
+        if started {
+            format!("Starting **{}**…", name)
+        } else {
+            format!(
+                "**{}** didn't start; it needs attention in DrakonixAnvil.",
+                name
+            )
+        }
+    }
 
-                if let Err(e) = docker.ensure_image(&docker_image).await {
-                    let err = format!("Failed to pull image: {}", e);
-                    tx.send(TaskMessage::Log(err.clone())).ok();
-                    tx.send(TaskMessage::ServerStatus {
-                        name,
-                        status: ServerStatus::Error(err),
-                        container_id: None,
+    /// Run a command from the Discord bot through the same paths as the buttons
+    fn handle_bot_command(&mut self, request: BotRequest) {
+        let BotRequest {
+            command,
+            user,
+            message_id,
+        } = request;
+        let find = |servers: &[ServerInstance], name: &str| {
+            servers
+                .iter()
+                .find(|s| s.config.name.eq_ignore_ascii_case(name.trim()))
+                .map(|s| s.config.name.clone())
+        };
+        match command {
+            BotCommand::Help => self.bot_reply(message_id, discord_bot::HELP.to_string()),
+            BotCommand::Status => {
+                let lines: Vec<String> = self
+                    .servers
+                    .iter()
+                    .map(|s| {
+                        let live = self.live_status.get(&s.config.name);
+                        discord_bot::status_line(&s.config.name, &s.status, live)
                     })
-                    .ok();
+                    .collect();
+                let text = if lines.is_empty() {
+                    "No servers yet.".to_string()
+                } else {
+                    lines.join("\n")
+                };
+                self.bot_reply(message_id, text);
+            }
+            BotCommand::Start(name) => {
+                let Some(name) = find(&self.servers, &name) else {
+                    self.bot_reply(message_id, format!("No server called '{}'.", name));
                     return;
+                };
+                self.log(tr_args(
+                    "log-discord-start",
+                    &[("user", user.into()), ("name", name.as_str().into())],
+                ));
+                self.start_server(&name);
+                if self.preflight_running.contains(&name) {
+                    // Answered from finish_preflight once the checks are done
+                    self.bot_start_replies.insert(name, message_id);
+                } else {
+                    let text = self.bot_start_text(&name);
+                    self.bot_reply(message_id, text);
                 }
-                tx.send(TaskMessage::Log(format!(
-                    "Docker image {} ready",
-                    docker_image
-                )))
-                .ok();
-
-                // Install modpack files on host if needed (ForgeWithPack)
-                if let crate::server::ModpackSource::ForgeWithPack { pack_url, .. } =
-                    &modpack_source
-                {
-                    tx.send(TaskMessage::Log(
-                        "Installing server pack on host...".to_string(),
-                    ))
-                    .ok();
-                    if let Err(e) =
-                        crate::pack_installer::install_forge_pack(&data_path, pack_url).await
-                    {
-                        let err = format!("Failed to install server pack: {}", e);
-                        tx.send(TaskMessage::Log(err.clone())).ok();
-                        tx.send(TaskMessage::ServerStatus {
-                            name,
-                            status: ServerStatus::Error(err),
-                            container_id: None,
-                        })
-                        .ok();
-                        return;
-                    }
-                    tx.send(TaskMessage::Log(
-                        "Server pack installed successfully".to_string(),
-                    ))
-                    .ok();
-                }
-
-                // Update status to Starting
-                tx.send(TaskMessage::ServerStatus {
-                    name: name.clone(),
-                    status: ServerStatus::Starting,
-                    container_id: None,
-                })
-                .ok();
-
-                // Create container
-                tx.send(TaskMessage::Log(format!(
-                    "Creating container {}...",
-                    container_name
-                )))
-                .ok();
-                match docker
-                    .create_minecraft_container(crate::docker::CreateContainerParams {
-                        container_name: &container_name,
-                        server_name: &name,
-                        image: &docker_image,
-                        port,
-                        rcon_port,
-                        memory_mb,
-                        env_vars,
-                        data_path: &data_path,
-                    })
-                    .await
-                {
-                    Ok(new_container_id) => {
-                        tx.send(TaskMessage::Log(format!(
-                            "Created container {}",
-                            new_container_id
-                        )))
-                        .ok();
-
-                        // Start the new container
-                        if let Err(e) = docker.start_container(&new_container_id).await {
-                            let err = format!("Failed to start container: {}", e);
-                            tx.send(TaskMessage::Log(err.clone())).ok();
-                            tx.send(TaskMessage::ServerStatus {
-                                name,
-                                status: ServerStatus::Error(err),
-                                container_id: Some(new_container_id),
-                            })
-                            .ok();
-                            return;
-                        }
-
-                        tx.send(TaskMessage::Log(
-                            "Container started, waiting for MC server to initialize...".to_string(),
-                        ))
-                        .ok();
-                        tx.send(TaskMessage::ServerStatus {
-                            name: name.clone(),
-                            status: ServerStatus::Initializing,
-                            container_id: Some(new_container_id.clone()),
-                        })
-                        .ok();
-
-                        // Poll MC server until it accepts connections
-                        Self::poll_mc_server_ready(
-                            tx.clone(),
-                            name,
-                            port,
-                            new_container_id,
-                            docker,
-                        )
-                        .await;
-                    }
-                    Err(e) => {
-                        let err_str = format!("{}", e);
-                        if err_str.contains("status code 409") {
-                            tx.send(TaskMessage::Log(format!(
-                                "Container name conflict for '{}' — old container still exists",
-                                name
-                            )))
-                            .ok();
-                            tx.send(TaskMessage::ContainerConflict { server_name: name })
-                                .ok();
-                        } else {
-                            let err = format!("Failed to create container: {}", e);
-                            tx.send(TaskMessage::Log(err.clone())).ok();
-                            tx.send(TaskMessage::ServerStatus {
-                                name,
-                                status: ServerStatus::Error(err),
-                                container_id: None,
-                            })
-                            .ok();
+            }
+            BotCommand::Stop(name) => {
+                let Some(name) = find(&self.servers, &name) else {
+                    self.bot_reply(message_id, format!("No server called '{}'.", name));
+                    return;
+                };
+                self.log(tr_args(
+                    "log-discord-stop",
+                    &[("user", user.into()), ("name", name.as_str().into())],
+                ));
+                self.stop_server(&name);
+                let stopping = self
+                    .servers
+                    .iter()
+                    .any(|s| s.config.name == name && s.status == ServerStatus::Stopping);
+                let text = if stopping {
+                    format!("Stopping **{}**…", name)
+                } else {
+                    format!("**{}** isn't running.", name)
+                };
+                self.bot_reply(message_id, text);
+            }
+            BotCommand::WhitelistAdd { player, server } => {
+                let name = match server {
+                    Some(server) => find(&self.servers, &server),
+                    None if self.servers.len() == 1 => Some(self.servers[0].config.name.clone()),
+                    None => {
+                        let mut running = self
+                            .servers
+                            .iter()
+                            .filter(|s| s.status == ServerStatus::Running);
+                        match (running.next(), running.next()) {
+                            (Some(s), None) => Some(s.config.name.clone()),
+                            _ => {
+                                let text = "Which server? `!whitelist add <player> <server>`";
+                                self.bot_reply(message_id, text.to_string());
+                                return;
+                            }
                         }
                     }
+                };
+                let Some(server) =
+                    name.and_then(|n| self.servers.iter().find(|s| s.config.name == n))
+                else {
+                    self.bot_reply(message_id, "No server by that name.".to_string());
+                    return;
+                };
+                let (names, _) = whitelist::parse_names(&player);
+                if names.is_empty() {
+                    self.bot_reply(
+                        message_id,
+                        format!("'{}' isn't a valid Minecraft name.", player),
+                    );
+                    return;
                 }
-            } else {
-                // Just start existing container
-                let cid = container_id.unwrap();
-                if let Err(e) = docker.start_container(&cid).await {
-                    let err = format!("Failed to start container: {}", e);
-                    tx.send(TaskMessage::Log(err.clone())).ok();
-                    tx.send(TaskMessage::ServerStatus {
-                        name,
-                        status: ServerStatus::Error(err),
-                        container_id: Some(cid),
-                    })
-                    .ok();
+                let running = server.status == ServerStatus::Running;
+                if !running && !server.config.server_properties.online_mode {
+                    let text = format!(
+                        "**{}** is an offline-mode server; start it first.",
+                        server.config.name
+                    );
+                    self.bot_reply(message_id, text);
                     return;
                 }
-
-                tx.send(TaskMessage::Log(
-                    "Container started, waiting for MC server to initialize...".to_string(),
-                ))
-                .ok();
-                tx.send(TaskMessage::ServerStatus {
-                    name: name.clone(),
-                    status: ServerStatus::Initializing,
-                    container_id: Some(cid.clone()),
-                })
-                .ok();
-
-                // Poll MC server until it accepts connections
-                Self::poll_mc_server_ready(tx.clone(), name, port, cid, docker).await;
+                let server_name = server.config.name.clone();
+                let address = format!("127.0.0.1:{}", server.config.rcon_port());
+                let password = server.config.rcon_password.clone();
+                self.log(tr_args(
+                    "log-discord-whitelist",
+                    &[
+                        ("user", user.into()),
+                        ("player", player.into()),
+                        ("name", server_name.as_str().into()),
+                    ],
+                ));
+                let target = ReplyTarget::new(&self.settings.discord_bot);
+                self.runtime.spawn(async move {
+                    let text = if running {
+                        let command = format!("whitelist add {}", names[0]);
+                        match rcon::send_command(&address, &password, &command).await {
+                            Ok(output) => output.trim().to_string(),
+                            Err(e) => format!("Whitelisting failed: {}", e),
+                        }
+                    } else {
+                        // Stopped: look the UUID up and write whitelist.json directly
+                        match whitelist::lookup(&names).await {
+                            Ok((found, _)) if found.is_empty() => {
+                                format!("No Minecraft account called '{}'.", names[0])
+                            }
+                            Ok((found, _)) => {
+                                let mut entries = whitelist::load(&server_name).unwrap_or_default();
+                                whitelist::merge(&mut entries, found);
+                                match whitelist::save(&server_name, &entries) {
+                                    Ok(()) => format!(
+                                        "Added {} to the whitelist of **{}**.",
+                                        names[0], server_name
+                                    ),
+                                    Err(e) => format!("Failed to save the whitelist: {}", e),
+                                }
+                            }
+                            Err(e) => format!("Username lookup failed: {}", e),
+                        }
+                    };
+                    if let Err(e) = discord_bot::reply(&target, &message_id, &text).await {
+                        tracing::warn!("Discord bot reply failed: {:#}", e);
+                    }
+                });
             }
-        });
+        }
     }
 
-    fn stop_server(&mut self, name: &str) {
-        let Some(docker) = self.docker.clone() else {
-            self.show_status_message("Docker not connected".to_string());
-            return;
-        };
-
-        // Find server index
-        let server_idx = self.servers.iter().position(|s| s.config.name == name);
-        let Some(idx) = server_idx else {
-            self.show_status_message(format!("Server '{}' not found", name));
+    /// Fetch the featured template catalog in the background
+    fn fetch_template_index(&mut self) {
+        if self.template_index_fetching || !self.settings.template_index.enabled {
             return;
-        };
+        }
+        self.template_index_fetching = true;
+        let url = self.settings.template_index.url.clone();
+        let tx = self.task_tx.clone();
+        self.runtime.spawn(async move {
+            let result = crate::templates::fetch_index(&url)
+                .await
+                .map_err(|e| format!("{:#}", e));
+            tx.send(TaskMessage::TemplateIndexFetched { result }).ok();
+        });
+    }
 
-        // Check if we have a container_id
-        let Some(container_id) = self.servers[idx].container_id.clone() else {
-            self.show_status_message(format!("Server '{}' has no container", name));
+    /// Check every template's pack source in the background; broken ones are
+    /// flagged in the create view
+    fn check_templates(&mut self) {
+        if self.create_view.template_check_running {
             return;
-        };
-
-        // Set status to Stopping
-        self.servers[idx].status = ServerStatus::Stopping;
-        self.log(format!("Stopping server '{}'...", name));
-
-        let server_name = name.to_string();
+        }
+        self.create_view.template_check_running = true;
+        let templates = self.templates.clone();
+        let cf_api_key = self.settings.curseforge_api_key.clone();
+        let mr_token = self.settings.modrinth_token.clone();
         let tx = self.task_tx.clone();
-
-        // Spawn background task
         self.runtime.spawn(async move {
-            match docker.stop_container(&container_id).await {
-                Ok(()) => {
-                    tx.send(TaskMessage::Log(format!(
-                        "Server '{}' stopped successfully!",
-                        server_name
-                    )))
-                    .ok();
-                    tx.send(TaskMessage::ServerStatus {
-                        name: server_name,
-                        status: ServerStatus::Stopped,
-                        container_id: Some(container_id),
-                    })
-                    .ok();
-                }
-                Err(e) => {
-                    let err = format!("Failed to stop: {}", e);
-                    tx.send(TaskMessage::Log(err.clone())).ok();
-                    tx.send(TaskMessage::ServerStatus {
-                        name: server_name,
-                        status: ServerStatus::Error(err),
-                        container_id: Some(container_id),
-                    })
-                    .ok();
-                }
-            }
+            let results = crate::template_check::check_all(templates, cf_api_key, mr_token).await;
+            tx.send(TaskMessage::TemplateHealthChecked { results }).ok();
         });
     }
 
-    fn view_container_logs(&mut self, name: &str) {
-        let Some(docker) = self.docker.clone() else {
-            self.show_status_message("Docker not connected".to_string());
-            return;
-        };
+    /// Pick up added or removed user templates
+    fn reload_templates(&mut self) {
+        self.templates = ModpackTemplate::all_templates(self.settings.template_index.enabled);
+        self.create_view.user_templates = crate::templates::user_template_names();
+    }
 
-        let Some(server) = self.servers.iter().find(|s| s.config.name == name) else {
-            self.show_status_message(format!("Server '{}' not found", name));
+    /// Create a server from a pack on this machine, copying it into the server's
+    /// data folder in the background. The server is removed again if the copy fails.
+    fn import_local_server(
+        &mut self,
+        name: String,
+        template: &ModpackTemplate,
+        port: u16,
+        memory_mb: u64,
+        source: std::path::PathBuf,
+    ) {
+        // Copy progress shares the restore slot, which keeps the server from starting meanwhile
+        if self.restore_progress.is_some() {
+            self.show_status_message(tr("msg-restore-or-import-busy"));
             return;
-        };
-
-        let Some(container_id) = server.container_id.clone() else {
-            self.container_logs =
-                "No container found. Start the server first to see logs.".to_string();
-            self.current_view = View::ContainerLogs(name.to_string());
+        }
+        if let Some(problem) = self
+            .restore_new_name_problem(&name)
+            .or_else(|| self.restore_new_port_problem(port))
+        {
+            self.show_status_message(problem);
             return;
-        };
+        }
 
-        self.container_logs_last_refresh = Some(std::time::Instant::now());
-        self.current_view = View::ContainerLogs(name.to_string());
+        self.create_server(name.clone(), template, port, memory_mb);
+        self.log(tr_args(
+            "log-importing-pack",
+            &[
+                ("source", format!("{:?}", source).into()),
+                ("name", name.as_str().into()),
+            ],
+        ));
+        self.restore_progress = Some((name.clone(), 0, 0, tr("progress-copying-files")));
 
         let tx = self.task_tx.clone();
-        self.runtime.spawn(async move {
-            let logs = docker
-                .get_container_logs(&container_id, 500)
-                .await
-                .unwrap_or_else(|e| format!("Error fetching logs: {}", e));
-            let _ = tx.send(TaskMessage::ContainerLogs(logs));
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.restore_cancel = Some(cancel.clone());
+        std::thread::spawn(move || {
+            let (progress_tx, progress_rx) = std::sync::mpsc::channel::<backup::BackupProgress>();
+            let tx_progress = tx.clone();
+            let name_for_progress = name.clone();
+            std::thread::spawn(move || {
+                while let Ok(progress) = progress_rx.recv() {
+                    let _ = tx_progress.send(TaskMessage::RestoreProgress {
+                        server_name: name_for_progress.clone(),
+                        current: progress.current,
+                        total: progress.total,
+                        current_file: progress.current_file,
+                    });
+                }
+            });
+
+            let result = crate::local_pack::copy_into(
+                &source,
+                &get_server_data_path(&name),
+                Some(progress_tx),
+                Some(&cancel),
+            );
+            let _ = tx.send(TaskMessage::LocalImportComplete {
+                server_name: name,
+                result: result.map_err(|e| e.to_string()),
+            });
         });
     }
 
-    /// Refresh container logs without changing view (for auto-refresh)
-    fn refresh_container_logs(&mut self, name: &str) {
-        let Some(docker) = self.docker.clone() else {
+    fn start_edit_server(&mut self, name: &str) {
+        self.open_server_tab(name, DetailsTab::Settings);
+    }
+
+    /// Fill the Settings and Schedules tabs from the saved config, dropping unsaved edits
+    fn load_edit_view(&mut self, name: &str) {
+        if let Some(server) = self.servers.iter().find(|s| s.config.name == name) {
+            self.edit_view.load_from_config(&server.config);
+        }
+    }
+
+    fn save_server_edit(&mut self, name: &str, result: ServerEditResult) {
+        if let Some(server) = self.servers.iter_mut().find(|s| s.config.name == name) {
+            let port_changed = server.config.port != result.port;
+            let memory_changed = server.config.memory_mb != result.memory_mb;
+            let args_changed = server.config.java_args != result.java_args;
+            let props_changed = server.config.server_properties != result.server_properties;
+            let modpack_changed = server.config.modpack != result.modpack;
+            let java_ver_changed = server.config.java_version != result.java_version;
+            let env_changed = server.config.extra_env != result.extra_env
+                || server.config.world_url != result.world_url;
+            let extra_ports_changed = server.config.extra_ports != result.extra_ports
+                || server.config.bind_address != result.bind_address;
+            let gc_logging_changed = server.config.gc_logging != result.gc_logging;
+
+            server.config.port = result.port;
+            server.config.memory_mb = result.memory_mb;
+            server.config.java_args = result.java_args;
+            server.config.server_properties = result.server_properties;
+            server.config.modpack = result.modpack;
+            server.config.java_version = result.java_version;
+            server.config.extra_env = result.extra_env;
+            server.config.world_url = result.world_url;
+            server.config.extra_ports = result.extra_ports;
+            server.config.bind_address = result.bind_address;
+            server.config.gc_logging = result.gc_logging;
+            server.config.backup_group = result.backup_group;
+            server.config.backup_excludes = result.backup_excludes;
+
+            // If any settings changed, we need to recreate the container
+            if port_changed
+                || memory_changed
+                || args_changed
+                || props_changed
+                || modpack_changed
+                || java_ver_changed
+                || env_changed
+                || extra_ports_changed
+                || gc_logging_changed
+            {
+                // Clear container_id to force recreation on next start
+                server.container_id = None;
+            }
+
+            self.save_servers();
+            self.show_status_message(tr_args("msg-server-updated", &[("name", name.into())]));
+        }
+        self.load_edit_view(name);
+    }
+
+    fn save_server_schedules(&mut self, name: &str, result: ScheduleResult) {
+        if let Some(server) = self.servers.iter_mut().find(|s| s.config.name == name) {
+            // Broadcasts run over RCON and backups on the host, so the container stays
+            server.config.broadcasts = result.broadcasts;
+            server.config.backup_schedule = result.backup_schedule;
+            server.config.remote_backup = result.remote_backup;
+            self.save_servers();
+            self.show_status_message(tr_args("msg-schedules-updated", &[("name", name.into())]));
+        }
+    }
+
+    /// Summarize the GC log of the run that just ended and keep it for comparing collectors
+    /// Keep how long a start took, for the "usually ready in" estimate
+    fn record_startup_time(&mut self, name: &str, took: Duration) {
+        let Some(server) = self.servers.iter_mut().find(|s| s.config.name == name) else {
             return;
         };
+        let history = &mut server.config.startup_history;
+        history.push(took.as_secs());
+        let excess = history.len().saturating_sub(startup::STARTUP_HISTORY_LEN);
+        history.drain(..excess);
+        self.save_servers();
+        self.log(tr_args(
+            "log-start-time",
+            &[("name", name.into()), ("seconds", took.as_secs().into())],
+        ));
+    }
 
-        let Some(server) = self.servers.iter().find(|s| s.config.name == name) else {
+    fn record_gc_benchmark(&mut self, name: &str) {
+        /// Older runs are dropped past this many per server
+        const MAX_BENCHMARKS: usize = 30;
+
+        let Some(server) = self.servers.iter_mut().find(|s| s.config.name == name) else {
             return;
         };
-
-        let Some(container_id) = server.container_id.clone() else {
+        if !server.config.gc_logging {
+            return;
+        }
+        let log_path = get_server_data_path(name).join(jvm::GC_LOG_FILE);
+        let Ok(text) = std::fs::read_to_string(&log_path) else {
+            return;
+        };
+        // Move the log aside so a second stop event can't count the same run twice
+        std::fs::rename(&log_path, log_path.with_extension("log.recorded")).ok();
+        let Some((uptime_secs, pauses)) = jvm::parse_gc_log(&text) else {
             return;
         };
 
-        self.container_logs_last_refresh = Some(std::time::Instant::now());
-        let tx = self.task_tx.clone();
+        let config = &mut server.config;
+        let collector = GarbageCollector::effective(&config.java_args, config.java_version);
+        let bench = GcBenchmark::from_pauses(
+            collector,
+            config.java_version,
+            config.memory_mb,
+            uptime_secs,
+            pauses,
+        );
+        let summary = tr_args(
+            "log-gc-benchmark",
+            &[
+                ("collector", bench.collector.to_string().into()),
+                ("name", name.into()),
+                ("pauses", bench.pauses.into()),
+                ("avg", format!("{:.1}", bench.avg_pause_ms).into()),
+                ("p99", format!("{:.1}", bench.p99_pause_ms).into()),
+                ("max", format!("{:.1}", bench.max_pause_ms).into()),
+            ],
+        );
+        config.gc_benchmarks.push(bench);
+        let excess = config.gc_benchmarks.len().saturating_sub(MAX_BENCHMARKS);
+        config.gc_benchmarks.drain(..excess);
 
-        self.runtime.spawn(async move {
-            let logs = docker
-                .get_container_logs(&container_id, 500)
-                .await
-                .unwrap_or_else(|e| format!("Error fetching logs: {}", e));
-            let _ = tx.send(TaskMessage::ContainerLogs(logs));
-        });
+        self.save_servers();
+        self.log(summary);
     }
 
-    fn load_all_docker_logs(&mut self) {
-        let Some(docker) = self.docker.clone() else {
-            self.show_status_message("Docker not connected".to_string());
+    /// Record the user's one-time acceptance of the Minecraft EULA
+    fn accept_eula(&mut self) {
+        self.settings.eula_accepted_at = Some(chrono::Local::now().to_rfc3339());
+        if let Err(e) = save_settings(&self.settings) {
+            self.show_status_message(tr_args(
+                "msg-settings-save-failed",
+                &[("error", e.to_string().into())],
+            ));
+        }
+        self.log(tr("log-eula-accepted"));
+    }
+
+    /// Run the preflight checklist for a server (disk, RAM, Docker storage, ports, API keys)
+    /// in the background; the report comes back as `PreflightDone`
+    fn run_preflight(&mut self, name: &str) {
+        let Some(server) = self.servers.iter().find(|s| s.config.name == name) else {
             return;
         };
+        if !self.preflight_running.insert(name.to_string()) {
+            return;
+        }
+        let config = server.config.clone();
+        let data_path = get_server_data_path(name);
 
-        self.docker_logs_last_refresh = Some(std::time::Instant::now());
-        self.current_view = View::DockerLogs;
+        let port_conflicts: Vec<_> = config
+            .all_ports()
+            .into_iter()
+            .map(|port| (port, self.check_port_conflict(port, name)))
+            .collect();
+        let host_memory = memory::host_memory();
+        let others_mb = memory::allocated_by_active_servers(&self.servers, name);
+        let has_cf_key = self
+            .settings
+            .curseforge_api_key
+            .as_ref()
+            .is_some_and(|k| !k.is_empty());
 
+        let docker = self.docker.clone();
         let tx = self.task_tx.clone();
-
-        // Fetch logs in background to avoid UI freeze
+        let name = name.to_string();
         self.runtime.spawn(async move {
-            let logs = docker
-                .get_all_managed_logs(200)
-                .await
-                .unwrap_or_else(|e| format!("Error fetching logs: {}", e));
-            let _ = tx.send(TaskMessage::DockerLogs(logs));
+            // An unresponsive daemon only leaves the storage check without data
+            let docker_root_dir = match docker {
+                Some(docker) => {
+                    tokio::time::timeout(Duration::from_secs(3), docker.docker_root_dir())
+                        .await
+                        .ok()
+                        .and_then(|r| r.ok())
+                        .flatten()
+                }
+                None => None,
+            };
+            let report = tokio::task::spawn_blocking(move || {
+                preflight::run(&preflight::PreflightInputs {
+                    config: &config,
+                    data_path: &data_path,
+                    host_memory,
+                    others_mb,
+                    port_conflicts,
+                    docker_root_dir,
+                    has_cf_key,
+                })
+            })
+            .await;
+            if let Ok(report) = report {
+                tx.send(TaskMessage::PreflightDone { name, report }).ok();
+            }
         });
     }
 
-    /// Refresh Docker logs without changing view (for auto-refresh)
-    fn refresh_docker_logs(&mut self) {
-        let Some(docker) = self.docker.clone() else {
-            return;
-        };
-
-        self.docker_logs_last_refresh = Some(std::time::Instant::now());
-        let tx = self.task_tx.clone();
+    /// Launch the server if every preflight check passed, otherwise show the checklist
+    fn finish_preflight(&mut self, name: &str, report: PreflightReport) {
+        self.preflight_running.remove(name);
+        let bot_message = self.bot_start_replies.remove(name);
+        let on_checklist = matches!(&self.current_view, View::Preflight(n) if n == name);
+        if report.all_passed() {
+            self.preflight_report = None;
+            if on_checklist {
+                self.current_view = View::Dashboard;
+            }
+            self.launch_server(name);
+            if let Some(message_id) = bot_message {
+                let text = self.bot_start_text(name);
+                self.bot_reply(message_id, text);
+            }
+        } else {
+            if let Some(message_id) = bot_message {
+                let problems: Vec<String> = report
+                    .checks
+                    .iter()
+                    .filter(|c| c.status != CheckStatus::Pass)
+                    .map(|c| format!("- {}: {}", c.name, c.detail))
+                    .collect();
+                self.bot_reply(
+                    message_id,
+                    format!(
+                        "**{}** is held back by preflight checks:\n{}",
+                        name,
+                        problems.join("\n")
+                    ),
+                );
+            }
+            for check in report
+                .checks
+                .iter()
+                .filter(|c| c.status != CheckStatus::Pass)
+            {
+                self.log(tr_args(
+                    "log-preflight",
+                    &[
+                        ("name", name.into()),
+                        ("check", check.name.as_str().into()),
+                        ("detail", check.detail.as_str().into()),
+                    ],
+                ));
+            }
+            self.preflight_report = Some(report);
+            self.current_view = View::Preflight(name.to_string());
+        }
+    }
 
-        self.runtime.spawn(async move {
-            let logs = docker
-                .get_all_managed_logs(200)
-                .await
-                .unwrap_or_else(|e| format!("Error fetching logs: {}", e));
-            let _ = tx.send(TaskMessage::DockerLogs(logs));
+    /// Channel for a pack install's progress, forwarded to the UI by a thread;
+    /// notices go to the log
+    fn pack_progress_sender(
+        tx: &mpsc::Sender<TaskMessage>,
+        name: &str,
+    ) -> mpsc::Sender<crate::pack_installer::PackProgress> {
+        let (progress_tx, progress_rx) = mpsc::channel();
+        let tx = tx.clone();
+        let name = name.to_string();
+        std::thread::spawn(move || {
+            while let Ok(progress) = progress_rx.recv() {
+                let msg = match progress {
+                    crate::pack_installer::PackProgress::Notice(notice) => {
+                        TaskMessage::Log(format!("WARNING: '{}': {}", name, notice))
+                    }
+                    progress => TaskMessage::PackInstallProgress {
+                        server_name: name.clone(),
+                        progress,
+                    },
+                };
+                let _ = tx.send(msg);
+            }
         });
+        progress_tx
     }
 
-    fn delete_server(&mut self, name: &str) {
-        let Some(docker) = self.docker.clone() else {
-            self.show_status_message("Docker not connected".to_string());
+    /// Start a server after the EULA and preflight checks pass.
+    /// Any warning or failure shows the preflight checklist instead.
+    fn start_server(&mut self, name: &str) {
+        if self.docker.is_none() {
+            self.show_status_message(tr("msg-docker-not-connected"));
             return;
-        };
+        }
+        if self.data_move_progress.is_some() {
+            self.show_status_message(tr("msg-data-moving"));
+            return;
+        }
 
-        // Find and remove the server
+        // Find server index
         let server_idx = self.servers.iter().position(|s| s.config.name == name);
         let Some(idx) = server_idx else {
-            self.show_status_message(format!("Server '{}' not found", name));
+            self.show_status_message(tr_args("msg-server-not-found", &[("name", name.into())]));
             return;
         };
 
-        let server = self.servers.remove(idx);
-
-        // Remove container if it exists
-        if let Some(container_id) = server.container_id {
-            let _ = self.runtime.block_on(async {
-                // Try to stop first (ignore errors - might already be stopped)
-                let _ = docker.stop_container(&container_id).await;
-                docker.remove_container(&container_id).await
-            });
-        }
-
-        self.save_servers();
-        self.refresh_orphaned_dirs();
-        self.show_status_message(format!("Server '{}' deleted", name));
-        self.current_view = View::Dashboard;
-    }
-
-    fn refresh_orphaned_dirs(&mut self) {
-        self.orphaned_dirs = find_orphaned_server_dirs(&self.servers);
-    }
-
-    fn adopt_server(&mut self, name: &str) {
-        let modpack = ModpackInfo {
-            name: "Unknown".to_string(),
-            version: "Unknown".to_string(),
-            minecraft_version: String::new(),
-            loader: crate::server::ModLoader::Vanilla,
-            source: crate::server::ModpackSource::Local {
-                path: ".".to_string(),
-            },
-        };
-        let config = ServerConfig::new(name.to_string(), modpack);
-        let instance = ServerInstance {
-            config,
-            container_id: None,
-            status: ServerStatus::Stopped,
-        };
-        self.servers.push(instance);
-        self.save_servers();
-        self.refresh_orphaned_dirs();
-        self.show_status_message(format!("Adopted server '{}'", name));
-        self.start_edit_server(name);
-    }
-
-    fn delete_orphan(&mut self, name: &str) {
-        let server_path = get_server_path(name);
-        if server_path.exists() {
-            if let Err(e) = std::fs::remove_dir_all(&server_path) {
-                self.show_status_message(format!("Failed to delete '{}': {}", name, e));
-                return;
-            }
+        // The EULA must be accepted once by the user, and is then recorded per server
+        if self.settings.eula_accepted_at.is_none() {
+            self.current_view = View::ConfirmEula(name.to_string());
+            return;
         }
-
-        let backup_path = get_backup_path(name);
-        if backup_path.exists() {
-            let _ = std::fs::remove_dir_all(&backup_path);
+        if self.servers[idx].config.eula_accepted_at.is_none() {
+            self.servers[idx].config.eula_accepted_at = self.settings.eula_accepted_at.clone();
+            self.log(tr_args("log-eula-recorded", &[("name", name.into())]));
+            self.save_servers();
         }
 
-        self.refresh_orphaned_dirs();
-        self.show_status_message(format!("Deleted orphaned directory '{}'", name));
+        self.run_preflight(name);
     }
 
-    fn remove_container_and_start(&mut self, name: &str) {
-        let Some(docker) = &self.docker else {
-            self.show_status_message("Docker not connected".to_string());
+    /// Pull, create, and start the container. Callers are expected to have run preflight.
+    fn launch_server(&mut self, name: &str) {
+        let Some(docker) = self.docker.clone() else {
+            self.show_status_message(tr("msg-docker-not-connected"));
             return;
         };
-        let docker = docker.clone();
-        let container_name = get_container_name(name);
 
-        let result = self.runtime.block_on(async {
-            // Try to stop first (ignore errors — may already be stopped)
-            let _ = docker.stop_container(&container_name).await;
-            docker.remove_container(&container_name).await
-        });
+        let server_idx = self.servers.iter().position(|s| s.config.name == name);
+        let Some(idx) = server_idx else {
+            self.show_status_message(tr_args("msg-server-not-found", &[("name", name.into())]));
+            return;
+        };
 
-        match result {
-            Ok(()) => {
-                self.log(format!(
-                    "Removed old container '{}', recreating...",
-                    container_name
-                ));
-                self.current_view = View::Dashboard;
-                self.start_server(name);
-            }
-            Err(e) => {
-                self.show_status_message(format!(
-                    "Failed to remove container '{}': {}",
-                    container_name, e
-                ));
-                self.current_view = View::Dashboard;
-            }
-        }
-    }
+        let port = self.servers[idx].config.port;
+        let host_ip = self.servers[idx].config.host_ip();
+        let local_address = self.servers[idx].config.local_address();
+        let rcon_port = self.servers[idx].config.rcon_port();
+        let extra_ports = self.servers[idx].config.extra_ports.clone();
 
-    fn create_backup(&mut self, name: &str) {
-        // Check if a backup is already in progress
-        if self.backup_progress.is_some() {
-            self.show_status_message("A backup is already in progress".to_string());
+        // Create data directory if needed
+        let data_path = get_server_data_path(name);
+        if let Err(e) = std::fs::create_dir_all(&data_path) {
+            self.servers[idx].status =
+                ServerStatus::Error(format!("Failed to create data dir: {}", e));
+            self.show_status_message(tr_args(
+                "msg-data-dir-failed",
+                &[("error", e.to_string().into())],
+            ));
             return;
         }
 
-        self.log(format!("Creating backup for '{}'...", name));
-        self.backup_progress = Some((name.to_string(), 0, 0, "Counting files...".to_string()));
+        // Determine if we need to pull/create or just start
+        let needs_container = self.servers[idx].container_id.is_none();
+        let container_id = self.servers[idx].container_id.clone();
+        let container_name = get_container_name(&self.settings.instance_id, name);
+        let mut env_vars = self.servers[idx].config.build_docker_env();
+
+        // Add CurseForge API key if configured
+        if let Some(cf_key) = &self.settings.curseforge_api_key {
+            if !cf_key.is_empty() {
+                env_vars.push(format!("CF_API_KEY={}", cf_key));
+            }
+        }
 
+        let memory_mb = self.servers[idx].config.memory_mb;
+        let docker_image = self.servers[idx].config.docker_image();
+        let modpack_source = self.servers[idx].config.modpack.source.clone();
+        let download_limit_kbps = self.settings.download_limit_kbps;
+        let cf_api_key = self
+            .settings
+            .curseforge_api_key
+            .clone()
+            .filter(|k| !k.is_empty());
         let server_name = name.to_string();
         let tx = self.task_tx.clone();
 
-        // Run backup in background thread (not async, since it's CPU/IO bound)
-        std::thread::spawn(move || {
-            let (progress_tx, progress_rx) = std::sync::mpsc::channel::<backup::BackupProgress>();
+        // Set initial status
+        if needs_container {
+            self.servers[idx].status = ServerStatus::Pulling;
+            self.log(tr_args("log-pulling", &[("name", name.into())]));
+        } else {
+            self.servers[idx].status = ServerStatus::Starting;
+            self.log(tr_args("log-starting", &[("name", name.into())]));
+        }
 
-            // Spawn a thread to forward progress updates
-            let tx_progress = tx.clone();
-            let name_for_progress = server_name.clone();
-            std::thread::spawn(move || {
-                while let Ok(progress) = progress_rx.recv() {
-                    let _ = tx_progress.send(TaskMessage::BackupProgress {
-                        server_name: name_for_progress.clone(),
-                        current: progress.current,
-                        total: progress.total,
-                        current_file: progress.current_file,
-                    });
+        // Spawn background task
+        self.runtime.spawn(async move {
+            let name = server_name.clone();
+
+            // Pull image if needed
+            if needs_container {
+                tx.send(TaskMessage::Log(tr_args(
+                    "log-checking-image",
+                    &[("image", docker_image.as_str().into())],
+                )))
+                .ok();
+
+                if let Err(e) = docker.ensure_image(&docker_image).await {
+                    let err = tr_args("log-pull-failed", &[("error", e.to_string().into())]);
+                    tx.send(TaskMessage::Log(err.clone())).ok();
+                    tx.send(TaskMessage::ServerStatus {
+                        name,
+                        status: ServerStatus::Error(err),
+                        container_id: None,
+                    })
+                    .ok();
+                    return;
                 }
-            });
+                tx.send(TaskMessage::Log(tr_args(
+                    "log-image-ready",
+                    &[("image", docker_image.as_str().into())],
+                )))
+                .ok();
 
-            let result = backup::create_backup_with_progress(&server_name, Some(progress_tx));
-            let _ = tx.send(TaskMessage::BackupComplete {
-                server_name,
-                result: result.map_err(|e| e.to_string()),
-            });
-        });
-    }
+                // Install modpack files on host if needed (ForgeWithPack)
+                if let crate::server::ModpackSource::ForgeWithPack {
+                    pack_url,
+                    pack_sha1,
+                    ..
+                } = &modpack_source
+                {
+                    tx.send(TaskMessage::Log(tr("log-installing-server-pack")))
+                        .ok();
 
-    fn view_backups(&mut self, name: &str) {
-        match backup::list_backups(name) {
-            Ok(backups) => {
-                self.backup_list = backups;
-                self.current_view = View::Backups(name.to_string());
-            }
-            Err(e) => {
-                self.show_status_message(format!("Failed to list backups: {}", e));
-            }
-        }
-    }
+                    let progress_tx = Self::pack_progress_sender(&tx, &name);
+
+                    // Templates without a checksum get the one CurseForge lists for the file
+                    let mut sha1 = pack_sha1.clone();
+                    if sha1.is_none() && !crate::pack_installer::is_pack_installed(&data_path) {
+                        let file_id = curseforge::file_id_from_cdn_url(pack_url);
+                        if let (Some(file_id), Some(key)) = (file_id, cf_api_key.as_deref()) {
+                            match curseforge::get_file(key, file_id).await {
+                                Ok(file) => sha1 = file.sha1().map(str::to_string),
+                                Err(e) => {
+                                    tx.send(TaskMessage::Log(format!(
+                                        "WARNING: {}",
+                                        tr_args(
+                                            "log-pack-unverified",
+                                            &[
+                                                ("name", name.as_str().into()),
+                                                ("error", e.to_string().into())
+                                            ]
+                                        )
+                                    )))
+                                    .ok();
+                                }
+                            }
+                        }
+                    }
 
-    fn restore_backup(&mut self, name: &str, backup_path: &std::path::Path) {
-        // Check if a restore is already in progress
-        if self.restore_progress.is_some() {
-            self.show_status_message("A restore is already in progress".to_string());
-            return;
-        }
+                    let options = crate::pack_installer::PackDownloadOptions {
+                        sha1,
+                        limit_kbps: download_limit_kbps,
+                        cache_dir: Some(get_download_cache_path()),
+                    };
+                    if let Err(e) = crate::pack_installer::install_forge_pack(
+                        &data_path,
+                        pack_url,
+                        &options,
+                        Some(progress_tx),
+                    )
+                    .await
+                    {
+                        let err =
+                            tr_args("log-server-pack-failed", &[("error", e.to_string().into())]);
+                        tx.send(TaskMessage::Log(err.clone())).ok();
+                        tx.send(TaskMessage::ServerStatus {
+                            name,
+                            status: ServerStatus::Error(err),
+                            container_id: None,
+                        })
+                        .ok();
+                        return;
+                    }
+                    tx.send(TaskMessage::Log(tr("log-server-pack-installed")))
+                        .ok();
+                }
 
-        self.log(format!("Restoring backup for '{}'...", name));
-        self.restore_progress = Some((name.to_string(), 0, 0, "Starting restore...".to_string()));
-        self.current_view = View::Dashboard;
+                // Packs installed on the host, whose manifest or libraries name
+                // the loader the container has to install
+                let options = crate::pack_installer::PackDownloadOptions {
+                    sha1: None,
+                    limit_kbps: download_limit_kbps,
+                    cache_dir: Some(get_download_cache_path()),
+                };
+                let host_install = match &modpack_source {
+                    crate::server::ModpackSource::CurseForgeManifest { slug, file_id } => {
+                        tx.send(TaskMessage::Log(tr("log-installing-cf-pack"))).ok();
+                        let progress_tx = Self::pack_progress_sender(&tx, &name);
+                        let result: anyhow::Result<_> = async {
+                            let key = cf_api_key
+                                .as_deref()
+                                .ok_or_else(|| anyhow::anyhow!(tr("log-cf-key-needed")))?;
+                            let mut file_id = *file_id;
+                            if file_id == 0 && !crate::pack_installer::is_pack_installed(&data_path)
+                            {
+                                let pack = curseforge::get_modpack_by_slug(key, slug).await?;
+                                file_id = curseforge::get_mod_files(key, pack.id)
+                                    .await?
+                                    .into_iter()
+                                    .max_by(|a, b| a.file_date.cmp(&b.file_date))
+                                    .map(|f| f.id)
+                                    .ok_or_else(|| anyhow::anyhow!("The modpack has no files"))?;
+                            }
+                            crate::pack_installer::install_cf_manifest(
+                                &data_path,
+                                key,
+                                file_id,
+                                &options,
+                                Some(progress_tx),
+                            )
+                            .await
+                            .map(Some)
+                        }
+                        .await;
+                        Some(result)
+                    }
+                    crate::server::ModpackSource::CurseForgeServerPack {
+                        server_pack_file_id,
+                        ..
+                    } => {
+                        tx.send(TaskMessage::Log(tr("log-installing-cf-server-pack")))
+                            .ok();
+                        let progress_tx = Self::pack_progress_sender(&tx, &name);
+                        let result = match cf_api_key.as_deref() {
+                            Some(key) => {
+                                crate::pack_installer::install_cf_server_pack(
+                                    &data_path,
+                                    key,
+                                    *server_pack_file_id,
+                                    &options,
+                                    Some(progress_tx),
+                                )
+                                .await
+                            }
+                            None => Err(anyhow::anyhow!(tr("log-cf-key-needed"))),
+                        };
+                        Some(result)
+                    }
+                    crate::server::ModpackSource::MrPackFile { path_or_url } => {
+                        tx.send(TaskMessage::Log(tr("log-installing-mrpack"))).ok();
+                        let progress_tx = Self::pack_progress_sender(&tx, &name);
+                        Some(
+                            crate::pack_installer::install_mrpack(
+                                &data_path,
+                                path_or_url,
+                                &options,
+                                Some(progress_tx),
+                            )
+                            .await
+                            .map(Some),
+                        )
+                    }
+                    // A CurseForge client zip imported from a file still needs its mods
+                    crate::server::ModpackSource::Local { path }
+                        if crate::local_pack::is_cf_client_zip(&data_path.join(path)) =>
+                    {
+                        tx.send(TaskMessage::Log(tr("log-installing-cf-pack"))).ok();
+                        let progress_tx = Self::pack_progress_sender(&tx, &name);
+                        let result = match cf_api_key.as_deref() {
+                            Some(key) => crate::pack_installer::install_cf_client_zip(
+                                &data_path,
+                                key,
+                                path,
+                                &options,
+                                Some(progress_tx),
+                            )
+                            .await
+                            .map(Some),
+                            None => Err(anyhow::anyhow!(tr("log-cf-key-needed"))),
+                        };
+                        // The image would otherwise unpack the client zip over the install
+                        env_vars.retain(|e| !e.starts_with("MODPACK="));
+                        Some(result)
+                    }
+                    _ => None,
+                };
+                match host_install {
+                    Some(Ok(Some(loader))) => {
+                        env_vars.retain(|e| !e.starts_with("TYPE=") && !e.starts_with("VERSION="));
+                        env_vars.extend(loader.docker_env());
+                        tx.send(TaskMessage::Log(tr_args(
+                            "log-modpack-installed",
+                            &[
+                                ("minecraft", loader.minecraft_version.as_str().into()),
+                                ("loader", format!("{:?}", loader.loader).into()),
+                                ("version", loader.loader_version.as_str().into()),
+                            ],
+                        )))
+                        .ok();
+                    }
+                    Some(Ok(None)) => {
+                        tx.send(TaskMessage::Log(tr("log-modpack-installed-unknown-loader")))
+                            .ok();
+                    }
+                    Some(Err(e)) => {
+                        let err = tr_args(
+                            "log-modpack-failed",
+                            &[("error", format!("{:#}", e).into())],
+                        );
+                        tx.send(TaskMessage::Log(err.clone())).ok();
+                        tx.send(TaskMessage::ServerStatus {
+                            name,
+                            status: ServerStatus::Error(err),
+                            container_id: None,
+                        })
+                        .ok();
+                        return;
+                    }
+                    None => {}
+                }
 
-        let server_name = name.to_string();
-        let backup_path = backup_path.to_path_buf();
-        let tx = self.task_tx.clone();
+                // Update status to Starting
+                tx.send(TaskMessage::ServerStatus {
+                    name: name.clone(),
+                    status: ServerStatus::Starting,
+                    container_id: None,
+                })
+                .ok();
 
-        // Run restore in background thread
-        std::thread::spawn(move || {
-            let (progress_tx, progress_rx) = std::sync::mpsc::channel::<backup::BackupProgress>();
+                // Create container
+                tx.send(TaskMessage::Log(tr_args(
+                    "log-creating-container",
+                    &[("container", container_name.as_str().into())],
+                )))
+                .ok();
+                match docker
+                    .create_minecraft_container(crate::docker::CreateContainerParams {
+                        container_name: &container_name,
+                        server_name: &name,
+                        image: &docker_image,
+                        port,
+                        host_ip: &host_ip,
+                        rcon_port,
+                        extra_ports: &extra_ports,
+                        memory_mb,
+                        env_vars,
+                        data_path: &data_path,
+                    })
+                    .await
+                {
+                    Ok(new_container_id) => {
+                        tx.send(TaskMessage::Log(tr_args(
+                            "log-created-container",
+                            &[("container", new_container_id.as_str().into())],
+                        )))
+                        .ok();
 
-            // Spawn a thread to forward progress updates
-            let tx_progress = tx.clone();
-            let name_for_progress = server_name.clone();
-            std::thread::spawn(move || {
-                while let Ok(progress) = progress_rx.recv() {
-                    let _ = tx_progress.send(TaskMessage::RestoreProgress {
-                        server_name: name_for_progress.clone(),
-                        current: progress.current,
-                        total: progress.total,
-                        current_file: progress.current_file,
-                    });
+                        // Start the new container
+                        if let Err(e) = docker.start_container(&new_container_id).await {
+                            let err = tr_args(
+                                "log-container-start-failed",
+                                &[("error", e.to_string().into())],
+                            );
+                            tx.send(TaskMessage::Log(err.clone())).ok();
+                            tx.send(TaskMessage::ServerStatus {
+                                name,
+                                status: ServerStatus::Error(err),
+                                container_id: Some(new_container_id),
+                            })
+                            .ok();
+                            return;
+                        }
+
+                        tx.send(TaskMessage::Log(tr("log-container-started"))).ok();
+                        tx.send(TaskMessage::ServerStatus {
+                            name: name.clone(),
+                            status: ServerStatus::Initializing,
+                            container_id: Some(new_container_id.clone()),
+                        })
+                        .ok();
+
+                        // Poll MC server until it accepts connections
+                        Self::poll_mc_server_ready(
+                            tx.clone(),
+                            name,
+                            local_address,
+                            new_container_id,
+                            docker,
+                        )
+                        .await;
+                    }
+                    Err(e) => {
+                        let err_str = format!("{}", e);
+                        if err_str.contains("status code 409") {
+                            tx.send(TaskMessage::Log(tr_args(
+                                "log-container-name-conflict",
+                                &[("name", name.as_str().into())],
+                            )))
+                            .ok();
+                            tx.send(TaskMessage::ContainerConflict { server_name: name })
+                                .ok();
+                        } else {
+                            let err = tr_args(
+                                "log-container-create-failed",
+                                &[("error", e.to_string().into())],
+                            );
+                            tx.send(TaskMessage::Log(err.clone())).ok();
+                            tx.send(TaskMessage::ServerStatus {
+                                name,
+                                status: ServerStatus::Error(err),
+                                container_id: None,
+                            })
+                            .ok();
+                        }
+                    }
+                }
+            } else {
+                // Just start existing container
+                let cid = container_id.unwrap();
+                if let Err(e) = docker.start_container(&cid).await {
+                    let err = tr_args(
+                        "log-container-start-failed",
+                        &[("error", e.to_string().into())],
+                    );
+                    tx.send(TaskMessage::Log(err.clone())).ok();
+                    tx.send(TaskMessage::ServerStatus {
+                        name,
+                        status: ServerStatus::Error(err),
+                        container_id: Some(cid),
+                    })
+                    .ok();
+                    return;
                 }
-            });
 
-            let result =
-                backup::restore_backup_with_progress(&server_name, &backup_path, Some(progress_tx));
-            let _ = tx.send(TaskMessage::RestoreComplete {
-                server_name,
-                result: result.map_err(|e| e.to_string()),
-            });
-        });
-    }
+                tx.send(TaskMessage::Log(tr("log-container-started"))).ok();
+                tx.send(TaskMessage::ServerStatus {
+                    name: name.clone(),
+                    status: ServerStatus::Initializing,
+                    container_id: Some(cid.clone()),
+                })
+                .ok();
 
-    fn delete_backup(&mut self, name: &str, backup_path: &std::path::Path) {
-        match backup::delete_backup(backup_path) {
-            Ok(()) => {
-                self.show_status_message("Backup deleted".to_string());
-                // Refresh the backup list
-                self.view_backups(name);
-            }
-            Err(e) => {
-                self.show_status_message(format!("Failed to delete backup: {}", e));
+                // Poll MC server until it accepts connections
+                Self::poll_mc_server_ready(tx.clone(), name, local_address, cid, docker).await;
             }
-        }
+        });
     }
 
-    fn export_server(&mut self, name: &str) {
-        // Check if an export is already in progress
-        if self.export_progress.is_some() {
-            self.show_status_message("An export is already in progress".to_string());
+    fn stop_server(&mut self, name: &str) {
+        let Some(docker) = self.docker.clone() else {
+            self.show_status_message(tr("msg-docker-not-connected"));
             return;
-        }
+        };
 
-        let Some(server) = self.servers.iter().find(|s| s.config.name == name) else {
-            self.show_status_message(format!("Server '{}' not found", name));
+        // Find server index
+        let server_idx = self.servers.iter().position(|s| s.config.name == name);
+        let Some(idx) = server_idx else {
+            self.show_status_message(tr_args("msg-server-not-found", &[("name", name.into())]));
             return;
         };
-        let config = server.config.clone();
-        let data_path = get_server_data_path(name);
-
-        // Open native save dialog
-        let default_name = format!("{}.drakonixanvil-server.zip", name);
-        let save_path = rfd::FileDialog::new()
-            .set_file_name(&default_name)
-            .add_filter("DrakonixAnvil Server", &["zip"])
-            .save_file();
 
-        let Some(output_path) = save_path else {
-            return; // User cancelled
+        // Check if we have a container_id
+        let Some(container_id) = self.servers[idx].container_id.clone() else {
+            self.show_status_message(tr_args("msg-no-container", &[("name", name.into())]));
+            return;
         };
 
-        self.log(format!("Exporting server '{}'...", name));
-        self.export_progress = Some((name.to_string(), 0, 0, "Counting files...".to_string()));
+        // A paused container can't handle the stop signal until it's resumed
+        let frozen = self.servers[idx].status == ServerStatus::Frozen;
+
+        // Set status to Stopping
+        self.servers[idx].status = ServerStatus::Stopping;
+        self.log(tr_args("log-stopping", &[("name", name.into())]));
 
         let server_name = name.to_string();
         let tx = self.task_tx.clone();
 
-        std::thread::spawn(move || {
-            let (progress_tx, progress_rx) = std::sync::mpsc::channel::<backup::BackupProgress>();
-
-            let tx_progress = tx.clone();
-            let name_for_progress = server_name.clone();
-            std::thread::spawn(move || {
-                while let Ok(progress) = progress_rx.recv() {
-                    let _ = tx_progress.send(TaskMessage::ExportProgress {
-                        server_name: name_for_progress.clone(),
-                        current: progress.current,
-                        total: progress.total,
-                        current_file: progress.current_file,
-                    });
+        // Spawn background task
+        self.runtime.spawn(async move {
+            if frozen {
+                if let Err(e) = docker.unpause_container(&container_id).await {
+                    tx.send(TaskMessage::Log(tr_args(
+                        "log-unpause-failed",
+                        &[("error", e.to_string().into())],
+                    )))
+                    .ok();
                 }
-            });
+            }
+            match docker.stop_container(&container_id).await {
+                Ok(()) => {
+                    tx.send(TaskMessage::Log(tr_args(
+                        "log-stopped",
+                        &[("name", server_name.as_str().into())],
+                    )))
+                    .ok();
+                    tx.send(TaskMessage::ServerStatus {
+                        name: server_name,
+                        status: ServerStatus::Stopped,
+                        container_id: Some(container_id),
+                    })
+                    .ok();
+                }
+                Err(e) => {
+                    let err = tr_args("log-stop-failed", &[("error", e.to_string().into())]);
+                    tx.send(TaskMessage::Log(err.clone())).ok();
+                    tx.send(TaskMessage::ServerStatus {
+                        name: server_name,
+                        status: ServerStatus::Error(err),
+                        container_id: Some(container_id),
+                    })
+                    .ok();
+                }
+            }
+        });
+    }
 
-            let result = backup::export_server_with_progress(
-                &config,
-                &data_path,
-                &output_path,
-                Some(progress_tx),
-            );
-            let _ = tx.send(TaskMessage::ExportComplete {
+    fn view_container_logs(&mut self, name: &str) {
+        self.open_server_tab(name, DetailsTab::Logs);
+    }
+
+    /// Start the Logs tab over from the container's output
+    fn load_container_logs(&mut self, name: &str) {
+        self.container_log_source = LogSource::Docker;
+        let Some(docker) = self.docker.clone() else {
+            self.show_status_message(tr("msg-docker-not-connected"));
+            return;
+        };
+
+        let Some(server) = self.servers.iter().find(|s| s.config.name == name) else {
+            self.show_status_message(tr_args("msg-server-not-found", &[("name", name.into())]));
+            return;
+        };
+
+        let Some(container_id) = server.container_id.clone() else {
+            self.container_logs = tr("logs-no-container");
+            return;
+        };
+
+        self.container_logs_last_refresh = Some(std::time::Instant::now());
+
+        let tx = self.task_tx.clone();
+        self.runtime.spawn(async move {
+            let logs = docker
+                .get_container_logs(&container_id, 500)
+                .await
+                .unwrap_or_else(|e| {
+                    tr_args("logs-fetch-failed", &[("error", e.to_string().into())])
+                });
+            let _ = tx.send(TaskMessage::ContainerLogs(logs));
+        });
+    }
+
+    /// Look for known failure signatures in the log of a server that errored
+    fn diagnose_from_logs(&mut self, name: &str, container_id: String) {
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+        let tx = self.task_tx.clone();
+        let server_name = name.to_string();
+        self.runtime.spawn(async move {
+            let Ok(logs) = docker.get_container_logs(&container_id, 1000).await else {
+                return;
+            };
+            let diagnoses = known_errors::analyze(&logs);
+            tx.send(TaskMessage::LogDiagnosis {
                 server_name,
-                result: result.map_err(|e| e.to_string()),
-            });
+                diagnoses,
+            })
+            .ok();
         });
     }
 
-    fn import_server_dialog(&mut self) {
-        let file = rfd::FileDialog::new()
-            .add_filter("DrakonixAnvil Server", &["zip"])
-            .pick_file();
+    /// Write log text to a file picked in a native save dialog, suggesting
+    /// `<prefix>-<timestamp>.log` as the name
+    fn save_logs(&mut self, prefix: &str, text: &str) {
+        let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+        let default_name = format!("{}-{}.log", prefix, timestamp);
+        let save_path = rfd::FileDialog::new()
+            .set_file_name(&default_name)
+            .add_filter(tr("msg-log-filter"), &["log", "txt"])
+            .save_file();
 
-        let Some(path) = file else {
+        let Some(path) = save_path else {
             return; // User cancelled
         };
 
-        self.current_view = View::ConfirmImport(path);
+        match std::fs::write(&path, text) {
+            Ok(()) => self.show_status_message(tr_args(
+                "msg-logs-saved",
+                &[("path", path.display().to_string().into())],
+            )),
+            Err(e) => self.log(format!(
+                "ERROR: {}",
+                tr_args(
+                    "log-save-logs-failed",
+                    &[
+                        ("path", path.display().to_string().into()),
+                        ("error", e.to_string().into())
+                    ]
+                )
+            )),
+        }
     }
 
-    fn confirm_import(&mut self, path: &std::path::Path) {
-        self.log(format!("Importing server from {:?}...", path));
+    /// Refresh container logs without changing view (for auto-refresh)
+    fn refresh_container_logs(&mut self, name: &str) {
+        if let Some(path) = self.container_log_source.path(name) {
+            // Read from the host-mounted data dir, no container needed
+            self.container_logs_last_refresh = Some(std::time::Instant::now());
+            self.container_logs = logfile::tail(&path).unwrap_or_else(|e| format!("{:#}", e));
+            return;
+        }
 
-        let zip_path = path.to_path_buf();
-        let servers_dir = std::path::PathBuf::from(crate::config::DATA_ROOT).join("servers");
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+
+        let Some(server) = self.servers.iter().find(|s| s.config.name == name) else {
+            return;
+        };
+
+        let Some(container_id) = server.container_id.clone() else {
+            return;
+        };
+
+        self.container_logs_last_refresh = Some(std::time::Instant::now());
         let tx = self.task_tx.clone();
 
-        std::thread::spawn(move || {
-            let result = backup::import_server(&zip_path, &servers_dir, None);
-            let _ = tx.send(TaskMessage::ImportComplete {
-                result: result.map(Box::new).map_err(|e| e.to_string()),
-            });
+        self.runtime.spawn(async move {
+            let logs = docker
+                .get_container_logs(&container_id, 500)
+                .await
+                .unwrap_or_else(|e| {
+                    tr_args("logs-fetch-failed", &[("error", e.to_string().into())])
+                });
+            let _ = tx.send(TaskMessage::ContainerLogs(logs));
         });
-
-        self.current_view = View::Dashboard;
-        self.show_status_message("Importing server...".to_string());
     }
 
-    fn open_console(&mut self, name: &str) {
-        self.console_input.clear();
-        self.console_output.clear();
-        self.console_output
-            .push(format!("Connected to RCON console for '{}'", name));
-        self.console_output
-            .push("Type commands and press Enter to send.".to_string());
-        self.console_output.push(
-            "Common commands: list, say <msg>, op <player>, whitelist add <player>".to_string(),
-        );
-        self.console_output.push(String::new());
-        self.current_view = View::Console(name.to_string());
+    fn load_all_docker_logs(&mut self) {
+        if self.docker.is_none() {
+            self.show_status_message(tr("msg-docker-not-connected"));
+            return;
+        }
+
+        // Start over from each container's tail; replies to older fetches are dropped
+        self.docker_log_chunks.clear();
+        self.docker_logs_generation += 1;
+        self.current_view = View::DockerLogs;
+        self.refresh_docker_logs();
     }
 
-    fn send_rcon_command(&mut self, server_name: &str, command: &str) {
-        // Find server config to get RCON password and port
-        let Some(server) = self.servers.iter().find(|s| s.config.name == server_name) else {
-            self.console_output
-                .push(format!("Error: Server '{}' not found", server_name));
+    /// Fetch only the lines written since the last fetch (for auto-refresh)
+    fn refresh_docker_logs(&mut self) {
+        let Some(docker) = self.docker.clone() else {
             return;
         };
 
-        let rcon_port = server.config.rcon_port();
-        let rcon_password = server.config.rcon_password.clone();
-
-        // Connect and send command
-        let address = format!("127.0.0.1:{}", rcon_port);
+        self.docker_logs_last_refresh = Some(std::time::Instant::now());
+        let tx = self.task_tx.clone();
+        let generation = self.docker_logs_generation;
+        let cursors: HashMap<String, String> = self
+            .docker_log_chunks
+            .iter()
+            .filter_map(|c| Some((c.container_id.clone(), c.last_timestamp.clone()?)))
+            .collect();
 
-        self.console_output.push(format!("> {}", command));
+        // Fetch logs in background to avoid UI freeze
+        self.runtime.spawn(async move {
+            let chunks = docker
+                .get_managed_logs_since(&cursors, 200)
+                .await
+                .map_err(|e| tr_args("logs-fetch-failed", &[("error", e.to_string().into())]));
+            let _ = tx.send(TaskMessage::DockerLogs { generation, chunks });
+        });
+    }
 
-        // Use our custom RCON client
-        match crate::rcon::RconClient::connect(&address, &rcon_password) {
-            Ok(mut client) => {
-                match client.command(command) {
-                    Ok(response) => {
-                        if response.is_empty() {
-                            self.console_output.push("(no response)".to_string());
-                        } else {
-                            // Split response into lines
-                            for line in response.lines() {
-                                self.console_output.push(line.to_string());
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        self.console_output.push(format!("Command error: {}", e));
+    /// Append newly fetched Docker log lines and rebuild the combined text
+    fn merge_docker_logs(&mut self, chunks: Vec<LogChunk>) {
+        // Containers that no longer exist drop out of the view
+        self.docker_log_chunks
+            .retain(|old| chunks.iter().any(|c| c.container_id == old.container_id));
+        for chunk in chunks {
+            match self
+                .docker_log_chunks
+                .iter_mut()
+                .find(|c| c.container_id == chunk.container_id)
+            {
+                Some(existing) => {
+                    existing.text.push_str(&chunk.text);
+                    existing.state = chunk.state;
+                    existing.last_timestamp = chunk.last_timestamp;
+                    let lines = existing.text.lines().count();
+                    if lines > DOCKER_LOG_MAX_LINES {
+                        let drop = lines - DOCKER_LOG_MAX_LINES;
+                        existing.text = existing.text.lines().skip(drop).fold(
+                            String::new(),
+                            |mut text, line| {
+                                text.push_str(line);
+                                text.push('\n');
+                                text
+                            },
+                        );
                     }
                 }
+                None => self.docker_log_chunks.push(chunk),
             }
-            Err(e) => {
-                self.console_output.push(format!("RCON error: {}", e));
-                if matches!(e, crate::rcon::RconError::AuthFailed) {
-                    self.console_output
-                        .push("Check that RCON is enabled and password is correct.".to_string());
-                } else {
-                    self.console_output
-                        .push(format!("Is the server running on RCON port {}?", rcon_port));
+        }
+
+        let mut combined = String::new();
+        for chunk in &self.docker_log_chunks {
+            combined.push_str(&format!("═══ {} [{}] ═══\n", chunk.name, chunk.state));
+            if chunk.text.is_empty() {
+                combined.push_str("(no logs)\n");
+            } else {
+                combined.push_str(&chunk.text);
+                if !chunk.text.ends_with('\n') {
+                    combined.push('\n');
                 }
             }
+            combined.push('\n');
         }
+        if combined.is_empty() {
+            combined = tr("docker-logs-none");
+        }
+        self.all_docker_logs = combined;
     }
 
-    /// Process messages from background tasks
-    fn process_task_messages(&mut self) {
-        while let Ok(msg) = self.task_rx.try_recv() {
-            match msg {
-                TaskMessage::Log(text) => {
-                    self.log(text);
-                }
-                TaskMessage::ServerStatus {
-                    name,
-                    status,
-                    container_id,
-                } => {
-                    if let Some(server) = self.servers.iter_mut().find(|s| s.config.name == name) {
-                        server.status = status.clone();
-                        if let Some(cid) = container_id {
-                            server.container_id = Some(cid);
-                        }
-                        // Show status message for terminal states
-                        match &status {
-                            ServerStatus::Running => {
-                                self.status_message = Some((
-                                    format!("Server '{}' started!", name),
-                                    std::time::Instant::now(),
-                                ));
-                            }
-                            ServerStatus::Stopped => {
-                                self.status_message = Some((
-                                    format!("Server '{}' stopped", name),
-                                    std::time::Instant::now(),
-                                ));
-                            }
-                            ServerStatus::Error(e) => {
-                                self.status_message = Some((e.clone(), std::time::Instant::now()));
-                            }
-                            _ => {}
-                        }
+    fn delete_server(&mut self, name: &str) {
+        let Some(docker) = self.docker.clone() else {
+            self.show_status_message(tr("msg-docker-not-connected"));
+            return;
+        };
+
+        // Find and remove the server
+        let server_idx = self.servers.iter().position(|s| s.config.name == name);
+        let Some(idx) = server_idx else {
+            self.show_status_message(tr_args("msg-server-not-found", &[("name", name.into())]));
+            return;
+        };
+
+        let server = self.servers.remove(idx);
+        self.uptime.remove(name);
+        self.disk_usage.servers.remove(name);
+        self.disk_usage_measured = None;
+
+        // Remove container if it exists, in the background so a slow daemon
+        // doesn't freeze the UI
+        if let Some(container_id) = server.container_id {
+            let tx = self.task_tx.clone();
+            let name = name.to_string();
+            self.runtime.spawn(async move {
+                // Try to stop first (ignore errors - might already be stopped)
+                let _ = docker.stop_container(&container_id).await;
+                let result = docker
+                    .remove_container(&container_id)
+                    .await
+                    .map_err(|e| e.to_string());
+                tx.send(TaskMessage::DeletedContainerRemoved { name, result })
+                    .ok();
+            });
+        }
+
+        self.save_servers();
+        self.refresh_orphaned_dirs();
+        self.show_status_message(tr_args("msg-server-deleted", &[("name", name.into())]));
+        self.current_view = View::Dashboard;
+    }
+
+    fn run_diagnostics(&mut self, automatic: bool) {
+        if self.diagnostics_running {
+            return;
+        }
+        self.diagnostics_running = true;
+        let docker = self.docker.clone();
+        let settings = self.settings.clone();
+        let tx = self.task_tx.clone();
+        self.runtime.spawn(async move {
+            let report = diagnostics::run(docker, &settings, automatic).await;
+            tx.send(TaskMessage::DiagnosticsComplete(report)).ok();
+        });
+    }
+
+    /// Check the proxy settings as entered, before they're saved
+    fn test_proxy(&mut self) {
+        if self.proxy_testing {
+            return;
+        }
+        self.proxy_testing = true;
+        self.proxy_test_result = None;
+        let proxy = self.settings.proxy.clone();
+        let tx = self.task_tx.clone();
+        self.runtime.spawn(async move {
+            let result = crate::http::test_connection(&proxy).await;
+            tx.send(TaskMessage::ProxyTestComplete(result)).ok();
+        });
+    }
+
+    /// Run diagnostics on our own when errors start piling up
+    fn check_error_spike(&mut self) {
+        let now = std::time::Instant::now();
+        while self
+            .recent_errors
+            .front()
+            .is_some_and(|t| now.duration_since(*t) > ERROR_SPIKE_WINDOW)
+        {
+            self.recent_errors.pop_front();
+        }
+        let cooled_down = self
+            .last_auto_diagnostics
+            .is_none_or(|t| now.duration_since(t) > AUTO_DIAGNOSTICS_COOLDOWN);
+        if self.recent_errors.len() >= ERROR_SPIKE_COUNT && cooled_down {
+            self.last_auto_diagnostics = Some(now);
+            self.recent_errors.clear();
+            self.log(tr_args(
+                "log-error-spike",
+                &[("count", ERROR_SPIKE_COUNT.into())],
+            ));
+            self.run_diagnostics(true);
+        }
+    }
+
+    /// Flag servers that wrote a crash report since their reports were last opened
+    fn check_crash_reports(&mut self) {
+        if self
+            .crash_checked
+            .is_some_and(|t| t.elapsed() < CRASH_CHECK_INTERVAL)
+        {
+            return;
+        }
+        self.crash_checked = Some(std::time::Instant::now());
+
+        let mut new_crashes = Vec::new();
+        for server in &self.servers {
+            let name = &server.config.name;
+            match crash::newest(name) {
+                Some(newest) if server.config.crash_report_seen.as_ref() != Some(&newest) => {
+                    if self.crash_unread.get(name) != Some(&newest) {
+                        new_crashes.push((name.clone(), newest));
                     }
-                    self.save_servers();
                 }
-                TaskMessage::BackupProgress {
-                    server_name,
-                    current,
-                    total,
-                    current_file,
-                } => {
-                    self.backup_progress = Some((server_name, current, total, current_file));
+                _ => {
+                    self.crash_unread.remove(name);
                 }
-                TaskMessage::BackupComplete {
-                    server_name,
-                    result,
-                } => {
-                    self.backup_progress = None;
-                    match result {
-                        Ok(path) => {
-                            let filename = path
-                                .file_name()
-                                .map(|s| s.to_string_lossy().to_string())
-                                .unwrap_or_else(|| "backup".to_string());
-                            self.show_status_message(format!("Backup created: {}", filename));
-                            self.log(format!("Backup saved to {:?}", path));
-                        }
-                        Err(e) => {
-                            self.show_status_message(format!("Backup failed: {}", e));
-                            self.log(format!("ERROR: Backup failed: {}", e));
-                        }
-                    }
-                    // If we're viewing backups for this server, refresh the list
-                    if let View::Backups(name) = &self.current_view {
-                        if name == &server_name {
-                            if let Ok(backups) = backup::list_backups(&server_name) {
-                                self.backup_list = backups;
-                            }
-                        }
-                    }
+            }
+        }
+        for (name, newest) in new_crashes {
+            self.show_status_message(tr_args(
+                "msg-server-crashed",
+                &[
+                    ("name", name.clone().into()),
+                    ("report", newest.clone().into()),
+                ],
+            ));
+            self.crash_unread.insert(name, newest);
+        }
+    }
+
+    /// Open the crash report viewer on a server's newest report and mark it read
+    fn open_crash_reports(&mut self, name: &str) {
+        self.crash_reports = crash::list(name);
+        self.crash_selected = None;
+        if let Some(newest) = self.crash_reports.first().cloned() {
+            self.select_crash_report(&newest);
+        }
+        self.crash_unread.remove(name);
+        let newest = crash::newest(name);
+        if let Some(server) = self.servers.iter_mut().find(|s| s.config.name == name) {
+            if newest.is_some() && server.config.crash_report_seen != newest {
+                server.config.crash_report_seen = newest;
+                self.save_servers();
+            }
+        }
+        self.current_view = View::CrashReports(name.to_string());
+    }
+
+    fn select_crash_report(&mut self, path: &std::path::Path) {
+        match crash::load(path) {
+            Ok(report) => self.crash_selected = Some(report),
+            Err(e) => self.log(format!("ERROR: {:#}", e)),
+        }
+    }
+
+    fn view_ports(&mut self) {
+        self.port_report = crate::ports::collect_port_usage(&self.servers, true);
+        self.current_view = View::Ports;
+    }
+
+    fn view_backup_overview(&mut self) {
+        self.backup_overview = crate::backup_overview::collect_backup_overview(&self.servers);
+        self.current_view = View::BackupOverview;
+    }
+
+    fn refresh_orphaned_dirs(&mut self) {
+        self.orphaned_dirs = find_orphaned_server_dirs(&self.servers);
+    }
+
+    fn adopt_server(&mut self, name: &str) {
+        let modpack = ModpackInfo {
+            name: "Unknown".to_string(),
+            version: "Unknown".to_string(),
+            minecraft_version: String::new(),
+            loader: crate::server::ModLoader::Vanilla,
+            source: crate::server::ModpackSource::Local {
+                path: ".".to_string(),
+            },
+        };
+        let config = ServerConfig::new(name.to_string(), modpack);
+        let instance = ServerInstance {
+            config,
+            container_id: None,
+            status: ServerStatus::Stopped,
+        };
+        self.servers.push(instance);
+        self.save_servers();
+        self.refresh_orphaned_dirs();
+        self.show_status_message(tr_args("msg-server-adopted", &[("name", name.into())]));
+        self.start_edit_server(name);
+    }
+
+    fn delete_orphan(&mut self, name: &str) {
+        let server_path = get_server_path(name);
+        if server_path.exists() {
+            if let Err(e) = std::fs::remove_dir_all(&server_path) {
+                self.show_status_message(tr_args(
+                    "msg-orphan-delete-failed",
+                    &[("name", name.into()), ("error", e.to_string().into())],
+                ));
+                return;
+            }
+        }
+
+        let backup_path = get_backup_path(name);
+        if backup_path.exists() {
+            let _ = std::fs::remove_dir_all(&backup_path);
+        }
+
+        self.refresh_orphaned_dirs();
+        self.show_status_message(tr_args("msg-orphan-deleted", &[("name", name.into())]));
+    }
+
+    /// Emergency freeze: flush the world to disk, pause the container so nothing else
+    /// changes, then snapshot the data directory as evidence before deciding on a rollback.
+    fn panic_freeze(&mut self, name: &str) {
+        let Some(docker) = self.docker.clone() else {
+            self.show_status_message(tr("msg-docker-not-connected"));
+            return;
+        };
+        let Some(server) = self.servers.iter().find(|s| s.config.name == name) else {
+            return;
+        };
+        let Some(container_id) = server.container_id.clone() else {
+            self.show_status_message(tr_args("msg-no-container", &[("name", name.into())]));
+            return;
+        };
+        let address = format!("127.0.0.1:{}", server.config.rcon_port());
+        let password = server.config.rcon_password.clone();
+        let excludes = server.config.backup_excludes.clone();
+        // Only one backup runs at a time; the freeze itself must not wait for it
+        let snapshot = self.backup_progress.is_none();
+        if snapshot {
+            self.backup_progress = Some((name.to_string(), 0, 0, tr("progress-freezing")));
+        }
+
+        self.log(format!(
+            "PANIC: {}",
+            tr_args("log-freezing", &[("name", name.into())])
+        ));
+        let server_name = name.to_string();
+        let compression = self.settings.backup_compression;
+        let tx = self.task_tx.clone();
+
+        self.runtime.spawn(async move {
+            match rcon::send_command(&address, &password, "save-all flush").await {
+                Ok(_) => {
+                    tx.send(TaskMessage::Log(tr_args(
+                        "log-world-saved",
+                        &[("name", server_name.as_str().into())],
+                    )))
+                    .ok();
                 }
-                TaskMessage::DockerLogs(logs) => {
-                    self.all_docker_logs = logs;
+                Err(e) => {
+                    tx.send(TaskMessage::Log(format!(
+                        "WARNING: {}",
+                        tr_args(
+                            "log-save-all-failed",
+                            &[
+                                ("name", server_name.as_str().into()),
+                                ("error", e.to_string().into())
+                            ]
+                        )
+                    )))
+                    .ok();
                 }
-                TaskMessage::ContainerLogs(logs) => {
-                    self.container_logs = logs;
+            }
+
+            if let Err(e) = docker.pause_container(&container_id).await {
+                tx.send(TaskMessage::Log(format!(
+                    "ERROR: {}",
+                    tr_args(
+                        "log-pause-failed",
+                        &[
+                            ("name", server_name.as_str().into()),
+                            ("error", e.to_string().into())
+                        ]
+                    )
+                )))
+                .ok();
+                if snapshot {
+                    tx.send(TaskMessage::BackupComplete {
+                        server_name,
+                        result: Err(format!("Freeze failed: {}", e)),
+                    })
+                    .ok();
                 }
-                TaskMessage::RestoreProgress {
+                return;
+            }
+            tx.send(TaskMessage::ServerStatus {
+                name: server_name.clone(),
+                status: ServerStatus::Frozen,
+                container_id: None,
+            })
+            .ok();
+
+            if !snapshot {
+                tx.send(TaskMessage::Log(tr_args(
+                    "log-frozen-backup-busy",
+                    &[("name", server_name.as_str().into())],
+                )))
+                .ok();
+                return;
+            }
+
+            // Zipping is blocking IO; keep it off the async workers
+            let _ = tokio::task::spawn_blocking(move || {
+                let (progress_tx, progress_rx) =
+                    std::sync::mpsc::channel::<backup::BackupProgress>();
+                let tx_progress = tx.clone();
+                let name_for_progress = server_name.clone();
+                std::thread::spawn(move || {
+                    while let Ok(progress) = progress_rx.recv() {
+                        let _ = tx_progress.send(TaskMessage::BackupProgress {
+                            server_name: name_for_progress.clone(),
+                            current: progress.current,
+                            total: progress.total,
+                            current_file: progress.current_file,
+                        });
+                    }
+                });
+
+                let result = backup::create_labeled_backup_with_progress(
+                    &server_name,
+                    "frozen",
+                    compression,
+                    &excludes,
+                    Some(progress_tx),
+                );
+                let _ = tx.send(TaskMessage::BackupComplete {
                     server_name,
-                    current,
-                    total,
-                    current_file,
-                } => {
-                    self.restore_progress = Some((server_name, current, total, current_file));
+                    result: result.map_err(|e| e.to_string()),
+                });
+            })
+            .await;
+        });
+    }
+
+    /// Resume a server frozen by the panic button
+    fn unfreeze_server(&mut self, name: &str) {
+        let Some(docker) = self.docker.clone() else {
+            self.show_status_message(tr("msg-docker-not-connected"));
+            return;
+        };
+        let Some(container_id) = self
+            .servers
+            .iter()
+            .find(|s| s.config.name == name)
+            .and_then(|s| s.container_id.clone())
+        else {
+            return;
+        };
+
+        let server_name = name.to_string();
+        let tx = self.task_tx.clone();
+        self.runtime.spawn(async move {
+            match docker.unpause_container(&container_id).await {
+                Ok(()) => {
+                    tx.send(TaskMessage::Log(tr_args(
+                        "log-unfrozen",
+                        &[("name", server_name.as_str().into())],
+                    )))
+                    .ok();
+                    tx.send(TaskMessage::ServerStatus {
+                        name: server_name,
+                        status: ServerStatus::Running,
+                        container_id: None,
+                    })
+                    .ok();
                 }
-                TaskMessage::RestoreComplete {
-                    server_name,
-                    result,
-                } => {
-                    self.restore_progress = None;
-                    match result {
-                        Ok(()) => {
-                            self.show_status_message(format!(
-                                "Backup restored for '{}'",
-                                server_name
-                            ));
-                            self.log(format!(
-                                "Backup restored successfully for '{}'",
-                                server_name
-                            ));
+                Err(e) => {
+                    tx.send(TaskMessage::Log(format!(
+                        "ERROR: {}",
+                        tr_args(
+                            "log-unfreeze-failed",
+                            &[
+                                ("name", server_name.as_str().into()),
+                                ("error", e.to_string().into())
+                            ]
+                        )
+                    )))
+                    .ok();
+                }
+            }
+        });
+    }
+
+    fn remove_container_and_start(&mut self, name: &str) {
+        let Some(docker) = &self.docker else {
+            self.show_status_message(tr("msg-docker-not-connected"));
+            return;
+        };
+        let docker = docker.clone();
+        let container_name = get_container_name(&self.settings.instance_id, name);
+
+        // Shown as busy until the old container is gone; then the start continues
+        // from the OldContainerRemoved handler
+        if let Some(server) = self.servers.iter_mut().find(|s| s.config.name == name) {
+            server.status = ServerStatus::Stopping;
+        }
+        self.current_view = View::Dashboard;
+        self.log(tr_args(
+            "log-removing-container",
+            &[("container", container_name.as_str().into())],
+        ));
+
+        let tx = self.task_tx.clone();
+        let name = name.to_string();
+        self.runtime.spawn(async move {
+            // Try to stop first (ignore errors — may already be stopped)
+            let _ = docker.stop_container(&container_name).await;
+            let result = docker
+                .remove_container(&container_name)
+                .await
+                .map_err(|e| e.to_string());
+            tx.send(TaskMessage::OldContainerRemoved {
+                name,
+                container_name,
+                result,
+            })
+            .ok();
+        });
+    }
+
+    fn create_backup(&mut self, name: &str, kind: BackupKind) {
+        // Check if a backup is already in progress
+        if self.backup_progress.is_some() {
+            self.show_status_message(tr("msg-backup-busy"));
+            return;
+        }
+
+        self.log(tr_args("log-creating-backup", &[("name", name.into())]));
+        self.start_backup(name, kind);
+    }
+
+    /// Back up a server in the background. Scheduled backups are followed by pruning
+    /// older scheduled backups per their retention rules.
+    /// A running server is hot-backed up: world saves are paused and flushed over
+    /// RCON before zipping, and switched back on afterwards whatever the outcome.
+    fn start_backup(&mut self, name: &str, kind: BackupKind) {
+        let live = self
+            .servers
+            .iter()
+            .find(|s| s.config.name == name && s.status == ServerStatus::Running)
+            .map(|s| {
+                (
+                    format!("127.0.0.1:{}", s.config.rcon_port()),
+                    s.config.rcon_password.clone(),
+                )
+            });
+        let stage = if live.is_some() {
+            tr("progress-pausing-saves")
+        } else {
+            tr("progress-counting-files")
+        };
+        self.backup_progress = Some((name.to_string(), 0, 0, stage));
+        let remote = self.remote_backup_for(name);
+        let verify = self.settings.verify_backups;
+        let excludes = self.backup_excludes_for(name);
+        let compression = match kind {
+            BackupKind::Scheduled(schedule) => schedule.compression,
+            _ => None,
+        }
+        .unwrap_or(self.settings.backup_compression);
+
+        let server_name = name.to_string();
+        let tx = self.task_tx.clone();
+        self.runtime.spawn(async move {
+            if let Some((address, password)) = &live {
+                if let Err(e) = rcon::pause_saving(address, password).await {
+                    tx.send(TaskMessage::BackupComplete {
+                        server_name,
+                        result: Err(format!("Could not pause world saves: {}", e)),
+                    })
+                    .ok();
+                    return;
+                }
+            }
+
+            // Zipping is CPU/IO bound, so it runs off the async workers
+            let tx_zip = tx.clone();
+            let name = server_name.clone();
+            let result = tokio::task::spawn_blocking(move || {
+                let (progress_tx, progress_rx) =
+                    std::sync::mpsc::channel::<backup::BackupProgress>();
+
+                // Spawn a thread to forward progress updates
+                let tx_progress = tx_zip.clone();
+                let name_for_progress = name.clone();
+                std::thread::spawn(move || {
+                    while let Ok(progress) = progress_rx.recv() {
+                        let _ = tx_progress.send(TaskMessage::BackupProgress {
+                            server_name: name_for_progress.clone(),
+                            current: progress.current,
+                            total: progress.total,
+                            current_file: progress.current_file,
+                        });
+                    }
+                });
+
+                let result = match kind {
+                    BackupKind::Full => backup::create_backup_with_progress(
+                        &name,
+                        compression,
+                        &excludes,
+                        Some(progress_tx),
+                    ),
+                    BackupKind::Incremental => backup::create_incremental_backup_with_progress(
+                        &name,
+                        compression,
+                        &excludes,
+                        Some(progress_tx),
+                    ),
+                    BackupKind::Scheduled(_) => backup::create_labeled_backup_with_progress(
+                        &name,
+                        backup::SCHEDULED_LABEL,
+                        compression,
+                        &excludes,
+                        Some(progress_tx),
+                    ),
+                };
+                if let (Ok(_), BackupKind::Scheduled(schedule)) = (&result, kind) {
+                    match backup::prune_backups(&name, &schedule.retention) {
+                        Ok(pruned) if !pruned.is_empty() => {
+                            let _ = tx_zip.send(TaskMessage::Log(tr_args(
+                                "log-pruned-backups",
+                                &[
+                                    ("count", pruned.len().into()),
+                                    ("name", name.as_str().into()),
+                                ],
+                            )));
                         }
+                        Ok(_) => {}
                         Err(e) => {
-                            self.show_status_message(format!("Restore failed: {}", e));
-                            self.log(format!("ERROR: Restore failed: {}", e));
+                            let _ = tx_zip.send(TaskMessage::Log(format!(
+                                "ERROR: {}",
+                                tr_args(
+                                    "log-prune-failed",
+                                    &[
+                                        ("name", name.as_str().into()),
+                                        ("error", e.to_string().into())
+                                    ]
+                                )
+                            )));
                         }
                     }
                 }
-                TaskMessage::CfSearchResults {
-                    results,
-                    total_count,
-                } => {
-                    if let Some(widget) = self.active_cf_widget() {
-                        widget.state.results = results;
-                        widget.state.total_count = total_count;
-                        widget.state.loading_search = false;
-                        widget.state.search_error = None;
-                    }
+                result.map_err(|e| e.to_string())
+            })
+            .await
+            .unwrap_or_else(|e| Err(e.to_string()));
+
+            if let Some((address, password)) = &live {
+                if let Err(e) = rcon::resume_saving(address, password).await {
+                    tx.send(TaskMessage::Log(format!(
+                        "ERROR: {}",
+                        tr_args(
+                            "log-save-on-failed",
+                            &[
+                                ("name", server_name.as_str().into()),
+                                ("error", e.to_string().into())
+                            ]
+                        )
+                    )))
+                    .ok();
                 }
-                TaskMessage::CfSearchError(err) => {
-                    if let Some(widget) = self.active_cf_widget() {
-                        widget.state.loading_search = false;
-                        widget.state.search_error = Some(err);
+            }
+            let result = match result {
+                Ok(path) if verify => {
+                    match verify_backup_file(server_name.clone(), path.clone(), tx.clone()).await {
+                        Ok(_) => Ok(path),
+                        Err(e) => Err(format!(
+                            "{} was written but failed verification: {}",
+                            path.file_name().unwrap_or_default().to_string_lossy(),
+                            e
+                        )),
                     }
                 }
-                TaskMessage::CfVersionResults { mod_id, files } => {
-                    let is_create_view =
-                        matches!(self.current_view, View::CreateServer);
-                    // Track memory to update on create view after the mutable borrow ends
-                    let mut new_memory: Option<String> = None;
-                    if let Some(widget) = self.active_cf_widget() {
-                        let matches = widget
-                            .state
-                            .selected_mod
-                            .as_ref()
-                            .is_some_and(|m| m.id == mod_id);
-                        if matches {
-                            widget.state.versions = files;
-                            widget.state.mc_versions =
-                                curseforge::extract_mc_versions(&widget.state.versions);
-                            widget.state.selected_mc_version =
-                                widget.state.mc_versions.first().cloned();
-                            widget.state.loading_versions = false;
-                            widget.state.versions_error = None;
+                other => other,
+            };
+            let upload = result.as_ref().ok().cloned().zip(remote);
+            tx.send(TaskMessage::BackupComplete {
+                server_name: server_name.clone(),
+                result,
+            })
+            .ok();
+            if let Some((path, remote)) = upload {
+                upload_backup(remote, server_name, path, tx).await;
+            }
+        });
+    }
 
-                            // Auto-select first file matching the default MC version
-                            let mc_ver = widget.state.selected_mc_version.clone();
-                            let first_match = widget
-                                .state
-                                .versions
-                                .iter()
-                                .enumerate()
-                                .find(|(_i, f)| match &mc_ver {
-                                    Some(mc) => f.game_versions.iter().any(|v| v == mc),
-                                    None => true,
-                                })
-                                .map(|(i, _)| i);
+    /// Re-check an existing backup's archive, reporting through `BackupVerified`
+    fn verify_backup(&mut self, name: &str, path: &std::path::Path) {
+        if self.backup_progress.is_some() {
+            self.show_status_message(tr("msg-backup-busy"));
+            return;
+        }
+        self.backup_progress = Some((name.to_string(), 0, 0, tr("progress-verifying")));
+        let server_name = name.to_string();
+        let path = path.to_path_buf();
+        let tx = self.task_tx.clone();
+        self.runtime.spawn(async move {
+            let result = verify_backup_file(server_name.clone(), path.clone(), tx.clone()).await;
+            tx.send(TaskMessage::BackupVerified {
+                server_name,
+                path,
+                result,
+            })
+            .ok();
+        });
+    }
 
-                            if let Some(idx) = first_match {
-                                widget.state.selected_file_idx = Some(idx);
-                                let selected_mod =
-                                    widget.state.selected_mod.clone().unwrap();
-                                let file = widget.state.versions[idx].clone();
-                                widget.build_cf_template(&selected_mod, &file);
-                                if is_create_view {
-                                    if let Some(t) = &widget.template {
-                                        new_memory =
-                                            Some(t.recommended_memory_mb.to_string());
-                                    }
-                                }
-                            } else {
-                                widget.state.selected_file_idx = None;
-                            }
-                        }
-                    }
-                    if let Some(mem) = new_memory {
-                        self.create_view.memory_mb = mem;
-                    }
-                }
-                TaskMessage::CfVersionError { mod_id, error } => {
-                    if let Some(widget) = self.active_cf_widget() {
-                        let matches = widget
-                            .state
-                            .selected_mod
-                            .as_ref()
-                            .is_some_and(|m| m.id == mod_id);
-                        if matches {
-                            widget.state.loading_versions = false;
-                            widget.state.versions_error = Some(error);
-                        }
-                    }
-                }
-                TaskMessage::CfDescriptionResult {
-                    mod_id,
-                    description,
-                } => {
-                    if let Some(widget) = self.active_cf_widget() {
-                        let matches = widget
-                            .state
-                            .selected_mod
-                            .as_ref()
-                            .is_some_and(|m| m.id == mod_id);
-                        if matches {
-                            widget.state.description = Some(description);
-                            widget.state.loading_description = false;
-                        }
-                    }
-                }
-                TaskMessage::CfDescriptionError { mod_id, error } => {
-                    if let Some(widget) = self.active_cf_widget() {
-                        let matches = widget
-                            .state
-                            .selected_mod
-                            .as_ref()
-                            .is_some_and(|m| m.id == mod_id);
-                        if matches {
-                            widget.state.loading_description = false;
-                            tracing::warn!(
-                                "Failed to fetch description for mod {}: {}",
-                                mod_id,
-                                error
-                            );
-                        }
-                    }
-                }
-                TaskMessage::MrSearchResults {
-                    results,
-                    total_count,
-                } => {
-                    if let Some(widget) = self.active_mr_widget() {
-                        widget.state.results = results;
-                        widget.state.total_count = total_count;
-                        widget.state.loading_search = false;
-                        widget.state.search_error = None;
-                    }
-                }
-                TaskMessage::MrSearchError(err) => {
-                    if let Some(widget) = self.active_mr_widget() {
-                        widget.state.loading_search = false;
-                        widget.state.search_error = Some(err);
+    /// Exclude patterns set for a server's backups
+    fn backup_excludes_for(&self, name: &str) -> Vec<String> {
+        self.servers
+            .iter()
+            .find(|s| s.config.name == name)
+            .map(|s| s.config.backup_excludes.clone())
+            .unwrap_or_default()
+    }
+
+    /// Remote destination for a server's backups, if it opted in and one is set up
+    fn remote_backup_for(&self, name: &str) -> Option<RemoteBackupSettings> {
+        let opted_in = self
+            .servers
+            .iter()
+            .any(|s| s.config.name == name && s.config.remote_backup);
+        (opted_in && remote_backup::is_configured(&self.settings.remote_backup))
+            .then(|| self.settings.remote_backup.clone())
+    }
+
+    fn group_members(&self, group: &str) -> Vec<String> {
+        self.servers
+            .iter()
+            .filter(|s| s.config.backup_group.as_deref() == Some(group))
+            .map(|s| s.config.name.clone())
+            .collect()
+    }
+
+    /// Snapshot every server in a backup group at the same moment. Running members
+    /// stop writing the world (`save-off`) and flush it first, so no member's data is
+    /// newer than another's; saving is switched back on once all archives are written.
+    fn backup_group(&mut self, group: &str) {
+        if self.backup_progress.is_some() {
+            self.show_status_message(tr("msg-backup-busy"));
+            return;
+        }
+        let members = self.group_members(group);
+        let Some(first) = members.first().cloned() else {
+            return;
+        };
+        // (name, rcon address, password) for members whose world is live
+        let running: Vec<(String, String, String)> = self
+            .servers
+            .iter()
+            .filter(|s| members.contains(&s.config.name) && s.status == ServerStatus::Running)
+            .map(|s| {
+                (
+                    s.config.name.clone(),
+                    format!("127.0.0.1:{}", s.config.rcon_port()),
+                    s.config.rcon_password.clone(),
+                )
+            })
+            .collect();
+
+        self.log(tr_args(
+            "log-creating-group-backup",
+            &[
+                ("group", group.into()),
+                ("members", members.join(", ").into()),
+            ],
+        ));
+        self.backup_progress = Some((first, 0, 0, tr("progress-pausing-saves")));
+
+        let uploads: Vec<(String, Option<RemoteBackupSettings>)> = members
+            .iter()
+            .map(|m| (m.clone(), self.remote_backup_for(m)))
+            .collect();
+        let excludes: Vec<Vec<String>> = members
+            .iter()
+            .map(|m| self.backup_excludes_for(m))
+            .collect();
+        let group = group.to_string();
+        let compression = self.settings.backup_compression;
+        let verify = self.settings.verify_backups;
+        let tx = self.task_tx.clone();
+        self.runtime.spawn(async move {
+            let mut paused = Vec::new();
+            for (name, address, password) in &running {
+                match rcon::pause_saving(address, password).await {
+                    Ok(_) => paused.push((address.clone(), password.clone())),
+                    Err(e) => {
+                        tx.send(TaskMessage::Log(format!(
+                            "WARNING: {}",
+                            tr_args(
+                                "log-save-off-failed",
+                                &[
+                                    ("name", name.as_str().into()),
+                                    ("error", e.to_string().into())
+                                ]
+                            )
+                        )))
+                        .ok();
                     }
                 }
-                TaskMessage::MrVersionResults {
-                    project_id,
-                    versions,
-                } => {
-                    let is_create_view =
-                        matches!(self.current_view, View::CreateServer);
-                    let mut new_memory: Option<String> = None;
-                    if let Some(widget) = self.active_mr_widget() {
-                        let matches = widget
-                            .state
-                            .selected_project
-                            .as_ref()
-                            .is_some_and(|p| p.slug == project_id);
-                        if matches {
-                            widget.state.versions = versions;
-                            widget.state.mc_versions =
-                                modrinth::extract_mc_versions(&widget.state.versions);
-                            widget.state.selected_mc_version =
-                                widget.state.mc_versions.first().cloned();
-                            widget.state.loading_versions = false;
-                            widget.state.versions_error = None;
+            }
 
-                            // Auto-select first version matching the default MC version
-                            let mc_ver = widget.state.selected_mc_version.clone();
-                            let first_match = widget
-                                .state
-                                .versions
-                                .iter()
-                                .enumerate()
-                                .find(|(_i, v)| match &mc_ver {
-                                    Some(mc) => v.game_versions.iter().any(|gv| gv == mc),
-                                    None => true,
-                                })
-                                .map(|(i, _)| i);
+            let tx_zip = tx.clone();
+            let filename = backup::group_backup_filename(&group);
+            let result = tokio::task::spawn_blocking(move || {
+                let mut written = Vec::new();
+                for (member, excludes) in members.iter().zip(&excludes) {
+                    let (progress_tx, progress_rx) =
+                        std::sync::mpsc::channel::<backup::BackupProgress>();
+                    let tx_progress = tx_zip.clone();
+                    let name_for_progress = member.clone();
+                    std::thread::spawn(move || {
+                        while let Ok(progress) = progress_rx.recv() {
+                            let _ = tx_progress.send(TaskMessage::BackupProgress {
+                                server_name: name_for_progress.clone(),
+                                current: progress.current,
+                                total: progress.total,
+                                current_file: progress.current_file,
+                            });
+                        }
+                    });
 
-                            if let Some(idx) = first_match {
-                                widget.state.selected_version_idx = Some(idx);
-                                let selected_project =
-                                    widget.state.selected_project.clone().unwrap();
-                                let version = widget.state.versions[idx].clone();
-                                widget.build_mr_template(&selected_project, &version);
-                                if is_create_view {
-                                    if let Some(t) = &widget.template {
-                                        new_memory =
-                                            Some(t.recommended_memory_mb.to_string());
-                                    }
-                                }
-                            } else {
-                                widget.state.selected_version_idx = None;
+                    match backup::create_named_backup_with_progress(
+                        member,
+                        &filename,
+                        compression,
+                        excludes,
+                        Some(progress_tx),
+                    ) {
+                        Ok(path) => written.push(path),
+                        Err(e) => {
+                            // A partial set can't be restored consistently
+                            for path in &written {
+                                let _ = std::fs::remove_file(path);
                             }
+                            return Err(format!("'{}': {}", member, e));
                         }
                     }
-                    if let Some(mem) = new_memory {
-                        self.create_view.memory_mb = mem;
-                    }
                 }
-                TaskMessage::MrVersionError { project_id, error } => {
-                    if let Some(widget) = self.active_mr_widget() {
-                        let matches = widget
-                            .state
-                            .selected_project
-                            .as_ref()
-                            .is_some_and(|p| p.slug == project_id);
-                        if matches {
-                            widget.state.loading_versions = false;
-                            widget.state.versions_error = Some(error);
-                        }
+                Ok(written)
+            })
+            .await
+            .unwrap_or_else(|e| Err(e.to_string()));
+
+            for (address, password) in &paused {
+                let _ = rcon::resume_saving(address, password).await;
+            }
+            let mut result = result;
+            if let (true, Ok(written)) = (verify, &result) {
+                for path in written.clone() {
+                    let member = path
+                        .parent()
+                        .and_then(|p| p.file_name())
+                        .map(|n| n.to_string_lossy().to_string())
+                        .unwrap_or_default();
+                    if let Err(e) = verify_backup_file(member.clone(), path, tx.clone()).await {
+                        result = Err(format!(
+                            "'{}' was written but failed verification: {}",
+                            member, e
+                        ));
+                        break;
                     }
                 }
-                TaskMessage::MrDescriptionResult {
-                    project_id,
-                    description,
-                } => {
-                    if let Some(widget) = self.active_mr_widget() {
-                        let matches = widget
-                            .state
-                            .selected_project
-                            .as_ref()
-                            .is_some_and(|p| p.slug == project_id);
-                        if matches {
-                            widget.state.description = Some(description);
-                            widget.state.loading_description = false;
-                        }
-                    }
+            }
+            // Archives are written in member order, and only a complete set is kept
+            let written = result.clone().unwrap_or_default();
+            tx.send(TaskMessage::GroupBackupComplete { group, result })
+                .ok();
+            for ((member, remote), path) in uploads.into_iter().zip(written) {
+                if let Some(remote) = remote {
+                    upload_backup(remote, member, path, tx.clone()).await;
                 }
-                TaskMessage::MrDescriptionError { project_id, error } => {
-                    if let Some(widget) = self.active_mr_widget() {
-                        let matches = widget
-                            .state
-                            .selected_project
-                            .as_ref()
-                            .is_some_and(|p| p.slug == project_id);
-                        if matches {
-                            widget.state.loading_description = false;
-                            tracing::warn!(
-                                "Failed to fetch Modrinth description for {}: {}",
-                                project_id,
-                                error
-                            );
+            }
+        });
+    }
+
+    /// Restore every member of a group snapshot. All members must be stopped.
+    fn restore_group(&mut self, group: &str, filename: &str) {
+        if self.restore_progress.is_some() {
+            self.show_status_message(tr("msg-restore-busy"));
+            return;
+        }
+        let members = self.group_members(group);
+        let running: Vec<&str> = self
+            .servers
+            .iter()
+            .filter(|s| members.contains(&s.config.name))
+            .filter(|s| !matches!(s.status, ServerStatus::Stopped | ServerStatus::Error(_)))
+            .map(|s| s.config.name.as_str())
+            .collect();
+        if !running.is_empty() {
+            self.show_status_message(tr_args(
+                "msg-group-restore-running",
+                &[("servers", running.join(", ").into())],
+            ));
+            return;
+        }
+
+        let set = backup::group_backup_set(&members, filename);
+        let Some((first, _)) = set.first() else {
+            self.show_status_message(tr("msg-group-snapshot-missing"));
+            return;
+        };
+        for member in &members {
+            if !set.iter().any(|(name, _)| name == member) {
+                self.log(format!(
+                    "WARNING: {}",
+                    tr_args(
+                        "log-group-member-newer",
+                        &[
+                            ("member", member.as_str().into()),
+                            ("group", group.into()),
+                            ("file", filename.into())
+                        ]
+                    )
+                ));
+            }
+        }
+
+        self.log(tr_args(
+            "log-restoring-group",
+            &[("group", group.into()), ("file", filename.into())],
+        ));
+        self.restore_progress = Some((first.clone(), 0, 0, tr("progress-checking-backups")));
+        self.current_view = View::Dashboard;
+
+        let group = group.to_string();
+        let tx = self.task_tx.clone();
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.restore_cancel = Some(cancel.clone());
+
+        std::thread::spawn(move || {
+            let restore_all = || -> Result<usize, String> {
+                backup::verify_backup_set(&set).map_err(|e| e.to_string())?;
+                for (member, path) in &set {
+                    let (progress_tx, progress_rx) =
+                        std::sync::mpsc::channel::<backup::BackupProgress>();
+                    let tx_progress = tx.clone();
+                    let name_for_progress = member.clone();
+                    std::thread::spawn(move || {
+                        while let Ok(progress) = progress_rx.recv() {
+                            let _ = tx_progress.send(TaskMessage::RestoreProgress {
+                                server_name: name_for_progress.clone(),
+                                current: progress.current,
+                                total: progress.total,
+                                current_file: progress.current_file,
+                            });
                         }
-                    }
-                }
-                TaskMessage::ExportProgress {
-                    server_name,
-                    current,
-                    total,
-                    current_file,
-                } => {
-                    self.export_progress = Some((server_name, current, total, current_file));
-                }
-                TaskMessage::ExportComplete {
-                    server_name,
-                    result,
-                } => {
-                    self.export_progress = None;
-                    match result {
-                        Ok(path) => {
-                            let filename = path
-                                .file_name()
-                                .map(|s| s.to_string_lossy().to_string())
-                                .unwrap_or_else(|| "export".to_string());
-                            self.show_status_message(format!("Exported: {}", filename));
-                            self.log(format!(
-                                "Server '{}' exported to {:?}",
-                                server_name, path
-                            ));
-                        }
-                        Err(e) => {
-                            self.show_status_message(format!("Export failed: {}", e));
-                            self.log(format!("ERROR: Export failed: {}", e));
-                        }
-                    }
-                }
-                TaskMessage::ImportComplete { result } => {
-                    match result {
-                        Ok(config) => {
-                            let config = *config;
-                            let name = config.name.clone();
-                            let instance = ServerInstance {
-                                config,
-                                container_id: None,
-                                status: ServerStatus::Stopped,
-                            };
-                            self.servers.push(instance);
-                            self.save_servers();
-                            self.refresh_orphaned_dirs();
-                            self.show_status_message(format!(
-                                "Server '{}' imported successfully!",
-                                name
-                            ));
-                        }
-                        Err(e) => {
-                            self.show_status_message(format!("Import failed: {}", e));
-                            self.log(format!("ERROR: Import failed: {}", e));
-                        }
-                    }
-                }
-                TaskMessage::ContainerConflict { server_name } => {
-                    if let Some(server) = self
-                        .servers
-                        .iter_mut()
-                        .find(|s| s.config.name == server_name)
-                    {
-                        server.status = ServerStatus::Stopped;
-                    }
-                    self.current_view = View::ConfirmRemoveContainer(server_name);
+                    });
+
+                    backup::restore_backup_with_progress(
+                        member,
+                        path,
+                        Some(progress_tx),
+                        Some(&cancel),
+                    )
+                    .map_err(|e| format!("'{}': {}", member, e))?;
                 }
+                Ok(set.len())
+            };
+            let result = restore_all();
+            let _ = tx.send(TaskMessage::GroupRestoreComplete { group, result });
+        });
+    }
+
+    fn view_backups(&mut self, name: &str) {
+        self.open_server_tab(name, DetailsTab::Backups);
+    }
+
+    fn load_backup_list(&mut self, name: &str) {
+        match backup::list_backups(name) {
+            Ok(backups) => {
+                self.backup_list = backups;
+            }
+            Err(e) => {
+                self.show_status_message(tr_args(
+                    "msg-backup-list-failed",
+                    &[("error", e.to_string().into())],
+                ));
             }
         }
     }
 
-    /// Return a mutable reference to the CF widget for whichever view is active.
-    fn active_cf_widget(&mut self) -> Option<&mut CfBrowseWidget> {
-        match &self.current_view {
-            View::CreateServer => Some(&mut self.create_view.cf),
-            View::EditServer(_) => Some(&mut self.edit_view.cf),
-            _ => None,
+    fn view_backup_players(&mut self, name: &str, backup_path: &std::path::Path) {
+        match backup::list_backup_players(backup_path) {
+            Ok(players) => {
+                self.backup_players = players;
+                self.current_view =
+                    View::BackupPlayers(name.to_string(), backup_path.to_path_buf());
+            }
+            Err(e) => {
+                self.show_status_message(tr_args(
+                    "msg-backup-read-failed",
+                    &[("error", e.to_string().into())],
+                ));
+            }
         }
     }
 
-    /// Spawn an async CurseForge search task.
-    fn dispatch_cf_search(&self, state: CfSearchState) {
-        let api_key = self
-            .settings
-            .curseforge_api_key
-            .clone()
-            .unwrap_or_default();
-        let tx = self.task_tx.clone();
-        let query = state.query.clone();
-        let mc_ver = state.mc_version_filter.clone();
-        let loader = state.selected_loader();
-        let sort_field = state.sort_field;
-        let page_offset = state.page_offset;
+    fn view_backup_contents(&mut self, name: &str, backup_path: &std::path::Path) {
+        match backup::list_backup_entries(backup_path) {
+            Ok(entries) => {
+                self.backup_contents = Some(BackupContentsView::new(backup_path, &entries));
+                self.current_view =
+                    View::BackupContents(name.to_string(), backup_path.to_path_buf());
+            }
+            Err(e) => {
+                self.show_status_message(tr_args(
+                    "msg-backup-read-failed",
+                    &[("error", e.to_string().into())],
+                ));
+            }
+        }
+    }
 
-        self.runtime.spawn(async move {
-            match curseforge::search_modpacks(
-                &api_key,
-                &query,
-                &mc_ver,
-                loader.as_ref(),
-                sort_field,
-                page_offset,
-            )
-            .await
-            {
-                Ok((results, total_count)) => {
-                    tx.send(TaskMessage::CfSearchResults {
-                        results,
-                        total_count,
-                    })
-                    .ok();
-                }
-                Err(e) => {
-                    tx.send(TaskMessage::CfSearchError(e.to_string())).ok();
-                }
+    /// Open the "Restore as new server" form with a free name and port filled in
+    fn start_restore_as_new(&mut self, name: &str, backup_path: &std::path::Path) {
+        let base_port = self
+            .servers
+            .iter()
+            .find(|s| s.config.name == name)
+            .map_or(25565, |s| s.config.port);
+        let mut new_name = format!("{}-restored", name);
+        for n in 2.. {
+            if self.restore_new_name_problem(&new_name).is_none() {
+                break;
             }
-        });
+            new_name = format!("{}-restored-{}", name, n);
+        }
+        self.restore_new_name = new_name;
+        self.restore_new_port = (base_port.saturating_add(1)..u16::MAX - 10)
+            .find(|p| self.restore_new_port_problem(*p).is_none())
+            .unwrap_or(base_port);
+        self.current_view = View::RestoreAsNew(name.to_string(), backup_path.to_path_buf());
     }
 
-    /// Spawn an async CurseForge version fetch task.
-    fn dispatch_cf_fetch_versions(&self, mod_id: u64) {
-        let api_key = self
-            .settings
-            .curseforge_api_key
-            .clone()
-            .unwrap_or_default();
-        let tx = self.task_tx.clone();
+    /// Why `name` can't be used for a new server, if it can't
+    fn restore_new_name_problem(&self, name: &str) -> Option<String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Some(tr("msg-new-name-empty"));
+        }
+        if name.contains(['/', '\\']) || name == "." || name == ".." {
+            return Some(tr("msg-new-name-slashes"));
+        }
+        if self.servers.iter().any(|s| s.config.name == name) {
+            return Some(tr_args("msg-new-name-taken", &[("name", name.into())]));
+        }
+        if get_server_path(name).exists() {
+            return Some(tr_args("msg-new-name-folder", &[("name", name.into())]));
+        }
+        None
+    }
 
-        self.runtime.spawn(async move {
-            match curseforge::get_mod_files(&api_key, mod_id).await {
-                Ok(files) => {
-                    tx.send(TaskMessage::CfVersionResults { mod_id, files })
-                        .ok();
-                }
-                Err(e) => {
-                    tx.send(TaskMessage::CfVersionError {
-                        mod_id,
-                        error: e.to_string(),
-                    })
-                    .ok();
-                }
-            }
-        });
+    /// Why `port` (and its RCON port) can't be used for a new server, if it can't
+    fn restore_new_port_problem(&self, port: u16) -> Option<String> {
+        let rcon = port.checked_add(10)?;
+        self.servers
+            .iter()
+            .find(|s| {
+                let used = s.config.all_ports();
+                used.contains(&port) || used.contains(&rcon)
+            })
+            .map(|s| {
+                tr_args(
+                    "msg-new-port-taken",
+                    &[
+                        ("port", port.to_string().into()),
+                        ("rcon", rcon.to_string().into()),
+                        ("server", s.config.name.clone().into()),
+                    ],
+                )
+            })
     }
 
-    /// Spawn an async CurseForge description fetch task.
-    fn dispatch_cf_fetch_description(&self, mod_id: u64) {
-        let api_key = self
-            .settings
-            .curseforge_api_key
-            .clone()
-            .unwrap_or_default();
+    /// Add a server cloned from `source_name`'s config and fill its data folder from
+    /// a backup, leaving the original untouched
+    fn restore_as_new(&mut self, source_name: &str, backup_path: &std::path::Path) {
+        if self.restore_progress.is_some() {
+            self.show_status_message(tr("msg-restore-busy"));
+            return;
+        }
+        let new_name = self.restore_new_name.trim().to_string();
+        let port = self.restore_new_port;
+        if let Some(problem) = self
+            .restore_new_name_problem(&new_name)
+            .or_else(|| self.restore_new_port_problem(port))
+        {
+            self.show_status_message(problem);
+            return;
+        }
+
+        // Settings come from the original, or from the bundle when the backup is a
+        // full export; a plain backup of a deleted server has nothing to go on
+        let source = self
+            .servers
+            .iter()
+            .find(|s| s.config.name == source_name)
+            .map(|s| s.config.clone())
+            .or_else(|| backup::read_export_config(backup_path).ok());
+        let Some(source) = source else {
+            self.show_status_message(tr_args(
+                "msg-restore-new-no-settings",
+                &[("name", source_name.into())],
+            ));
+            return;
+        };
+        // A fresh config only to draw a new RCON password
+        let rcon_password =
+            ServerConfig::new(new_name.clone(), source.modpack.clone()).rcon_password;
+        let mut config = ServerConfig {
+            name: new_name.clone(),
+            port,
+            rcon_password,
+            ..source
+        };
+        // The copy is for trying things out: it publishes no extra ports the original
+        // holds, and stays out of the original's schedules, group and uploads
+        config.extra_ports.clear();
+        config.backup_group = None;
+        config.backup_schedule.enabled = false;
+        config.remote_backup = false;
+        config.gc_benchmarks.clear();
+        config.crash_report_seen = None;
+
+        self.servers.push(ServerInstance {
+            config,
+            container_id: None,
+            status: ServerStatus::Stopped,
+        });
+        self.save_servers();
+        self.log(tr_args(
+            "log-restoring-into-new",
+            &[
+                ("backup", format!("{:?}", backup_path).into()),
+                ("source", source_name.into()),
+                ("name", new_name.as_str().into()),
+            ],
+        ));
+        self.restore_progress = Some((new_name.clone(), 0, 0, tr("progress-starting-restore")));
+        self.current_view = View::Dashboard;
+
+        let server_name = new_name;
+        let backup_path = backup_path.to_path_buf();
         let tx = self.task_tx.clone();
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.restore_cancel = Some(cancel.clone());
 
-        self.runtime.spawn(async move {
-            match curseforge::get_mod_description(&api_key, mod_id).await {
-                Ok(description) => {
-                    tx.send(TaskMessage::CfDescriptionResult {
-                        mod_id,
-                        description,
-                    })
-                    .ok();
-                }
-                Err(e) => {
-                    tx.send(TaskMessage::CfDescriptionError {
-                        mod_id,
-                        error: e.to_string(),
-                    })
-                    .ok();
+        std::thread::spawn(move || {
+            let (progress_tx, progress_rx) = std::sync::mpsc::channel::<backup::BackupProgress>();
+            let tx_progress = tx.clone();
+            let name_for_progress = server_name.clone();
+            std::thread::spawn(move || {
+                while let Ok(progress) = progress_rx.recv() {
+                    let _ = tx_progress.send(TaskMessage::RestoreProgress {
+                        server_name: name_for_progress.clone(),
+                        current: progress.current,
+                        total: progress.total,
+                        current_file: progress.current_file,
+                    });
                 }
-            }
+            });
+
+            let result = backup::restore_backup_with_progress(
+                &server_name,
+                &backup_path,
+                Some(progress_tx),
+                Some(&cancel),
+            );
+            let _ = tx.send(TaskMessage::RestoreAsNewComplete {
+                server_name,
+                result: result.map_err(|e| e.to_string()),
+            });
         });
     }
 
-    /// Return a mutable reference to the MR widget for whichever view is active.
-    fn active_mr_widget(&mut self) -> Option<&mut MrBrowseWidget> {
-        match &self.current_view {
-            View::CreateServer => Some(&mut self.create_view.mr),
-            View::EditServer(_) => Some(&mut self.edit_view.mr),
-            _ => None,
+    /// Put back only the chosen files and folders from a backup
+    fn restore_selected(&mut self, name: &str, backup_path: &std::path::Path, paths: Vec<String>) {
+        if self.restore_progress.is_some() {
+            self.show_status_message(tr("msg-restore-busy"));
+            return;
+        }
+        let running = self
+            .servers
+            .iter()
+            .find(|s| s.config.name == name)
+            .is_some_and(|s| !matches!(s.status, ServerStatus::Stopped | ServerStatus::Error(_)));
+        if running {
+            self.show_status_message(tr("msg-restore-files-running"));
+            return;
         }
-    }
 
-    /// Spawn an async Modrinth search task.
-    fn dispatch_mr_search(&self, state: MrSearchState) {
+        self.log(tr_args(
+            "log-restoring-files",
+            &[
+                ("paths", paths.join(", ").into()),
+                ("backup", format!("{:?}", backup_path).into()),
+                ("name", name.into()),
+            ],
+        ));
+        self.restore_progress = Some((name.to_string(), 0, 0, tr("progress-starting-restore")));
+        self.current_view = View::Dashboard;
+        self.backup_contents = None;
+
+        let server_name = name.to_string();
+        let backup_path = backup_path.to_path_buf();
         let tx = self.task_tx.clone();
-        let query = state.query.clone();
-        let mc_ver = state.mc_version_filter.clone();
-        let loader = state.selected_loader_str().to_string();
-        let sort = state.sort_index;
-        let page_offset = state.page_offset;
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.restore_cancel = Some(cancel.clone());
 
-        self.runtime.spawn(async move {
-            match modrinth::search_modpacks(&query, &mc_ver, &loader, sort, page_offset).await {
-                Ok((results, total_count)) => {
-                    tx.send(TaskMessage::MrSearchResults {
-                        results,
-                        total_count,
-                    })
-                    .ok();
-                }
-                Err(e) => {
-                    tx.send(TaskMessage::MrSearchError(e.to_string())).ok();
+        std::thread::spawn(move || {
+            let (progress_tx, progress_rx) = std::sync::mpsc::channel::<backup::BackupProgress>();
+            let tx_progress = tx.clone();
+            let name_for_progress = server_name.clone();
+            std::thread::spawn(move || {
+                while let Ok(progress) = progress_rx.recv() {
+                    let _ = tx_progress.send(TaskMessage::RestoreProgress {
+                        server_name: name_for_progress.clone(),
+                        current: progress.current,
+                        total: progress.total,
+                        current_file: progress.current_file,
+                    });
                 }
-            }
+            });
+
+            let result = backup::restore_paths_with_progress(
+                &server_name,
+                &backup_path,
+                &paths,
+                Some(progress_tx),
+                Some(&cancel),
+            );
+            let _ = tx.send(TaskMessage::SelectiveRestoreComplete {
+                server_name,
+                result: result.map_err(|e| e.to_string()),
+            });
         });
     }
 
-    /// Spawn an async Modrinth version fetch task.
-    fn dispatch_mr_fetch_versions(&self, project_id: String) {
-        let tx = self.task_tx.clone();
-        let pid = project_id.clone();
+    /// Roll one player back to their state in a backup, leaving the world untouched
+    fn restore_player(&mut self, name: &str, backup_path: &std::path::Path, player: &BackupPlayer) {
+        let running = self
+            .servers
+            .iter()
+            .find(|s| s.config.name == name)
+            .is_some_and(|s| !matches!(s.status, ServerStatus::Stopped | ServerStatus::Error(_)));
+        if running {
+            // The server rewrites player files on save and logout
+            self.show_status_message(tr("msg-restore-player-running"));
+            return;
+        }
 
-        self.runtime.spawn(async move {
-            match modrinth::get_project_versions(&pid).await {
-                Ok(versions) => {
-                    tx.send(TaskMessage::MrVersionResults {
-                        project_id,
-                        versions,
-                    })
-                    .ok();
-                }
-                Err(e) => {
-                    tx.send(TaskMessage::MrVersionError {
-                        project_id,
-                        error: e.to_string(),
+        match backup::restore_player(name, backup_path, player) {
+            Ok(count) => {
+                self.show_status_message(tr_args(
+                    "msg-player-restored",
+                    &[("player", player.display_name().to_string().into())],
+                ));
+                self.log(tr_args(
+                    "log-player-restored",
+                    &[
+                        ("count", count.into()),
+                        ("player", player.display_name().into()),
+                        ("uuid", player.uuid.as_str().into()),
+                        ("name", name.into()),
+                        ("backup", format!("{:?}", backup_path).into()),
+                    ],
+                ));
+            }
+            Err(e) => {
+                self.show_status_message(tr_args(
+                    "msg-player-restore-failed",
+                    &[("error", e.to_string().into())],
+                ));
+                self.log(format!(
+                    "ERROR: {}",
+                    tr_args(
+                        "log-player-restore-failed",
+                        &[
+                            ("uuid", player.uuid.as_str().into()),
+                            ("error", e.to_string().into())
+                        ]
+                    )
+                ));
+            }
+        }
+    }
+
+    fn view_mods(&mut self, name: &str) {
+        self.mod_graph = None;
+        self.current_view = View::Mods(name.to_string());
+        self.scan_mods(name);
+    }
+
+    /// Read mod metadata in the background; large packs have hundreds of jars
+    fn scan_mods(&mut self, name: &str) {
+        self.mods_scanning = true;
+        let tx = self.task_tx.clone();
+        let server_name = name.to_string();
+        let mods_dir = get_server_data_path(name).join("mods");
+        std::thread::spawn(move || {
+            let result = mods::scan(&mods_dir).map_err(|e| e.to_string());
+            tx.send(TaskMessage::ModScanComplete {
+                server_name,
+                result,
+            })
+            .ok();
+        });
+    }
+
+    /// Download missing dependencies from Modrinth into the server's mods folder
+    fn install_mod_dependencies(&mut self, name: &str, mod_ids: Vec<String>) {
+        let Some(server) = self.servers.iter().find(|s| s.config.name == name) else {
+            return;
+        };
+        let loader = server.config.modpack.loader.clone();
+        let mc_version = server.config.modpack.minecraft_version.clone();
+        let mods_dir = get_server_data_path(name).join("mods");
+        let token = self.settings.modrinth_token.clone();
+
+        for mod_id in mod_ids {
+            if self.mods_installing.contains(&mod_id) {
+                continue;
+            }
+            self.mods_installing.push(mod_id.clone());
+            let tx = self.task_tx.clone();
+            let server_name = name.to_string();
+            let loader = loader.clone();
+            let mc_version = mc_version.clone();
+            let mods_dir = mods_dir.clone();
+            let token = token.clone();
+            self.runtime.spawn(async move {
+                let result = mods::install_from_modrinth(
+                    token.as_deref(),
+                    &mods_dir,
+                    &mod_id,
+                    &loader,
+                    &mc_version,
+                )
+                .await
+                .map_err(|e| e.to_string());
+                tx.send(TaskMessage::ModInstallComplete {
+                    server_name,
+                    mod_id,
+                    result,
+                })
+                .ok();
+            });
+        }
+    }
+
+    /// Actions shared by the Mods view and the Mods tab of the details page
+    fn run_mods_action(&mut self, name: &str, action: ModsAction) {
+        match action {
+            ModsAction::Rescan => self.scan_mods(name),
+            ModsAction::Install(mod_id) => {
+                self.install_mod_dependencies(name, vec![mod_id]);
+            }
+            ModsAction::InstallAllMissing => {
+                let missing = self
+                    .mod_graph
+                    .iter()
+                    .flat_map(|g| g.issues.iter())
+                    .filter_map(|i| match i {
+                        DependencyIssue::Missing { mod_id, .. } => Some(mod_id.clone()),
+                        _ => None,
                     })
-                    .ok();
-                }
+                    .collect();
+                self.install_mod_dependencies(name, missing);
             }
+            ModsAction::RemoveUnused(files) => self.remove_mod_jars(name, &files),
+            ModsAction::Delete(file) => self.remove_mod_jars(name, &[file]),
+            ModsAction::Add(paths) => self.add_mod_jars(name, &paths),
+            ModsAction::Browse => self.open_mod_browse(name),
+            ModsAction::Dependencies => {
+                // Keeps the graph already read for the tab
+                self.current_view = View::Mods(name.to_string());
+            }
+            ModsAction::Back => {
+                self.mod_graph = None;
+                self.current_view = View::Dashboard;
+            }
+        }
+    }
+
+    fn open_mod_browse(&mut self, name: &str) {
+        self.mod_browse = ModBrowseView::new(self.mod_browse.site);
+        self.current_view = View::ModBrowse(name.to_string());
+        if self.mod_graph.is_none() && !self.mods_scanning {
+            self.scan_mods(name);
+        }
+        // Start with the most popular mods that fit the server
+        self.dispatch_mod_search(name);
+    }
+
+    /// Search site and compatibility target for a server's mods
+    fn mod_query(&self, name: &str) -> Option<ModQuery> {
+        let server = self.servers.iter().find(|s| s.config.name == name)?;
+        let key = match self.mod_browse.site {
+            mod_browse::ModSite::Modrinth => self.settings.modrinth_token.clone(),
+            mod_browse::ModSite::CurseForge => self.settings.curseforge_api_key.clone(),
+        };
+        Some(ModQuery {
+            site: self.mod_browse.site,
+            key,
+            loader: server.config.modpack.loader.clone(),
+            minecraft_version: server.config.modpack.minecraft_version.clone(),
+        })
+    }
+
+    /// Normalized IDs of every mod on the server, from the last scan
+    fn installed_mod_ids(&self) -> HashSet<String> {
+        self.mod_graph
+            .iter()
+            .flat_map(|g| g.mods.iter())
+            .flat_map(|m| std::iter::once(&m.mod_id).chain(m.provides.iter()))
+            .map(|id| mod_browse::normalize_id(id))
+            .collect()
+    }
+
+    fn dispatch_mod_search(&mut self, name: &str) {
+        let Some(query) = self.mod_query(name) else {
+            return;
+        };
+        let text = self.mod_browse.query.trim().to_string();
+        let offset = self.mod_browse.offset;
+        let tx = self.task_tx.clone();
+        self.runtime.spawn(async move {
+            let result = mod_browse::search(&query, &text, offset)
+                .await
+                .map_err(|e| e.to_string());
+            tx.send(TaskMessage::ModSearchResults(result)).ok();
         });
     }
 
-    /// Spawn an async Modrinth description fetch task.
-    fn dispatch_mr_fetch_description(&self, project_id: String) {
+    fn dispatch_mod_releases(&mut self, name: &str, id: String) {
+        let Some(query) = self.mod_query(name) else {
+            return;
+        };
         let tx = self.task_tx.clone();
-        let pid = project_id.clone();
+        self.runtime.spawn(async move {
+            let result = mod_browse::releases(&query, &id)
+                .await
+                .map_err(|e| e.to_string());
+            tx.send(TaskMessage::ModReleasesResult { id, result }).ok();
+        });
+    }
+
+    /// Download a mod picked in the add mods view, plus the dependencies it needs
+    fn install_browsed_mod(&mut self, name: &str, release: ModRelease, title: String) {
+        let Some(query) = self.mod_query(name) else {
+            return;
+        };
+        let installed = self.installed_mod_ids();
+        let mods_dir = get_server_data_path(name).join("mods");
+        self.mod_browse.installing.push(title.clone());
+        self.log(tr_args(
+            "log-installing-mod",
+            &[
+                ("file", release.file_name.as_str().into()),
+                ("name", name.into()),
+            ],
+        ));
 
+        let tx = self.task_tx.clone();
+        let server_name = name.to_string();
         self.runtime.spawn(async move {
-            match modrinth::get_project_description(&pid).await {
-                Ok(description) => {
-                    tx.send(TaskMessage::MrDescriptionResult {
-                        project_id,
-                        description,
-                    })
-                    .ok();
+            let result = mod_browse::install(&query, &mods_dir, release, &installed)
+                .await
+                .map_err(|e| format!("{:#}", e));
+            tx.send(TaskMessage::ModBrowseInstallComplete {
+                server_name,
+                title,
+                result,
+            })
+            .ok();
+        });
+    }
+
+    /// Copy jars picked or dropped by the user into the mods folder, then rescan
+    fn add_mod_jars(&mut self, name: &str, paths: &[std::path::PathBuf]) {
+        let mods_dir = get_server_data_path(name).join("mods");
+        match mods::add_mods(&mods_dir, paths) {
+            Ok(added) if added.is_empty() => {
+                self.show_status_message(tr("msg-mods-only-jars"));
+                return;
+            }
+            Ok(added) => {
+                self.show_status_message(tr_args(
+                    "msg-mods-added",
+                    &[("count", added.len().into())],
+                ));
+                self.log(tr_args(
+                    "log-mods-added",
+                    &[("mods", added.join(", ").into()), ("name", name.into())],
+                ));
+            }
+            Err(e) => {
+                self.show_status_message(tr_args(
+                    "msg-mods-add-failed",
+                    &[("error", e.to_string().into())],
+                ));
+                self.log(format!(
+                    "ERROR: {}",
+                    tr_args(
+                        "log-add-mods-failed",
+                        &[("name", name.into()), ("error", e.to_string().into())]
+                    )
+                ));
+            }
+        }
+        self.scan_mods(name);
+    }
+
+    /// Move jars out of the mods folder, then rescan (removals can orphan more)
+    fn remove_mod_jars(&mut self, name: &str, files: &[String]) {
+        let running = self
+            .servers
+            .iter()
+            .find(|s| s.config.name == name)
+            .is_some_and(|s| !matches!(s.status, ServerStatus::Stopped | ServerStatus::Error(_)));
+        if running {
+            self.show_status_message(tr("msg-mods-remove-running"));
+            return;
+        }
+
+        let mods_dir = get_server_data_path(name).join("mods");
+        let archive_dir = get_server_path(name).join("removed-mods");
+        match mods::remove_mods(&mods_dir, &archive_dir, files) {
+            Ok(count) => {
+                self.show_status_message(tr_args("msg-mods-removed", &[("count", count.into())]));
+                self.log(tr_args(
+                    "log-mods-moved",
+                    &[
+                        ("count", count.into()),
+                        ("name", name.into()),
+                        ("folder", format!("{:?}", archive_dir).into()),
+                    ],
+                ));
+            }
+            Err(e) => {
+                self.show_status_message(tr_args(
+                    "msg-mods-remove-failed",
+                    &[("error", e.to_string().into())],
+                ));
+                self.log(format!(
+                    "ERROR: {}",
+                    tr_args("log-remove-mods-failed", &[("error", e.to_string().into())])
+                ));
+            }
+        }
+        self.scan_mods(name);
+    }
+
+    fn restore_backup(&mut self, name: &str, backup_path: &std::path::Path) {
+        // Check if a restore is already in progress
+        if self.restore_progress.is_some() {
+            self.show_status_message(tr("msg-restore-busy"));
+            return;
+        }
+
+        self.log(tr_args("log-restoring-backup", &[("name", name.into())]));
+        self.restore_progress = Some((name.to_string(), 0, 0, tr("progress-starting-restore")));
+        self.current_view = View::Dashboard;
+
+        let server_name = name.to_string();
+        let backup_path = backup_path.to_path_buf();
+        let tx = self.task_tx.clone();
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.restore_cancel = Some(cancel.clone());
+
+        // Run restore in background thread
+        std::thread::spawn(move || {
+            let (progress_tx, progress_rx) = std::sync::mpsc::channel::<backup::BackupProgress>();
+
+            // Spawn a thread to forward progress updates
+            let tx_progress = tx.clone();
+            let name_for_progress = server_name.clone();
+            std::thread::spawn(move || {
+                while let Ok(progress) = progress_rx.recv() {
+                    let _ = tx_progress.send(TaskMessage::RestoreProgress {
+                        server_name: name_for_progress.clone(),
+                        current: progress.current,
+                        total: progress.total,
+                        current_file: progress.current_file,
+                    });
                 }
-                Err(e) => {
-                    tx.send(TaskMessage::MrDescriptionError {
-                        project_id,
-                        error: e.to_string(),
+            });
+
+            let result = backup::restore_backup_with_progress(
+                &server_name,
+                &backup_path,
+                Some(progress_tx),
+                Some(&cancel),
+            );
+            let _ = tx.send(TaskMessage::RestoreComplete {
+                server_name,
+                result: result.map_err(|e| e.to_string()),
+            });
+        });
+    }
+
+    fn delete_backup(&mut self, name: &str, backup_path: &std::path::Path) {
+        match backup::delete_backup(backup_path) {
+            Ok(()) => {
+                self.show_status_message(tr("msg-backup-deleted"));
+                // Refresh the backup list
+                self.view_backups(name);
+            }
+            Err(e) => {
+                self.show_status_message(tr_args(
+                    "msg-backup-delete-failed",
+                    &[("error", e.to_string().into())],
+                ));
+            }
+        }
+    }
+
+    fn export_server(&mut self, name: &str) {
+        // Check if an export is already in progress
+        if self.export_progress.is_some() {
+            self.show_status_message(tr("msg-export-busy"));
+            return;
+        }
+
+        let Some(server) = self.servers.iter().find(|s| s.config.name == name) else {
+            self.show_status_message(tr_args("msg-server-not-found", &[("name", name.into())]));
+            return;
+        };
+        let config = server.config.clone();
+        let data_path = get_server_data_path(name);
+
+        // Open native save dialog
+        let default_name = format!("{}.drakonixanvil-server.zip", name);
+        let save_path = rfd::FileDialog::new()
+            .set_file_name(&default_name)
+            .add_filter(tr("msg-bundle-filter"), &["zip"])
+            .save_file();
+
+        let Some(output_path) = save_path else {
+            return; // User cancelled
+        };
+
+        self.log(tr_args("log-exporting", &[("name", name.into())]));
+        self.export_progress = Some((name.to_string(), 0, 0, tr("progress-counting-files")));
+
+        let server_name = name.to_string();
+        let tx = self.task_tx.clone();
+
+        std::thread::spawn(move || {
+            let (progress_tx, progress_rx) = std::sync::mpsc::channel::<backup::BackupProgress>();
+
+            let tx_progress = tx.clone();
+            let name_for_progress = server_name.clone();
+            std::thread::spawn(move || {
+                while let Ok(progress) = progress_rx.recv() {
+                    let _ = tx_progress.send(TaskMessage::ExportProgress {
+                        server_name: name_for_progress.clone(),
+                        current: progress.current,
+                        total: progress.total,
+                        current_file: progress.current_file,
+                    });
+                }
+            });
+
+            let result = backup::export_server_with_progress(
+                &config,
+                &data_path,
+                &output_path,
+                Some(progress_tx),
+            );
+            let _ = tx.send(TaskMessage::ExportComplete {
+                server_name,
+                result: result.map_err(|e| e.to_string()),
+            });
+        });
+    }
+
+    /// Zip just the world so players can open the map in singleplayer. A running
+    /// server has world saves paused while it's read, as for hot backups.
+    fn export_world(&mut self, name: &str) {
+        if self.export_progress.is_some() {
+            self.show_status_message(tr("msg-export-busy"));
+            return;
+        }
+
+        let Some(server) = self.servers.iter().find(|s| s.config.name == name) else {
+            self.show_status_message(tr_args("msg-server-not-found", &[("name", name.into())]));
+            return;
+        };
+        let live = (server.status == ServerStatus::Running).then(|| {
+            (
+                format!("127.0.0.1:{}", server.config.rcon_port()),
+                server.config.rcon_password.clone(),
+            )
+        });
+        let data_path = get_server_data_path(name);
+
+        let default_name = format!(
+            "{}-{}-{}.zip",
+            name,
+            backup::level_name(&data_path),
+            chrono::Local::now().format("%Y%m%d")
+        );
+        let save_path = rfd::FileDialog::new()
+            .set_file_name(&default_name)
+            .add_filter(tr("msg-world-filter"), &["zip"])
+            .save_file();
+
+        let Some(output_path) = save_path else {
+            return; // User cancelled
+        };
+
+        self.log(tr_args("log-exporting-world", &[("name", name.into())]));
+        self.export_progress = Some((name.to_string(), 0, 0, tr("progress-counting-files")));
+
+        let server_name = name.to_string();
+        let tx = self.task_tx.clone();
+        self.runtime.spawn(async move {
+            if let Some((address, password)) = &live {
+                if let Err(e) = rcon::pause_saving(address, password).await {
+                    tx.send(TaskMessage::ExportComplete {
+                        server_name,
+                        result: Err(format!("Could not pause world saves: {}", e)),
                     })
                     .ok();
+                    return;
+                }
+            }
+
+            let tx_zip = tx.clone();
+            let name = server_name.clone();
+            let result = tokio::task::spawn_blocking(move || {
+                let (progress_tx, progress_rx) =
+                    std::sync::mpsc::channel::<backup::BackupProgress>();
+                let tx_progress = tx_zip.clone();
+                std::thread::spawn(move || {
+                    while let Ok(progress) = progress_rx.recv() {
+                        let _ = tx_progress.send(TaskMessage::ExportProgress {
+                            server_name: name.clone(),
+                            current: progress.current,
+                            total: progress.total,
+                            current_file: progress.current_file,
+                        });
+                    }
+                });
+                backup::export_world_with_progress(&data_path, &output_path, Some(progress_tx))
+                    .map_err(|e| e.to_string())
+            })
+            .await
+            .unwrap_or_else(|e| Err(e.to_string()));
+
+            if let Some((address, password)) = &live {
+                if let Err(e) = rcon::resume_saving(address, password).await {
+                    tx.send(TaskMessage::Log(format!(
+                        "ERROR: {}",
+                        tr_args(
+                            "log-save-on-failed",
+                            &[
+                                ("name", server_name.as_str().into()),
+                                ("error", e.to_string().into())
+                            ]
+                        )
+                    )))
+                    .ok();
+                }
+            }
+            tx.send(TaskMessage::ExportComplete {
+                server_name,
+                result,
+            })
+            .ok();
+        });
+    }
+
+    /// Build a `.mrpack` or CurseForge zip of the server's mods and configs for
+    /// players, linking jars the site hosts and bundling the rest
+    fn export_client_pack(&mut self, name: &str) {
+        if self.export_progress.is_some() {
+            self.show_status_message(tr("msg-export-busy"));
+            return;
+        }
+
+        let Some(server) = self.servers.iter().find(|s| s.config.name == name) else {
+            self.show_status_message(tr_args("msg-server-not-found", &[("name", name.into())]));
+            return;
+        };
+        let config = server.config.clone();
+        let data_path = get_server_data_path(name);
+
+        let save_path = rfd::FileDialog::new()
+            .set_file_name(format!("{}-client.mrpack", name))
+            .add_filter(tr("edit-mrpack-filter"), &["mrpack"])
+            .add_filter(tr("msg-curseforge-pack-filter"), &["zip"])
+            .save_file();
+        let Some(output_path) = save_path else {
+            return; // User cancelled
+        };
+        let format = ClientPackFormat::from_path(&output_path);
+        let key = match format {
+            ClientPackFormat::Modrinth => self.settings.modrinth_token.clone(),
+            ClientPackFormat::CurseForge => self.settings.curseforge_api_key.clone(),
+        };
+
+        self.log(tr_args(
+            "log-exporting-client-pack",
+            &[("format", format.label().into()), ("name", name.into())],
+        ));
+        self.export_progress = Some((name.to_string(), 0, 0, tr("progress-looking-up-mods")));
+
+        let server_name = name.to_string();
+        let tx = self.task_tx.clone();
+        self.runtime.spawn(async move {
+            let plan = match client_pack::plan(&config, &data_path, format, key.as_deref()).await {
+                Ok(plan) => plan,
+                Err(e) => {
+                    tx.send(TaskMessage::ExportComplete {
+                        server_name,
+                        result: Err(format!("{:#}", e)),
+                    })
+                    .ok();
+                    return;
+                }
+            };
+            let args = [
+                ("name", server_name.as_str().into()),
+                ("linked", plan.linked.into()),
+                ("site", format.label().into()),
+                ("bundled", plan.bundled.len().into()),
+                ("skipped", plan.server_only.len().into()),
+                ("mods", plan.server_only.join(", ").into()),
+            ];
+            let summary = if plan.server_only.is_empty() {
+                tr_args("log-client-pack", &args)
+            } else {
+                tr_args("log-client-pack-left-out", &args)
+            };
+            tx.send(TaskMessage::Log(summary)).ok();
+            if plan.loader_version.is_none() {
+                tx.send(TaskMessage::Log(format!(
+                    "WARNING: {}",
+                    tr_args(
+                        "log-client-loader-unknown",
+                        &[
+                            ("loader", format!("{:?}", config.modpack.loader).into()),
+                            ("name", server_name.as_str().into())
+                        ]
+                    )
+                )))
+                .ok();
+            }
+
+            let tx_zip = tx.clone();
+            let name = server_name.clone();
+            let result = tokio::task::spawn_blocking(move || {
+                let (progress_tx, progress_rx) =
+                    std::sync::mpsc::channel::<backup::BackupProgress>();
+                std::thread::spawn(move || {
+                    while let Ok(progress) = progress_rx.recv() {
+                        let _ = tx_zip.send(TaskMessage::ExportProgress {
+                            server_name: name.clone(),
+                            current: progress.current,
+                            total: progress.total,
+                            current_file: progress.current_file,
+                        });
+                    }
+                });
+                backup::write_client_pack(
+                    &data_path,
+                    &output_path,
+                    plan.index_name,
+                    &plan.index,
+                    &plan.bundled,
+                    client_pack::CLIENT_FOLDERS,
+                    Some(progress_tx),
+                )
+                .map_err(|e| e.to_string())
+            })
+            .await
+            .unwrap_or_else(|e| Err(e.to_string()));
+            tx.send(TaskMessage::ExportComplete {
+                server_name,
+                result,
+            })
+            .ok();
+        });
+    }
+
+    /// Pick a new folder for the data and show what moving there involves
+    fn pick_data_root(&mut self) {
+        if self.any_server_up() {
+            self.show_status_message(tr("msg-data-move-running"));
+            return;
+        }
+        let Some(picked) = rfd::FileDialog::new().pick_folder() else {
+            return; // User cancelled
+        };
+        let target = storage::target_for(&picked);
+        match storage::plan(&crate::config::data_root(), &target) {
+            Ok(plan) => {
+                self.data_move_plan = Some(plan);
+                self.current_view = View::ConfirmMoveData;
+            }
+            Err(e) => self.show_status_message(format!("{:#}", e)),
+        }
+    }
+
+    /// Whether any server isn't stopped (or failed), so its data may be in use
+    fn any_server_up(&self) -> bool {
+        self.servers
+            .iter()
+            .any(|s| !matches!(s.status, ServerStatus::Stopped | ServerStatus::Error(_)))
+    }
+
+    /// Move the data in the background; containers are recreated on next start
+    /// so their bind mounts point at the new folder. Schedules wait until it's done.
+    fn move_data_root(&mut self) {
+        let Some(plan) = self.data_move_plan.clone() else {
+            return;
+        };
+        if self.data_move_progress.is_some() {
+            return;
+        }
+        // Things may have started since the folder was picked
+        if self.any_server_up() || !self.preflight_running.is_empty() {
+            self.show_status_message(tr("msg-data-move-running"));
+            return;
+        }
+        if self.has_active_tasks() {
+            self.show_status_message(tr("msg-data-move-busy"));
+            return;
+        }
+        self.log(tr_args(
+            "log-moving-data",
+            &[
+                ("from", plan.from.display().to_string().into()),
+                ("to", plan.to.display().to_string().into()),
+            ],
+        ));
+        self.data_move_progress = Some((0, 0, tr("progress-moving")));
+        let tx = self.task_tx.clone();
+
+        std::thread::spawn(move || {
+            let (progress_tx, progress_rx) = std::sync::mpsc::channel::<backup::BackupProgress>();
+
+            let tx_progress = tx.clone();
+            std::thread::spawn(move || {
+                while let Ok(progress) = progress_rx.recv() {
+                    let _ = tx_progress.send(TaskMessage::DataMoveProgress {
+                        current: progress.current,
+                        total: progress.total,
+                        current_file: progress.current_file,
+                    });
+                }
+            });
+
+            let result = storage::move_data(&plan, Some(progress_tx));
+            let _ = tx.send(TaskMessage::DataMoveComplete {
+                result: result.map_err(|e| format!("{:#}", e)),
+            });
+        });
+    }
+
+    /// Switch to the moved data folder and drop the containers bound to the old one
+    fn finish_data_move(&mut self, result: Result<Vec<String>, String>) {
+        self.data_move_progress = None;
+        let Some(plan) = self.data_move_plan.take() else {
+            return;
+        };
+        self.current_view = View::Settings;
+        let left_behind = match result {
+            Ok(left_behind) => left_behind,
+            Err(e) => {
+                self.show_status_message(tr_args(
+                    "msg-data-move-failed",
+                    &[
+                        ("path", plan.from.display().to_string().into()),
+                        ("error", e.clone().into()),
+                    ],
+                ));
+                self.log(format!(
+                    "ERROR: {}",
+                    tr_args("log-data-move-failed", &[("error", e.to_string().into())])
+                ));
+                return;
+            }
+        };
+
+        self.settings.data_root = plan.setting();
+        crate::config::set_data_root(
+            self.settings
+                .data_root
+                .as_ref()
+                .map(std::path::PathBuf::from),
+        );
+        if let Err(e) = save_settings(&self.settings) {
+            self.log(format!(
+                "ERROR: {}",
+                tr_args(
+                    "log-data-folder-save-failed",
+                    &[("error", e.to_string().into())]
+                )
+            ));
+            self.data_root_unsaved = Some((plan.to.clone(), e.to_string()));
+        }
+
+        let container_names: Vec<String> = self
+            .servers
+            .iter()
+            .map(|s| get_container_name(&self.settings.instance_id, &s.config.name))
+            .collect();
+        for server in &mut self.servers {
+            server.container_id = None;
+        }
+        self.save_servers();
+        if let Some(docker) = self.docker.clone() {
+            self.runtime.spawn(async move {
+                for name in container_names {
+                    // Servers are stopped; containers that don't exist are fine
+                    let _ = docker.remove_container(&name).await;
+                }
+            });
+        }
+
+        self.log(tr_args(
+            "log-data-moved",
+            &[("path", plan.to.display().to_string().into())],
+        ));
+        if left_behind.is_empty() {
+            self.show_status_message(tr_args(
+                "msg-data-moved",
+                &[("path", plan.to.display().to_string().into())],
+            ));
+        } else {
+            self.log(format!(
+                "WARNING: {}",
+                tr_args(
+                    "log-data-left-behind",
+                    &[
+                        ("path", plan.from.display().to_string().into()),
+                        ("files", left_behind.join(", ").into())
+                    ]
+                )
+            ));
+            self.show_status_message(tr_args(
+                "msg-data-moved-partly",
+                &[
+                    ("path", plan.to.display().to_string().into()),
+                    ("files", left_behind.join(", ").into()),
+                ],
+            ));
+        }
+    }
+
+    fn import_server_dialog(&mut self) {
+        let file = rfd::FileDialog::new()
+            .add_filter(tr("msg-bundle-filter"), &["zip"])
+            .pick_file();
+
+        let Some(path) = file else {
+            return; // User cancelled
+        };
+
+        self.current_view = View::ConfirmImport(path);
+    }
+
+    /// Files dropped onto the window: server bundles go to the import preview,
+    /// pack zips and .mrpacks to the create view, and jars into the mods folder of
+    /// the server whose page is open
+    fn handle_dropped_files(&mut self, ctx: &egui::Context) {
+        let dropped: Vec<std::path::PathBuf> = ctx.input(|i| {
+            i.raw
+                .dropped_files
+                .iter()
+                .filter_map(|f| f.path.clone())
+                .collect()
+        });
+        if dropped.is_empty() {
+            return;
+        }
+
+        let lower = |p: &std::path::Path| {
+            p.file_name()
+                .map(|n| n.to_string_lossy().to_lowercase())
+                .unwrap_or_default()
+        };
+        let (jars, others): (Vec<_>, Vec<_>) = dropped
+            .into_iter()
+            .partition(|p| lower(p).ends_with(".jar"));
+
+        if !jars.is_empty() {
+            match &self.current_view {
+                View::ServerDetails(name) | View::Mods(name) | View::ModBrowse(name) => {
+                    let name = name.clone();
+                    self.add_mod_jars(&name, &jars);
+                }
+                _ => self.show_status_message(tr("msg-drop-jars-no-server")),
+            }
+        }
+
+        // Only one import or create flow can be open at a time
+        let Some(path) = others.into_iter().next() else {
+            return;
+        };
+        let name = lower(&path);
+        if name.ends_with(".drakonixanvil-server.zip") {
+            self.current_view = View::ConfirmImport(path);
+        } else if name.ends_with(".zip") || name.ends_with(".mrpack") {
+            self.create_view.reset();
+            self.create_view.open_local_pack(path);
+            self.current_view = View::CreateServer;
+        } else {
+            self.show_status_message(tr_args(
+                "msg-drop-unknown",
+                &[("file", path.display().to_string().into())],
+            ));
+        }
+    }
+
+    fn confirm_import(&mut self, path: &std::path::Path) {
+        self.log(tr_args(
+            "log-importing-server",
+            &[("path", format!("{:?}", path).into())],
+        ));
+
+        let zip_path = path.to_path_buf();
+        let servers_dir = crate::config::get_servers_root();
+        let tx = self.task_tx.clone();
+
+        std::thread::spawn(move || {
+            let result = backup::import_server(&zip_path, &servers_dir, None);
+            let _ = tx.send(TaskMessage::ImportComplete {
+                result: result.map(Box::new).map_err(|e| e.to_string()),
+            });
+        });
+
+        self.current_view = View::Dashboard;
+        self.show_status_message(tr("msg-importing-server"));
+    }
+
+    fn open_server_details(&mut self, name: &str) {
+        let running = self
+            .servers
+            .iter()
+            .any(|s| s.config.name == name && s.status == ServerStatus::Running);
+        self.details_tab = if running {
+            DetailsTab::Players
+        } else {
+            DetailsTab::Overview
+        };
+        self.online_players = None;
+        self.online_players_error = None;
+        self.online_players_polled = None;
+        self.player_message.clear();
+        self.whitelist_input.clear();
+        self.whitelist_busy = false;
+        self.load_whitelist(name);
+        self.online_ops = Self::read_ops(name);
+        self.crash_reports = crash::list(name);
+        self.mod_graph = None;
+        self.rcon_session = None;
+        self.files_view.reset();
+        self.load_edit_view(name);
+        self.current_view = View::ServerDetails(name.to_string());
+    }
+
+    /// Show one tab of a server's details page, opening the page first if needed
+    fn open_server_tab(&mut self, name: &str, tab: DetailsTab) {
+        if self.current_view != View::ServerDetails(name.to_string()) {
+            self.open_server_details(name);
+        }
+        self.select_details_tab(name, tab);
+    }
+
+    /// Switch tabs, loading what the new tab shows
+    fn select_details_tab(&mut self, name: &str, tab: DetailsTab) {
+        let entering = self.details_tab != tab;
+        if entering && self.details_tab == DetailsTab::Console {
+            self.rcon_session = None;
+        }
+        self.details_tab = tab;
+        match tab {
+            DetailsTab::Console if entering => self.start_console(name),
+            DetailsTab::Logs => self.load_container_logs(name),
+            DetailsTab::Backups => self.load_backup_list(name),
+            DetailsTab::Files if entering || self.files_view.entries.is_none() => {
+                self.load_files(name)
+            }
+            _ => {}
+        }
+    }
+
+    /// List the Files tab's folder in the background (folder sizes walk the tree)
+    fn load_files(&mut self, name: &str) {
+        self.files_view.busy = true;
+        let root = get_server_data_path(name);
+        let path = self.files_view.path.clone();
+        let server_name = name.to_string();
+        let tx = self.task_tx.clone();
+        self.runtime.spawn_blocking(move || {
+            let result = crate::files::list(&root, &path).map_err(|e| format!("{:#}", e));
+            tx.send(TaskMessage::FilesListed {
+                server_name,
+                path,
+                result,
+            })
+            .ok();
+        });
+    }
+
+    fn run_files_action(&mut self, name: &str, action: FilesAction) {
+        let root = get_server_data_path(name);
+        let rel = self.files_view.path.clone();
+        let op: Box<dyn FnOnce() -> anyhow::Result<String> + Send> = match action {
+            FilesAction::Open(path) => {
+                self.files_view.path = path;
+                self.files_view.entries = None;
+                self.load_files(name);
+                return;
+            }
+            FilesAction::Refresh => {
+                self.load_files(name);
+                return;
+            }
+            FilesAction::OpenInOs => {
+                let path = root.join(&rel);
+                if let Err(e) = open::that(&path) {
+                    tracing::error!("Failed to open folder {:?}: {}", path, e);
+                }
+                return;
+            }
+            FilesAction::Upload(paths) => Box::new(move || {
+                let count = crate::files::upload(&root, &rel, &paths)?;
+                Ok(tr_args(
+                    "files-op-uploaded",
+                    &[
+                        ("count", count.into()),
+                        ("folder", rel.display().to_string().into()),
+                    ],
+                ))
+            }),
+            FilesAction::Download { name, dest } => Box::new(move || {
+                crate::files::download(&root, &rel, &name, &dest)?;
+                Ok(tr_args(
+                    "files-op-saved",
+                    &[
+                        ("name", name.into()),
+                        ("path", dest.display().to_string().into()),
+                    ],
+                ))
+            }),
+            FilesAction::Rename { from, to } => Box::new(move || {
+                crate::files::rename(&root, &rel, &from, &to)?;
+                Ok(tr_args(
+                    "files-op-renamed",
+                    &[("from", from.into()), ("to", to.trim().to_string().into())],
+                ))
+            }),
+            FilesAction::Delete(file) => Box::new(move || {
+                crate::files::delete(&root, &rel, &file)?;
+                Ok(tr_args(
+                    "files-op-deleted",
+                    &[("path", rel.join(&file).display().to_string().into())],
+                ))
+            }),
+        };
+
+        self.files_view.busy = true;
+        let server_name = name.to_string();
+        let tx = self.task_tx.clone();
+        std::thread::spawn(move || {
+            let result = op().map_err(|e| format!("{:#}", e));
+            tx.send(TaskMessage::FileOpDone {
+                server_name,
+                result,
+            })
+            .ok();
+        });
+    }
+
+    /// RCON console for the Console tab
+    fn show_console_tab(&mut self, ui: &mut egui::Ui, ctx: &egui::Context, name: &str) {
+        let name = name.to_string();
+        // Show RCON password for reference
+        if let Some(server) = self.servers.iter().find(|s| s.config.name == name) {
+            ui.horizontal(|ui| {
+                ui.small(tr_args(
+                    "console-rcon-info",
+                    &[
+                        ("port", server.config.rcon_port().to_string().into()),
+                        ("password", server.config.rcon_password.clone().into()),
+                    ],
+                ));
+                if self.rcon_connected {
+                    ui.colored_label(egui::Color32::GREEN, tr("console-connected"));
+                } else {
+                    ui.colored_label(egui::Color32::GRAY, tr("console-not-connected"));
+                }
+                if !self.console_pending.is_empty() {
+                    ui.spinner();
+                }
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.button(tr("console-clear")).clicked() {
+                        self.console_output.clear();
+                    }
+                });
+            });
+        }
+        ui.separator();
+
+        // Keep the player list fresh for name completion
+        let players_stale = self
+            .console_players_refreshed
+            .is_none_or(|t| t.elapsed() > Duration::from_secs(30));
+        if self.rcon_connected && self.console_pending.is_empty() && players_stale {
+            self.refresh_console_players();
+        }
+
+        // Console output (scrollable)
+        let available_height = ui.available_height()
+            - QuickCommandBar::HEIGHT
+            - CommandLine::height(&self.console_input, &self.console_players);
+        egui::ScrollArea::vertical()
+            .max_height(available_height)
+            .stick_to_bottom(true)
+            .show(ui, |ui| {
+                for line in &self.console_output {
+                    ui.label(formatted_line(ui, line));
+                }
+            });
+
+        ui.separator();
+
+        // Quick-command buttons
+        let quick_commands = self
+            .servers
+            .iter()
+            .find(|s| s.config.name == name)
+            .map(|s| s.config.quick_commands.clone())
+            .unwrap_or_default();
+        let quick_action =
+            QuickCommandBar::show(ui, &quick_commands, &mut self.quick_command_draft);
+        match quick_action {
+            Some(QuickCommandAction::Send(cmd)) => self.send_rcon_command(&name, &cmd),
+            Some(QuickCommandAction::Save(commands)) => {
+                if let Some(server) = self.servers.iter_mut().find(|s| s.config.name == name) {
+                    server.config.quick_commands = commands;
+                }
+                self.save_servers();
+            }
+            None => {}
+        }
+
+        // Command input
+        if self.settings.shortcuts.pressed(ctx, Shortcut::FocusConsole) {
+            CommandLine::focus(ctx);
+        }
+        let shortcut_send = self.settings.shortcuts.pressed(ctx, Shortcut::SendCommand);
+        let send_command =
+            CommandLine::show(ui, &mut self.console_input, &self.console_players) || shortcut_send;
+
+        if send_command && !self.console_input.is_empty() {
+            let cmd = self.console_input.clone();
+            self.console_input.clear();
+            self.send_rcon_command(&name, &cmd);
+        }
+    }
+
+    /// Container or log file output for the Logs tab
+    fn show_logs_tab(&mut self, ui: &mut egui::Ui, ctx: &egui::Context, name: &str) {
+        let name = name.to_string();
+
+        // While following, Docker logs refresh every 5 seconds; log files are
+        // read locally, so they can keep up every second
+        let following = self.container_log_filter.follow();
+        let interval = if self.container_log_source == LogSource::Docker {
+            5
+        } else {
+            1
+        };
+        let should_refresh = self
+            .container_logs_last_refresh
+            .map(|t| following && t.elapsed().as_secs() >= interval)
+            .unwrap_or(true);
+        if should_refresh || self.settings.shortcuts.pressed(ctx, Shortcut::RefreshLogs) {
+            self.refresh_container_logs(&name);
+        }
+        if following {
+            // Request repaint to keep auto-refresh going
+            ctx.request_repaint_after(std::time::Duration::from_secs(1));
+        }
+
+        ui.horizontal(|ui| {
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button(tr("button-refresh")).clicked() {
+                    self.refresh_container_logs(&name);
+                }
+                if ui.button(tr("logs-save")).clicked() {
+                    let source = match self.container_log_source {
+                        LogSource::Docker => "container",
+                        LogSource::LatestLog => "latest",
+                        LogSource::DebugLog => "debug",
+                    };
+                    let text = self.container_logs.clone();
+                    self.save_logs(&format!("{}-{}", name, source), &text);
+                }
+                // Show auto-refresh indicator
+                if following {
+                    ui.small(tr_args(
+                        "logs-auto-refresh",
+                        &[("seconds", interval.into())],
+                    ));
+                }
+            });
+        });
+        ui.horizontal(|ui| {
+            ui.label(tr("logs-source"));
+            let before = self.container_log_source;
+            for source in LogSource::ALL {
+                ui.selectable_value(&mut self.container_log_source, source, source.label());
+            }
+            if self.container_log_source != before {
+                self.container_logs.clear();
+                self.refresh_container_logs(&name);
+            }
+        });
+        ui.separator();
+
+        self.container_log_filter
+            .show(ui, "container_logs", self.container_logs.lines());
+    }
+
+    /// Backup list for the Backups tab
+    fn show_backups_tab(&mut self, ui: &mut egui::Ui, name: &str) {
+        let name = name.to_string();
+        let group = self
+            .servers
+            .iter()
+            .find(|s| s.config.name == name)
+            .and_then(|s| s.config.backup_group.clone());
+        ui.horizontal(|ui| {
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button(tr("button-refresh")).clicked() {
+                    self.load_backup_list(&name);
+                }
+                let idle = self.backup_progress.is_none();
+                if ui
+                    .add_enabled(idle, egui::Button::new(tr("backups-incremental")))
+                    .on_hover_text(tr("backups-incremental-hint"))
+                    .clicked()
+                {
+                    self.create_backup(&name, BackupKind::Incremental);
+                }
+                if ui
+                    .add_enabled(idle, egui::Button::new(tr("backups-full")))
+                    .clicked()
+                {
+                    self.create_backup(&name, BackupKind::Full);
+                }
+                if let Some(group) = &group {
+                    let members = self.group_members(group);
+                    let button = egui::Button::new(tr_args(
+                        "backups-group",
+                        &[("group", group.clone().into())],
+                    ));
+                    if ui
+                        .add_enabled(self.backup_progress.is_none(), button)
+                        .on_hover_text(tr_args(
+                            "backups-group-hint",
+                            &[("servers", members.join(", ").into())],
+                        ))
+                        .clicked()
+                    {
+                        self.backup_group(group);
+                    }
+                }
+            });
+        });
+        ui.separator();
+
+        if self.backup_list.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.add_space(50.0);
+                ui.label(tr("backups-none"));
+                ui.add_space(10.0);
+                ui.label(tr("backups-none-hint"));
+            });
+        } else {
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                let mut restore_path = None;
+                let mut delete_path = None;
+                let mut players_path = None;
+                let mut group_restore_path = None;
+                let mut verify_path = None;
+                let mut contents_path = None;
+                let mut restore_new_path = None;
+
+                for backup in &self.backup_list {
+                    egui::Frame::none()
+                        .fill(ui.style().visuals.extreme_bg_color)
+                        .rounding(8.0)
+                        .inner_margin(12.0)
+                        .show(ui, |ui| {
+                            ui.horizontal(|ui| {
+                                ui.vertical(|ui| {
+                                    ui.strong(&backup.filename);
+                                    ui.label(tr_args(
+                                        "backups-size",
+                                        &[("size", backup::format_bytes(backup.size_bytes).into())],
+                                    ));
+                                    if let Some(base) = &backup.base {
+                                        ui.small(tr_args(
+                                            "backups-builds-on",
+                                            &[("base", base.clone().into())],
+                                        ));
+                                    }
+                                    if let Some(v) = &backup.verified {
+                                        let key = if v.compared {
+                                            "backups-verified-compared"
+                                        } else {
+                                            "backups-verified-crc"
+                                        };
+                                        let verified = tr("backups-verified");
+                                        ui.colored_label(egui::Color32::GREEN, verified)
+                                            .on_hover_text(tr_args(
+                                                key,
+                                                &[
+                                                    ("files", v.files.into()),
+                                                    ("size", backup::format_bytes(v.bytes).into()),
+                                                    ("time", v.verified_at.clone().into()),
+                                                ],
+                                            ));
+                                    }
+                                    if let Ok(duration) = backup.created.elapsed() {
+                                        let hours = duration.as_secs() / 3600;
+                                        let days = hours / 24;
+                                        if days > 0 {
+                                            ui.small(tr_args(
+                                                "backups-days-ago",
+                                                &[("count", days.into())],
+                                            ));
+                                        } else if hours > 0 {
+                                            ui.small(tr_args(
+                                                "backups-hours-ago",
+                                                &[("count", hours.into())],
+                                            ));
+                                        } else {
+                                            ui.small(tr("backups-just-now"));
+                                        }
+                                    }
+                                });
+
+                                ui.with_layout(
+                                    egui::Layout::right_to_left(egui::Align::Center),
+                                    |ui| {
+                                        let delete = egui::Button::new(tr("button-delete"))
+                                            .fill(egui::Color32::from_rgb(100, 30, 30));
+                                        if ui.add(delete).clicked() {
+                                            delete_path = Some(backup.path.clone());
+                                        }
+                                        if ui.button(tr("backups-restore")).clicked() {
+                                            restore_path = Some(backup.path.clone());
+                                        }
+                                        if ui
+                                            .button(tr("backups-restore-new"))
+                                            .on_hover_text(tr("backups-restore-new-hint"))
+                                            .clicked()
+                                        {
+                                            restore_new_path = Some(backup.path.clone());
+                                        }
+                                        if ui
+                                            .button(tr("backups-contents"))
+                                            .on_hover_text(tr("backups-contents-hint"))
+                                            .clicked()
+                                        {
+                                            contents_path = Some(backup.path.clone());
+                                        }
+                                        let verify = egui::Button::new(tr("backups-verify"));
+                                        if ui
+                                            .add_enabled(self.backup_progress.is_none(), verify)
+                                            .on_hover_text(tr("backups-verify-hint"))
+                                            .clicked()
+                                        {
+                                            verify_path = Some(backup.path.clone());
+                                        }
+                                        if group.is_some()
+                                            && backup::is_group_backup(&backup.filename)
+                                            && ui
+                                                .button(tr("backups-restore-set"))
+                                                .on_hover_text(tr("backups-restore-set-hint"))
+                                                .clicked()
+                                        {
+                                            group_restore_path = Some(backup.path.clone());
+                                        }
+                                        // Incrementals only hold players whose data changed
+                                        if backup.base.is_none()
+                                            && ui
+                                                .button(tr("backups-players"))
+                                                .on_hover_text(tr("backups-players-hint"))
+                                                .clicked()
+                                        {
+                                            players_path = Some(backup.path.clone());
+                                        }
+                                    },
+                                );
+                            });
+                        });
+                    ui.add_space(8.0);
+                }
+
+                if let Some(path) = restore_path {
+                    self.current_view = View::ConfirmRestore(name.clone(), path);
+                }
+                if let Some(path) = delete_path {
+                    self.current_view = View::ConfirmDeleteBackup(name.clone(), path);
+                }
+                if let Some(path) = players_path {
+                    self.view_backup_players(&name, &path);
+                }
+                if let Some(path) = group_restore_path {
+                    self.current_view = View::ConfirmRestoreGroup(name.clone(), path);
+                }
+                if let Some(path) = verify_path {
+                    self.verify_backup(&name, &path);
+                }
+                if let Some(path) = contents_path {
+                    self.view_backup_contents(&name, &path);
+                }
+                if let Some(path) = restore_new_path {
+                    self.start_restore_as_new(&name, &path);
+                }
+            });
+        }
+    }
+
+    /// The server's settings form for the Settings tab
+    fn show_settings_tab(&mut self, ui: &mut egui::Ui, name: &str) {
+        let mut saved = None;
+        let mut reverted = false;
+        let name = name.to_string();
+        let templates = self.templates.clone();
+        let mut search_request: Option<CfSearchState> = None;
+        let mut version_request: Option<u64> = None;
+        let mut description_request: Option<u64> = None;
+        let mut mr_search_request: Option<MrSearchState> = None;
+        let mut mr_version_request: Option<String> = None;
+        let mut mr_description_request: Option<String> = None;
+
+        let has_cf_key = self
+            .settings
+            .curseforge_api_key
+            .as_ref()
+            .is_some_and(|k| !k.is_empty());
+
+        self.edit_view.memory_budget = MemoryBudget {
+            host_total_mb: self.host_memory.total_mb,
+            others_mb: memory::allocated_by_active_servers(&self.servers, &name),
+        };
+        self.edit_view.show(
+            ui,
+            &templates,
+            &mut CfCallbacks {
+                on_search: &mut |state| {
+                    search_request = Some(state);
+                },
+                on_fetch_versions: &mut |mod_id| {
+                    version_request = Some(mod_id);
+                },
+                on_fetch_description: &mut |mod_id| {
+                    description_request = Some(mod_id);
+                },
+                has_api_key: has_cf_key,
+            },
+            &mut MrCallbacks {
+                on_search: &mut |state| {
+                    mr_search_request = Some(state);
+                },
+                on_fetch_versions: &mut |project_id| {
+                    mr_version_request = Some(project_id);
+                },
+                on_fetch_description: &mut |project_id| {
+                    mr_description_request = Some(project_id);
+                },
+            },
+            &mut |result| {
+                saved = Some(result);
+            },
+            &mut || reverted = true,
+        );
+
+        if let Some(result) = saved {
+            self.save_server_edit(&name, result);
+        }
+        if reverted {
+            self.load_edit_view(&name);
+        }
+
+        if let Some(state) = search_request {
+            self.dispatch_cf_search(state);
+        }
+        if let Some(mod_id) = version_request {
+            self.dispatch_cf_fetch_versions(mod_id);
+        }
+        if let Some(mod_id) = description_request {
+            self.dispatch_cf_fetch_description(mod_id);
+        }
+        if let Some(state) = mr_search_request {
+            self.dispatch_mr_search(state);
+        }
+        if let Some(project_id) = mr_version_request {
+            self.dispatch_mr_fetch_versions(project_id);
+        }
+        if let Some(project_id) = mr_description_request {
+            self.dispatch_mr_fetch_description(project_id);
+        }
+    }
+
+    fn load_whitelist(&mut self, name: &str) {
+        match whitelist::load(name) {
+            Ok(entries) => self.whitelist_entries = Some(entries),
+            Err(e) => {
+                self.log(format!(
+                    "ERROR: {}",
+                    tr_args(
+                        "log-whitelist-read-failed",
+                        &[("name", name.into()), ("error", e.to_string().into())]
+                    )
+                ));
+                self.whitelist_entries = None;
+            }
+        }
+    }
+
+    /// Whitelist changes go through RCON while the server runs (it keeps the list in
+    /// memory and would overwrite the file), and straight to whitelist.json otherwise
+    fn run_whitelist_action(&mut self, name: &str, action: WhitelistAction) {
+        let Some(server) = self.servers.iter().find(|s| s.config.name == name) else {
+            return;
+        };
+        let running = server.status == ServerStatus::Running;
+        let online_mode = server.config.server_properties.online_mode;
+        let address = format!("127.0.0.1:{}", server.config.rcon_port());
+        let password = server.config.rcon_password.clone();
+
+        let commands = match action {
+            WhitelistAction::Reload => {
+                self.load_whitelist(name);
+                return;
+            }
+            WhitelistAction::Add => {
+                let (names, invalid) = whitelist::parse_names(&self.whitelist_input);
+                if !invalid.is_empty() {
+                    self.show_status_message(tr_args(
+                        "whitelist-invalid-names",
+                        &[("names", invalid.join(", ").into())],
+                    ));
+                    return;
+                }
+                if !running && !online_mode {
+                    // Offline-mode UUIDs are derived from the name by the server itself
+                    self.show_status_message(tr("whitelist-offline-stopped"));
+                    return;
+                }
+                self.whitelist_input.clear();
+                if !running {
+                    self.lookup_whitelist_names(name, names);
+                    return;
+                }
+                names
+                    .iter()
+                    .map(|n| format!("whitelist add {}", n))
+                    .collect::<Vec<_>>()
+            }
+            WhitelistAction::Remove(player) => {
+                if !running {
+                    let mut entries = self.whitelist_entries.clone().unwrap_or_default();
+                    entries.retain(|e| e.name != player);
+                    match whitelist::save(name, &entries) {
+                        Ok(()) => {
+                            self.show_status_message(tr_args(
+                                "whitelist-removed",
+                                &[("player", player.clone().into())],
+                            ));
+                            self.whitelist_entries = Some(entries);
+                        }
+                        Err(e) => self.show_status_message(tr_args(
+                            "msg-whitelist-save-failed",
+                            &[("error", e.to_string().into())],
+                        )),
+                    }
+                    return;
+                }
+                vec![format!("whitelist remove {}", player)]
+            }
+        };
+
+        self.whitelist_busy = true;
+        let server_name = name.to_string();
+        let tx = self.task_tx.clone();
+        self.runtime.spawn(async move {
+            let mut replies = Vec::new();
+            for command in &commands {
+                match rcon::send_command(&address, &password, command).await {
+                    Ok(output) => replies.push(output.trim().to_string()),
+                    Err(e) => {
+                        replies.push(tr_args(
+                            "msg-command-failed",
+                            &[
+                                ("command", command.clone().into()),
+                                ("error", e.to_string().into()),
+                            ],
+                        ));
+                        break;
+                    }
+                }
+            }
+            tx.send(TaskMessage::WhitelistUpdated {
+                server_name,
+                summary: replies.join("; "),
+            })
+            .ok();
+        });
+    }
+
+    fn lookup_whitelist_names(&mut self, name: &str, names: Vec<String>) {
+        self.whitelist_busy = true;
+        let server_name = name.to_string();
+        let tx = self.task_tx.clone();
+        self.runtime.spawn(async move {
+            let result = whitelist::lookup(&names).await.map_err(|e| e.to_string());
+            tx.send(TaskMessage::WhitelistLookup {
+                server_name,
+                result,
+            })
+            .ok();
+        });
+    }
+
+    /// Ask the server who's online (one-shot RCON, so it works without the console open)
+    fn refresh_online_players(&mut self, name: &str) {
+        let Some(server) = self.servers.iter().find(|s| s.config.name == name) else {
+            return;
+        };
+        let address = format!("127.0.0.1:{}", server.config.rcon_port());
+        let password = server.config.rcon_password.clone();
+        self.online_players_polled = Some(std::time::Instant::now());
+        self.online_ops = Self::read_ops(name);
+
+        let server_name = name.to_string();
+        let tx = self.task_tx.clone();
+        self.runtime.spawn(async move {
+            let result = match rcon::send_command(&address, &password, "list").await {
+                Ok(output) => rcon::parse_player_list(&output)
+                    .ok_or_else(|| format!("Unexpected `list` output: {}", output.trim())),
+                Err(e) => Err(e.to_string()),
+            };
+            tx.send(TaskMessage::OnlinePlayers {
+                server_name,
+                result,
+            })
+            .ok();
+        });
+    }
+
+    fn run_player_action(&mut self, name: &str, action: PlayerAction) {
+        let Some(command) = action.command(&self.player_message) else {
+            self.refresh_online_players(name);
+            return;
+        };
+        let Some(server) = self.servers.iter().find(|s| s.config.name == name) else {
+            return;
+        };
+        let address = format!("127.0.0.1:{}", server.config.rcon_port());
+        let password = server.config.rcon_password.clone();
+        if matches!(action, PlayerAction::Whisper(_)) {
+            self.player_message.clear();
+        }
+
+        self.log(format!("'{}' > {}", name, command));
+        let server_name = name.to_string();
+        let tx = self.task_tx.clone();
+        self.runtime.spawn(async move {
+            let result = rcon::send_command(&address, &password, &command)
+                .await
+                .map_err(|e| e.to_string());
+            tx.send(TaskMessage::PlayerCommandDone {
+                server_name,
+                command,
+                result,
+            })
+            .ok();
+        });
+    }
+
+    fn open_console(&mut self, name: &str) {
+        self.open_server_tab(name, DetailsTab::Console);
+    }
+
+    /// Fresh console output and RCON session for the Console tab
+    fn start_console(&mut self, name: &str) {
+        self.console_input.clear();
+        self.console_output.clear();
+        self.quick_command_draft = None;
+        self.console_output
+            .push(tr_args("console-opening", &[("name", name.into())]));
+        self.console_output.push(tr("console-intro-send"));
+        self.console_output.push(tr("console-intro-commands"));
+        self.console_output.push(tr("console-intro-tab"));
+        self.console_output.push(String::new());
+        self.connect_rcon(name);
+    }
+
+    /// Start a persistent RCON session for the console; replaces any previous one
+    fn connect_rcon(&mut self, server_name: &str) {
+        self.rcon_session = None;
+        self.rcon_connected = false;
+        self.console_pending.clear();
+        self.console_players.clear();
+        self.console_players_refreshed = None;
+
+        let Some(server) = self.servers.iter().find(|s| s.config.name == server_name) else {
+            self.console_output.push(tr_args(
+                "console-server-not-found",
+                &[("name", server_name.into())],
+            ));
+            return;
+        };
+
+        let address = format!("127.0.0.1:{}", server.config.rcon_port());
+        let password = server.config.rcon_password.clone();
+        let tx = self.task_tx.clone();
+        let name = server_name.to_string();
+
+        self.rcon_session = Some(RconSession::start(
+            self.runtime.handle(),
+            address,
+            password,
+            move |event| {
+                tx.send(TaskMessage::Rcon {
+                    server_name: name.clone(),
+                    event,
+                })
+                .ok();
+            },
+        ));
+    }
+
+    fn send_rcon_command(&mut self, server_name: &str, command: &str) {
+        if self.rcon_session.is_none() {
+            self.connect_rcon(server_name);
+        }
+        let Some(session) = &self.rcon_session else {
+            return;
+        };
+
+        self.console_output.push(format!("> {}", command));
+        if session.send(command) {
+            self.console_pending.push_back((command.to_string(), false));
+        } else {
+            self.console_output.push(tr("console-session-ended"));
+        }
+    }
+
+    /// Refresh the online player list without printing anything to the console
+    fn refresh_console_players(&mut self) {
+        let Some(session) = &self.rcon_session else {
+            return;
+        };
+        self.console_players_refreshed = Some(std::time::Instant::now());
+        if session.send("list") {
+            self.console_pending.push_back(("list".to_string(), true));
+        }
+    }
+
+    /// Start a scheduled backup that's due. Backups run one at a time, so any other
+    /// due server waits for the next check. Running servers get a hot backup.
+    fn run_scheduled_backups(&mut self) {
+        if self.backup_progress.is_some()
+            || self
+                .backup_schedule_checked
+                .is_some_and(|t| t.elapsed() < BACKUP_SCHEDULE_CHECK_INTERVAL)
+        {
+            return;
+        }
+        self.backup_schedule_checked = Some(std::time::Instant::now());
+
+        let now = chrono::Local::now().naive_local();
+        let restoring = self
+            .restore_progress
+            .as_ref()
+            .map(|(name, ..)| name.as_str());
+        let due = self
+            .servers
+            .iter()
+            .filter(|s| {
+                s.config.backup_schedule.enabled
+                    && matches!(s.status, ServerStatus::Stopped | ServerStatus::Running)
+            })
+            .filter(|s| restoring != Some(s.config.name.as_str()))
+            .filter(|s| {
+                // Failing again every check would toggle world saves and post a
+                // webhook each minute, so wait longer after each failure
+                self.scheduled_backup_attempts
+                    .get(&s.config.name)
+                    .is_none_or(|(failures, at)| {
+                        let backoff = SCHEDULED_BACKUP_RETRY_MIN
+                            .saturating_mul(1 << failures.saturating_sub(1).min(16))
+                            .min(SCHEDULED_BACKUP_RETRY_MAX);
+                        at.elapsed() >= backoff
+                    })
+            })
+            .find(|s| {
+                let frequency = s.config.backup_schedule.frequency;
+                backup::next_scheduled_backup(&s.config.name, frequency, now) <= now
+            })
+            .map(|s| (s.config.name.clone(), s.config.backup_schedule));
+
+        if let Some((name, schedule)) = due {
+            self.log(tr_args(
+                "log-scheduled-backup",
+                &[("name", name.as_str().into())],
+            ));
+            let attempts = self
+                .scheduled_backup_attempts
+                .entry(name.clone())
+                .or_insert((0, std::time::Instant::now()));
+            *attempts = (attempts.0 + 1, std::time::Instant::now());
+            self.start_backup(&name, BackupKind::Scheduled(schedule));
+        }
+    }
+
+    /// Send any scheduled broadcasts that are due. The first run of each happens one
+    /// interval after the server comes up, not immediately.
+    fn run_scheduled_broadcasts(&mut self) {
+        let now = std::time::Instant::now();
+        let mut due = Vec::new();
+        let mut active = Vec::new();
+
+        for server in &self.servers {
+            if server.status != ServerStatus::Running {
+                continue;
+            }
+            for broadcast in &server.config.broadcasts {
+                if !broadcast.enabled || broadcast.interval_minutes == 0 {
+                    continue;
+                }
+                let key = (server.config.name.clone(), broadcast.command.clone());
+                active.push(key.clone());
+                let interval = Duration::from_secs(broadcast.interval_minutes as u64 * 60);
+                let last = self.broadcasts_last_sent.entry(key).or_insert(now);
+                if now.duration_since(*last) >= interval {
+                    *last = now;
+                    due.push((
+                        server.config.name.clone(),
+                        format!("127.0.0.1:{}", server.config.rcon_port()),
+                        server.config.rcon_password.clone(),
+                        broadcast.command.clone(),
+                    ));
+                }
+            }
+        }
+        // Restart the timer for anything stopped, disabled, edited, or deleted
+        self.broadcasts_last_sent
+            .retain(|key, _| active.contains(key));
+
+        for (name, address, password, command) in due {
+            let tx = self.task_tx.clone();
+            self.runtime.spawn(async move {
+                if let Err(e) = rcon::send_command(&address, &password, &command).await {
+                    let _ = tx.send(TaskMessage::Log(tr_args(
+                        "log-broadcast-failed",
+                        &[
+                            ("name", name.as_str().into()),
+                            ("error", e.to_string().into()),
+                        ],
+                    )));
+                }
+            });
+        }
+    }
+
+    /// Ask each running server who's online, once a minute, for playtime stats
+    fn sample_playtime(&mut self) {
+        let now = std::time::Instant::now();
+        let mut due = Vec::new();
+        for server in &self.servers {
+            let name = &server.config.name;
+            if server.status != ServerStatus::Running {
+                // Close out sessions so the downtime isn't counted
+                if self.playtime_sampled.remove(name).is_some() {
+                    if let Some(store) = self.playtime.get_mut(name) {
+                        store.end_sessions();
+                    }
+                }
+                continue;
+            }
+            let last = self.playtime_sampled.get(name);
+            if last.is_none_or(|t| now.duration_since(*t) >= PLAYTIME_SAMPLE_INTERVAL) {
+                due.push((
+                    name.clone(),
+                    format!("127.0.0.1:{}", server.config.rcon_port()),
+                    server.config.rcon_password.clone(),
+                ));
+            }
+        }
+
+        for (name, address, password) in due {
+            // Marked before the answer arrives so a slow server isn't asked twice
+            self.playtime_sampled.insert(name.clone(), now);
+            let tx = self.task_tx.clone();
+            self.runtime.spawn(async move {
+                let Ok(output) = rcon::send_command(&address, &password, "list").await else {
+                    return;
+                };
+                if let Some(players) = rcon::parse_player_list(&output) {
+                    tx.send(TaskMessage::PlaytimeSample {
+                        server_name: name,
+                        players,
+                    })
+                    .ok();
+                }
+            });
+        }
+    }
+
+    /// Ping each running server every STATUS_PING_INTERVAL for the dashboard cards
+    fn ping_running_servers(&mut self) {
+        let now = std::time::Instant::now();
+        let mut due = Vec::new();
+        for server in &self.servers {
+            let name = &server.config.name;
+            if server.status != ServerStatus::Running {
+                if self.status_pinged.remove(name).is_some() {
+                    self.live_status.remove(name);
+                }
+                continue;
+            }
+            let last = self.status_pinged.get(name);
+            if last.is_none_or(|t| now.duration_since(*t) >= STATUS_PING_INTERVAL) {
+                // Query is published on localhost only, whatever the bind address
+                due.push((
+                    name.clone(),
+                    server.config.local_address(),
+                    format!("127.0.0.1:{}", server.config.port),
+                ));
+            }
+        }
+
+        for (name, address, query_address) in due {
+            self.status_pinged.insert(name.clone(), now);
+            let tx = self.task_tx.clone();
+            self.runtime.spawn(async move {
+                let client = McClient::new().with_timeout(Duration::from_secs(3));
+                let mut status = match client.ping(&address, ServerEdition::Java).await {
+                    Ok(status) if status.online => match status.data {
+                        ServerData::Java(java) => Some(LiveStatus {
+                            players_online: java.players.online,
+                            players_max: java.players.max,
+                            motd: java.description,
+                            version: java.version.name,
+                            query: None,
+                        }),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+                // Containers created before query was enabled don't answer; that's fine
+                if let Some(status) = &mut status {
+                    status.query = query::full_stat(&query_address, Duration::from_secs(2))
+                        .await
+                        .ok();
+                }
+                tx.send(TaskMessage::LiveStatus {
+                    server_name: name,
+                    status,
+                })
+                .ok();
+            });
+        }
+    }
+
+    /// Sample TPS and container stats of running servers, every
+    /// HISTORY_SAMPLE_INTERVAL for their history and, while the metrics endpoint
+    /// is on, every METRICS_SAMPLE_INTERVAL to refresh its page (with player
+    /// counts from the last status ping, and backup ages)
+    fn sample_metrics(&mut self) {
+        let now = std::time::Instant::now();
+        let serving = self.metrics_endpoint.is_some()
+            && self
+                .metrics_sampled
+                .is_none_or(|t| now.duration_since(t) >= METRICS_SAMPLE_INTERVAL);
+        let record = self
+            .servers
+            .iter()
+            .any(|s| s.status == ServerStatus::Running)
+            && self
+                .history_sampled
+                .is_none_or(|t| now.duration_since(t) >= HISTORY_SAMPLE_INTERVAL);
+        if !serving && !record {
+            return;
+        }
+        if serving {
+            self.metrics_sampled = Some(now);
+        }
+        if record {
+            self.history_sampled = Some(now);
+        }
+
+        let servers = self.servers.clone();
+        let live_status = self.live_status.clone();
+        let docker = self.docker.clone();
+        let body = self.metrics_body.clone();
+        let tx = self.task_tx.clone();
+        self.runtime.spawn(async move {
+            let mut samples = Vec::with_capacity(servers.len());
+            for server in &servers {
+                let sample = if server.status == ServerStatus::Running {
+                    metrics::sample(docker.as_deref(), server).await
+                } else {
+                    metrics::Sample::default()
+                };
+                if sample.exited {
+                    tx.send(TaskMessage::ContainerExited {
+                        name: server.config.name.clone(),
+                    })
+                    .ok();
+                }
+                samples.push(sample);
+            }
+            if record {
+                let running = servers
+                    .iter()
+                    .zip(&samples)
+                    .filter(|(server, sample)| {
+                        server.status == ServerStatus::Running && !sample.exited
+                    })
+                    .map(|(server, sample)| (server.config.name.clone(), sample.clone()))
+                    .collect();
+                tx.send(TaskMessage::MetricsHistorySample { samples: running })
+                    .ok();
+            }
+            if !serving {
+                return;
+            }
+            // Backup ages come from listing the backup folders
+            let page = tokio::task::spawn_blocking(move || {
+                let rows: Vec<_> = servers
+                    .into_iter()
+                    .zip(samples)
+                    .map(|(server, sample)| metrics::ServerMetrics {
+                        live: live_status.get(&server.config.name).cloned(),
+                        backup_age: metrics::backup_age(&server.config.name),
+                        name: server.config.name,
+                        status: server.status,
+                        sample,
+                    })
+                    .collect();
+                metrics::render(&rows)
+            })
+            .await;
+            if let (Ok(page), Ok(mut body)) = (page, body.lock()) {
+                *body = page;
+            }
+        });
+    }
+
+    /// Measure folder sizes in the background every DISK_USAGE_INTERVAL, or
+    /// straight away after something changed them (`disk_usage_measured` cleared)
+    fn check_disk_usage(&mut self) {
+        if self.disk_usage_measuring
+            || self
+                .disk_usage_measured
+                .is_some_and(|t| t.elapsed() < DISK_USAGE_INTERVAL)
+        {
+            return;
+        }
+        self.disk_usage_measuring = true;
+        self.disk_usage_measured = Some(std::time::Instant::now());
+        let names: Vec<String> = self.servers.iter().map(|s| s.config.name.clone()).collect();
+        let tx = self.task_tx.clone();
+        self.runtime.spawn_blocking(move || {
+            let usage = DiskUsage::measure(&names);
+            tx.send(TaskMessage::DiskUsageMeasured { usage }).ok();
+        });
+    }
+
+    /// Read a server's metrics history for the Overview tab's charts
+    fn load_metrics_history(&mut self, name: &str) {
+        self.metrics_history = Some((name.to_string(), None));
+        let server_name = name.to_string();
+        let tx = self.task_tx.clone();
+        self.runtime.spawn_blocking(move || {
+            let points = metrics_history::load(&server_name);
+            tx.send(TaskMessage::MetricsHistoryLoaded {
+                server_name,
+                points,
+            })
+            .ok();
+        });
+    }
+
+    /// Record a server's new status and everything that hangs off it: startup
+    /// timing, uptime, diagnoses of errors, and the status bar message
+    fn apply_server_status(
+        &mut self,
+        name: String,
+        status: ServerStatus,
+        container_id: Option<String>,
+    ) {
+        if status != ServerStatus::Pulling
+            && self
+                .pack_install_progress
+                .as_ref()
+                .is_some_and(|(n, _)| n == &name)
+        {
+            self.pack_install_progress = None;
+        }
+        let previous = self
+            .servers
+            .iter()
+            .find(|s| s.config.name == name)
+            .map(|s| s.status.clone());
+        let was_up = self.servers.iter().any(|s| {
+            s.config.name == name
+                && matches!(s.status, ServerStatus::Running | ServerStatus::Stopping)
+        });
+        if was_up && status == ServerStatus::Stopped {
+            self.record_gc_benchmark(&name);
+        }
+        if status == ServerStatus::Initializing {
+            self.startup_progress
+                .insert(name.clone(), StartupProgress::default());
+        } else {
+            self.startup_progress.remove(&name);
+        }
+        match status {
+            ServerStatus::Starting => {
+                self.starting_since
+                    .insert(name.clone(), std::time::Instant::now());
+            }
+            ServerStatus::Initializing => {}
+            ServerStatus::Running => {
+                if let Some(since) = self.starting_since.remove(&name) {
+                    self.record_startup_time(&name, since.elapsed());
+                }
+            }
+            _ => {
+                self.starting_since.remove(&name);
+            }
+        }
+        match status {
+            // Unfreezing reports Running again; the container kept its start time
+            ServerStatus::Running => {
+                self.running_since
+                    .entry(name.clone())
+                    .or_insert_with(chrono::Utc::now);
+            }
+            ServerStatus::Frozen => {}
+            _ => {
+                self.running_since.remove(&name);
+            }
+        }
+        // A container that was up and went into Error stopped on its own
+        let was_active = self.servers.iter().any(|s| {
+            s.config.name == name
+                && matches!(s.status, ServerStatus::Running | ServerStatus::Initializing)
+        });
+        match &status {
+            ServerStatus::Running => {
+                let since = self.running_since.get(&name).map(|t| t.timestamp());
+                if let Some(since) = since {
+                    self.record_uptime(&name, |log| log.started(since));
+                }
+            }
+            ServerStatus::Stopped | ServerStatus::Error(_) => {
+                let crashed = was_active && matches!(status, ServerStatus::Error(_));
+                let now = chrono::Utc::now().timestamp();
+                self.record_uptime(&name, |log| log.ended(now, crashed));
+            }
+            _ => {}
+        }
+        if status != ServerStatus::Running && status != ServerStatus::Frozen {
+            self.alert_monitor.server_stopped(&name);
+        }
+        if let (true, ServerStatus::Error(reason)) = (was_active, &status) {
+            let rules = self.server_alert_rules(&name);
+            if let Some(alert) = self.alert_monitor.container_exited(&name, &rules, reason) {
+                self.raise_alert(alert);
+            }
+            self.post_event(WebhookEvent::ServerCrashed, &name, Some(reason.clone()));
+        }
+        match (&previous, &status) {
+            (Some(ServerStatus::Starting | ServerStatus::Initializing), ServerStatus::Running) => {
+                // Nobody is on yet, so everyone from the first player sample has joined
+                self.playtime_store(&name).end_sessions();
+                let detail = self
+                    .servers
+                    .iter()
+                    .find(|s| s.config.name == name)
+                    .map(|s| {
+                        format!(
+                            "{} {} · Minecraft {}",
+                            s.config.modpack.name,
+                            s.config.modpack.version,
+                            s.config.modpack.minecraft_version
+                        )
+                    });
+                self.post_event(WebhookEvent::ServerStarted, &name, detail);
+            }
+            (
+                Some(ServerStatus::Running | ServerStatus::Stopping | ServerStatus::Frozen),
+                ServerStatus::Stopped,
+            ) => {
+                self.post_event(WebhookEvent::ServerStopped, &name, None);
+            }
+            _ => {}
+        }
+        self.log_diagnoses.remove(&name);
+        if matches!(status, ServerStatus::Error(_)) {
+            let cid = container_id.clone().or_else(|| {
+                self.servers
+                    .iter()
+                    .find(|s| s.config.name == name)
+                    .and_then(|s| s.container_id.clone())
+            });
+            if let Some(cid) = cid {
+                self.diagnose_from_logs(&name, cid);
+            }
+        }
+        if let Some(server) = self.servers.iter_mut().find(|s| s.config.name == name) {
+            server.status = status.clone();
+            if let Some(cid) = container_id {
+                server.container_id = Some(cid);
+            }
+            // Show status message for terminal states
+            match &status {
+                ServerStatus::Running => {
+                    self.status_message = Some((
+                        tr_args("msg-server-started", &[("name", name.clone().into())]),
+                        std::time::Instant::now(),
+                    ));
+                }
+                ServerStatus::Stopped => {
+                    self.status_message = Some((
+                        tr_args("msg-server-stopped", &[("name", name.clone().into())]),
+                        std::time::Instant::now(),
+                    ));
+                }
+                ServerStatus::Frozen => {
+                    self.status_message = Some((
+                        tr_args("msg-server-frozen", &[("name", name.clone().into())]),
+                        std::time::Instant::now(),
+                    ));
+                }
+                ServerStatus::Error(e) => {
+                    self.status_message = Some((e.clone(), std::time::Instant::now()));
+                }
+                _ => {}
+            }
+        }
+        self.save_servers();
+    }
+
+    fn server_alert_rules(&self, name: &str) -> AlertRules {
+        self.servers
+            .iter()
+            .find(|s| s.config.name == name)
+            .map(|s| s.config.alerts.clone())
+            .unwrap_or_default()
+    }
+
+    /// Post an event to each webhook in Settings → Webhooks that wants it
+    fn post_event(&self, event: WebhookEvent, server_name: &str, detail: Option<String>) {
+        let message = EventMessage::new(event, server_name, detail);
+        for webhook in self.settings.webhooks.iter().filter(|w| w.wants(event)) {
+            let url = webhook.url.trim().to_string();
+            let body = message.payload(&url);
+            let tx = self.task_tx.clone();
+            self.runtime.spawn(async move {
+                if let Err(e) = notify::post_json(&url, &body).await {
+                    tx.send(TaskMessage::Log(format!(
+                        "WARNING: {}",
+                        tr_args(
+                            "log-webhook-failed",
+                            &[
+                                ("event", event.label().into()),
+                                ("error", format!("{:#}", e).into())
+                            ]
+                        )
+                    )))
+                    .ok();
+                }
+            });
+        }
+    }
+
+    /// Log an alert and send it wherever Settings → Alerts says
+    fn raise_alert(&mut self, alert: Alert) {
+        self.log(format!("ALERT: {}", alert.message));
+        self.show_status_message(alert.message.clone());
+        if self.settings.alerts.desktop {
+            if let Err(e) = notify::desktop(&alert.title(), &alert.message) {
+                self.log(format!(
+                    "WARNING: {}",
+                    tr_args(
+                        "log-notification-failed",
+                        &[("error", format!("{:#}", e).into())]
+                    )
+                ));
+            }
+        }
+        let url = self.settings.alerts.webhook_url.trim().to_string();
+        if !url.is_empty() {
+            let tx = self.task_tx.clone();
+            self.runtime.spawn(async move {
+                if let Err(e) = notify::webhook(&url, &alert.message).await {
+                    tx.send(TaskMessage::Log(format!(
+                        "WARNING: {}",
+                        tr_args(
+                            "log-alert-webhook-failed",
+                            &[("error", format!("{:#}", e).into())]
+                        )
+                    )))
+                    .ok();
+                }
+            });
+        }
+    }
+
+    /// Check newest backup ages every BACKUP_ALERT_CHECK_INTERVAL for servers
+    /// with a no-backup alert
+    fn check_backup_alerts(&mut self) {
+        if self
+            .backup_alerts_checked
+            .is_some_and(|t| t.elapsed() < BACKUP_ALERT_CHECK_INTERVAL)
+        {
+            return;
+        }
+        self.backup_alerts_checked = Some(std::time::Instant::now());
+        let names: Vec<String> = self
+            .servers
+            .iter()
+            .filter(|s| s.config.alerts.no_backup)
+            .map(|s| s.config.name.clone())
+            .collect();
+        if names.is_empty() {
+            return;
+        }
+        let tx = self.task_tx.clone();
+        self.runtime.spawn_blocking(move || {
+            let ages = names
+                .into_iter()
+                .map(|name| {
+                    let age = metrics::backup_age(&name);
+                    (name, age)
+                })
+                .collect();
+            tx.send(TaskMessage::BackupAges { ages }).ok();
+        });
+    }
+
+    /// Update a server's uptime log, saving it if `update` changed it
+    fn record_uptime(&mut self, name: &str, update: impl FnOnce(&mut UptimeLog) -> bool) {
+        let log = self
+            .uptime
+            .entry(name.to_string())
+            .or_insert_with(|| UptimeLog::load(name));
+        if !update(log) {
+            return;
+        }
+        if let Err(e) = log.save(name) {
+            tracing::warn!("Saving uptime for '{}' failed: {}", name, e);
+        }
+    }
+
+    fn playtime_store(&mut self, name: &str) -> &mut PlaytimeStore {
+        self.playtime
+            .entry(name.to_string())
+            .or_insert_with(|| PlaytimeStore::load(name))
+    }
+
+    fn handle_rcon_event(&mut self, server_name: &str, event: RconEvent) {
+        match event {
+            RconEvent::Connected => {
+                if !self.rcon_connected {
+                    self.console_output.push(tr("console-connected-line"));
+                }
+                self.rcon_connected = true;
+            }
+            RconEvent::Response(output) => {
+                let (command, quiet) = self.console_pending.pop_front().unwrap_or_default();
+                if command.trim_start_matches('/').trim() == "list" {
+                    if let Some(players) = rcon::parse_player_list(&output) {
+                        self.console_players = players;
+                        self.console_players_refreshed = Some(std::time::Instant::now());
+                    }
+                }
+                if quiet {
+                    return;
+                }
+                if output.is_empty() {
+                    self.console_output.push(tr("console-no-response"));
+                } else {
+                    // Split response into lines
+                    for line in output.lines() {
+                        self.console_output.push(line.to_string());
+                    }
+                }
+            }
+            RconEvent::Error {
+                command,
+                message,
+                auth,
+            } => {
+                if command.is_some() {
+                    self.console_pending.pop_front();
+                }
+                self.rcon_connected = false;
+                self.console_output
+                    .push(tr_args("console-rcon-error", &[("error", message.into())]));
+                if auth {
+                    self.console_output.push(tr("console-rcon-auth-hint"));
+                } else if let Some(server) =
+                    self.servers.iter().find(|s| s.config.name == server_name)
+                {
+                    self.console_output.push(tr_args(
+                        "console-rcon-port-hint",
+                        &[("port", server.config.rcon_port().to_string().into())],
+                    ));
+                }
+            }
+        }
+    }
+
+    /// Process messages from background tasks
+    fn process_task_messages(&mut self) {
+        while let Ok(msg) = self.task_rx.try_recv() {
+            match msg {
+                TaskMessage::Log(text) => {
+                    self.log(text);
+                }
+                TaskMessage::LogDiagnosis {
+                    server_name,
+                    diagnoses,
+                } => {
+                    if !diagnoses.is_empty() {
+                        let titles: Vec<&str> =
+                            diagnoses.iter().map(|d| d.title.as_str()).collect();
+                        self.log(tr_args(
+                            "log-likely-cause",
+                            &[
+                                ("name", server_name.as_str().into()),
+                                ("causes", titles.join("; ").into()),
+                            ],
+                        ));
+                        self.log_diagnoses.insert(server_name, diagnoses);
+                    }
+                }
+                TaskMessage::StartupProgress {
+                    server_name,
+                    milestone,
+                } => {
+                    if let Some(progress) = self.startup_progress.get_mut(&server_name) {
+                        progress.update(milestone);
+                    }
+                }
+                TaskMessage::ServerStatus {
+                    name,
+                    status,
+                    container_id,
+                } => {
+                    self.apply_server_status(name, status, container_id);
+                }
+                TaskMessage::BackupProgress {
+                    server_name,
+                    current,
+                    total,
+                    current_file,
+                } => {
+                    self.backup_progress = Some((server_name, current, total, current_file));
+                }
+                TaskMessage::BackupComplete {
+                    server_name,
+                    result,
+                } => {
+                    self.backup_progress = None;
+                    self.disk_usage_measured = None;
+                    match result {
+                        Ok(path) => {
+                            self.scheduled_backup_attempts.remove(&server_name);
+                            let filename = path
+                                .file_name()
+                                .map(|s| s.to_string_lossy().to_string())
+                                .unwrap_or_else(|| "backup".to_string());
+                            self.show_status_message(tr_args(
+                                "msg-backup-created",
+                                &[("file", filename.clone().into())],
+                            ));
+                            self.log(tr_args(
+                                "log-backup-saved",
+                                &[("path", format!("{:?}", path).into())],
+                            ));
+                            self.post_event(
+                                WebhookEvent::BackupCompleted,
+                                &server_name,
+                                Some(filename),
+                            );
+                        }
+                        Err(e) => {
+                            // Back off from the failure, not from when the backup started
+                            if let Some((_, at)) =
+                                self.scheduled_backup_attempts.get_mut(&server_name)
+                            {
+                                *at = std::time::Instant::now();
+                            }
+                            self.show_status_message(tr_args(
+                                "msg-backup-failed",
+                                &[("error", e.to_string().into())],
+                            ));
+                            self.log(format!(
+                                "ERROR: {}",
+                                tr_args("log-backup-failed", &[("error", e.to_string().into())])
+                            ));
+                            self.post_event(
+                                WebhookEvent::BackupFailed,
+                                &server_name,
+                                Some(e.to_string()),
+                            );
+                        }
+                    }
+                    // If we're viewing backups for this server, refresh the list
+                    if let (View::ServerDetails(name), DetailsTab::Backups) =
+                        (&self.current_view, self.details_tab)
+                    {
+                        if name == &server_name {
+                            if let Ok(backups) = backup::list_backups(&server_name) {
+                                self.backup_list = backups;
+                            }
+                        }
+                    }
+                }
+                TaskMessage::OnlinePlayers {
+                    server_name,
+                    result,
+                } => {
+                    if self.current_view != View::ServerDetails(server_name) {
+                        continue;
+                    }
+                    match result {
+                        Ok(players) => {
+                            self.online_players = Some(players);
+                            self.online_players_error = None;
+                        }
+                        Err(e) => self.online_players_error = Some(e),
+                    }
+                }
+                TaskMessage::PlayerCommandDone {
+                    server_name,
+                    command,
+                    result,
+                } => match result {
+                    Ok(output) => {
+                        let output = output.trim();
+                        self.show_status_message(if output.is_empty() {
+                            tr_args("msg-command-sent", &[("command", command.clone().into())])
+                        } else {
+                            output.to_string()
+                        });
+                        if self.current_view == View::ServerDetails(server_name.clone()) {
+                            self.refresh_online_players(&server_name);
+                        }
+                    }
+                    Err(e) => {
+                        self.show_status_message(tr_args(
+                            "msg-command-failed",
+                            &[("command", command.into()), ("error", e.into())],
+                        ));
+                    }
+                },
+                TaskMessage::LiveStatus {
+                    server_name,
+                    status,
+                } => {
+                    // Ignore answers that arrive after the server stopped
+                    match status {
+                        Some(status) if self.status_pinged.contains_key(&server_name) => {
+                            self.live_status.insert(server_name, status);
+                        }
+                        _ => {
+                            self.live_status.remove(&server_name);
+                        }
+                    }
+                }
+                TaskMessage::PlaytimeSample {
+                    server_name,
+                    players,
+                } => {
+                    let store = self.playtime_store(&server_name);
+                    let changes = store.record(&players);
+                    if let Err(e) = store.save(&server_name) {
+                        tracing::warn!("Saving playtime for '{}' failed: {}", server_name, e);
+                    }
+                    for player in changes.iter().flat_map(|c| &c.joined) {
+                        self.post_event(
+                            WebhookEvent::PlayerJoined,
+                            &server_name,
+                            Some(player.clone()),
+                        );
+                    }
+                    for player in changes.iter().flat_map(|c| &c.left) {
+                        self.post_event(
+                            WebhookEvent::PlayerLeft,
+                            &server_name,
+                            Some(player.clone()),
+                        );
+                    }
+                }
+                TaskMessage::DeletedContainerRemoved { name, result } => {
+                    if let Err(e) = result {
+                        self.log(format!(
+                            "WARNING: {}",
+                            tr_args(
+                                "log-deleted-container-remove-failed",
+                                &[("name", name.into()), ("error", e.to_string().into())]
+                            )
+                        ));
+                    }
+                }
+                TaskMessage::OldContainerRemoved {
+                    name,
+                    container_name,
+                    result,
+                } => {
+                    if let Some(server) = self.servers.iter_mut().find(|s| s.config.name == name) {
+                        server.status = ServerStatus::Stopped;
+                    }
+                    match result {
+                        Ok(()) => {
+                            self.log(tr_args(
+                                "log-container-recreating",
+                                &[("container", container_name.as_str().into())],
+                            ));
+                            self.start_server(&name);
+                        }
+                        Err(e) => {
+                            self.show_status_message(tr_args(
+                                "msg-container-remove-failed",
+                                &[
+                                    ("container", container_name.as_str().into()),
+                                    ("error", e.to_string().into()),
+                                ],
+                            ));
+                        }
+                    }
+                }
+                TaskMessage::FilesListed {
+                    server_name,
+                    path,
+                    result,
+                } => {
+                    // Drop listings for a folder or server that's no longer shown
+                    if self.current_view == View::ServerDetails(server_name)
+                        && self.files_view.path == path
+                    {
+                        self.files_view.busy = false;
+                        self.files_view.entries = Some(result);
+                    }
+                }
+                TaskMessage::FileOpDone {
+                    server_name,
+                    result,
+                } => {
+                    match result {
+                        Ok(summary) => {
+                            self.log(format!("{} ('{}')", summary, server_name));
+                            self.show_status_message(summary);
+                        }
+                        Err(e) => {
+                            self.log(format!(
+                                "ERROR: {}",
+                                tr_args(
+                                    "log-file-op-failed",
+                                    &[
+                                        ("name", server_name.as_str().into()),
+                                        ("error", e.to_string().into())
+                                    ]
+                                )
+                            ));
+                            self.show_status_message(tr_args(
+                                "files-op-failed",
+                                &[("error", e.into())],
+                            ));
+                        }
+                    }
+                    self.files_view.busy = false;
+                    if self.current_view == View::ServerDetails(server_name.clone()) {
+                        self.load_files(&server_name);
+                    }
+                }
+                TaskMessage::WhitelistLookup {
+                    server_name,
+                    result,
+                } => {
+                    self.whitelist_busy = false;
+                    let (found, missing) = match result {
+                        Ok(r) => r,
+                        Err(e) => {
+                            self.show_status_message(tr_args(
+                                "msg-username-lookup-failed",
+                                &[("error", e.into())],
+                            ));
+                            continue;
+                        }
+                    };
+                    // Re-read in case the file changed while the lookup ran
+                    let mut entries = whitelist::load(&server_name).unwrap_or_default();
+                    let added = whitelist::merge(&mut entries, found);
+                    if let Err(e) = whitelist::save(&server_name, &entries) {
+                        self.show_status_message(tr_args(
+                            "msg-whitelist-save-failed",
+                            &[("error", e.to_string().into())],
+                        ));
+                        continue;
+                    }
+                    let message = if missing.is_empty() {
+                        tr_args("whitelist-added", &[("count", added.into())])
+                    } else {
+                        tr_args(
+                            "whitelist-added-unknown",
+                            &[
+                                ("count", added.into()),
+                                ("names", missing.join(", ").into()),
+                            ],
+                        )
+                    };
+                    self.show_status_message(message);
+                    if self.current_view == View::ServerDetails(server_name) {
+                        self.whitelist_entries = Some(entries);
+                    }
+                }
+                TaskMessage::WhitelistUpdated {
+                    server_name,
+                    summary,
+                } => {
+                    self.whitelist_busy = false;
+                    self.show_status_message(summary);
+                    if self.current_view == View::ServerDetails(server_name.clone()) {
+                        self.load_whitelist(&server_name);
+                    }
+                }
+                TaskMessage::DiagnosticsComplete(report) => {
+                    self.diagnostics_running = false;
+                    let problems: Vec<String> = report
+                        .problems()
+                        .map(|c| format!("{}: {}", c.name, c.detail))
+                        .collect();
+                    for problem in &problems {
+                        self.log(tr_args(
+                            "log-diagnostics-problem",
+                            &[("problem", problem.as_str().into())],
+                        ));
+                    }
+                    if report.automatic && !problems.is_empty() {
+                        self.show_status_message(tr_args(
+                            "msg-diagnostics-problems",
+                            &[("count", problems.len().into())],
+                        ));
+                    }
+                    self.diagnostics = Some(report);
+                }
+                TaskMessage::BackupVerified {
+                    server_name,
+                    path,
+                    result,
+                } => {
+                    self.backup_progress = None;
+                    let filename = path
+                        .file_name()
+                        .map(|s| s.to_string_lossy().to_string())
+                        .unwrap_or_else(|| "backup".to_string());
+                    match result {
+                        Ok(v) => {
+                            self.show_status_message(tr_args(
+                                "msg-backup-verified",
+                                &[("file", filename.clone().into())],
+                            ));
+                            self.log(tr_args(
+                                "log-backup-verified",
+                                &[
+                                    ("file", filename.as_str().into()),
+                                    ("name", server_name.as_str().into()),
+                                    ("files", v.files.into()),
+                                    ("size", backup::format_bytes(v.bytes).into()),
+                                ],
+                            ));
+                        }
+                        Err(e) => {
+                            self.show_status_message(tr_args(
+                                "msg-backup-verify-failed",
+                                &[
+                                    ("file", filename.clone().into()),
+                                    ("error", e.to_string().into()),
+                                ],
+                            ));
+                            self.log(format!(
+                                "ERROR: {}",
+                                tr_args(
+                                    "log-backup-verify-failed",
+                                    &[
+                                        ("file", filename.as_str().into()),
+                                        ("name", server_name.as_str().into()),
+                                        ("error", e.to_string().into())
+                                    ]
+                                )
+                            ));
+                        }
+                    }
+                    if let (View::ServerDetails(name), DetailsTab::Backups) =
+                        (&self.current_view, self.details_tab)
+                    {
+                        if name == &server_name {
+                            if let Ok(backups) = backup::list_backups(&server_name) {
+                                self.backup_list = backups;
+                            }
+                        }
+                    }
+                }
+                TaskMessage::UploadProgress {
+                    server_name,
+                    sent,
+                    total,
+                } => {
+                    self.upload_progress = Some((server_name, sent, Some(total)));
+                }
+                TaskMessage::UploadComplete {
+                    server_name,
+                    result,
+                } => {
+                    self.upload_progress = None;
+                    match result {
+                        Ok(pruned) => {
+                            self.log(tr_args(
+                                "log-remote-uploaded",
+                                &[("name", server_name.as_str().into())],
+                            ));
+                            if pruned > 0 {
+                                self.log(tr_args(
+                                    "log-remote-pruned",
+                                    &[
+                                        ("count", pruned.into()),
+                                        ("name", server_name.as_str().into()),
+                                    ],
+                                ));
+                            }
+                        }
+                        Err(e) => {
+                            self.show_status_message(tr_args(
+                                "msg-upload-failed",
+                                &[("error", e.to_string().into())],
+                            ));
+                            self.log(format!(
+                                "ERROR: {}",
+                                tr_args(
+                                    "log-remote-upload-failed",
+                                    &[
+                                        ("name", server_name.as_str().into()),
+                                        ("error", e.to_string().into())
+                                    ]
+                                )
+                            ));
+                        }
+                    }
+                }
+                TaskMessage::GroupBackupComplete { group, result } => {
+                    self.backup_progress = None;
+                    match result {
+                        Ok(paths) => {
+                            self.show_status_message(tr_args(
+                                "msg-group-backed-up",
+                                &[
+                                    ("group", group.clone().into()),
+                                    ("count", paths.len().into()),
+                                ],
+                            ));
+                            for path in &paths {
+                                self.log(tr_args(
+                                    "log-group-backup-saved",
+                                    &[("path", format!("{:?}", path).into())],
+                                ));
+                            }
+                        }
+                        Err(e) => {
+                            self.show_status_message(tr_args(
+                                "msg-group-backup-failed",
+                                &[("error", e.to_string().into())],
+                            ));
+                            self.log(format!(
+                                "ERROR: {}",
+                                tr_args(
+                                    "log-group-backup-failed",
+                                    &[
+                                        ("group", group.as_str().into()),
+                                        ("error", e.to_string().into())
+                                    ]
+                                )
+                            ));
+                        }
+                    }
+                    if let (View::ServerDetails(name), DetailsTab::Backups) =
+                        (&self.current_view, self.details_tab)
+                    {
+                        if let Ok(backups) = backup::list_backups(name) {
+                            self.backup_list = backups;
+                        }
+                    }
+                }
+                TaskMessage::GroupRestoreComplete { group, result } => {
+                    self.restore_progress = None;
+                    self.restore_cancel = None;
+                    match result {
+                        Ok(count) => {
+                            self.show_status_message(tr_args(
+                                "msg-group-restored",
+                                &[("group", group.clone().into()), ("count", count.into())],
+                            ));
+                            self.log(tr_args(
+                                "log-group-restored",
+                                &[("group", group.as_str().into())],
+                            ));
+                        }
+                        Err(e) => {
+                            self.show_status_message(tr_args(
+                                "msg-group-restore-failed",
+                                &[("error", e.to_string().into())],
+                            ));
+                            self.log(format!(
+                                "ERROR: {}",
+                                tr_args(
+                                    "log-group-restore-failed",
+                                    &[
+                                        ("group", group.as_str().into()),
+                                        ("error", e.to_string().into())
+                                    ]
+                                )
+                            ));
+                        }
+                    }
+                }
+                TaskMessage::DockerLogs { generation, chunks } => {
+                    if generation != self.docker_logs_generation {
+                        continue;
+                    }
+                    match chunks {
+                        Ok(chunks) => self.merge_docker_logs(chunks),
+                        Err(e) => self.all_docker_logs = e,
+                    }
+                }
+                TaskMessage::ContainerLogs(logs) => {
+                    // A fetch still in flight when the viewer switched to a log file
+                    if self.container_log_source == LogSource::Docker {
+                        self.container_logs = logs;
+                    }
+                }
+                TaskMessage::RestoreProgress {
+                    server_name,
+                    current,
+                    total,
+                    current_file,
+                } => {
+                    self.restore_progress = Some((server_name, current, total, current_file));
+                }
+                TaskMessage::PackInstallProgress {
+                    server_name,
+                    progress,
+                } => {
+                    self.pack_install_progress = Some((server_name, progress));
+                }
+                TaskMessage::RestoreComplete {
+                    server_name,
+                    result,
+                } => {
+                    self.restore_progress = None;
+                    self.disk_usage_measured = None;
+                    self.restore_cancel = None;
+                    match result {
+                        Ok(()) => {
+                            self.show_status_message(tr_args(
+                                "msg-backup-restored",
+                                &[("name", server_name.clone().into())],
+                            ));
+                            self.log(tr_args(
+                                "log-restored",
+                                &[("name", server_name.as_str().into())],
+                            ));
+                        }
+                        Err(e) => {
+                            self.show_status_message(tr_args(
+                                "msg-restore-failed",
+                                &[("error", e.to_string().into())],
+                            ));
+                            self.log(format!(
+                                "ERROR: {}",
+                                tr_args("log-restore-failed", &[("error", e.to_string().into())])
+                            ));
+                        }
+                    }
+                }
+                TaskMessage::RestoreAsNewComplete {
+                    server_name,
+                    result,
+                } => {
+                    self.restore_progress = None;
+                    self.restore_cancel = None;
+                    match result {
+                        Ok(()) => {
+                            self.show_status_message(tr_args(
+                                "msg-restored-as-new",
+                                &[("name", server_name.clone().into())],
+                            ));
+                            self.log(tr_args(
+                                "log-restored-into-new",
+                                &[("name", server_name.as_str().into())],
+                            ));
+                        }
+                        Err(e) => {
+                            // The folder was new, so nothing of the user's is lost with it
+                            self.servers.retain(|s| s.config.name != server_name);
+                            self.save_servers();
+                            let _ = std::fs::remove_dir_all(get_server_path(&server_name));
+                            self.show_status_message(tr_args(
+                                "msg-restore-failed",
+                                &[("error", e.to_string().into())],
+                            ));
+                            self.log(format!(
+                                "ERROR: {}",
+                                tr_args(
+                                    "log-restore-into-new-failed",
+                                    &[
+                                        ("name", server_name.as_str().into()),
+                                        ("error", e.to_string().into())
+                                    ]
+                                )
+                            ));
+                        }
+                    }
+                }
+                TaskMessage::LocalImportComplete {
+                    server_name,
+                    result,
+                } => {
+                    self.restore_progress = None;
+                    self.restore_cancel = None;
+                    match result {
+                        Ok(()) => {
+                            self.show_status_message(tr_args(
+                                "msg-local-imported",
+                                &[("name", server_name.clone().into())],
+                            ));
+                            self.log(tr_args(
+                                "log-local-pack-copied",
+                                &[("name", server_name.as_str().into())],
+                            ));
+                        }
+                        Err(e) => {
+                            // The folder was new, so nothing of the user's is lost with it
+                            self.servers.retain(|s| s.config.name != server_name);
+                            self.save_servers();
+                            let _ = std::fs::remove_dir_all(get_server_path(&server_name));
+                            self.show_status_message(tr_args(
+                                "msg-import-failed",
+                                &[("error", e.to_string().into())],
+                            ));
+                            self.log(format!(
+                                "ERROR: {}",
+                                tr_args(
+                                    "log-import-into-new-failed",
+                                    &[
+                                        ("name", server_name.as_str().into()),
+                                        ("error", e.to_string().into())
+                                    ]
+                                )
+                            ));
+                        }
+                    }
+                }
+                TaskMessage::SelectiveRestoreComplete {
+                    server_name,
+                    result,
+                } => {
+                    self.restore_progress = None;
+                    self.restore_cancel = None;
+                    match result {
+                        Ok(count) => {
+                            self.show_status_message(tr_args(
+                                "msg-files-restored",
+                                &[
+                                    ("count", count.into()),
+                                    ("name", server_name.clone().into()),
+                                ],
+                            ));
+                            self.log(tr_args(
+                                "log-selective-restored",
+                                &[
+                                    ("name", server_name.as_str().into()),
+                                    ("count", count.into()),
+                                ],
+                            ));
+                        }
+                        Err(e) => {
+                            self.show_status_message(tr_args(
+                                "msg-restore-failed",
+                                &[("error", e.to_string().into())],
+                            ));
+                            self.log(format!(
+                                "ERROR: {}",
+                                tr_args(
+                                    "log-selective-restore-failed",
+                                    &[("error", e.to_string().into())]
+                                )
+                            ));
+                        }
+                    }
+                }
+                TaskMessage::CfSearchResults {
+                    results,
+                    total_count,
+                } => {
+                    if let Some(widget) = self.active_cf_widget() {
+                        widget.state.results = results;
+                        widget.state.total_count = total_count;
+                        widget.state.loading_search = false;
+                        widget.state.search_error = None;
+                    }
+                }
+                TaskMessage::CfSearchError(err) => {
+                    if let Some(widget) = self.active_cf_widget() {
+                        widget.state.loading_search = false;
+                        widget.state.search_error = Some(err);
+                    }
+                }
+                TaskMessage::CfVersionResults { mod_id, files } => {
+                    let is_create_view = matches!(self.current_view, View::CreateServer);
+                    // Track memory to update on create view after the mutable borrow ends
+                    let mut new_memory: Option<String> = None;
+                    if let Some(widget) = self.active_cf_widget() {
+                        let matches = widget
+                            .state
+                            .selected_mod
+                            .as_ref()
+                            .is_some_and(|m| m.id == mod_id);
+                        if matches {
+                            widget.state.versions = files;
+                            widget.state.mc_versions =
+                                curseforge::extract_mc_versions(&widget.state.versions);
+                            widget.state.selected_mc_version =
+                                widget.state.mc_versions.first().cloned();
+                            widget.state.loading_versions = false;
+                            widget.state.versions_error = None;
+
+                            // Auto-select first file matching the default MC version
+                            let mc_ver = widget.state.selected_mc_version.clone();
+                            let first_match = widget
+                                .state
+                                .versions
+                                .iter()
+                                .enumerate()
+                                .find(|(_i, f)| match &mc_ver {
+                                    Some(mc) => f.game_versions.iter().any(|v| v == mc),
+                                    None => true,
+                                })
+                                .map(|(i, _)| i);
+
+                            if let Some(idx) = first_match {
+                                widget.state.selected_file_idx = Some(idx);
+                                let selected_mod = widget.state.selected_mod.clone().unwrap();
+                                let file = widget.state.versions[idx].clone();
+                                widget.build_cf_template(&selected_mod, &file);
+                                if is_create_view {
+                                    if let Some(t) = &widget.template {
+                                        new_memory = Some(t.recommended_memory_mb.to_string());
+                                    }
+                                }
+                            } else {
+                                widget.state.selected_file_idx = None;
+                            }
+                        }
+                    }
+                    if let Some(mem) = new_memory {
+                        self.create_view.memory_mb = mem;
+                    }
+                }
+                TaskMessage::CfVersionError { mod_id, error } => {
+                    if let Some(widget) = self.active_cf_widget() {
+                        let matches = widget
+                            .state
+                            .selected_mod
+                            .as_ref()
+                            .is_some_and(|m| m.id == mod_id);
+                        if matches {
+                            widget.state.loading_versions = false;
+                            widget.state.versions_error = Some(error);
+                        }
+                    }
+                }
+                TaskMessage::CfDescriptionResult {
+                    mod_id,
+                    description,
+                } => {
+                    if let Some(widget) = self.active_cf_widget() {
+                        let matches = widget
+                            .state
+                            .selected_mod
+                            .as_ref()
+                            .is_some_and(|m| m.id == mod_id);
+                        if matches {
+                            widget.state.description = Some(description);
+                            widget.state.loading_description = false;
+                        }
+                    }
+                }
+                TaskMessage::CfDescriptionError { mod_id, error } => {
+                    if let Some(widget) = self.active_cf_widget() {
+                        let matches = widget
+                            .state
+                            .selected_mod
+                            .as_ref()
+                            .is_some_and(|m| m.id == mod_id);
+                        if matches {
+                            widget.state.loading_description = false;
+                            tracing::warn!(
+                                "Failed to fetch description for mod {}: {}",
+                                mod_id,
+                                error
+                            );
+                        }
+                    }
+                }
+                TaskMessage::MrSearchResults {
+                    results,
+                    total_count,
+                } => {
+                    if let Some(widget) = self.active_mr_widget() {
+                        widget.state.results = results;
+                        widget.state.total_count = total_count;
+                        widget.state.loading_search = false;
+                        widget.state.search_error = None;
+                    }
+                }
+                TaskMessage::FtbSearchComplete { result } => {
+                    let state = &mut self.create_view.ftb.state;
+                    state.loading_search = false;
+                    state.searched = true;
+                    match result {
+                        Ok(packs) => {
+                            state.results = packs;
+                            state.search_error = None;
+                        }
+                        Err(e) => state.search_error = Some(e),
+                    }
+                }
+                TaskMessage::MrSearchError(err) => {
+                    if let Some(widget) = self.active_mr_widget() {
+                        widget.state.loading_search = false;
+                        widget.state.search_error = Some(err);
+                    }
+                }
+                TaskMessage::MrVersionResults {
+                    project_id,
+                    versions,
+                } => {
+                    let is_create_view = matches!(self.current_view, View::CreateServer);
+                    let mut new_memory: Option<String> = None;
+                    if let Some(widget) = self.active_mr_widget() {
+                        let matches = widget
+                            .state
+                            .selected_project
+                            .as_ref()
+                            .is_some_and(|p| p.slug == project_id);
+                        if matches {
+                            widget.state.versions = versions;
+                            widget.state.mc_versions =
+                                modrinth::extract_mc_versions(&widget.state.versions);
+                            widget.state.selected_mc_version =
+                                widget.state.mc_versions.first().cloned();
+                            widget.state.loading_versions = false;
+                            widget.state.versions_error = None;
+
+                            // Auto-select first version matching the default MC version
+                            let mc_ver = widget.state.selected_mc_version.clone();
+                            let first_match = widget
+                                .state
+                                .versions
+                                .iter()
+                                .enumerate()
+                                .find(|(_i, v)| match &mc_ver {
+                                    Some(mc) => v.game_versions.iter().any(|gv| gv == mc),
+                                    None => true,
+                                })
+                                .map(|(i, _)| i);
+
+                            if let Some(idx) = first_match {
+                                widget.state.selected_version_idx = Some(idx);
+                                let selected_project =
+                                    widget.state.selected_project.clone().unwrap();
+                                let version = widget.state.versions[idx].clone();
+                                widget.build_mr_template(&selected_project, &version);
+                                if is_create_view {
+                                    if let Some(t) = &widget.template {
+                                        new_memory = Some(t.recommended_memory_mb.to_string());
+                                    }
+                                }
+                            } else {
+                                widget.state.selected_version_idx = None;
+                            }
+                        }
+                    }
+                    if let Some(mem) = new_memory {
+                        self.create_view.memory_mb = mem;
+                    }
+                }
+                TaskMessage::MrVersionError { project_id, error } => {
+                    if let Some(widget) = self.active_mr_widget() {
+                        let matches = widget
+                            .state
+                            .selected_project
+                            .as_ref()
+                            .is_some_and(|p| p.slug == project_id);
+                        if matches {
+                            widget.state.loading_versions = false;
+                            widget.state.versions_error = Some(error);
+                        }
+                    }
+                }
+                TaskMessage::MrDescriptionResult {
+                    project_id,
+                    description,
+                } => {
+                    if let Some(widget) = self.active_mr_widget() {
+                        let matches = widget
+                            .state
+                            .selected_project
+                            .as_ref()
+                            .is_some_and(|p| p.slug == project_id);
+                        if matches {
+                            widget.state.description = Some(description);
+                            widget.state.loading_description = false;
+                        }
+                    }
+                }
+                TaskMessage::MrDescriptionError { project_id, error } => {
+                    if let Some(widget) = self.active_mr_widget() {
+                        let matches = widget
+                            .state
+                            .selected_project
+                            .as_ref()
+                            .is_some_and(|p| p.slug == project_id);
+                        if matches {
+                            widget.state.loading_description = false;
+                            tracing::warn!(
+                                "Failed to fetch Modrinth description for {}: {}",
+                                project_id,
+                                error
+                            );
+                        }
+                    }
+                }
+                TaskMessage::ModScanComplete {
+                    server_name,
+                    result,
+                } => {
+                    self.mods_scanning = false;
+                    let showing = match &self.current_view {
+                        View::Mods(n) => *n == server_name,
+                        View::ServerDetails(n) => {
+                            *n == server_name && self.details_tab == DetailsTab::Mods
+                        }
+                        View::ModBrowse(n) => *n == server_name,
+                        _ => false,
+                    };
+                    if !showing {
+                        continue;
+                    }
+                    match result {
+                        Ok(graph) => self.mod_graph = Some(graph),
+                        Err(e) => {
+                            self.mod_graph = Some(ModGraph::default());
+                            self.show_status_message(tr_args(
+                                "msg-mods-read-failed",
+                                &[("error", e.to_string().into())],
+                            ));
+                        }
+                    }
+                }
+                TaskMessage::ModInstallComplete {
+                    server_name,
+                    mod_id,
+                    result,
+                } => {
+                    self.mods_installing.retain(|id| id != &mod_id);
+                    match result {
+                        Ok(file_name) => {
+                            self.log(tr_args(
+                                "log-dependency-installed",
+                                &[
+                                    ("file", file_name.as_str().into()),
+                                    ("name", server_name.as_str().into()),
+                                    ("dependency", mod_id.as_str().into()),
+                                ],
+                            ));
+                        }
+                        Err(e) => {
+                            self.show_status_message(tr_args(
+                                "msg-install-failed",
+                                &[
+                                    ("name", mod_id.clone().into()),
+                                    ("error", e.to_string().into()),
+                                ],
+                            ));
+                            self.log(format!(
+                                "ERROR: {}",
+                                tr_args(
+                                    "log-install-failed",
+                                    &[
+                                        ("item", mod_id.as_str().into()),
+                                        ("error", e.to_string().into())
+                                    ]
+                                )
+                            ));
+                        }
+                    }
+                    if self.mods_installing.is_empty()
+                        && self.current_view == View::Mods(server_name.clone())
+                    {
+                        self.scan_mods(&server_name);
+                    }
+                }
+                TaskMessage::ModSearchResults(result) => {
+                    self.mod_browse.set_results(result);
+                }
+                TaskMessage::ModReleasesResult { id, result } => {
+                    self.mod_browse.set_releases(&id, result);
+                }
+                TaskMessage::ModBrowseInstallComplete {
+                    server_name,
+                    title,
+                    result,
+                } => {
+                    self.mod_browse.installing.retain(|t| t != &title);
+                    match result {
+                        Ok(files) => {
+                            self.show_status_message(tr_args(
+                                "msg-installed",
+                                &[
+                                    ("name", title.clone().into()),
+                                    ("dependencies", files.len().saturating_sub(1).into()),
+                                ],
+                            ));
+                            self.log(tr_args(
+                                "log-installed",
+                                &[
+                                    ("files", files.join(", ").into()),
+                                    ("name", server_name.as_str().into()),
+                                ],
+                            ));
+                        }
+                        Err(e) => {
+                            self.show_status_message(tr_args(
+                                "msg-install-failed",
+                                &[
+                                    ("name", title.clone().into()),
+                                    ("error", e.to_string().into()),
+                                ],
+                            ));
+                            self.log(format!(
+                                "ERROR: {}",
+                                tr_args(
+                                    "log-install-failed",
+                                    &[
+                                        ("item", title.as_str().into()),
+                                        ("error", e.to_string().into())
+                                    ]
+                                )
+                            ));
+                        }
+                    }
+                    // The new jars count as installed for later picks
+                    self.scan_mods(&server_name);
+                }
+                TaskMessage::Rcon { server_name, event } => {
+                    // Late events from a console that was closed or switched
+                    if self.current_view == View::ServerDetails(server_name.clone())
+                        && self.details_tab == DetailsTab::Console
+                        && self.rcon_session.is_some()
+                    {
+                        self.handle_rcon_event(&server_name, event);
+                    }
+                }
+                TaskMessage::UnifiedSearchResults { results, errors } => {
+                    let widget = &mut self.create_view.unified;
+                    widget.results = results;
+                    widget.errors = errors;
+                    widget.loading = false;
+                    widget.searched = true;
+                }
+                TaskMessage::ExportProgress {
+                    server_name,
+                    current,
+                    total,
+                    current_file,
+                } => {
+                    self.export_progress = Some((server_name, current, total, current_file));
+                }
+                TaskMessage::ExportComplete {
+                    server_name,
+                    result,
+                } => {
+                    self.export_progress = None;
+                    match result {
+                        Ok(path) => {
+                            let filename = path
+                                .file_name()
+                                .map(|s| s.to_string_lossy().to_string())
+                                .unwrap_or_else(|| "export".to_string());
+                            self.show_status_message(tr_args(
+                                "msg-exported",
+                                &[("file", filename.into())],
+                            ));
+                            self.log(tr_args(
+                                "log-exported",
+                                &[
+                                    ("name", server_name.as_str().into()),
+                                    ("path", format!("{:?}", path).into()),
+                                ],
+                            ));
+                        }
+                        Err(e) => {
+                            self.show_status_message(tr_args(
+                                "msg-export-failed",
+                                &[("error", e.to_string().into())],
+                            ));
+                            self.log(format!(
+                                "ERROR: {}",
+                                tr_args("log-export-failed", &[("error", e.to_string().into())])
+                            ));
+                        }
+                    }
+                }
+                TaskMessage::TemplateIndexFetched { result } => {
+                    self.template_index_fetching = false;
+                    match &result {
+                        Ok(count) => {
+                            self.log(tr_args("log-templates-updated", &[("count", count.into())]));
+                            self.reload_templates();
+                        }
+                        Err(e) => self.log(tr_args(
+                            "log-templates-not-updated",
+                            &[("error", e.to_string().into())],
+                        )),
+                    }
+                    self.template_index_result = Some(result);
+                }
+                TaskMessage::PreflightDone { name, report } => {
+                    self.finish_preflight(&name, report);
+                }
+                TaskMessage::LegacyContainerRemoved { name, result } => match result {
+                    Ok(()) => {
+                        if let Some(server) =
+                            self.servers.iter_mut().find(|s| s.config.name == name)
+                        {
+                            server.container_id = None;
+                        }
+                        self.save_servers();
+                        self.log(tr_args(
+                            "log-unlabeled-container-removed",
+                            &[("name", name.into())],
+                        ));
+                    }
+                    Err(e) => self.log(format!(
+                        "WARNING: {}",
+                        tr_args(
+                            "log-old-container-remove-failed",
+                            &[("name", name.into()), ("error", e.to_string().into())]
+                        )
+                    )),
+                },
+                TaskMessage::DynamicDnsChecked { ip, update } => {
+                    self.ddns_busy = false;
+                    let now = chrono::Local::now();
+                    let ip = match ip {
+                        Ok(ip) => ip,
+                        Err(e) => {
+                            self.log(format!(
+                                "WARNING: {}",
+                                tr_args("log-ddns-error", &[("error", e.to_string().into())])
+                            ));
+                            self.ddns_status = Some((Err(e), now));
+                            continue;
+                        }
+                    };
+                    if self.settings.detect_public_ip {
+                        self.public_ip = Some(ip.clone());
+                    }
+                    match update {
+                        Some(Ok(())) => {
+                            self.log(tr_args(
+                                "log-ddns-updated",
+                                &[
+                                    ("host", self.settings.ddns.hostname.trim().into()),
+                                    ("ip", ip.to_string().into()),
+                                ],
+                            ));
+                            self.ddns_pushed = Some((ip.clone(), std::time::Instant::now()));
+                            self.ddns_status = Some((Ok(ip), now));
+                        }
+                        Some(Err(e)) => {
+                            self.log(format!(
+                                "WARNING: {}",
+                                tr_args("log-ddns-failed", &[("error", e.to_string().into())])
+                            ));
+                            self.ddns_status = Some((Err(e), now));
+                        }
+                        None => {}
+                    }
+                }
+                TaskMessage::PublicIpDetected { result } => match result {
+                    Ok(ip) => self.public_ip = Some(ip),
+                    Err(e) => self.log(tr_args(
+                        "log-public-ip-failed",
+                        &[("error", e.to_string().into())],
+                    )),
+                },
+                TaskMessage::ContainerExited { name } => {
+                    // Ignore it if the server was stopped in the meantime
+                    let running = self
+                        .servers
+                        .iter()
+                        .any(|s| s.config.name == name && s.status == ServerStatus::Running);
+                    if running {
+                        self.log(tr_args(
+                            "log-container-stopped",
+                            &[("name", name.as_str().into())],
+                        ));
+                        let status =
+                            ServerStatus::Error("Container exited unexpectedly".to_string());
+                        self.apply_server_status(name, status, None);
+                    }
+                }
+                TaskMessage::BackupAges { ages } => {
+                    for (name, age) in ages {
+                        let rules = self.server_alert_rules(&name);
+                        if let Some(alert) = self.alert_monitor.check_backup(&name, &rules, age) {
+                            self.raise_alert(alert);
+                        }
+                    }
+                }
+                TaskMessage::DiskUsageMeasured { usage } => {
+                    self.disk_usage_measuring = false;
+                    self.disk_usage = usage;
+                }
+                TaskMessage::MetricsHistorySample { samples } => {
+                    let at = chrono::Utc::now().timestamp();
+                    for (server_name, sample) in samples {
+                        let point = HistoryPoint {
+                            at,
+                            players: self.live_status.get(&server_name).map(|s| s.players_online),
+                            tps: sample.tps,
+                            memory_bytes: sample.stats.map(|s| s.memory_bytes),
+                        };
+                        if let Err(e) = metrics_history::append(&server_name, &point) {
+                            tracing::warn!("Saving metrics for '{}' failed: {:#}", server_name, e);
+                        }
+                        if let Some((name, Some(points))) = &mut self.metrics_history {
+                            if *name == server_name {
+                                points.push(point);
+                            }
+                        }
+                        let rules = self.server_alert_rules(&server_name);
+                        for alert in self
+                            .alert_monitor
+                            .check_sample(&server_name, &rules, &sample)
+                        {
+                            self.raise_alert(alert);
+                        }
+                    }
+                }
+                TaskMessage::MetricsHistoryLoaded {
+                    server_name,
+                    points,
+                } => {
+                    // Ignore a history for a server that's no longer shown
+                    if let Some((name, loaded)) = &mut self.metrics_history {
+                        if *name == server_name {
+                            *loaded = Some(points);
+                        }
+                    }
+                }
+                TaskMessage::BotCommand(request) => self.handle_bot_command(request),
+                TaskMessage::DiscordBotFailed { error } => {
+                    self.discord_bot = None;
+                    self.log(format!(
+                        "WARNING: {}",
+                        tr_args(
+                            "log-discord-bot-stopped",
+                            &[("error", error.to_string().into())]
+                        )
+                    ));
+                    self.discord_bot_error = Some(error);
+                }
+                TaskMessage::MetricsEndpointFailed { error } => {
+                    self.metrics_endpoint = None;
+                    self.log(tr_args(
+                        "log-metrics-error",
+                        &[("error", error.to_string().into())],
+                    ));
+                    self.metrics_error = Some(error);
+                }
+                TaskMessage::ReachabilityChecked { address, result } => match result {
+                    Ok(true) => self.log(tr_args(
+                        "log-reachable",
+                        &[("address", address.to_string().into())],
+                    )),
+                    Ok(false) => {
+                        self.log(format!(
+                            "WARNING: {}",
+                            tr_args(
+                                "log-unreachable",
+                                &[("address", address.to_string().into())]
+                            )
+                        ));
+                        self.show_status_message(tr_args(
+                            "msg-unreachable",
+                            &[("address", address.into())],
+                        ));
+                    }
+                    Err(e) => self.log(tr_args(
+                        "log-reachability-failed",
+                        &[
+                            ("address", address.to_string().into()),
+                            ("error", e.to_string().into()),
+                        ],
+                    )),
+                },
+                TaskMessage::TemplateHealthChecked { results } => {
+                    self.create_view.template_check_running = false;
+                    let mut broken: Vec<&String> = results
+                        .iter()
+                        .filter(|(_, h)| h.status == CheckStatus::Fail)
+                        .map(|(name, _)| name)
+                        .collect();
+                    broken.sort();
+                    for name in &broken {
+                        self.log(tr_args(
+                            "log-template-broken",
+                            &[
+                                ("name", name.to_string().into()),
+                                ("detail", results[*name].detail.as_str().into()),
+                            ],
+                        ));
+                    }
+                    self.show_status_message(if broken.is_empty() {
+                        tr_args("msg-templates-fine", &[("count", results.len().into())])
+                    } else {
+                        tr_args(
+                            "msg-templates-broken",
+                            &[
+                                ("broken", broken.len().into()),
+                                ("count", results.len().into()),
+                            ],
+                        )
+                    });
+                    self.create_view.template_health = results;
+                }
+                TaskMessage::TemplateFetched { result } => {
+                    self.create_view.template_url_loading = false;
+                    match result {
+                        Ok(template) => {
+                            self.create_view.template_url.clear();
+                            self.add_user_template(template);
+                        }
+                        Err(e) => self.show_status_message(tr_args(
+                            "msg-template-add-failed",
+                            &[("error", e.into())],
+                        )),
+                    }
+                }
+                TaskMessage::ProxyTestComplete(result) => {
+                    self.proxy_testing = false;
+                    self.proxy_test_result = Some(result);
+                }
+                TaskMessage::DataMoveProgress {
+                    current,
+                    total,
+                    current_file,
+                } => {
+                    self.data_move_progress = Some((current, total, current_file));
+                }
+                TaskMessage::DataMoveComplete { result } => {
+                    self.finish_data_move(result);
+                }
+                TaskMessage::ImportComplete { result } => match result {
+                    Ok(config) => {
+                        let config = *config;
+                        let name = config.name.clone();
+                        let instance = ServerInstance {
+                            config,
+                            container_id: None,
+                            status: ServerStatus::Stopped,
+                        };
+                        self.servers.push(instance);
+                        self.save_servers();
+                        self.refresh_orphaned_dirs();
+                        self.show_status_message(tr_args(
+                            "msg-server-imported",
+                            &[("name", name.into())],
+                        ));
+                    }
+                    Err(e) => {
+                        self.show_status_message(tr_args(
+                            "msg-import-failed",
+                            &[("error", e.to_string().into())],
+                        ));
+                        self.log(format!(
+                            "ERROR: {}",
+                            tr_args("log-import-failed", &[("error", e.to_string().into())])
+                        ));
+                    }
+                },
+                TaskMessage::ContainerStartedAt { name, started_at } => {
+                    self.record_uptime(&name, |log| log.started(started_at.timestamp()));
+                    self.running_since.insert(name, started_at);
+                }
+                TaskMessage::ContainerConflict { server_name } => {
+                    if let Some(server) = self
+                        .servers
+                        .iter_mut()
+                        .find(|s| s.config.name == server_name)
+                    {
+                        server.status = ServerStatus::Stopped;
+                    }
+                    self.current_view = View::ConfirmRemoveContainer(server_name);
+                }
+            }
+        }
+    }
+
+    /// Return a mutable reference to the CF widget for whichever view is active.
+    fn active_cf_widget(&mut self) -> Option<&mut CfBrowseWidget> {
+        match &self.current_view {
+            View::CreateServer => Some(&mut self.create_view.cf),
+            View::ServerDetails(_) => Some(&mut self.edit_view.cf),
+            _ => None,
+        }
+    }
+
+    /// Spawn an async CurseForge search task.
+    fn dispatch_cf_search(&self, state: CfSearchState) {
+        let api_key = self.settings.curseforge_api_key.clone().unwrap_or_default();
+        let tx = self.task_tx.clone();
+        let query = state.query.clone();
+        let mc_ver = state.mc_version_filter.clone();
+        let loader = state.selected_loader();
+        let sort_field = state.sort_field;
+        let page_offset = state.page_offset;
+
+        self.runtime.spawn(async move {
+            match curseforge::search_modpacks(
+                &api_key,
+                &query,
+                &mc_ver,
+                loader.as_ref(),
+                sort_field,
+                page_offset,
+            )
+            .await
+            {
+                Ok((results, total_count)) => {
+                    tx.send(TaskMessage::CfSearchResults {
+                        results,
+                        total_count,
+                    })
+                    .ok();
+                }
+                Err(e) => {
+                    tx.send(TaskMessage::CfSearchError(e.to_string())).ok();
+                }
+            }
+        });
+    }
+
+    /// Spawn an async CurseForge version fetch task.
+    fn dispatch_cf_fetch_versions(&self, mod_id: u64) {
+        let api_key = self.settings.curseforge_api_key.clone().unwrap_or_default();
+        let tx = self.task_tx.clone();
+
+        self.runtime.spawn(async move {
+            match curseforge::get_mod_files(&api_key, mod_id).await {
+                Ok(files) => {
+                    tx.send(TaskMessage::CfVersionResults { mod_id, files })
+                        .ok();
+                }
+                Err(e) => {
+                    tx.send(TaskMessage::CfVersionError {
+                        mod_id,
+                        error: e.to_string(),
+                    })
+                    .ok();
+                }
+            }
+        });
+    }
+
+    /// Spawn an async CurseForge description fetch task.
+    fn dispatch_cf_fetch_description(&self, mod_id: u64) {
+        let api_key = self.settings.curseforge_api_key.clone().unwrap_or_default();
+        let tx = self.task_tx.clone();
+
+        self.runtime.spawn(async move {
+            match curseforge::get_mod_description(&api_key, mod_id).await {
+                Ok(description) => {
+                    tx.send(TaskMessage::CfDescriptionResult {
+                        mod_id,
+                        description,
+                    })
+                    .ok();
+                }
+                Err(e) => {
+                    tx.send(TaskMessage::CfDescriptionError {
+                        mod_id,
+                        error: e.to_string(),
+                    })
+                    .ok();
+                }
+            }
+        });
+    }
+
+    /// Return a mutable reference to the MR widget for whichever view is active.
+    fn active_mr_widget(&mut self) -> Option<&mut MrBrowseWidget> {
+        match &self.current_view {
+            View::CreateServer => Some(&mut self.create_view.mr),
+            View::ServerDetails(_) => Some(&mut self.edit_view.mr),
+            _ => None,
+        }
+    }
+
+    /// Spawn an async Modrinth search task.
+    fn dispatch_ftb_search(&self, query: String) {
+        let tx = self.task_tx.clone();
+        self.runtime.spawn(async move {
+            let result = crate::ftb::search(&query)
+                .await
+                .map_err(|e| format!("{:#}", e));
+            tx.send(TaskMessage::FtbSearchComplete { result }).ok();
+        });
+    }
+
+    fn dispatch_mr_search(&self, state: MrSearchState) {
+        let tx = self.task_tx.clone();
+        let query = state.query.clone();
+        let mc_ver = state.mc_version_filter.clone();
+        let loader = state.selected_loader_str().to_string();
+        let sort = state.sort_index;
+        let page_offset = state.page_offset;
+        let token = self.settings.modrinth_token.clone();
+
+        self.runtime.spawn(async move {
+            match modrinth::search_modpacks(
+                token.as_deref(),
+                &query,
+                &mc_ver,
+                &loader,
+                sort,
+                page_offset,
+            )
+            .await
+            {
+                Ok((results, total_count)) => {
+                    tx.send(TaskMessage::MrSearchResults {
+                        results,
+                        total_count,
+                    })
+                    .ok();
+                }
+                Err(e) => {
+                    tx.send(TaskMessage::MrSearchError(e.to_string())).ok();
+                }
+            }
+        });
+    }
+
+    /// Spawn a combined CurseForge + Modrinth search task.
+    fn dispatch_unified_search(&self, state: UnifiedSearchState) {
+        let tx = self.task_tx.clone();
+        let cf_key = self
+            .settings
+            .curseforge_api_key
+            .clone()
+            .filter(|k| !k.is_empty());
+        let mr_token = self.settings.modrinth_token.clone();
+        let loader = state.selected_loader();
+
+        self.runtime.spawn(async move {
+            let (results, errors) = search::search_all(
+                cf_key.as_deref(),
+                mr_token.as_deref(),
+                &state.query,
+                &state.mc_version_filter,
+                loader.as_ref(),
+            )
+            .await;
+            tx.send(TaskMessage::UnifiedSearchResults { results, errors })
+                .ok();
+        });
+    }
+
+    /// Spawn an async Modrinth version fetch task.
+    fn dispatch_mr_fetch_versions(&self, project_id: String) {
+        let tx = self.task_tx.clone();
+        let pid = project_id.clone();
+        let token = self.settings.modrinth_token.clone();
+
+        self.runtime.spawn(async move {
+            match modrinth::get_project_versions(token.as_deref(), &pid).await {
+                Ok(versions) => {
+                    tx.send(TaskMessage::MrVersionResults {
+                        project_id,
+                        versions,
+                    })
+                    .ok();
+                }
+                Err(e) => {
+                    tx.send(TaskMessage::MrVersionError {
+                        project_id,
+                        error: e.to_string(),
+                    })
+                    .ok();
+                }
+            }
+        });
+    }
+
+    /// Spawn an async Modrinth description fetch task.
+    fn dispatch_mr_fetch_description(&self, project_id: String) {
+        let tx = self.task_tx.clone();
+        let pid = project_id.clone();
+        let token = self.settings.modrinth_token.clone();
+
+        self.runtime.spawn(async move {
+            match modrinth::get_project_description(token.as_deref(), &pid).await {
+                Ok(description) => {
+                    tx.send(TaskMessage::MrDescriptionResult {
+                        project_id,
+                        description,
+                    })
+                    .ok();
+                }
+                Err(e) => {
+                    tx.send(TaskMessage::MrDescriptionError {
+                        project_id,
+                        error: e.to_string(),
+                    })
+                    .ok();
+                }
+            }
+        });
+    }
+
+    /// Check if any servers are in a transient state (need UI refresh)
+    fn has_active_tasks(&self) -> bool {
+        self.backup_progress.is_some()
+            || self.restore_progress.is_some()
+            || self.export_progress.is_some()
+            || self.data_move_progress.is_some()
+            || self.proxy_testing
+            || self.create_view.template_url_loading
+            || self.template_index_fetching
+            || self.create_view.template_check_running
+            || self.create_view.unified.loading
+            || self.mods_scanning
+            || !self.console_pending.is_empty()
+            || !self.mods_installing.is_empty()
+            || !self.mod_browse.installing.is_empty()
+            || self.create_view.cf.state.loading_search
+            || self.create_view.cf.state.loading_versions
+            || self.create_view.cf.state.loading_description
+            || self.edit_view.cf.state.loading_search
+            || self.edit_view.cf.state.loading_versions
+            || self.edit_view.cf.state.loading_description
+            || self.create_view.mr.state.loading_search
+            || self.create_view.ftb.state.loading_search
+            || self.create_view.mr.state.loading_versions
+            || self.create_view.mr.state.loading_description
+            || self.edit_view.mr.state.loading_search
+            || self.edit_view.mr.state.loading_versions
+            || self.edit_view.mr.state.loading_description
+            || self.servers.iter().any(|s| {
+                matches!(
+                    s.status,
+                    ServerStatus::Pulling
+                        | ServerStatus::Starting
+                        | ServerStatus::Initializing
+                        | ServerStatus::Stopping
+                )
+            })
+    }
+
+    /// Keep the tray menu in step with the servers and act on what was picked in it
+    fn handle_tray(&mut self, ctx: &egui::Context) {
+        let Some(tray) = &mut self.tray else {
+            return;
+        };
+        let servers: Vec<TrayServer> = self
+            .servers
+            .iter()
+            .map(|s| TrayServer {
+                name: s.config.name.clone(),
+                running: matches!(
+                    s.status,
+                    ServerStatus::Running | ServerStatus::Initializing | ServerStatus::Frozen
+                ),
+                busy: matches!(
+                    s.status,
+                    ServerStatus::Pulling | ServerStatus::Starting | ServerStatus::Stopping
+                ),
+                checking: self.preflight_running.contains(&s.config.name),
+            })
+            .collect();
+        tray.update(&servers);
+
+        let actions: Vec<TrayAction> = std::iter::from_fn(|| tray.try_recv()).collect();
+        for action in actions {
+            match action {
+                TrayAction::Show => self.restore_window(ctx),
+                TrayAction::Start(name) => self.start_server(&name),
+                TrayAction::Stop(name) => self.stop_server(&name),
+                TrayAction::Quit => {
+                    if self.running_servers().is_empty() {
+                        ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+                    } else {
+                        // Same question as closing the window with servers running
+                        self.restore_window(ctx);
+                        self.show_close_confirmation = true;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Bring the window back from being minimized and in front
+    fn restore_window(&mut self, ctx: &egui::Context) {
+        self.minimized_on_close = false;
+        ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(false));
+        ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+    }
+
+    /// Get list of running server names
+    fn running_servers(&self) -> Vec<&str> {
+        self.servers
+            .iter()
+            .filter(|s| {
+                matches!(
+                    s.status,
+                    ServerStatus::Running | ServerStatus::Initializing | ServerStatus::Frozen
+                )
+            })
+            .map(|s| s.config.name.as_str())
+            .collect()
+    }
+
+    /// Containers created before instance IDs have no instance label, so another install
+    /// on the same host can't tell they aren't its own. Claim the ones whose ID we recorded
+    /// by renaming them to this install's scheme; labels can't be added to a container, so
+    /// the stopped ones are returned for the user to decide whether to recreate them.
+    async fn migrate_legacy_containers(
+        docker: &DockerManager,
+        servers: &[ServerInstance],
+        instance_id: &str,
+    ) -> (Vec<String>, Vec<String>) {
+        let mut messages = Vec::new();
+        let mut stopped_servers = Vec::new();
+        let legacy = match docker.list_legacy_containers().await {
+            Ok(containers) => containers,
+            Err(e) => {
+                messages.push(format!(
+                    "WARNING: {}",
+                    tr_args(
+                        "log-legacy-check-failed",
+                        &[("error", e.to_string().into())]
+                    )
+                ));
+                return (messages, stopped_servers);
+            }
+        };
+
+        for container in legacy {
+            let Some(id) = container.id.as_deref() else {
+                continue;
+            };
+            // Not one we created; leave it to whichever install did
+            let Some(server) = servers
+                .iter()
+                .find(|s| s.container_id.as_deref() == Some(id))
+            else {
+                continue;
+            };
+            let name = server.config.name.clone();
+
+            let new_name = get_container_name(instance_id, &name);
+            let current = container
+                .names
+                .as_ref()
+                .and_then(|n| n.first())
+                .map(|n| n.trim_start_matches('/').to_string());
+            if current.as_deref() != Some(new_name.as_str()) {
+                match docker.rename_container(id, &new_name).await {
+                    Ok(()) => messages.push(tr_args(
+                        "log-legacy-renamed",
+                        &[
+                            ("name", name.as_str().into()),
+                            ("container", new_name.as_str().into()),
+                        ],
+                    )),
+                    Err(e) => messages.push(format!(
+                        "WARNING: {}",
+                        tr_args(
+                            "log-legacy-rename-failed",
+                            &[
+                                ("name", name.as_str().into()),
+                                ("error", e.to_string().into())
+                            ]
+                        )
+                    )),
+                }
+            }
+
+            let stopped = matches!(
+                container.state.as_deref(),
+                Some("exited") | Some("created") | Some("dead")
+            );
+            if stopped {
+                stopped_servers.push(name);
+            }
+        }
+        (messages, stopped_servers)
+    }
+
+    /// Remove the pre-instance-ID containers the user agreed to recreate; each server
+    /// gets a labeled container on its next start
+    fn recreate_legacy_containers(&mut self) {
+        let names = std::mem::take(&mut self.legacy_containers);
+        let Some(docker) = self.docker.clone() else {
+            return;
+        };
+        for name in names {
+            let Some(container_id) = self
+                .servers
+                .iter()
+                .find(|s| s.config.name == name)
+                .and_then(|s| s.container_id.clone())
+            else {
+                continue;
+            };
+            let docker = docker.clone();
+            let tx = self.task_tx.clone();
+            self.runtime.spawn(async move {
+                let result = docker
+                    .remove_container(&container_id)
+                    .await
+                    .map_err(|e| e.to_string());
+                tx.send(TaskMessage::LegacyContainerRemoved { name, result })
+                    .ok();
+            });
+        }
+    }
+
+    /// Names of the server's operators, from ops.json in its data directory
+    fn read_ops(server_name: &str) -> Vec<String> {
+        let path = get_server_data_path(server_name).join("ops.json");
+        let Ok(json) = std::fs::read_to_string(path) else {
+            return Vec::new();
+        };
+        let entries: Vec<serde_json::Value> = serde_json::from_str(&json).unwrap_or_default();
+        entries
+            .iter()
+            .filter_map(|e| e["name"].as_str().map(str::to_string))
+            .collect()
+    }
+
+    /// Poll the Minecraft server until it accepts connections
+    async fn poll_mc_server_ready(
+        tx: mpsc::Sender<TaskMessage>,
+        name: String,
+        address: String,
+        container_id: String,
+        docker: Arc<DockerManager>,
+    ) {
+        let client = McClient::new().with_timeout(Duration::from_secs(3));
+        let max_attempts = 120; // 10 minutes at 5 second intervals
+        let poll_interval = Duration::from_secs(5);
+
+        for attempt in 1..=max_attempts {
+            // First check if container is still running
+            match docker.is_container_running(&container_id).await {
+                Ok(true) => {} // Container still running, continue
+                Ok(false) => {
+                    // Container stopped/crashed
+                    tx.send(TaskMessage::Log(tr_args(
+                        "log-container-stopped",
+                        &[("name", name.as_str().into())],
+                    )))
+                    .ok();
+                    tx.send(TaskMessage::ServerStatus {
+                        name,
+                        status: ServerStatus::Error("Container exited unexpectedly".to_string()),
+                        container_id: Some(container_id),
+                    })
+                    .ok();
+                    return;
+                }
+                Err(e) => {
+                    tx.send(TaskMessage::Log(tr_args(
+                        "log-container-status-failed",
+                        &[("error", e.to_string().into())],
+                    )))
+                    .ok();
+                    // Continue trying - might be transient
+                }
+            }
+
+            // Estimate how far along startup is from the log
+            if let Ok(logs) = docker.get_container_logs(&container_id, 200).await {
+                if let Some(milestone) = startup::parse(&logs) {
+                    tx.send(TaskMessage::StartupProgress {
+                        server_name: name.clone(),
+                        milestone,
+                    })
+                    .ok();
+                }
+            }
+
+            match client.ping(&address, ServerEdition::Java).await {
+                Ok(status) if status.online => {
+                    // Log basic connection info
+                    tx.send(TaskMessage::Log(tr_args(
+                        "log-accepting-connections",
+                        &[
+                            ("name", name.as_str().into()),
+                            ("latency", format!("{:.0}", status.latency).into()),
+                        ],
+                    )))
+                    .ok();
+
+                    // Extract and log rich Java status info
+                    if let ServerData::Java(java) = &status.data {
+                        // Version info
+                        tx.send(TaskMessage::Log(format!(
+                            "  {}",
+                            tr_args(
+                                "log-ping-version",
+                                &[
+                                    ("version", java.version.name.as_str().into()),
+                                    ("protocol", java.version.protocol.to_string().into())
+                                ]
+                            )
+                        )))
+                        .ok();
+
+                        // MOTD/Description
+                        if !java.description.is_empty() {
+                            tx.send(TaskMessage::Log(format!(
+                                "  {}",
+                                tr_args(
+                                    "log-ping-motd",
+                                    &[(
+                                        "motd",
+                                        java.description
+                                            .lines()
+                                            .next()
+                                            .unwrap_or(&java.description)
+                                            .into()
+                                    )]
+                                )
+                            )))
+                            .ok();
+                        }
+
+                        // Player info
+                        tx.send(TaskMessage::Log(format!(
+                            "  {}",
+                            tr_args(
+                                "log-ping-players",
+                                &[
+                                    ("online", java.players.online.into()),
+                                    ("max", java.players.max.into())
+                                ]
+                            )
+                        )))
+                        .ok();
+
+                        // Server software if available
+                        if let Some(software) = &java.software {
+                            tx.send(TaskMessage::Log(format!(
+                                "  {}",
+                                tr_args(
+                                    "log-ping-software",
+                                    &[("software", software.as_str().into())]
+                                )
+                            )))
+                            .ok();
+                        }
+
+                        // Mod count if modded
+                        if let Some(mods) = &java.mods {
+                            if !mods.is_empty() {
+                                tx.send(TaskMessage::Log(format!(
+                                    "  {}",
+                                    tr_args("log-ping-mods", &[("count", mods.len().into())])
+                                )))
+                                .ok();
+                            }
+                        }
+
+                        // Plugin count if available
+                        if let Some(plugins) = &java.plugins {
+                            if !plugins.is_empty() {
+                                tx.send(TaskMessage::Log(format!(
+                                    "  {}",
+                                    tr_args("log-ping-plugins", &[("count", plugins.len().into())])
+                                )))
+                                .ok();
+                            }
+                        }
+
+                        // Map name if available
+                        if let Some(map) = &java.map {
+                            tx.send(TaskMessage::Log(format!(
+                                "  {}",
+                                tr_args("log-ping-map", &[("map", map.as_str().into())])
+                            )))
+                            .ok();
+                        }
+                    }
+
+                    tx.send(TaskMessage::ServerStatus {
+                        name,
+                        status: ServerStatus::Running,
+                        container_id: Some(container_id),
+                    })
+                    .ok();
+                    return;
+                }
+                Ok(_) => {
+                    // Server responded but says offline - keep trying
+                    if attempt % 6 == 0 {
+                        // Log every 30 seconds
+                        tx.send(TaskMessage::Log(tr_args(
+                            "log-not-ready",
+                            &[
+                                ("name", name.as_str().into()),
+                                ("attempt", attempt.into()),
+                                ("max", max_attempts.into()),
+                            ],
+                        )))
+                        .ok();
+                    }
+                }
+                Err(_) => {
+                    // Connection failed - server not ready
+                    if attempt % 6 == 0 {
+                        // Log every 30 seconds
+                        tx.send(TaskMessage::Log(tr_args(
+                            "log-waiting-init",
+                            &[
+                                ("name", name.as_str().into()),
+                                ("attempt", attempt.into()),
+                                ("max", max_attempts.into()),
+                            ],
+                        )))
+                        .ok();
+                    }
+                }
+            }
+
+            tokio::time::sleep(poll_interval).await;
+        }
+
+        // Timed out but don't error - modpacks can take a very long time
+        tx.send(TaskMessage::Log(tr_args(
+            "log-still-initializing",
+            &[("name", name.as_str().into())],
+        )))
+        .ok();
+        // Keep status as Initializing - user can check logs
+    }
+}
+
+/// Read a backup back on the blocking pool, showing it as the server's backup progress
+async fn verify_backup_file(
+    server_name: String,
+    path: std::path::PathBuf,
+    tx: mpsc::Sender<TaskMessage>,
+) -> Result<backup::BackupVerification, String> {
+    tokio::task::spawn_blocking(move || {
+        let (progress_tx, progress_rx) = std::sync::mpsc::channel::<backup::BackupProgress>();
+        std::thread::spawn(move || {
+            while let Ok(progress) = progress_rx.recv() {
+                let _ = tx.send(TaskMessage::BackupProgress {
+                    server_name: server_name.clone(),
+                    current: progress.current,
+                    total: progress.total,
+                    current_file: tr_args(
+                        "progress-verifying-file",
+                        &[("file", progress.current_file.into())],
+                    ),
+                });
+            }
+        });
+        backup::verify_backup(&path, Some(progress_tx)).map_err(|e| e.to_string())
+    })
+    .await
+    .unwrap_or_else(|e| Err(e.to_string()))
+}
+
+/// Copy a finished backup to remote storage, then trim old uploads of the server.
+/// Progress and the outcome go back over the task channel.
+async fn upload_backup(
+    remote: RemoteBackupSettings,
+    server_name: String,
+    path: std::path::PathBuf,
+    tx: mpsc::Sender<TaskMessage>,
+) {
+    let tx_progress = tx.clone();
+    let name = server_name.clone();
+    let progress: remote_backup::Progress = Arc::new(move |sent, total| {
+        let _ = tx_progress.send(TaskMessage::UploadProgress {
+            server_name: name.clone(),
+            sent,
+            total,
+        });
+    });
+    let result = async {
+        remote_backup::upload(&remote, &server_name, &path, progress).await?;
+        remote_backup::prune(&remote, &server_name).await
+    }
+    .await;
+    tx.send(TaskMessage::UploadComplete {
+        server_name,
+        result: result.map_err(|e| e.to_string()),
+    })
+    .ok();
+}
+
+impl eframe::App for DrakonixApp {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        // Process any pending messages from background tasks
+        self.process_task_messages();
+        // Scheduled work would write into the data folder while it's being moved
+        if self.data_move_progress.is_none() {
+            self.run_scheduled_broadcasts();
+            self.run_scheduled_backups();
+        }
+        self.sample_playtime();
+        self.ping_running_servers();
+        self.sample_metrics();
+        self.check_disk_usage();
+        self.check_backup_alerts();
+        self.check_dynamic_dns();
+        self.check_crash_reports();
+        self.check_error_spike();
+        self.handle_dropped_files(ctx);
+
+        self.handle_tray(ctx);
+
+        // Once the window minimized on close is back in front, closing it asks again
+        if self.minimized_on_close {
+            let (minimized, focused) =
+                ctx.input(|i| (i.viewport().minimized, i.viewport().focused));
+            if minimized == Some(true) || focused == Some(false) {
+                self.left_after_minimize = true;
+            } else if self.left_after_minimize && focused == Some(true) {
+                self.minimized_on_close = false;
+            }
+        }
+
+        // Handle close request - warn if servers are running
+        if ctx.input(|i| i.viewport().close_requested()) {
+            let running = self.running_servers();
+            let shift = ctx.input(|i| i.modifiers.shift);
+            if running.is_empty() {
+                // No running servers, allow close
+            } else if self.settings.minimize_on_close && !self.minimized_on_close && !shift {
+                // Keep supervising from the background; a second close (or a
+                // Shift-close) quits for real
+                let count = running.len();
+                self.minimized_on_close = true;
+                self.left_after_minimize = false;
+                ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
+                ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(true));
+                self.show_status_message(tr_args("close-minimized", &[("count", count.into())]));
+            } else if self.settings.minimize_on_close {
+                // Already minimized once or Shift held: quit, leaving the
+                // containers running in Docker
+            } else {
+                // Servers running, show confirmation
+                ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
+                self.show_close_confirmation = true;
+            }
+        }
+
+        // Show close confirmation dialog
+        if self.show_close_confirmation {
+            let running = self.running_servers();
+            let running_names: Vec<String> = running.iter().map(|s| s.to_string()).collect();
+
+            egui::Window::new(tr("close-title"))
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .show(ctx, |ui| {
+                    ui.vertical_centered(|ui| {
+                        ui.add_space(10.0);
+                        ui.colored_label(
+                            egui::Color32::YELLOW,
+                            tr_args("close-running", &[("count", running_names.len().into())]),
+                        );
+                        ui.add_space(5.0);
+
+                        for name in &running_names {
+                            ui.label(format!("  • {}", name));
+                        }
+
+                        ui.add_space(15.0);
+                        ui.label(tr("close-left-running"));
+                        ui.small(tr("close-stop-later"));
+                        ui.add_space(15.0);
+
+                        ui.horizontal(|ui| {
+                            if ui.button(tr("button-cancel")).clicked() {
+                                self.show_close_confirmation = false;
+                            }
+                            if ui
+                                .button(tr("close-minimize"))
+                                .on_hover_text(tr("close-minimize-hint"))
+                                .clicked()
+                            {
+                                self.show_close_confirmation = false;
+                                ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(true));
+                            }
+                            ui.add_space(20.0);
+                            if ui
+                                .add(
+                                    egui::Button::new(tr("close-anyway"))
+                                        .fill(egui::Color32::from_rgb(150, 100, 40)),
+                                )
+                                .clicked()
+                            {
+                                ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+                            }
+                        });
+                        ui.add_space(10.0);
+                    });
+                });
+        }
+
+        // Show orphan deletion confirmation dialog
+        if let Some(orphan_name) = self.confirm_delete_orphan.clone() {
+            egui::Window::new(tr("orphan-delete-title"))
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .show(ctx, |ui| {
+                    ui.vertical_centered(|ui| {
+                        ui.add_space(10.0);
+                        ui.colored_label(egui::Color32::RED, tr("orphan-delete-warning"));
+                        ui.add_space(5.0);
+                        ui.label(format!("  • servers/{}/", orphan_name));
+                        ui.label(format!(
+                            "  • backups/{}/  {}",
+                            orphan_name,
+                            tr("orphan-delete-if-any")
+                        ));
+                        ui.add_space(10.0);
+                        ui.label(tr("orphan-delete-undo"));
+                        ui.add_space(15.0);
+
+                        ui.horizontal(|ui| {
+                            if ui.button(tr("button-cancel")).clicked() {
+                                self.confirm_delete_orphan = None;
+                            }
+                            ui.add_space(20.0);
+                            if ui
+                                .add(
+                                    egui::Button::new(tr("button-delete"))
+                                        .fill(egui::Color32::from_rgb(180, 50, 50)),
+                                )
+                                .clicked()
+                            {
+                                self.delete_orphan(&orphan_name);
+                                self.confirm_delete_orphan = None;
+                            }
+                        });
+                        ui.add_space(10.0);
+                    });
+                });
+        }
+
+        // Ask before recreating containers from before instance IDs
+        if !self.legacy_containers.is_empty() {
+            egui::Window::new(tr("legacy-containers-title"))
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .show(ctx, |ui| {
+                    ui.vertical_centered(|ui| {
+                        ui.add_space(10.0);
+                        ui.label(tr("legacy-containers-explain"));
+                        ui.add_space(5.0);
+                        for name in &self.legacy_containers {
+                            ui.label(format!("  • {}", name));
+                        }
+                        ui.add_space(10.0);
+                        ui.label(tr("legacy-containers-data-kept"));
+                        ui.add_space(15.0);
+
+                        ui.horizontal(|ui| {
+                            if ui.button(tr("legacy-containers-keep")).clicked() {
+                                self.legacy_containers.clear();
+                            }
+                            ui.add_space(20.0);
+                            if ui.button(tr("legacy-containers-recreate")).clicked() {
+                                self.recreate_legacy_containers();
+                            }
+                        });
+                        ui.add_space(10.0);
+                    });
+                });
+        }
+
+        // Without the saved setting the next launch looks in the old, empty folder
+        if let Some((path, error)) = self.data_root_unsaved.clone() {
+            egui::Window::new(tr("data-move-unsaved-title"))
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .show(ctx, |ui| {
+                    ui.vertical_centered(|ui| {
+                        ui.add_space(10.0);
+                        ui.colored_label(
+                            egui::Color32::from_rgb(220, 80, 80),
+                            tr_args(
+                                "data-move-unsaved-explain",
+                                &[
+                                    ("path", path.display().to_string().into()),
+                                    ("error", error.into()),
+                                ],
+                            ),
+                        );
+                        ui.add_space(15.0);
+                        if ui.button(tr("data-move-unsaved-retry")).clicked() {
+                            match save_settings(&self.settings) {
+                                Ok(()) => self.data_root_unsaved = None,
+                                Err(e) => {
+                                    self.data_root_unsaved = Some((path.clone(), e.to_string()))
+                                }
+                            }
+                        }
+                        ui.add_space(10.0);
+                    });
+                });
+        }
+
+        // Show new profile dialog
+        if self.new_profile_name.is_some() {
+            let mut create = false;
+            let mut cancel = false;
+            egui::Window::new(tr("profile-new-title"))
+                .collapsible(false)
+                .resizable(false)
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .show(ctx, |ui| {
+                    ui.label(tr("profile-new-hint"));
+                    ui.add_space(5.0);
+                    if let Some(name) = &mut self.new_profile_name {
+                        let response = ui.add(
+                            egui::TextEdit::singleline(name)
+                                .hint_text(tr("profile-new-placeholder")),
+                        );
+                        response.request_focus();
+                        create =
+                            response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                    }
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        if ui.button(tr("button-cancel")).clicked() {
+                            cancel = true;
+                        }
+                        if ui.button(tr("profile-create")).clicked() {
+                            create = true;
+                        }
+                    });
+                });
+            if cancel {
+                self.new_profile_name = None;
+            } else if create {
+                let name = self.new_profile_name.clone().unwrap_or_default();
+                match crate::profiles::create(&name) {
+                    Ok(name) => {
+                        self.new_profile_name = None;
+                        self.switch_profile(ctx, Some(name));
+                        return;
+                    }
+                    Err(e) => self.show_status_message(format!("{:#}", e)),
+                }
+            }
+        }
+
+        // Global keyboard shortcuts, held off while one is being rebound
+        if self.current_view != View::Settings {
+            self.rebinding_shortcut = None;
+        }
+        if self.rebinding_shortcut.is_none() {
+            if self.settings.shortcuts.pressed(ctx, Shortcut::NewServer) {
+                self.current_view = View::CreateServer;
+            }
+            if self.settings.shortcuts.pressed(ctx, Shortcut::ShowLogs) {
+                self.current_view = View::Logs;
+            }
+        }
+
+        // Request repaint if there are active background tasks
+        if self.has_active_tasks() {
+            ctx.request_repaint_after(std::time::Duration::from_millis(100));
+        } else if self.discord_bot.is_some() {
+            // Bot commands are run from here, so don't leave them waiting
+            ctx.request_repaint_after(discord_bot::POLL_INTERVAL);
+        } else if !self.broadcasts_last_sent.is_empty()
+            || !self.playtime_sampled.is_empty()
+            || self.settings.ddns.enabled
+        {
+            // Wake up to send scheduled broadcasts, sample playtime and keep dynamic DNS
+            // current even when the UI is idle
+            ctx.request_repaint_after(std::time::Duration::from_secs(15));
+        }
+
+        // Top panel with app title and navigation
+        let mut profile_choice = None;
+        egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.strong("DrakonixAnvil");
+                ui.separator();
+
+                if ui
+                    .selectable_label(self.current_view == View::Dashboard, tr("nav-servers"))
+                    .clicked()
+                {
+                    self.current_view = View::Dashboard;
+                }
+                if ui
+                    .selectable_label(self.current_view == View::Logs, tr("nav-logs"))
+                    .clicked()
+                {
+                    self.current_view = View::Logs;
+                }
+                if ui
+                    .selectable_label(self.current_view == View::DockerLogs, tr("nav-docker-logs"))
+                    .clicked()
+                {
+                    self.load_all_docker_logs();
+                }
+                if ui
+                    .selectable_label(self.current_view == View::BackupOverview, tr("nav-backups"))
+                    .clicked()
+                {
+                    self.view_backup_overview();
+                }
+                if ui
+                    .selectable_label(self.current_view == View::Ports, tr("nav-ports"))
+                    .clicked()
+                {
+                    self.view_ports();
+                }
+                if ui
+                    .selectable_label(
+                        self.current_view == View::Diagnostics,
+                        tr("nav-diagnostics"),
+                    )
+                    .clicked()
+                {
+                    if self.diagnostics.is_none() {
+                        self.run_diagnostics(false);
+                    }
+                    self.current_view = View::Diagnostics;
+                }
+                if ui
+                    .selectable_label(self.current_view == View::Settings, tr("nav-settings"))
+                    .clicked()
+                {
+                    self.current_view = View::Settings;
+                }
+                if ui
+                    .selectable_label(self.current_view == View::Help, tr("nav-help"))
+                    .clicked()
+                {
+                    self.current_view = View::Help;
+                }
+
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    ui.hyperlink_to("GitHub", "https://github.com/meltingscales/DrakonixAnvil");
+                    ui.separator();
+                    let active = crate::profiles::active();
+                    egui::ComboBox::from_id_salt("profile_picker")
+                        .selected_text(tr_args(
+                            "profile-label",
+                            &[("name", crate::profiles::active_label().into())],
+                        ))
+                        .show_ui(ui, |ui| {
+                            let others = crate::profiles::list().into_iter().map(Some);
+                            for profile in std::iter::once(None).chain(others) {
+                                let label = profile.clone().unwrap_or_else(|| {
+                                    crate::profiles::DEFAULT_PROFILE.to_string()
+                                });
+                                if ui.selectable_label(profile == active, label).clicked()
+                                    && profile != active
+                                {
+                                    profile_choice = Some(profile);
+                                }
+                            }
+                            ui.separator();
+                            if ui.button(tr("profile-new")).clicked() {
+                                self.new_profile_name = Some(String::new());
+                            }
+                        })
+                        .response
+                        .on_hover_text(tr("profile-hint"));
+                });
+            });
+        });
+        if let Some(profile) = profile_choice {
+            self.switch_profile(ctx, profile);
+            return;
+        }
+
+        // Compact status bar at the bottom
+        egui::TopBottomPanel::bottom("status_bar")
+            .exact_height(20.0)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    // Docker status indicator
+                    if self.docker_connected {
+                        ui.colored_label(egui::Color32::GREEN, "●");
+                        ui.small(tr_args(
+                            "status-docker",
+                            &[("version", self.docker_version.as_str().into())],
+                        ));
+                    } else {
+                        ui.colored_label(egui::Color32::RED, "●");
+                        ui.small(tr("status-docker-disconnected"));
+                    }
+
+                    // Space used by servers and backups, and free space left
+                    if let Some(free) = self.disk_usage.available {
+                        ui.separator();
+                        let level = self.disk_usage.space_level();
+                        let free = backup::format_bytes(free);
+                        let text = match level {
+                            SpaceLevel::Ok => egui::RichText::new(tr_args(
+                                "status-storage",
+                                &[
+                                    ("used", backup::format_bytes(self.disk_usage.total()).into()),
+                                    ("free", free.into()),
+                                ],
+                            )),
+                            SpaceLevel::Low | SpaceLevel::Critical => {
+                                let color = if level == SpaceLevel::Critical {
+                                    egui::Color32::RED
+                                } else {
+                                    egui::Color32::YELLOW
+                                };
+                                egui::RichText::new(format!(
+                                    "⚠ {}",
+                                    tr_args("status-storage-low", &[("free", free.into())])
+                                ))
+                                .color(color)
+                            }
+                        };
+                        let mut hover = String::new();
+                        for (name, usage) in self.disk_usage.largest() {
+                            let line = tr_args(
+                                "status-storage-server",
+                                &[
+                                    ("name", name.into()),
+                                    ("data", backup::format_bytes(usage.data_bytes).into()),
+                                    ("backups", backup::format_bytes(usage.backup_bytes).into()),
+                                ],
+                            );
+                            hover.push_str(&format!("{}\n", line));
+                        }
+                        if level != SpaceLevel::Ok {
+                            hover.push_str(&format!("\n{}\n", tr("status-storage-low-hint")));
+                        }
+                        hover.push_str(&tr("status-storage-refresh"));
+                        if ui
+                            .add(egui::Label::new(text.small()).sense(egui::Sense::click()))
+                            .on_hover_text(hover)
+                            .clicked()
+                        {
+                            self.disk_usage_measured = None;
+                        }
+                    }
+
+                    // Dynamic DNS hostname and the IP it was last pointed at
+                    if let (true, Some((result, at))) =
+                        (self.settings.ddns.enabled, &self.ddns_status)
+                    {
+                        ui.separator();
+                        let hostname = self.settings.ddns.hostname.trim();
+                        let time = at.format("%H:%M").to_string();
+                        let (text, hover) = match result {
+                            Ok(ip) => (
+                                egui::RichText::new(format!("🌐 {} → {}", hostname, ip)),
+                                tr_args("status-ddns-updated", &[("time", time.into())]),
+                            ),
+                            Err(e) => (
+                                egui::RichText::new(format!(
+                                    "⚠ {}",
+                                    tr_args("status-ddns-failed", &[("hostname", hostname.into())],)
+                                ))
+                                .color(egui::Color32::YELLOW),
+                                format!("{} ({})", e, time),
+                            ),
+                        };
+                        if ui
+                            .add(egui::Label::new(text.small()).sense(egui::Sense::click()))
+                            .on_hover_text(format!("{}\n{}", hover, tr("status-ddns-refresh")))
+                            .clicked()
+                        {
+                            self.force_dynamic_dns_update();
+                        }
+                    }
+
+                    // Status message
+                    if let Some((msg, time)) = &self.status_message {
+                        if time.elapsed().as_secs() < 5 {
+                            ui.separator();
+                            ui.small(msg);
+                        }
+                    }
+                });
+            });
+
+        // Main content area
+        egui::CentralPanel::default().show(ctx, |ui| {
+            match &self.current_view {
+                View::Dashboard => {
+                    let mut create_clicked = false;
+                    let mut import_clicked = false;
+                    let mut start_name = None;
+                    let mut stop_name = None;
+                    let mut panic_name = None;
+                    let mut unfreeze_name = None;
+                    let mut edit_name = None;
+                    let mut delete_name = None;
+                    let mut logs_name = None;
+                    let mut backup_name = None;
+                    let mut view_backups_name = None;
+                    let mut view_mods_name = None;
+                    let mut console_name = None;
+                    let mut details_name = None;
+                    let mut crashes_name = None;
+                    let mut adopt_name = None;
+                    let mut delete_orphan_name = None;
+                    let mut export_name = None;
+                    let mut export_world_name = None;
+                    let mut export_client_pack_name = None;
+                    let mut open_folder_name = None;
+                    let mut cancel_restore_clicked = false;
+                    let mut toggle_compact = false;
+                    let mut copied_address = None;
+                    let crashed_servers: Vec<String> = self.crash_unread.keys().cloned().collect();
+
+                    DashboardView::show(
+                        ui,
+                        &self.servers,
+                        &self.backup_progress,
+                        &self.restore_progress,
+                        &self.export_progress,
+                        &self.pack_install_progress,
+                        &mut DashboardCallbacks {
+                            on_create_server: &mut || create_clicked = true,
+                            on_start_server: &mut |name: &str| start_name = Some(name.to_string()),
+                            on_stop_server: &mut |name: &str| stop_name = Some(name.to_string()),
+                            on_panic_server: &mut |name: &str| panic_name = Some(name.to_string()),
+                            on_unfreeze_server: &mut |name: &str| unfreeze_name = Some(name.to_string()),
+                            on_edit_server: &mut |name: &str| edit_name = Some(name.to_string()),
+                            on_delete_server: &mut |name: &str| delete_name = Some(name.to_string()),
+                            on_view_logs: &mut |name: &str| logs_name = Some(name.to_string()),
+                            on_backup_server: &mut |name: &str| backup_name = Some(name.to_string()),
+                            on_view_backups: &mut |name: &str| view_backups_name = Some(name.to_string()),
+                            on_view_mods: &mut |name: &str| view_mods_name = Some(name.to_string()),
+                            on_open_console: &mut |name: &str| console_name = Some(name.to_string()),
+                            on_view_details: &mut |name: &str| details_name = Some(name.to_string()),
+                            on_view_crashes: &mut |name: &str| crashes_name = Some(name.to_string()),
+                            on_adopt_server: &mut |name: &str| adopt_name = Some(name.to_string()),
+                            on_delete_orphan: &mut |name: &str| delete_orphan_name = Some(name.to_string()),
+                            on_export_server: &mut |name: &str| export_name = Some(name.to_string()),
+                            on_export_world: &mut |name: &str| export_world_name = Some(name.to_string()),
+                            on_export_client_pack: &mut |name: &str| export_client_pack_name = Some(name.to_string()),
+                            on_open_folder: &mut |name: &str| open_folder_name = Some(name.to_string()),
+                            on_import_server: &mut || import_clicked = true,
+                            on_cancel_restore: &mut || cancel_restore_clicked = true,
+                            on_toggle_compact: &mut || toggle_compact = true,
+                            on_address_copied: &mut |name: &str, address: &str, public: bool| {
+                                copied_address = Some((name.to_string(), address.to_string(), public))
+                            },
+                            orphaned_dirs: &self.orphaned_dirs,
+                            crashed_servers: &crashed_servers,
+                            startup_progress: &self.startup_progress,
+                            log_diagnoses: &self.log_diagnoses,
+                            upload_progress: &self.upload_progress,
+                            filter: &mut self.dashboard_filter,
+                            compact: self.settings.dashboard_compact,
+                            running_since: &self.running_since,
+                            live_status: &self.live_status,
+                            disk_usage: &self.disk_usage.servers,
+                            lan_ip: self.lan_ip.as_deref(),
+                            public_ip: self.public_ip.as_deref(),
+                            checking: &self.preflight_running,
+                        },
+                    );
+
+                    if let Some((name, address, public)) = copied_address {
+                        self.show_status_message(tr_args(
+                            "msg-copied",
+                            &[("address", address.clone().into())],
+                        ));
+                        let running = self
+                            .servers
+                            .iter()
+                            .any(|s| s.config.name == name && s.status == ServerStatus::Running);
+                        if public && running {
+                            self.check_reachability(address);
+                        }
+                    }
+                    if toggle_compact {
+                        self.settings.dashboard_compact = !self.settings.dashboard_compact;
+                        if let Err(e) = save_settings(&self.settings) {
+                            self.show_status_message(tr_args(
+                                "msg-settings-save-failed",
+                                &[("error", e.to_string().into())],
+                            ));
+                        }
+                    }
+
+                    if create_clicked {
+                        self.current_view = View::CreateServer;
+                    }
+                    if import_clicked {
+                        self.import_server_dialog();
+                    }
+                    if cancel_restore_clicked {
+                        if let Some(cancel) = &self.restore_cancel {
+                            cancel.store(true, Ordering::Relaxed);
+                            self.log(tr("log-cancelling-restore"));
+                        }
+                    }
+                    if let Some(name) = start_name {
+                        self.start_server(&name);
+                    }
+                    if let Some(name) = stop_name {
+                        self.stop_server(&name);
+                    }
+                    if let Some(name) = panic_name {
+                        self.panic_freeze(&name);
+                    }
+                    if let Some(name) = unfreeze_name {
+                        self.unfreeze_server(&name);
+                    }
+                    if let Some(name) = edit_name {
+                        self.start_edit_server(&name);
+                    }
+                    if let Some(name) = delete_name {
+                        self.current_view = View::ConfirmDelete(name);
+                    }
+                    if let Some(name) = logs_name {
+                        self.view_container_logs(&name);
+                    }
+                    if let Some(name) = backup_name {
+                        self.create_backup(&name, BackupKind::Full);
+                    }
+                    if let Some(name) = view_backups_name {
+                        self.view_backups(&name);
+                    }
+                    if let Some(name) = view_mods_name {
+                        self.view_mods(&name);
+                    }
+                    if let Some(name) = details_name {
+                        self.open_server_details(&name);
+                    }
+                    if let Some(name) = crashes_name {
+                        self.open_crash_reports(&name);
+                    }
+                    if let Some(name) = console_name {
+                        self.open_console(&name);
+                    }
+                    if let Some(name) = adopt_name {
+                        self.adopt_server(&name);
+                    }
+                    if let Some(name) = delete_orphan_name {
+                        self.confirm_delete_orphan = Some(name);
+                    }
+                    if let Some(name) = export_name {
+                        self.export_server(&name);
+                    }
+                    if let Some(name) = export_world_name {
+                        self.export_world(&name);
+                    }
+                    if let Some(name) = export_client_pack_name {
+                        self.export_client_pack(&name);
+                    }
+                    if let Some(name) = open_folder_name {
+                        let path = get_server_data_path(&name);
+                        if let Err(e) = open::that(&path) {
+                            tracing::error!("Failed to open folder {:?}: {}", path, e);
+                        }
+                    }
+                }
+                View::CreateServer => {
+                    let mut created = None;
+                    let mut imported = None;
+                    let mut template_action = None;
+                    let mut ftb_search_request = None;
+                    let mut cancelled = false;
+                    let mut search_request: Option<CfSearchState> = None;
+                    let mut version_request: Option<u64> = None;
+                    let mut description_request: Option<u64> = None;
+                    let mut mr_search_request: Option<MrSearchState> = None;
+                    let mut mr_version_request: Option<String> = None;
+                    let mut mr_description_request: Option<String> = None;
+                    let mut unified_search_request: Option<UnifiedSearchState> = None;
+
+                    let has_cf_key = self
+                        .settings
+                        .curseforge_api_key
+                        .as_ref()
+                        .is_some_and(|k| !k.is_empty());
+
+                    self.create_view.memory_budget = MemoryBudget {
+                        host_total_mb: self.host_memory.total_mb,
+                        others_mb: memory::allocated_by_active_servers(&self.servers, ""),
+                    };
+                    self.create_view.show(
+                        ui,
+                        &self.templates,
+                        &mut CfCallbacks {
+                            on_search: &mut |state| {
+                                search_request = Some(state);
+                            },
+                            on_fetch_versions: &mut |mod_id| {
+                                version_request = Some(mod_id);
+                            },
+                            on_fetch_description: &mut |mod_id| {
+                                description_request = Some(mod_id);
+                            },
+                            has_api_key: has_cf_key,
+                        },
+                        &mut MrCallbacks {
+                            on_search: &mut |state| {
+                                mr_search_request = Some(state);
+                            },
+                            on_fetch_versions: &mut |project_id| {
+                                mr_version_request = Some(project_id);
+                            },
+                            on_fetch_description: &mut |project_id| {
+                                mr_description_request = Some(project_id);
+                            },
+                        },
+                        &mut UnifiedCallbacks {
+                            on_search: &mut |state| {
+                                unified_search_request = Some(state);
+                            },
+                            has_cf_key,
+                        },
+                        &mut CreateViewCallbacks {
+                            on_create: &mut |name, template, port, memory| {
+                                created = Some((name, template, port, memory));
+                            },
+                            on_import: &mut |name, template, port, memory, path| {
+                                imported = Some((name, template, port, memory, path));
+                            },
+                            on_template: &mut |action| template_action = Some(action),
+                            on_ftb_search: &mut |query| ftb_search_request = Some(query),
+                            on_cancel: &mut || cancelled = true,
+                        },
+                    );
+
+                    if let Some((name, template, port, memory)) = created {
+                        self.create_server(name, &template, port, memory);
+                    }
+                    if let Some((name, template, port, memory, path)) = imported {
+                        self.import_local_server(name, &template, port, memory, path);
+                    }
+                    if let Some(action) = template_action {
+                        self.handle_template_action(action);
+                    }
+                    if cancelled {
+                        self.current_view = View::Dashboard;
+                        self.create_view.reset();
+                    }
+
+                    if let Some(state) = search_request {
+                        self.dispatch_cf_search(state);
+                    }
+                    if let Some(mod_id) = version_request {
+                        self.dispatch_cf_fetch_versions(mod_id);
+                    }
+                    if let Some(mod_id) = description_request {
+                        self.dispatch_cf_fetch_description(mod_id);
+                    }
+                    if let Some(state) = mr_search_request {
+                        self.dispatch_mr_search(state);
+                    }
+                    if let Some(project_id) = mr_version_request {
+                        self.dispatch_mr_fetch_versions(project_id);
+                    }
+                    if let Some(project_id) = mr_description_request {
+                        self.dispatch_mr_fetch_description(project_id);
+                    }
+                    if let Some(state) = unified_search_request {
+                        self.dispatch_unified_search(state);
+                    }
+                    if let Some(query) = ftb_search_request {
+                        self.dispatch_ftb_search(query);
+                    }
+                }
+                View::ServerDetails(name) => {
+                    let name = name.clone();
+                    let mut tab = self.details_tab;
+                    if ServerDetailsView::header(ui, &name, &mut tab) {
+                        self.rcon_session = None;
+                        self.current_view = View::Dashboard;
+                    }
+                    if tab != self.details_tab {
+                        self.select_details_tab(&name, tab);
+                    }
+                    let server = self.servers.iter().find(|s| s.config.name == name);
+                    let running = server.is_some_and(|s| s.status == ServerStatus::Running);
+                    match self.details_tab {
+                        DetailsTab::Overview => {
+                            if let Some(server) = server {
+                                let usage = self.disk_usage.servers.get(&name);
+                                let live = self.live_status.get(&name);
+                                let uptime = self
+                                    .uptime
+                                    .entry(name.clone())
+                                    .or_insert_with(|| UptimeLog::load(&name));
+                                if ServerDetailsView::overview(ui, server, usage, live, uptime, self.crash_reports.len()) {
+                                    self.open_crash_reports(&name);
+                                }
+                            }
+                            if self.metrics_history.as_ref().is_none_or(|(n, _)| *n != name) {
+                                self.load_metrics_history(&name);
+                            }
+                            ui.add_space(12.0);
+                            let points = self.metrics_history.as_ref().and_then(|(_, p)| p.as_deref());
+                            MetricsHistoryView::show(ui, points, &mut self.history_range);
+                            // Keep the hover readout and newest samples current
+                            ctx.request_repaint_after(HISTORY_SAMPLE_INTERVAL);
+                        }
+                        DetailsTab::Players => {
+                            let due = self.online_players_polled
+                                .is_none_or(|t| t.elapsed() >= PLAYER_POLL_INTERVAL);
+                            if running && due {
+                                self.refresh_online_players(&name);
+                            }
+                            ctx.request_repaint_after(PLAYER_POLL_INTERVAL);
+
+                            let action = PlayersView::show(
+                                ui,
+                                running,
+                                self.online_players.as_deref(),
+                                &self.online_ops,
+                                self.online_players_error.as_deref(),
+                                &mut self.player_message,
+                                &self.player_heads,
+                            );
+                            if let Some(action) = action {
+                                self.run_player_action(&name, action);
+                            }
+                            PlayersView::operators(ui, &self.online_ops, &self.player_heads);
+                            let store = self.playtime
+                                .entry(name.clone())
+                                .or_insert_with(|| PlaytimeStore::load(&name));
+                            PlayersView::playtime(ui, store, &self.player_heads);
+                        }
+                        DetailsTab::Whitelist => {
+                            let enforced =
+                                server.is_some_and(|s| s.config.server_properties.white_list);
+                            let action = WhitelistView::show(
+                                ui,
+                                self.whitelist_entries.as_deref(),
+                                enforced,
+                                running,
+                                self.whitelist_busy,
+                                &mut self.whitelist_input,
+                                &self.player_heads,
+                            );
+                            if let Some(action) = action {
+                                self.run_whitelist_action(&name, action);
+                            }
+                        }
+                        DetailsTab::Mods => {
+                            if self.mod_graph.is_none() && !self.mods_scanning {
+                                self.scan_mods(&name);
+                            }
+                            let action = ModsView::manager(
+                                ui,
+                                self.mod_graph.as_ref(),
+                                self.mods_scanning,
+                                running,
+                            );
+                            if let Some(action) = action {
+                                self.run_mods_action(&name, action);
+                            }
+                        }
+                        DetailsTab::Files => {
+                            if let Some(action) = self.files_view.show(ui, running) {
+                                self.run_files_action(&name, action);
+                            }
+                        }
+                        DetailsTab::Console => self.show_console_tab(ui, ctx, &name),
+                        DetailsTab::Logs => self.show_logs_tab(ui, ctx, &name),
+                        DetailsTab::Backups => self.show_backups_tab(ui, &name),
+                        DetailsTab::Schedules => {
+                            if let Some(result) = self.edit_view.show_schedules(ui) {
+                                self.save_server_schedules(&name, result);
+                            }
+                        }
+                        DetailsTab::Settings => self.show_settings_tab(ui, &name),
+                    }
                 }
-            }
-        });
-    }
+                View::ConfirmDelete(name) => {
+                    let name = name.clone();
 
-    /// Check if any servers are in a transient state (need UI refresh)
-    fn has_active_tasks(&self) -> bool {
-        self.backup_progress.is_some()
-            || self.restore_progress.is_some()
-            || self.export_progress.is_some()
-            || self.create_view.cf.state.loading_search
-            || self.create_view.cf.state.loading_versions
-            || self.create_view.cf.state.loading_description
-            || self.edit_view.cf.state.loading_search
-            || self.edit_view.cf.state.loading_versions
-            || self.edit_view.cf.state.loading_description
-            || self.create_view.mr.state.loading_search
-            || self.create_view.mr.state.loading_versions
-            || self.create_view.mr.state.loading_description
-            || self.edit_view.mr.state.loading_search
-            || self.edit_view.mr.state.loading_versions
-            || self.edit_view.mr.state.loading_description
-            || self.servers.iter().any(|s| {
-                matches!(
-                    s.status,
-                    ServerStatus::Pulling
-                        | ServerStatus::Starting
-                        | ServerStatus::Initializing
-                        | ServerStatus::Stopping
-                )
-            })
-    }
+                    // Get server details for display (clone to avoid borrow issues)
+                    let server_info = self.servers.iter().find(|s| s.config.name == name);
+                    let container_name =
+                        crate::config::get_container_name(&self.settings.instance_id, &name);
+                    let modpack_name = server_info
+                        .map(|s| s.config.modpack.name.clone())
+                        .unwrap_or_else(|| tr("confirm-unknown"));
+                    let port = server_info
+                        .map(|s| s.config.port)
+                        .unwrap_or(0);
+                    let has_container = server_info
+                        .and_then(|s| s.container_id.as_ref())
+                        .is_some();
 
-    /// Get list of running server names
-    fn running_servers(&self) -> Vec<&str> {
-        self.servers
-            .iter()
-            .filter(|s| matches!(s.status, ServerStatus::Running | ServerStatus::Initializing))
-            .map(|s| s.config.name.as_str())
-            .collect()
-    }
+                    ui.vertical_centered(|ui| {
+                        ui.add_space(50.0);
+                        ui.heading(tr("confirm-delete-server"));
+                        ui.add_space(20.0);
 
-    /// Poll the Minecraft server until it accepts connections
-    async fn poll_mc_server_ready(
-        tx: mpsc::Sender<TaskMessage>,
-        name: String,
-        port: u16,
-        container_id: String,
-        docker: Arc<DockerManager>,
-    ) {
-        let client = McClient::new().with_timeout(Duration::from_secs(3));
-        let address = format!("127.0.0.1:{}", port);
-        let max_attempts = 120; // 10 minutes at 5 second intervals
-        let poll_interval = Duration::from_secs(5);
+                        // Resource indicator box
+                        egui::Frame::none()
+                            .fill(egui::Color32::from_rgb(60, 30, 30))
+                            .rounding(8.0)
+                            .inner_margin(16.0)
+                            .show(ui, |ui| {
+                                ui.horizontal(|ui| {
+                                    ui.colored_label(egui::Color32::RED, "🗑");
+                                    ui.add_space(8.0);
+                                    ui.vertical(|ui| {
+                                        ui.strong(tr("confirm-docker-container"));
+                                        ui.monospace(&container_name);
+                                        ui.small(tr_args(
+                                            "confirm-server",
+                                            &[("name", name.as_str().into())],
+                                        ));
+                                        ui.small(tr_args(
+                                            "confirm-modpack",
+                                            &[("name", modpack_name.as_str().into())],
+                                        ));
+                                        ui.small(tr_args(
+                                            "confirm-port",
+                                            &[("port", port.to_string().into())],
+                                        ));
+                                        if has_container {
+                                            ui.colored_label(egui::Color32::YELLOW, tr("confirm-container-removed"));
+                                        } else {
+                                            ui.colored_label(egui::Color32::GRAY, tr("confirm-no-container"));
+                                        }
+                                    });
+                                });
+                            });
 
-        for attempt in 1..=max_attempts {
-            // First check if container is still running
-            match docker.is_container_running(&container_id).await {
-                Ok(true) => {} // Container still running, continue
-                Ok(false) => {
-                    // Container stopped/crashed
-                    tx.send(TaskMessage::Log(format!(
-                        "Container for '{}' has stopped. Check container logs for errors.",
-                        name
-                    )))
-                    .ok();
-                    tx.send(TaskMessage::ServerStatus {
-                        name,
-                        status: ServerStatus::Error("Container exited unexpectedly".to_string()),
-                        container_id: Some(container_id),
-                    })
-                    .ok();
-                    return;
-                }
-                Err(e) => {
-                    tx.send(TaskMessage::Log(format!(
-                        "Failed to check container status: {}",
-                        e
-                    )))
-                    .ok();
-                    // Continue trying - might be transient
+                        ui.add_space(20.0);
+                        ui.colored_label(egui::Color32::GREEN, tr("confirm-delete-server-data-kept"));
+                        ui.small(tr("confirm-delete-server-recreate"));
+                        ui.add_space(30.0);
+                        ui.horizontal(|ui| {
+                            ui.add_space(ui.available_width() / 2.0 - 80.0);
+                            if ui.button(tr("button-cancel")).clicked() {
+                                self.current_view = View::Dashboard;
+                            }
+                            ui.add_space(20.0);
+                            if ui.add(egui::Button::new(tr("button-delete")).fill(egui::Color32::from_rgb(150, 40, 40))).clicked() {
+                                self.delete_server(&name);
+                            }
+                        });
+                    });
                 }
-            }
-
-            match client.ping(&address, ServerEdition::Java).await {
-                Ok(status) if status.online => {
-                    // Log basic connection info
-                    tx.send(TaskMessage::Log(format!(
-                        "Server '{}' is now accepting connections! (latency: {:.0}ms)",
-                        name, status.latency
-                    )))
-                    .ok();
+                View::ConfirmEula(name) => {
+                    let name = name.clone();
+                    ui.vertical_centered(|ui| {
+                        ui.add_space(50.0);
+                        ui.heading(tr("settings-eula"));
+                        ui.add_space(20.0);
 
-                    // Extract and log rich Java status info
-                    if let ServerData::Java(java) = &status.data {
-                        // Version info
-                        tx.send(TaskMessage::Log(format!(
-                            "  Version: {} (protocol {})",
-                            java.version.name, java.version.protocol
-                        )))
-                        .ok();
+                        egui::Frame::none()
+                            .fill(ui.style().visuals.extreme_bg_color)
+                            .rounding(8.0)
+                            .inner_margin(16.0)
+                            .show(ui, |ui| {
+                                ui.label(tr("eula-required"));
+                                ui.horizontal(|ui| {
+                                    ui.label(tr("eula-read"));
+                                    ui.hyperlink("https://aka.ms/MinecraftEULA");
+                                });
+                                ui.add_space(5.0);
+                                ui.label(tr("eula-agree-explain"));
+                                ui.small(tr("eula-asked-once"));
+                            });
 
-                        // MOTD/Description
-                        if !java.description.is_empty() {
-                            tx.send(TaskMessage::Log(format!(
-                                "  MOTD: {}",
-                                java.description.lines().next().unwrap_or(&java.description)
-                            )))
-                            .ok();
+                        ui.add_space(30.0);
+                        ui.horizontal(|ui| {
+                            ui.add_space(ui.available_width() / 2.0 - 80.0);
+                            if ui.button(tr("button-cancel")).clicked() {
+                                self.current_view = View::Dashboard;
+                            }
+                            ui.add_space(20.0);
+                            if ui.add(egui::Button::new(tr("eula-agree")).fill(egui::Color32::from_rgb(40, 100, 40))).clicked() {
+                                self.accept_eula();
+                                self.current_view = View::Dashboard;
+                                self.start_server(&name);
+                            }
+                        });
+                    });
+                }
+                View::BackupContents(name, path) => {
+                    let name = name.clone();
+                    let path = path.clone();
+                    let filename = path.file_name()
+                        .map(|s| s.to_string_lossy().to_string())
+                        .unwrap_or_else(|| "backup".to_string());
+                    let busy = self.restore_progress.is_some();
+                    let action = match self.backup_contents.as_mut() {
+                        Some(contents) => contents.show(ui, &name, &filename, busy),
+                        None => Some(BackupContentsAction::Back),
+                    };
+                    match action {
+                        Some(BackupContentsAction::Back) => {
+                            self.backup_contents = None;
+                            self.view_backups(&name);
+                        }
+                        Some(BackupContentsAction::RestoreSelected(paths)) => {
+                            self.restore_selected(&name, &path, paths);
                         }
+                        None => {}
+                    }
+                }
+                View::BackupPlayers(name, path) => {
+                    let name = name.clone();
+                    let path = path.clone();
+                    let filename = path.file_name()
+                        .map(|s| s.to_string_lossy().to_string())
+                        .unwrap_or_else(|| "backup".to_string());
 
-                        // Player info
-                        tx.send(TaskMessage::Log(format!(
-                            "  Players: {}/{} online",
-                            java.players.online, java.players.max
-                        )))
-                        .ok();
+                    ui.horizontal(|ui| {
+                        ui.heading(tr_args(
+                            "backup-players-title",
+                            &[("file", filename.as_str().into())],
+                        ));
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            if ui.button(tr("button-back")).clicked() {
+                                self.view_backups(&name);
+                            }
+                        });
+                    });
+                    ui.label(tr("backup-players-hint"));
+                    ui.small(tr("backup-players-stop-first"));
+                    ui.separator();
 
-                        // Server software if available
-                        if let Some(software) = &java.software {
-                            tx.send(TaskMessage::Log(format!("  Software: {}", software)))
-                                .ok();
+                    if self.backup_players.is_empty() {
+                        ui.vertical_centered(|ui| {
+                            ui.add_space(50.0);
+                            ui.label(tr("backup-players-none"));
+                        });
+                    } else {
+                        let mut restore = None;
+                        egui::ScrollArea::vertical().show(ui, |ui| {
+                            egui::Grid::new("backup_players_grid")
+                                .num_columns(3)
+                                .striped(true)
+                                .spacing([16.0, 6.0])
+                                .show(ui, |ui| {
+                                    for player in &self.backup_players {
+                                        ui.strong(player.display_name());
+                                        ui.monospace(&player.uuid);
+                                        if ui.button(tr("backups-restore")).clicked() {
+                                            restore = Some(player.clone());
+                                        }
+                                        ui.end_row();
+                                    }
+                                });
+                        });
+                        if let Some(player) = restore {
+                            self.restore_player(&name, &path, &player);
                         }
+                    }
+                }
+                View::ConfirmRestore(name, path) => {
+                    let name = name.clone();
+                    let path = path.clone();
+                    let filename = path.file_name()
+                        .map(|s| s.to_string_lossy().to_string())
+                        .unwrap_or_else(|| "backup".to_string());
 
-                        // Mod count if modded
-                        if let Some(mods) = &java.mods {
-                            if !mods.is_empty() {
-                                tx.send(TaskMessage::Log(format!("  Mods: {} loaded", mods.len())))
-                                    .ok();
+                    ui.vertical_centered(|ui| {
+                        ui.add_space(50.0);
+                        ui.heading(tr("confirm-restore"));
+                        ui.add_space(20.0);
+                        ui.label(tr_args(
+                            "confirm-restore-question",
+                            &[("file", filename.as_str().into()), ("name", name.as_str().into())],
+                        ));
+                        ui.add_space(10.0);
+                        ui.colored_label(egui::Color32::RED, tr("confirm-restore-warning"));
+                        ui.label(tr("confirm-restore-stop-first"));
+                        ui.add_space(30.0);
+                        ui.horizontal(|ui| {
+                            ui.add_space(ui.available_width() / 2.0 - 80.0);
+                            if ui.button(tr("button-cancel")).clicked() {
+                                self.view_backups(&name);
                             }
+                            ui.add_space(20.0);
+                            if ui.add(egui::Button::new(tr("backups-restore")).fill(egui::Color32::from_rgb(150, 100, 40))).clicked() {
+                                self.restore_backup(&name, &path);
+                            }
+                        });
+                    });
+                }
+                View::RestoreAsNew(name, path) => {
+                    let name = name.clone();
+                    let path = path.clone();
+                    let filename = path.file_name()
+                        .map(|s| s.to_string_lossy().to_string())
+                        .unwrap_or_else(|| "backup".to_string());
+
+                    ui.vertical_centered(|ui| {
+                        ui.add_space(50.0);
+                        ui.heading(tr("restore-new-title"));
+                        ui.add_space(20.0);
+                        ui.label(tr_args(
+                            "restore-new-explain",
+                            &[("name", name.as_str().into()), ("file", filename.as_str().into())],
+                        ));
+                        ui.label(tr("restore-new-untouched"));
+                        ui.add_space(15.0);
+
+                        egui::Grid::new("restore_new_grid").num_columns(2).spacing([12.0, 8.0]).show(ui, |ui| {
+                            ui.label(tr("restore-new-name"));
+                            ui.add(egui::TextEdit::singleline(&mut self.restore_new_name).desired_width(220.0));
+                            ui.end_row();
+                            ui.label(tr("create-port"));
+                            ui.add(egui::DragValue::new(&mut self.restore_new_port).range(1024..=65525));
+                            ui.end_row();
+                        });
+                        let rcon_port = self.restore_new_port.saturating_add(10).to_string();
+                        ui.small(tr_args("restore-new-rcon", &[("port", rcon_port.into())]));
+                        ui.small(tr("restore-new-not-copied"));
+
+                        let problem = self
+                            .restore_new_name_problem(&self.restore_new_name)
+                            .or_else(|| self.restore_new_port_problem(self.restore_new_port));
+                        if let Some(problem) = &problem {
+                            ui.add_space(8.0);
+                            ui.colored_label(egui::Color32::YELLOW, problem);
                         }
 
-                        // Plugin count if available
-                        if let Some(plugins) = &java.plugins {
-                            if !plugins.is_empty() {
-                                tx.send(TaskMessage::Log(format!(
-                                    "  Plugins: {} loaded",
-                                    plugins.len()
-                                )))
-                                .ok();
+                        ui.add_space(30.0);
+                        ui.horizontal(|ui| {
+                            ui.add_space(ui.available_width() / 2.0 - 80.0);
+                            if ui.button(tr("button-cancel")).clicked() {
+                                self.view_backups(&name);
                             }
-                        }
+                            ui.add_space(20.0);
+                            let button = egui::Button::new(tr("backups-restore")).fill(egui::Color32::from_rgb(40, 120, 40));
+                            if ui.add_enabled(problem.is_none(), button).clicked() {
+                                self.restore_as_new(&name, &path);
+                            }
+                        });
+                    });
+                }
+                View::ConfirmRestoreGroup(name, path) => {
+                    let name = name.clone();
+                    let filename = path.file_name()
+                        .map(|s| s.to_string_lossy().to_string())
+                        .unwrap_or_default();
+                    let group = self.servers.iter()
+                        .find(|s| s.config.name == name)
+                        .and_then(|s| s.config.backup_group.clone())
+                        .unwrap_or_default();
+                    let members = self.group_members(&group);
+                    let set = backup::group_backup_set(&members, &filename);
 
-                        // Map name if available
-                        if let Some(map) = &java.map {
-                            tx.send(TaskMessage::Log(format!("  Map: {}", map))).ok();
+                    ui.vertical_centered(|ui| {
+                        ui.add_space(50.0);
+                        ui.heading(tr("confirm-restore-group"));
+                        ui.add_space(20.0);
+                        ui.label(tr_args(
+                            "confirm-restore-group-question",
+                            &[("file", filename.as_str().into()), ("group", group.as_str().into())],
+                        ));
+                        ui.add_space(10.0);
+                        for member in &members {
+                            if set.iter().any(|(m, _)| m == member) {
+                                ui.label(format!("• {}", member));
+                            } else {
+                                let text = tr_args(
+                                    "confirm-restore-group-missing",
+                                    &[("name", member.as_str().into())],
+                                );
+                                ui.colored_label(egui::Color32::GRAY, text);
+                            }
                         }
-                    }
-
-                    tx.send(TaskMessage::ServerStatus {
-                        name,
-                        status: ServerStatus::Running,
-                        container_id: Some(container_id),
-                    })
-                    .ok();
-                    return;
-                }
-                Ok(_) => {
-                    // Server responded but says offline - keep trying
-                    if attempt % 6 == 0 {
-                        // Log every 30 seconds
-                        tx.send(TaskMessage::Log(format!(
-                            "Server '{}' not ready yet (attempt {}/{})",
-                            name, attempt, max_attempts
-                        )))
-                        .ok();
-                    }
-                }
-                Err(_) => {
-                    // Connection failed - server not ready
-                    if attempt % 6 == 0 {
-                        // Log every 30 seconds
-                        tx.send(TaskMessage::Log(format!(
-                            "Waiting for '{}' to initialize (attempt {}/{})",
-                            name, attempt, max_attempts
-                        )))
-                        .ok();
-                    }
+                        ui.add_space(10.0);
+                        ui.colored_label(egui::Color32::RED, tr("confirm-restore-group-warning"));
+                        ui.label(tr("confirm-restore-group-stop-first"));
+                        ui.add_space(30.0);
+                        ui.horizontal(|ui| {
+                            ui.add_space(ui.available_width() / 2.0 - 80.0);
+                            if ui.button(tr("button-cancel")).clicked() {
+                                self.view_backups(&name);
+                            }
+                            ui.add_space(20.0);
+                            if ui.add(egui::Button::new(tr("confirm-restore-all")).fill(egui::Color32::from_rgb(150, 100, 40))).clicked() {
+                                self.restore_group(&group, &filename);
+                            }
+                        });
+                    });
                 }
-            }
-
-            tokio::time::sleep(poll_interval).await;
-        }
+                View::ConfirmDeleteBackup(name, path) => {
+                    let name = name.clone();
+                    let path = path.clone();
+                    let filename = path.file_name()
+                        .map(|s| s.to_string_lossy().to_string())
+                        .unwrap_or_else(|| "backup".to_string());
 
-        // Timed out but don't error - modpacks can take a very long time
-        tx.send(TaskMessage::Log(format!(
-            "Server '{}' still initializing after 10 minutes. Check container logs for progress.",
-            name
-        )))
-        .ok();
-        // Keep status as Initializing - user can check logs
-    }
-}
+                    // Get file size for display
+                    let size_str = std::fs::metadata(&path)
+                        .map(|m| backup::format_bytes(m.len()))
+                        .unwrap_or_else(|_| tr("confirm-unknown-size"));
 
-impl eframe::App for DrakonixApp {
-    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
-        // Process any pending messages from background tasks
-        self.process_task_messages();
+                    ui.vertical_centered(|ui| {
+                        ui.add_space(50.0);
+                        ui.heading(tr("confirm-delete-backup"));
+                        ui.add_space(20.0);
 
-        // Handle close request - warn if servers are running
-        if ctx.input(|i| i.viewport().close_requested()) {
-            let running = self.running_servers();
-            if running.is_empty() {
-                // No running servers, allow close
-            } else {
-                // Servers running, show confirmation
-                ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
-                self.show_close_confirmation = true;
-            }
-        }
+                        // Resource indicator box
+                        egui::Frame::none()
+                            .fill(egui::Color32::from_rgb(60, 30, 30))
+                            .rounding(8.0)
+                            .inner_margin(16.0)
+                            .show(ui, |ui| {
+                                ui.horizontal(|ui| {
+                                    ui.colored_label(egui::Color32::RED, "🗑");
+                                    ui.add_space(8.0);
+                                    ui.vertical(|ui| {
+                                        ui.strong(tr("confirm-backup-file"));
+                                        ui.monospace(&filename);
+                                        ui.small(tr_args(
+                                            "backups-size",
+                                            &[("size", size_str.as_str().into())],
+                                        ));
+                                        ui.small(tr_args(
+                                            "confirm-server",
+                                            &[("name", name.as_str().into())],
+                                        ));
+                                    });
+                                });
+                            });
 
-        // Show close confirmation dialog
-        if self.show_close_confirmation {
-            let running = self.running_servers();
-            let running_names: Vec<String> = running.iter().map(|s| s.to_string()).collect();
+                        ui.add_space(20.0);
+                        ui.label(tr("confirm-cannot-undo"));
+                        ui.add_space(30.0);
+                        ui.horizontal(|ui| {
+                            ui.add_space(ui.available_width() / 2.0 - 80.0);
+                            if ui.button(tr("button-cancel")).clicked() {
+                                self.view_backups(&name);
+                            }
+                            ui.add_space(20.0);
+                            if ui.add(egui::Button::new(tr("button-delete")).fill(egui::Color32::from_rgb(150, 40, 40))).clicked() {
+                                self.delete_backup(&name, &path);
+                            }
+                        });
+                    });
+                }
+                View::ConfirmRemoveContainer(name) => {
+                    let name = name.clone();
+                    let container_name = get_container_name(&self.settings.instance_id, &name);
 
-            egui::Window::new("Servers Still Running")
-                .collapsible(false)
-                .resizable(false)
-                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
-                .show(ctx, |ui| {
                     ui.vertical_centered(|ui| {
-                        ui.add_space(10.0);
-                        ui.colored_label(
-                            egui::Color32::YELLOW,
-                            format!("You have {} server(s) still running:", running_names.len()),
-                        );
-                        ui.add_space(5.0);
+                        ui.add_space(50.0);
+                        ui.heading(tr("confirm-container-exists"));
+                        ui.add_space(20.0);
 
-                        for name in &running_names {
-                            ui.label(format!("  • {}", name));
-                        }
+                        // Info box (blue - this is safe)
+                        egui::Frame::none()
+                            .fill(egui::Color32::from_rgb(30, 40, 60))
+                            .rounding(8.0)
+                            .inner_margin(16.0)
+                            .show(ui, |ui| {
+                                ui.horizontal(|ui| {
+                                    ui.colored_label(egui::Color32::from_rgb(100, 150, 255), "ℹ");
+                                    ui.add_space(8.0);
+                                    ui.vertical(|ui| {
+                                        ui.strong(tr("confirm-old-container"));
+                                        ui.monospace(&container_name);
+                                        ui.small(tr_args(
+                                            "confirm-server",
+                                            &[("name", name.as_str().into())],
+                                        ));
+                                        ui.add_space(4.0);
+                                        ui.label(tr("confirm-container-recreate"));
+                                    });
+                                });
+                            });
 
-                        ui.add_space(15.0);
-                        ui.label("Closing will leave them running in Docker.");
-                        ui.small("You can stop them later with 'docker stop'");
-                        ui.add_space(15.0);
+                        ui.add_space(12.0);
+
+                        // Green reassurance box
+                        egui::Frame::none()
+                            .fill(egui::Color32::from_rgb(30, 50, 30))
+                            .rounding(8.0)
+                            .inner_margin(16.0)
+                            .show(ui, |ui| {
+                                ui.horizontal(|ui| {
+                                    ui.colored_label(egui::Color32::GREEN, "✓");
+                                    ui.add_space(8.0);
+                                    ui.vertical(|ui| {
+                                        ui.label(tr("confirm-container-safe"));
+                                    });
+                                });
+                            });
 
+                        ui.add_space(30.0);
                         ui.horizontal(|ui| {
-                            if ui.button("Cancel").clicked() {
-                                self.show_close_confirmation = false;
+                            ui.add_space(ui.available_width() / 2.0 - 100.0);
+                            if ui.button(tr("button-cancel")).clicked() {
+                                self.current_view = View::Dashboard;
                             }
                             ui.add_space(20.0);
-                            if ui
-                                .add(
-                                    egui::Button::new("Close Anyway")
-                                        .fill(egui::Color32::from_rgb(150, 100, 40)),
-                                )
-                                .clicked()
-                            {
-                                ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+                            if ui.add(egui::Button::new(tr("confirm-remove-restart")).fill(egui::Color32::from_rgb(40, 120, 40))).clicked() {
+                                self.remove_container_and_start(&name);
                             }
                         });
-                        ui.add_space(10.0);
                     });
-                });
-        }
+                }
+                View::ConfirmImport(path) => {
+                    let path = path.clone();
+
+                    // Try to read the config for preview
+                    let config_result = backup::read_export_config(&path);
 
-        // Show orphan deletion confirmation dialog
-        if let Some(orphan_name) = self.confirm_delete_orphan.clone() {
-            egui::Window::new("Delete Server Directory")
-                .collapsible(false)
-                .resizable(false)
-                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
-                .show(ctx, |ui| {
                     ui.vertical_centered(|ui| {
-                        ui.add_space(10.0);
-                        ui.colored_label(
-                            egui::Color32::RED,
-                            "This will permanently delete:",
-                        );
-                        ui.add_space(5.0);
-                        ui.label(format!("  • servers/{}/", orphan_name));
-                        ui.label(format!("  • backups/{}/  (if any)", orphan_name));
-                        ui.add_space(10.0);
-                        ui.label("This cannot be undone.");
-                        ui.add_space(15.0);
+                        ui.add_space(50.0);
+                        ui.heading(tr("import-title"));
+                        ui.add_space(20.0);
+
+                        match &config_result {
+                            Ok(config) => {
+                                // Preview box
+                                egui::Frame::none()
+                                    .fill(egui::Color32::from_rgb(30, 40, 60))
+                                    .rounding(8.0)
+                                    .inner_margin(16.0)
+                                    .show(ui, |ui| {
+                                        ui.horizontal(|ui| {
+                                            ui.colored_label(
+                                                egui::Color32::from_rgb(100, 150, 255),
+                                                "ℹ",
+                                            );
+                                            ui.add_space(8.0);
+                                            ui.vertical(|ui| {
+                                                ui.strong(tr("import-preview"));
+                                                ui.add_space(4.0);
+                                                ui.label(tr_args(
+                                                    "import-name",
+                                                    &[("name", config.name.as_str().into())],
+                                                ));
+                                                ui.label(tr_args(
+                                                    "confirm-modpack",
+                                                    &[("name", config.modpack.name.as_str().into())],
+                                                ));
+                                                ui.label(tr_args(
+                                                    "import-version",
+                                                    &[("version", config.modpack.version.as_str().into())],
+                                                ));
+                                                ui.label(tr_args(
+                                                    "import-minecraft",
+                                                    &[(
+                                                        "version",
+                                                        config.modpack.minecraft_version.as_str().into(),
+                                                    )],
+                                                ));
+                                                ui.label(tr_args(
+                                                    "import-loader",
+                                                    &[("loader", format!("{:?}", config.modpack.loader).into())],
+                                                ));
+                                                ui.label(tr_args(
+                                                    "confirm-port",
+                                                    &[("port", config.port.to_string().into())],
+                                                ));
+                                                ui.label(tr_args(
+                                                    "import-memory",
+                                                    &[("mb", config.memory_mb.to_string().into())],
+                                                ));
+                                            });
+                                        });
+                                    });
+
+                                // Check for name conflict
+                                let name_conflict = self
+                                    .servers
+                                    .iter()
+                                    .any(|s| s.config.name == config.name);
+                                if name_conflict {
+                                    ui.add_space(12.0);
+                                    ui.colored_label(
+                                        egui::Color32::YELLOW,
+                                        tr_args(
+                                            "import-name-conflict",
+                                            &[("name", config.name.as_str().into())],
+                                        ),
+                                    );
+                                }
+                            }
+                            Err(e) => {
+                                ui.colored_label(
+                                    egui::Color32::RED,
+                                    tr_args(
+                                        "import-read-failed",
+                                        &[("error", e.to_string().into())],
+                                    ),
+                                );
+                            }
+                        }
 
+                        ui.add_space(30.0);
                         ui.horizontal(|ui| {
-                            if ui.button("Cancel").clicked() {
-                                self.confirm_delete_orphan = None;
+                            ui.add_space(ui.available_width() / 2.0 - 80.0);
+                            if ui.button(tr("button-cancel")).clicked() {
+                                self.current_view = View::Dashboard;
                             }
                             ui.add_space(20.0);
+                            let can_import = config_result.is_ok();
                             if ui
-                                .add(
-                                    egui::Button::new("Delete")
-                                        .fill(egui::Color32::from_rgb(180, 50, 50)),
+                                .add_enabled(
+                                    can_import,
+                                    egui::Button::new(tr("import-button"))
+                                        .fill(egui::Color32::from_rgb(40, 120, 40)),
                                 )
                                 .clicked()
                             {
-                                self.delete_orphan(&orphan_name);
-                                self.confirm_delete_orphan = None;
+                                self.confirm_import(&path);
                             }
                         });
-                        ui.add_space(10.0);
                     });
-                });
-        }
-
-        // Request repaint if there are active background tasks
-        if self.has_active_tasks() {
-            ctx.request_repaint_after(std::time::Duration::from_millis(100));
-        }
-
-        // Top panel with app title and navigation
-        egui::TopBottomPanel::top("top_panel").show(ctx, |ui| {
-            ui.horizontal(|ui| {
-                ui.strong("DrakonixAnvil");
-                ui.separator();
-
-                if ui
-                    .selectable_label(self.current_view == View::Dashboard, "Servers")
-                    .clicked()
-                {
-                    self.current_view = View::Dashboard;
-                }
-                if ui
-                    .selectable_label(self.current_view == View::Logs, "Logs")
-                    .clicked()
-                {
-                    self.current_view = View::Logs;
-                }
-                if ui
-                    .selectable_label(self.current_view == View::DockerLogs, "Docker Logs")
-                    .clicked()
-                {
-                    self.load_all_docker_logs();
-                }
-                if ui
-                    .selectable_label(self.current_view == View::Settings, "Settings")
-                    .clicked()
-                {
-                    self.current_view = View::Settings;
-                }
-                if ui
-                    .selectable_label(self.current_view == View::Help, "Help")
-                    .clicked()
-                {
-                    self.current_view = View::Help;
                 }
+                View::ConfirmMoveData => {
+                    let Some(plan) = self.data_move_plan.clone() else {
+                        self.current_view = View::Settings;
+                        return;
+                    };
+                    ui.vertical_centered(|ui| {
+                        ui.add_space(50.0);
+                        ui.heading(tr("data-move-title"));
+                        ui.add_space(20.0);
 
-                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                    ui.hyperlink_to("GitHub", "https://github.com/meltingscales/DrakonixAnvil");
-                });
-            });
-        });
-
-        // Compact status bar at the bottom
-        egui::TopBottomPanel::bottom("status_bar")
-            .exact_height(20.0)
-            .show(ctx, |ui| {
-                ui.horizontal(|ui| {
-                    // Docker status indicator
-                    if self.docker_connected {
-                        ui.colored_label(egui::Color32::GREEN, "●");
-                        ui.small(format!("Docker v{}", self.docker_version));
-                    } else {
-                        ui.colored_label(egui::Color32::RED, "●");
-                        ui.small("Docker disconnected");
-                    }
+                        egui::Frame::none()
+                            .fill(egui::Color32::from_rgb(30, 40, 60))
+                            .rounding(8.0)
+                            .inner_margin(16.0)
+                            .show(ui, |ui| {
+                                egui::Grid::new("data_move_grid").num_columns(2).show(ui, |ui| {
+                                    ui.label(tr("data-move-from"));
+                                    ui.monospace(plan.from.display().to_string());
+                                    ui.end_row();
+                                    ui.label(tr("data-move-to"));
+                                    ui.monospace(plan.to.display().to_string());
+                                    ui.end_row();
+                                    ui.label(tr("data-move-moves"));
+                                    ui.label(if plan.entries.is_empty() {
+                                        tr("data-move-nothing")
+                                    } else {
+                                        plan.entries.join(", ")
+                                    });
+                                    ui.end_row();
+                                    ui.label(tr("data-move-size"));
+                                    ui.label(backup::format_bytes(plan.size));
+                                    ui.end_row();
+                                    ui.label(tr("data-move-free"));
+                                    ui.label(
+                                        plan.available
+                                            .map_or_else(|| tr("data-move-unknown"), backup::format_bytes),
+                                    );
+                                    ui.end_row();
+                                });
+                            });
 
-                    // Status message
-                    if let Some((msg, time)) = &self.status_message {
-                        if time.elapsed().as_secs() < 5 {
-                            ui.separator();
-                            ui.small(msg);
+                        ui.add_space(12.0);
+                        let profile = crate::config::profile_root().display().to_string();
+                        ui.label(tr_args("data-move-settings-stay", &[("path", profile.into())]));
+                        ui.label(tr("data-move-how"));
+                        if !plan.fits() {
+                            ui.add_space(8.0);
+                            ui.colored_label(
+                                egui::Color32::YELLOW,
+                                tr("data-move-no-space"),
+                            );
                         }
-                    }
-                });
-            });
-
-        // Main content area
-        egui::CentralPanel::default().show(ctx, |ui| {
-            match &self.current_view {
-                View::Dashboard => {
-                    let mut create_clicked = false;
-                    let mut import_clicked = false;
-                    let mut start_name = None;
-                    let mut stop_name = None;
-                    let mut edit_name = None;
-                    let mut delete_name = None;
-                    let mut logs_name = None;
-                    let mut backup_name = None;
-                    let mut view_backups_name = None;
-                    let mut console_name = None;
-                    let mut adopt_name = None;
-                    let mut delete_orphan_name = None;
-                    let mut export_name = None;
-                    let mut open_folder_name = None;
-
-                    DashboardView::show(
-                        ui,
-                        &self.servers,
-                        &self.backup_progress,
-                        &self.restore_progress,
-                        &self.export_progress,
-                        &mut DashboardCallbacks {
-                            on_create_server: &mut || create_clicked = true,
-                            on_start_server: &mut |name: &str| start_name = Some(name.to_string()),
-                            on_stop_server: &mut |name: &str| stop_name = Some(name.to_string()),
-                            on_edit_server: &mut |name: &str| edit_name = Some(name.to_string()),
-                            on_delete_server: &mut |name: &str| delete_name = Some(name.to_string()),
-                            on_view_logs: &mut |name: &str| logs_name = Some(name.to_string()),
-                            on_backup_server: &mut |name: &str| backup_name = Some(name.to_string()),
-                            on_view_backups: &mut |name: &str| view_backups_name = Some(name.to_string()),
-                            on_open_console: &mut |name: &str| console_name = Some(name.to_string()),
-                            on_adopt_server: &mut |name: &str| adopt_name = Some(name.to_string()),
-                            on_delete_orphan: &mut |name: &str| delete_orphan_name = Some(name.to_string()),
-                            on_export_server: &mut |name: &str| export_name = Some(name.to_string()),
-                            on_open_folder: &mut |name: &str| open_folder_name = Some(name.to_string()),
-                            on_import_server: &mut || import_clicked = true,
-                            orphaned_dirs: &self.orphaned_dirs,
-                        },
-                    );
 
-                    if create_clicked {
-                        self.current_view = View::CreateServer;
-                    }
-                    if import_clicked {
-                        self.import_server_dialog();
-                    }
-                    if let Some(name) = start_name {
-                        self.start_server(&name);
-                    }
-                    if let Some(name) = stop_name {
-                        self.stop_server(&name);
-                    }
-                    if let Some(name) = edit_name {
-                        self.start_edit_server(&name);
-                    }
-                    if let Some(name) = delete_name {
-                        self.current_view = View::ConfirmDelete(name);
-                    }
-                    if let Some(name) = logs_name {
-                        self.view_container_logs(&name);
-                    }
-                    if let Some(name) = backup_name {
-                        self.create_backup(&name);
-                    }
-                    if let Some(name) = view_backups_name {
-                        self.view_backups(&name);
-                    }
-                    if let Some(name) = console_name {
-                        self.open_console(&name);
-                    }
-                    if let Some(name) = adopt_name {
-                        self.adopt_server(&name);
-                    }
-                    if let Some(name) = delete_orphan_name {
-                        self.confirm_delete_orphan = Some(name);
-                    }
-                    if let Some(name) = export_name {
-                        self.export_server(&name);
-                    }
-                    if let Some(name) = open_folder_name {
-                        let path = get_server_data_path(&name);
-                        if let Err(e) = open::that(&path) {
-                            tracing::error!("Failed to open folder {:?}: {}", path, e);
+                        ui.add_space(20.0);
+                        if let Some((current, total, file)) = &self.data_move_progress {
+                            ui.horizontal(|ui| {
+                                ui.add_space(ui.available_width() / 2.0 - 150.0);
+                                ui.spinner();
+                                if *total > 0 {
+                                    ui.label(tr_args(
+                                        "progress-copying-file",
+                                        &[
+                                            ("current", current.to_string().into()),
+                                            ("total", total.to_string().into()),
+                                            ("file", file.as_str().into()),
+                                        ],
+                                    ));
+                                } else {
+                                    ui.label(file);
+                                }
+                            });
+                            return;
                         }
-                    }
+                        ui.horizontal(|ui| {
+                            ui.add_space(ui.available_width() / 2.0 - 80.0);
+                            if ui.button(tr("button-cancel")).clicked() {
+                                self.data_move_plan = None;
+                                self.current_view = View::Settings;
+                            }
+                            ui.add_space(20.0);
+                            if ui
+                                .add_enabled(
+                                    plan.fits(),
+                                    egui::Button::new(tr("data-move-button"))
+                                        .fill(egui::Color32::from_rgb(40, 120, 40)),
+                                )
+                                .clicked()
+                            {
+                                self.move_data_root();
+                            }
+                        });
+                    });
                 }
-                View::CreateServer => {
-                    let mut created = None;
-                    let mut cancelled = false;
-                    let mut search_request: Option<CfSearchState> = None;
-                    let mut version_request: Option<u64> = None;
-                    let mut description_request: Option<u64> = None;
-                    let mut mr_search_request: Option<MrSearchState> = None;
-                    let mut mr_version_request: Option<String> = None;
-                    let mut mr_description_request: Option<String> = None;
-
-                    let has_cf_key = self
-                        .settings
-                        .curseforge_api_key
-                        .as_ref()
-                        .is_some_and(|k| !k.is_empty());
-
-                    self.create_view.show(
-                        ui,
-                        &self.templates,
-                        &mut CfCallbacks {
-                            on_search: &mut |state| {
-                                search_request = Some(state);
-                            },
-                            on_fetch_versions: &mut |mod_id| {
-                                version_request = Some(mod_id);
-                            },
-                            on_fetch_description: &mut |mod_id| {
-                                description_request = Some(mod_id);
-                            },
-                            has_api_key: has_cf_key,
-                        },
-                        &mut MrCallbacks {
-                            on_search: &mut |state| {
-                                mr_search_request = Some(state);
-                            },
-                            on_fetch_versions: &mut |project_id| {
-                                mr_version_request = Some(project_id);
-                            },
-                            on_fetch_description: &mut |project_id| {
-                                mr_description_request = Some(project_id);
-                            },
-                        },
-                        &mut CreateViewCallbacks {
-                            on_create: &mut |name, template, port, memory| {
-                                created = Some((name, template, port, memory));
-                            },
-                            on_cancel: &mut || cancelled = true,
-                        },
-                    );
-
-                    if let Some((name, template, port, memory)) = created {
-                        self.create_server(name, &template, port, memory);
+                View::Logs => {
+                    ui.horizontal(|ui| {
+                        ui.heading(tr("nav-logs"));
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            if ui.button(tr("console-clear")).clicked() {
+                                self.log_buffer.clear();
+                            }
+                            if ui.button(tr("logs-save")).clicked() {
+                                let text = self.log_buffer.join("\n");
+                                self.save_logs("drakonixanvil", &text);
+                            }
+                        });
+                    });
+                    ui.separator();
+
+                    self.app_log_filter.show(ui, "app_logs", self.log_buffer.iter().map(String::as_str));
+                }
+                View::DockerLogs => {
+                    // Auto-refresh every 5 seconds while following
+                    let following = self.docker_log_filter.follow();
+                    let should_refresh = self.docker_logs_last_refresh
+                        .map(|t| following && t.elapsed().as_secs() >= 5)
+                        .unwrap_or(true);
+                    if should_refresh || self.settings.shortcuts.pressed(ctx, Shortcut::RefreshLogs) {
+                        self.refresh_docker_logs();
                     }
-                    if cancelled {
-                        self.current_view = View::Dashboard;
-                        self.create_view.reset();
+                    if following {
+                        // Request repaint to keep auto-refresh going
+                        ctx.request_repaint_after(std::time::Duration::from_secs(1));
                     }
 
-                    if let Some(state) = search_request {
-                        self.dispatch_cf_search(state);
-                    }
-                    if let Some(mod_id) = version_request {
-                        self.dispatch_cf_fetch_versions(mod_id);
+                    ui.horizontal(|ui| {
+                        ui.heading(tr("nav-docker-logs"));
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            if ui.button(tr("button-refresh")).clicked() {
+                                self.refresh_docker_logs();
+                            }
+                            if ui.button(tr("logs-save")).clicked() {
+                                let text = self.all_docker_logs.clone();
+                                self.save_logs("docker-logs", &text);
+                            }
+                            // Show auto-refresh indicator
+                            if following {
+                                ui.small(tr("docker-logs-auto-refresh"));
+                            }
+                        });
+                    });
+                    ui.label(tr("docker-logs-hint"));
+                    ui.separator();
+
+                    self.docker_log_filter.show(ui, "docker_logs", self.all_docker_logs.lines());
+                }
+                View::Ports => {
+                    if PortsView::show(ui, &self.port_report) {
+                        self.view_ports();
                     }
-                    if let Some(mod_id) = description_request {
-                        self.dispatch_cf_fetch_description(mod_id);
+                }
+                View::BackupOverview => {
+                    match BackupOverviewView::show(ui, &self.backup_overview) {
+                        Some(BackupOverviewAction::Refresh) => self.view_backup_overview(),
+                        Some(BackupOverviewAction::Open(name)) => self.view_backups(&name),
+                        None => {}
                     }
-                    if let Some(state) = mr_search_request {
-                        self.dispatch_mr_search(state);
+                }
+                View::CrashReports(name) => {
+                    let name = name.clone();
+                    match CrashReportView::show(ui, &name, &self.crash_reports, self.crash_selected.as_ref()) {
+                        Some(CrashAction::Back) => self.open_server_details(&name),
+                        Some(CrashAction::Select(path)) => self.select_crash_report(&path),
+                        Some(CrashAction::OpenFile(path)) => {
+                            if let Err(e) = open::that(&path) {
+                                self.log(format!(
+                                    "ERROR: {}",
+                                    tr_args(
+                                        "log-open-failed",
+                                        &[
+                                            ("path", path.display().to_string().into()),
+                                            ("error", e.to_string().into()),
+                                        ],
+                                    )
+                                ));
+                            }
+                        }
+                        None => {}
                     }
-                    if let Some(project_id) = mr_version_request {
-                        self.dispatch_mr_fetch_versions(project_id);
+                }
+                View::Diagnostics => {
+                    let running = self.diagnostics_running;
+                    if DiagnosticsView::show(ui, self.diagnostics.as_ref(), running) {
+                        self.run_diagnostics(false);
                     }
-                    if let Some(project_id) = mr_description_request {
-                        self.dispatch_mr_fetch_description(project_id);
+                }
+                View::Mods(name) => {
+                    let name = name.clone();
+                    let action = ModsView::show(
+                        ui,
+                        &name,
+                        self.mod_graph.as_ref(),
+                        self.mods_scanning,
+                        &self.mods_installing,
+                    );
+                    if let Some(action) = action {
+                        self.run_mods_action(&name, action);
                     }
                 }
-                View::EditServer(name) => {
-                    let mut saved = None;
-                    let mut cancelled = false;
+                View::ModBrowse(name) => {
                     let name = name.clone();
-                    let templates = ModpackTemplate::builtin_templates();
-                    let mut search_request: Option<CfSearchState> = None;
-                    let mut version_request: Option<u64> = None;
-                    let mut description_request: Option<u64> = None;
-                    let mut mr_search_request: Option<MrSearchState> = None;
-                    let mut mr_version_request: Option<String> = None;
-                    let mut mr_description_request: Option<String> = None;
-
+                    let target = self
+                        .servers
+                        .iter()
+                        .find(|s| s.config.name == name)
+                        .map(|s| {
+                            format!(
+                                "{:?} {}",
+                                s.config.modpack.loader, s.config.modpack.minecraft_version
+                            )
+                        })
+                        .unwrap_or_default();
                     let has_cf_key = self
                         .settings
                         .curseforge_api_key
                         .as_ref()
                         .is_some_and(|k| !k.is_empty());
-
-                    self.edit_view.show(
-                        ui,
-                        &templates,
-                        &mut CfCallbacks {
-                            on_search: &mut |state| {
-                                search_request = Some(state);
-                            },
-                            on_fetch_versions: &mut |mod_id| {
-                                version_request = Some(mod_id);
-                            },
-                            on_fetch_description: &mut |mod_id| {
-                                description_request = Some(mod_id);
-                            },
-                            has_api_key: has_cf_key,
-                        },
-                        &mut MrCallbacks {
-                            on_search: &mut |state| {
-                                mr_search_request = Some(state);
-                            },
-                            on_fetch_versions: &mut |project_id| {
-                                mr_version_request = Some(project_id);
-                            },
-                            on_fetch_description: &mut |project_id| {
-                                mr_description_request = Some(project_id);
-                            },
-                        },
-                        &mut |result| {
-                            saved = Some(result);
-                        },
-                        &mut || cancelled = true,
-                    );
-
-                    if let Some(result) = saved {
-                        self.save_server_edit(&name, result);
-                    }
-                    if cancelled {
-                        self.current_view = View::Dashboard;
-                        self.edit_view.reset();
-                    }
-
-                    if let Some(state) = search_request {
-                        self.dispatch_cf_search(state);
-                    }
-                    if let Some(mod_id) = version_request {
-                        self.dispatch_cf_fetch_versions(mod_id);
-                    }
-                    if let Some(mod_id) = description_request {
-                        self.dispatch_cf_fetch_description(mod_id);
-                    }
-                    if let Some(state) = mr_search_request {
-                        self.dispatch_mr_search(state);
-                    }
-                    if let Some(project_id) = mr_version_request {
-                        self.dispatch_mr_fetch_versions(project_id);
-                    }
-                    if let Some(project_id) = mr_description_request {
-                        self.dispatch_mr_fetch_description(project_id);
+                    let installed = self.installed_mod_ids();
+                    let action =
+                        self.mod_browse.show(ui, &name, &target, has_cf_key, &installed);
+                    match action {
+                        Some(ModBrowseAction::Back) => {
+                            self.details_tab = DetailsTab::Mods;
+                            self.current_view = View::ServerDetails(name);
+                        }
+                        Some(ModBrowseAction::Search) => self.dispatch_mod_search(&name),
+                        Some(ModBrowseAction::FetchReleases(id)) => {
+                            self.dispatch_mod_releases(&name, id);
+                        }
+                        Some(ModBrowseAction::Install(release, title)) => {
+                            self.install_browsed_mod(&name, release, title);
+                        }
+                        None => {}
                     }
                 }
-                View::ServerDetails(name) => {
-                    ui.heading(format!("Server: {}", name));
-                    ui.label("Server details view - Coming soon!");
-                    if ui.button("Back to Dashboard").clicked() {
-                        self.current_view = View::Dashboard;
+                View::Preflight(name) => {
+                    let name = name.clone();
+                    let action = match &self.preflight_report {
+                        Some(report) => PreflightView::show(ui, report),
+                        None => Some(PreflightAction::Back),
+                    };
+                    match action {
+                        Some(PreflightAction::Back) => {
+                            self.preflight_report = None;
+                            self.current_view = View::Dashboard;
+                        }
+                        Some(PreflightAction::Recheck) => {
+                            self.start_server(&name);
+                        }
+                        Some(PreflightAction::StartAnyway) => {
+                            self.log(tr_args(
+                                "log-start-despite-warnings",
+                                &[("name", name.as_str().into())],
+                            ));
+                            self.preflight_report = None;
+                            self.current_view = View::Dashboard;
+                            self.launch_server(&name);
+                        }
+                        None => {}
                     }
                 }
-                View::ContainerLogs(name) => {
-                    let name = name.clone();
+                View::Settings => {
+                    ui.heading(tr("settings-title"));
+                    ui.add_space(10.0);
 
-                    // Auto-refresh every 5 seconds
-                    let should_refresh = self.container_logs_last_refresh
-                        .map(|t| t.elapsed().as_secs() >= 5)
-                        .unwrap_or(true);
-                    if should_refresh {
-                        self.refresh_container_logs(&name);
-                    }
-                    // Request repaint to keep auto-refresh going
-                    ctx.request_repaint_after(std::time::Duration::from_secs(1));
+                    // Language
+                    ui.group(|ui| {
+                        ui.strong(tr("settings-language"));
+                        let before = self.settings.language;
+                        egui::ComboBox::from_id_salt("settings_language")
+                            .selected_text(self.settings.language.label())
+                            .show_ui(ui, |ui| {
+                                for language in Language::ALL {
+                                    ui.selectable_value(
+                                        &mut self.settings.language,
+                                        language,
+                                        language.label(),
+                                    );
+                                }
+                            });
+                        ui.small(tr("settings-language-hint"));
+                        if self.settings.language != before {
+                            i18n::set_language(self.settings.language);
+                            if let Err(e) = save_settings(&self.settings) {
+                                self.show_status_message(tr_args(
+                                    "msg-settings-save-failed",
+                                    &[("error", e.to_string().into())],
+                                ));
+                            }
+                        }
+                    });
 
-                    ui.horizontal(|ui| {
-                        ui.heading(format!("Container Logs: {}", name));
-                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                            if ui.button("Refresh").clicked() {
-                                self.refresh_container_logs(&name);
+                    ui.add_space(10.0);
+
+                    // Background mode
+                    ui.group(|ui| {
+                        ui.strong(tr("settings-background"));
+                        if ui
+                            .checkbox(
+                                &mut self.settings.minimize_on_close,
+                                tr("settings-minimize-on-close"),
+                            )
+                            .changed()
+                        {
+                            if let Err(e) = save_settings(&self.settings) {
+                                self.show_status_message(tr_args(
+                                    "msg-settings-save-failed",
+                                    &[("error", e.to_string().into())],
+                                ));
                             }
-                            // Show auto-refresh indicator
-                            ui.small("(auto-refresh: 5s)");
-                            if ui.button("Back").clicked() {
-                                self.current_view = View::Dashboard;
+                        }
+                        ui.small(tr("settings-minimize-on-close-hint"));
+                    });
+
+                    ui.add_space(10.0);
+
+                    // Connect address
+                    ui.group(|ui| {
+                        ui.strong(tr("settings-connect-address"));
+                        if ui
+                            .checkbox(
+                                &mut self.settings.detect_public_ip,
+                                tr("settings-detect-public-ip"),
+                            )
+                            .changed()
+                        {
+                            if let Err(e) = save_settings(&self.settings) {
+                                self.show_status_message(tr_args(
+                                    "msg-settings-save-failed",
+                                    &[("error", e.to_string().into())],
+                                ));
+                            }
+                            if self.settings.detect_public_ip {
+                                self.detect_public_ip();
+                            } else {
+                                self.public_ip = None;
+                            }
+                        }
+                        ui.small(tr("settings-detect-public-ip-hint"));
+                        if let Some(ip) = &self.public_ip {
+                            ui.label(tr_args("settings-public-ip", &[("ip", ip.as_str().into())]));
+                        }
+                    });
+
+                    ui.add_space(10.0);
+
+                    // Dynamic DNS
+                    ui.group(|ui| {
+                        ui.strong(tr("settings-ddns"));
+                        ui.label(tr("settings-ddns-hint"));
+                        ui.add_space(5.0);
+                        let ddns = &mut self.settings.ddns;
+                        let mut interval_changed = false;
+                        let mut save = ui.checkbox(&mut ddns.enabled, tr("settings-ddns-enable")).changed();
+                        egui::Grid::new("ddns_settings").num_columns(2).show(ui, |ui| {
+                            ui.label(tr("settings-ddns-provider"));
+                            egui::ComboBox::from_id_salt("ddns_provider")
+                                .selected_text(ddns.provider.label())
+                                .show_ui(ui, |ui| {
+                                    for provider in DdnsProvider::ALL {
+                                        save |= ui
+                                            .selectable_value(&mut ddns.provider, provider, provider.label())
+                                            .changed();
+                                    }
+                                });
+                            ui.end_row();
+                            ui.label(tr("settings-ddns-hostname"));
+                            let hint = match ddns.provider {
+                                DdnsProvider::DuckDns => "myserver.duckdns.org",
+                                DdnsProvider::Cloudflare => "mc.example.com",
+                            };
+                            save |= ui
+                                .add(egui::TextEdit::singleline(&mut ddns.hostname).desired_width(250.0).hint_text(hint))
+                                .lost_focus();
+                            ui.end_row();
+                            ui.label(tr("settings-ddns-token"));
+                            save |= ui
+                                .add(egui::TextEdit::singleline(&mut ddns.token).password(true).desired_width(250.0))
+                                .lost_focus();
+                            ui.end_row();
+                            if ddns.provider == DdnsProvider::Cloudflare {
+                                ui.label(tr("settings-ddns-zone"));
+                                save |= ui
+                                    .add(egui::TextEdit::singleline(&mut ddns.zone_id).desired_width(250.0))
+                                    .lost_focus();
+                                ui.end_row();
                             }
+                            ui.label(tr("settings-ddns-interval"));
+                            interval_changed = ui
+                                .add(egui::DragValue::new(&mut ddns.interval_minutes).range(5..=1440).suffix(" min"))
+                                .changed();
+                            ui.end_row();
                         });
+                        ui.small(tr("settings-ddns-interval-hint"));
+                        let update_now = ui
+                            .add_enabled(self.settings.ddns.enabled, egui::Button::new(tr("settings-ddns-update-now")))
+                            .clicked();
+                        if save || interval_changed {
+                            if let Err(e) = save_settings(&self.settings) {
+                                self.show_status_message(tr_args(
+                                    "msg-settings-save-failed",
+                                    &[("error", e.to_string().into())],
+                                ));
+                            }
+                        }
+                        if save || update_now {
+                            self.force_dynamic_dns_update();
+                        }
+                        match &self.ddns_status {
+                            Some((Ok(ip), at)) => {
+                                ui.label(format!("{} → {} ({})", self.settings.ddns.hostname.trim(), ip, at.format("%H:%M")));
+                            }
+                            Some((Err(e), _)) => {
+                                ui.colored_label(egui::Color32::RED, e);
+                            }
+                            None => {}
+                        }
                     });
-                    ui.separator();
 
-                    egui::ScrollArea::vertical()
-                        .auto_shrink([false, false])
-                        .stick_to_bottom(true)
-                        .show(ui, |ui| {
-                            ui.add(
-                                egui::TextEdit::multiline(&mut self.container_logs.as_str())
-                                    .font(egui::TextStyle::Monospace)
-                                    .desired_width(f32::INFINITY)
+                    ui.add_space(10.0);
+
+                    // Prometheus metrics endpoint
+                    ui.group(|ui| {
+                        ui.strong(tr("settings-metrics"));
+                        ui.label(tr("settings-metrics-hint"));
+                        ui.add_space(5.0);
+                        let mut restart = ui
+                            .checkbox(&mut self.settings.metrics.enabled, tr("settings-metrics-enable"))
+                            .changed();
+                        ui.horizontal(|ui| {
+                            ui.label(tr("settings-metrics-bind"));
+                            let response = ui.add(
+                                egui::TextEdit::singleline(&mut self.settings.metrics.bind)
+                                    .desired_width(160.0)
+                                    .hint_text(metrics::DEFAULT_BIND),
                             );
+                            if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                                restart = true;
+                            }
+                            if ui.button(tr("settings-metrics-apply")).clicked() {
+                                restart = true;
+                            }
                         });
-                }
-                View::ConfirmDelete(name) => {
-                    let name = name.clone();
+                        ui.small(tr("settings-metrics-bind-hint"));
+                        if restart {
+                            if let Err(e) = save_settings(&self.settings) {
+                                self.show_status_message(tr_args(
+                                    "msg-settings-save-failed",
+                                    &[("error", e.to_string().into())],
+                                ));
+                            }
+                            self.start_metrics_endpoint();
+                        }
+                        if let Some(error) = &self.metrics_error {
+                            ui.colored_label(egui::Color32::RED, error);
+                        } else if self.metrics_endpoint.is_some() {
+                            let url = format!("http://{}/metrics", self.settings.metrics.bind.trim());
+                            ui.horizontal(|ui| {
+                                ui.label(tr("settings-metrics-serving"));
+                                ui.hyperlink(url);
+                            });
+                        }
+                    });
 
-                    // Get server details for display (clone to avoid borrow issues)
-                    let server_info = self.servers.iter().find(|s| s.config.name == name);
-                    let container_name = crate::config::get_container_name(&name);
-                    let modpack_name = server_info
-                        .map(|s| s.config.modpack.name.clone())
-                        .unwrap_or_else(|| "Unknown".to_string());
-                    let port = server_info
-                        .map(|s| s.config.port)
-                        .unwrap_or(0);
-                    let has_container = server_info
-                        .and_then(|s| s.container_id.as_ref())
-                        .is_some();
+                    ui.add_space(10.0);
 
-                    ui.vertical_centered(|ui| {
-                        ui.add_space(50.0);
-                        ui.heading("Delete Server?");
-                        ui.add_space(20.0);
+                    // Alert rules per server, and where alerts are sent
+                    ui.group(|ui| {
+                        ui.strong(tr("settings-alerts"));
+                        ui.label(tr("settings-alerts-hint"));
+                        ui.add_space(5.0);
+                        let mut save = ui
+                            .checkbox(&mut self.settings.alerts.desktop, tr("settings-alerts-desktop"))
+                            .changed();
+                        ui.horizontal(|ui| {
+                            ui.label(tr("settings-alerts-webhook"));
+                            save |= ui
+                                .add(
+                                    egui::TextEdit::singleline(&mut self.settings.alerts.webhook_url)
+                                        .desired_width(300.0)
+                                        .hint_text("https://discord.com/api/webhooks/..."),
+                                )
+                                .lost_focus();
+                        });
+                        if ui.button(tr("settings-alerts-test")).clicked() {
+                            self.raise_alert(Alert {
+                                server_name: "DrakonixAnvil".to_string(),
+                                kind: AlertKind::ContainerExited,
+                                message: "Test alert from DrakonixAnvil".to_string(),
+                            });
+                        }
+                        if save {
+                            if let Err(e) = save_settings(&self.settings) {
+                                self.show_status_message(tr_args(
+                                    "msg-settings-save-failed",
+                                    &[("error", e.to_string().into())],
+                                ));
+                            }
+                        }
 
-                        // Resource indicator box
-                        egui::Frame::none()
-                            .fill(egui::Color32::from_rgb(60, 30, 30))
-                            .rounding(8.0)
-                            .inner_margin(16.0)
-                            .show(ui, |ui| {
-                                ui.horizontal(|ui| {
-                                    ui.colored_label(egui::Color32::RED, "🗑");
-                                    ui.add_space(8.0);
-                                    ui.vertical(|ui| {
-                                        ui.strong("Docker Container");
-                                        ui.monospace(&container_name);
-                                        ui.small(format!("Server: {}", name));
-                                        ui.small(format!("Modpack: {}", modpack_name));
-                                        ui.small(format!("Port: {}", port));
-                                        if has_container {
-                                            ui.colored_label(egui::Color32::YELLOW, "Container exists and will be removed");
+                        ui.separator();
+                        if self.servers.is_empty() {
+                            ui.weak(tr("settings-alerts-no-servers"));
+                            return;
+                        }
+                        let selected = self
+                            .alerts_server
+                            .clone()
+                            .filter(|name| self.servers.iter().any(|s| s.config.name == *name))
+                            .unwrap_or_else(|| self.servers[0].config.name.clone());
+                        ui.horizontal(|ui| {
+                            ui.label(tr("settings-alerts-server"));
+                            egui::ComboBox::from_id_salt("alerts_server")
+                                .selected_text(&selected)
+                                .show_ui(ui, |ui| {
+                                    for server in &self.servers {
+                                        let name = &server.config.name;
+                                        let label = if server.config.alerts.any() {
+                                            format!("🔔 {}", name)
                                         } else {
-                                            ui.colored_label(egui::Color32::GRAY, "No container (config only)");
+                                            name.clone()
+                                        };
+                                        if ui.selectable_label(*name == selected, label).clicked() {
+                                            self.alerts_server = Some(name.clone());
                                         }
-                                    });
+                                    }
+                                });
+                        });
+                        let Some(index) = self.servers.iter().position(|s| s.config.name == selected) else {
+                            return;
+                        };
+                        let mut changed = alert_rules_editor(ui, &mut self.servers[index].config.alerts);
+                        if ui
+                            .button(tr("settings-alerts-copy"))
+                            .on_hover_text(tr("settings-alerts-copy-hint"))
+                            .clicked()
+                        {
+                            let rules = self.servers[index].config.alerts.clone();
+                            for server in &mut self.servers {
+                                server.config.alerts = rules.clone();
+                            }
+                            changed = true;
+                        }
+                        if changed {
+                            self.save_servers();
+                        }
+                    });
+
+                    ui.add_space(10.0);
+
+                    // Chat webhooks for server, player and backup events
+                    ui.group(|ui| {
+                        ui.strong(tr("settings-webhooks"));
+                        ui.label(tr("settings-webhooks-hint"));
+                        ui.add_space(5.0);
+                        let mut save = false;
+                        let mut remove = None;
+                        let mut test = None;
+                        for (i, webhook) in self.settings.webhooks.iter_mut().enumerate() {
+                            ui.push_id(i, |ui| {
+                                ui.horizontal(|ui| {
+                                    save |= ui
+                                        .add(
+                                            egui::TextEdit::singleline(&mut webhook.url)
+                                                .desired_width(300.0)
+                                                .hint_text("https://discord.com/api/webhooks/..."),
+                                        )
+                                        .lost_focus();
+                                    if ui.button(tr("settings-webhooks-test")).clicked() {
+                                        test = Some(webhook.url.trim().to_string());
+                                    }
+                                    if ui.button("🗑").on_hover_text(tr("settings-webhooks-remove")).clicked() {
+                                        remove = Some(i);
+                                    }
+                                });
+                                ui.horizontal_wrapped(|ui| {
+                                    for event in WebhookEvent::ALL {
+                                        let mut on = webhook.events.contains(&event);
+                                        if ui.checkbox(&mut on, event.label()).changed() {
+                                            if on {
+                                                webhook.events.push(event);
+                                            } else {
+                                                webhook.events.retain(|e| *e != event);
+                                            }
+                                            save = true;
+                                        }
+                                    }
                                 });
                             });
-
-                        ui.add_space(20.0);
-                        ui.colored_label(egui::Color32::GREEN, "Server data in DrakonixAnvilData/servers/ will NOT be deleted.");
-                        ui.small("You can recreate the server later using the same data.");
-                        ui.add_space(30.0);
-                        ui.horizontal(|ui| {
-                            ui.add_space(ui.available_width() / 2.0 - 80.0);
-                            if ui.button("Cancel").clicked() {
-                                self.current_view = View::Dashboard;
-                            }
-                            ui.add_space(20.0);
-                            if ui.add(egui::Button::new("Delete").fill(egui::Color32::from_rgb(150, 40, 40))).clicked() {
-                                self.delete_server(&name);
+                            ui.add_space(5.0);
+                        }
+                        if let Some(i) = remove {
+                            self.settings.webhooks.remove(i);
+                            save = true;
+                        }
+                        if ui.button(tr("settings-webhooks-add")).clicked() {
+                            self.settings.webhooks.push(Webhook::default());
+                        }
+                        if let Some(url) = test {
+                            let body = EventMessage::new(
+                                WebhookEvent::ServerStarted,
+                                "DrakonixAnvil",
+                                Some("Test message: this webhook works".to_string()),
+                            )
+                            .payload(&url);
+                            let tx = self.task_tx.clone();
+                            self.runtime.spawn(async move {
+                                let result = notify::post_json(&url, &body).await;
+                                let line = match result {
+                                    Ok(()) => tr("log-test-webhook-sent"),
+                                    Err(e) => format!(
+                                        "WARNING: {}",
+                                        tr_args(
+                                            "log-test-webhook-failed",
+                                            &[("error", format!("{:#}", e).into())],
+                                        )
+                                    ),
+                                };
+                                tx.send(TaskMessage::Log(line)).ok();
+                            });
+                        }
+                        if save {
+                            if let Err(e) = save_settings(&self.settings) {
+                                self.show_status_message(tr_args(
+                                    "msg-settings-save-failed",
+                                    &[("error", e.to_string().into())],
+                                ));
                             }
-                        });
+                        }
                     });
-                }
-                View::Backups(name) => {
-                    let name = name.clone();
-                    ui.horizontal(|ui| {
-                        ui.heading(format!("Backups: {}", name));
-                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                            if ui.button("Refresh").clicked() {
-                                self.view_backups(&name);
+
+                    ui.add_space(10.0);
+
+                    // Discord bot: chat commands from allowed users
+                    ui.group(|ui| {
+                        ui.strong(tr("settings-discord-bot"));
+                        ui.label(tr("settings-discord-bot-hint"));
+                        ui.add_space(5.0);
+                        let bot = &mut self.settings.discord_bot;
+                        let mut restart = ui.checkbox(&mut bot.enabled, tr("settings-discord-bot-enable")).changed();
+                        egui::Grid::new("discord_bot_settings").num_columns(2).show(ui, |ui| {
+                            ui.label(tr("settings-discord-bot-token"));
+                            ui.add(egui::TextEdit::singleline(&mut bot.token).password(true).desired_width(300.0));
+                            ui.end_row();
+                            ui.label(tr("settings-discord-bot-channel"));
+                            ui.add(
+                                egui::TextEdit::singleline(&mut bot.channel_id)
+                                    .desired_width(200.0)
+                                    .hint_text("123456789012345678"),
+                            );
+                            ui.end_row();
+                            ui.label(tr("settings-discord-bot-users"));
+                            ui.add(
+                                egui::TextEdit::multiline(&mut self.discord_users_input)
+                                    .desired_rows(3)
+                                    .desired_width(200.0)
+                                    .hint_text(tr("settings-discord-bot-users-hint")),
+                            );
+                            ui.end_row();
+                        });
+                        if ui.button(tr("settings-discord-bot-apply")).clicked() {
+                            restart = true;
+                        }
+                        ui.small(discord_bot::HELP);
+                        if restart {
+                            let (ids, ignored): (Vec<&str>, Vec<&str>) = self
+                                .discord_users_input
+                                .split(|c: char| c == ',' || c.is_whitespace())
+                                .filter(|u| !u.is_empty())
+                                .partition(|u| discord_bot::is_user_id(u));
+                            self.settings.discord_bot.allowed_user_ids =
+                                ids.into_iter().map(str::to_string).collect();
+                            if !ignored.is_empty() {
+                                self.show_status_message(tr_args(
+                                    "settings-discord-bot-users-ignored",
+                                    &[("entries", ignored.join(", ").into())],
+                                ));
                             }
-                            if ui.button("Back").clicked() {
-                                self.current_view = View::Dashboard;
+                            if let Err(e) = save_settings(&self.settings) {
+                                self.show_status_message(tr_args(
+                                    "msg-settings-save-failed",
+                                    &[("error", e.to_string().into())],
+                                ));
                             }
-                        });
+                            self.start_discord_bot();
+                        }
+                        if let Some(error) = &self.discord_bot_error {
+                            ui.colored_label(egui::Color32::RED, error);
+                        } else if self.discord_bot.is_some() {
+                            ui.label(tr("settings-discord-bot-running"));
+                        }
                     });
-                    ui.separator();
 
-                    if self.backup_list.is_empty() {
-                        ui.vertical_centered(|ui| {
-                            ui.add_space(50.0);
-                            ui.label("No backups found for this server.");
-                            ui.add_space(10.0);
-                            ui.label("Use the 'Backup' button on the dashboard to create one.");
+                    ui.add_space(10.0);
+
+                    // CurseForge API Key
+                    ui.group(|ui| {
+                        ui.strong(tr("settings-curseforge"));
+                        ui.label(tr("settings-curseforge-hint"));
+                        ui.horizontal(|ui| {
+                            ui.label(tr("settings-curseforge-get-key"));
+                            ui.hyperlink("https://console.curseforge.com/");
                         });
-                    } else {
-                        egui::ScrollArea::vertical().show(ui, |ui| {
-                            let mut restore_path = None;
-                            let mut delete_path = None;
+                        ui.add_space(5.0);
 
-                            for backup in &self.backup_list {
-                                egui::Frame::none()
-                                    .fill(ui.style().visuals.extreme_bg_color)
-                                    .rounding(8.0)
-                                    .inner_margin(12.0)
-                                    .show(ui, |ui| {
-                                        ui.horizontal(|ui| {
-                                            ui.vertical(|ui| {
-                                                ui.strong(&backup.filename);
-                                                ui.label(format!("Size: {}", backup::format_bytes(backup.size_bytes)));
-                                                if let Ok(duration) = backup.created.elapsed() {
-                                                    let hours = duration.as_secs() / 3600;
-                                                    let days = hours / 24;
-                                                    if days > 0 {
-                                                        ui.small(format!("{} days ago", days));
-                                                    } else if hours > 0 {
-                                                        ui.small(format!("{} hours ago", hours));
-                                                    } else {
-                                                        ui.small("Just now");
-                                                    }
-                                                }
-                                            });
+                        ui.horizontal(|ui| {
+                            ui.label(tr("settings-curseforge-key"));
+                            let response = ui.add(
+                                egui::TextEdit::singleline(&mut self.settings_cf_key_input)
+                                    .password(!self.settings_cf_key_visible)
+                                    .desired_width(300.0)
+                                    .hint_text(tr("settings-curseforge-key-hint"))
+                            );
 
-                                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                                                if ui.add(egui::Button::new("Delete").fill(egui::Color32::from_rgb(100, 30, 30))).clicked() {
-                                                    delete_path = Some(backup.path.clone());
-                                                }
-                                                if ui.button("Restore").clicked() {
-                                                    restore_path = Some(backup.path.clone());
-                                                }
-                                            });
-                                        });
-                                    });
-                                ui.add_space(8.0);
+                            // Show/hide toggle
+                            if ui.button("👁").on_hover_text(tr("settings-curseforge-show-key")).clicked() {
+                                self.settings_cf_key_visible = !self.settings_cf_key_visible;
                             }
 
-                            if let Some(path) = restore_path {
-                                self.current_view = View::ConfirmRestore(name.clone(), path);
-                            }
-                            if let Some(path) = delete_path {
-                                self.current_view = View::ConfirmDeleteBackup(name.clone(), path);
+                            if response.changed() {
+                                // Update settings when text changes
+                                let key = self.settings_cf_key_input.trim().to_string();
+                                self.settings.curseforge_api_key = if key.is_empty() {
+                                    None
+                                } else {
+                                    Some(key)
+                                };
                             }
                         });
-                    }
-                }
-                View::ConfirmRestore(name, path) => {
-                    let name = name.clone();
-                    let path = path.clone();
-                    let filename = path.file_name()
-                        .map(|s| s.to_string_lossy().to_string())
-                        .unwrap_or_else(|| "backup".to_string());
 
-                    ui.vertical_centered(|ui| {
-                        ui.add_space(50.0);
-                        ui.heading("Restore Backup?");
-                        ui.add_space(20.0);
-                        ui.label(format!("Restore '{}' to server '{}'?", filename, name));
-                        ui.add_space(10.0);
-                        ui.colored_label(egui::Color32::RED, "WARNING: This will overwrite all current server data!");
-                        ui.label("Make sure the server is stopped before restoring.");
-                        ui.add_space(30.0);
+                        // Status indicator
                         ui.horizontal(|ui| {
-                            ui.add_space(ui.available_width() / 2.0 - 80.0);
-                            if ui.button("Cancel").clicked() {
-                                self.current_view = View::Backups(name.clone());
-                            }
-                            ui.add_space(20.0);
-                            if ui.add(egui::Button::new("Restore").fill(egui::Color32::from_rgb(150, 100, 40))).clicked() {
-                                self.restore_backup(&name, &path);
+                            if self.settings.curseforge_api_key.is_some() {
+                                ui.colored_label(egui::Color32::GREEN, tr("settings-curseforge-key-set"));
+                            } else {
+                                ui.colored_label(egui::Color32::GRAY, tr("settings-curseforge-key-unset"));
                             }
                         });
-                    });
-                }
-                View::ConfirmDeleteBackup(name, path) => {
-                    let name = name.clone();
-                    let path = path.clone();
-                    let filename = path.file_name()
-                        .map(|s| s.to_string_lossy().to_string())
-                        .unwrap_or_else(|| "backup".to_string());
-
-                    // Get file size for display
-                    let size_str = std::fs::metadata(&path)
-                        .map(|m| backup::format_bytes(m.len()))
-                        .unwrap_or_else(|_| "unknown size".to_string());
-
-                    ui.vertical_centered(|ui| {
-                        ui.add_space(50.0);
-                        ui.heading("Delete Backup?");
-                        ui.add_space(20.0);
-
-                        // Resource indicator box
-                        egui::Frame::none()
-                            .fill(egui::Color32::from_rgb(60, 30, 30))
-                            .rounding(8.0)
-                            .inner_margin(16.0)
-                            .show(ui, |ui| {
-                                ui.horizontal(|ui| {
-                                    ui.colored_label(egui::Color32::RED, "🗑");
-                                    ui.add_space(8.0);
-                                    ui.vertical(|ui| {
-                                        ui.strong("Backup File");
-                                        ui.monospace(&filename);
-                                        ui.small(format!("Size: {}", size_str));
-                                        ui.small(format!("Server: {}", name));
-                                    });
-                                });
-                            });
 
-                        ui.add_space(20.0);
-                        ui.label("This action cannot be undone.");
-                        ui.add_space(30.0);
-                        ui.horizontal(|ui| {
-                            ui.add_space(ui.available_width() / 2.0 - 80.0);
-                            if ui.button("Cancel").clicked() {
-                                self.current_view = View::Backups(name.clone());
-                            }
-                            ui.add_space(20.0);
-                            if ui.add(egui::Button::new("Delete").fill(egui::Color32::from_rgb(150, 40, 40))).clicked() {
-                                self.delete_backup(&name, &path);
+                        ui.add_space(5.0);
+                        if ui.button(tr("settings-save")).clicked() {
+                            let key_newly_added = !self.settings_cf_key_was_set
+                                && self.settings.curseforge_api_key.is_some();
+                            if let Err(e) = save_settings(&self.settings) {
+                                self.show_status_message(tr_args(
+                                    "msg-settings-save-failed",
+                                    &[("error", e.to_string().into())],
+                                ));
+                            } else if key_newly_added {
+                                self.settings_cf_key_was_set = true;
+                                self.show_status_message(tr("settings-curseforge-saved-restart"));
+                            } else {
+                                self.show_status_message(tr("settings-saved"));
                             }
-                        });
+                        }
                     });
-                }
-                View::ConfirmRemoveContainer(name) => {
-                    let name = name.clone();
-                    let container_name = get_container_name(&name);
-
-                    ui.vertical_centered(|ui| {
-                        ui.add_space(50.0);
-                        ui.heading("Container Already Exists");
-                        ui.add_space(20.0);
-
-                        // Info box (blue - this is safe)
-                        egui::Frame::none()
-                            .fill(egui::Color32::from_rgb(30, 40, 60))
-                            .rounding(8.0)
-                            .inner_margin(16.0)
-                            .show(ui, |ui| {
-                                ui.horizontal(|ui| {
-                                    ui.colored_label(egui::Color32::from_rgb(100, 150, 255), "ℹ");
-                                    ui.add_space(8.0);
-                                    ui.vertical(|ui| {
-                                        ui.strong("Old Container");
-                                        ui.monospace(&container_name);
-                                        ui.small(format!("Server: {}", name));
-                                        ui.add_space(4.0);
-                                        ui.label("Settings were changed, so the old container needs to be removed and recreated.");
-                                    });
-                                });
-                            });
 
-                        ui.add_space(12.0);
+                    ui.add_space(10.0);
 
-                        // Green reassurance box
-                        egui::Frame::none()
-                            .fill(egui::Color32::from_rgb(30, 50, 30))
-                            .rounding(8.0)
-                            .inner_margin(16.0)
-                            .show(ui, |ui| {
-                                ui.horizontal(|ui| {
-                                    ui.colored_label(egui::Color32::GREEN, "✓");
-                                    ui.add_space(8.0);
-                                    ui.vertical(|ui| {
-                                        ui.label("This is safe! All server data lives in DrakonixAnvilData/servers/, not inside the container. Removing the container is like deleting a shortcut — your worlds, configs, and mods are untouched.");
-                                    });
-                                });
-                            });
+                    // Modrinth token
+                    ui.group(|ui| {
+                        ui.strong(tr("settings-modrinth"));
+                        ui.label(tr("settings-modrinth-hint"));
+                        ui.horizontal(|ui| {
+                            ui.label(tr("settings-modrinth-create"));
+                            ui.hyperlink("https://modrinth.com/settings/pats");
+                        });
+                        ui.add_space(5.0);
 
-                        ui.add_space(30.0);
                         ui.horizontal(|ui| {
-                            ui.add_space(ui.available_width() / 2.0 - 100.0);
-                            if ui.button("Cancel").clicked() {
-                                self.current_view = View::Dashboard;
-                            }
-                            ui.add_space(20.0);
-                            if ui.add(egui::Button::new("Remove & Restart").fill(egui::Color32::from_rgb(40, 120, 40))).clicked() {
-                                self.remove_container_and_start(&name);
+                            ui.label(tr("settings-ddns-token"));
+                            let mut token = self.settings.modrinth_token.clone().unwrap_or_default();
+                            let response = ui.add(
+                                egui::TextEdit::singleline(&mut token)
+                                    .password(true)
+                                    .desired_width(300.0)
+                                    .hint_text("mrp_..."),
+                            );
+                            if response.changed() {
+                                let token = token.trim().to_string();
+                                self.settings.modrinth_token = if token.is_empty() {
+                                    None
+                                } else {
+                                    Some(token)
+                                };
                             }
                         });
-                    });
-                }
-                View::ConfirmImport(path) => {
-                    let path = path.clone();
 
-                    // Try to read the config for preview
-                    let config_result = backup::read_export_config(&path);
-
-                    ui.vertical_centered(|ui| {
-                        ui.add_space(50.0);
-                        ui.heading("Import Server");
-                        ui.add_space(20.0);
+                        ui.add_space(5.0);
+                        if ui.button(tr("settings-save")).clicked() {
+                            if let Err(e) = save_settings(&self.settings) {
+                                self.show_status_message(tr_args(
+                                    "msg-settings-save-failed",
+                                    &[("error", e.to_string().into())],
+                                ));
+                            } else {
+                                self.show_status_message(tr("settings-saved"));
+                            }
+                        }
+                    });
 
-                        match &config_result {
-                            Ok(config) => {
-                                // Preview box
-                                egui::Frame::none()
-                                    .fill(egui::Color32::from_rgb(30, 40, 60))
-                                    .rounding(8.0)
-                                    .inner_margin(16.0)
-                                    .show(ui, |ui| {
-                                        ui.horizontal(|ui| {
-                                            ui.colored_label(
-                                                egui::Color32::from_rgb(100, 150, 255),
-                                                "ℹ",
-                                            );
-                                            ui.add_space(8.0);
-                                            ui.vertical(|ui| {
-                                                ui.strong("Server Preview");
-                                                ui.add_space(4.0);
-                                                ui.label(format!("Name: {}", config.name));
-                                                ui.label(format!(
-                                                    "Modpack: {}",
-                                                    config.modpack.name
-                                                ));
-                                                ui.label(format!(
-                                                    "Version: {}",
-                                                    config.modpack.version
-                                                ));
-                                                ui.label(format!(
-                                                    "Minecraft: {}",
-                                                    config.modpack.minecraft_version
-                                                ));
-                                                ui.label(format!(
-                                                    "Loader: {:?}",
-                                                    config.modpack.loader
-                                                ));
-                                                ui.label(format!("Port: {}", config.port));
-                                                ui.label(format!(
-                                                    "Memory: {} MB",
-                                                    config.memory_mb
-                                                ));
-                                            });
-                                        });
-                                    });
+                    ui.add_space(10.0);
 
-                                // Check for name conflict
-                                let name_conflict = self
-                                    .servers
-                                    .iter()
-                                    .any(|s| s.config.name == config.name);
-                                if name_conflict {
-                                    ui.add_space(12.0);
-                                    ui.colored_label(
-                                        egui::Color32::YELLOW,
-                                        format!(
-                                            "A server named '{}' already exists. \
-                                             Importing will overwrite its data.",
-                                            config.name
-                                        ),
-                                    );
+                    // Download bandwidth
+                    ui.group(|ui| {
+                        ui.strong(tr("settings-downloads"));
+                        ui.label(tr("settings-downloads-hint"));
+                        ui.add_space(5.0);
+                        ui.horizontal(|ui| {
+                            ui.label(tr("settings-downloads-limit"));
+                            ui.add(
+                                egui::DragValue::new(&mut self.settings.download_limit_kbps)
+                                    .range(0..=1_000_000)
+                                    .speed(64)
+                                    .suffix(" KiB/s"),
+                            );
+                            if self.settings.download_limit_kbps == 0 {
+                                ui.small(tr("settings-downloads-unlimited"));
+                            }
+                        });
+                        ui.horizontal(|ui| {
+                            let cache_dir = get_download_cache_path();
+                            let cached = crate::pack_installer::cache_size(&cache_dir);
+                            ui.label(tr_args(
+                                "settings-download-cache",
+                                &[("size", backup::format_bytes(cached).into())],
+                            ))
+                                .on_hover_text(tr("settings-download-cache-hint"));
+                            if ui.add_enabled(cached > 0, egui::Button::new(tr("settings-clear-cache"))).clicked() {
+                                match crate::pack_installer::clear_cache(&cache_dir) {
+                                    Ok(freed) => self.show_status_message(tr_args(
+                                        "msg-download-cache-cleared",
+                                        &[("size", backup::format_bytes(freed).into())],
+                                    )),
+                                    Err(e) => self.show_status_message(format!("{:#}", e)),
                                 }
                             }
-                            Err(e) => {
-                                ui.colored_label(
-                                    egui::Color32::RED,
-                                    format!("Failed to read export bundle: {}", e),
-                                );
+                        });
+                        ui.horizontal(|ui| {
+                            let cached = crate::api_cache::size();
+                            ui.label(tr_args(
+                                "settings-api-cache",
+                                &[("size", backup::format_bytes(cached).into())],
+                            ))
+                                .on_hover_text(tr("settings-api-cache-hint"));
+                            if ui.add_enabled(cached > 0, egui::Button::new(tr("settings-clear-api-cache"))).clicked() {
+                                match crate::api_cache::clear() {
+                                    Ok(freed) => self.show_status_message(tr_args(
+                                        "msg-api-cache-cleared",
+                                        &[("size", backup::format_bytes(freed).into())],
+                                    )),
+                                    Err(e) => self.show_status_message(format!("{:#}", e)),
+                                }
+                            }
+                        });
+                        ui.add_space(5.0);
+                        if ui.button(tr("settings-save")).clicked() {
+                            if let Err(e) = save_settings(&self.settings) {
+                                self.show_status_message(tr_args(
+                                    "msg-settings-save-failed",
+                                    &[("error", e.to_string().into())],
+                                ));
+                            } else {
+                                self.show_status_message(tr("settings-saved"));
                             }
                         }
+                    });
 
-                        ui.add_space(30.0);
+                    ui.add_space(10.0);
+
+                    // Remote template index
+                    ui.group(|ui| {
+                        ui.strong(tr("settings-template-index"));
+                        ui.label(tr("settings-template-index-hint"));
+                        ui.add_space(5.0);
+                        if ui
+                            .checkbox(&mut self.settings.template_index.enabled, tr("settings-template-index-enable"))
+                            .changed()
+                        {
+                            self.reload_templates();
+                        }
                         ui.horizontal(|ui| {
-                            ui.add_space(ui.available_width() / 2.0 - 80.0);
-                            if ui.button("Cancel").clicked() {
-                                self.current_view = View::Dashboard;
+                            ui.label(tr("settings-template-index-url"));
+                            ui.add_enabled(
+                                self.settings.template_index.enabled,
+                                egui::TextEdit::singleline(&mut self.settings.template_index.url).desired_width(400.0),
+                            );
+                            if self.settings.template_index.url != crate::config::DEFAULT_TEMPLATE_INDEX_URL
+                                && ui.small_button(tr("settings-reset")).clicked()
+                            {
+                                self.settings.template_index.url = crate::config::DEFAULT_TEMPLATE_INDEX_URL.to_string();
                             }
-                            ui.add_space(20.0);
-                            let can_import = config_result.is_ok();
+                        });
+                        ui.horizontal(|ui| {
                             if ui
                                 .add_enabled(
-                                    can_import,
-                                    egui::Button::new("Import")
-                                        .fill(egui::Color32::from_rgb(40, 120, 40)),
+                                    self.settings.template_index.enabled && !self.template_index_fetching,
+                                    egui::Button::new(tr("settings-ddns-update-now")),
                                 )
                                 .clicked()
                             {
-                                self.confirm_import(&path);
+                                self.fetch_template_index();
+                            }
+                            if self.template_index_fetching {
+                                ui.spinner();
+                            }
+                            match &self.template_index_result {
+                                Some(Ok(count)) => {
+                                    let text = tr_args(
+                                        "settings-template-index-count",
+                                        &[("count", (*count).into())],
+                                    );
+                                    ui.colored_label(egui::Color32::GREEN, text);
+                                }
+                                Some(Err(e)) => {
+                                    ui.colored_label(egui::Color32::RED, e);
+                                }
+                                None => {}
                             }
                         });
-                    });
-                }
-                View::Console(name) => {
-                    let name = name.clone();
-                    ui.horizontal(|ui| {
-                        ui.heading(format!("Console: {}", name));
-                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                            if ui.button("Clear").clicked() {
-                                self.console_output.clear();
+                        ui.horizontal(|ui| {
+                            if ui
+                                .add_enabled(!self.create_view.template_check_running, egui::Button::new(tr("settings-check-templates")))
+                                .on_hover_text(tr("settings-check-templates-hint"))
+                                .clicked()
+                            {
+                                self.check_templates();
                             }
-                            if ui.button("Back").clicked() {
-                                self.current_view = View::Dashboard;
+                            if self.create_view.template_check_running {
+                                ui.spinner();
+                            } else if !self.create_view.template_health.is_empty() {
+                                let broken = self
+                                    .create_view
+                                    .template_health
+                                    .values()
+                                    .filter(|h| h.status == CheckStatus::Fail)
+                                    .count();
+                                if broken == 0 {
+                                    ui.colored_label(egui::Color32::GREEN, tr("settings-templates-resolve"));
+                                } else {
+                                    ui.colored_label(
+                                        egui::Color32::RED,
+                                        tr_args(
+                                            "settings-templates-broken",
+                                            &[("count", broken.into())],
+                                        ),
+                                    );
+                                }
                             }
                         });
+                        ui.add_space(5.0);
+                        if ui.button(tr("settings-save")).clicked() {
+                            if let Err(e) = save_settings(&self.settings) {
+                                self.show_status_message(tr_args(
+                                    "msg-settings-save-failed",
+                                    &[("error", e.to_string().into())],
+                                ));
+                            } else {
+                                self.show_status_message(tr("settings-saved"));
+                            }
+                        }
                     });
 
-                    // Show RCON password for reference
-                    if let Some(server) = self.servers.iter().find(|s| s.config.name == name) {
-                        ui.horizontal(|ui| {
-                            ui.small(format!("RCON Port: {} | Password: {}",
-                                server.config.rcon_port(),
-                                server.config.rcon_password
-                            ));
-                        });
-                    }
-                    ui.separator();
+                    ui.add_space(10.0);
 
-                    // Console output (scrollable)
-                    let available_height = ui.available_height() - 35.0; // Reserve space for input
-                    egui::ScrollArea::vertical()
-                        .max_height(available_height)
-                        .stick_to_bottom(true)
-                        .show(ui, |ui| {
-                            for line in &self.console_output {
-                                ui.monospace(line);
+                    // Backup compression
+                    ui.group(|ui| {
+                        ui.strong(tr("settings-backups"));
+                        ui.label(tr("settings-backups-hint"));
+                        ui.add_space(5.0);
+                        compression_picker(ui, "settings_backup_compression", &mut self.settings.backup_compression);
+                        if matches!(self.settings.backup_compression, BackupCompression::Zstd { .. }) {
+                            ui.small(tr("settings-backups-zstd-hint"));
+                        }
+                        ui.checkbox(&mut self.settings.verify_backups, tr("settings-verify-backups"))
+                            .on_hover_text(tr("settings-verify-backups-hint"));
+                        ui.add_space(5.0);
+                        if ui.button(tr("settings-save")).clicked() {
+                            if let Err(e) = save_settings(&self.settings) {
+                                self.show_status_message(tr_args(
+                                    "msg-settings-save-failed",
+                                    &[("error", e.to_string().into())],
+                                ));
+                            } else {
+                                self.show_status_message(tr("settings-saved"));
                             }
-                        });
+                        }
+                    });
 
-                    ui.separator();
+                    ui.add_space(10.0);
 
-                    // Command input
-                    let mut send_command = false;
-                    ui.horizontal(|ui| {
-                        ui.label(">");
-                        let response = ui.add(
-                            egui::TextEdit::singleline(&mut self.console_input)
-                                .desired_width(ui.available_width() - 70.0)
-                                .font(egui::TextStyle::Monospace)
-                                .hint_text("Enter command...")
-                        );
+                    // Remote backup destination
+                    ui.group(|ui| {
+                        ui.strong(tr("settings-remote-backups"));
+                        ui.label(tr("settings-remote-hint"));
+                        ui.add_space(5.0);
+                        let remote = &mut self.settings.remote_backup;
+                        ui.horizontal(|ui| {
+                            ui.radio_value(&mut remote.kind, RemoteBackupKind::Disabled, tr("settings-remote-disabled"));
+                            ui.radio_value(&mut remote.kind, RemoteBackupKind::S3, tr("settings-remote-s3"));
+                            ui.radio_value(&mut remote.kind, RemoteBackupKind::WebDav, "WebDAV");
+                            // Only offered when built with the `sftp` feature; settings from
+                            // a build that had it still show, with the warning below
+                            if cfg!(feature = "sftp") || remote.kind == RemoteBackupKind::Sftp {
+                                ui.radio_value(&mut remote.kind, RemoteBackupKind::Sftp, "SFTP");
+                            }
+                        });
 
-                        // Send on Enter key
-                        if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
-                            send_command = true;
+                        match remote.kind {
+                            RemoteBackupKind::Disabled => {}
+                            RemoteBackupKind::S3 => {
+                                egui::Grid::new("remote_s3_grid").num_columns(2).show(ui, |ui| {
+                                    ui.label(tr("settings-remote-endpoint"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut remote.s3_endpoint)
+                                            .desired_width(300.0)
+                                            .hint_text("https://s3.us-west-004.backblazeb2.com"),
+                                    );
+                                    ui.end_row();
+                                    ui.label(tr("settings-remote-region"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut remote.s3_region)
+                                            .desired_width(300.0)
+                                            .hint_text("us-east-1"),
+                                    );
+                                    ui.end_row();
+                                    ui.label(tr("settings-remote-bucket"));
+                                    ui.add(egui::TextEdit::singleline(&mut remote.s3_bucket).desired_width(300.0));
+                                    ui.end_row();
+                                    ui.label(tr("settings-remote-access-key"));
+                                    ui.add(egui::TextEdit::singleline(&mut remote.s3_access_key).desired_width(300.0));
+                                    ui.end_row();
+                                    ui.label(tr("settings-remote-secret-key"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut remote.s3_secret_key)
+                                            .password(true)
+                                            .desired_width(300.0),
+                                    );
+                                    ui.end_row();
+                                });
+                            }
+                            RemoteBackupKind::WebDav => {
+                                egui::Grid::new("remote_webdav_grid").num_columns(2).show(ui, |ui| {
+                                    ui.label(tr("settings-remote-folder-url"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut remote.webdav_url)
+                                            .desired_width(300.0)
+                                            .hint_text("https://cloud.example.com/remote.php/dav/files/me/backups"),
+                                    );
+                                    ui.end_row();
+                                    ui.label(tr("settings-remote-username"));
+                                    ui.add(egui::TextEdit::singleline(&mut remote.webdav_username).desired_width(300.0));
+                                    ui.end_row();
+                                    ui.label(tr("settings-remote-password"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut remote.webdav_password)
+                                            .password(true)
+                                            .desired_width(300.0),
+                                    );
+                                    ui.end_row();
+                                });
+                            }
+                            RemoteBackupKind::Sftp => {
+                                if !cfg!(feature = "sftp") {
+                                    ui.colored_label(
+                                        egui::Color32::YELLOW,
+                                        tr("settings-remote-no-sftp"),
+                                    );
+                                }
+                                egui::Grid::new("remote_sftp_grid").num_columns(2).show(ui, |ui| {
+                                    ui.label(tr("settings-remote-host"));
+                                    ui.horizontal(|ui| {
+                                        ui.add(
+                                            egui::TextEdit::singleline(&mut remote.sftp_host)
+                                                .desired_width(220.0)
+                                                .hint_text("backup.example.com"),
+                                        );
+                                        ui.label(tr("create-port"));
+                                        ui.add(egui::DragValue::new(&mut remote.sftp_port).range(1..=65535));
+                                    });
+                                    ui.end_row();
+                                    ui.label(tr("settings-remote-username"));
+                                    ui.add(egui::TextEdit::singleline(&mut remote.sftp_username).desired_width(300.0));
+                                    ui.end_row();
+                                    ui.label(tr("settings-remote-private-key"));
+                                    ui.horizontal(|ui| {
+                                        ui.add(
+                                            egui::TextEdit::singleline(&mut remote.sftp_key_path)
+                                                .desired_width(230.0)
+                                                .hint_text(tr("settings-remote-private-key-hint")),
+                                        );
+                                        if ui.button(tr("edit-browse")).clicked() {
+                                            let dialog = rfd::FileDialog::new().set_title(tr("settings-remote-key-dialog"));
+                                            if let Some(path) = dialog.pick_file() {
+                                                remote.sftp_key_path = path.display().to_string();
+                                            }
+                                        }
+                                    });
+                                    ui.end_row();
+                                    ui.label(if remote.sftp_key_path.trim().is_empty() {
+                                        tr("settings-remote-password")
+                                    } else {
+                                        tr("settings-remote-passphrase")
+                                    });
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut remote.sftp_password)
+                                            .password(true)
+                                            .desired_width(300.0),
+                                    );
+                                    ui.end_row();
+                                    ui.label(tr("settings-remote-dir"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut remote.sftp_dir)
+                                            .desired_width(300.0)
+                                            .hint_text(tr("settings-remote-dir-hint")),
+                                    );
+                                    ui.end_row();
+                                });
+                                ui.small(tr("settings-remote-host-key"));
+                            }
                         }
 
-                        if ui.button("Send").clicked() {
-                            send_command = true;
+                        if remote.kind != RemoteBackupKind::Disabled {
+                            ui.horizontal(|ui| {
+                                ui.label(tr("settings-remote-keep"));
+                                ui.add(egui::DragValue::new(&mut remote.keep_last).range(0..=1000));
+                                ui.label(tr("settings-remote-keep-unit"));
+                                if remote.keep_last == 0 {
+                                    ui.small(tr("settings-remote-keep-all"));
+                                }
+                            });
+                            if !remote_backup::is_configured(remote) {
+                                ui.colored_label(egui::Color32::YELLOW, tr("settings-remote-incomplete"));
+                            }
                         }
-                    });
 
-                    if send_command && !self.console_input.is_empty() {
-                        let cmd = self.console_input.clone();
-                        self.console_input.clear();
-                        self.send_rcon_command(&name, &cmd);
-                    }
-                }
-                View::Logs => {
-                    ui.horizontal(|ui| {
-                        ui.heading("Logs");
-                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                            if ui.button("Clear").clicked() {
-                                self.log_buffer.clear();
+                        ui.add_space(5.0);
+                        if ui.button(tr("settings-save")).clicked() {
+                            if let Err(e) = save_settings(&self.settings) {
+                                self.show_status_message(tr_args(
+                                    "msg-settings-save-failed",
+                                    &[("error", e.to_string().into())],
+                                ));
+                            } else {
+                                self.show_status_message(tr("settings-saved"));
                             }
-                        });
+                        }
                     });
-                    ui.separator();
 
-                    egui::ScrollArea::vertical()
-                        .auto_shrink([false, false])
-                        .stick_to_bottom(true)
-                        .show(ui, |ui| {
-                            for line in &self.log_buffer {
-                                ui.monospace(line);
-                            }
+                    ui.add_space(10.0);
+
+                    // Network proxy
+                    ui.group(|ui| {
+                        ui.strong(tr("settings-proxy"));
+                        ui.label(tr("settings-proxy-hint"));
+                        ui.add_space(5.0);
+                        ui.horizontal(|ui| {
+                            ui.radio_value(&mut self.settings.proxy.mode, ProxyMode::System, tr("settings-proxy-system"));
+                            ui.radio_value(&mut self.settings.proxy.mode, ProxyMode::Disabled, tr("settings-proxy-none"));
+                            ui.radio_value(&mut self.settings.proxy.mode, ProxyMode::Manual, tr("settings-proxy-manual"));
                         });
-                }
-                View::DockerLogs => {
-                    // Auto-refresh every 5 seconds
-                    let should_refresh = self.docker_logs_last_refresh
-                        .map(|t| t.elapsed().as_secs() >= 5)
-                        .unwrap_or(true);
-                    if should_refresh {
-                        self.refresh_docker_logs();
-                    }
-                    // Request repaint to keep auto-refresh going
-                    ctx.request_repaint_after(std::time::Duration::from_secs(1));
 
-                    ui.horizontal(|ui| {
-                        ui.heading("Docker Logs");
-                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                            if ui.button("Refresh").clicked() {
-                                self.refresh_docker_logs();
+                        match self.settings.proxy.mode {
+                            ProxyMode::System => match crate::http::env_proxy() {
+                                Some((var, value)) => {
+                                    ui.small(tr_args(
+                                        "settings-proxy-using",
+                                        &[("var", var.into()), ("value", value.into())],
+                                    ));
+                                }
+                                None => {
+                                    ui.small(tr("settings-proxy-no-env"));
+                                }
+                            },
+                            ProxyMode::Disabled => {}
+                            ProxyMode::Manual => {
+                                egui::Grid::new("proxy_grid").num_columns(2).show(ui, |ui| {
+                                    ui.label(tr("settings-proxy-url"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut self.settings.proxy.url)
+                                            .desired_width(300.0)
+                                            .hint_text(tr("settings-proxy-url-hint")),
+                                    );
+                                    ui.end_row();
+                                    ui.label(tr("settings-proxy-bypass"));
+                                    ui.add(
+                                        egui::TextEdit::singleline(&mut self.settings.proxy.no_proxy)
+                                            .desired_width(300.0)
+                                            .hint_text("localhost,.internal"),
+                                    );
+                                    ui.end_row();
+                                });
+                                if let Err(e) = crate::http::validate_proxy_url(&self.settings.proxy.url) {
+                                    ui.colored_label(egui::Color32::YELLOW, e);
+                                }
+                            }
+                        }
+
+                        ui.horizontal(|ui| {
+                            if ui
+                                .add_enabled(!self.proxy_testing, egui::Button::new(tr("settings-proxy-test")))
+                                .on_hover_text(tr("settings-proxy-test-hint"))
+                                .clicked()
+                            {
+                                self.test_proxy();
+                            }
+                            if self.proxy_testing {
+                                ui.spinner();
+                            }
+                            match &self.proxy_test_result {
+                                Some(Ok(ms)) => {
+                                    let text = tr_args(
+                                        "settings-proxy-connected",
+                                        &[("ms", ms.to_string().into())],
+                                    );
+                                    ui.colored_label(egui::Color32::GREEN, text);
+                                }
+                                Some(Err(e)) => {
+                                    ui.colored_label(egui::Color32::RED, format!("✗ {}", e));
+                                }
+                                None => {}
                             }
-                            // Show auto-refresh indicator
-                            ui.small("(auto-refresh: 5s)");
                         });
-                    });
-                    ui.label("Combined logs from all DrakonixAnvil-managed containers");
-                    ui.separator();
 
-                    egui::ScrollArea::vertical()
-                        .auto_shrink([false, false])
-                        .stick_to_bottom(true)
-                        .show(ui, |ui| {
-                            ui.add(
-                                egui::TextEdit::multiline(&mut self.all_docker_logs.as_str())
-                                    .font(egui::TextStyle::Monospace)
-                                    .desired_width(f32::INFINITY)
-                            );
+                        ui.add_space(5.0);
+                        ui.small(tr("settings-proxy-docker"));
+                        ui.horizontal(|ui| {
+                            ui.small(tr("settings-proxy-docker-desktop"));
+                            ui.hyperlink_to(tr("settings-proxy-docker-guide"), "https://docs.docker.com/engine/daemon/proxy/");
                         });
-                }
-                View::Settings => {
-                    ui.heading("Settings");
+
+                        ui.add_space(5.0);
+                        if ui.button(tr("settings-save")).clicked() {
+                            if self.settings.proxy.mode == ProxyMode::Manual {
+                                if let Err(e) = crate::http::validate_proxy_url(&self.settings.proxy.url) {
+                                    self.show_status_message(tr_args(
+                                        "msg-invalid-proxy",
+                                        &[("error", e.into())],
+                                    ));
+                                    return;
+                                }
+                            }
+                            crate::http::set_proxy_settings(&self.settings.proxy);
+                            if let Err(e) = save_settings(&self.settings) {
+                                self.show_status_message(tr_args(
+                                    "msg-settings-save-failed",
+                                    &[("error", e.to_string().into())],
+                                ));
+                            } else {
+                                self.show_status_message(tr("settings-saved"));
+                            }
+                        }
+                    });
+
                     ui.add_space(10.0);
 
-                    // CurseForge API Key
+                    // Data folder
                     ui.group(|ui| {
-                        ui.strong("CurseForge API Key");
-                        ui.label("Required for downloading CurseForge modpacks.");
+                        ui.strong(tr("settings-storage"));
+                        ui.label(tr("settings-storage-hint"));
+                        ui.add_space(5.0);
+                        let root = crate::config::data_root();
                         ui.horizontal(|ui| {
-                            ui.label("Get your key:");
-                            ui.hyperlink("https://console.curseforge.com/");
+                            ui.label(tr("settings-storage-folder"));
+                            ui.monospace(
+                                std::path::absolute(&root)
+                                    .unwrap_or_else(|_| root.clone())
+                                    .display()
+                                    .to_string(),
+                            );
+                        });
+                        if let Ok(free) = backup::available_space(&root) {
+                            ui.small(tr_args(
+                                "settings-storage-free",
+                                &[("size", backup::format_bytes(free).into())],
+                            ));
+                        }
+                        if crate::config::is_portable() {
+                            ui.small(tr("settings-storage-portable"));
+                        } else {
+                            ui.small(tr_args(
+                                "settings-storage-portable-hint",
+                                &[("marker", crate::config::PORTABLE_MARKER.into())],
+                            ));
+                        }
+                        let all_stopped = self.servers.iter().all(|s| {
+                            matches!(s.status, ServerStatus::Stopped | ServerStatus::Error(_))
                         });
                         ui.add_space(5.0);
+                        if ui
+                            .add_enabled(all_stopped, egui::Button::new(tr("settings-storage-move")))
+                            .on_disabled_hover_text(tr("settings-storage-move-stopped"))
+                            .clicked()
+                        {
+                            self.pick_data_root();
+                        }
+                    });
 
-                        ui.horizontal(|ui| {
-                            ui.label("API Key:");
-                            let response = ui.add(
-                                egui::TextEdit::singleline(&mut self.settings_cf_key_input)
-                                    .password(!self.settings_cf_key_visible)
-                                    .desired_width(300.0)
-                                    .hint_text("Paste your CurseForge API key here")
-                            );
+                    ui.add_space(10.0);
 
-                            // Show/hide toggle
-                            if ui.button("👁").on_hover_text("Show/hide key").clicked() {
-                                self.settings_cf_key_visible = !self.settings_cf_key_visible;
+                    // Keyboard shortcuts
+                    ui.group(|ui| {
+                        ui.strong(tr("settings-shortcuts"));
+                        ui.label(tr("settings-shortcuts-hint"));
+                        ui.add_space(5.0);
+
+                        if let Some(shortcut) = self.rebinding_shortcut {
+                            let captured = ctx.input(KeyBinding::capture);
+                            match captured {
+                                Some(b) if b.key == egui::Key::Escape.name() && !b.command && !b.shift && !b.alt => {
+                                    self.rebinding_shortcut = None;
+                                }
+                                Some(binding) => {
+                                    if let Some(other) = self.settings.shortcuts.conflict(shortcut, &binding) {
+                                        self.show_status_message(tr_args(
+                                            "msg-shortcut-taken",
+                                            &[("keys", binding.label(ctx).into()), ("action", other.label().into())],
+                                        ));
+                                    } else {
+                                        self.settings.shortcuts.set(shortcut, binding);
+                                        self.rebinding_shortcut = None;
+                                        if let Err(e) = save_settings(&self.settings) {
+                                            self.show_status_message(tr_args(
+                                                "msg-settings-save-failed",
+                                                &[("error", e.to_string().into())],
+                                            ));
+                                        }
+                                    }
+                                }
+                                None => {}
                             }
+                        }
 
-                            if response.changed() {
-                                // Update settings when text changes
-                                let key = self.settings_cf_key_input.trim().to_string();
-                                self.settings.curseforge_api_key = if key.is_empty() {
-                                    None
+                        let mut reset = None;
+                        egui::Grid::new("shortcuts_grid").num_columns(3).show(ui, |ui| {
+                            for shortcut in Shortcut::ALL {
+                                ui.label(shortcut.label());
+                                let waiting = self.rebinding_shortcut == Some(shortcut);
+                                let text = if waiting {
+                                    tr("settings-shortcuts-press")
                                 } else {
-                                    Some(key)
+                                    self.settings.shortcuts.get(shortcut).label(ctx)
                                 };
+                                if ui.selectable_label(waiting, text).clicked() {
+                                    self.rebinding_shortcut = (!waiting).then_some(shortcut);
+                                }
+                                if !self.settings.shortcuts.is_default(shortcut) && ui.small_button(tr("settings-reset")).clicked() {
+                                    reset = Some(shortcut);
+                                }
+                                ui.end_row();
                             }
                         });
+                        if let Some(shortcut) = reset {
+                            self.settings.shortcuts.reset(shortcut);
+                            if let Err(e) = save_settings(&self.settings) {
+                                self.show_status_message(tr_args(
+                                    "msg-settings-save-failed",
+                                    &[("error", e.to_string().into())],
+                                ));
+                            }
+                        }
+                    });
 
-                        // Status indicator
+                    ui.add_space(10.0);
+
+                    // Minecraft EULA
+                    ui.group(|ui| {
+                        ui.strong(tr("settings-eula"));
                         ui.horizontal(|ui| {
-                            if self.settings.curseforge_api_key.is_some() {
-                                ui.colored_label(egui::Color32::GREEN, "✓ API key configured");
-                            } else {
-                                ui.colored_label(egui::Color32::GRAY, "○ No API key set");
+                            match &self.settings.eula_accepted_at {
+                                Some(at) => {
+                                    ui.colored_label(egui::Color32::GREEN, tr_args(
+                                        "settings-eula-accepted",
+                                        &[("at", at.as_str().into())],
+                                    ));
+                                }
+                                None => {
+                                    ui.colored_label(egui::Color32::GRAY, tr("settings-eula-not-accepted"));
+                                }
                             }
                         });
-
-                        ui.add_space(5.0);
-                        if ui.button("Save Settings").clicked() {
-                            let key_newly_added = !self.settings_cf_key_was_set
-                                && self.settings.curseforge_api_key.is_some();
-                            if let Err(e) = save_settings(&self.settings) {
-                                self.show_status_message(format!("Failed to save settings: {}", e));
-                            } else if key_newly_added {
-                                self.settings_cf_key_was_set = true;
-                                self.show_status_message(
-                                    "Settings saved! Restart DrakonixAnvil for the CurseForge API key to take effect.".to_string(),
-                                );
-                            } else {
-                                self.show_status_message("Settings saved!".to_string());
-                            }
-                        }
+                        ui.hyperlink("https://aka.ms/MinecraftEULA");
                     });
 
                     ui.add_space(20.0);
                     ui.separator();
                     ui.add_space(10.0);
 
+                    ui.horizontal(|ui| {
+                        ui.label(tr("settings-instance-id"));
+                        ui.monospace(&self.settings.instance_id);
+                    })
+                    .response
+                    .on_hover_text(tr("settings-instance-id-hint"));
+                    ui.add_space(5.0);
+
                     // Info section
-                    ui.label("Note: After setting the API key, you'll need to recreate any CurseForge servers for the key to take effect.");
+                    ui.label(tr("settings-curseforge-recreate-note"));
                 }
                 View::Help => {
-                    ui.heading("Help & FAQ");
+                    ui.heading(tr("help-title"));
                     ui.add_space(10.0);
 
                     egui::ScrollArea::vertical().show(ui, |ui| {
+                        // Shortcut cheat-sheet
+                        ui.group(|ui| {
+                            ui.strong(tr("help-shortcuts"));
+                            ui.add_space(5.0);
+                            egui::Grid::new("help_shortcuts_grid").num_columns(2).show(ui, |ui| {
+                                for shortcut in Shortcut::ALL {
+                                    ui.monospace(self.settings.shortcuts.get(shortcut).label(ctx));
+                                    ui.label(shortcut.label());
+                                    ui.end_row();
+                                }
+                                ui.monospace("Enter");
+                                ui.label(tr("help-shortcut-enter"));
+                                ui.end_row();
+                                ui.monospace("Tab");
+                                ui.label(tr("help-shortcut-tab"));
+                                ui.end_row();
+                            });
+                            ui.small(tr("help-shortcuts-change"));
+                        });
+
+                        ui.add_space(10.0);
+
                         // Docker basics
                         ui.group(|ui| {
-                            ui.strong("What is Docker?");
+                            ui.strong(tr("help-docker-what"));
                             ui.add_space(5.0);
-                            ui.label("Docker is a tool that runs applications in isolated 'containers'.");
-                            ui.label("Think of it like a lightweight virtual machine.");
-                            ui.label("Each Minecraft server runs in its own container with its own Java version.");
+                            ui.label(tr("help-docker-what-body"));
                         });
 
                         ui.add_space(10.0);
 
                         ui.group(|ui| {
-                            ui.strong("Why do I need Docker?");
+                            ui.strong(tr("help-docker-why"));
                             ui.add_space(5.0);
-                            ui.label("Docker makes server management much easier:");
-                            ui.label("  • No need to install Java yourself");
-                            ui.label("  • Each server is isolated (can't break each other)");
-                            ui.label("  • Easy to run multiple servers with different versions");
-                            ui.label("  • Works the same on Windows, Mac, and Linux");
-                            ui.label("  • Clean uninstall - just delete the container");
+                            ui.label(tr("help-docker-why-body"));
                         });
 
                         ui.add_space(10.0);
 
                         ui.group(|ui| {
-                            ui.strong("How do I install Docker?");
+                            ui.strong(tr("help-docker-install"));
                             ui.add_space(5.0);
-                            ui.label("Windows/Mac:");
+                            ui.label(tr("help-docker-install-desktop"));
                             ui.horizontal(|ui| {
-                                ui.label("  Download Docker Desktop:");
+                                ui.label(tr("help-docker-install-download"));
                                 ui.hyperlink("https://www.docker.com/products/docker-desktop/");
                             });
                             ui.add_space(5.0);
-                            ui.label("Linux (Ubuntu/Debian):");
+                            ui.label(tr("help-docker-install-linux"));
                             ui.monospace("  sudo apt install docker.io");
                             ui.monospace("  sudo usermod -aG docker $USER");
-                            ui.label("  (Log out and back in after)");
+                            ui.label(tr("help-docker-install-relog"));
                         });
 
                         ui.add_space(10.0);
 
                         // File Locations
                         ui.group(|ui| {
-                            ui.strong("Where are my server files?");
+                            ui.strong(tr("help-files"));
                             ui.add_space(5.0);
-                            ui.label("All server data is stored in:");
+                            ui.label(tr("help-files-stored"));
                             ui.monospace("./DrakonixAnvilData/servers/<server-name>/data/");
+                            ui.small(tr("help-files-move"));
                             ui.add_space(5.0);
-                            ui.label("This includes:");
-                            ui.label("  • world/ - World saves");
-                            ui.label("  • mods/ - Installed mods");
-                            ui.label("  • config/ - Mod configurations");
-                            ui.label("  • server.properties - Server settings");
+                            ui.label(tr("help-files-includes"));
                         });
 
                         ui.add_space(10.0);
 
                         ui.group(|ui| {
-                            ui.strong("How do I edit server.properties?");
+                            ui.strong(tr("help-properties"));
                             ui.add_space(5.0);
-                            ui.label("1. Stop the server");
-                            ui.label("2. Edit the file with any text editor:");
+                            ui.label(tr("help-properties-steps"));
                             ui.monospace("  DrakonixAnvilData/servers/<name>/data/server.properties");
-                            ui.label("3. Start the server again");
+                            ui.label(tr("help-start-again"));
                         });
 
                         ui.add_space(10.0);
 
                         ui.group(|ui| {
-                            ui.strong("How do I add mods?");
+                            ui.strong(tr("help-mods"));
                             ui.add_space(5.0);
-                            ui.label("1. Stop the server");
-                            ui.label("2. Copy .jar files to:");
+                            ui.label(tr("help-mods-steps"));
                             ui.monospace("  DrakonixAnvilData/servers/<name>/data/mods/");
-                            ui.label("3. Start the server again");
+                            ui.label(tr("help-start-again"));
                         });
 
                         ui.add_space(10.0);
 
                         ui.group(|ui| {
-                            ui.strong("What happens when I delete a server?");
+                            ui.strong(tr("help-delete"));
                             ui.add_space(5.0);
-                            ui.label("Only the Docker container is removed.");
-                            ui.label("Your world data, mods, and configs are preserved.");
-                            ui.label("You can recreate the server to use them again.");
+                            ui.label(tr("help-delete-body"));
                         });
 
                         ui.add_space(10.0);
 
                         ui.group(|ui| {
-                            ui.strong("How do backups work?");
+                            ui.strong(tr("help-backups"));
                             ui.add_space(5.0);
-                            ui.label("Backups are zip files of the entire data/ folder.");
-                            ui.label("They include: world, mods, configs, scripts, etc.");
-                            ui.label("Stored in: DrakonixAnvilData/backups/<server-name>/");
+                            ui.label(tr("help-backups-body"));
                         });
 
                         ui.add_space(10.0);
 
                         ui.group(|ui| {
-                            ui.strong("RCON Console");
+                            ui.strong(tr("help-rcon"));
                             ui.add_space(5.0);
-                            ui.label("RCON lets you run server commands remotely.");
-                            ui.label("Each server has an auto-generated password.");
-                            ui.label("Common commands: list, say, op, whitelist, stop");
+                            ui.label(tr("help-rcon-body"));
                         });
 
                         ui.add_space(10.0);
 
                         ui.group(|ui| {
-                            ui.strong("Port Forwarding Basics");
+                            ui.strong(tr("help-port-forwarding"));
                             ui.add_space(5.0);
-                            ui.label("Port forwarding lets players outside your local network connect to your server.");
-                            ui.label("Without it, only devices on your home Wi-Fi can join.");
+                            ui.label(tr("help-port-forwarding-body"));
                             ui.add_space(3.0);
-                            ui.label("Steps to set up port forwarding:");
-                            ui.label("1. Find your router's admin page (usually 192.168.1.1 or 192.168.0.1)");
-                            ui.label("2. Log in (check your router for default credentials)");
-                            ui.label("3. Find 'Port Forwarding' or 'Virtual Servers' in the settings");
-                            ui.label("4. Forward TCP port 25565 (or your chosen port) to your PC's local IP");
+                            ui.label(tr("help-port-forwarding-steps"));
                             ui.add_space(3.0);
                             ui.horizontal_wrapped(|ui| {
-                                ui.label("Router-specific guides:");
+                                ui.label(tr("help-port-forwarding-guides"));
                                 ui.hyperlink_to("portforward.com", "https://portforward.com/router.htm");
                             });
                         });
@@ -3160,12 +11256,11 @@ impl eframe::App for DrakonixApp {
                         ui.add_space(10.0);
 
                         ui.group(|ui| {
-                            ui.strong("Testing Your Server Connection");
+                            ui.strong(tr("help-testing"));
                             ui.add_space(5.0);
-                            ui.label("After port forwarding, use an external tool to verify your server is reachable.");
-                            ui.label("Make sure your server is running first, then enter your public IP:port.");
+                            ui.label(tr("help-testing-body"));
                             ui.add_space(3.0);
-                            ui.label("Free server status checkers:");
+                            ui.label(tr("help-testing-checkers"));
                             ui.horizontal_wrapped(|ui| {
                                 ui.label("•");
                                 ui.hyperlink_to("Dinnerbone's MC Server Status", "https://dinnerbone.com/minecraft/tools/status/");
@@ -3183,18 +11278,17 @@ impl eframe::App for DrakonixApp {
                         ui.add_space(10.0);
 
                         ui.group(|ui| {
-                            ui.strong("Sharing Your Server with Friends");
+                            ui.strong(tr("help-sharing"));
                             ui.add_space(5.0);
                             ui.horizontal_wrapped(|ui| {
-                                ui.label("Find your public IP:");
+                                ui.label(tr("help-sharing-find-ip"));
                                 ui.hyperlink_to("ifconfig.me", "https://ifconfig.me/");
-                                ui.label("or Google 'what is my ip'");
+                                ui.label(tr("help-sharing-search-ip"));
                             });
                             ui.add_space(3.0);
-                            ui.label("Share your address as: <public-ip>:<port>");
-                            ui.label("If using the default port (25565), friends can connect with just the IP.");
+                            ui.label(tr("help-sharing-body"));
                             ui.add_space(3.0);
-                            ui.label("If your IP changes frequently, consider a free dynamic DNS service:");
+                            ui.label(tr("help-sharing-ddns"));
                             ui.horizontal_wrapped(|ui| {
                                 ui.label("•");
                                 ui.hyperlink_to("No-IP", "https://www.noip.com/");
@@ -3208,10 +11302,10 @@ impl eframe::App for DrakonixApp {
                         ui.add_space(10.0);
 
                         ui.group(|ui| {
-                            ui.strong("Need more help?");
+                            ui.strong(tr("help-more"));
                             ui.add_space(5.0);
                             ui.horizontal(|ui| {
-                                ui.label("Report issues:");
+                                ui.label(tr("help-report-issues"));
                                 ui.hyperlink("https://github.com/meltingscales/DrakonixAnvil/issues");
                             });
                         });
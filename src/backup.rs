@@ -1,14 +1,19 @@
 use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs::{self, File};
 use std::io::{Read, Write};
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::Sender;
 use walkdir::WalkDir;
 use zip::write::FileOptions;
 use zip::{CompressionMethod, ZipArchive, ZipWriter};
 
-use crate::config::{get_backup_path, get_server_data_path};
-use crate::server::ServerConfig;
+use crate::config::{get_backup_path, get_server_data_path, get_server_path, BackupCompression};
+use crate::server::{BackupFrequency, BackupRetention, ServerConfig};
+use chrono::{Datelike, Duration as ChronoDuration, NaiveDateTime};
+use regex::Regex;
 
 /// Progress update for backup/restore operations
 #[derive(Debug, Clone)]
@@ -25,22 +30,225 @@ pub struct BackupInfo {
     pub path: PathBuf,
     pub size_bytes: u64,
     pub created: std::time::SystemTime,
+    /// Full backup an incremental backup builds on; `None` for full backups
+    pub base: Option<String>,
+    /// Result of the last successful verification, if it has been verified
+    pub verified: Option<BackupVerification>,
+}
+
+/// Archive entry recording what the data directory held when a backup was taken.
+/// It's never extracted.
+const MANIFEST_NAME: &str = ".drakonix-backup.json";
+
+/// Size and modification time of a file, used to spot what changed since a backup
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+struct FileStamp {
+    size: u64,
+    /// Seconds since the Unix epoch
+    modified: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct BackupManifest {
+    /// File name of the full backup an incremental builds on
+    #[serde(default)]
+    base: Option<String>,
+    /// Exclude patterns the backup was taken with. Restores carry matching paths
+    /// over from the current data directory, since the backup doesn't have them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    excludes: Vec<String>,
+    /// Every entry of the data directory, keyed by `/`-separated relative path.
+    /// Directories and symlinks have an empty stamp.
+    entries: BTreeMap<String, FileStamp>,
+}
+
+fn file_stamp(entry: &walkdir::DirEntry) -> FileStamp {
+    let Ok(metadata) = entry.metadata() else {
+        return FileStamp::default();
+    };
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+        .map_or(0, |d| d.as_secs());
+    FileStamp {
+        size: metadata.len(),
+        modified,
+    }
+}
+
+fn read_manifest(archive: &mut ZipArchive<File>) -> Option<BackupManifest> {
+    let mut json = String::new();
+    archive
+        .by_name(MANIFEST_NAME)
+        .ok()?
+        .read_to_string(&mut json)
+        .ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+fn read_manifest_at(path: &Path) -> Option<BackupManifest> {
+    let file = File::open(path).ok()?;
+    read_manifest(&mut ZipArchive::new(file).ok()?)
+}
+
+/// Options for a regular file entry compressed as `compression`. Files of 4 GiB and
+/// up get zip64 headers; without them the writer refuses the entry.
+fn file_options(compression: BackupCompression, mode: u32, size: u64) -> FileOptions<'static, ()> {
+    let (method, level) = match compression {
+        BackupCompression::Store => (CompressionMethod::Stored, None),
+        BackupCompression::Deflate { level } => {
+            (CompressionMethod::Deflated, Some(level.clamp(1, 9)))
+        }
+        BackupCompression::Zstd { level } => (CompressionMethod::Zstd, Some(level.clamp(1, 22))),
+    };
+    FileOptions::<()>::default()
+        .compression_method(method)
+        .compression_level(level.map(i64::from))
+        .unix_permissions(mode)
+        .large_file(size >= u32::MAX as u64)
+}
+
+fn write_manifest(zip: &mut ZipWriter<File>, manifest: &BackupManifest) -> Result<()> {
+    let options = FileOptions::<()>::default().compression_method(CompressionMethod::Deflated);
+    zip.start_file(MANIFEST_NAME, options)
+        .context("Failed to start backup manifest")?;
+    zip.write_all(&serde_json::to_vec(manifest)?)
+        .context("Failed to write backup manifest")?;
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Exclude patterns
+// ---------------------------------------------------------------------------
+
+/// Common patterns for data that's rebuilt or re-downloaded anyway
+pub const SUGGESTED_EXCLUDES: &[&str] =
+    &["logs/**", "crash-reports/**", "cache/**", "libraries/**"];
+
+/// Regex source for one glob, matched against `/`-separated paths relative to the
+/// data directory. `*` and `?` stay within one folder, `**` spans any number, and a
+/// pattern without a `/` matches the name at any depth, e.g. `*.log`.
+fn glob_to_regex(pattern: &str) -> String {
+    let pattern = pattern.trim().trim_start_matches('/');
+    let mut regex = String::from("^");
+    if !pattern.contains('/') {
+        regex.push_str("(?:.*/)?");
+    }
+    let mut rest = pattern;
+    while let Some(c) = rest.chars().next() {
+        if let Some(after) = rest.strip_prefix("**/") {
+            regex.push_str("(?:.*/)?");
+            rest = after;
+        } else if rest == "/**" {
+            // The folder itself as well as everything in it
+            regex.push_str("(?:/.*)?");
+            rest = "";
+        } else if let Some(after) = rest.strip_prefix("**") {
+            regex.push_str(".*");
+            rest = after;
+        } else {
+            match c {
+                '*' => regex.push_str("[^/]*"),
+                '?' => regex.push_str("[^/]"),
+                c => regex.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+            }
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    regex.push('$');
+    regex
+}
+
+/// One regex matching any of `patterns`; `None` when there are none
+fn exclude_regex(patterns: &[String]) -> Option<Regex> {
+    let sources: Vec<String> = patterns
+        .iter()
+        .filter(|p| !p.trim().is_empty())
+        .map(|p| format!("(?:{})", glob_to_regex(p)))
+        .collect();
+    if sources.is_empty() {
+        return None;
+    }
+    // Every piece is escaped or built from fixed syntax, so this always compiles
+    Regex::new(&sources.join("|")).ok()
+}
+
+/// Move paths the backup excluded from the current data directory into `staging`,
+/// so a restore keeps them instead of dropping them with the rest of the old data
+fn carry_over_excluded(data_path: &Path, staging: &Path, excludes: &[String]) -> Result<()> {
+    let Some(exclude) = exclude_regex(excludes) else {
+        return Ok(());
+    };
+    if !data_path.exists() {
+        return Ok(());
+    }
+    let mut walk = WalkDir::new(data_path).follow_links(false).into_iter();
+    while let Some(entry) = walk.next() {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        let Ok(relative) = entry.path().strip_prefix(data_path) else {
+            continue;
+        };
+        let key = relative.to_string_lossy().replace('\\', "/");
+        if key.is_empty() || !exclude.is_match(&key) {
+            continue;
+        }
+        if entry.file_type().is_dir() {
+            walk.skip_current_dir();
+        }
+        let target = staging.join(relative);
+        if target.exists() {
+            continue;
+        }
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).context("Failed to create directory")?;
+        }
+        fs::rename(entry.path(), &target)
+            .with_context(|| format!("Failed to keep excluded {:?}", relative))?;
+    }
+    Ok(())
 }
 
 // ---------------------------------------------------------------------------
 // Internal helpers shared by backup, restore, export, and import
 // ---------------------------------------------------------------------------
 
-/// Walk `data_path` and add all files/dirs into the zip under `prefix`.
-/// Backup calls with `prefix=""`, export calls with `prefix="data/"`.
-fn zip_directory_with_progress(
-    zip: &mut ZipWriter<File>,
-    data_path: &Path,
-    prefix: &str,
-    progress_tx: Option<&Sender<BackupProgress>>,
-) -> Result<()> {
-    let entries: Vec<_> = WalkDir::new(data_path)
+/// Default permissions used when the source filesystem doesn't expose Unix modes.
+const DEFAULT_FILE_MODE: u32 = 0o644;
+const DEFAULT_DIR_MODE: u32 = 0o755;
+
+/// Permission bits for a walked entry, falling back to `default` off Unix.
+fn entry_mode(entry: &walkdir::DirEntry, default: u32) -> u32 {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if let Ok(metadata) = entry.metadata() {
+            return metadata.permissions().mode() & 0o777;
+        }
+    }
+    let _ = entry;
+    default
+}
+
+/// Everything under `data_path` that goes into a backup, i.e. all but the root
+/// itself and paths matching `excludes`. Symlinks are listed, not followed.
+fn backup_entries(data_path: &Path, excludes: &[String]) -> Vec<walkdir::DirEntry> {
+    let exclude = exclude_regex(excludes);
+    WalkDir::new(data_path)
+        .follow_links(false)
         .into_iter()
+        // Excluded folders aren't walked at all, which is where the time goes
+        .filter_entry(|e| {
+            let Some(exclude) = &exclude else {
+                return true;
+            };
+            e.path().strip_prefix(data_path).map_or(true, |p| {
+                p.as_os_str().is_empty()
+                    || !exclude.is_match(&p.to_string_lossy().replace('\\', "/"))
+            })
+        })
         .filter_map(|e| e.ok())
         .filter(|e| {
             !e.path()
@@ -48,15 +256,120 @@ fn zip_directory_with_progress(
                 .map(|p| p.as_os_str().is_empty())
                 .unwrap_or(true)
         })
-        .collect();
-    let total_files = entries.len();
+        .collect()
+}
 
-    let file_options = FileOptions::<()>::default()
-        .compression_method(CompressionMethod::Deflated)
-        .unix_permissions(0o644);
-    let dir_options = FileOptions::<()>::default()
-        .compression_method(CompressionMethod::Stored)
-        .unix_permissions(0o755);
+/// Share of the original size a backup is assumed to keep when there's no earlier
+/// backup to go by. Region files are compressed already, so worlds shrink little.
+fn assumed_ratio(compression: BackupCompression) -> f64 {
+    match compression {
+        BackupCompression::Store => 1.0,
+        BackupCompression::Deflate { .. } | BackupCompression::Zstd { .. } => 0.8,
+    }
+}
+
+/// Refuse to start a backup that won't fit on the backup drive, instead of failing
+/// partway through. The data size (only changed files for an incremental) is scaled
+/// by the ratio the newest full backup achieved, never below `assumed_ratio`.
+fn ensure_space_for_backup(
+    server_name: &str,
+    data_path: &Path,
+    backup_dir: &Path,
+    compression: BackupCompression,
+    excludes: &[String],
+    unchanged: Option<&BackupManifest>,
+) -> Result<()> {
+    let data_size: u64 = backup_entries(data_path, excludes)
+        .iter()
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            let stamp = file_stamp(e);
+            let key = e
+                .path()
+                .strip_prefix(data_path)
+                .ok()?
+                .to_string_lossy()
+                .replace('\\', "/");
+            let same = unchanged.is_some_and(|m| m.entries.get(&key) == Some(&stamp));
+            (!same).then_some(stamp.size)
+        })
+        .sum();
+
+    let previous_ratio = list_backups(server_name)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|b| !is_incremental_backup(&b.filename))
+        .find_map(|b| {
+            let source: u64 = read_manifest_at(&b.path)?
+                .entries
+                .values()
+                .map(|s| s.size)
+                .sum();
+            (source > 0).then(|| b.size_bytes as f64 / source as f64)
+        });
+    let ratio = previous_ratio
+        .unwrap_or(0.0)
+        .clamp(assumed_ratio(compression), 1.0);
+    // Headers and the manifest on top of the file data
+    let estimate = (data_size as f64 * ratio * 1.05) as u64 + 1024 * 1024;
+
+    let available = available_space(backup_dir)?;
+    if estimate > available {
+        anyhow::bail!(
+            "Not enough free disk space for this backup: it needs about {} ({} of data), \
+             only {} free for backups",
+            format_bytes(estimate),
+            format_bytes(data_size),
+            format_bytes(available)
+        );
+    }
+    Ok(())
+}
+
+/// Build a backup zip under a `.partial` name and only rename it to `backup_path`
+/// once it's complete, so a backup that fails partway never passes for a good one
+fn write_backup_zip(
+    backup_path: &Path,
+    write: impl FnOnce(&mut ZipWriter<File>) -> Result<()>,
+) -> Result<()> {
+    let partial = backup_path.with_extension("zip.partial");
+    let result = (|| {
+        let file = File::create(&partial).context("Failed to create backup file")?;
+        let mut zip = ZipWriter::new(file);
+        write(&mut zip)?;
+        zip.finish().context("Failed to finalize zip file")?;
+        fs::rename(&partial, backup_path).context("Failed to move the finished backup in place")
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&partial);
+    }
+    result
+}
+
+/// Walk `data_path` and add all files/dirs into the zip under `prefix`.
+/// Backup calls with `prefix=""`, export calls with `prefix="data/"`.
+/// Regular files whose stamp matches `unchanged` are left out (incremental backups).
+/// Returns a manifest of everything that was in the directory.
+///
+/// Symlinks are stored as symlink entries (never followed), and Unix permission
+/// bits are preserved so start scripts stay executable. Sockets, FIFOs, and device
+/// nodes can't be meaningfully archived, so they are skipped with a warning.
+fn zip_directory_with_progress(
+    zip: &mut ZipWriter<File>,
+    data_path: &Path,
+    prefix: &str,
+    compression: BackupCompression,
+    excludes: &[String],
+    unchanged: Option<&BackupManifest>,
+    progress_tx: Option<&Sender<BackupProgress>>,
+) -> Result<BackupManifest> {
+    let mut manifest = BackupManifest {
+        excludes: excludes.to_vec(),
+        ..Default::default()
+    };
+    let entries = backup_entries(data_path, excludes);
+    let total_files = entries.len();
+    let mut skipped_special = 0usize;
 
     for (idx, entry) in entries.iter().enumerate() {
         let path = entry.path();
@@ -65,6 +378,7 @@ fn zip_directory_with_progress(
             .context("Failed to get relative path")?;
 
         let path_str = format!("{}{}", prefix, relative_path.to_string_lossy());
+        let manifest_key = relative_path.to_string_lossy().replace('\\', "/");
 
         if let Some(tx) = progress_tx {
             let _ = tx.send(BackupProgress {
@@ -74,37 +388,186 @@ fn zip_directory_with_progress(
             });
         }
 
-        if path.is_dir() {
+        let file_type = entry.file_type();
+        let stamp = if file_type.is_file() {
+            file_stamp(entry)
+        } else {
+            FileStamp::default()
+        };
+        if file_type.is_file() || file_type.is_dir() || file_type.is_symlink() {
+            manifest.entries.insert(manifest_key.clone(), stamp);
+        }
+        if file_type.is_file()
+            && unchanged.is_some_and(|m| m.entries.get(&manifest_key) == Some(&stamp))
+        {
+            continue;
+        }
+
+        if file_type.is_symlink() {
+            let target = fs::read_link(path)
+                .with_context(|| format!("Failed to read symlink: {:?}", path))?;
+            let options = FileOptions::<()>::default().unix_permissions(0o777);
+            zip.add_symlink(&path_str, target.to_string_lossy(), options)
+                .context("Failed to add symlink to zip")?;
+        } else if file_type.is_dir() {
+            let dir_options = FileOptions::<()>::default()
+                .compression_method(CompressionMethod::Stored)
+                .unix_permissions(entry_mode(entry, DEFAULT_DIR_MODE));
             zip.add_directory(&path_str, dir_options)
                 .context("Failed to add directory to zip")?;
-        } else {
+        } else if file_type.is_file() {
+            let file_options = file_options(
+                compression,
+                entry_mode(entry, DEFAULT_FILE_MODE),
+                stamp.size,
+            );
             zip.start_file(&path_str, file_options)
                 .context("Failed to start file in zip")?;
 
+            // Streamed, so multi-gigabyte region files never sit in memory whole
             let mut file = File::open(path).context("Failed to open file for backup")?;
-            let mut buffer = Vec::new();
-            file.read_to_end(&mut buffer)
-                .context("Failed to read file")?;
-            zip.write_all(&buffer)
-                .context("Failed to write file to zip")?;
+            let written = std::io::copy(&mut file, zip)
+                .with_context(|| format!("Failed to write {:?} to zip", path))?;
+            // A file still being written can grow after the walk; verify against what was stored
+            manifest.entries.insert(
+                manifest_key,
+                FileStamp {
+                    size: written,
+                    ..stamp
+                },
+            );
+        } else {
+            tracing::warn!("Skipping special file (socket/FIFO/device): {:?}", path);
+            skipped_special += 1;
         }
     }
 
+    if skipped_special > 0 {
+        tracing::warn!(
+            "Skipped {} special file(s) that cannot be archived",
+            skipped_special
+        );
+    }
+
+    Ok(manifest)
+}
+
+/// Whether a symlink at `link_relative` pointing to `target` stays inside the extraction root.
+/// Absolute targets and targets that climb above the root are rejected.
+fn symlink_target_is_contained(link_relative: &Path, target: &str) -> bool {
+    let target = Path::new(target);
+    if target.is_absolute() {
+        return false;
+    }
+
+    // Depth of the directory containing the link, relative to the root
+    let mut depth: i64 = link_relative
+        .parent()
+        .map(|p| p.components().count() as i64)
+        .unwrap_or(0);
+
+    for component in target.components() {
+        match component {
+            std::path::Component::ParentDir => depth -= 1,
+            std::path::Component::Normal(_) => depth += 1,
+            std::path::Component::CurDir => {}
+            _ => return false,
+        }
+        if depth < 0 {
+            return false;
+        }
+    }
+    true
+}
+
+/// Refuse to write `relative` under `dest_path` through a symlink: an earlier entry (or
+/// layer) could have planted a link that leads outside the data directory. A symlink at
+/// `relative` itself is removed so the entry replaces it rather than writing through it.
+fn ensure_no_symlink_ancestors(dest_path: &Path, relative: &Path) -> Result<()> {
+    let is_symlink =
+        |path: &Path| fs::symlink_metadata(path).is_ok_and(|m| m.file_type().is_symlink());
+    let mut path = dest_path.to_path_buf();
+    if let Some(parent) = relative.parent() {
+        for component in parent.components() {
+            path.push(component);
+            if is_symlink(&path) {
+                anyhow::bail!(
+                    "Refusing to extract {:?}: {:?} is a symlink",
+                    relative,
+                    path.strip_prefix(dest_path).unwrap_or(&path)
+                );
+            }
+        }
+    }
+    let outpath = dest_path.join(relative);
+    if is_symlink(&outpath) {
+        fs::remove_file(&outpath)
+            .with_context(|| format!("Failed to replace existing symlink: {:?}", outpath))?;
+    }
+    Ok(())
+}
+
+/// Recreate a symlink entry from an archive. On non-Unix platforms symlinks are skipped.
+fn restore_symlink(dest_path: &Path, relative: &Path, target: &str) -> Result<()> {
+    if !symlink_target_is_contained(relative, target) {
+        tracing::warn!(
+            "Skipping symlink {:?} -> {:?}: target points outside the data directory",
+            relative,
+            target
+        );
+        return Ok(());
+    }
+
+    ensure_no_symlink_ancestors(dest_path, relative)?;
+    let outpath = dest_path.join(relative);
+    if let Some(parent) = outpath.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create parent directory: {:?}", parent))?;
+    }
+    if fs::symlink_metadata(&outpath).is_ok() {
+        fs::remove_file(&outpath)
+            .with_context(|| format!("Failed to replace existing file: {:?}", outpath))?;
+    }
+
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(target, &outpath)
+            .with_context(|| format!("Failed to create symlink: {:?}", outpath))?;
+    }
+    #[cfg(not(unix))]
+    {
+        tracing::warn!(
+            "Skipping symlink {:?} -> {:?}: symlinks are not restored on this platform",
+            relative,
+            target
+        );
+    }
+
     Ok(())
 }
 
 /// Extract entries from a zip into `dest_path`.
 /// If `strip_prefix` is `Some("data/")`, only entries starting with that prefix are extracted
 /// and the prefix is removed from their path.
+/// `cancel` is checked before each entry so a long restore can be aborted cleanly.
+/// Nothing is written through a symlink, and symlinks are created only once every
+/// other entry is in place.
 fn extract_zip_with_progress(
     archive: &mut ZipArchive<File>,
     dest_path: &Path,
     strip_prefix: Option<&str>,
+    include: Option<&dyn Fn(&Path) -> bool>,
     progress_tx: Option<&Sender<BackupProgress>>,
+    cancel: Option<&AtomicBool>,
 ) -> Result<()> {
     let total_entries = archive.len();
+    let mut symlinks = Vec::new();
 
     for i in 0..total_entries {
+        if cancel.is_some_and(|c| c.load(Ordering::Relaxed)) {
+            anyhow::bail!("Cancelled by user");
+        }
+
         let mut file = archive.by_index(i).context("Failed to read zip entry")?;
 
         let enclosed = match file.enclosed_name() {
@@ -131,8 +594,11 @@ fn extract_zip_with_progress(
             enclosed.clone()
         };
 
-        // Skip empty relative paths
-        if relative.as_os_str().is_empty() {
+        // Skip empty relative paths, the backup manifest and anything not selected
+        if relative.as_os_str().is_empty()
+            || relative == Path::new(MANIFEST_NAME)
+            || include.is_some_and(|include| !include(&relative))
+        {
             continue;
         }
 
@@ -148,6 +614,15 @@ fn extract_zip_with_progress(
             });
         }
 
+        if file.is_symlink() {
+            let mut target = String::new();
+            file.read_to_string(&mut target)
+                .with_context(|| format!("Failed to read symlink target: {:?}", outpath))?;
+            symlinks.push((relative, target));
+            continue;
+        }
+
+        ensure_no_symlink_ancestors(dest_path, &relative)?;
         if file.is_dir() {
             fs::create_dir_all(&outpath)
                 .with_context(|| format!("Failed to create directory: {:?}", outpath))?;
@@ -176,6 +651,92 @@ fn extract_zip_with_progress(
         }
     }
 
+    for (relative, target) in symlinks {
+        restore_symlink(dest_path, &relative, &target)?;
+    }
+
+    Ok(())
+}
+
+/// Total uncompressed size of the entries that would be extracted.
+fn archive_uncompressed_size(archive: &mut ZipArchive<File>, strip_prefix: Option<&str>) -> u64 {
+    (0..archive.len())
+        .filter_map(|i| {
+            archive
+                .by_index_raw(i)
+                .ok()
+                .map(|f| (f.name().to_string(), f.size()))
+        })
+        .filter(|(name, _)| strip_prefix.map(|p| name.starts_with(p)).unwrap_or(true))
+        .map(|(_, size)| size)
+        .sum()
+}
+
+/// Free space on the filesystem holding `path` (or its nearest existing ancestor).
+pub fn available_space(path: &Path) -> Result<u64> {
+    let probe = path
+        .ancestors()
+        .find(|p| !p.as_os_str().is_empty() && p.exists())
+        .unwrap_or(Path::new("."));
+    fs2::available_space(probe)
+        .with_context(|| format!("Failed to query free space for {:?}", probe))
+}
+
+/// Refuse to start an extraction that can't fit on the destination disk.
+fn ensure_space_for_extract(
+    archive: &mut ZipArchive<File>,
+    dest_path: &Path,
+    strip_prefix: Option<&str>,
+) -> Result<()> {
+    let required = archive_uncompressed_size(archive, strip_prefix);
+    let available = available_space(dest_path)?;
+    if required > available {
+        anyhow::bail!(
+            "Not enough free disk space: need {}, only {} available",
+            format_bytes(required),
+            format_bytes(available)
+        );
+    }
+    Ok(())
+}
+
+/// Let `fill` extract into a sibling staging directory, then swap it into place only
+/// once every entry succeeded. On failure or cancellation the staging directory is
+/// removed and the existing `dest_path` is left untouched.
+fn extract_staged(dest_path: &Path, fill: impl FnOnce(&Path) -> Result<()>) -> Result<()> {
+    let staging = dest_path.with_extension("restoring");
+    let previous = dest_path.with_extension("previous");
+
+    // Leftovers from an earlier interrupted run
+    if staging.exists() {
+        fs::remove_dir_all(&staging).context("Failed to clear stale staging directory")?;
+    }
+    fs::create_dir_all(&staging).context("Failed to create staging directory")?;
+
+    if let Err(e) = fill(&staging) {
+        tracing::warn!("Extraction failed, cleaning up partial data: {}", e);
+        fs::remove_dir_all(&staging).ok();
+        return Err(e);
+    }
+
+    if previous.exists() {
+        fs::remove_dir_all(&previous).context("Failed to clear old data directory")?;
+    }
+    if dest_path.exists() {
+        fs::rename(dest_path, &previous).context("Failed to move existing data aside")?;
+    }
+    if let Err(e) = fs::rename(&staging, dest_path) {
+        // Put the original data back so the server isn't left without a data dir
+        if previous.exists() {
+            fs::rename(&previous, dest_path).ok();
+        }
+        fs::remove_dir_all(&staging).ok();
+        return Err(e).context("Failed to move restored data into place");
+    }
+    if previous.exists() {
+        fs::remove_dir_all(&previous).ok();
+    }
+
     Ok(())
 }
 
@@ -187,13 +748,59 @@ fn extract_zip_with_progress(
 /// Returns the path to the created backup file
 #[allow(dead_code)]
 pub fn create_backup(server_name: &str) -> Result<PathBuf> {
-    create_backup_with_progress(server_name, None)
+    create_backup_with_progress(server_name, BackupCompression::default(), &[], None)
 }
 
-/// Create a backup with optional progress reporting
-/// The progress sender receives updates as files are processed
+/// Create a backup with optional progress reporting, leaving out paths matching
+/// any of `excludes`. The progress sender receives updates as files are processed
 pub fn create_backup_with_progress(
     server_name: &str,
+    compression: BackupCompression,
+    excludes: &[String],
+    progress_tx: Option<Sender<BackupProgress>>,
+) -> Result<PathBuf> {
+    write_backup(server_name, None, compression, excludes, progress_tx)
+}
+
+/// Create a backup whose filename is tagged with `label` (e.g. `20250101_120000_frozen.zip`)
+/// so it stands out in the backups list
+pub fn create_labeled_backup_with_progress(
+    server_name: &str,
+    label: &str,
+    compression: BackupCompression,
+    excludes: &[String],
+    progress_tx: Option<Sender<BackupProgress>>,
+) -> Result<PathBuf> {
+    write_backup(server_name, Some(label), compression, excludes, progress_tx)
+}
+
+fn write_backup(
+    server_name: &str,
+    label: Option<&str>,
+    compression: BackupCompression,
+    excludes: &[String],
+    progress_tx: Option<Sender<BackupProgress>>,
+) -> Result<PathBuf> {
+    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    let backup_filename = match label {
+        Some(label) => format!("{}_{}.zip", timestamp, label),
+        None => format!("{}.zip", timestamp),
+    };
+    create_named_backup_with_progress(
+        server_name,
+        &backup_filename,
+        compression,
+        excludes,
+        progress_tx,
+    )
+}
+
+/// Create a backup with an exact file name, used when several servers must share one
+pub fn create_named_backup_with_progress(
+    server_name: &str,
+    backup_filename: &str,
+    compression: BackupCompression,
+    excludes: &[String],
     progress_tx: Option<Sender<BackupProgress>>,
 ) -> Result<PathBuf> {
     let data_path = get_server_data_path(server_name);
@@ -203,22 +810,450 @@ pub fn create_backup_with_progress(
         anyhow::bail!("Server data directory does not exist: {:?}", data_path);
     }
 
+    ensure_space_for_backup(
+        server_name,
+        &data_path,
+        &backup_dir,
+        compression,
+        excludes,
+        None,
+    )?;
     fs::create_dir_all(&backup_dir).context("Failed to create backup directory")?;
 
-    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
-    let backup_filename = format!("{}.zip", timestamp);
-    let backup_path = backup_dir.join(&backup_filename);
+    let backup_path = backup_dir.join(backup_filename);
+
+    write_backup_zip(&backup_path, |zip| {
+        let manifest = zip_directory_with_progress(
+            zip,
+            &data_path,
+            "",
+            compression,
+            excludes,
+            None,
+            progress_tx.as_ref(),
+        )?;
+        write_manifest(zip, &manifest)
+    })?;
 
-    let file = File::create(&backup_path).context("Failed to create backup file")?;
-    let mut zip = ZipWriter::new(file);
+    Ok(backup_path)
+}
+
+// ---------------------------------------------------------------------------
+// Incremental backups
+// ---------------------------------------------------------------------------
+
+/// Label on incremental backups, e.g. `20250101_120000_incremental.zip`
+const INCREMENTAL_LABEL: &str = "incremental";
+
+pub fn is_incremental_backup(filename: &str) -> bool {
+    filename
+        .strip_suffix(".zip")
+        .is_some_and(|stem| stem.ends_with(&format!("_{}", INCREMENTAL_LABEL)))
+}
+
+/// Back up only the files added or changed since the newest full backup. Restoring
+/// it replays the full backup first, then this one, then removes files that had
+/// been deleted by the time it was taken.
+pub fn create_incremental_backup_with_progress(
+    server_name: &str,
+    compression: BackupCompression,
+    excludes: &[String],
+    progress_tx: Option<Sender<BackupProgress>>,
+) -> Result<PathBuf> {
+    let data_path = get_server_data_path(server_name);
+    if !data_path.exists() {
+        anyhow::bail!("Server data directory does not exist: {:?}", data_path);
+    }
 
-    zip_directory_with_progress(&mut zip, &data_path, "", progress_tx.as_ref())?;
+    // Backups from before manifests were recorded can't be compared against
+    let (base, base_manifest) = list_backups(server_name)?
+        .into_iter()
+        .filter(|b| !is_incremental_backup(&b.filename))
+        .find_map(|b| Some((b.filename, read_manifest_at(&b.path)?)))
+        .context("No full backup to build on yet, take a full backup first")?;
 
-    zip.finish().context("Failed to finalize zip file")?;
+    let backup_dir = get_backup_path(server_name);
+    ensure_space_for_backup(
+        server_name,
+        &data_path,
+        &backup_dir,
+        compression,
+        excludes,
+        Some(&base_manifest),
+    )?;
+    let backup_filename = format!(
+        "{}_{}.zip",
+        chrono::Local::now().format("%Y%m%d_%H%M%S"),
+        INCREMENTAL_LABEL
+    );
+    let backup_path = backup_dir.join(backup_filename);
+
+    write_backup_zip(&backup_path, |zip| {
+        let mut manifest = zip_directory_with_progress(
+            zip,
+            &data_path,
+            "",
+            compression,
+            excludes,
+            Some(&base_manifest),
+            progress_tx.as_ref(),
+        )?;
+        manifest.base = Some(base);
+        write_manifest(zip, &manifest)
+    })?;
 
     Ok(backup_path)
 }
 
+/// Incremental backups next to `backup_path` that need it to be restored
+fn dependent_backups(backup_path: &Path) -> Vec<String> {
+    let Some(filename) = backup_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+    else {
+        return Vec::new();
+    };
+    let Ok(entries) = backup_path.parent().map_or(Ok(Vec::new()), |dir| {
+        fs::read_dir(dir).map(|rd| rd.flatten().collect::<Vec<_>>())
+    }) else {
+        return Vec::new();
+    };
+    entries
+        .into_iter()
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .filter(|name| is_incremental_backup(name))
+        .filter(|name| {
+            read_manifest_at(&backup_path.with_file_name(name))
+                .is_some_and(|m| m.base.as_deref() == Some(filename.as_str()))
+        })
+        .collect()
+}
+
+/// Delete whatever the restored layers left in `root` that isn't in `manifest`,
+/// i.e. files removed between the full backup and the incremental one
+fn remove_unlisted(root: &Path, manifest: &BackupManifest) -> Result<()> {
+    for entry in WalkDir::new(root)
+        .follow_links(false)
+        .contents_first(true)
+        .into_iter()
+        .filter_map(|e| e.ok())
+    {
+        let Ok(relative) = entry.path().strip_prefix(root) else {
+            continue;
+        };
+        let key = relative.to_string_lossy().replace('\\', "/");
+        if key.is_empty() || manifest.entries.contains_key(&key) {
+            continue;
+        }
+        if entry.file_type().is_dir() {
+            fs::remove_dir_all(entry.path())
+        } else {
+            fs::remove_file(entry.path())
+        }
+        .with_context(|| format!("Failed to remove deleted file: {:?}", relative))?;
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Verification
+// ---------------------------------------------------------------------------
+
+/// Suffix of the file recording that a backup passed verification,
+/// e.g. `20250101_120000.zip.verified`
+const VERIFIED_SUFFIX: &str = ".verified";
+
+/// What a successful verification checked
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupVerification {
+    /// RFC 3339
+    pub verified_at: String,
+    pub files: usize,
+    pub bytes: u64,
+    /// Whether entries were also compared with the manifest of the data directory.
+    /// Backups from before manifests were recorded only get their CRCs checked.
+    pub compared: bool,
+}
+
+fn verified_marker(backup_path: &Path) -> PathBuf {
+    let mut name = backup_path.file_name().unwrap_or_default().to_os_string();
+    name.push(VERIFIED_SUFFIX);
+    backup_path.with_file_name(name)
+}
+
+fn read_verification(backup_path: &Path) -> Option<BackupVerification> {
+    serde_json::from_str(&fs::read_to_string(verified_marker(backup_path)).ok()?).ok()
+}
+
+/// Re-open a backup and read every file back, which runs the zip's CRC-32 check on
+/// each, then compare the entries and their sizes with the manifest recorded from
+/// the data directory while the backup was written. Passing backups get a marker
+/// next to them that `list_backups` reports.
+pub fn verify_backup(
+    backup_path: &Path,
+    progress_tx: Option<Sender<BackupProgress>>,
+) -> Result<BackupVerification> {
+    let _ = fs::remove_file(verified_marker(backup_path));
+    let file = File::open(backup_path).context("Failed to open backup")?;
+    let mut archive = ZipArchive::new(file).context("Backup is not a valid zip")?;
+
+    let manifest = read_manifest(&mut archive);
+    // An incremental only holds what differs from its base
+    let base = match manifest.as_ref().and_then(|m| m.base.as_ref()) {
+        Some(base) => Some(
+            read_manifest_at(&backup_path.with_file_name(base))
+                .with_context(|| format!("Base backup {} is missing or unreadable", base))?,
+        ),
+        None => None,
+    };
+    let mut expected: Option<BTreeMap<&str, FileStamp>> = manifest.as_ref().map(|m| {
+        m.entries
+            .iter()
+            .filter(|(key, stamp)| {
+                **stamp == FileStamp::default()
+                    || base
+                        .as_ref()
+                        .is_none_or(|b| b.entries.get(key.as_str()) != Some(*stamp))
+            })
+            .map(|(key, stamp)| (key.as_str(), *stamp))
+            .collect()
+    });
+
+    let total_entries = archive.len();
+    let mut files = 0usize;
+    let mut bytes = 0u64;
+    for i in 0..total_entries {
+        let mut entry = archive.by_index(i).context("Failed to read zip entry")?;
+        let name = entry.name().replace('\\', "/");
+        if let Some(tx) = &progress_tx {
+            let _ = tx.send(BackupProgress {
+                current: i + 1,
+                total: total_entries,
+                current_file: name.clone(),
+            });
+        }
+        if name == MANIFEST_NAME {
+            continue;
+        }
+
+        let key = name.trim_end_matches('/');
+        let stamp = match expected.as_mut() {
+            Some(expected) => Some(
+                expected
+                    .remove(key)
+                    .with_context(|| format!("{} is in the archive but not the manifest", key))?,
+            ),
+            None => None,
+        };
+        if entry.is_dir() || entry.is_symlink() {
+            continue;
+        }
+
+        // Reading to the end is what makes the zip reader compare the CRC
+        let read = std::io::copy(&mut entry, &mut std::io::sink())
+            .with_context(|| format!("{} is corrupt", key))?;
+        if let Some(stamp) = stamp.filter(|s| s.size != read) {
+            anyhow::bail!(
+                "{} holds {} but the source file was {}",
+                key,
+                format_bytes(read),
+                format_bytes(stamp.size)
+            );
+        }
+        files += 1;
+        bytes += read;
+    }
+
+    if let Some(missing) = expected.filter(|e| !e.is_empty()) {
+        let names: Vec<&str> = missing.keys().take(5).copied().collect();
+        anyhow::bail!(
+            "{} file(s) from the source are missing, e.g. {}",
+            missing.len(),
+            names.join(", ")
+        );
+    }
+
+    let verification = BackupVerification {
+        verified_at: chrono::Local::now().to_rfc3339(),
+        files,
+        bytes,
+        compared: manifest.is_some(),
+    };
+    fs::write(
+        verified_marker(backup_path),
+        serde_json::to_string_pretty(&verification)?,
+    )
+    .context("Failed to record verification")?;
+    Ok(verification)
+}
+
+// ---------------------------------------------------------------------------
+// Group snapshots (servers sharing state, e.g. a proxy network's economy)
+// ---------------------------------------------------------------------------
+
+/// Marker in the file name of backups taken as part of a group snapshot
+const GROUP_MARKER: &str = "_group-";
+
+/// File name shared by every member of one group snapshot, e.g.
+/// `20250101_120000_group-survival-net.zip`. The same name in each member's
+/// backup folder is what ties the set together.
+pub fn group_backup_filename(group: &str) -> String {
+    let slug: String = group
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    format!(
+        "{}{}{}.zip",
+        chrono::Local::now().format("%Y%m%d_%H%M%S"),
+        GROUP_MARKER,
+        slug
+    )
+}
+
+pub fn is_group_backup(filename: &str) -> bool {
+    filename.contains(GROUP_MARKER)
+}
+
+/// The members of `members` that have a backup named `filename`
+pub fn group_backup_set(members: &[String], filename: &str) -> Vec<(String, PathBuf)> {
+    members
+        .iter()
+        .map(|m| (m.clone(), get_backup_path(m).join(filename)))
+        .filter(|(_, path)| path.is_file())
+        .collect()
+}
+
+/// Open every archive in a set before anything is restored, so a missing or
+/// corrupt member stops the whole set instead of leaving it half rolled back
+pub fn verify_backup_set(set: &[(String, PathBuf)]) -> Result<()> {
+    for (server, path) in set {
+        let file =
+            File::open(path).with_context(|| format!("Failed to open backup for '{}'", server))?;
+        let mut archive = ZipArchive::new(file)
+            .with_context(|| format!("Backup for '{}' is not a valid zip", server))?;
+        ensure_space_for_extract(&mut archive, &get_server_data_path(server), None)?;
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Scheduled backups and retention
+// ---------------------------------------------------------------------------
+
+/// Label on backups taken by a server's backup schedule. Only these are pruned;
+/// manual, frozen and group backups stay until deleted by hand.
+pub const SCHEDULED_LABEL: &str = "scheduled";
+
+pub fn is_scheduled_backup(filename: &str) -> bool {
+    filename
+        .strip_suffix(".zip")
+        .is_some_and(|stem| stem.ends_with(&format!("_{}", SCHEDULED_LABEL)))
+}
+
+/// When a backup was taken, from the `20250101_120000` local time its name starts with
+pub fn backup_time(filename: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(filename.get(..15)?, "%Y%m%d_%H%M%S").ok()
+}
+
+/// Newest scheduled backup of a server
+fn last_scheduled_backup(server_name: &str) -> Option<NaiveDateTime> {
+    list_backups(server_name)
+        .ok()?
+        .iter()
+        .filter(|b| is_scheduled_backup(&b.filename))
+        .filter_map(|b| backup_time(&b.filename))
+        .max()
+}
+
+/// When the next scheduled backup of a server is due. A time in the past means it's
+/// due now, including a daily slot that was missed while the app was closed.
+pub fn next_scheduled_backup(
+    server_name: &str,
+    frequency: BackupFrequency,
+    now: NaiveDateTime,
+) -> NaiveDateTime {
+    let Some(last) = last_scheduled_backup(server_name) else {
+        return now;
+    };
+    match frequency {
+        BackupFrequency::EveryHours(hours) => last + ChronoDuration::hours(hours.max(1) as i64),
+        BackupFrequency::DailyAt { hour, minute } => {
+            let slot = |date: chrono::NaiveDate| {
+                date.and_hms_opt(hour.min(23) as u32, minute.min(59) as u32, 0)
+                    .unwrap_or(date.and_time(chrono::NaiveTime::MIN))
+            };
+            let same_day = slot(last.date());
+            if same_day > last {
+                same_day
+            } else {
+                slot(last.date() + ChronoDuration::days(1))
+            }
+        }
+    }
+}
+
+/// Which of `times` (newest first) `retention` keeps. The newest is always kept.
+fn retained(times: &[NaiveDateTime], retention: &BackupRetention, now: NaiveDateTime) -> Vec<bool> {
+    let today = now.date();
+    let this_week = today - ChronoDuration::days(today.weekday().num_days_from_monday() as i64);
+    let mut days_kept = Vec::new();
+    let mut weeks_kept = Vec::new();
+
+    times
+        .iter()
+        .enumerate()
+        .map(|(i, time)| {
+            let date = time.date();
+            let week = date - ChronoDuration::days(date.weekday().num_days_from_monday() as i64);
+            let mut keep = i < retention.keep_last.max(1) as usize;
+            // Backups are newest first, so the first one seen for a day or week is its newest
+            if (today - date).num_days() < retention.keep_daily_days as i64
+                && !days_kept.contains(&date)
+            {
+                days_kept.push(date);
+                keep = true;
+            }
+            if (this_week - week).num_days() / 7 < retention.keep_weekly_weeks as i64
+                && !weeks_kept.contains(&week)
+            {
+                weeks_kept.push(week);
+                keep = true;
+            }
+            keep
+        })
+        .collect()
+}
+
+/// Delete the scheduled backups no retention rule keeps, returning their paths
+pub fn prune_backups(server_name: &str, retention: &BackupRetention) -> Result<Vec<PathBuf>> {
+    let mut scheduled: Vec<(NaiveDateTime, PathBuf)> = list_backups(server_name)?
+        .into_iter()
+        .filter(|b| is_scheduled_backup(&b.filename))
+        .filter_map(|b| Some((backup_time(&b.filename)?, b.path)))
+        .collect();
+    scheduled.sort_by_key(|(time, _)| std::cmp::Reverse(*time));
+
+    let times: Vec<NaiveDateTime> = scheduled.iter().map(|(t, _)| *t).collect();
+    let keep = retained(&times, retention, chrono::Local::now().naive_local());
+
+    let mut pruned = Vec::new();
+    for ((_, path), keep) in scheduled.into_iter().zip(keep) {
+        // Full backups that incrementals build on stay as long as those do
+        if !keep && dependent_backups(&path).is_empty() {
+            delete_backup(&path)?;
+            pruned.push(path);
+        }
+    }
+    Ok(pruned)
+}
+
 /// List all backups for a server
 pub fn list_backups(server_name: &str) -> Result<Vec<BackupInfo>> {
     let backup_dir = get_backup_path(server_name);
@@ -240,19 +1275,24 @@ pub fn list_backups(server_name: &str) -> Result<Vec<BackupInfo>> {
                 .map(|s| s.to_string_lossy().to_string())
                 .unwrap_or_default();
 
+            let base = is_incremental_backup(&filename)
+                .then(|| read_manifest_at(&path).and_then(|m| m.base))
+                .flatten();
             backups.push(BackupInfo {
                 filename,
-                path,
                 size_bytes: metadata.len(),
                 created: metadata
                     .created()
                     .unwrap_or(std::time::SystemTime::UNIX_EPOCH),
+                base,
+                verified: read_verification(&path),
+                path,
             });
         }
     }
 
     // Sort by creation time, newest first
-    backups.sort_by(|a, b| b.created.cmp(&a.created));
+    backups.sort_by_key(|b| std::cmp::Reverse(b.created));
 
     Ok(backups)
 }
@@ -261,14 +1301,18 @@ pub fn list_backups(server_name: &str) -> Result<Vec<BackupInfo>> {
 /// WARNING: This will overwrite existing data!
 #[allow(dead_code)]
 pub fn restore_backup(server_name: &str, backup_path: &Path) -> Result<()> {
-    restore_backup_with_progress(server_name, backup_path, None)
+    restore_backup_with_progress(server_name, backup_path, None, None)
 }
 
-/// Restore a backup with optional progress reporting
+/// Restore a backup with optional progress reporting and cancellation.
+/// The archive is extracted to a staging directory first, so a failed or cancelled
+/// restore (e.g. disk full) leaves the current data intact. An incremental backup
+/// is restored on top of the full backup it was taken against.
 pub fn restore_backup_with_progress(
     server_name: &str,
     backup_path: &Path,
     progress_tx: Option<Sender<BackupProgress>>,
+    cancel: Option<&AtomicBool>,
 ) -> Result<()> {
     let data_path = get_server_data_path(server_name);
 
@@ -276,22 +1320,499 @@ pub fn restore_backup_with_progress(
         anyhow::bail!("Backup file does not exist: {:?}", backup_path);
     }
 
-    if data_path.exists() {
-        fs::remove_dir_all(&data_path).context("Failed to clear existing data directory")?;
-    }
-    fs::create_dir_all(&data_path).context("Failed to create data directory")?;
+    let (mut archive, mut base, manifest) = open_backup_layers(backup_path)?;
+    let excludes = read_manifest(&mut archive)
+        .map(|m| m.excludes)
+        .unwrap_or_default();
+
+    // The full backup holds nearly everything an incremental restores
+    ensure_space_for_extract(base.as_mut().unwrap_or(&mut archive), &data_path, None)?;
+    extract_staged(&data_path, |staging| {
+        if let Some(base) = base.as_mut() {
+            extract_zip_with_progress(base, staging, None, None, progress_tx.as_ref(), cancel)?;
+        }
+        extract_zip_with_progress(
+            &mut archive,
+            staging,
+            None,
+            None,
+            progress_tx.as_ref(),
+            cancel,
+        )?;
+        if let Some(manifest) = &manifest {
+            remove_unlisted(staging, manifest)?;
+        }
+        carry_over_excluded(&data_path, staging, &excludes)
+    })?;
+
+    Ok(())
+}
+
+/// A file or folder stored in a backup
+#[derive(Debug, Clone)]
+pub struct BackupEntry {
+    /// `/`-separated path inside the data directory
+    pub path: String,
+    pub size: u64,
+    pub is_dir: bool,
+    /// When the file was last changed before it was backed up, in local time
+    pub modified: Option<NaiveDateTime>,
+}
 
+/// A backup's archive, the full backup it builds on if it's an incremental, and
+/// the incremental's manifest
+type BackupLayers = (
+    ZipArchive<File>,
+    Option<ZipArchive<File>>,
+    Option<BackupManifest>,
+);
+
+/// Open a backup and, for an incremental, the full backup it builds on
+fn open_backup_layers(backup_path: &Path) -> Result<BackupLayers> {
     let file = File::open(backup_path).context("Failed to open backup file")?;
     let mut archive = ZipArchive::new(file).context("Failed to read zip archive")?;
+    let manifest = read_manifest(&mut archive).filter(|m| m.base.is_some());
+    let base = match manifest.as_ref().and_then(|m| m.base.as_deref()) {
+        Some(base_name) => {
+            let file = File::open(backup_path.with_file_name(base_name)).with_context(|| {
+                format!(
+                    "Incremental backup needs its full backup {}, which is missing",
+                    base_name
+                )
+            })?;
+            Some(ZipArchive::new(file).context("Failed to read full backup archive")?)
+        }
+        None => None,
+    };
+    Ok((archive, base, manifest))
+}
 
-    extract_zip_with_progress(&mut archive, &data_path, None, progress_tx.as_ref())?;
+/// Everything a restore of this backup would put back, sorted by path. An
+/// incremental lists the full picture, i.e. its base plus its own changes.
+pub fn list_backup_entries(backup_path: &Path) -> Result<Vec<BackupEntry>> {
+    let (mut archive, mut base, manifest) = open_backup_layers(backup_path)?;
+    // Full backups carry a manifest too; it has the files' own modification times,
+    // where the zip only knows when each entry was written
+    let stamps = manifest.clone().or_else(|| read_manifest(&mut archive));
+    let mut entries: BTreeMap<String, BackupEntry> = BTreeMap::new();
+    for layer in base.iter_mut().chain(std::iter::once(&mut archive)) {
+        for i in 0..layer.len() {
+            let Ok(entry) = layer.by_index_raw(i) else {
+                continue;
+            };
+            let path = entry
+                .name()
+                .replace('\\', "/")
+                .trim_end_matches('/')
+                .to_string();
+            if path.is_empty() || path == MANIFEST_NAME {
+                continue;
+            }
+            let modified = match stamps.as_ref().and_then(|m| m.entries.get(&path)) {
+                Some(stamp) if stamp.modified > 0 => local_time(stamp.modified),
+                _ => entry.last_modified().and_then(zip_entry_time),
+            };
+            entries.insert(
+                path.clone(),
+                BackupEntry {
+                    path,
+                    size: entry.size(),
+                    is_dir: entry.is_dir(),
+                    modified: modified.filter(|_| !entry.is_dir()),
+                },
+            );
+        }
+    }
+    if let Some(manifest) = &manifest {
+        entries.retain(|path, _| manifest.entries.contains_key(path));
+    }
+    Ok(entries.into_values().collect())
+}
 
-    Ok(())
+/// Seconds since the Unix epoch as local time
+fn local_time(secs: u64) -> Option<NaiveDateTime> {
+    let utc = chrono::DateTime::from_timestamp(i64::try_from(secs).ok()?, 0)?;
+    Some(utc.with_timezone(&chrono::Local).naive_local())
+}
+
+/// Timestamp of a zip entry, which zip stores without a time zone
+fn zip_entry_time(time: zip::DateTime) -> Option<NaiveDateTime> {
+    chrono::NaiveDate::from_ymd_opt(time.year().into(), time.month().into(), time.day().into())?
+        .and_hms_opt(
+            time.hour().into(),
+            time.minute().into(),
+            time.second().into(),
+        )
+}
+
+/// Most of a file [`read_backup_text`] reads for a preview
+pub const PREVIEW_LIMIT: u64 = 64 * 1024;
+
+/// The start of a text file in a backup, for previewing it without a restore, and
+/// whether it was cut off at [`PREVIEW_LIMIT`]. Fails for binary files.
+pub fn read_backup_text(backup_path: &Path, path: &str) -> Result<(String, bool)> {
+    let (mut archive, mut base, _) = open_backup_layers(backup_path)?;
+    // An incremental only holds the files that changed; the rest are in its base
+    let (layer, index) = std::iter::once(&mut archive)
+        .chain(base.iter_mut())
+        .find_map(|layer| {
+            let index = (0..layer.len()).find(|&i| {
+                layer
+                    .name_for_index(i)
+                    .is_some_and(|name| name.replace('\\', "/") == path)
+            })?;
+            Some((layer, index))
+        })
+        .with_context(|| format!("{} is not in this backup", path))?;
+
+    let entry = layer
+        .by_index(index)
+        .with_context(|| format!("Failed to read {} from backup", path))?;
+    if entry.is_dir() {
+        anyhow::bail!("{} is a folder", path);
+    }
+    let truncated = entry.size() > PREVIEW_LIMIT;
+    let mut bytes = Vec::new();
+    entry
+        .take(PREVIEW_LIMIT)
+        .read_to_end(&mut bytes)
+        .with_context(|| format!("Failed to read {} from backup", path))?;
+    if bytes.contains(&0) {
+        anyhow::bail!("Binary file, no preview available");
+    }
+    Ok((String::from_utf8_lossy(&bytes).into_owned(), truncated))
+}
+
+/// Whether `path` is one of `selected` or inside one of them
+fn path_selected(path: &Path, selected: &[PathBuf]) -> bool {
+    selected.iter().any(|s| path.starts_with(s))
+}
+
+/// Restore only the given files and folders from a backup, leaving the rest of the
+/// data directory as it is. Each selected path is extracted to a staging folder
+/// first; only when all of them succeeded are the current copies moved to
+/// `replaced-files/<timestamp>/` next to the data folder and the restored ones put
+/// in their place. Returns the number of files restored.
+pub fn restore_paths_with_progress(
+    server_name: &str,
+    backup_path: &Path,
+    paths: &[String],
+    progress_tx: Option<Sender<BackupProgress>>,
+    cancel: Option<&AtomicBool>,
+) -> Result<usize> {
+    let mut selected: Vec<PathBuf> = Vec::new();
+    for path in paths {
+        let path = PathBuf::from(path.trim_end_matches('/'));
+        if !path.components().all(|c| matches!(c, Component::Normal(_))) {
+            anyhow::bail!("Invalid path in selection: {}", path.display());
+        }
+        selected.push(path);
+    }
+    // A folder already brings everything inside it
+    let selected: Vec<PathBuf> = selected
+        .iter()
+        .filter(|p| {
+            !selected
+                .iter()
+                .any(|other| other != *p && p.starts_with(other))
+        })
+        .cloned()
+        .collect();
+    if selected.is_empty() {
+        anyhow::bail!("Nothing selected to restore");
+    }
+
+    let data_path = get_server_data_path(server_name);
+    let server_path = get_server_path(server_name);
+    let required: u64 = list_backup_entries(backup_path)?
+        .iter()
+        .filter(|e| path_selected(Path::new(&e.path), &selected))
+        .map(|e| e.size)
+        .sum();
+    let available = available_space(&server_path)?;
+    if required > available {
+        anyhow::bail!(
+            "Not enough free disk space: need {}, only {} available",
+            format_bytes(required),
+            format_bytes(available)
+        );
+    }
+
+    let (mut archive, mut base, manifest) = open_backup_layers(backup_path)?;
+    let staging = server_path.join("restoring-files");
+    if staging.exists() {
+        fs::remove_dir_all(&staging).context("Failed to clear stale staging directory")?;
+    }
+    fs::create_dir_all(&staging).context("Failed to create staging directory")?;
+
+    let include = |path: &Path| path_selected(path, &selected);
+    let extracted = (|| -> Result<()> {
+        if let Some(base) = base.as_mut() {
+            extract_zip_with_progress(
+                base,
+                &staging,
+                None,
+                Some(&include),
+                progress_tx.as_ref(),
+                cancel,
+            )?;
+        }
+        extract_zip_with_progress(
+            &mut archive,
+            &staging,
+            None,
+            Some(&include),
+            progress_tx.as_ref(),
+            cancel,
+        )?;
+        match &manifest {
+            Some(manifest) => remove_unlisted(&staging, manifest),
+            None => Ok(()),
+        }
+    })();
+    if let Err(e) = extracted {
+        fs::remove_dir_all(&staging).ok();
+        return Err(e);
+    }
+
+    let restored = WalkDir::new(&staging)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .count();
+    let stamp = chrono::Local::now().format("%Y%m%d_%H%M%S").to_string();
+    let mut replaced_dir = server_path.join("replaced-files").join(&stamp);
+    // Two restores within a second would otherwise move files into each other
+    for n in 2.. {
+        if !replaced_dir.exists() {
+            break;
+        }
+        replaced_dir = server_path
+            .join("replaced-files")
+            .join(format!("{}-{}", stamp, n));
+    }
+    let selected: Vec<&PathBuf> = selected
+        .iter()
+        .filter(|path| {
+            let staged = staging.join(path);
+            staged.exists() || staged.is_symlink()
+        })
+        .collect();
+    // Check every destination before touching any, so a bad one can't leave a half-done swap
+    for path in &selected {
+        if let Err(e) = ensure_no_symlink_ancestors(&data_path, path) {
+            fs::remove_dir_all(&staging).ok();
+            return Err(e);
+        }
+    }
+
+    // Restored path and, if something was there, where it was moved aside to
+    let mut swapped: Vec<(PathBuf, Option<PathBuf>)> = Vec::new();
+    let swap = (|| -> Result<()> {
+        for path in &selected {
+            let staged = staging.join(path);
+            let dest = data_path.join(path);
+            let mut kept = None;
+            if dest.exists() || dest.is_symlink() {
+                let aside = replaced_dir.join(path);
+                if let Some(parent) = aside.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::rename(&dest, &aside)
+                    .with_context(|| format!("Failed to move aside {}", path.display()))?;
+                kept = Some(aside);
+            }
+            // Recorded before the restored copy moves in, so a failure still puts back the original
+            swapped.push((dest.clone(), kept));
+            if let Some(parent) = dest.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::rename(&staged, &dest).with_context(|| {
+                format!("Failed to move restored {} into place", path.display())
+            })?;
+        }
+        Ok(())
+    })();
+    if let Err(e) = swap {
+        tracing::warn!(
+            "Partial restore failed, putting the original files back: {}",
+            e
+        );
+        for (dest, kept) in swapped.into_iter().rev() {
+            if dest.is_dir() && !dest.is_symlink() {
+                fs::remove_dir_all(&dest).ok();
+            } else if dest.exists() || dest.is_symlink() {
+                fs::remove_file(&dest).ok();
+            }
+            if let Some(kept) = kept {
+                fs::rename(&kept, &dest).ok();
+            }
+        }
+        fs::remove_dir_all(&staging).ok();
+        return Err(e);
+    }
+    fs::remove_dir_all(&staging).ok();
+
+    Ok(restored)
+}
+
+/// A player whose data is stored in a backup
+#[derive(Debug, Clone)]
+pub struct BackupPlayer {
+    pub uuid: String,
+    /// Last known name from the backup's usercache.json
+    pub name: Option<String>,
+    /// Archive entries holding this player's inventory, stats and advancements
+    pub files: Vec<String>,
+}
+
+impl BackupPlayer {
+    pub fn display_name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.uuid)
+    }
+}
+
+/// Per-player folders inside a world, keyed by UUID
+const PLAYER_DIRS: &[&str] = &["playerdata", "stats", "advancements"];
+
+/// UUID of a `<world>/<playerdata|stats|advancements>/<uuid>.(dat|json)` entry
+fn player_file_uuid(entry: &str) -> Option<&str> {
+    let mut parts = entry.split('/');
+    let (world, dir, file) = (parts.next()?, parts.next()?, parts.next()?);
+    if parts.next().is_some() || matches!(world, "" | "." | "..") || !PLAYER_DIRS.contains(&dir) {
+        return None;
+    }
+    let uuid = file
+        .strip_suffix(".dat")
+        .or_else(|| file.strip_suffix(".json"))?;
+    (uuid.len() == 36 && uuid.chars().all(|c| c.is_ascii_hexdigit() || c == '-')).then_some(uuid)
+}
+
+/// An entry as a restore of the backup would put it back: an incremental's own copy
+/// if the file changed, otherwise its base's
+fn layered_entry<'a>(
+    archive: &'a mut ZipArchive<File>,
+    base: Option<&'a mut ZipArchive<File>>,
+    name: &str,
+) -> zip::result::ZipResult<zip::read::ZipFile<'a>> {
+    if archive.index_for_name(name).is_some() {
+        return archive.by_name(name);
+    }
+    match base {
+        Some(base) => base.by_name(name),
+        None => Err(zip::result::ZipError::FileNotFound),
+    }
+}
+
+/// List the players with saved data in a backup, named via its usercache.json.
+/// An incremental is read through its base, like a full restore.
+pub fn list_backup_players(backup_path: &Path) -> Result<Vec<BackupPlayer>> {
+    let (mut archive, mut base, manifest) = open_backup_layers(backup_path)?;
+
+    let mut names: Vec<&str> = base
+        .iter()
+        .chain(std::iter::once(&archive))
+        .flat_map(|layer| layer.file_names())
+        .collect();
+    names.sort_unstable();
+    names.dedup();
+    // Files deleted since the base are left out of the incremental's manifest
+    if let Some(manifest) = &manifest {
+        names.retain(|name| manifest.entries.contains_key(*name));
+    }
+
+    let mut players: Vec<BackupPlayer> = Vec::new();
+    for entry in names {
+        let Some(uuid) = player_file_uuid(entry) else {
+            continue;
+        };
+        match players.iter_mut().find(|p| p.uuid == uuid) {
+            Some(player) => player.files.push(entry.to_string()),
+            None => players.push(BackupPlayer {
+                uuid: uuid.to_string(),
+                name: None,
+                files: vec![entry.to_string()],
+            }),
+        }
+    }
+
+    if let Ok(mut cache) = layered_entry(&mut archive, base.as_mut(), "usercache.json") {
+        let mut json = String::new();
+        if cache.read_to_string(&mut json).is_ok() {
+            let entries: Vec<serde_json::Value> = serde_json::from_str(&json).unwrap_or_default();
+            for entry in entries {
+                let (Some(uuid), Some(name)) = (entry["uuid"].as_str(), entry["name"].as_str())
+                else {
+                    continue;
+                };
+                if let Some(player) = players.iter_mut().find(|p| p.uuid == uuid) {
+                    player.name = Some(name.to_string());
+                }
+            }
+        }
+    }
+
+    players.sort_by_key(|p| p.display_name().to_lowercase());
+    Ok(players)
+}
+
+/// Restore one player's inventory, stats and advancements from a backup without
+/// touching the rest of the world. The files being replaced are moved to
+/// `replaced-playerdata/<timestamp>/` next to the data folder first.
+/// Returns the number of files restored.
+pub fn restore_player(
+    server_name: &str,
+    backup_path: &Path,
+    player: &BackupPlayer,
+) -> Result<usize> {
+    let data_path = get_server_data_path(server_name);
+    let archive_dir = get_server_path(server_name)
+        .join("replaced-playerdata")
+        .join(chrono::Local::now().format("%Y%m%d_%H%M%S").to_string());
+
+    let (mut archive, mut base, _) = open_backup_layers(backup_path)?;
+
+    let mut restored = 0;
+    for entry_name in &player.files {
+        // Names were validated by player_file_uuid, so they stay inside the data folder,
+        // but a symlinked world or playerdata folder could still point outside it
+        ensure_no_symlink_ancestors(&data_path, Path::new(entry_name))?;
+        let dest = data_path.join(entry_name);
+        let mut entry = layered_entry(&mut archive, base.as_mut(), entry_name)
+            .with_context(|| format!("{} missing from backup", entry_name))?;
+        if dest.exists() {
+            let kept = archive_dir.join(entry_name);
+            if let Some(parent) = kept.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::rename(&dest, &kept)
+                .with_context(|| format!("Failed to move aside {}", entry_name))?;
+        }
+
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut out =
+            File::create(&dest).with_context(|| format!("Failed to create {}", dest.display()))?;
+        std::io::copy(&mut entry, &mut out)
+            .with_context(|| format!("Failed to extract {}", entry_name))?;
+        restored += 1;
+    }
+
+    Ok(restored)
 }
 
-/// Delete a backup file
+/// Delete a backup file. Full backups that incremental backups build on are kept.
 pub fn delete_backup(backup_path: &Path) -> Result<()> {
+    let dependents = dependent_backups(backup_path);
+    if !dependents.is_empty() {
+        anyhow::bail!(
+            "Incremental backups build on it, delete these first: {}",
+            dependents.join(", ")
+        );
+    }
     fs::remove_file(backup_path).context("Failed to delete backup file")?;
+    let _ = fs::remove_file(verified_marker(backup_path));
     Ok(())
 }
 
@@ -330,13 +1851,139 @@ pub fn export_server_with_progress(
         .context("Failed to write config data")?;
 
     // Add all data files under the "data/" prefix
-    zip_directory_with_progress(&mut zip, data_path, "data/", progress_tx.as_ref())?;
+    // Exports always use Deflate so they open in any zip tool on the other machine
+    zip_directory_with_progress(
+        &mut zip,
+        data_path,
+        "data/",
+        BackupCompression::default(),
+        &[],
+        None,
+        progress_tx.as_ref(),
+    )?;
 
     zip.finish().context("Failed to finalize export zip")?;
 
     Ok(output_path.to_path_buf())
 }
 
+/// World folder name from the server's `server.properties`, `world` if it isn't set
+pub fn level_name(data_path: &Path) -> String {
+    fs::read_to_string(data_path.join("server.properties"))
+        .ok()
+        .and_then(|props| {
+            props.lines().find_map(|line| {
+                let value = line.trim().strip_prefix("level-name")?.trim_start();
+                let value = value.strip_prefix(['=', ':'])?.trim();
+                (!value.is_empty()).then(|| value.to_string())
+            })
+        })
+        .unwrap_or_else(|| "world".to_string())
+}
+
+/// Zip just the world as a singleplayer save, i.e. one `<level>/` folder with
+/// `level.dat` at its root, ready to drop into a client's `saves/`. Paper and
+/// Spigot keep the Nether and End in `<level>_nether/DIM-1` and
+/// `<level>_the_end/DIM1`; those are folded back in where vanilla expects them.
+/// Lock files are left out.
+pub fn export_world_with_progress(
+    data_path: &Path,
+    output_path: &Path,
+    progress_tx: Option<Sender<BackupProgress>>,
+) -> Result<PathBuf> {
+    let level = level_name(data_path);
+    let world = data_path.join(&level);
+    if !world.join("level.dat").exists() {
+        anyhow::bail!(
+            "No world found at {:?}; has the server been started?",
+            world
+        );
+    }
+
+    let mut parts = vec![(world.clone(), format!("{}/", level))];
+    for (folder, dim) in [("nether", "DIM-1"), ("the_end", "DIM1")] {
+        let split = data_path.join(format!("{}_{}", level, folder)).join(dim);
+        if split.is_dir() && !world.join(dim).exists() {
+            parts.push((split, format!("{}/{}/", level, dim)));
+        }
+    }
+
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).context("Failed to create output directory")?;
+    }
+    let file = File::create(output_path).context("Failed to create export file")?;
+    let mut zip = ZipWriter::new(file);
+    // Deflate so the zip opens with any tool on the player's machine
+    for (source, prefix) in &parts {
+        zip_directory_with_progress(
+            &mut zip,
+            source,
+            prefix,
+            BackupCompression::default(),
+            &["session.lock".to_string()],
+            None,
+            progress_tx.as_ref(),
+        )?;
+    }
+    zip.finish().context("Failed to finalize world zip")?;
+
+    Ok(output_path.to_path_buf())
+}
+
+/// Zip a client modpack: the pack index (`modrinth.index.json` or `manifest.json`),
+/// the mod jars no site hosts under `overrides/mods/`, and the server's `folders`
+/// that exist (config and the like) under `overrides/`.
+pub fn write_client_pack(
+    data_path: &Path,
+    output_path: &Path,
+    index_name: &str,
+    index: &str,
+    bundled_jars: &[String],
+    folders: &[&str],
+    progress_tx: Option<Sender<BackupProgress>>,
+) -> Result<PathBuf> {
+    if let Some(parent) = output_path.parent() {
+        fs::create_dir_all(parent).context("Failed to create output directory")?;
+    }
+    let file = File::create(output_path).context("Failed to create client pack file")?;
+    let mut zip = ZipWriter::new(file);
+    let file_options = FileOptions::<()>::default()
+        .compression_method(CompressionMethod::Deflated)
+        .unix_permissions(0o644);
+
+    zip.start_file(index_name, file_options)
+        .context("Failed to write pack index")?;
+    zip.write_all(index.as_bytes())
+        .context("Failed to write pack index")?;
+
+    for name in bundled_jars {
+        zip.start_file(format!("overrides/mods/{}", name), file_options)
+            .context("Failed to start file in zip")?;
+        let mut jar = File::open(data_path.join("mods").join(name))
+            .with_context(|| format!("Failed to open {}", name))?;
+        std::io::copy(&mut jar, &mut zip)
+            .with_context(|| format!("Failed to write {} to zip", name))?;
+    }
+
+    for folder in folders {
+        let source = data_path.join(folder);
+        if source.is_dir() {
+            zip_directory_with_progress(
+                &mut zip,
+                &source,
+                &format!("overrides/{}/", folder),
+                BackupCompression::default(),
+                &[],
+                None,
+                progress_tx.as_ref(),
+            )?;
+        }
+    }
+    zip.finish().context("Failed to finalize client pack")?;
+
+    Ok(output_path.to_path_buf())
+}
+
 /// Read the `server-config.json` from an export zip without extracting data.
 /// Useful for previewing before import.
 pub fn read_export_config(zip_path: &Path) -> Result<ServerConfig> {
@@ -368,12 +2015,24 @@ pub fn import_server(
     let config = read_export_config(zip_path)?;
 
     let data_path = servers_dir.join(&config.name).join("data");
-    fs::create_dir_all(&data_path).context("Failed to create server data directory")?;
+    if let Some(parent) = data_path.parent() {
+        fs::create_dir_all(parent).context("Failed to create server directory")?;
+    }
 
     let file = File::open(zip_path).context("Failed to open export file")?;
     let mut archive = ZipArchive::new(file).context("Failed to read zip archive")?;
 
-    extract_zip_with_progress(&mut archive, &data_path, Some("data/"), progress_tx.as_ref())?;
+    ensure_space_for_extract(&mut archive, &data_path, Some("data/"))?;
+    extract_staged(&data_path, |staging| {
+        extract_zip_with_progress(
+            &mut archive,
+            staging,
+            Some("data/"),
+            None,
+            progress_tx.as_ref(),
+            None,
+        )
+    })?;
 
     Ok(config)
 }
@@ -0,0 +1,114 @@
+//! Backup health across every server: disk used, age of the newest backup, and
+//! which servers have gone too long without one.
+
+use crate::backup::{self, BackupInfo};
+use crate::config::get_backups_root;
+use crate::playtime::format_duration;
+use crate::server::{BackupFrequency, ServerInstance};
+use std::time::{Duration, SystemTime};
+
+/// Servers without a backup schedule are flagged once their newest backup is older
+/// than this
+const UNSCHEDULED_STALE_AFTER: Duration = Duration::from_secs(7 * 24 * 3600);
+
+/// One row in the backups overview
+#[derive(Debug, Clone)]
+pub struct BackupSummary {
+    pub server_name: String,
+    /// `false` for backups left behind by a deleted server
+    pub configured: bool,
+    pub count: usize,
+    pub total_bytes: u64,
+    /// Time since the newest backup was written
+    pub newest_age: Option<Duration>,
+    pub scheduled: bool,
+    /// Why the server's backups need attention, if they do
+    pub warning: Option<String>,
+}
+
+/// Totals for the whole backups folder
+#[derive(Debug, Clone, Default)]
+pub struct BackupOverview {
+    pub rows: Vec<BackupSummary>,
+    pub total_bytes: u64,
+    /// Free space on the drive holding the backups
+    pub available: Option<u64>,
+}
+
+/// Age after which a server's newest backup counts as overdue: two missed runs
+/// for a schedule, otherwise a week
+fn stale_after(server: &ServerInstance) -> Duration {
+    let schedule = &server.config.backup_schedule;
+    if !schedule.enabled {
+        return UNSCHEDULED_STALE_AFTER;
+    }
+    let interval_hours = match schedule.frequency {
+        BackupFrequency::EveryHours(hours) => u64::from(hours.max(1)),
+        BackupFrequency::DailyAt { .. } => 24,
+    };
+    Duration::from_secs(2 * interval_hours * 3600)
+}
+
+fn summarize(server_name: &str, backups: &[BackupInfo]) -> BackupSummary {
+    let now = SystemTime::now();
+    BackupSummary {
+        server_name: server_name.to_string(),
+        configured: false,
+        count: backups.len(),
+        total_bytes: backups.iter().map(|b| b.size_bytes).sum(),
+        // Listed newest first
+        newest_age: backups
+            .first()
+            .map(|b| now.duration_since(b.created).unwrap_or_default()),
+        scheduled: false,
+        warning: None,
+    }
+}
+
+/// Summarize the backups of every configured server, plus any backup folders whose
+/// server has since been deleted
+pub fn collect_backup_overview(servers: &[ServerInstance]) -> BackupOverview {
+    let mut rows: Vec<BackupSummary> = servers
+        .iter()
+        .map(|server| {
+            let name = &server.config.name;
+            let backups = backup::list_backups(name).unwrap_or_default();
+            let mut row = summarize(name, &backups);
+            row.configured = true;
+            row.scheduled = server.config.backup_schedule.enabled;
+            row.warning = match row.newest_age {
+                None => Some("No backups yet".to_string()),
+                Some(age) if age > stale_after(server) => Some(format!(
+                    "Last backup {} ago",
+                    format_duration(age.as_secs())
+                )),
+                Some(_) => None,
+            };
+            row
+        })
+        .collect();
+
+    let mut leftovers: Vec<String> = std::fs::read_dir(get_backups_root())
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.file_type().is_ok_and(|t| t.is_dir()))
+                .filter_map(|e| e.file_name().into_string().ok())
+                .filter(|name| !servers.iter().any(|s| &s.config.name == name))
+                .collect()
+        })
+        .unwrap_or_default();
+    leftovers.sort();
+    for name in leftovers {
+        let backups = backup::list_backups(&name).unwrap_or_default();
+        if !backups.is_empty() {
+            rows.push(summarize(&name, &backups));
+        }
+    }
+
+    BackupOverview {
+        total_bytes: rows.iter().map(|r| r.total_bytes).sum(),
+        available: backup::available_space(&get_backups_root()).ok(),
+        rows,
+    }
+}
@@ -0,0 +1,299 @@
+//! Client modpack export: a Modrinth `.mrpack` or CurseForge zip holding what the
+//! server runs right now, so players can join with exactly its mods and configs.
+//! Jars found on the chosen site are linked by download; the rest are bundled.
+
+use crate::curseforge;
+use crate::local_pack;
+use crate::modrinth;
+use crate::pack_installer;
+use crate::server::{ModLoader, ServerConfig};
+use anyhow::{Context, Result};
+use sha1::{Digest, Sha1};
+use sha2::Sha512;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// Server folders the client needs too, copied into the pack's overrides
+pub const CLIENT_FOLDERS: &[&str] = &[
+    "config",
+    "defaultconfigs",
+    "kubejs",
+    "resourcepacks",
+    "scripts",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClientPackFormat {
+    /// `.mrpack`, opened by Prism, Modrinth App and most other launchers
+    Modrinth,
+    /// CurseForge profile zip with a `manifest.json`
+    CurseForge,
+}
+
+impl ClientPackFormat {
+    /// From the file name picked in the save dialog: `.zip` is a CurseForge profile
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("zip") => Self::CurseForge,
+            _ => Self::Modrinth,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Modrinth => "Modrinth",
+            Self::CurseForge => "CurseForge",
+        }
+    }
+}
+
+/// Pack index plus the jars to bundle, ready for [`crate::backup::write_client_pack`]
+pub struct ClientPackPlan {
+    pub index_name: &'static str,
+    pub index: String,
+    /// Jars no site knows, shipped inside the pack
+    pub bundled: Vec<String>,
+    /// Jars linked to a download on the site
+    pub linked: usize,
+    /// Jars left out because they only run on servers
+    pub server_only: Vec<String>,
+    /// Loader version written to the index, if it could be found
+    pub loader_version: Option<String>,
+}
+
+struct Jar {
+    name: String,
+    size: u64,
+    sha1: String,
+    sha512: String,
+    fingerprint: u32,
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn read_jars(mods_dir: &Path) -> Result<Vec<Jar>> {
+    let mut jars = Vec::new();
+    let entries = match std::fs::read_dir(mods_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(jars),
+        Err(e) => return Err(e).context("Failed to read the mods folder"),
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.is_file() || path.extension().and_then(|e| e.to_str()) != Some("jar") {
+            continue;
+        }
+        // Mod jars are a few MB at most, so each is read whole for its three hashes
+        let bytes =
+            std::fs::read(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+        jars.push(Jar {
+            name: entry.file_name().to_string_lossy().into_owned(),
+            size: bytes.len() as u64,
+            sha1: hex(&Sha1::digest(&bytes)),
+            sha512: hex(&Sha512::digest(&bytes)),
+            fingerprint: curseforge::fingerprint(&bytes),
+        });
+    }
+    jars.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(jars)
+}
+
+/// Loader version the server runs: recorded by a host install, else read from
+/// the loader's libraries in the data folder
+pub fn loader_version(data_path: &Path, loader: &ModLoader) -> Option<String> {
+    pack_installer::installed_loader(data_path)
+        .filter(|l| l.loader == *loader)
+        .map(|l| l.loader_version)
+        .or_else(|| {
+            let listing = local_pack::list_pack(data_path, "the server folder").ok()?;
+            local_pack::detect_loader_version(&listing, loader)
+        })
+}
+
+/// Work out the pack for `config`'s server: hash its jars, look them up on the
+/// format's site, and write the index. `key` is the Modrinth token or CurseForge
+/// API key, depending on the format.
+pub async fn plan(
+    config: &ServerConfig,
+    data_path: &Path,
+    format: ClientPackFormat,
+    key: Option<&str>,
+) -> Result<ClientPackPlan> {
+    let modpack = &config.modpack;
+    let jars = read_jars(&data_path.join("mods"))?;
+    let loader_version = loader_version(data_path, &modpack.loader);
+    match format {
+        ClientPackFormat::Modrinth => plan_mrpack(config, jars, loader_version, key).await,
+        ClientPackFormat::CurseForge => {
+            let key = key
+                .filter(|k| !k.is_empty())
+                .context("A CurseForge pack needs a CurseForge API key (Settings)")?;
+            plan_curseforge(config, jars, loader_version, key).await
+        }
+    }
+}
+
+async fn plan_mrpack(
+    config: &ServerConfig,
+    jars: Vec<Jar>,
+    loader_version: Option<String>,
+    token: Option<&str>,
+) -> Result<ClientPackPlan> {
+    let sha1s: Vec<String> = jars.iter().map(|j| j.sha1.clone()).collect();
+    let versions = if sha1s.is_empty() {
+        HashMap::new()
+    } else {
+        modrinth::get_versions_by_sha1(token, &sha1s).await?
+    };
+
+    // Mods that don't run on the client at all stay out of the pack
+    let project_ids: Vec<String> = versions
+        .values()
+        .map(|v| v.project_id.clone())
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let server_only_projects: HashSet<String> = if project_ids.is_empty() {
+        HashSet::new()
+    } else {
+        modrinth::get_project_sides(token, &project_ids)
+            .await?
+            .into_iter()
+            .filter(|p| p.client_side == "unsupported")
+            .map(|p| p.id)
+            .collect()
+    };
+
+    let mut files = Vec::new();
+    let mut bundled = Vec::new();
+    let mut server_only = Vec::new();
+    for jar in jars {
+        let url = versions.get(&jar.sha1).and_then(|v| {
+            if server_only_projects.contains(&v.project_id) {
+                return Some(None);
+            }
+            v.files
+                .iter()
+                .find(|f| f.hashes.get("sha1") == Some(&jar.sha1))
+                .map(|f| Some(f.url.clone()))
+        });
+        match url {
+            Some(Some(url)) => files.push(serde_json::json!({
+                "path": format!("mods/{}", jar.name),
+                "hashes": { "sha1": jar.sha1, "sha512": jar.sha512 },
+                "env": { "client": "required", "server": "required" },
+                "downloads": [url],
+                "fileSize": jar.size,
+            })),
+            Some(None) => server_only.push(jar.name),
+            None => bundled.push(jar.name),
+        }
+    }
+
+    let modpack = &config.modpack;
+    let mut dependencies = serde_json::json!({ "minecraft": modpack.minecraft_version });
+    let loader_key = match modpack.loader {
+        ModLoader::Forge => Some("forge"),
+        ModLoader::NeoForge => Some("neoforge"),
+        ModLoader::Fabric => Some("fabric-loader"),
+        ModLoader::Vanilla => None,
+    };
+    if let (Some(key), Some(version)) = (loader_key, &loader_version) {
+        dependencies[key] = serde_json::json!(version);
+    }
+    let linked = files.len();
+    let index = serde_json::json!({
+        "formatVersion": 1,
+        "game": "minecraft",
+        "versionId": modpack.version,
+        "name": config.name,
+        "summary": format!("Client pack for the {} server ({})", config.name, modpack.name),
+        "files": files,
+        "dependencies": dependencies,
+    });
+    Ok(ClientPackPlan {
+        index_name: "modrinth.index.json",
+        index: serde_json::to_string_pretty(&index)?,
+        bundled,
+        linked,
+        server_only,
+        loader_version,
+    })
+}
+
+async fn plan_curseforge(
+    config: &ServerConfig,
+    jars: Vec<Jar>,
+    loader_version: Option<String>,
+    api_key: &str,
+) -> Result<ClientPackPlan> {
+    let fingerprints: Vec<u32> = jars.iter().map(|j| j.fingerprint).collect();
+    let matches = if fingerprints.is_empty() {
+        Vec::new()
+    } else {
+        curseforge::match_fingerprints(api_key, &fingerprints).await?
+    };
+    let by_fingerprint: HashMap<u64, &curseforge::CfFingerprintMatch> = matches
+        .iter()
+        .map(|m| (m.file.file_fingerprint, m))
+        .collect();
+
+    let mut files = Vec::new();
+    let mut bundled = Vec::new();
+    let mut server_only = Vec::new();
+    for jar in jars {
+        match by_fingerprint.get(&(jar.fingerprint as u64)) {
+            Some(m) => {
+                let tagged = |tag: &str| m.file.game_versions.iter().any(|v| v == tag);
+                if tagged("Server") && !tagged("Client") {
+                    server_only.push(jar.name);
+                    continue;
+                }
+                files.push(serde_json::json!({
+                    "projectID": m.id,
+                    "fileID": m.file.id,
+                    "required": true,
+                }));
+            }
+            None => bundled.push(jar.name),
+        }
+    }
+
+    let modpack = &config.modpack;
+    let mod_loaders: Vec<serde_json::Value> = loader_version
+        .iter()
+        .filter(|_| modpack.loader != ModLoader::Vanilla)
+        .map(|version| {
+            let loader = pack_installer::PackLoader {
+                minecraft_version: modpack.minecraft_version.clone(),
+                loader: modpack.loader.clone(),
+                loader_version: version.clone(),
+            };
+            serde_json::json!({ "id": loader.id(), "primary": true })
+        })
+        .collect();
+    let linked = files.len();
+    let manifest = serde_json::json!({
+        "minecraft": {
+            "version": modpack.minecraft_version,
+            "modLoaders": mod_loaders,
+        },
+        "manifestType": "minecraftModpack",
+        "manifestVersion": 1,
+        "name": config.name,
+        "version": modpack.version,
+        "author": "",
+        "files": files,
+        "overrides": "overrides",
+    });
+    Ok(ClientPackPlan {
+        index_name: "manifest.json",
+        index: serde_json::to_string_pretty(&manifest)?,
+        bundled,
+        linked,
+        server_only,
+        loader_version,
+    })
+}
@@ -1,7 +1,13 @@
+use crate::ddns::DdnsSettings;
+use crate::discord_bot::DiscordBotSettings;
+use crate::i18n::{tr, Language};
 use crate::server::ServerInstance;
-use anyhow::Result;
+use crate::shortcuts::ShortcutSettings;
+use crate::webhooks::Webhook;
+use anyhow::{bail, Result};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, RwLock};
 
 /// Global application settings
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -10,29 +16,383 @@ pub struct AppSettings {
     /// Get one from https://console.curseforge.com/
     #[serde(default)]
     pub curseforge_api_key: Option<String>,
+    /// Optional Modrinth personal access token (higher rate limits)
+    /// Create one at https://modrinth.com/settings/pats
+    #[serde(default)]
+    pub modrinth_token: Option<String>,
+    /// Bandwidth cap for server pack downloads in KiB/s (0 = unlimited)
+    #[serde(default)]
+    pub download_limit_kbps: u32,
+    /// When the user accepted the Minecraft EULA (RFC 3339). Servers won't start until set.
+    #[serde(default)]
+    pub eula_accepted_at: Option<String>,
+    /// Proxy used for CurseForge, Modrinth, and pack downloads
+    #[serde(default)]
+    pub proxy: ProxySettings,
+    /// Namespaces this install's containers so several installs can share one Docker host.
+    /// Generated on first launch.
+    #[serde(default)]
+    pub instance_id: String,
+    /// Compression for backups, unless a server's schedule picks its own
+    #[serde(default)]
+    pub backup_compression: BackupCompression,
+    /// Read each new backup back and check it against the data it was made from
+    #[serde(default)]
+    pub verify_backups: bool,
+    /// Offsite copy of backups, for servers that opt in
+    #[serde(default)]
+    pub remote_backup: RemoteBackupSettings,
+    /// Folder holding servers, backups, caches and logs (absolute). Unset uses
+    /// the profile's folder ([`profile_root`]); settings stay there either way.
+    #[serde(default)]
+    pub data_root: Option<String>,
+    /// Language of the UI
+    #[serde(default)]
+    pub language: Language,
+    /// Key bindings for common actions
+    #[serde(default)]
+    pub shortcuts: ShortcutSettings,
+    /// Remote catalog of featured templates, fetched at startup
+    #[serde(default)]
+    pub template_index: TemplateIndexSettings,
+    /// Show the dashboard as a dense table instead of cards
+    #[serde(default)]
+    pub dashboard_compact: bool,
+    /// Minimize the window instead of closing it while servers are running, so
+    /// schedules, broadcasts and crash checks keep going
+    #[serde(default)]
+    pub minimize_on_close: bool,
+    /// Look up this network's public IP for the dashboard's Copy address menu
+    #[serde(default)]
+    pub detect_public_ip: bool,
+    /// Prometheus endpoint with per-server metrics
+    #[serde(default)]
+    pub metrics: MetricsSettings,
+    /// Where alerts from servers' alert rules are sent
+    #[serde(default)]
+    pub alerts: AlertSettings,
+    /// Chat webhooks and the server/player/backup events posted to each
+    #[serde(default)]
+    pub webhooks: Vec<Webhook>,
+    /// Discord bot taking `!start`/`!stop`/`!status`/`!whitelist` commands
+    #[serde(default)]
+    pub discord_bot: DiscordBotSettings,
+    /// Keep a DuckDNS/Cloudflare hostname pointed at the public IP
+    #[serde(default)]
+    pub ddns: DdnsSettings,
+    /// Defaults standing in for a settings file that is there but couldn't be read;
+    /// [`save_settings`] won't write these over it
+    #[serde(skip)]
+    pub loaded_from_defaults: bool,
+}
+
+impl AppSettings {
+    /// Every credential kept in settings.json: API keys, passwords, bot and DNS tokens,
+    /// and webhook URLs (whose path is the credential). New secret fields belong here
+    /// so diagnostics cover them.
+    pub fn stored_secrets(&self) -> Vec<&str> {
+        let remote = &self.remote_backup;
+        let mut secrets = vec![
+            self.curseforge_api_key.as_deref().unwrap_or_default(),
+            self.modrinth_token.as_deref().unwrap_or_default(),
+            &remote.s3_secret_key,
+            &remote.webdav_password,
+            &remote.sftp_password,
+            &self.alerts.webhook_url,
+            &self.discord_bot.token,
+            &self.ddns.token,
+        ];
+        secrets.extend(self.webhooks.iter().map(|w| w.url.as_str()));
+        secrets.retain(|s| !s.trim().is_empty());
+        secrets
+    }
+}
+
+/// Template catalog published alongside the app, so new packs and fixed pack
+/// URLs arrive without a new release
+pub const DEFAULT_TEMPLATE_INDEX_URL: &str =
+    "https://raw.githubusercontent.com/meltingscales/DrakonixAnvil/main/template-index.json";
+
+/// Where featured templates are fetched from
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct TemplateIndexSettings {
+    pub enabled: bool,
+    pub url: String,
+}
+
+impl Default for TemplateIndexSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            url: DEFAULT_TEMPLATE_INDEX_URL.to_string(),
+        }
+    }
+}
+
+/// Delivery of alerts; the rules themselves are per server
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct AlertSettings {
+    /// Show a desktop notification
+    pub desktop: bool,
+    /// Discord, Slack or compatible webhook to post alerts to (empty for none)
+    pub webhook_url: String,
+}
+
+/// Embedded HTTP endpoint serving metrics in Prometheus format
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct MetricsSettings {
+    pub enabled: bool,
+    /// `host:port` to listen on
+    pub bind: String,
+}
+
+impl Default for MetricsSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bind: crate::metrics::DEFAULT_BIND.to_string(),
+        }
+    }
+}
+
+/// Where backups are uploaded after they're written locally
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub enum RemoteBackupKind {
+    #[default]
+    Disabled,
+    /// Any S3-compatible store: AWS, Backblaze B2, MinIO, Wasabi...
+    S3,
+    WebDav,
+    Sftp,
+}
+
+/// Remote backup destination. Uploads go to `<server name>/<backup file>` under it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct RemoteBackupSettings {
+    pub kind: RemoteBackupKind,
+    /// e.g. `https://s3.eu-west-1.amazonaws.com` or `https://s3.us-west-004.backblazeb2.com`
+    pub s3_endpoint: String,
+    pub s3_region: String,
+    pub s3_bucket: String,
+    pub s3_access_key: String,
+    pub s3_secret_key: String,
+    /// Folder URL, e.g. `https://cloud.example.com/remote.php/dav/files/me/backups`
+    pub webdav_url: String,
+    pub webdav_username: String,
+    pub webdav_password: String,
+    pub sftp_host: String,
+    pub sftp_port: u16,
+    pub sftp_username: String,
+    /// Login password, or the private key's passphrase when a key is set
+    pub sftp_password: String,
+    /// Private key file (OpenSSH or PEM); empty to log in with the password
+    pub sftp_key_path: String,
+    /// Folder on the host, relative to the login directory unless absolute
+    pub sftp_dir: String,
+    /// Newest uploads kept per server; 0 keeps them all
+    pub keep_last: u32,
+}
+
+impl Default for RemoteBackupSettings {
+    fn default() -> Self {
+        Self {
+            kind: RemoteBackupKind::Disabled,
+            s3_endpoint: String::new(),
+            s3_region: String::new(),
+            s3_bucket: String::new(),
+            s3_access_key: String::new(),
+            s3_secret_key: String::new(),
+            webdav_url: String::new(),
+            webdav_username: String::new(),
+            webdav_password: String::new(),
+            sftp_host: String::new(),
+            sftp_port: 22,
+            sftp_username: String::new(),
+            sftp_password: String::new(),
+            sftp_key_path: String::new(),
+            sftp_dir: String::new(),
+            keep_last: 0,
+        }
+    }
+}
+
+/// How files are compressed in backup archives
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum BackupCompression {
+    /// No compression: fastest, and region files barely shrink anyway
+    Store,
+    /// Level 1-9. Opens in every zip tool but is slow and single-threaded on big worlds.
+    Deflate { level: u8 },
+    /// Level 1-22. Much faster than Deflate at a similar ratio; needs a zstd-aware
+    /// zip tool to open outside the app.
+    Zstd { level: u8 },
+}
+
+impl Default for BackupCompression {
+    fn default() -> Self {
+        BackupCompression::Deflate { level: 6 }
+    }
+}
+
+impl BackupCompression {
+    pub fn label(self) -> String {
+        match self {
+            BackupCompression::Store => tr("compression-store"),
+            BackupCompression::Deflate { .. } => tr("compression-deflate"),
+            BackupCompression::Zstd { .. } => tr("compression-zstd"),
+        }
+    }
+}
+
+/// How outbound HTTP requests pick a proxy
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub enum ProxyMode {
+    /// Use HTTP_PROXY / HTTPS_PROXY / ALL_PROXY / NO_PROXY from the environment
+    #[default]
+    System,
+    /// Connect directly, ignoring environment variables
+    Disabled,
+    /// Use the configured proxy URL
+    Manual,
+}
+
+/// Proxy configuration for outbound HTTP requests
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct ProxySettings {
+    pub mode: ProxyMode,
+    /// e.g. `http://proxy.example.com:3128` or `socks5://127.0.0.1:1080`
+    pub url: String,
+    /// Comma-separated hosts that bypass the proxy (e.g. `localhost,.internal`)
+    pub no_proxy: String,
 }
 
 /// Path to the settings file
 pub fn get_settings_path() -> PathBuf {
-    PathBuf::from(DATA_ROOT).join("settings.json")
+    profile_root().join("settings.json")
 }
 
-/// Load settings from disk
+/// Host keys of SFTP backup targets, remembered the first time each is seen
+#[cfg(feature = "sftp")]
+pub fn get_sftp_known_hosts_path() -> PathBuf {
+    profile_root().join("sftp_known_hosts")
+}
+
+/// Load settings from disk, assigning an instance ID if this install doesn't have one yet
 pub fn load_settings() -> AppSettings {
     let path = get_settings_path();
-    if !path.exists() {
-        return AppSettings::default();
+    let mut settings = match std::fs::read_to_string(&path) {
+        Ok(json) => match serde_json::from_str(&json) {
+            Ok(settings) => settings,
+            Err(e) => {
+                // Saving defaults over the file would wipe API keys, proxy and storage
+                // settings, so it's moved aside for the user to fix or restore
+                let instance_id = recover_instance_id(&json);
+                let backup = path.with_extension("json.bak");
+                if let Err(move_err) = std::fs::rename(&path, &backup) {
+                    return unsaved_defaults(
+                        format!(
+                            "settings.json couldn't be read ({}) or moved aside ({}); \
+                             defaults are in use and won't be saved over it",
+                            e, move_err
+                        ),
+                        instance_id,
+                    );
+                }
+                set_settings_error(format!(
+                    "settings.json couldn't be read ({}); it was moved to {:?} and \
+                     defaults are in use",
+                    e, backup
+                ));
+                // Keeping the old ID keeps this install's existing containers its own
+                AppSettings {
+                    instance_id: instance_id.unwrap_or_default(),
+                    ..AppSettings::default()
+                }
+            }
+        },
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => AppSettings::default(),
+        Err(e) => {
+            let instance_id = std::fs::read(&path)
+                .ok()
+                .and_then(|bytes| recover_instance_id(&String::from_utf8_lossy(&bytes)));
+            return unsaved_defaults(
+                format!(
+                    "settings.json couldn't be read ({}); defaults are in use and \
+                     won't be saved over it",
+                    e
+                ),
+                instance_id,
+            );
+        }
+    };
+
+    if settings.instance_id.is_empty() {
+        settings.instance_id = generate_instance_id();
+        if let Err(e) = save_settings(&settings) {
+            tracing::warn!("Failed to save new instance ID: {}", e);
+        }
     }
+    settings
+}
 
-    match std::fs::read_to_string(&path) {
-        Ok(json) => serde_json::from_str(&json).unwrap_or_default(),
-        Err(_) => AppSettings::default(),
+/// Defaults for this run only, when the settings file is there but unusable
+fn unsaved_defaults(error: String, instance_id: Option<String>) -> AppSettings {
+    set_settings_error(error);
+    AppSettings {
+        instance_id: instance_id.unwrap_or_else(generate_instance_id),
+        loaded_from_defaults: true,
+        ..AppSettings::default()
     }
 }
 
-/// Save settings to disk
+/// The `instance_id` out of a settings file that doesn't parse as a whole
+fn recover_instance_id(text: &str) -> Option<String> {
+    let re = regex::Regex::new(r#""instance_id"\s*:\s*"([a-z0-9]+)""#).ok()?;
+    re.captures(text).map(|c| c[1].to_string())
+}
+
+/// Why the settings file couldn't be loaded, kept for the app to show at startup
+static SETTINGS_ERROR: RwLock<Option<String>> = RwLock::new(None);
+
+fn set_settings_error(error: String) {
+    tracing::error!("{}", error);
+    if let Ok(mut slot) = SETTINGS_ERROR.write() {
+        *slot = Some(error);
+    }
+}
+
+/// The last settings load problem, if any, clearing it
+pub fn take_settings_error() -> Option<String> {
+    SETTINGS_ERROR.write().ok().and_then(|mut slot| slot.take())
+}
+
+/// Short random ID that is valid inside a Docker container name
+fn generate_instance_id() -> String {
+    use rand::Rng;
+
+    const CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+    let mut rng = rand::thread_rng();
+    (0..6)
+        .map(|_| CHARS[rng.gen_range(0..CHARS.len())] as char)
+        .collect()
+}
+
+/// Save settings to disk, unless they are stand-in defaults for a file that
+/// couldn't be read (see [`AppSettings::loaded_from_defaults`])
 pub fn save_settings(settings: &AppSettings) -> Result<()> {
     let path = get_settings_path();
+    if settings.loaded_from_defaults {
+        bail!(
+            "{} couldn't be read at startup, so it isn't saved over; fix or remove it \
+             and restart to keep changes",
+            path.display()
+        );
+    }
 
     // Ensure parent directory exists
     if let Some(parent) = path.parent() {
@@ -44,12 +404,94 @@ pub fn save_settings(settings: &AppSettings) -> Result<()> {
     Ok(())
 }
 
-/// Root directory for all DrakonixAnvil data
+/// Root directory for DrakonixAnvil data, relative to the working directory:
+/// the default profile, and the list of the others (see [`crate::profiles`])
 pub const DATA_ROOT: &str = "./DrakonixAnvilData";
 
+/// File next to the executable that turns on portable mode
+pub const PORTABLE_MARKER: &str = "portable.txt";
+
+/// Data root next to the executable, set when running in portable mode
+static PORTABLE_ROOT: OnceLock<PathBuf> = OnceLock::new();
+
+/// Keep all data next to the executable (e.g. on a USB stick) instead of the
+/// working directory, when `flag` (`--portable`) is given or a [`PORTABLE_MARKER`]
+/// file sits beside the executable. Call once at startup; returns whether
+/// portable mode is on.
+pub fn init_portable(flag: bool) -> bool {
+    let exe_dir = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(Path::to_path_buf));
+    let Some(exe_dir) = exe_dir else {
+        return false;
+    };
+    if !flag && !exe_dir.join(PORTABLE_MARKER).is_file() {
+        return false;
+    }
+    PORTABLE_ROOT.set(exe_dir.join("DrakonixAnvilData")).is_ok()
+}
+
+/// Whether data is kept next to the executable
+pub fn is_portable() -> bool {
+    PORTABLE_ROOT.get().is_some()
+}
+
+/// Where the default profile and the list of the others live: [`DATA_ROOT`],
+/// or next to the executable in portable mode
+pub fn app_root() -> PathBuf {
+    PORTABLE_ROOT
+        .get()
+        .cloned()
+        .unwrap_or_else(|| PathBuf::from(DATA_ROOT))
+}
+
+/// Folder of the open profile, when it isn't the default one in [`app_root`]
+static PROFILE_ROOT: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Data folder chosen in Settings → Storage, when it isn't the profile's folder
+static DATA_ROOT_OVERRIDE: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Folder holding the open profile's settings
+pub fn profile_root() -> PathBuf {
+    PROFILE_ROOT
+        .read()
+        .ok()
+        .and_then(|root| root.clone())
+        .unwrap_or_else(app_root)
+}
+
+/// Point settings at another profile's folder; see [`crate::profiles::open`]
+pub fn set_profile_root(root: PathBuf) {
+    if let Ok(mut current) = PROFILE_ROOT.write() {
+        *current = Some(root);
+    }
+}
+
+/// Root directory for servers, backups, caches and logs
+pub fn data_root() -> PathBuf {
+    DATA_ROOT_OVERRIDE
+        .read()
+        .ok()
+        .and_then(|root| root.clone())
+        .unwrap_or_else(profile_root)
+}
+
+/// Point every data path at `root` (None for the profile's folder); called when
+/// a profile is opened and after the data is moved
+pub fn set_data_root(root: Option<PathBuf>) {
+    if let Ok(mut current) = DATA_ROOT_OVERRIDE.write() {
+        *current = root;
+    }
+}
+
+/// Folder for the app's own log files
+pub fn get_logs_path() -> PathBuf {
+    data_root().join("logs")
+}
+
 /// Path to the servers index file
 pub fn get_servers_index_path() -> PathBuf {
-    PathBuf::from(DATA_ROOT).join("servers.json")
+    data_root().join("servers.json")
 }
 
 /// Save all servers to disk
@@ -79,9 +521,14 @@ pub fn load_servers() -> Result<Vec<ServerInstance>> {
     Ok(servers)
 }
 
+/// Folder holding every server's directory
+pub fn get_servers_root() -> PathBuf {
+    data_root().join("servers")
+}
+
 /// Get the path to a server's data directory
 pub fn get_server_path(server_name: &str) -> PathBuf {
-    PathBuf::from(DATA_ROOT).join("servers").join(server_name)
+    get_servers_root().join(server_name)
 }
 
 /// Get the path to a server's data volume (mounted as /data in container)
@@ -101,15 +548,40 @@ pub fn get_server_metadata_path(server_name: &str) -> PathBuf {
     get_server_path(server_name).join("server.json")
 }
 
+/// Folder holding every server's backups
+pub fn get_backups_root() -> PathBuf {
+    data_root().join("backups")
+}
+
+/// Downloaded pack archives shared by all servers
+pub fn get_download_cache_path() -> PathBuf {
+    data_root().join("cache").join("packs")
+}
+
+/// Recent CurseForge/Modrinth API responses, see [`crate::api_cache`]
+pub fn get_api_cache_path() -> PathBuf {
+    data_root().join("cache").join("api")
+}
+
+/// Templates imported from files or URLs
+pub fn get_templates_path() -> PathBuf {
+    data_root().join("templates")
+}
+
+/// Last template index fetched, used until the next fetch succeeds
+pub fn get_template_index_cache_path() -> PathBuf {
+    data_root().join("cache").join("template-index.json")
+}
+
 /// Get the path to backups for a server
 pub fn get_backup_path(server_name: &str) -> PathBuf {
-    PathBuf::from(DATA_ROOT).join("backups").join(server_name)
+    get_backups_root().join(server_name)
 }
 
-/// Find server directories in DrakonixAnvilData/servers/ that aren't tracked by any ServerConfig.
+/// Find server directories in the data root's servers/ that aren't tracked by any ServerConfig.
 /// Returns sorted directory names. Returns empty vec on IO errors.
 pub fn find_orphaned_server_dirs(servers: &[ServerInstance]) -> Vec<String> {
-    let servers_dir = PathBuf::from(DATA_ROOT).join("servers");
+    let servers_dir = get_servers_root();
     let entries = match std::fs::read_dir(&servers_dir) {
         Ok(entries) => entries,
         Err(_) => return Vec::new(),
@@ -132,7 +604,45 @@ pub fn find_orphaned_server_dirs(servers: &[ServerInstance]) -> Vec<String> {
 /// Docker container name prefix
 pub const CONTAINER_PREFIX: &str = "drakonix";
 
-/// Get the Docker container name for a server
-pub fn get_container_name(server_name: &str) -> String {
-    format!("{}-{}", CONTAINER_PREFIX, server_name)
+/// Get the Docker container name for a server, namespaced by this install's instance ID
+pub fn get_container_name(instance_id: &str, server_name: &str) -> String {
+    format!("{}-{}-{}", CONTAINER_PREFIX, instance_id, server_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failed_load_is_not_saved_over() {
+        let root = std::env::temp_dir().join(format!("drakonix-settings-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+        set_profile_root(root.clone());
+        let path = get_settings_path();
+
+        // Not UTF-8, so it can't even be read as text
+        let unreadable = b"{\"instance_id\": \"abc123\", \"proxy\": \"\xff\"}".to_vec();
+        std::fs::write(&path, &unreadable).unwrap();
+        let mut settings = load_settings();
+        assert!(settings.loaded_from_defaults);
+        assert_eq!(settings.instance_id, "abc123");
+        settings.dashboard_compact = true;
+        assert!(save_settings(&settings).is_err());
+        assert_eq!(std::fs::read(&path).unwrap(), unreadable);
+
+        // Readable but broken JSON is moved aside, and the instance ID carried over
+        let broken = "{\"instance_id\": \"def456\", \"proxy\": ";
+        std::fs::write(&path, broken).unwrap();
+        let settings = load_settings();
+        assert!(!settings.loaded_from_defaults);
+        assert_eq!(settings.instance_id, "def456");
+        assert_eq!(
+            std::fs::read_to_string(path.with_extension("json.bak")).unwrap(),
+            broken
+        );
+        assert!(take_settings_error().is_some());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
 }
@@ -0,0 +1,203 @@
+//! Minecraft crash reports: finds the files a server writes to `crash-reports/`
+//! and pulls the useful parts out of them for a readable summary.
+
+use crate::config::get_server_data_path;
+use anyhow::{Context, Result};
+use regex::Regex;
+use std::path::{Path, PathBuf};
+
+/// How many stack frames the summary shows
+const STACK_HEAD_LINES: usize = 8;
+/// Cap on registry names listed, some reports dump whole chunks of world state
+const MAX_REGISTRY_NAMES: usize = 20;
+
+#[derive(Debug, Clone, Default)]
+pub struct CrashReport {
+    pub path: PathBuf,
+    /// `Time:` line, as written by the game
+    pub time: Option<String>,
+    /// `Description:` line, e.g. `Exception in server tick loop`
+    pub description: Option<String>,
+    /// First line of the exception, e.g. `java.lang.NullPointerException: ...`
+    pub exception: Option<String>,
+    pub stack_head: Vec<String>,
+    /// Mods the loader blamed, or failing that, mods that appear in the stack frames
+    pub suspected_mods: Vec<String>,
+    /// Registry names (`create:mechanical_press`) mentioned in the detail sections
+    pub registry_names: Vec<String>,
+    pub raw: String,
+}
+
+fn crash_dir(server_name: &str) -> PathBuf {
+    get_server_data_path(server_name).join("crash-reports")
+}
+
+/// Crash report files, newest first. The game names them
+/// `crash-<yyyy-mm-dd_hh.mm.ss>-server.txt`, so name order is time order.
+pub fn list(server_name: &str) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(crash_dir(server_name)) else {
+        return Vec::new();
+    };
+    let mut reports: Vec<PathBuf> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| {
+            p.file_name()
+                .map(|n| n.to_string_lossy())
+                .is_some_and(|n| n.starts_with("crash-") && n.ends_with(".txt"))
+        })
+        .collect();
+    reports.sort();
+    reports.reverse();
+    reports
+}
+
+/// File name of the newest crash report, if the server has any
+pub fn newest(server_name: &str) -> Option<String> {
+    list(server_name)
+        .first()
+        .and_then(|p| p.file_name())
+        .map(|n| n.to_string_lossy().to_string())
+}
+
+pub fn load(path: &Path) -> Result<CrashReport> {
+    let raw = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let mut report = parse(&raw);
+    report.path = path.to_path_buf();
+    report.raw = raw;
+    Ok(report)
+}
+
+fn parse(raw: &str) -> CrashReport {
+    let mut report = CrashReport::default();
+    let lines: Vec<&str> = raw.lines().collect();
+
+    for line in &lines {
+        if let Some(time) = line.strip_prefix("Time: ") {
+            report.time.get_or_insert_with(|| time.trim().to_string());
+        } else if let Some(description) = line.strip_prefix("Description: ") {
+            report
+                .description
+                .get_or_insert_with(|| description.trim().to_string());
+        }
+    }
+
+    // The exception follows the description, then its frames until the first blank line
+    if let Some(start) = lines.iter().position(|l| l.starts_with("Description: ")) {
+        let mut rest = lines[start + 1..]
+            .iter()
+            .skip_while(|l| l.trim().is_empty());
+        if let Some(exception) = rest.next() {
+            report.exception = Some(exception.trim().to_string());
+            report.stack_head = rest
+                .take_while(|l| !l.trim().is_empty())
+                .filter(|l| l.trim_start().starts_with("at "))
+                .take(STACK_HEAD_LINES)
+                .map(|l| l.trim().to_string())
+                .collect();
+        }
+    }
+
+    report.suspected_mods = suspected_mods(&lines);
+    if report.suspected_mods.is_empty() {
+        report.suspected_mods = mods_in_frames(&lines);
+    }
+    report.registry_names = registry_names(&lines);
+    report
+}
+
+/// Forge's `Suspected Mods: Create (create), Version: ...` and NeoForge's
+/// multi-line `Suspected Mod:` block
+fn suspected_mods(lines: &[&str]) -> Vec<String> {
+    let mut mods = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        let Some(value) = trimmed
+            .strip_prefix("Suspected Mods:")
+            .or_else(|| trimmed.strip_prefix("Suspected Mod:"))
+        else {
+            continue;
+        };
+        let value = value.trim();
+        if value.eq_ignore_ascii_case("none") {
+            continue;
+        }
+        if !value.is_empty() {
+            mods.extend(
+                value
+                    .split(", ")
+                    .filter(|m| !m.starts_with("Version"))
+                    .map(|m| m.to_string()),
+            );
+            continue;
+        }
+        // Block form: one indented `Name (id), Version: x` line per mod
+        for next in &lines[i + 1..] {
+            let next = next.trim();
+            if next.is_empty() || (next.ends_with(':') && !next.contains(", Version")) {
+                break;
+            }
+            if let Some((name, _)) = next.split_once(", Version") {
+                mods.push(name.to_string());
+            }
+        }
+    }
+    mods.dedup();
+    mods
+}
+
+/// Mod ids from transformed stack frames, e.g. `at TRANSFORMER/create@0.5.1/...`
+fn mods_in_frames(lines: &[&str]) -> Vec<String> {
+    let mut mods: Vec<String> = Vec::new();
+    for line in lines {
+        let Some((_, rest)) = line.split_once("TRANSFORMER/") else {
+            continue;
+        };
+        let Some((id, _)) = rest.split_once('@') else {
+            continue;
+        };
+        if id != "minecraft" && !mods.iter().any(|m| m == id) {
+            mods.push(id.to_string());
+        }
+    }
+    mods
+}
+
+/// `namespace:path` names from the detail sections (block entity being ticked,
+/// entity being ticked and so on). System details are skipped.
+fn registry_names(lines: &[&str]) -> Vec<String> {
+    let Ok(re) = Regex::new(r"\b([a-z0-9_-]+):([a-z0-9_][a-z0-9_/.-]*)\b") else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = Vec::new();
+    let mut in_details = false;
+    for line in lines {
+        let trimmed = line.trim();
+        if trimmed.starts_with("-- ") && trimmed.ends_with(" --") {
+            in_details = trimmed != "-- System Details --";
+            continue;
+        }
+        if !in_details || trimmed.starts_with("at ") {
+            continue;
+        }
+        for cap in re.captures_iter(trimmed) {
+            let whole = cap.get(0).map_or(0..0, |m| m.range());
+            let name = trimmed[whole.clone()].to_string();
+            // Skip `http://`, `Foo.java:123` line numbers and `mod.mixins.json:Mixin` names
+            if cap[2].chars().all(|c| c.is_ascii_digit())
+                || cap[2].starts_with('/')
+                || trimmed[..whole.start].ends_with('.')
+            {
+                continue;
+            }
+            if !names.contains(&name) {
+                names.push(name);
+            }
+            if names.len() >= MAX_REGISTRY_NAMES {
+                return names;
+            }
+        }
+    }
+    names
+}
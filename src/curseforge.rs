@@ -48,6 +48,29 @@ pub struct CfFilesResponse {
     pub data: Vec<CfFile>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct CfFingerprintResponse {
+    pub data: CfFingerprintMatches,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CfFingerprintMatches {
+    pub exact_matches: Vec<CfFingerprintMatch>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CfFingerprintMatch {
+    /// Mod (project) ID
+    pub id: u64,
+    pub file: CfFile,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CfModResponse {
+    pub data: CfMod,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CfDescriptionResponse {
     pub data: String,
@@ -58,12 +81,72 @@ pub struct CfDescriptionResponse {
 pub struct CfFile {
     pub id: u64,
     pub display_name: String,
-    #[allow(dead_code)] // Available for future display
     pub file_name: String,
     pub game_versions: Vec<String>,
     pub file_date: String,
-    #[allow(dead_code)] // Deserialized from API, may be useful for display
+    /// Separate server pack published alongside this (client) file, if any
     pub server_pack_file_id: Option<u64>,
+    #[serde(default)]
+    pub hashes: Vec<CfFileHash>,
+    /// Missing for files whose authors opted out of third-party downloads
+    #[serde(default)]
+    pub download_url: Option<String>,
+    #[serde(default)]
+    pub dependencies: Vec<CfFileDependency>,
+    /// Murmur2 fingerprint CurseForge identifies the file by
+    #[serde(default)]
+    pub file_fingerprint: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CfFileDependency {
+    pub mod_id: u64,
+    /// 1 = embedded, 2 = optional, 3 = required, 4 = tool, 5 = incompatible, 6 = include
+    pub relation_type: u32,
+}
+
+impl CfFileDependency {
+    pub fn is_required(&self) -> bool {
+        self.relation_type == 3
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CfFileHash {
+    pub value: String,
+    /// 1 = SHA-1, 2 = MD5
+    pub algo: u32,
+}
+
+impl CfFile {
+    /// SHA-1 of the file, which CurseForge lists next to its MD5
+    pub fn sha1(&self) -> Option<&str> {
+        self.hashes
+            .iter()
+            .find(|h| h.algo == 1)
+            .map(|h| h.value.as_str())
+    }
+
+    /// Where to download the file. Files without an API download URL are still
+    /// served from the CDN under their ID and name.
+    pub fn url(&self) -> String {
+        self.download_url.clone().unwrap_or_else(|| {
+            format!(
+                "https://mediafilez.forgecdn.net/files/{}/{}/{}",
+                self.id / 1000,
+                self.id % 1000,
+                self.file_name
+            )
+        })
+    }
+
+    /// Tagged for the client but not the server, so it would crash or do
+    /// nothing on a dedicated server
+    pub fn is_client_only(&self) -> bool {
+        let tagged = |tag: &str| self.game_versions.iter().any(|v| v == tag);
+        tagged("Client") && !tagged("Server")
+    }
 }
 
 // ── Search parameters ──────────────────────────────────────────────────────
@@ -118,6 +201,7 @@ pub fn mod_loader_api_value(loader: &ModLoader) -> Option<u32> {
 const CF_BASE: &str = "https://api.curseforge.com/v1";
 const GAME_ID: u32 = 432; // Minecraft
 const CLASS_ID: u32 = 4471; // Modpacks
+const MODS_CLASS_ID: u32 = 6;
 
 /// Search CurseForge for modpacks. Returns (results, total_count).
 pub async fn search_modpacks(
@@ -128,14 +212,55 @@ pub async fn search_modpacks(
     sort_field: CfSortField,
     page_offset: u64,
 ) -> anyhow::Result<(Vec<CfMod>, u64)> {
-    let client = reqwest::Client::new();
+    search(
+        api_key,
+        CLASS_ID,
+        query,
+        game_version,
+        mod_loader,
+        sort_field,
+        page_offset,
+    )
+    .await
+}
+
+/// Search CurseForge for mods. Returns (results, total_count).
+pub async fn search_mods(
+    api_key: &str,
+    query: &str,
+    game_version: &str,
+    mod_loader: Option<&ModLoader>,
+    page_offset: u64,
+) -> anyhow::Result<(Vec<CfMod>, u64)> {
+    search(
+        api_key,
+        MODS_CLASS_ID,
+        query,
+        game_version,
+        mod_loader,
+        CfSortField::Popularity,
+        page_offset,
+    )
+    .await
+}
+
+async fn search(
+    api_key: &str,
+    class_id: u32,
+    query: &str,
+    game_version: &str,
+    mod_loader: Option<&ModLoader>,
+    sort_field: CfSortField,
+    page_offset: u64,
+) -> anyhow::Result<(Vec<CfMod>, u64)> {
+    let client = crate::http::client();
 
     let mut req = client
         .get(format!("{}/mods/search", CF_BASE))
         .header("x-api-key", api_key)
         .query(&[
             ("gameId", GAME_ID.to_string()),
-            ("classId", CLASS_ID.to_string()),
+            ("classId", class_id.to_string()),
             ("pageSize", "20".to_string()),
             ("sortField", sort_field.as_api_value().to_string()),
             ("sortOrder", "desc".to_string()),
@@ -154,6 +279,23 @@ pub async fn search_modpacks(
         }
     }
 
+    let data: CfSearchResponse = get_json(req).await?;
+    let total = data.pagination.total_count;
+    Ok((data.data, total))
+}
+
+/// Send a GET and parse the JSON reply, reusing a recent response from the
+/// [`crate::api_cache`] when there is one
+async fn get_json<T: serde::de::DeserializeOwned>(
+    req: reqwest::RequestBuilder,
+) -> anyhow::Result<T> {
+    let key = crate::api_cache::key(&req);
+    if let Some(body) = key.as_deref().and_then(crate::api_cache::get) {
+        if let Ok(data) = serde_json::from_str(&body) {
+            return Ok(data);
+        }
+    }
+
     let resp = req.send().await?;
     if !resp.status().is_success() {
         let status = resp.status();
@@ -161,19 +303,152 @@ pub async fn search_modpacks(
         anyhow::bail!("CurseForge API error {}: {}", status, body);
     }
 
-    let data: CfSearchResponse = resp.json().await?;
-    let total = data.pagination.total_count;
-    Ok((data.data, total))
+    let body = resp.text().await?;
+    let data = serde_json::from_str(&body)?;
+    if let Some(key) = key {
+        crate::api_cache::put(&key, &body);
+    }
+    Ok(data)
 }
 
 /// Fetch available files for a specific mod/modpack.
 pub async fn get_mod_files(api_key: &str, mod_id: u64) -> anyhow::Result<Vec<CfFile>> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
 
-    let resp = client
+    let req = client
         .get(format!("{}/mods/{}/files", CF_BASE, mod_id))
         .header("x-api-key", api_key)
-        .query(&[("pageSize", "50")])
+        .query(&[("pageSize", "50")]);
+
+    let data: CfFilesResponse = get_json(req).await?;
+    Ok(data.data)
+}
+
+/// Fetch a mod's files for one Minecraft version and loader, newest first.
+pub async fn get_compatible_files(
+    api_key: &str,
+    mod_id: u64,
+    game_version: &str,
+    mod_loader: &ModLoader,
+) -> anyhow::Result<Vec<CfFile>> {
+    let client = crate::http::client();
+
+    let mut req = client
+        .get(format!("{}/mods/{}/files", CF_BASE, mod_id))
+        .header("x-api-key", api_key)
+        .query(&[("pageSize", "50"), ("gameVersion", game_version)]);
+    if let Some(val) = mod_loader_api_value(mod_loader) {
+        req = req.query(&[("modLoaderType", val.to_string())]);
+    }
+
+    let data: CfFilesResponse = get_json(req).await?;
+    Ok(data.data)
+}
+
+/// Fetch one mod (name, slug, ...) by its ID.
+pub async fn get_mod(api_key: &str, mod_id: u64) -> anyhow::Result<CfMod> {
+    let client = crate::http::client();
+
+    let req = client
+        .get(format!("{}/mods/{}", CF_BASE, mod_id))
+        .header("x-api-key", api_key);
+
+    let data: CfModResponse = get_json(req).await?;
+    Ok(data.data)
+}
+
+/// File ID in a CurseForge CDN link, e.g. `.../files/5410/874/pack.zip` -> 5410874
+pub fn file_id_from_cdn_url(url: &str) -> Option<u64> {
+    let (host, path) = url.split_once("://")?.1.split_once('/')?;
+    if !host.ends_with("forgecdn.net") {
+        return None;
+    }
+    let mut parts = path.strip_prefix("files/")?.split('/');
+    let high: u64 = parts.next()?.parse().ok()?;
+    let low = parts.next()?;
+    // The low part is the last three digits, zero-padded or not
+    (low.len() <= 3).then_some(())?;
+    Some(high * 1000 + low.parse::<u64>().ok()?)
+}
+
+/// Fetch one file's metadata (name, hashes, ...) by its ID alone.
+pub async fn get_file(api_key: &str, file_id: u64) -> anyhow::Result<CfFile> {
+    get_files(api_key, &[file_id])
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("CurseForge has no file {}", file_id))
+}
+
+/// Fetch metadata for many files at once, e.g. every mod in a pack manifest.
+/// IDs CurseForge doesn't know are left out of the result.
+pub async fn get_files(api_key: &str, file_ids: &[u64]) -> anyhow::Result<Vec<CfFile>> {
+    let client = crate::http::client();
+
+    let mut files = Vec::with_capacity(file_ids.len());
+    for chunk in file_ids.chunks(500) {
+        let resp = client
+            .post(format!("{}/mods/files", CF_BASE))
+            .header("x-api-key", api_key)
+            .json(&serde_json::json!({ "fileIds": chunk }))
+            .send()
+            .await?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().await.unwrap_or_default();
+            anyhow::bail!("CurseForge API error {}: {}", status, body);
+        }
+
+        let data: CfFilesResponse = resp.json().await?;
+        files.extend(data.data);
+    }
+    Ok(files)
+}
+
+/// Fingerprint of a file as CurseForge computes it: MurmurHash2 (seed 1) of the
+/// bytes with tabs, newlines, carriage returns and spaces taken out
+pub fn fingerprint(bytes: &[u8]) -> u32 {
+    const M: u32 = 0x5bd1_e995;
+    let data: Vec<u8> = bytes
+        .iter()
+        .copied()
+        .filter(|b| !matches!(b, 9 | 10 | 13 | 32))
+        .collect();
+
+    let mut h = 1 ^ data.len() as u32;
+    let mut chunks = data.chunks_exact(4);
+    for chunk in &mut chunks {
+        let mut k = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        k = k.wrapping_mul(M);
+        k ^= k >> 24;
+        k = k.wrapping_mul(M);
+        h = h.wrapping_mul(M) ^ k;
+    }
+    let rest = chunks.remainder();
+    if !rest.is_empty() {
+        for (i, b) in rest.iter().enumerate() {
+            h ^= (*b as u32) << (8 * i);
+        }
+        h = h.wrapping_mul(M);
+    }
+    h ^= h >> 13;
+    h = h.wrapping_mul(M);
+    h ^ (h >> 15)
+}
+
+/// Find the CurseForge files with these fingerprints. Unknown fingerprints are
+/// left out of the result.
+pub async fn match_fingerprints(
+    api_key: &str,
+    fingerprints: &[u32],
+) -> anyhow::Result<Vec<CfFingerprintMatch>> {
+    let client = crate::http::client();
+
+    let resp = client
+        .post(format!("{}/fingerprints/{}", CF_BASE, GAME_ID))
+        .header("x-api-key", api_key)
+        .json(&serde_json::json!({ "fingerprints": fingerprints }))
         .send()
         .await?;
 
@@ -183,17 +458,22 @@ pub async fn get_mod_files(api_key: &str, mod_id: u64) -> anyhow::Result<Vec<CfF
         anyhow::bail!("CurseForge API error {}: {}", status, body);
     }
 
-    let data: CfFilesResponse = resp.json().await?;
-    Ok(data.data)
+    let data: CfFingerprintResponse = resp.json().await?;
+    Ok(data.data.exact_matches)
 }
 
-/// Fetch the HTML description for a mod/modpack and return it as plain text.
-pub async fn get_mod_description(api_key: &str, mod_id: u64) -> anyhow::Result<String> {
-    let client = reqwest::Client::new();
+/// Look up a modpack by its URL slug
+pub async fn get_modpack_by_slug(api_key: &str, slug: &str) -> anyhow::Result<CfMod> {
+    let client = crate::http::client();
 
     let resp = client
-        .get(format!("{}/mods/{}/description", CF_BASE, mod_id))
+        .get(format!("{}/mods/search", CF_BASE))
         .header("x-api-key", api_key)
+        .query(&[
+            ("gameId", GAME_ID.to_string()),
+            ("classId", CLASS_ID.to_string()),
+            ("slug", slug.to_string()),
+        ])
         .send()
         .await?;
 
@@ -203,7 +483,22 @@ pub async fn get_mod_description(api_key: &str, mod_id: u64) -> anyhow::Result<S
         anyhow::bail!("CurseForge API error {}: {}", status, body);
     }
 
-    let data: CfDescriptionResponse = resp.json().await?;
+    let data: CfSearchResponse = resp.json().await?;
+    data.data
+        .into_iter()
+        .find(|m| m.slug == slug)
+        .ok_or_else(|| anyhow::anyhow!("No CurseForge modpack with slug '{}'", slug))
+}
+
+/// Fetch the HTML description for a mod/modpack and return it as plain text.
+pub async fn get_mod_description(api_key: &str, mod_id: u64) -> anyhow::Result<String> {
+    let client = crate::http::client();
+
+    let req = client
+        .get(format!("{}/mods/{}/description", CF_BASE, mod_id))
+        .header("x-api-key", api_key);
+
+    let data: CfDescriptionResponse = get_json(req).await?;
     Ok(strip_html(&data.data))
 }
 
@@ -319,21 +614,6 @@ pub fn format_downloads(count: u64) -> String {
     }
 }
 
-/// Default G1GC JVM arguments.
-pub fn default_java_args() -> Vec<String> {
-    vec![
-        "-XX:+UseG1GC".to_string(),
-        "-XX:+ParallelRefProcEnabled".to_string(),
-        "-XX:MaxGCPauseMillis=200".to_string(),
-        "-XX:+UnlockExperimentalVMOptions".to_string(),
-        "-XX:+DisableExplicitGC".to_string(),
-        "-XX:G1NewSizePercent=30".to_string(),
-        "-XX:G1MaxNewSizePercent=40".to_string(),
-        "-XX:G1HeapRegionSize=8M".to_string(),
-        "-XX:G1ReservePercent=20".to_string(),
-    ]
-}
-
 /// Default memory allocation based on Minecraft version.
 /// Modern packs (1.16+) get 6144MB, older get 4096MB.
 pub fn default_memory_mb(mc_version: &str) -> u64 {
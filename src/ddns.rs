@@ -0,0 +1,160 @@
+//! Dynamic DNS: pointing a hostname at this network's public IP through DuckDNS
+//! or a Cloudflare DNS record, so players keep one address when the home
+//! connection's IP changes.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+
+const DUCKDNS_URL: &str = "https://www.duckdns.org/update";
+const CLOUDFLARE_API: &str = "https://api.cloudflare.com/client/v4";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DdnsProvider {
+    #[default]
+    DuckDns,
+    Cloudflare,
+}
+
+impl DdnsProvider {
+    pub const ALL: [DdnsProvider; 2] = [DdnsProvider::DuckDns, DdnsProvider::Cloudflare];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            DdnsProvider::DuckDns => "DuckDNS",
+            DdnsProvider::Cloudflare => "Cloudflare",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct DdnsSettings {
+    pub enabled: bool,
+    pub provider: DdnsProvider,
+    /// e.g. `myserver.duckdns.org`, or the full record name on Cloudflare
+    pub hostname: String,
+    /// DuckDNS account token, or a Cloudflare API token with DNS edit rights
+    pub token: String,
+    /// Cloudflare only: the zone holding the record
+    pub zone_id: String,
+    /// Update at least this often even if the IP looks unchanged
+    pub interval_minutes: u32,
+}
+
+impl Default for DdnsSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            provider: DdnsProvider::default(),
+            hostname: String::new(),
+            token: String::new(),
+            zone_id: String::new(),
+            interval_minutes: 60,
+        }
+    }
+}
+
+/// Point the configured hostname at `ip`
+pub async fn update(settings: &DdnsSettings, ip: &str) -> Result<()> {
+    let hostname = settings.hostname.trim();
+    let token = settings.token.trim();
+    if hostname.is_empty() || token.is_empty() {
+        bail!("Hostname and token are both required");
+    }
+    let ip: IpAddr = ip
+        .parse()
+        .with_context(|| format!("Not an IP address: {}", ip))?;
+    match settings.provider {
+        DdnsProvider::DuckDns => update_duckdns(hostname, token, ip).await,
+        DdnsProvider::Cloudflare => {
+            update_cloudflare(settings.zone_id.trim(), hostname, token, ip).await
+        }
+    }
+}
+
+async fn update_duckdns(hostname: &str, token: &str, ip: IpAddr) -> Result<()> {
+    let domain = hostname.trim_end_matches(".duckdns.org");
+    let ip_param = if ip.is_ipv6() { "ipv6" } else { "ip" };
+    let answer = crate::http::client()
+        .get(DUCKDNS_URL)
+        .query(&[
+            ("domains", domain),
+            ("token", token),
+            (ip_param, &ip.to_string()),
+        ])
+        .send()
+        .await
+        .context("Failed to reach DuckDNS")?
+        .error_for_status()?
+        .text()
+        .await?;
+    // DuckDNS answers KO for a wrong token or domain, without more detail
+    if answer.trim() != "OK" {
+        bail!("DuckDNS refused the update; check the domain and token");
+    }
+    Ok(())
+}
+
+#[derive(Deserialize)]
+struct CfResponse<T> {
+    success: bool,
+    #[serde(default)]
+    errors: Vec<CfError>,
+    result: Option<T>,
+}
+
+#[derive(Deserialize)]
+struct CfError {
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct CfRecord {
+    id: String,
+}
+
+impl<T> CfResponse<T> {
+    fn into_result(self) -> Result<Option<T>> {
+        if !self.success {
+            let messages: Vec<_> = self.errors.into_iter().map(|e| e.message).collect();
+            bail!("Cloudflare: {}", messages.join("; "));
+        }
+        Ok(self.result)
+    }
+}
+
+async fn update_cloudflare(zone_id: &str, hostname: &str, token: &str, ip: IpAddr) -> Result<()> {
+    if zone_id.is_empty() {
+        bail!("Cloudflare needs the zone ID (on the domain's Overview page)");
+    }
+    let record_type = if ip.is_ipv6() { "AAAA" } else { "A" };
+    let records_url = format!("{}/zones/{}/dns_records", CLOUDFLARE_API, zone_id);
+    let found: CfResponse<Vec<CfRecord>> = crate::http::client()
+        .get(&records_url)
+        .bearer_auth(token)
+        .query(&[("type", record_type), ("name", hostname)])
+        .send()
+        .await
+        .context("Failed to reach Cloudflare")?
+        .json()
+        .await
+        .context("Unexpected answer from Cloudflare")?;
+    let record = found
+        .into_result()?
+        .and_then(|records| records.into_iter().next())
+        .with_context(|| format!("No {} record named {} in that zone", record_type, hostname))?;
+
+    let updated: CfResponse<serde_json::Value> = crate::http::client()
+        .patch(format!("{}/{}", records_url, record.id))
+        .bearer_auth(token)
+        .json(&serde_json::json!({ "content": ip.to_string() }))
+        .send()
+        .await
+        .context("Failed to reach Cloudflare")?
+        .json()
+        .await
+        .context("Unexpected answer from Cloudflare")?;
+    updated.into_result()?;
+    Ok(())
+}
@@ -0,0 +1,344 @@
+//! App health self-diagnostics: checks the host environment the app depends on
+//! (Docker, data directory, disk, clock, DNS, port binding, credential storage)
+//! and suggests a fix for anything that isn't right.
+
+use crate::config::{data_root, get_settings_path, AppSettings};
+use crate::docker::DockerManager;
+use crate::i18n::{tr, tr_args};
+use crate::preflight::{CheckStatus, LOW_FREE_DISK_MB, MIN_FREE_DISK_MB};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// API hosts the app downloads modpacks and server metadata from
+const ENDPOINTS: &[(&str, &str)] = &[
+    ("CurseForge", "api.curseforge.com"),
+    ("Modrinth", "api.modrinth.com"),
+    ("Mojang", "piston-meta.mojang.com"),
+];
+
+/// Used as a time source by comparing its HTTP `Date` header with the local clock
+const TIME_SOURCE_URL: &str = "https://api.modrinth.com/";
+
+/// Clock skew beyond this breaks TLS and signed API requests
+const MAX_CLOCK_SKEW_SECS: i64 = 300;
+
+/// Clock skew beyond this is worth mentioning
+const WARN_CLOCK_SKEW_SECS: i64 = 30;
+
+const NETWORK_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone)]
+pub struct DiagnosticCheck {
+    pub name: String,
+    pub status: CheckStatus,
+    pub detail: String,
+    /// What to do about a warning or failure
+    pub hint: Option<String>,
+}
+
+impl DiagnosticCheck {
+    fn pass(name: &str, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status: CheckStatus::Pass,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn problem(
+        name: &str,
+        status: CheckStatus,
+        detail: impl Into<String>,
+        hint: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            status,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DiagnosticsReport {
+    pub checks: Vec<DiagnosticCheck>,
+    pub ran_at: chrono::DateTime<chrono::Local>,
+    /// Started automatically because errors were piling up
+    pub automatic: bool,
+}
+
+impl DiagnosticsReport {
+    pub fn problems(&self) -> impl Iterator<Item = &DiagnosticCheck> {
+        self.checks.iter().filter(|c| c.status != CheckStatus::Pass)
+    }
+}
+
+/// Run every check. Network checks time out after a few seconds each.
+pub async fn run(
+    docker: Option<Arc<DockerManager>>,
+    settings: &AppSettings,
+    automatic: bool,
+) -> DiagnosticsReport {
+    let root = data_root();
+    let mut checks = vec![
+        check_docker(docker).await,
+        check_data_dir(&root),
+        check_disk(&root),
+        check_clock().await,
+    ];
+    for (label, host) in ENDPOINTS {
+        checks.push(check_dns(label, host).await);
+    }
+    checks.push(check_port_bind());
+    checks.push(check_keyring(settings));
+
+    DiagnosticsReport {
+        checks,
+        ran_at: chrono::Local::now(),
+        automatic,
+    }
+}
+
+async fn check_docker(docker: Option<Arc<DockerManager>>) -> DiagnosticCheck {
+    let name = &tr("diag-docker");
+    let hint = tr("diag-docker-hint");
+    let Some(docker) = docker else {
+        return DiagnosticCheck::problem(
+            name,
+            CheckStatus::Fail,
+            tr("diag-docker-not-connected"),
+            hint,
+        );
+    };
+    match tokio::time::timeout(NETWORK_TIMEOUT, docker.check_connection()).await {
+        Ok(Ok(true)) => {
+            let version = docker.get_version().await.unwrap_or_default();
+            DiagnosticCheck::pass(
+                name,
+                tr_args("diag-docker-ok", &[("version", version.into())]),
+            )
+        }
+        Ok(_) => {
+            DiagnosticCheck::problem(name, CheckStatus::Fail, tr("diag-docker-no-answer"), hint)
+        }
+        Err(_) => DiagnosticCheck::problem(
+            name,
+            CheckStatus::Fail,
+            tr("diag-docker-timeout"),
+            tr("diag-docker-timeout-hint"),
+        ),
+    }
+}
+
+fn check_data_dir(data_root: &Path) -> DiagnosticCheck {
+    let name = &tr("diag-data-dir");
+    let probe = data_root.join(".diagnostics-probe");
+    let result = std::fs::create_dir_all(data_root)
+        .and_then(|_| std::fs::write(&probe, b"ok"))
+        .and_then(|_| std::fs::remove_file(&probe));
+    let path = data_root.display().to_string();
+    match result {
+        Ok(()) => {
+            DiagnosticCheck::pass(name, tr_args("diag-data-dir-ok", &[("path", path.into())]))
+        }
+        Err(e) => DiagnosticCheck::problem(
+            name,
+            CheckStatus::Fail,
+            tr_args(
+                "diag-data-dir-fail",
+                &[("path", path.into()), ("error", e.to_string().into())],
+            ),
+            tr("diag-data-dir-hint"),
+        ),
+    }
+}
+
+fn check_disk(data_root: &Path) -> DiagnosticCheck {
+    let name = &tr("diag-disk");
+    let hint = tr("diag-disk-hint");
+    match crate::backup::available_space(data_root) {
+        Ok(bytes) => {
+            let free = tr_args(
+                "diag-disk-free",
+                &[("free", crate::backup::format_bytes(bytes).into())],
+            );
+            let mb = bytes / (1024 * 1024);
+            if mb < MIN_FREE_DISK_MB {
+                DiagnosticCheck::problem(name, CheckStatus::Fail, free, hint)
+            } else if mb < LOW_FREE_DISK_MB {
+                DiagnosticCheck::problem(name, CheckStatus::Warn, free, hint)
+            } else {
+                DiagnosticCheck::pass(name, free)
+            }
+        }
+        Err(e) => DiagnosticCheck::problem(
+            name,
+            CheckStatus::Warn,
+            tr_args("diag-disk-unknown", &[("error", e.to_string().into())]),
+            tr("diag-disk-unknown-hint"),
+        ),
+    }
+}
+
+async fn check_clock() -> DiagnosticCheck {
+    let name = &tr("diag-clock");
+    let client = match crate::http::client_builder()
+        .timeout(NETWORK_TIMEOUT)
+        .build()
+    {
+        Ok(client) => client,
+        Err(e) => {
+            return DiagnosticCheck::problem(
+                name,
+                CheckStatus::Warn,
+                tr_args("diag-clock-no-client", &[("error", e.to_string().into())]),
+                tr("diag-clock-no-client-hint"),
+            )
+        }
+    };
+    let server_time = client
+        .head(TIME_SOURCE_URL)
+        .send()
+        .await
+        .ok()
+        .and_then(|r| {
+            r.headers()
+                .get(reqwest::header::DATE)
+                .and_then(|d| d.to_str().ok())
+                .and_then(|d| chrono::DateTime::parse_from_rfc2822(d).ok())
+        });
+    let Some(server_time) = server_time else {
+        return DiagnosticCheck::problem(
+            name,
+            CheckStatus::Warn,
+            tr("diag-clock-unreachable"),
+            tr("diag-clock-unreachable-hint"),
+        );
+    };
+
+    let skew = (chrono::Utc::now() - server_time.with_timezone(&chrono::Utc))
+        .num_seconds()
+        .abs();
+    let detail = tr_args(
+        "diag-clock-skew",
+        &[("seconds", skew.into()), ("source", TIME_SOURCE_URL.into())],
+    );
+    let hint = tr("diag-clock-hint");
+    if skew > MAX_CLOCK_SKEW_SECS {
+        DiagnosticCheck::problem(name, CheckStatus::Fail, detail, hint)
+    } else if skew > WARN_CLOCK_SKEW_SECS {
+        DiagnosticCheck::problem(name, CheckStatus::Warn, detail, hint)
+    } else {
+        DiagnosticCheck::pass(name, detail)
+    }
+}
+
+async fn check_dns(label: &str, host: &str) -> DiagnosticCheck {
+    let name = tr_args("diag-dns", &[("service", label.into())]);
+    match tokio::time::timeout(NETWORK_TIMEOUT, tokio::net::lookup_host((host, 443))).await {
+        Ok(Ok(mut addrs)) => match addrs.next() {
+            Some(addr) => DiagnosticCheck::pass(&name, format!("{} → {}", host, addr.ip())),
+            None => DiagnosticCheck::problem(
+                &name,
+                CheckStatus::Fail,
+                tr_args("diag-dns-empty", &[("host", host.into())]),
+                tr("diag-dns-empty-hint"),
+            ),
+        },
+        Ok(Err(e)) => DiagnosticCheck::problem(
+            &name,
+            CheckStatus::Fail,
+            format!("{}: {}", host, e),
+            tr("diag-dns-error-hint"),
+        ),
+        Err(_) => DiagnosticCheck::problem(
+            &name,
+            CheckStatus::Fail,
+            tr_args("diag-dns-timeout", &[("host", host.into())]),
+            tr("diag-dns-timeout-hint"),
+        ),
+    }
+}
+
+fn check_port_bind() -> DiagnosticCheck {
+    let name = &tr("diag-port-bind");
+    match std::net::TcpListener::bind(("0.0.0.0", 0)) {
+        Ok(listener) => {
+            let port = listener.local_addr().map(|a| a.port()).unwrap_or(0);
+            DiagnosticCheck::pass(
+                name,
+                tr_args("diag-port-bind-ok", &[("port", port.to_string().into())]),
+            )
+        }
+        Err(e) => DiagnosticCheck::problem(
+            name,
+            CheckStatus::Fail,
+            tr_args("diag-port-bind-fail", &[("error", e.to_string().into())]),
+            tr("diag-port-bind-hint"),
+        ),
+    }
+}
+
+/// API keys, passwords and tokens live in settings.json; report whether that file is private and whether
+/// the OS offers a keyring
+fn check_keyring(settings: &AppSettings) -> DiagnosticCheck {
+    let name = &tr("diag-keyring");
+    let keyring = if system_keyring_available() {
+        tr("diag-keyring-available")
+    } else {
+        tr("diag-keyring-missing")
+    };
+    if settings.stored_secrets().is_empty() {
+        return DiagnosticCheck::pass(
+            name,
+            tr_args("diag-keyring-no-secrets", &[("keyring", keyring.into())]),
+        );
+    }
+
+    let path = get_settings_path();
+    let shown = path.display().to_string();
+    if settings_readable_by_others(&path) {
+        return DiagnosticCheck::problem(
+            name,
+            CheckStatus::Warn,
+            tr_args("diag-keyring-exposed", &[("path", shown.as_str().into())]),
+            tr_args(
+                "diag-keyring-exposed-hint",
+                &[("path", shown.as_str().into())],
+            ),
+        );
+    }
+    DiagnosticCheck::pass(
+        name,
+        tr_args(
+            "diag-keyring-ok",
+            &[("path", shown.into()), ("keyring", keyring.into())],
+        ),
+    )
+}
+
+fn system_keyring_available() -> bool {
+    if cfg!(target_os = "windows") {
+        // Credential Manager is always present
+        true
+    } else if cfg!(target_os = "macos") {
+        Path::new("/usr/bin/security").exists()
+    } else {
+        // Secret Service (GNOME Keyring, KWallet) is reached over the session bus
+        std::env::var_os("DBUS_SESSION_BUS_ADDRESS").is_some()
+    }
+}
+
+#[cfg(unix)]
+fn settings_readable_by_others(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path).is_ok_and(|m| m.permissions().mode() & 0o077 != 0)
+}
+
+#[cfg(not(unix))]
+fn settings_readable_by_others(_path: &Path) -> bool {
+    false
+}
@@ -0,0 +1,284 @@
+//! Discord bot mode: polls one channel through the Discord REST API for `!`
+//! commands from allowed users and hands them to the app, which runs them and
+//! replies. Polling needs no gateway connection, so it works with nothing but
+//! the HTTP client; the bot needs the Message Content intent to read commands.
+
+use crate::server::{LiveStatus, ServerStatus};
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+const API: &str = "https://discord.com/api/v10";
+/// Time between checks for new messages; well under Discord's rate limits
+pub const POLL_INTERVAL: Duration = Duration::from_secs(3);
+/// Wait after a failed poll before trying again
+const RETRY_DELAY: Duration = Duration::from_secs(30);
+/// Discord rejects messages longer than this
+const MAX_MESSAGE_LEN: usize = 2000;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct DiscordBotSettings {
+    pub enabled: bool,
+    pub token: String,
+    /// Channel the bot reads commands from and replies in
+    pub channel_id: String,
+    /// Discord user IDs allowed to run commands. Usernames can be changed and
+    /// taken over, so they never grant access.
+    #[serde(alias = "allowed_users")]
+    pub allowed_user_ids: Vec<String>,
+}
+
+impl DiscordBotSettings {
+    fn allows(&self, author: &Author) -> bool {
+        self.allowed_user_ids
+            .iter()
+            .any(|id| id.trim() == author.id)
+    }
+}
+
+/// Whether `input` looks like a Discord user ID (a snowflake: up to 20 digits)
+pub fn is_user_id(input: &str) -> bool {
+    (1..=20).contains(&input.len()) && input.bytes().all(|b| b.is_ascii_digit())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum BotCommand {
+    Status,
+    Start(String),
+    Stop(String),
+    /// Player name, and the server when there's more than one
+    WhitelistAdd {
+        player: String,
+        server: Option<String>,
+    },
+    Help,
+}
+
+pub const HELP: &str = "Commands: `!status`, `!start <server>`, `!stop <server>`, \
+                        `!whitelist add <player> [server]`";
+
+/// Parse a message; `None` if it isn't a command. A known command with missing
+/// arguments parses as `Help`.
+pub fn parse_command(content: &str) -> Option<BotCommand> {
+    let content = content.trim();
+    let rest = content.strip_prefix('!')?;
+    let (word, args) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+    let args = args.trim();
+    let command = match word.to_lowercase().as_str() {
+        "status" => BotCommand::Status,
+        "help" => BotCommand::Help,
+        "start" if !args.is_empty() => BotCommand::Start(args.to_string()),
+        "stop" if !args.is_empty() => BotCommand::Stop(args.to_string()),
+        "whitelist" => {
+            let mut parts = args.splitn(3, char::is_whitespace);
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(add), Some(player), server) if add.eq_ignore_ascii_case("add") => {
+                    BotCommand::WhitelistAdd {
+                        player: player.to_string(),
+                        server: server
+                            .map(|s| s.trim().to_string())
+                            .filter(|s| !s.is_empty()),
+                    }
+                }
+                _ => BotCommand::Help,
+            }
+        }
+        "start" | "stop" => BotCommand::Help,
+        _ => return None,
+    };
+    Some(command)
+}
+
+/// A command from an allowed user, to be answered in `message_id`'s channel
+#[derive(Debug, Clone)]
+pub struct BotRequest {
+    pub command: BotCommand,
+    pub user: String,
+    pub message_id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Message {
+    id: String,
+    #[serde(default)]
+    content: String,
+    author: Author,
+}
+
+#[derive(Debug, Deserialize)]
+struct Author {
+    id: String,
+    username: String,
+    #[serde(default)]
+    bot: bool,
+}
+
+/// A poll failed in a way retrying won't fix: the settings need changing
+#[derive(Debug)]
+struct SetupError(&'static str);
+
+impl std::fmt::Display for SetupError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl std::error::Error for SetupError {}
+
+#[derive(Debug, Deserialize)]
+struct RateLimited {
+    retry_after: f64,
+}
+
+/// Where a reply goes, and how to authenticate
+#[derive(Debug, Clone)]
+pub struct ReplyTarget {
+    token: String,
+    channel_id: String,
+}
+
+impl ReplyTarget {
+    pub fn new(settings: &DiscordBotSettings) -> Self {
+        Self {
+            token: settings.token.trim().to_string(),
+            channel_id: settings.channel_id.trim().to_string(),
+        }
+    }
+}
+
+/// Reply to a command message, cut to Discord's length limit
+pub async fn reply(target: &ReplyTarget, message_id: &str, text: &str) -> Result<()> {
+    let mut text = text.to_string();
+    if text.len() > MAX_MESSAGE_LEN {
+        let mut end = MAX_MESSAGE_LEN - 1;
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        text.truncate(end);
+        text.push('…');
+    }
+    let body = serde_json::json!({
+        "content": text,
+        "message_reference": { "message_id": message_id, "fail_if_not_exists": false },
+        "allowed_mentions": { "parse": [] },
+    });
+    crate::http::client()
+        .post(format!("{}/channels/{}/messages", API, target.channel_id))
+        .header("Authorization", format!("Bot {}", target.token))
+        .json(&body)
+        .send()
+        .await
+        .context("Failed to reach Discord")?
+        .error_for_status()
+        .context("Discord refused the reply")?;
+    Ok(())
+}
+
+/// Newest messages after `after` (or just the newest one), oldest first
+async fn fetch_messages(target: &ReplyTarget, after: Option<&str>) -> Result<Vec<Message>> {
+    let mut request = crate::http::client()
+        .get(format!("{}/channels/{}/messages", API, target.channel_id))
+        .header("Authorization", format!("Bot {}", target.token));
+    request = match after {
+        Some(id) => request.query(&[("after", id), ("limit", "50")]),
+        None => request.query(&[("limit", "1")]),
+    };
+    let response = request.send().await.context("Failed to reach Discord")?;
+    let setup_error = match response.status().as_u16() {
+        401 => Some("Discord rejected the bot token"),
+        403 => Some("The bot can't read that channel; check its permissions"),
+        404 => Some("Channel not found; check the channel ID"),
+        _ => None,
+    };
+    if let Some(error) = setup_error {
+        return Err(SetupError(error).into());
+    }
+    if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        let wait = response
+            .json::<RateLimited>()
+            .await
+            .map_or(RETRY_DELAY.as_secs_f64(), |r| r.retry_after);
+        tokio::time::sleep(Duration::from_secs_f64(wait)).await;
+        return Ok(Vec::new());
+    }
+    let mut messages: Vec<Message> = response
+        .error_for_status()
+        .context("Discord refused the request")?
+        .json()
+        .await
+        .context("Unexpected answer from Discord")?;
+    messages.reverse();
+    Ok(messages)
+}
+
+/// Poll the channel until the task is aborted. Commands from allowed users go to
+/// `on_command`; others get a refusal. Only returns on a settings problem (bad
+/// token, missing channel); network errors are retried.
+pub async fn run(settings: DiscordBotSettings, on_command: impl Fn(BotRequest)) -> Result<()> {
+    let target = ReplyTarget::new(&settings);
+    if target.token.is_empty() || target.channel_id.is_empty() {
+        bail!("Bot token and channel ID are both required");
+    }
+    // Start after the newest message so old commands aren't run again
+    let mut last_seen = fetch_messages(&target, None)
+        .await?
+        .pop()
+        .map(|m| m.id)
+        .unwrap_or_else(|| "0".to_string());
+    tracing::info!("Discord bot watching channel {}", target.channel_id);
+
+    loop {
+        tokio::time::sleep(POLL_INTERVAL).await;
+        let messages = match fetch_messages(&target, Some(&last_seen)).await {
+            Ok(messages) => messages,
+            Err(e) if e.is::<SetupError>() => return Err(e),
+            Err(e) => {
+                tracing::warn!("Discord bot poll failed: {:#}", e);
+                tokio::time::sleep(RETRY_DELAY).await;
+                continue;
+            }
+        };
+        for message in messages {
+            last_seen = message.id.clone();
+            if message.author.bot {
+                continue;
+            }
+            let Some(command) = parse_command(&message.content) else {
+                continue;
+            };
+            if !settings.allows(&message.author) {
+                let text = "You're not on this bot's list of allowed users.";
+                if let Err(e) = reply(&target, &message.id, text).await {
+                    tracing::warn!("Discord bot reply failed: {:#}", e);
+                }
+                continue;
+            }
+            on_command(BotRequest {
+                command,
+                user: message.author.username,
+                message_id: message.id,
+            });
+        }
+    }
+}
+
+/// One line of the `!status` reply
+pub fn status_line(name: &str, status: &ServerStatus, live: Option<&LiveStatus>) -> String {
+    let state = match status {
+        ServerStatus::Stopped => "stopped".to_string(),
+        ServerStatus::Pulling => "downloading".to_string(),
+        ServerStatus::Starting | ServerStatus::Initializing => "starting".to_string(),
+        ServerStatus::Running => match live {
+            Some(live) => format!(
+                "online, {}/{} players",
+                live.players_online, live.players_max
+            ),
+            None => "online".to_string(),
+        },
+        ServerStatus::Stopping => "stopping".to_string(),
+        ServerStatus::Frozen => "frozen".to_string(),
+        ServerStatus::Error(e) => format!("error: {}", e),
+    };
+    format!("**{}**: {}", name, state)
+}
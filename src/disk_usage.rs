@@ -0,0 +1,101 @@
+//! Space taken by each server's folder and its backups, and free space on the
+//! drive holding the data folder. Measuring walks every file, so it runs in the
+//! background and the result is kept until the next measurement.
+
+use crate::backup;
+use crate::config::{data_root, get_backup_path, get_server_path};
+use crate::preflight::{LOW_FREE_DISK_MB, MIN_FREE_DISK_MB};
+use std::collections::HashMap;
+use std::path::Path;
+use walkdir::WalkDir;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ServerUsage {
+    /// The server's folder: world, mods, configs and logs
+    pub data_bytes: u64,
+    pub backup_bytes: u64,
+}
+
+impl ServerUsage {
+    pub fn total(&self) -> u64 {
+        self.data_bytes + self.backup_bytes
+    }
+}
+
+/// How worried to be about free space on the data drive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpaceLevel {
+    Ok,
+    /// Under LOW_FREE_DISK_MB: modpacks and worlds can use that up quickly
+    Low,
+    /// Under MIN_FREE_DISK_MB: servers won't start
+    Critical,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DiskUsage {
+    /// Keyed by server name
+    pub servers: HashMap<String, ServerUsage>,
+    /// Free space on the drive holding the data folder
+    pub available: Option<u64>,
+}
+
+impl DiskUsage {
+    /// Walk the folder and backups of each server in `names`
+    pub fn measure(names: &[String]) -> Self {
+        let servers = names
+            .iter()
+            .map(|name| {
+                let usage = ServerUsage {
+                    data_bytes: dir_size(&get_server_path(name)),
+                    backup_bytes: dir_size(&get_backup_path(name)),
+                };
+                (name.clone(), usage)
+            })
+            .collect();
+        Self {
+            servers,
+            available: backup::available_space(&data_root()).ok(),
+        }
+    }
+
+    /// Everything used by all servers and their backups
+    pub fn total(&self) -> u64 {
+        self.servers.values().map(ServerUsage::total).sum()
+    }
+
+    pub fn space_level(&self) -> SpaceLevel {
+        let Some(available) = self.available else {
+            return SpaceLevel::Ok;
+        };
+        let mb = available / (1024 * 1024);
+        if mb < MIN_FREE_DISK_MB {
+            SpaceLevel::Critical
+        } else if mb < LOW_FREE_DISK_MB {
+            SpaceLevel::Low
+        } else {
+            SpaceLevel::Ok
+        }
+    }
+
+    /// Servers by space used, largest first
+    pub fn largest(&self) -> Vec<(&str, ServerUsage)> {
+        let mut servers: Vec<_> = self
+            .servers
+            .iter()
+            .map(|(name, usage)| (name.as_str(), *usage))
+            .collect();
+        servers.sort_by_key(|(_, usage)| std::cmp::Reverse(usage.total()));
+        servers
+    }
+}
+
+fn dir_size(path: &Path) -> u64 {
+    WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter_map(|e| e.metadata().ok())
+        .filter(|m| m.is_file())
+        .map(|m| m.len())
+        .sum()
+}
@@ -2,8 +2,8 @@
 
 use anyhow::Result;
 use bollard::container::{
-    Config, CreateContainerOptions, ListContainersOptions, LogsOptions, StartContainerOptions,
-    StopContainerOptions,
+    Config, CreateContainerOptions, ListContainersOptions, LogsOptions, MemoryStatsStats,
+    RenameContainerOptions, StartContainerOptions, StatsOptions, StopContainerOptions,
 };
 use bollard::image::CreateImageOptions;
 use bollard::models::ContainerSummary;
@@ -12,8 +12,76 @@ use futures_util::StreamExt;
 use std::collections::HashMap;
 use std::path::Path;
 
+/// Label holding the instance ID of the DrakonixAnvil install that owns a container
+const INSTANCE_LABEL: &str = "drakonix.instance";
+
+/// Game and RCON ports inside the container. Extra ports are published 1:1, so one of
+/// these as an extra port would replace the game or RCON binding.
+pub const CONTAINER_PORTS: [u16; 2] = [25565, 25575];
+
+/// Log output of one managed container, as fetched for the combined logs view
+#[derive(Debug, Clone)]
+pub struct LogChunk {
+    pub container_id: String,
+    pub name: String,
+    pub state: String,
+    pub text: String,
+    /// Timestamp of the newest line read, passed back as the next fetch's cursor
+    pub last_timestamp: Option<String>,
+}
+
+/// Remove ANSI escape sequences (colours, cursor movement, window titles) that the
+/// image's startup scripts write to stdout
+pub fn strip_ansi(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            // CSI: parameters and intermediates up to a final byte in @..~
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC: up to BEL or ESC \
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\u{7}' {
+                        break;
+                    }
+                    if c == '\u{1b}' {
+                        chars.next_if_eq(&'\\');
+                        break;
+                    }
+                }
+            }
+            // Two-character sequences such as ESC ( B
+            Some('(') | Some(')') => {
+                chars.next();
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Resource use of a running container
+#[derive(Debug, Clone, Copy)]
+pub struct ContainerStats {
+    pub cpu_percent: f64,
+    pub memory_bytes: u64,
+    pub memory_limit_bytes: u64,
+}
+
 pub struct DockerManager {
     client: Docker,
+    instance_id: String,
 }
 
 /// Parameters for creating a Minecraft Docker container
@@ -22,16 +90,22 @@ pub struct CreateContainerParams<'a> {
     pub server_name: &'a str,
     pub image: &'a str,
     pub port: u16,
+    /// Host IP the game and extra ports are published on; RCON and query stay on localhost
+    pub host_ip: &'a str,
     pub rcon_port: u16,
+    pub extra_ports: &'a [u16],
     pub memory_mb: u64,
     pub env_vars: Vec<String>,
     pub data_path: &'a Path,
 }
 
 impl DockerManager {
-    pub fn new() -> Result<Self> {
+    pub fn new(instance_id: &str) -> Result<Self> {
         let client = Docker::connect_with_local_defaults()?;
-        Ok(Self { client })
+        Ok(Self {
+            client,
+            instance_id: instance_id.to_string(),
+        })
     }
 
     pub async fn check_connection(&self) -> Result<bool> {
@@ -49,9 +123,20 @@ impl DockerManager {
         Ok(version.version.unwrap_or_else(|| "unknown".to_string()))
     }
 
+    /// Docker's storage directory (e.g. /var/lib/docker), as reported by the daemon
+    pub async fn docker_root_dir(&self) -> Result<Option<String>> {
+        let info = self.client.info().await?;
+        Ok(info.docker_root_dir)
+    }
+
+    /// Containers created by this install (other installs on the same host are ignored)
     pub async fn list_minecraft_containers(&self) -> Result<Vec<ContainerSummary>> {
+        let instance_filter = format!("{}={}", INSTANCE_LABEL, self.instance_id);
         let mut filters = HashMap::new();
-        filters.insert("label", vec!["drakonix.managed=true"]);
+        filters.insert(
+            "label",
+            vec!["drakonix.managed=true", instance_filter.as_str()],
+        );
 
         let options = ListContainersOptions {
             all: true,
@@ -63,6 +148,35 @@ impl DockerManager {
         Ok(containers)
     }
 
+    /// Managed containers from before instance IDs existed, belonging to any install
+    pub async fn list_legacy_containers(&self) -> Result<Vec<ContainerSummary>> {
+        let mut filters = HashMap::new();
+        filters.insert("label", vec!["drakonix.managed=true"]);
+
+        let options = ListContainersOptions {
+            all: true,
+            filters,
+            ..Default::default()
+        };
+
+        let containers = self.client.list_containers(Some(options)).await?;
+        Ok(containers
+            .into_iter()
+            .filter(|c| {
+                !c.labels
+                    .as_ref()
+                    .is_some_and(|l| l.contains_key(INSTANCE_LABEL))
+            })
+            .collect())
+    }
+
+    pub async fn rename_container(&self, id: &str, new_name: &str) -> Result<()> {
+        self.client
+            .rename_container(id, RenameContainerOptions { name: new_name })
+            .await?;
+        Ok(())
+    }
+
     pub async fn image_exists(&self, image: &str) -> Result<bool> {
         match self.client.inspect_image(image).await {
             Ok(_) => {
@@ -137,6 +251,7 @@ impl DockerManager {
         labels.insert("drakonix.managed", "true");
         labels.insert("drakonix.type", "minecraft-server");
         labels.insert("drakonix.server-name", params.server_name);
+        labels.insert(INSTANCE_LABEL, &self.instance_id);
 
         // Convert data_path to absolute path for Docker bind mount
         let data_path_abs = std::fs::canonicalize(params.data_path)
@@ -150,7 +265,7 @@ impl DockerManager {
                 bindings.insert(
                     "25565/tcp".to_string(),
                     Some(vec![bollard::models::PortBinding {
-                        host_ip: Some("0.0.0.0".to_string()),
+                        host_ip: Some(params.host_ip.to_string()),
                         host_port: Some(params.port.to_string()),
                     }]),
                 );
@@ -162,6 +277,31 @@ impl DockerManager {
                         host_port: Some(params.rcon_port.to_string()),
                     }]),
                 );
+                // Query port (UDP, same number as the game port), localhost only like RCON
+                bindings.insert(
+                    "25565/udp".to_string(),
+                    Some(vec![bollard::models::PortBinding {
+                        host_ip: Some("127.0.0.1".to_string()),
+                        host_port: Some(params.port.to_string()),
+                    }]),
+                );
+                // Extra ports (published 1:1)
+                for extra in params.extra_ports {
+                    let key = format!("{}/tcp", extra);
+                    if bindings.contains_key(&key) {
+                        anyhow::bail!(
+                            "Extra port {} clashes with the game, RCON or another extra port",
+                            extra
+                        );
+                    }
+                    bindings.insert(
+                        key,
+                        Some(vec![bollard::models::PortBinding {
+                            host_ip: Some(params.host_ip.to_string()),
+                            host_port: Some(extra.to_string()),
+                        }]),
+                    );
+                }
                 bindings
             }),
             binds: Some(vec![bind_mount]),
@@ -177,6 +317,10 @@ impl DockerManager {
         let mut exposed_ports = HashMap::new();
         exposed_ports.insert("25565/tcp".to_string(), HashMap::new());
         exposed_ports.insert("25575/tcp".to_string(), HashMap::new());
+        exposed_ports.insert("25565/udp".to_string(), HashMap::new());
+        for extra in params.extra_ports {
+            exposed_ports.insert(format!("{}/tcp", extra), HashMap::new());
+        }
 
         let config = Config {
             image: Some(params.image.to_string()),
@@ -215,6 +359,17 @@ impl DockerManager {
         Ok(())
     }
 
+    /// Freeze every process in the container (cgroup freezer); memory and sockets are kept
+    pub async fn pause_container(&self, id: &str) -> Result<()> {
+        self.client.pause_container(id).await?;
+        Ok(())
+    }
+
+    pub async fn unpause_container(&self, id: &str) -> Result<()> {
+        self.client.unpause_container(id).await?;
+        Ok(())
+    }
+
     pub async fn remove_container(&self, id: &str) -> Result<()> {
         self.client.remove_container(id, None).await?;
         Ok(())
@@ -228,6 +383,67 @@ impl DockerManager {
         Ok(running)
     }
 
+    /// When the container was last started; `None` if it isn't running
+    pub async fn container_started_at(
+        &self,
+        id: &str,
+    ) -> Result<Option<chrono::DateTime<chrono::Utc>>> {
+        let info = self.client.inspect_container(id, None).await?;
+        let Some(state) = info.state.filter(|s| s.running == Some(true)) else {
+            return Ok(None);
+        };
+        Ok(state
+            .started_at
+            .and_then(|t| chrono::DateTime::parse_from_rfc3339(&t).ok())
+            .map(|t| t.with_timezone(&chrono::Utc)))
+    }
+
+    /// CPU use (percent of one core, so 250% is two and a half cores busy) and
+    /// memory in use and allowed, in bytes
+    pub async fn container_stats(&self, id: &str) -> Result<ContainerStats> {
+        let options = StatsOptions {
+            stream: false,
+            one_shot: false,
+        };
+        let stats = self
+            .client
+            .stats(id, Some(options))
+            .next()
+            .await
+            .ok_or_else(|| anyhow::anyhow!("No stats for container {}", id))??;
+
+        let cpu = &stats.cpu_stats;
+        let precpu = &stats.precpu_stats;
+        let cpu_delta = cpu
+            .cpu_usage
+            .total_usage
+            .saturating_sub(precpu.cpu_usage.total_usage);
+        let system_delta = cpu
+            .system_cpu_usage
+            .unwrap_or(0)
+            .saturating_sub(precpu.system_cpu_usage.unwrap_or(0));
+        let cpus = cpu.online_cpus.unwrap_or(1).max(1);
+        let cpu_percent = if system_delta > 0 {
+            cpu_delta as f64 / system_delta as f64 * cpus as f64 * 100.0
+        } else {
+            0.0
+        };
+
+        // Same as `docker stats`: page cache the kernel can drop doesn't count
+        let memory = &stats.memory_stats;
+        let cache = match memory.stats {
+            Some(MemoryStatsStats::V1(v1)) => v1.total_inactive_file,
+            Some(MemoryStatsStats::V2(v2)) => v2.inactive_file,
+            None => 0,
+        };
+
+        Ok(ContainerStats {
+            cpu_percent,
+            memory_bytes: memory.usage.unwrap_or(0).saturating_sub(cache),
+            memory_limit_bytes: memory.limit.unwrap_or(0),
+        })
+    }
+
     pub async fn get_container_logs(&self, id: &str, tail_lines: usize) -> Result<String> {
         let options = LogsOptions::<String> {
             stdout: true,
@@ -242,7 +458,7 @@ impl DockerManager {
         while let Some(result) = stream.next().await {
             match result {
                 Ok(log) => {
-                    output.push_str(&log.to_string());
+                    output.push_str(&strip_ansi(&log.to_string()));
                 }
                 Err(e) => {
                     tracing::warn!("Error reading logs: {}", e);
@@ -254,10 +470,70 @@ impl DockerManager {
         Ok(output)
     }
 
-    /// Get combined logs from all DrakonixAnvil-managed containers
-    pub async fn get_all_managed_logs(&self, tail_lines_per_container: usize) -> Result<String> {
+    /// Log output of a container newer than `since`, the timestamp of the last line
+    /// already read (RFC 3339, as returned here). Without a cursor, the last
+    /// `tail_lines` lines are returned. Also returns the new cursor.
+    pub async fn get_container_logs_since(
+        &self,
+        id: &str,
+        since: Option<&str>,
+        tail_lines: usize,
+    ) -> Result<(String, Option<String>)> {
+        let cursor = since.and_then(|s| chrono::DateTime::parse_from_rfc3339(s).ok());
+        let options = LogsOptions::<String> {
+            stdout: true,
+            stderr: true,
+            timestamps: true,
+            // Docker only takes whole seconds; lines from that second are filtered below
+            since: cursor.map_or(0, |c| c.timestamp()),
+            tail: if cursor.is_some() {
+                "all".to_string()
+            } else {
+                tail_lines.to_string()
+            },
+            ..Default::default()
+        };
+
+        let mut stream = self.client.logs(id, Some(options));
+        let mut output = String::new();
+        let mut last = since.map(|s| s.to_string());
+
+        while let Some(result) = stream.next().await {
+            match result {
+                Ok(log) => {
+                    let line = strip_ansi(&log.to_string());
+                    let Some((timestamp, message)) = line.split_once(' ') else {
+                        continue;
+                    };
+                    let Ok(time) = chrono::DateTime::parse_from_rfc3339(timestamp) else {
+                        output.push_str(&line);
+                        continue;
+                    };
+                    if cursor.is_some_and(|c| time <= c) {
+                        continue;
+                    }
+                    output.push_str(message);
+                    last = Some(timestamp.to_string());
+                }
+                Err(e) => {
+                    tracing::warn!("Error reading logs: {}", e);
+                    break;
+                }
+            }
+        }
+
+        Ok((output, last))
+    }
+
+    /// New log output from every DrakonixAnvil-managed container. `cursors` maps
+    /// container IDs to the timestamp of the last line read from them.
+    pub async fn get_managed_logs_since(
+        &self,
+        cursors: &HashMap<String, String>,
+        tail_lines_per_container: usize,
+    ) -> Result<Vec<LogChunk>> {
         let containers = self.list_minecraft_containers().await?;
-        let mut combined_output = String::new();
+        let mut chunks = Vec::new();
 
         for container in containers {
             let container_id = match &container.id {
@@ -265,43 +541,35 @@ impl DockerManager {
                 None => continue,
             };
 
-            let container_name = container
+            let name = container
                 .names
                 .as_ref()
                 .and_then(|n| n.first())
                 .map(|s| s.trim_start_matches('/').to_string())
                 .unwrap_or_else(|| container_id[..12].to_string());
+            let state = container.state.as_deref().unwrap_or("unknown").to_string();
 
-            let state = container.state.as_deref().unwrap_or("unknown");
-
-            combined_output.push_str(&format!("═══ {} [{}] ═══\n", container_name, state));
-
-            match self
-                .get_container_logs(container_id, tail_lines_per_container)
+            let since = cursors.get(container_id).map(|s| s.as_str());
+            let (text, last_timestamp) = match self
+                .get_container_logs_since(container_id, since, tail_lines_per_container)
                 .await
             {
-                Ok(logs) => {
-                    if logs.is_empty() {
-                        combined_output.push_str("(no logs)\n");
-                    } else {
-                        combined_output.push_str(&logs);
-                        if !logs.ends_with('\n') {
-                            combined_output.push('\n');
-                        }
-                    }
-                }
-                Err(e) => {
-                    combined_output.push_str(&format!("(error fetching logs: {})\n", e));
-                }
-            }
-
-            combined_output.push('\n');
-        }
+                Ok(logs) => logs,
+                Err(e) => (
+                    format!("(error fetching logs: {})\n", e),
+                    since.map(|s| s.to_string()),
+                ),
+            };
 
-        if combined_output.is_empty() {
-            combined_output = "No DrakonixAnvil-managed containers found.".to_string();
+            chunks.push(LogChunk {
+                container_id: container_id.clone(),
+                name,
+                state,
+                text,
+                last_timestamp,
+            });
         }
 
-        Ok(combined_output)
+        Ok(chunks)
     }
 }
@@ -0,0 +1,208 @@
+//! File browser backend for a server's data folder: listings with sizes, and
+//! uploads, downloads, renames and deletes that can't reach outside the folder.
+
+use anyhow::{bail, Context, Result};
+use std::path::{Component, Path, PathBuf};
+use std::time::SystemTime;
+use walkdir::WalkDir;
+
+/// One row of a folder listing
+#[derive(Debug, Clone)]
+pub struct FileEntry {
+    pub name: String,
+    pub is_dir: bool,
+    /// Bytes; for folders, everything inside them
+    pub size: u64,
+    pub modified: Option<SystemTime>,
+}
+
+/// `root` joined with `rel`, refusing anything but plain folder names in `rel`.
+/// Symlinks along the way are refused too: the server can create them in its own
+/// folder, and following one would reach files anywhere on this machine.
+pub fn resolve(root: &Path, rel: &Path) -> Result<PathBuf> {
+    if rel.components().any(|c| !matches!(c, Component::Normal(_))) {
+        bail!("{} isn't inside the server folder", rel.display());
+    }
+    let mut path = root.to_path_buf();
+    for component in rel.components() {
+        path.push(component);
+        refuse_symlink(&path)?;
+    }
+    Ok(path)
+}
+
+/// Fail if `path` is a symlink; a path that doesn't exist yet is fine
+fn refuse_symlink(path: &Path) -> Result<()> {
+    if std::fs::symlink_metadata(path).is_ok_and(|m| m.file_type().is_symlink()) {
+        bail!(
+            "{} is a symlink, which the file manager won't follow",
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+/// A single file or folder name typed by the user, e.g. for a rename
+fn check_name(name: &str) -> Result<()> {
+    let name = name.trim();
+    if name.is_empty() || name == "." || name == ".." || name.contains(['/', '\\']) {
+        bail!("'{}' isn't a valid file name", name);
+    }
+    Ok(())
+}
+
+/// Folders first, then files, each alphabetically
+pub fn list(root: &Path, rel: &Path) -> Result<Vec<FileEntry>> {
+    let dir = resolve(root, rel)?;
+    let mut entries = Vec::new();
+    for entry in
+        std::fs::read_dir(&dir).with_context(|| format!("Failed to read {}", dir.display()))?
+    {
+        let entry = entry?;
+        let meta = entry.metadata()?;
+        let is_dir = meta.is_dir();
+        let size = if is_dir {
+            dir_size(&entry.path())
+        } else {
+            meta.len()
+        };
+        entries.push(FileEntry {
+            name: entry.file_name().to_string_lossy().into_owned(),
+            is_dir,
+            size,
+            modified: meta.modified().ok(),
+        });
+    }
+    entries.sort_by(|a, b| {
+        b.is_dir
+            .cmp(&a.is_dir)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+    Ok(entries)
+}
+
+fn dir_size(path: &Path) -> u64 {
+    WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter_map(|e| e.metadata().ok())
+        .filter(|m| m.is_file())
+        .map(|m| m.len())
+        .sum()
+}
+
+/// Copy files (and folders, recursively) from this machine into `rel`, replacing
+/// anything with the same name. Returns how many were copied.
+pub fn upload(root: &Path, rel: &Path, paths: &[PathBuf]) -> Result<usize> {
+    let dest_dir = resolve(root, rel)?;
+    for path in paths {
+        let Some(name) = path.file_name() else {
+            continue;
+        };
+        let dest = dest_dir.join(name);
+        if path.is_dir() {
+            for entry in WalkDir::new(path) {
+                let entry = entry?;
+                let target = dest.join(entry.path().strip_prefix(path)?);
+                // Parents were checked on the way down, so only the entry itself is left
+                refuse_symlink(&target)?;
+                if entry.file_type().is_dir() {
+                    std::fs::create_dir_all(&target)
+                        .with_context(|| format!("Failed to create {}", target.display()))?;
+                } else {
+                    std::fs::copy(entry.path(), &target)
+                        .with_context(|| format!("Failed to copy {}", entry.path().display()))?;
+                }
+            }
+        } else {
+            refuse_symlink(&dest)?;
+            std::fs::copy(path, &dest)
+                .with_context(|| format!("Failed to copy {}", path.display()))?;
+        }
+    }
+    Ok(paths.len())
+}
+
+/// Copy the file `name` in `rel` out to `dest` on this machine
+pub fn download(root: &Path, rel: &Path, name: &str, dest: &Path) -> Result<()> {
+    check_name(name)?;
+    let src = resolve(root, &rel.join(name))?;
+    std::fs::copy(&src, dest).with_context(|| format!("Failed to copy {}", src.display()))?;
+    Ok(())
+}
+
+pub fn rename(root: &Path, rel: &Path, from: &str, to: &str) -> Result<()> {
+    check_name(from)?;
+    check_name(to)?;
+    let dir = resolve(root, rel)?;
+    let dest = dir.join(to.trim());
+    if dest.exists() {
+        bail!("{} already exists", to.trim());
+    }
+    std::fs::rename(dir.join(from), &dest)
+        .with_context(|| format!("Failed to rename {} to {}", from, to.trim()))
+}
+
+/// Delete the file or folder `name` in `rel`
+pub fn delete(root: &Path, rel: &Path, name: &str) -> Result<()> {
+    check_name(name)?;
+    let path = resolve(root, rel)?.join(name);
+    let meta = std::fs::symlink_metadata(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    if meta.is_dir() {
+        std::fs::remove_dir_all(&path)
+    } else {
+        std::fs::remove_file(&path)
+    }
+    .with_context(|| format!("Failed to delete {}", name))
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    /// Fresh empty folder under the system temp dir
+    fn scratch_dir(label: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("drakonix-files-{}-{}", label, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn symlinks_out_of_the_root_are_refused() {
+        let base = scratch_dir("symlink");
+        let root = base.join("data");
+        let outside = base.join("outside");
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::create_dir_all(&outside).unwrap();
+        std::fs::write(outside.join("secret.txt"), b"host file").unwrap();
+        std::os::unix::fs::symlink(&outside, root.join("escape")).unwrap();
+        std::os::unix::fs::symlink(outside.join("secret.txt"), root.join("secret.txt")).unwrap();
+
+        assert!(list(&root, Path::new("escape")).is_err());
+
+        let copy = base.join("copy.txt");
+        assert!(download(&root, Path::new(""), "secret.txt", &copy).is_err());
+        assert!(download(&root, Path::new("escape"), "secret.txt", &copy).is_err());
+        assert!(!copy.exists());
+
+        let upload_src = base.join("upload");
+        std::fs::create_dir_all(&upload_src).unwrap();
+        std::fs::write(upload_src.join("secret.txt"), b"overwritten").unwrap();
+        let file = [upload_src.join("secret.txt")];
+        assert!(upload(&root, Path::new(""), &file).is_err());
+        assert!(upload(&root, Path::new("escape"), &file).is_err());
+        assert_eq!(
+            std::fs::read(outside.join("secret.txt")).unwrap(),
+            b"host file"
+        );
+
+        // Deleting the link removes only the link
+        delete(&root, Path::new(""), "escape").unwrap();
+        assert!(outside.join("secret.txt").exists());
+
+        std::fs::remove_dir_all(&base).unwrap();
+    }
+}
@@ -0,0 +1,189 @@
+//! FTB modpacks API client (the catalog behind the FTB App): pack search and
+//! pack details with their versions, for the FTB browse tab.
+
+use crate::server::ModLoader;
+use serde::Deserialize;
+
+const FTB_BASE: &str = "https://api.feed-the-beast.com/v1/modpacks/public/modpack";
+
+/// Packs shown per search
+const SEARCH_LIMIT: u32 = 20;
+
+// ── API types ─────────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct FtbSearchResponse {
+    #[serde(default)]
+    packs: Vec<u64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FtbPack {
+    pub id: u64,
+    pub name: String,
+    #[serde(default)]
+    pub synopsis: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub art: Vec<FtbArt>,
+    #[serde(default)]
+    pub versions: Vec<FtbVersion>,
+    #[serde(default)]
+    pub installs: u64,
+    #[serde(default)]
+    pub tags: Vec<FtbTag>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FtbArt {
+    pub url: String,
+    #[serde(rename = "type", default)]
+    pub kind: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FtbTag {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FtbVersion {
+    pub id: u64,
+    pub name: String,
+    /// `release`, `beta` or `alpha`
+    #[serde(rename = "type", default)]
+    pub kind: String,
+    /// Unix timestamp
+    #[serde(default)]
+    pub updated: i64,
+    #[serde(default)]
+    pub specs: Option<FtbSpecs>,
+    #[serde(default)]
+    pub targets: Vec<FtbTarget>,
+}
+
+/// Memory the pack asks for, in MB
+#[derive(Debug, Clone, Deserialize)]
+pub struct FtbSpecs {
+    #[serde(default)]
+    pub minimum: u64,
+    #[serde(default)]
+    pub recommended: u64,
+}
+
+/// Something a version runs on: `minecraft`, a mod loader, or `java`
+#[derive(Debug, Clone, Deserialize)]
+pub struct FtbTarget {
+    pub name: String,
+    pub version: String,
+}
+
+impl FtbPack {
+    /// Square pack art, else whatever art there is
+    pub fn icon_url(&self) -> Option<&str> {
+        self.art
+            .iter()
+            .find(|a| a.kind == "square")
+            .or_else(|| self.art.first())
+            .map(|a| a.url.as_str())
+    }
+}
+
+impl FtbVersion {
+    fn target(&self, name: &str) -> Option<&str> {
+        self.targets
+            .iter()
+            .find(|t| t.name.eq_ignore_ascii_case(name))
+            .map(|t| t.version.as_str())
+    }
+
+    pub fn minecraft_version(&self) -> &str {
+        self.target("minecraft").unwrap_or_default()
+    }
+
+    pub fn loader(&self) -> ModLoader {
+        if self.target("neoforge").is_some() {
+            ModLoader::NeoForge
+        } else if self.target("fabric").is_some() {
+            ModLoader::Fabric
+        } else if self.target("forge").is_some() {
+            ModLoader::Forge
+        } else {
+            ModLoader::Vanilla
+        }
+    }
+
+    /// Java major version the pack ships with (`17.0.8` -> 17, `1.8.0_312` -> 8)
+    pub fn java_version(&self) -> Option<u8> {
+        let version = self.target("java")?;
+        let mut parts = version.split(['.', '_']);
+        match parts.next()?.parse().ok()? {
+            1 => parts.next()?.parse().ok(),
+            major => Some(major),
+        }
+    }
+
+    /// Release date as `YYYY-MM-DD`
+    pub fn date(&self) -> String {
+        chrono::DateTime::from_timestamp(self.updated, 0)
+            .map(|d| d.format("%Y-%m-%d").to_string())
+            .unwrap_or_default()
+    }
+}
+
+// ── API functions ─────────────────────────────────────────────────────────
+
+/// GET and parse the JSON reply, reusing a recent response from the
+/// [`crate::api_cache`] when there is one
+async fn get_json<T: serde::de::DeserializeOwned>(url: String) -> anyhow::Result<T> {
+    let req = crate::http::client().get(url);
+    let key = crate::api_cache::key(&req);
+    if let Some(body) = key.as_deref().and_then(crate::api_cache::get) {
+        if let Ok(data) = serde_json::from_str(&body) {
+            return Ok(data);
+        }
+    }
+
+    let resp = req.send().await?;
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        anyhow::bail!("FTB API error {}: {}", status, body);
+    }
+
+    let body = resp.text().await?;
+    let data = serde_json::from_str(&body)?;
+    if let Some(key) = key {
+        crate::api_cache::put(&key, &body);
+    }
+    Ok(data)
+}
+
+/// Fetch one pack with all its versions
+pub async fn get_pack(pack_id: u64) -> anyhow::Result<FtbPack> {
+    get_json(format!("{}/{}", FTB_BASE, pack_id)).await
+}
+
+/// Search FTB packs by name; an empty query lists the most installed packs.
+/// Each hit is fetched in full, so results come with their versions.
+pub async fn search(query: &str) -> anyhow::Result<Vec<FtbPack>> {
+    let url = if query.trim().is_empty() {
+        format!("{}/popular/installs/{}", FTB_BASE, SEARCH_LIMIT)
+    } else {
+        let mut url = reqwest::Url::parse(&format!("{}/search/{}", FTB_BASE, SEARCH_LIMIT))?;
+        url.query_pairs_mut().append_pair("term", query.trim());
+        url.to_string()
+    };
+    let ids = get_json::<FtbSearchResponse>(url).await?.packs;
+
+    let fetched = futures_util::future::join_all(ids.into_iter().map(get_pack)).await;
+    let mut packs = Vec::new();
+    for result in fetched {
+        match result {
+            Ok(pack) => packs.push(pack),
+            Err(e) => tracing::warn!("Skipping FTB pack: {:#}", e),
+        }
+    }
+    Ok(packs)
+}
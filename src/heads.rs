@@ -0,0 +1,110 @@
+//! Player head avatars from mc-heads.net, cached on disk so lists of players
+//! don't re-download every head each session.
+
+use crate::config::data_root;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Heads are re-fetched after this long so skin changes show up eventually
+const MAX_AGE: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Pixel size requested from the head service
+const HEAD_SIZE: u32 = 64;
+
+pub struct PlayerHeads {
+    runtime: tokio::runtime::Handle,
+    /// URI chosen for each name this session, so the disk is checked once per name
+    resolved: Mutex<HashMap<String, String>>,
+    /// Names with a download in flight
+    pending: Mutex<HashSet<String>>,
+}
+
+impl PlayerHeads {
+    pub fn new(runtime: tokio::runtime::Handle) -> Self {
+        Self {
+            runtime,
+            resolved: Mutex::new(HashMap::new()),
+            pending: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Where head PNGs are kept; absolute so cached heads load as `file://` URIs.
+    /// Read each time so a moved data folder is picked up.
+    fn cache_dir() -> PathBuf {
+        std::env::current_dir()
+            .unwrap_or_default()
+            .join(data_root())
+            .join("cache")
+            .join("heads")
+    }
+
+    /// Image URI for a player's head: the cached PNG when there is one, otherwise
+    /// the remote image while the PNG is fetched into the cache in the background
+    pub fn uri(&self, name: &str) -> String {
+        if let Some(uri) = self.resolved.lock().ok().and_then(|r| r.get(name).cloned()) {
+            return uri;
+        }
+        let uri = self.resolve(name);
+        if let Ok(mut resolved) = self.resolved.lock() {
+            resolved.insert(name.to_string(), uri.clone());
+        }
+        uri
+    }
+
+    fn resolve(&self, name: &str) -> String {
+        let remote = format!("https://mc-heads.net/avatar/{}/{}", name, HEAD_SIZE);
+        // Names end up in a file path; anything that isn't a Minecraft name stays remote-only
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return remote;
+        }
+
+        let path = Self::cache_dir().join(format!("{}.png", name.to_lowercase()));
+        let age = std::fs::metadata(&path)
+            .and_then(|m| m.modified())
+            .ok()
+            .and_then(|t| t.elapsed().ok());
+        if age.is_none_or(|age| age > MAX_AGE) {
+            self.fetch(remote.clone(), path.clone());
+        }
+        match age {
+            Some(_) => format!("file://{}", path.display()),
+            None => remote,
+        }
+    }
+
+    fn fetch(&self, url: String, path: PathBuf) {
+        let key = path.to_string_lossy().to_string();
+        let Ok(mut pending) = self.pending.lock() else {
+            return;
+        };
+        if !pending.insert(key) {
+            return;
+        }
+        drop(pending);
+
+        self.runtime.spawn(async move {
+            if let Err(e) = download(&url, &path).await {
+                tracing::debug!("Fetching player head {} failed: {}", url, e);
+            }
+        });
+    }
+}
+
+async fn download(url: &str, path: &std::path::Path) -> anyhow::Result<()> {
+    let response = crate::http::client()
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?;
+    let bytes = response.bytes().await?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    // Write then rename so a half-written PNG is never picked up
+    let tmp = path.with_extension("png.part");
+    std::fs::write(&tmp, &bytes)?;
+    std::fs::rename(&tmp, path)?;
+    Ok(())
+}
@@ -0,0 +1,122 @@
+//! Shared HTTP client construction.
+//!
+//! Every outbound reqwest client (CurseForge, Modrinth, pack downloads) is built here so the
+//! proxy configured in Settings applies consistently.
+
+use crate::config::{ProxyMode, ProxySettings};
+use std::sync::RwLock;
+
+/// Proxy settings currently in effect. `None` until settings are loaded at startup.
+static PROXY: RwLock<Option<ProxySettings>> = RwLock::new(None);
+
+/// Fetched by [`test_connection`]; small and needs no key
+const TEST_URL: &str = "https://api.modrinth.com/";
+
+/// Environment variables reqwest reads when the proxy mode is `System`
+const PROXY_ENV_VARS: &[&str] = &[
+    "HTTPS_PROXY",
+    "https_proxy",
+    "HTTP_PROXY",
+    "http_proxy",
+    "ALL_PROXY",
+    "all_proxy",
+];
+
+/// Apply new proxy settings to all clients built from now on
+pub fn set_proxy_settings(settings: &ProxySettings) {
+    if let Ok(mut guard) = PROXY.write() {
+        *guard = Some(settings.clone());
+    }
+}
+
+/// A client builder with the configured proxy applied
+pub fn client_builder() -> reqwest::ClientBuilder {
+    let settings = PROXY
+        .read()
+        .ok()
+        .and_then(|g| g.clone())
+        .unwrap_or_default();
+    builder_for(&settings)
+}
+
+fn builder_for(settings: &ProxySettings) -> reqwest::ClientBuilder {
+    let builder = reqwest::Client::builder();
+
+    match settings.mode {
+        // reqwest picks up HTTP(S)_PROXY / ALL_PROXY / NO_PROXY from the environment by default
+        ProxyMode::System => builder,
+        ProxyMode::Disabled => builder.no_proxy(),
+        ProxyMode::Manual => match reqwest::Proxy::all(settings.url.trim()) {
+            Ok(proxy) => {
+                let proxy = proxy.no_proxy(reqwest::NoProxy::from_string(&settings.no_proxy));
+                builder.proxy(proxy)
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "Invalid proxy URL {:?}, falling back to system proxy: {}",
+                    settings.url,
+                    e
+                );
+                builder
+            }
+        },
+    }
+}
+
+/// Reach the Modrinth API through `settings` (not necessarily saved yet), so a
+/// proxy can be checked from Settings. Returns the round trip in milliseconds.
+pub async fn test_connection(settings: &ProxySettings) -> Result<u128, String> {
+    if settings.mode == ProxyMode::Manual {
+        validate_proxy_url(&settings.url)?;
+    }
+    let client = builder_for(settings)
+        .timeout(std::time::Duration::from_secs(10))
+        .build()
+        .map_err(|e| e.to_string())?;
+    let started = std::time::Instant::now();
+    let response = client
+        .get(TEST_URL)
+        .send()
+        .await
+        .map_err(|e| format!("{:#}", anyhow::Error::from(e)))?;
+    if !response.status().is_success() {
+        return Err(format!("{} answered {}", TEST_URL, response.status()));
+    }
+    Ok(started.elapsed().as_millis())
+}
+
+/// A default client with the configured proxy applied
+pub fn client() -> reqwest::Client {
+    client_builder()
+        .build()
+        .expect("Failed to build HTTP client")
+}
+
+/// Check a manual proxy URL. Supports `http://`, `https://`, `socks5://` and `socks5h://`.
+pub fn validate_proxy_url(url: &str) -> Result<(), String> {
+    let url = url.trim();
+    if url.is_empty() {
+        return Err("Proxy URL is empty".to_string());
+    }
+    let scheme_ok = ["http://", "https://", "socks5://", "socks5h://"]
+        .iter()
+        .any(|s| url.starts_with(s));
+    if !scheme_ok {
+        return Err(
+            "Proxy URL must start with http://, https://, socks5:// or socks5h://".to_string(),
+        );
+    }
+    reqwest::Proxy::all(url)
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+/// The proxy picked up from the environment, if any (for display in Settings)
+pub fn env_proxy() -> Option<(&'static str, String)> {
+    PROXY_ENV_VARS.iter().find_map(|name| {
+        std::env::var(name)
+            .ok()
+            .filter(|v| !v.is_empty())
+            .map(|v| (*name, v))
+    })
+}
@@ -0,0 +1,111 @@
+//! UI translations. Strings live in Fluent files under `locales/`, compiled into
+//! the binary; a message the chosen language doesn't have yet falls back to English.
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use serde::{Deserialize, Serialize};
+use std::sync::{OnceLock, RwLock};
+
+/// Language of the UI, picked in Settings
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub enum Language {
+    #[default]
+    English,
+    German,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::German];
+
+    /// Name of the language in itself, for the selector
+    pub fn label(self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::German => "Deutsch",
+        }
+    }
+
+    fn tag(self) -> &'static str {
+        match self {
+            Language::English => "en-US",
+            Language::German => "de",
+        }
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Language::English => include_str!("../locales/en-US.ftl"),
+            Language::German => include_str!("../locales/de.ftl"),
+        }
+    }
+
+    fn bundle(self) -> FluentBundle<FluentResource> {
+        let tag = self.tag().parse().expect("language tags are valid");
+        let mut bundle = FluentBundle::new_concurrent(vec![tag]);
+        // egui would draw the Unicode isolation marks around arguments as boxes
+        bundle.set_use_isolating(false);
+        let resource = match FluentResource::try_new(self.source().to_string()) {
+            Ok(resource) => resource,
+            Err((resource, errors)) => {
+                tracing::warn!("{} translation has syntax errors: {:?}", self.tag(), errors);
+                resource
+            }
+        };
+        if let Err(errors) = bundle.add_resource(resource) {
+            tracing::warn!(
+                "{} translation has duplicate messages: {:?}",
+                self.tag(),
+                errors
+            );
+        }
+        bundle
+    }
+}
+
+/// Loaded bundles, in the order of [`Language::ALL`]
+fn bundles() -> &'static [FluentBundle<FluentResource>] {
+    static BUNDLES: OnceLock<Vec<FluentBundle<FluentResource>>> = OnceLock::new();
+    BUNDLES.get_or_init(|| Language::ALL.iter().map(|l| l.bundle()).collect())
+}
+
+static CURRENT: RwLock<Language> = RwLock::new(Language::English);
+
+/// Switch the UI language; takes effect from the next frame
+pub fn set_language(language: Language) {
+    if let Ok(mut current) = CURRENT.write() {
+        *current = language;
+    }
+}
+
+fn format(language: Language, id: &str, args: Option<&FluentArgs>) -> Option<String> {
+    let index = Language::ALL.iter().position(|l| *l == language)?;
+    let bundle = &bundles()[index];
+    let pattern = bundle.get_message(id)?.value()?;
+    let mut errors = Vec::new();
+    let text = bundle.format_pattern(pattern, args, &mut errors);
+    if !errors.is_empty() {
+        tracing::warn!("Formatting '{}' ({}): {:?}", id, language.tag(), errors);
+    }
+    Some(text.into_owned())
+}
+
+/// Translated text for message `id`, with `{ $name }` placeables filled from `args`.
+/// Falls back to English, then to the ID itself so a missing string is obvious.
+pub fn tr_args(id: &str, args: &[(&str, FluentValue<'_>)]) -> String {
+    let args = (!args.is_empty()).then(|| {
+        let mut fluent_args = FluentArgs::new();
+        for (name, value) in args {
+            fluent_args.set(*name, value.clone());
+        }
+        fluent_args
+    });
+    let language = CURRENT.read().map(|l| *l).unwrap_or_default();
+    format(language, id, args.as_ref())
+        .or_else(|| format(Language::English, id, args.as_ref()))
+        .unwrap_or_else(|| id.to_string())
+}
+
+/// Translated text for message `id`
+pub fn tr(id: &str) -> String {
+    tr_args(id, &[])
+}
@@ -0,0 +1,306 @@
+//! JVM flag presets and garbage collector guardrails. Templates and the create/edit views
+//! generate `java_args` from these instead of carrying their own copies of the G1 flags.
+
+use crate::i18n::{tr, tr_args};
+
+/// Heaps above this get Aikar's large-heap G1 tuning
+const AIKAR_LARGE_HEAP_MB: u64 = 12 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JvmPreset {
+    /// Aikar's tuned G1 flags (https://docs.papermc.io/paper/aikars-flags)
+    Aikar,
+    /// Plain G1 with a pause target, nothing experimental
+    G1Conservative,
+    /// Low-latency ZGC (Java 17+, generational on Java 21)
+    Zgc,
+    /// Low-pause Shenandoah (Java 17+)
+    Shenandoah,
+    /// No extra flags; let the JVM pick
+    None,
+}
+
+impl JvmPreset {
+    pub const ALL: [JvmPreset; 5] = [
+        JvmPreset::Aikar,
+        JvmPreset::G1Conservative,
+        JvmPreset::Zgc,
+        JvmPreset::Shenandoah,
+        JvmPreset::None,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            JvmPreset::Aikar => "Aikar's flags (G1)",
+            JvmPreset::G1Conservative => "G1 conservative",
+            JvmPreset::Zgc => "ZGC (Java 17+)",
+            JvmPreset::Shenandoah => "Shenandoah (Java 17+)",
+            JvmPreset::None => "None",
+        }
+    }
+
+    /// Whether the preset's collector exists in the given Java version's JVM
+    pub fn supports_java(self, java_version: u8) -> bool {
+        match self {
+            JvmPreset::Zgc | JvmPreset::Shenandoah => java_version >= 17,
+            _ => true,
+        }
+    }
+
+    /// Flags for this preset, tuned for the Java version and heap size
+    pub fn args(self, java_version: u8, memory_mb: u64) -> Vec<String> {
+        let flags: Vec<&str> = match self {
+            JvmPreset::Aikar => {
+                let mut flags = vec![
+                    "-XX:+UseG1GC",
+                    "-XX:+ParallelRefProcEnabled",
+                    "-XX:MaxGCPauseMillis=200",
+                    "-XX:+UnlockExperimentalVMOptions",
+                    "-XX:+DisableExplicitGC",
+                    "-XX:+AlwaysPreTouch",
+                ];
+                if memory_mb > AIKAR_LARGE_HEAP_MB {
+                    flags.extend([
+                        "-XX:G1NewSizePercent=40",
+                        "-XX:G1MaxNewSizePercent=50",
+                        "-XX:G1HeapRegionSize=16M",
+                        "-XX:G1ReservePercent=15",
+                        "-XX:InitiatingHeapOccupancyPercent=20",
+                    ]);
+                } else {
+                    flags.extend([
+                        "-XX:G1NewSizePercent=30",
+                        "-XX:G1MaxNewSizePercent=40",
+                        "-XX:G1HeapRegionSize=8M",
+                        "-XX:G1ReservePercent=20",
+                        "-XX:InitiatingHeapOccupancyPercent=15",
+                    ]);
+                }
+                flags.extend([
+                    "-XX:G1HeapWastePercent=5",
+                    "-XX:G1MixedGCCountTarget=4",
+                    "-XX:G1MixedGCLiveThresholdPercent=90",
+                    "-XX:G1RSetUpdatingPauseTimePercent=5",
+                    "-XX:SurvivorRatio=32",
+                    "-XX:+PerfDisableSharedMem",
+                    "-XX:MaxTenuringThreshold=1",
+                ]);
+                flags
+            }
+            JvmPreset::G1Conservative => vec![
+                "-XX:+UseG1GC",
+                "-XX:+ParallelRefProcEnabled",
+                "-XX:MaxGCPauseMillis=200",
+            ],
+            JvmPreset::Zgc => {
+                let mut flags = vec!["-XX:+UseZGC"];
+                // Generational ZGC is opt-in on 21 and the only mode from 23 on
+                if java_version == 21 {
+                    flags.push("-XX:+ZGenerational");
+                }
+                flags.extend(["-XX:+AlwaysPreTouch", "-XX:+DisableExplicitGC"]);
+                flags
+            }
+            JvmPreset::Shenandoah => vec![
+                "-XX:+UseShenandoahGC",
+                "-XX:+ParallelRefProcEnabled",
+                "-XX:+AlwaysPreTouch",
+                "-XX:+DisableExplicitGC",
+            ],
+            JvmPreset::None => vec![],
+        };
+        flags.into_iter().map(String::from).collect()
+    }
+
+    /// Which preset produced `args` for this Java version and heap, if any (None = hand-edited)
+    pub fn detect(args: &[String], java_version: u8, memory_mb: u64) -> Option<JvmPreset> {
+        Self::ALL
+            .into_iter()
+            .find(|p| p.args(java_version, memory_mb) == args)
+    }
+}
+
+/// Garbage collectors selectable through `-XX:+Use...GC`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GarbageCollector {
+    G1,
+    Zgc,
+    Shenandoah,
+    Parallel,
+    Serial,
+}
+
+impl GarbageCollector {
+    const FLAGS: [(&'static str, GarbageCollector); 5] = [
+        ("-XX:+UseG1GC", GarbageCollector::G1),
+        ("-XX:+UseZGC", GarbageCollector::Zgc),
+        ("-XX:+UseShenandoahGC", GarbageCollector::Shenandoah),
+        ("-XX:+UseParallelGC", GarbageCollector::Parallel),
+        ("-XX:+UseSerialGC", GarbageCollector::Serial),
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            GarbageCollector::G1 => "G1",
+            GarbageCollector::Zgc => "ZGC",
+            GarbageCollector::Shenandoah => "Shenandoah",
+            GarbageCollector::Parallel => "Parallel",
+            GarbageCollector::Serial => "Serial",
+        }
+    }
+
+    /// Oldest Java version the images ship with this collector usable in production
+    pub fn min_java(self) -> u8 {
+        match self {
+            GarbageCollector::Zgc | GarbageCollector::Shenandoah => 17,
+            _ => 8,
+        }
+    }
+
+    /// Collectors explicitly enabled in `args`
+    pub fn selected(args: &[String]) -> Vec<GarbageCollector> {
+        Self::FLAGS
+            .into_iter()
+            .filter(|(flag, _)| args.iter().any(|a| a == flag))
+            .map(|(_, gc)| gc)
+            .collect()
+    }
+
+    /// The collector the JVM will actually run with these args
+    pub fn effective(args: &[String], java_version: u8) -> GarbageCollector {
+        match Self::selected(args).first() {
+            Some(gc) => *gc,
+            // G1 became the default in Java 9
+            None if java_version <= 8 => GarbageCollector::Parallel,
+            None => GarbageCollector::G1,
+        }
+    }
+}
+
+/// Problems that would stop the JVM from starting (or silently ignore the chosen GC).
+/// Empty means the flags are fine for this Java version.
+pub fn validate_args(args: &[String], java_version: u8) -> Vec<String> {
+    let mut problems = Vec::new();
+    let selected = GarbageCollector::selected(args);
+
+    if selected.len() > 1 {
+        let names: Vec<&str> = selected.iter().map(|gc| gc.label()).collect();
+        problems.push(tr_args(
+            "jvm-several-gcs",
+            &[("collectors", names.join(", ").into())],
+        ));
+    }
+    let java = java_version.to_string();
+    for gc in &selected {
+        if java_version < gc.min_java() {
+            problems.push(tr_args(
+                "jvm-gc-needs-java",
+                &[
+                    ("gc", gc.label().into()),
+                    ("needed", gc.min_java().to_string().into()),
+                    ("java", java.as_str().into()),
+                ],
+            ));
+        }
+    }
+    if args.iter().any(|a| a == "-XX:+ZGenerational") {
+        if !selected.contains(&GarbageCollector::Zgc) {
+            problems.push(tr("jvm-generational-without-zgc"));
+        } else if java_version < 21 {
+            problems.push(tr_args(
+                "jvm-generational-needs-java",
+                &[("java", java.as_str().into())],
+            ));
+        }
+    }
+    if java_version <= 8 && args.iter().any(|a| a.starts_with("-Xlog:")) {
+        problems.push(tr("jvm-xlog-needs-java"));
+    }
+    problems
+}
+
+// ── GC pause benchmarking ──────────────────────────────────────────────────
+
+/// GC log location inside the data dir (the container sees it under /data)
+pub const GC_LOG_FILE: &str = "logs/gc.log";
+
+/// Unified-logging flags that record every pause to `GC_LOG_FILE` (Java 9+)
+pub fn gc_log_args() -> String {
+    format!(
+        "-Xlog:gc,gc+phases:file=/data/{}:uptime:filecount=3,filesize=10M",
+        GC_LOG_FILE
+    )
+}
+
+/// Pause statistics from one server run, kept per server to compare collectors
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct GcBenchmark {
+    pub collector: String,
+    pub java_version: u8,
+    pub memory_mb: u64,
+    /// When the run ended (RFC 3339)
+    pub recorded_at: String,
+    pub uptime_secs: u64,
+    pub pauses: u64,
+    pub avg_pause_ms: f64,
+    pub p99_pause_ms: f64,
+    pub max_pause_ms: f64,
+}
+
+/// Parse a unified GC log and summarize the stop-the-world pauses in it.
+/// Lines look like `[12.345s][info][gc] GC(7) Pause Young (Normal) ... 3.456ms`.
+pub fn parse_gc_log(text: &str) -> Option<(u64, Vec<f64>)> {
+    let mut pauses = Vec::new();
+    let mut uptime = 0.0_f64;
+    for line in text.lines() {
+        if let Some(secs) = line
+            .strip_prefix('[')
+            .and_then(|l| l.split_once("s]"))
+            .and_then(|(t, _)| t.parse::<f64>().ok())
+        {
+            uptime = uptime.max(secs);
+        }
+        if !line.contains("Pause") {
+            continue;
+        }
+        let Some(ms) = line
+            .trim_end()
+            .strip_suffix("ms")
+            .and_then(|l| l.rsplit(' ').next())
+            .and_then(|v| v.parse::<f64>().ok())
+        else {
+            continue;
+        };
+        pauses.push(ms);
+    }
+    if pauses.is_empty() {
+        None
+    } else {
+        Some((uptime as u64, pauses))
+    }
+}
+
+impl GcBenchmark {
+    pub fn from_pauses(
+        collector: GarbageCollector,
+        java_version: u8,
+        memory_mb: u64,
+        uptime_secs: u64,
+        mut pauses: Vec<f64>,
+    ) -> Self {
+        pauses.sort_by(|a, b| a.total_cmp(b));
+        let count = pauses.len();
+        let p99_idx = ((count as f64 * 0.99).ceil() as usize).clamp(1, count) - 1;
+        GcBenchmark {
+            collector: collector.label().to_string(),
+            java_version,
+            memory_mb,
+            recorded_at: chrono::Local::now().to_rfc3339(),
+            uptime_secs,
+            pauses: count as u64,
+            avg_pause_ms: pauses.iter().sum::<f64>() / count as f64,
+            p99_pause_ms: pauses[p99_idx],
+            max_pause_ms: pauses[count - 1],
+        }
+    }
+}
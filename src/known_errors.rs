@@ -0,0 +1,88 @@
+//! Recognizes common server failure signatures in container logs and turns them
+//! into a plain-language diagnosis with a suggested fix.
+
+use crate::i18n::{tr, tr_args};
+
+/// One recognized problem
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnosis {
+    pub title: String,
+    pub explanation: String,
+    pub fix: String,
+}
+
+/// The diagnosis under `known-<id>-title`, `-explanation` and `-fix`
+fn diagnosis(id: &str) -> Diagnosis {
+    Diagnosis {
+        title: tr(&format!("known-{}-title", id)),
+        explanation: tr(&format!("known-{}-explanation", id)),
+        fix: tr(&format!("known-{}-fix", id)),
+    }
+}
+
+/// Java release that introduced a class file version, e.g. 61 -> 17
+fn java_for_class_version(class_version: u32) -> Option<u32> {
+    // Class file 52 is Java 8, and each release since has added one
+    (class_version >= 52).then(|| class_version - 44)
+}
+
+/// Java version named in `... compiled by a more recent version of the Java Runtime
+/// (class file version 65.0) ...`
+fn required_java(logs: &str) -> Option<u32> {
+    let (_, rest) = logs.split_once("class file version ")?;
+    let version: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+    java_for_class_version(version.parse().ok()?)
+}
+
+/// Every known problem found in the log, most specific first
+pub fn analyze(logs: &str) -> Vec<Diagnosis> {
+    let mut found = Vec::new();
+    let lower = logs.to_lowercase();
+
+    if lower.contains("cf_api_key")
+        && (lower.contains("not set") || lower.contains("required") || lower.contains("missing"))
+    {
+        found.push(diagnosis("cf-key"));
+    }
+
+    if logs.contains("Failed to auto-install CurseForge modpack") {
+        found.push(diagnosis("cf-install"));
+    }
+
+    if logs.contains("You need to agree to the EULA") || lower.contains("eula=true") {
+        found.push(diagnosis("eula"));
+    }
+
+    if logs.contains("UnsupportedClassVersionError")
+        || logs.contains("compiled by a more recent version of the Java Runtime")
+    {
+        let fix = match required_java(logs) {
+            Some(java) => tr_args("known-java-fix-version", &[("java", java.into())]),
+            None => tr("known-java-fix"),
+        };
+        found.push(Diagnosis {
+            fix,
+            ..diagnosis("java")
+        });
+    }
+
+    if logs.contains("DuplicateModsFoundException")
+        || lower.contains("found duplicate mods")
+        || lower.contains("duplicate mods found")
+    {
+        found.push(diagnosis("duplicate-mods"));
+    }
+
+    if logs.contains("net/minecraft/client")
+        || logs.contains("for invalid dist DEDICATED_SERVER")
+        || (logs.contains("Attempted to load class") && logs.contains("invalid dist"))
+    {
+        found.push(diagnosis("client-mod"));
+    }
+
+    if logs.contains("java.lang.OutOfMemoryError") {
+        found.push(diagnosis("out-of-memory"));
+    }
+
+    found
+}
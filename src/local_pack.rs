@@ -0,0 +1,377 @@
+//! Modpacks on disk (server pack zips, CurseForge client zips, `.mrpack` files and
+//! plain folders): what's in them, and which loader and Minecraft version they're for.
+
+use crate::backup::BackupProgress;
+use crate::server::ModLoader;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::Sender;
+
+/// Entries of a local pack (zip or folder), relative to its single wrapping folder if any
+pub struct PackListing {
+    pub names: Vec<String>,
+    pub cf_manifest: Option<String>,
+    pub mr_index: Option<String>,
+}
+
+impl PackListing {
+    /// A jar at the top of the pack whose name starts with `prefix`
+    pub fn top_level_jar(&self, prefix: &str) -> bool {
+        self.names
+            .iter()
+            .any(|n| !n.contains('/') && n.starts_with(prefix) && n.ends_with(".jar"))
+    }
+}
+
+/// List a pack zip or folder and read its manifest or index if it has one.
+/// `label` names the pack in error messages.
+pub fn list_pack(full: &Path, label: &str) -> Result<PackListing, String> {
+    let mut names = Vec::new();
+    let cf_manifest;
+    let mr_index;
+
+    if full.is_dir() {
+        for entry in walkdir::WalkDir::new(full)
+            .min_depth(1)
+            // Reaches library version folders (`libraries/net/fabricmc/intermediary/1.20.1/`),
+            // also below a wrapping folder
+            .max_depth(6)
+            .into_iter()
+            .filter_map(|e| e.ok())
+        {
+            let Ok(rel) = entry.path().strip_prefix(full) else {
+                continue;
+            };
+            let mut name = rel.to_string_lossy().replace('\\', "/");
+            if entry.file_type().is_dir() {
+                name.push('/');
+            }
+            names.push(name);
+        }
+        let root = single_root(&names);
+        let read =
+            |file: &str| std::fs::read_to_string(full.join(format!("{}{}", root, file))).ok();
+        cf_manifest = read("manifest.json");
+        mr_index = read("modrinth.index.json");
+    } else if label.to_lowercase().ends_with(".zip") {
+        let file =
+            std::fs::File::open(full).map_err(|e| format!("Can't open '{}': {}", label, e))?;
+        let mut archive = zip::ZipArchive::new(file)
+            .map_err(|e| format!("'{}' is not a valid zip: {}", label, e))?;
+        names = archive.file_names().map(String::from).collect();
+        let root = single_root(&names);
+        let mut read = |file: &str| {
+            let mut text = String::new();
+            let mut entry = archive.by_name(&format!("{}{}", root, file)).ok()?;
+            std::io::Read::read_to_string(&mut entry, &mut text).ok()?;
+            Some(text)
+        };
+        cf_manifest = read("manifest.json");
+        mr_index = read("modrinth.index.json");
+    } else {
+        return Err(format!(
+            "'{}' should be a .zip server pack or a folder",
+            label
+        ));
+    }
+
+    let root = single_root(&names);
+    let names = names
+        .iter()
+        .filter_map(|n| n.strip_prefix(&root))
+        .filter(|n| !n.is_empty())
+        .map(String::from)
+        .collect();
+
+    Ok(PackListing {
+        names,
+        cf_manifest,
+        mr_index,
+    })
+}
+
+/// Folder wrapping every entry, e.g. "MyPack-Server/" (many server packs have one), or ""
+pub fn single_root(names: &[String]) -> String {
+    let root = match names.first().and_then(|n| n.split_once('/')) {
+        Some((root, _)) => format!("{}/", root),
+        None => return String::new(),
+    };
+    // A pack of only mods/ isn't wrapped, it just has nothing else
+    let pack_folder = matches!(root.as_str(), "mods/" | "config/" | "libraries/");
+    if !pack_folder && names.iter().all(|n| n.starts_with(&root)) {
+        root
+    } else {
+        String::new()
+    }
+}
+
+/// Loader from the pack's installed libraries or launcher jars, falling back to
+/// what its manifest or index names
+pub fn detect_loader(listing: &PackListing) -> Option<ModLoader> {
+    let names = &listing.names;
+    if names
+        .iter()
+        .any(|n| n.starts_with("libraries/net/neoforged/"))
+    {
+        Some(ModLoader::NeoForge)
+    } else if names
+        .iter()
+        .any(|n| n.starts_with("libraries/net/minecraftforge/"))
+        || listing.top_level_jar("forge-")
+    {
+        Some(ModLoader::Forge)
+    } else if names.iter().any(|n| n.starts_with(".fabric/"))
+        || listing.top_level_jar("fabric-server")
+    {
+        Some(ModLoader::Fabric)
+    } else {
+        manifest_loader(listing)
+    }
+}
+
+/// Loader named by a CurseForge manifest.json or Modrinth modrinth.index.json
+fn manifest_loader(listing: &PackListing) -> Option<ModLoader> {
+    if let Some(text) = &listing.cf_manifest {
+        let manifest: serde_json::Value = serde_json::from_str(text).ok()?;
+        let id = manifest["minecraft"]["modLoaders"][0]["id"].as_str()?;
+        return if id.starts_with("neoforge") {
+            Some(ModLoader::NeoForge)
+        } else if id.starts_with("forge") {
+            Some(ModLoader::Forge)
+        } else if id.starts_with("fabric") {
+            Some(ModLoader::Fabric)
+        } else {
+            None
+        };
+    }
+
+    let index: serde_json::Value = serde_json::from_str(listing.mr_index.as_deref()?).ok()?;
+    let deps = index["dependencies"].as_object()?;
+    if deps.contains_key("neoforge") {
+        Some(ModLoader::NeoForge)
+    } else if deps.contains_key("forge") {
+        Some(ModLoader::Forge)
+    } else if deps.contains_key("fabric-loader") {
+        Some(ModLoader::Fabric)
+    } else {
+        None
+    }
+}
+
+/// Minecraft version from the manifest or index, or from the version folders of
+/// the installed loader's libraries
+pub fn detect_minecraft_version(listing: &PackListing) -> Option<String> {
+    let from_json = |text: &Option<String>, pointer: &str| {
+        let value: serde_json::Value = serde_json::from_str(text.as_deref()?).ok()?;
+        value.pointer(pointer)?.as_str().map(String::from)
+    };
+    if let Some(version) = from_json(&listing.cf_manifest, "/minecraft/version")
+        .or_else(|| from_json(&listing.mr_index, "/dependencies/minecraft"))
+    {
+        return Some(version);
+    }
+
+    let library_version = |prefix: &str| library_version(listing, prefix);
+    if let Some(version) = library_version("libraries/net/minecraft/server/")
+        .or_else(|| library_version("libraries/net/fabricmc/intermediary/"))
+    {
+        return Some(version);
+    }
+    if let Some(forge) = library_version("libraries/net/minecraftforge/forge/") {
+        return forge.split_once('-').map(|(mc, _)| mc.to_string());
+    }
+    if let Some((mc, _)) = forge_jar_versions(listing) {
+        return Some(mc);
+    }
+    // NeoForge 21.1.x is for Minecraft 1.21.1, 20.4.x for 1.20.4
+    if let Some(neoforge) = library_version("libraries/net/neoforged/neoforge/") {
+        let mut parts = neoforge.split('.');
+        let major = parts.next()?;
+        return Some(match parts.next()? {
+            "0" => format!("1.{}", major),
+            minor => format!("1.{}.{}", major, minor),
+        });
+    }
+    None
+}
+
+/// Version of the pack's installed loader, e.g. `47.2.0` for Forge, as the
+/// container's `FORGE_VERSION` and friends expect it
+pub fn detect_loader_version(listing: &PackListing, loader: &ModLoader) -> Option<String> {
+    match loader {
+        ModLoader::Forge => library_version(listing, "libraries/net/minecraftforge/forge/")
+            .and_then(|v| v.split_once('-').map(|(_, forge)| forge.to_string()))
+            .or_else(|| forge_jar_versions(listing).map(|(_, forge)| forge)),
+        ModLoader::NeoForge => library_version(listing, "libraries/net/neoforged/neoforge/"),
+        ModLoader::Fabric => library_version(listing, "libraries/net/fabricmc/fabric-loader/"),
+        ModLoader::Vanilla => None,
+    }
+}
+
+/// Folder name right below a library's path, e.g. `1.20.1-47.2.0` below
+/// `libraries/net/minecraftforge/forge/`
+fn library_version(listing: &PackListing, prefix: &str) -> Option<String> {
+    listing.names.iter().find_map(|n| {
+        let version = n.strip_prefix(prefix)?.split('/').next()?;
+        (!version.is_empty()).then(|| version.to_string())
+    })
+}
+
+/// Minecraft and Forge versions from a top-level Forge jar such as
+/// `forge-1.20.1-47.2.0-installer.jar` or `forge-1.12.2-14.23.5.2860.jar`
+fn forge_jar_versions(listing: &PackListing) -> Option<(String, String)> {
+    listing.names.iter().find_map(|n| {
+        if n.contains('/') {
+            return None;
+        }
+        let mut parts = n.strip_prefix("forge-")?.strip_suffix(".jar")?.split('-');
+        let mc = parts.next()?;
+        let forge = parts.next()?;
+        let numeric = |v: &str| v.starts_with(|c: char| c.is_ascii_digit());
+        (numeric(mc) && numeric(forge)).then(|| (mc.to_string(), forge.to_string()))
+    })
+}
+
+/// What kind of pack a local file or folder is
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LocalPackKind {
+    /// Zip of a ready-to-run server (mods/, config/, maybe the loader)
+    ServerPack,
+    /// Folder laid out like a server
+    ServerFolder,
+    /// CurseForge client zip: a manifest.json listing mods, plus overrides
+    CurseForgeClient,
+    Mrpack,
+}
+
+impl LocalPackKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            LocalPackKind::ServerPack => "Server pack zip",
+            LocalPackKind::ServerFolder => "Server folder",
+            LocalPackKind::CurseForgeClient => "CurseForge client pack",
+            LocalPackKind::Mrpack => "Modrinth .mrpack",
+        }
+    }
+}
+
+/// What could be read from a local pack before importing it
+#[derive(Debug, Clone)]
+pub struct LocalPackInfo {
+    pub kind: LocalPackKind,
+    pub name: String,
+    pub version: String,
+    pub minecraft_version: Option<String>,
+    pub loader: Option<ModLoader>,
+}
+
+/// Work out what a local file or folder holds and which loader and Minecraft
+/// version it's for
+pub fn inspect(path: &Path) -> Result<LocalPackInfo, String> {
+    let label = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string());
+    let stem = path
+        .file_stem()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| label.clone());
+
+    if label.to_lowercase().ends_with(".mrpack") {
+        let info = crate::pack_installer::read_mrpack_info(path).map_err(|e| format!("{:#}", e))?;
+        return Ok(LocalPackInfo {
+            kind: LocalPackKind::Mrpack,
+            name: info.name,
+            version: info.version,
+            minecraft_version: Some(info.loader.minecraft_version),
+            loader: Some(info.loader.loader),
+        });
+    }
+
+    let listing = list_pack(path, &label)?;
+    let kind = if path.is_dir() {
+        LocalPackKind::ServerFolder
+    } else if is_cf_client(&listing) {
+        LocalPackKind::CurseForgeClient
+    } else {
+        LocalPackKind::ServerPack
+    };
+    let manifest: Option<serde_json::Value> = listing
+        .cf_manifest
+        .as_deref()
+        .and_then(|text| serde_json::from_str(text).ok());
+    let field = |key: &str| {
+        manifest
+            .as_ref()
+            .and_then(|m| m[key].as_str())
+            .map(String::from)
+    };
+    Ok(LocalPackInfo {
+        kind,
+        name: field("name").unwrap_or(stem),
+        version: field("version").unwrap_or_default(),
+        minecraft_version: detect_minecraft_version(&listing),
+        loader: detect_loader(&listing),
+    })
+}
+
+/// A manifest.json and no mods/ folder: the mods still have to be downloaded
+fn is_cf_client(listing: &PackListing) -> bool {
+    listing.cf_manifest.is_some() && !listing.names.iter().any(|n| n.starts_with("mods/"))
+}
+
+/// Whether `path` is a CurseForge client zip, which is installed on the host
+/// instead of being handed to the container as is
+pub fn is_cf_client_zip(path: &Path) -> bool {
+    let label = path.to_string_lossy();
+    path.is_file()
+        && label.to_lowercase().ends_with(".zip")
+        && list_pack(path, &label).is_ok_and(|listing| is_cf_client(&listing))
+}
+
+/// Copy an imported pack into a new server's data folder: a folder's contents
+/// go straight in, a file keeps its name. The copy can be cancelled between files.
+pub fn copy_into(
+    source: &Path,
+    data_path: &Path,
+    progress_tx: Option<Sender<BackupProgress>>,
+    cancel: Option<&AtomicBool>,
+) -> anyhow::Result<()> {
+    std::fs::create_dir_all(data_path)?;
+    let files: Vec<(std::path::PathBuf, String)> = if source.is_dir() {
+        walkdir::WalkDir::new(source)
+            .min_depth(1)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            .filter_map(|e| {
+                let rel = e.path().strip_prefix(source).ok()?.to_path_buf();
+                Some((e.path().to_path_buf(), rel.to_string_lossy().into_owned()))
+            })
+            .collect()
+    } else {
+        let name = source
+            .file_name()
+            .ok_or_else(|| anyhow::anyhow!("'{}' has no file name", source.display()))?;
+        vec![(source.to_path_buf(), name.to_string_lossy().into_owned())]
+    };
+
+    for (i, (from, rel)) in files.iter().enumerate() {
+        if cancel.is_some_and(|c| c.load(Ordering::Relaxed)) {
+            anyhow::bail!("Cancelled by user");
+        }
+        if let Some(tx) = &progress_tx {
+            let _ = tx.send(BackupProgress {
+                current: i + 1,
+                total: files.len(),
+                current_file: rel.clone(),
+            });
+        }
+        let to = data_path.join(rel);
+        if let Some(parent) = to.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::copy(from, &to).map_err(|e| anyhow::anyhow!("Failed to copy {}: {}", rel, e))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,62 @@
+//! Server log files read straight from the host-mounted data directory. Some packs
+//! only write important output to `logs/latest.log` or `logs/debug.log`, not stdout.
+
+use crate::config::get_server_data_path;
+use anyhow::{Context, Result};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::PathBuf;
+
+/// Only the end of a log file is read; debug.log in particular grows quickly
+const TAIL_BYTES: u64 = 512 * 1024;
+
+/// Where the container log viewer reads from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogSource {
+    /// The container's stdout/stderr, via the Docker API
+    #[default]
+    Docker,
+    LatestLog,
+    DebugLog,
+}
+
+impl LogSource {
+    pub const ALL: [LogSource; 3] = [LogSource::Docker, LogSource::LatestLog, LogSource::DebugLog];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            LogSource::Docker => "Docker stdout",
+            LogSource::LatestLog => "logs/latest.log",
+            LogSource::DebugLog => "logs/debug.log",
+        }
+    }
+
+    /// Path of the file this source reads, `None` for Docker
+    pub fn path(self, server_name: &str) -> Option<PathBuf> {
+        let file = match self {
+            LogSource::Docker => return None,
+            LogSource::LatestLog => "latest.log",
+            LogSource::DebugLog => "debug.log",
+        };
+        Some(get_server_data_path(server_name).join("logs").join(file))
+    }
+}
+
+/// The last part of a log file, starting at a line boundary
+pub fn tail(path: &std::path::Path) -> Result<String> {
+    let mut file = std::fs::File::open(path)
+        .with_context(|| format!("{} doesn't exist yet", path.display()))?;
+    let len = file.metadata()?.len();
+    let start = len.saturating_sub(TAIL_BYTES);
+    file.seek(SeekFrom::Start(start))?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes)?;
+
+    let text = String::from_utf8_lossy(&bytes);
+    if start == 0 {
+        return Ok(text.into_owned());
+    }
+    // Started mid-file: drop the partial first line
+    Ok(text
+        .split_once('\n')
+        .map_or(String::new(), |(_, rest)| rest.to_string()))
+}
@@ -1,31 +1,78 @@
 #![deny(warnings)]
 
+mod address;
+mod alerts;
+mod api_cache;
 mod app;
 mod backup;
+mod backup_overview;
+mod client_pack;
 mod config;
+mod crash;
 mod curseforge;
+mod ddns;
+mod diagnostics;
+mod discord_bot;
+mod disk_usage;
 mod docker;
+mod files;
+mod ftb;
+mod heads;
+mod http;
+mod i18n;
+mod jvm;
+mod known_errors;
+mod local_pack;
+mod logfile;
+mod memory;
+mod metrics;
+mod metrics_history;
+mod mod_browse;
 mod modrinth;
+mod mods;
+mod notify;
 mod pack_installer;
+mod playtime;
+mod ports;
+mod preflight;
+mod profiles;
+mod query;
 mod rcon;
+mod remote_backup;
+mod search;
 mod server;
+mod shortcuts;
+mod startup;
+mod storage;
+mod template_check;
 mod templates;
+mod tray;
 mod ui;
+mod uptime;
+mod webhooks;
+mod whitelist;
 
 use app::DrakonixApp;
 use tracing_subscriber::prelude::*;
 
 fn main() -> eframe::Result<()> {
+    // Portable mode keeps everything next to the executable, e.g. on a USB stick
+    let portable = config::init_portable(std::env::args().any(|arg| arg == "--portable"));
+
+    // Reopen the last profile; its data may live outside its folder (Settings → Storage)
+    profiles::open(profiles::saved());
+
     // Create logs directory
-    let log_dir = std::path::Path::new("./DrakonixAnvilData/logs");
-    std::fs::create_dir_all(log_dir).ok();
+    let log_dir = config::get_logs_path();
+    std::fs::create_dir_all(&log_dir).ok();
+    api_cache::prune();
 
     // Generate timestamped log filename
     let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
     let log_filename = format!("drakonixanvil_{}.log", timestamp);
 
     // Set up file appender
-    let file_appender = tracing_appender::rolling::never(log_dir, &log_filename);
+    let file_appender = tracing_appender::rolling::never(&log_dir, &log_filename);
     let (non_blocking, _guard) = tracing_appender::non_blocking(file_appender);
 
     // Create filter
@@ -49,6 +96,9 @@ fn main() -> eframe::Result<()> {
     tracing::info!("DrakonixAnvil v{}", env!("CARGO_PKG_VERSION"));
     tracing::info!("Report issues: https://github.com/meltingscales/DrakonixAnvil/issues");
     tracing::info!("Log file: {}", log_dir.join(&log_filename).display());
+    if portable {
+        tracing::info!("Portable mode: data in {}", config::app_root().display());
+    }
     tracing::info!("═══════════════════════════════════════════════════════════════");
 
     let native_options = eframe::NativeOptions {
@@ -0,0 +1,88 @@
+//! Host memory detection and a simple advisor for per-server memory allocation.
+
+use crate::server::{ServerInstance, ServerStatus};
+
+/// Memory left for the OS, Docker, and the app itself when suggesting allocations
+pub const HOST_RESERVE_MB: u64 = 2048;
+
+/// Smallest allocation the advisor will ever suggest
+const MIN_SUGGESTED_MB: u64 = 1024;
+
+/// Allocations are suggested in steps of this size
+const SUGGEST_STEP_MB: u64 = 512;
+
+/// Snapshot of host memory in MB. Both fields are 0 if detection failed.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HostMemory {
+    pub total_mb: u64,
+    pub available_mb: u64,
+}
+
+/// Read total and currently available system memory
+pub fn host_memory() -> HostMemory {
+    let mut sys = sysinfo::System::new();
+    sys.refresh_memory();
+    HostMemory {
+        total_mb: sys.total_memory() / (1024 * 1024),
+        available_mb: sys.available_memory() / (1024 * 1024),
+    }
+}
+
+/// Memory already spoken for by servers that are running (or about to be), excluding `exclude`
+pub fn allocated_by_active_servers(servers: &[ServerInstance], exclude: &str) -> u64 {
+    servers
+        .iter()
+        .filter(|s| s.config.name != exclude)
+        .filter(|s| !matches!(s.status, ServerStatus::Stopped | ServerStatus::Error(_)))
+        .map(|s| s.config.memory_mb)
+        .sum()
+}
+
+/// What the create/edit views need to judge a memory value
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MemoryBudget {
+    /// Total host RAM (0 = unknown, advisor is hidden)
+    pub host_total_mb: u64,
+    /// Memory allocated to other servers likely to run at the same time
+    pub others_mb: u64,
+}
+
+impl MemoryBudget {
+    pub fn is_known(&self) -> bool {
+        self.host_total_mb > 0
+    }
+
+    /// Memory this server can take without pushing the host into swap
+    pub fn headroom_mb(&self) -> u64 {
+        self.host_total_mb
+            .saturating_sub(HOST_RESERVE_MB)
+            .saturating_sub(self.others_mb)
+    }
+
+    /// A safe allocation close to `requested`, rounded down to a 512 MB step
+    pub fn suggested_mb(&self, requested: u64) -> u64 {
+        let capped = requested.min(self.headroom_mb());
+        (capped / SUGGEST_STEP_MB * SUGGEST_STEP_MB).max(MIN_SUGGESTED_MB)
+    }
+
+    /// Explain why `requested` is too much, if it is
+    pub fn warning(&self, requested: u64) -> Option<String> {
+        if !self.is_known() {
+            return None;
+        }
+        let total = requested + self.others_mb;
+        if requested > self.host_total_mb {
+            Some(format!(
+                "{} MB is more than this machine's {} MB of RAM.",
+                requested, self.host_total_mb
+            ))
+        } else if total + HOST_RESERVE_MB > self.host_total_mb {
+            Some(format!(
+                "Running servers would use {} MB of {} MB host RAM, leaving less than {} MB for the system.",
+                total, self.host_total_mb, HOST_RESERVE_MB
+            ))
+        } else {
+            None
+        }
+    }
+}
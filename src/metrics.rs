@@ -0,0 +1,279 @@
+//! Optional Prometheus endpoint: per-server status, players, TPS, container CPU
+//! and memory, and backup age, served as text for an existing Prometheus or
+//! Grafana Agent to scrape.
+
+use crate::backup;
+use crate::docker::{ContainerStats, DockerManager};
+use crate::rcon;
+use crate::server::{LiveStatus, ModLoader, ServerInstance, ServerStatus};
+use anyhow::{Context, Result};
+use std::fmt::Write;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Loopback only, so nothing is exposed to the network unless asked for
+pub const DEFAULT_BIND: &str = "127.0.0.1:9940";
+
+/// Content type of the Prometheus text exposition format
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+/// Longest a scraper gets to send its request line
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+/// Longest a server gets to answer its TPS command; big packs can be slow
+const TPS_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Figures sampled from one running server
+#[derive(Debug, Clone, Default)]
+pub struct Sample {
+    pub tps: Option<f64>,
+    pub stats: Option<ContainerStats>,
+    /// The container had stopped although the server was meant to be running
+    pub exited: bool,
+}
+
+/// Everything reported for one server
+pub struct ServerMetrics {
+    pub name: String,
+    pub status: ServerStatus,
+    pub live: Option<LiveStatus>,
+    pub sample: Sample,
+    /// Time since the newest backup was written
+    pub backup_age: Option<Duration>,
+}
+
+/// Command that reports ticks per second with this loader. Vanilla and Fabric
+/// have none of their own; `tps` works there when a mod or Paper adds it.
+pub fn tps_command(loader: &ModLoader) -> &'static str {
+    match loader {
+        ModLoader::Forge => "forge tps",
+        ModLoader::NeoForge => "neoforge tps",
+        ModLoader::Fabric | ModLoader::Vanilla => "tps",
+    }
+}
+
+/// TPS over RCON and CPU and memory from Docker. Either is left out if it
+/// can't be read.
+pub async fn sample(docker: Option<&DockerManager>, server: &ServerInstance) -> Sample {
+    let stats = match (docker, &server.container_id) {
+        (Some(docker), Some(id)) => {
+            if let Ok(false) = docker.is_container_running(id).await {
+                return Sample {
+                    exited: true,
+                    ..Default::default()
+                };
+            }
+            docker.container_stats(id).await.ok()
+        }
+        _ => None,
+    };
+    let address = format!("127.0.0.1:{}", server.config.rcon_port());
+    let command = tps_command(&server.config.modpack.loader);
+    let tps = tokio::time::timeout(
+        TPS_TIMEOUT,
+        rcon::send_command(&address, &server.config.rcon_password, command),
+    )
+    .await
+    .ok()
+    .and_then(|r| r.ok())
+    .and_then(|output| rcon::parse_tps(&output));
+    Sample {
+        tps,
+        stats,
+        exited: false,
+    }
+}
+
+/// Time since `server_name`'s newest backup was written
+pub fn backup_age(server_name: &str) -> Option<Duration> {
+    let backups = backup::list_backups(server_name).ok()?;
+    // Listed newest first
+    let newest = backups.first()?;
+    Some(
+        SystemTime::now()
+            .duration_since(newest.created)
+            .unwrap_or_default(),
+    )
+}
+
+/// Prometheus label value for a status
+fn status_name(status: &ServerStatus) -> &'static str {
+    match status {
+        ServerStatus::Stopped => "stopped",
+        ServerStatus::Pulling => "pulling",
+        ServerStatus::Starting => "starting",
+        ServerStatus::Initializing => "initializing",
+        ServerStatus::Running => "running",
+        ServerStatus::Stopping => "stopping",
+        ServerStatus::Frozen => "frozen",
+        ServerStatus::Error(_) => "error",
+    }
+}
+
+/// Label values may not contain raw backslashes, quotes or newlines
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// One gauge with a value per server; servers without a value are left out
+fn gauge(
+    out: &mut String,
+    name: &str,
+    help: &str,
+    servers: &[ServerMetrics],
+    value: impl Fn(&ServerMetrics) -> Option<f64>,
+) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} gauge", name);
+    for server in servers {
+        if let Some(value) = value(server) {
+            let _ = writeln!(
+                out,
+                "{}{{server=\"{}\"}} {}",
+                name,
+                escape_label(&server.name),
+                value
+            );
+        }
+    }
+}
+
+/// The page served at `/metrics`
+pub fn render(servers: &[ServerMetrics]) -> String {
+    let mut out = String::new();
+
+    let _ = writeln!(
+        out,
+        "# HELP drakonix_server_status 1 for the status the server is in"
+    );
+    let _ = writeln!(out, "# TYPE drakonix_server_status gauge");
+    for server in servers {
+        let _ = writeln!(
+            out,
+            "drakonix_server_status{{server=\"{}\",status=\"{}\"}} 1",
+            escape_label(&server.name),
+            status_name(&server.status)
+        );
+    }
+    gauge(
+        &mut out,
+        "drakonix_server_up",
+        "1 if the server is running and accepting players",
+        servers,
+        |s| Some(f64::from(u8::from(s.status == ServerStatus::Running))),
+    );
+    gauge(
+        &mut out,
+        "drakonix_players_online",
+        "Players online, from the last status ping",
+        servers,
+        |s| s.live.as_ref().map(|l| l.players_online as f64),
+    );
+    gauge(
+        &mut out,
+        "drakonix_players_max",
+        "Player slots, from the last status ping",
+        servers,
+        |s| s.live.as_ref().map(|l| l.players_max as f64),
+    );
+    gauge(
+        &mut out,
+        "drakonix_tps",
+        "Ticks per second (20 is full speed)",
+        servers,
+        |s| s.sample.tps,
+    );
+    gauge(
+        &mut out,
+        "drakonix_container_cpu_percent",
+        "Container CPU use, percent of one core",
+        servers,
+        |s| s.sample.stats.map(|st| st.cpu_percent),
+    );
+    gauge(
+        &mut out,
+        "drakonix_container_memory_bytes",
+        "Container memory in use, excluding page cache",
+        servers,
+        |s| s.sample.stats.map(|st| st.memory_bytes as f64),
+    );
+    gauge(
+        &mut out,
+        "drakonix_container_memory_limit_bytes",
+        "Memory the container may use",
+        servers,
+        |s| {
+            s.sample
+                .stats
+                .filter(|st| st.memory_limit_bytes > 0)
+                .map(|st| st.memory_limit_bytes as f64)
+        },
+    );
+    gauge(
+        &mut out,
+        "drakonix_backup_age_seconds",
+        "Seconds since the newest backup was written",
+        servers,
+        |s| s.backup_age.map(|age| age.as_secs() as f64),
+    );
+    out
+}
+
+pub async fn bind(address: &str) -> Result<TcpListener> {
+    TcpListener::bind(address)
+        .await
+        .with_context(|| format!("Failed to listen on {}", address))
+}
+
+/// Answer `GET /metrics` with the latest `body` until the task is aborted
+pub async fn serve(listener: TcpListener, body: Arc<Mutex<String>>) {
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                // e.g. out of file handles; don't spin on it
+                tracing::warn!("Metrics endpoint accept failed: {}", e);
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                continue;
+            }
+        };
+        let body = body.clone();
+        tokio::spawn(async move {
+            if let Err(e) = respond(stream, &body).await {
+                tracing::debug!("Metrics request failed: {:#}", e);
+            }
+        });
+    }
+}
+
+async fn respond(mut stream: TcpStream, body: &Mutex<String>) -> Result<()> {
+    // Only the request line matters; headers are ignored
+    let mut buf = [0u8; 2048];
+    let read = tokio::time::timeout(REQUEST_TIMEOUT, stream.read(&mut buf))
+        .await
+        .context("Request timed out")??;
+    let request = String::from_utf8_lossy(&buf[..read]);
+    let path = request.split_whitespace().nth(1).unwrap_or("");
+    let path = path.split('?').next().unwrap_or(path);
+
+    let (status, content_type, text) = if path == "/metrics" {
+        let text = body.lock().map(|b| b.clone()).unwrap_or_default();
+        ("200 OK", CONTENT_TYPE, text)
+    } else {
+        let text = "Metrics are at /metrics\n".to_string();
+        ("404 Not Found", "text/plain; charset=utf-8", text)
+    };
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        text.len(),
+        text
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await.ok();
+    Ok(())
+}
@@ -0,0 +1,124 @@
+//! Players, TPS and memory of each running server, sampled once a minute and
+//! kept for a week in `metrics.jsonl` next to the data directory, for the charts
+//! on the Overview tab. One JSON object per line, so recording a sample is a
+//! single append.
+
+use crate::config::get_server_path;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Samples older than this are dropped
+pub const RETENTION: Duration = Duration::from_secs(7 * 24 * 3600);
+/// Past this size the file is rewritten without expired samples; a week of
+/// one-a-minute samples is about 800 KB
+const COMPACT_AT_BYTES: u64 = 2 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct HistoryPoint {
+    /// Unix seconds
+    pub at: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub players: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tps: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_bytes: Option<u64>,
+}
+
+/// Time span shown by the charts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HistoryRange {
+    #[default]
+    Day,
+    Week,
+}
+
+impl HistoryRange {
+    pub const ALL: [HistoryRange; 2] = [HistoryRange::Day, HistoryRange::Week];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            HistoryRange::Day => "24 hours",
+            HistoryRange::Week => "7 days",
+        }
+    }
+
+    pub fn duration(self) -> Duration {
+        match self {
+            HistoryRange::Day => Duration::from_secs(24 * 3600),
+            HistoryRange::Week => RETENTION,
+        }
+    }
+}
+
+fn history_path(server_name: &str) -> PathBuf {
+    get_server_path(server_name).join("metrics.jsonl")
+}
+
+/// Record one sample, compacting the file once it has grown well past a week
+pub fn append(server_name: &str, point: &HistoryPoint) -> Result<()> {
+    let path = history_path(server_name);
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    writeln!(file, "{}", serde_json::to_string(point)?)?;
+    if file.metadata().map(|m| m.len()).unwrap_or(0) > COMPACT_AT_BYTES {
+        compact(server_name)?;
+    }
+    Ok(())
+}
+
+/// Samples from the last week, oldest first. Lines that don't parse (e.g. cut
+/// short by a crash) are skipped.
+pub fn load(server_name: &str) -> Vec<HistoryPoint> {
+    let cutoff = chrono::Utc::now().timestamp() - RETENTION.as_secs() as i64;
+    std::fs::read_to_string(history_path(server_name))
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| serde_json::from_str::<HistoryPoint>(line).ok())
+        .filter(|p| p.at >= cutoff)
+        .collect()
+}
+
+/// Rewrite the file with only the last week's samples
+fn compact(server_name: &str) -> Result<()> {
+    let mut text = String::new();
+    for point in load(server_name) {
+        text.push_str(&serde_json::to_string(&point)?);
+        text.push('\n');
+    }
+    let path = history_path(server_name);
+    let tmp = path.with_extension("jsonl.tmp");
+    std::fs::write(&tmp, text).with_context(|| format!("Failed to write {}", tmp.display()))?;
+    std::fs::rename(&tmp, &path).with_context(|| format!("Failed to replace {}", path.display()))
+}
+
+/// Average of `value` over each of `buckets` equal slices of `from..to` (Unix
+/// seconds); `None` where there are no samples, so gaps show as gaps
+pub fn downsample(
+    points: &[HistoryPoint],
+    from: i64,
+    to: i64,
+    buckets: usize,
+    value: impl Fn(&HistoryPoint) -> Option<f64>,
+) -> Vec<Option<f64>> {
+    let mut sums = vec![(0.0, 0u32); buckets];
+    let span = (to - from).max(1) as f64;
+    for point in points.iter().filter(|p| p.at >= from && p.at <= to) {
+        let Some(v) = value(point) else {
+            continue;
+        };
+        let i = ((point.at - from) as f64 / span * buckets as f64) as usize;
+        let slot = &mut sums[i.min(buckets - 1)];
+        slot.0 += v;
+        slot.1 += 1;
+    }
+    sums.into_iter()
+        .map(|(sum, n)| (n > 0).then(|| sum / n as f64))
+        .collect()
+}
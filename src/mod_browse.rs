@@ -0,0 +1,290 @@
+//! Finding single mods on Modrinth or CurseForge for an existing server and
+//! installing them into its mods folder, along with any required dependencies
+//! the server doesn't have yet.
+
+use crate::curseforge::{self, CfFile, CfMod};
+use crate::modrinth::{self, MrProject, MrVersion};
+use crate::server::ModLoader;
+use anyhow::{Context, Result};
+use std::collections::{HashSet, VecDeque};
+use std::path::Path;
+
+/// Required dependencies followed before giving up, so a bad chain can't run forever
+const MAX_DEPENDENCIES: usize = 50;
+
+/// Where mods are searched for and downloaded from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ModSite {
+    #[default]
+    Modrinth,
+    CurseForge,
+}
+
+impl ModSite {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Modrinth => "Modrinth",
+            Self::CurseForge => "CurseForge",
+        }
+    }
+}
+
+/// The site to use and what the mods have to run on
+#[derive(Debug, Clone)]
+pub struct ModQuery {
+    pub site: ModSite,
+    /// Modrinth token or CurseForge API key, depending on the site
+    pub key: Option<String>,
+    pub loader: ModLoader,
+    pub minecraft_version: String,
+}
+
+/// A mod found by a search
+#[derive(Debug, Clone)]
+pub struct ModHit {
+    /// Modrinth project ID or CurseForge mod ID
+    pub id: String,
+    pub slug: String,
+    pub title: String,
+    pub summary: String,
+    pub downloads: u64,
+    pub icon_url: Option<String>,
+}
+
+impl From<MrProject> for ModHit {
+    fn from(p: MrProject) -> Self {
+        Self {
+            id: p.project_id,
+            slug: p.slug,
+            title: p.title,
+            summary: p.description,
+            downloads: p.downloads,
+            icon_url: p.icon_url,
+        }
+    }
+}
+
+impl From<CfMod> for ModHit {
+    fn from(m: CfMod) -> Self {
+        Self {
+            id: m.id.to_string(),
+            slug: m.slug,
+            title: m.name,
+            summary: m.summary,
+            downloads: m.download_count,
+            icon_url: m.logo.map(|l| l.thumbnail_url),
+        }
+    }
+}
+
+/// One downloadable build of a mod for the server's loader and Minecraft version
+#[derive(Debug, Clone)]
+pub struct ModRelease {
+    pub name: String,
+    pub file_name: String,
+    /// Publish date as sent by the site (RFC 3339)
+    pub date: String,
+    pub url: String,
+    pub sha1: Option<String>,
+    /// Project/mod IDs of required dependencies
+    pub dependencies: Vec<String>,
+    /// Tagged for the client only; it would do nothing or crash a server
+    pub client_only: bool,
+}
+
+impl ModRelease {
+    fn from_modrinth(v: MrVersion) -> Option<Self> {
+        let file = v
+            .files
+            .iter()
+            .find(|f| f.primary)
+            .or_else(|| v.files.first())?;
+        Some(Self {
+            name: v.name,
+            file_name: file.filename.clone(),
+            date: v.date_published,
+            url: file.url.clone(),
+            sha1: file.hashes.get("sha1").cloned(),
+            dependencies: v
+                .dependencies
+                .iter()
+                .filter(|d| d.is_required())
+                .filter_map(|d| d.project_id.clone())
+                .collect(),
+            client_only: false,
+        })
+    }
+
+    fn from_curseforge(f: CfFile) -> Self {
+        Self {
+            name: f.display_name.clone(),
+            url: f.url(),
+            sha1: f.sha1().map(str::to_string),
+            dependencies: f
+                .dependencies
+                .iter()
+                .filter(|d| d.is_required())
+                .map(|d| d.mod_id.to_string())
+                .collect(),
+            client_only: f.is_client_only(),
+            file_name: f.file_name,
+            date: f.file_date,
+        }
+    }
+}
+
+fn modrinth_loader(loader: &ModLoader) -> Result<&'static str> {
+    modrinth::loader_name(loader).context("Vanilla servers don't load mods")
+}
+
+fn cf_key(query: &ModQuery) -> Result<&str> {
+    query
+        .key
+        .as_deref()
+        .filter(|k| !k.is_empty())
+        .context("Add a CurseForge API key in Settings to use CurseForge")
+}
+
+fn cf_id(id: &str) -> Result<u64> {
+    id.parse()
+        .with_context(|| format!("'{}' is not a CurseForge mod ID", id))
+}
+
+/// Search for mods that run on the query's loader and Minecraft version. Returns
+/// one page of hits and the total count.
+pub async fn search(query: &ModQuery, text: &str, offset: u64) -> Result<(Vec<ModHit>, u64)> {
+    match query.site {
+        ModSite::Modrinth => {
+            let loader = modrinth_loader(&query.loader)?;
+            let (hits, total) = modrinth::search_mods(
+                query.key.as_deref(),
+                text,
+                &query.minecraft_version,
+                loader,
+                offset,
+            )
+            .await?;
+            Ok((hits.into_iter().map(ModHit::from).collect(), total))
+        }
+        ModSite::CurseForge => {
+            let (hits, total) = curseforge::search_mods(
+                cf_key(query)?,
+                text,
+                &query.minecraft_version,
+                Some(&query.loader),
+                offset,
+            )
+            .await?;
+            Ok((hits.into_iter().map(ModHit::from).collect(), total))
+        }
+    }
+}
+
+/// Builds of a mod for the query's loader and Minecraft version, newest first
+pub async fn releases(query: &ModQuery, id: &str) -> Result<Vec<ModRelease>> {
+    let mut releases: Vec<ModRelease> = match query.site {
+        ModSite::Modrinth => {
+            let loader = modrinth_loader(&query.loader)?;
+            modrinth::get_compatible_versions(
+                query.key.as_deref(),
+                id,
+                loader,
+                &query.minecraft_version,
+            )
+            .await?
+            .into_iter()
+            .filter_map(ModRelease::from_modrinth)
+            .collect()
+        }
+        ModSite::CurseForge => curseforge::get_compatible_files(
+            cf_key(query)?,
+            cf_id(id)?,
+            &query.minecraft_version,
+            &query.loader,
+        )
+        .await?
+        .into_iter()
+        .map(ModRelease::from_curseforge)
+        .collect(),
+    };
+    // RFC 3339 dates in UTC sort as text
+    releases.sort_by(|a, b| b.date.cmp(&a.date));
+    Ok(releases)
+}
+
+/// URL slug of a project/mod, for matching against installed mod IDs
+async fn slug(query: &ModQuery, id: &str) -> Result<String> {
+    match query.site {
+        ModSite::Modrinth => Ok(modrinth::get_project(query.key.as_deref(), id).await?.slug),
+        ModSite::CurseForge => Ok(curseforge::get_mod(cf_key(query)?, cf_id(id)?).await?.slug),
+    }
+}
+
+/// Mod IDs and slugs written differently (`cloth-config` vs `cloth_config`) compare equal
+pub fn normalize_id(id: &str) -> String {
+    id.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Download `release` into `mods_dir`, then each required dependency that isn't
+/// installed yet (newest compatible build), following their dependencies in turn.
+/// `installed` holds the normalized IDs of mods already on the server. Returns the
+/// file names written.
+pub async fn install(
+    query: &ModQuery,
+    mods_dir: &Path,
+    release: ModRelease,
+    installed: &HashSet<String>,
+) -> Result<Vec<String>> {
+    let mut written = Vec::new();
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut queue = VecDeque::from([release]);
+
+    while let Some(release) = queue.pop_front() {
+        let dest = mods_dir.join(&release.file_name);
+        crate::pack_installer::install_file(
+            std::slice::from_ref(&release.url),
+            &dest,
+            release.sha1.as_deref(),
+            0,
+        )
+        .await?;
+        tracing::info!(
+            "Installed {} into {}",
+            release.file_name,
+            mods_dir.display()
+        );
+        written.push(release.file_name);
+
+        for dep in release.dependencies {
+            if !seen.insert(dep.clone()) {
+                continue;
+            }
+            if seen.len() > MAX_DEPENDENCIES {
+                anyhow::bail!("Stopped after {} dependencies", MAX_DEPENDENCIES);
+            }
+            let dep_slug = slug(query, &dep).await.unwrap_or_default();
+            if installed.contains(&normalize_id(&dep_slug)) {
+                continue;
+            }
+            let found = releases(query, &dep)
+                .await
+                .with_context(|| format!("Couldn't look up dependency '{}'", dep_slug))?;
+            let Some(newest) = found.into_iter().next() else {
+                anyhow::bail!(
+                    "Required dependency '{}' has no build for {:?} {}",
+                    if dep_slug.is_empty() { &dep } else { &dep_slug },
+                    query.loader,
+                    query.minecraft_version
+                );
+            };
+            if mods_dir.join(&newest.file_name).exists() {
+                continue;
+            }
+            queue.push_back(newest);
+        }
+    }
+    Ok(written)
+}
@@ -1,3 +1,4 @@
+use crate::server::ModLoader;
 use serde::Deserialize;
 
 // ── Modrinth API response types ──────────────────────────────────────────
@@ -26,26 +27,53 @@ pub struct MrProject {
 #[derive(Debug, Clone, Deserialize)]
 pub struct MrVersion {
     pub id: String,
+    #[serde(default)]
+    pub project_id: String,
     pub version_number: String,
     pub name: String,
     pub game_versions: Vec<String>,
     pub loaders: Vec<String>,
     pub date_published: String,
-    #[allow(dead_code)] // Deserialized from API, available for future use
     pub files: Vec<MrFile>,
+    #[serde(default)]
+    pub dependencies: Vec<MrDependency>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
-#[allow(dead_code)] // Deserialized from API, available for future use
 pub struct MrFile {
     pub url: String,
     pub filename: String,
     pub primary: bool,
+    /// Hex digests keyed by algorithm ("sha1", "sha512")
+    #[serde(default)]
+    pub hashes: std::collections::HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct MrDependency {
+    pub project_id: Option<String>,
+    /// "required", "optional", "incompatible" or "embedded"
+    pub dependency_type: String,
+}
+
+impl MrDependency {
+    pub fn is_required(&self) -> bool {
+        self.dependency_type == "required"
+    }
+}
+
+/// Whether a project runs on the client: "required", "optional" or "unsupported"
+#[derive(Debug, Clone, Deserialize)]
+pub struct MrProjectSides {
+    pub id: String,
+    pub client_side: String,
 }
 
 /// Full project detail (for fetching the body/description).
 #[derive(Debug, Deserialize)]
 pub struct MrProjectDetail {
+    #[serde(default)]
+    pub slug: String,
     pub body: String,
 }
 
@@ -95,25 +123,176 @@ impl MrSortIndex {
 const MR_BASE: &str = "https://api.modrinth.com/v2";
 const USER_AGENT: &str = "henrypost/DrakonixAnvil/0.5.0";
 
+/// Retries after a 429 before giving up on a request
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+
+/// Requests allowed in flight at once; the rest queue here instead of tripping the limit
+static REQUEST_SLOTS: tokio::sync::Semaphore = tokio::sync::Semaphore::const_new(4);
+
+/// When set, every request waits until this instant (shared cooldown after a 429)
+static RATE_LIMITED_UNTIL: std::sync::Mutex<Option<std::time::Instant>> =
+    std::sync::Mutex::new(None);
+
 fn modrinth_client() -> reqwest::Client {
-    reqwest::Client::builder()
+    crate::http::client_builder()
         .user_agent(USER_AGENT)
         .build()
         .expect("Failed to build HTTP client")
 }
 
+/// GET request with the optional personal access token attached
+fn modrinth_get(url: String, token: Option<&str>) -> reqwest::RequestBuilder {
+    with_token(modrinth_client().get(url), token)
+}
+
+fn with_token(req: reqwest::RequestBuilder, token: Option<&str>) -> reqwest::RequestBuilder {
+    match token.filter(|t| !t.is_empty()) {
+        Some(token) => req.header(reqwest::header::AUTHORIZATION, token),
+        None => req,
+    }
+}
+
+/// Push the shared cooldown out to at least `wait` from now
+fn set_cooldown(wait: std::time::Duration) {
+    let until = std::time::Instant::now() + wait;
+    if let Ok(mut guard) = RATE_LIMITED_UNTIL.lock() {
+        if guard.is_none_or(|current| current < until) {
+            *guard = Some(until);
+        }
+    }
+}
+
+async fn wait_for_cooldown() {
+    let until = RATE_LIMITED_UNTIL.lock().ok().and_then(|g| *g);
+    if let Some(until) = until {
+        let now = std::time::Instant::now();
+        if until > now {
+            tokio::time::sleep(until - now).await;
+        }
+    }
+}
+
+/// Seconds from a `Retry-After` or `X-Ratelimit-Reset` header
+fn header_secs(resp: &reqwest::Response, name: &str) -> Option<u64> {
+    resp.headers().get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+/// Send a request through the shared queue, honoring Modrinth's rate limit.
+/// On 429 the request waits for `Retry-After` (or backs off) and is retried.
+async fn send(req: reqwest::RequestBuilder) -> anyhow::Result<reqwest::Response> {
+    let _slot = REQUEST_SLOTS.acquire().await?;
+
+    for attempt in 0..=MAX_RATE_LIMIT_RETRIES {
+        wait_for_cooldown().await;
+
+        let attempt_req = req
+            .try_clone()
+            .ok_or_else(|| anyhow::anyhow!("Modrinth request cannot be retried"))?;
+        let resp = attempt_req.send().await?;
+
+        // Out of quota for this window: make everyone wait for the reset
+        if header_secs(&resp, "x-ratelimit-remaining") == Some(0) {
+            if let Some(reset) = header_secs(&resp, "x-ratelimit-reset") {
+                set_cooldown(std::time::Duration::from_secs(reset));
+            }
+        }
+
+        if resp.status() != reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Ok(resp);
+        }
+
+        let wait = header_secs(&resp, "retry-after")
+            .or_else(|| header_secs(&resp, "x-ratelimit-reset"))
+            .map(std::time::Duration::from_secs)
+            .unwrap_or_else(|| std::time::Duration::from_secs(2u64.pow(attempt + 1)));
+        tracing::warn!(
+            "Modrinth rate limit hit, retrying in {}s (attempt {}/{})",
+            wait.as_secs(),
+            attempt + 1,
+            MAX_RATE_LIMIT_RETRIES
+        );
+        set_cooldown(wait);
+    }
+
+    anyhow::bail!(
+        "Modrinth rate limit exceeded; try again in a minute (or add a Modrinth token in Settings)"
+    )
+}
+
+/// Send a GET through [`send`] and parse the JSON reply, reusing a recent
+/// response from the [`crate::api_cache`] when there is one
+async fn get_json<T: serde::de::DeserializeOwned>(
+    req: reqwest::RequestBuilder,
+) -> anyhow::Result<T> {
+    let key = crate::api_cache::key(&req);
+    if let Some(body) = key.as_deref().and_then(crate::api_cache::get) {
+        if let Ok(data) = serde_json::from_str(&body) {
+            return Ok(data);
+        }
+    }
+
+    let resp = send(req).await?;
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        anyhow::bail!("Modrinth API error {}: {}", status, body);
+    }
+
+    let body = resp.text().await?;
+    let data = serde_json::from_str(&body)?;
+    if let Some(key) = key {
+        crate::api_cache::put(&key, &body);
+    }
+    Ok(data)
+}
+
 /// Search Modrinth for modpacks. Returns (results, total_hits).
 pub async fn search_modpacks(
+    token: Option<&str>,
     query: &str,
     game_version: &str,
     loader: &str,
     sort: MrSortIndex,
     offset: u64,
 ) -> anyhow::Result<(Vec<MrProject>, u64)> {
-    let client = modrinth_client();
+    search(token, "modpack", query, game_version, loader, sort, offset).await
+}
+
+/// Search Modrinth for mods that run on a server. Returns (results, total_hits).
+pub async fn search_mods(
+    token: Option<&str>,
+    query: &str,
+    game_version: &str,
+    loader: &str,
+    offset: u64,
+) -> anyhow::Result<(Vec<MrProject>, u64)> {
+    search(
+        token,
+        "mod",
+        query,
+        game_version,
+        loader,
+        MrSortIndex::Relevance,
+        offset,
+    )
+    .await
+}
 
-    // Build facets: always filter project_type:modpack
-    let mut facets: Vec<String> = vec!["[\"project_type:modpack\"]".to_string()];
+async fn search(
+    token: Option<&str>,
+    project_type: &str,
+    query: &str,
+    game_version: &str,
+    loader: &str,
+    sort: MrSortIndex,
+    offset: u64,
+) -> anyhow::Result<(Vec<MrProject>, u64)> {
+    // Build facets: always filter by project type
+    let mut facets: Vec<String> = vec![format!("[\"project_type:{}\"]", project_type)];
+    if project_type == "mod" {
+        // Client-only mods would be useless (or fatal) on a dedicated server
+        facets.push("[\"server_side:required\",\"server_side:optional\"]".to_string());
+    }
     if !game_version.is_empty() {
         facets.push(format!("[\"versions:{}\"]", game_version));
     }
@@ -122,39 +301,72 @@ pub async fn search_modpacks(
     }
     let facets_str = format!("[{}]", facets.join(","));
 
-    let mut req = client
-        .get(format!("{}/search", MR_BASE))
-        .query(&[
-            ("facets", facets_str.as_str()),
-            ("limit", "20"),
-            ("index", sort.as_api_value()),
-            ("offset", &offset.to_string()),
-        ]);
+    let mut req = modrinth_get(format!("{}/search", MR_BASE), token).query(&[
+        ("facets", facets_str.as_str()),
+        ("limit", "20"),
+        ("index", sort.as_api_value()),
+        ("offset", &offset.to_string()),
+    ]);
 
     if !query.is_empty() {
         req = req.query(&[("query", query)]);
     }
 
-    let resp = req.send().await?;
-    if !resp.status().is_success() {
-        let status = resp.status();
-        let body = resp.text().await.unwrap_or_default();
-        anyhow::bail!("Modrinth API error {}: {}", status, body);
-    }
-
-    let data: MrSearchResponse = resp.json().await?;
+    let data: MrSearchResponse = get_json(req).await?;
     let total = data.total_hits;
     Ok((data.hits, total))
 }
 
 /// Fetch all versions for a project (by slug or id).
-pub async fn get_project_versions(id_or_slug: &str) -> anyhow::Result<Vec<MrVersion>> {
-    let client = modrinth_client();
+pub async fn get_project_versions(
+    token: Option<&str>,
+    id_or_slug: &str,
+) -> anyhow::Result<Vec<MrVersion>> {
+    let versions: Vec<MrVersion> = get_json(modrinth_get(
+        format!("{}/project/{}/version", MR_BASE, id_or_slug),
+        token,
+    ))
+    .await?;
+    Ok(versions)
+}
+
+/// Fetch a project's versions for one loader and Minecraft version, newest first.
+pub async fn get_compatible_versions(
+    token: Option<&str>,
+    id_or_slug: &str,
+    loader: &str,
+    game_version: &str,
+) -> anyhow::Result<Vec<MrVersion>> {
+    let loaders = format!("[\"{}\"]", loader);
+    let game_versions = format!("[\"{}\"]", game_version);
+    let req = modrinth_get(format!("{}/project/{}/version", MR_BASE, id_or_slug), token).query(&[
+        ("loaders", loaders.as_str()),
+        ("game_versions", game_versions.as_str()),
+    ]);
+    let versions: Vec<MrVersion> = get_json(req).await?;
+    Ok(versions)
+}
+
+/// Fetch a project (slug, description) by slug or id.
+pub async fn get_project(token: Option<&str>, id_or_slug: &str) -> anyhow::Result<MrProjectDetail> {
+    get_json(modrinth_get(
+        format!("{}/project/{}", MR_BASE, id_or_slug),
+        token,
+    ))
+    .await
+}
 
-    let resp = client
-        .get(format!("{}/project/{}/version", MR_BASE, id_or_slug))
-        .send()
-        .await?;
+/// Find the versions that contain files with these SHA-1s, keyed by SHA-1.
+/// Files Modrinth doesn't host are left out.
+pub async fn get_versions_by_sha1(
+    token: Option<&str>,
+    sha1s: &[String],
+) -> anyhow::Result<std::collections::HashMap<String, MrVersion>> {
+    let body = serde_json::json!({ "hashes": sha1s, "algorithm": "sha1" });
+    let req = modrinth_client()
+        .post(format!("{}/version_files", MR_BASE))
+        .json(&body);
+    let resp = send(with_token(req, token)).await?;
 
     if !resp.status().is_success() {
         let status = resp.status();
@@ -162,18 +374,17 @@ pub async fn get_project_versions(id_or_slug: &str) -> anyhow::Result<Vec<MrVers
         anyhow::bail!("Modrinth API error {}: {}", status, body);
     }
 
-    let versions: Vec<MrVersion> = resp.json().await?;
-    Ok(versions)
+    Ok(resp.json().await?)
 }
 
-/// Fetch the full project description (body field, markdown).
-pub async fn get_project_description(id_or_slug: &str) -> anyhow::Result<String> {
-    let client = modrinth_client();
-
-    let resp = client
-        .get(format!("{}/project/{}", MR_BASE, id_or_slug))
-        .send()
-        .await?;
+/// Fetch the client/server sides of many projects at once.
+pub async fn get_project_sides(
+    token: Option<&str>,
+    ids: &[String],
+) -> anyhow::Result<Vec<MrProjectSides>> {
+    let ids = serde_json::to_string(ids)?;
+    let req = modrinth_get(format!("{}/projects", MR_BASE), token).query(&[("ids", ids)]);
+    let resp = send(req).await?;
 
     if !resp.status().is_success() {
         let status = resp.status();
@@ -181,8 +392,15 @@ pub async fn get_project_description(id_or_slug: &str) -> anyhow::Result<String>
         anyhow::bail!("Modrinth API error {}: {}", status, body);
     }
 
-    let detail: MrProjectDetail = resp.json().await?;
-    Ok(detail.body)
+    Ok(resp.json().await?)
+}
+
+/// Fetch the full project description (body field, markdown).
+pub async fn get_project_description(
+    token: Option<&str>,
+    id_or_slug: &str,
+) -> anyhow::Result<String> {
+    Ok(get_project(token, id_or_slug).await?.body)
 }
 
 // ── Helper functions ─────────────────────────────────────────────────────
@@ -200,14 +418,24 @@ pub fn extract_mc_versions(versions: &[MrVersion]) -> Vec<String> {
         .collect();
 
     mc_versions.sort_by(|a, b| {
-        let parse =
-            |s: &str| -> Vec<u32> { s.split('.').filter_map(|p| p.parse().ok()).collect() };
+        let parse = |s: &str| -> Vec<u32> { s.split('.').filter_map(|p| p.parse().ok()).collect() };
         parse(b).cmp(&parse(a))
     });
 
     mc_versions
 }
 
+/// Modrinth's name for a loader, as used in version filters and search categories.
+/// Vanilla has none.
+pub fn loader_name(loader: &ModLoader) -> Option<&'static str> {
+    match loader {
+        ModLoader::Forge => Some("forge"),
+        ModLoader::Fabric => Some("fabric"),
+        ModLoader::NeoForge => Some("neoforge"),
+        ModLoader::Vanilla => None,
+    }
+}
+
 /// Detect the mod loader from a Modrinth version's loaders array.
 /// Returns a lowercase string like "forge", "fabric", "neoforge".
 pub fn detect_loader(loaders: &[String]) -> &str {
@@ -0,0 +1,747 @@
+//! Installed mod metadata and dependency analysis. Reads `mods.toml` / `neoforge.mods.toml` /
+//! `fabric.mod.json` out of each jar in `data/mods` and works out which dependencies are
+//! missing, too old, or no longer needed. Also adds and removes jars for the Mods tab.
+
+use crate::server::ModLoader;
+use anyhow::{Context, Result};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{Cursor, Read, Seek};
+use std::path::{Path, PathBuf};
+use zip::ZipArchive;
+
+/// Dependency IDs provided by the loader/game itself, never jars in the mods folder
+const BUILTIN_IDS: &[&str] = &[
+    "minecraft",
+    "java",
+    "forge",
+    "neoforge",
+    "fabricloader",
+    "fabric-loader",
+    "quilt_loader",
+    "javafml",
+    "lowcodefml",
+    "mixinextras",
+];
+
+/// Well-known library mods that don't follow the *lib / *api naming pattern
+const KNOWN_LIBRARIES: &[&str] = &[
+    "architectury",
+    "balm",
+    "bookshelf",
+    "citadel",
+    "cloth_config",
+    "cloth-config",
+    "collective",
+    "cristellib",
+    "curios",
+    "fabric-language-kotlin",
+    "fabric_language_kotlin",
+    "forgeconfigapiport",
+    "fzzy_config",
+    "geckolib",
+    "kotlinforforge",
+    "lithostitched",
+    "moonlight",
+    "owo",
+    "puzzleslib",
+    "resourcefulconfig",
+    "terrablender",
+    "trinkets",
+    "yet_another_config_lib_v3",
+];
+
+#[derive(Debug, Clone)]
+pub struct ModDependency {
+    pub mod_id: String,
+    /// Version requirement as written in the metadata (Maven range or Fabric predicate)
+    pub version_req: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ModInfo {
+    pub file_name: String,
+    pub mod_id: String,
+    pub name: String,
+    pub version: String,
+    /// Other IDs this jar satisfies (extra [[mods]] entries, Fabric `provides`, jar-in-jar)
+    pub provides: Vec<String>,
+    /// Required dependencies only; optional and client-only ones are skipped
+    pub dependencies: Vec<ModDependency>,
+    /// IDs of optional dependencies, only used to keep those libraries from looking unused
+    pub optional_dependencies: Vec<String>,
+    pub library: bool,
+}
+
+impl ModInfo {
+    pub fn satisfies(&self, mod_id: &str) -> bool {
+        self.mod_id == mod_id || self.provides.iter().any(|p| p == mod_id)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum DependencyIssue {
+    Missing {
+        mod_id: String,
+        version_req: Option<String>,
+        required_by: Vec<String>,
+    },
+    Outdated {
+        mod_id: String,
+        installed: String,
+        version_req: String,
+        required_by: Vec<String>,
+    },
+    /// A library jar nothing depends on anymore
+    Orphaned { mod_id: String, file_name: String },
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ModGraph {
+    pub mods: Vec<ModInfo>,
+    pub issues: Vec<DependencyIssue>,
+    /// Jars in the mods folder without readable metadata
+    pub unreadable: Vec<String>,
+    /// Size in bytes of every jar scanned, readable or not, by file name
+    pub sizes: HashMap<String, u64>,
+}
+
+impl ModGraph {
+    pub fn find(&self, mod_id: &str) -> Option<&ModInfo> {
+        self.mods.iter().find(|m| m.satisfies(mod_id))
+    }
+
+    /// Mods that list `target` (or anything it provides) as a required dependency
+    pub fn dependents(&self, target: &ModInfo) -> Vec<&ModInfo> {
+        self.mods
+            .iter()
+            .filter(|m| m.file_name != target.file_name)
+            .filter(|m| m.dependencies.iter().any(|d| target.satisfies(&d.mod_id)))
+            .collect()
+    }
+
+    /// Mods that list `target` (or anything it provides) as a required or optional dependency
+    fn users(&self, target: &ModInfo) -> Vec<&ModInfo> {
+        self.mods
+            .iter()
+            .filter(|m| m.file_name != target.file_name)
+            .filter(|m| {
+                m.dependencies.iter().any(|d| target.satisfies(&d.mod_id))
+                    || m.optional_dependencies.iter().any(|d| target.satisfies(d))
+            })
+            .collect()
+    }
+
+    /// Jar file names of libraries nothing needs anymore
+    pub fn orphaned_files(&self) -> Vec<String> {
+        self.issues
+            .iter()
+            .filter_map(|i| match i {
+                DependencyIssue::Orphaned { file_name, .. } => Some(file_name.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn missing_count(&self) -> usize {
+        self.issues
+            .iter()
+            .filter(|i| matches!(i, DependencyIssue::Missing { .. }))
+            .count()
+    }
+}
+
+// ── Scanning ───────────────────────────────────────────────────────────────
+
+/// Read every jar in `mods_dir` and build the dependency graph
+pub fn scan(mods_dir: &Path) -> Result<ModGraph> {
+    let mut mods = Vec::new();
+    let mut unreadable = Vec::new();
+    let mut sizes = HashMap::new();
+
+    if mods_dir.exists() {
+        let mut entries: Vec<_> = std::fs::read_dir(mods_dir)
+            .with_context(|| format!("Failed to read {}", mods_dir.display()))?
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|e| e == "jar"))
+            .collect();
+        entries.sort();
+
+        for path in entries {
+            let file_name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            if let Ok(meta) = path.metadata() {
+                sizes.insert(file_name.clone(), meta.len());
+            }
+            match read_jar(&path) {
+                Ok(Some(info)) => mods.push(info),
+                Ok(None) => unreadable.push(file_name),
+                Err(e) => {
+                    tracing::warn!("Failed to read mod metadata from {}: {}", file_name, e);
+                    unreadable.push(file_name);
+                }
+            }
+        }
+    }
+
+    let mut graph = analyze(mods, unreadable);
+    graph.sizes = sizes;
+    Ok(graph)
+}
+
+fn read_jar(path: &Path) -> Result<Option<ModInfo>> {
+    let file = File::open(path)?;
+    let mut archive = ZipArchive::new(file)?;
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    read_metadata(&mut archive, file_name, true)
+}
+
+/// Parse mod metadata from an open jar. Nested jars (jar-in-jar) are read one level deep
+/// and their IDs added to `provides`.
+fn read_metadata<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    file_name: String,
+    include_nested: bool,
+) -> Result<Option<ModInfo>> {
+    let mut info = if let Some(text) = read_entry(archive, "META-INF/neoforge.mods.toml") {
+        parse_mods_toml(&text, file_name, archive)?
+    } else if let Some(text) = read_entry(archive, "META-INF/mods.toml") {
+        parse_mods_toml(&text, file_name, archive)?
+    } else if let Some(text) = read_entry(archive, "fabric.mod.json") {
+        parse_fabric_json(&text, file_name)?
+    } else {
+        None
+    };
+
+    if let (Some(info), true) = (info.as_mut(), include_nested) {
+        let nested: Vec<String> = archive
+            .file_names()
+            .filter(|n| {
+                (n.starts_with("META-INF/jars/") || n.starts_with("META-INF/jarjar/"))
+                    && n.ends_with(".jar")
+            })
+            .map(String::from)
+            .collect();
+        for name in nested {
+            let mut bytes = Vec::new();
+            if archive.by_name(&name)?.read_to_end(&mut bytes).is_err() {
+                continue;
+            }
+            let Ok(mut inner) = ZipArchive::new(Cursor::new(bytes)) else {
+                continue;
+            };
+            if let Ok(Some(nested_info)) = read_metadata(&mut inner, name, false) {
+                info.provides.push(nested_info.mod_id);
+                info.provides.extend(nested_info.provides);
+            }
+        }
+    }
+
+    Ok(info)
+}
+
+fn read_entry<R: Read + Seek>(archive: &mut ZipArchive<R>, name: &str) -> Option<String> {
+    let mut entry = archive.by_name(name).ok()?;
+    let mut text = String::new();
+    entry.read_to_string(&mut text).ok()?;
+    Some(text)
+}
+
+/// `Implementation-Version` from the jar manifest, used for `${file.jarVersion}`
+fn manifest_version<R: Read + Seek>(archive: &mut ZipArchive<R>) -> Option<String> {
+    read_entry(archive, "META-INF/MANIFEST.MF")?
+        .lines()
+        .find_map(|l| l.strip_prefix("Implementation-Version:"))
+        .map(|v| v.trim().to_string())
+}
+
+fn parse_mods_toml<R: Read + Seek>(
+    text: &str,
+    file_name: String,
+    archive: &mut ZipArchive<R>,
+) -> Result<Option<ModInfo>> {
+    let doc: toml::Table = text.parse().context("Invalid mods.toml")?;
+    let Some(entries) = doc.get("mods").and_then(|m| m.as_array()) else {
+        return Ok(None);
+    };
+
+    let ids: Vec<String> = entries
+        .iter()
+        .filter_map(|m| m.get("modId")?.as_str().map(String::from))
+        .collect();
+    let Some(primary) = entries.first() else {
+        return Ok(None);
+    };
+    let Some(mod_id) = ids.first().cloned() else {
+        return Ok(None);
+    };
+
+    let mut version = primary
+        .get("version")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+    if version.contains("${") {
+        version = manifest_version(archive).unwrap_or_default();
+    }
+    let name = primary
+        .get("displayName")
+        .and_then(|v| v.as_str())
+        .unwrap_or(&mod_id)
+        .to_string();
+
+    let mut dependencies = Vec::new();
+    let mut optional_dependencies = Vec::new();
+    if let Some(deps) = doc.get("dependencies").and_then(|d| d.as_table()) {
+        for id in &ids {
+            let Some(list) = deps.get(id).and_then(|l| l.as_array()) else {
+                continue;
+            };
+            for dep in list {
+                let Some(dep_id) = dep.get("modId").and_then(|v| v.as_str()) else {
+                    continue;
+                };
+                if is_builtin(dep_id) || ids.iter().any(|i| i == dep_id) {
+                    continue;
+                }
+                // Forge uses `mandatory`, NeoForge uses `type = "required"`
+                let (required, optional) = match dep.get("type").and_then(|v| v.as_str()) {
+                    Some(kind) => (
+                        kind.eq_ignore_ascii_case("required"),
+                        kind.eq_ignore_ascii_case("optional"),
+                    ),
+                    None => {
+                        let mandatory = dep
+                            .get("mandatory")
+                            .and_then(|v| v.as_bool())
+                            .unwrap_or(false);
+                        (mandatory, !mandatory)
+                    }
+                };
+                let client_only = dep
+                    .get("side")
+                    .and_then(|v| v.as_str())
+                    .is_some_and(|s| s.eq_ignore_ascii_case("CLIENT"));
+                if optional && !client_only {
+                    optional_dependencies.push(dep_id.to_string());
+                }
+                if !required || client_only {
+                    continue;
+                }
+                dependencies.push(ModDependency {
+                    mod_id: dep_id.to_string(),
+                    version_req: dep
+                        .get("versionRange")
+                        .and_then(|v| v.as_str())
+                        .map(String::from),
+                });
+            }
+        }
+    }
+
+    let library = looks_like_library(&mod_id);
+    Ok(Some(ModInfo {
+        file_name,
+        mod_id,
+        name,
+        version,
+        provides: ids.into_iter().skip(1).collect(),
+        dependencies,
+        optional_dependencies,
+        library,
+    }))
+}
+
+fn parse_fabric_json(text: &str, file_name: String) -> Result<Option<ModInfo>> {
+    let doc: serde_json::Value = serde_json::from_str(text).context("Invalid fabric.mod.json")?;
+    let Some(mod_id) = doc.get("id").and_then(|v| v.as_str()).map(String::from) else {
+        return Ok(None);
+    };
+
+    let version = doc
+        .get("version")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let name = doc
+        .get("name")
+        .and_then(|v| v.as_str())
+        .unwrap_or(&mod_id)
+        .to_string();
+    let provides = doc
+        .get("provides")
+        .and_then(|v| v.as_array())
+        .map(|a| {
+            a.iter()
+                .filter_map(|p| p.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut dependencies = Vec::new();
+    if let Some(depends) = doc.get("depends").and_then(|d| d.as_object()) {
+        for (dep_id, req) in depends {
+            if is_builtin(dep_id) {
+                continue;
+            }
+            // A list of predicates means "any of these"; keep them joined with ||
+            let version_req = match req {
+                serde_json::Value::String(s) => Some(s.clone()),
+                serde_json::Value::Array(a) => Some(
+                    a.iter()
+                        .filter_map(|v| v.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" || "),
+                ),
+                _ => None,
+            };
+            dependencies.push(ModDependency {
+                mod_id: dep_id.clone(),
+                version_req,
+            });
+        }
+    }
+
+    let optional_dependencies = ["recommends", "suggests"]
+        .iter()
+        .filter_map(|key| doc.get(key).and_then(|d| d.as_object()))
+        .flat_map(|deps| deps.keys())
+        .filter(|id| !is_builtin(id))
+        .cloned()
+        .collect();
+
+    let badged_library = doc
+        .pointer("/custom/modmenu/badges")
+        .and_then(|b| b.as_array())
+        .is_some_and(|b| b.iter().any(|v| v.as_str() == Some("library")));
+    let library = badged_library || looks_like_library(&mod_id);
+
+    Ok(Some(ModInfo {
+        file_name,
+        mod_id,
+        name,
+        version,
+        provides,
+        dependencies,
+        optional_dependencies,
+        library,
+    }))
+}
+
+fn is_builtin(mod_id: &str) -> bool {
+    BUILTIN_IDS.contains(&mod_id)
+}
+
+fn looks_like_library(mod_id: &str) -> bool {
+    let id = mod_id.to_ascii_lowercase();
+    KNOWN_LIBRARIES.contains(&id.as_str())
+        || id.ends_with("lib")
+        || id.ends_with("api")
+        || id.contains("library")
+}
+
+// ── Analysis ───────────────────────────────────────────────────────────────
+
+fn analyze(mods: Vec<ModInfo>, unreadable: Vec<String>) -> ModGraph {
+    let mut graph = ModGraph {
+        mods,
+        issues: Vec::new(),
+        unreadable,
+        sizes: HashMap::new(),
+    };
+
+    // Group requirements by dependency ID so each problem is listed once
+    let mut seen: HashSet<String> = HashSet::new();
+    for dep_id in graph
+        .mods
+        .iter()
+        .flat_map(|m| m.dependencies.iter().map(|d| d.mod_id.clone()))
+        .collect::<Vec<_>>()
+    {
+        if !seen.insert(dep_id.clone()) {
+            continue;
+        }
+        let requirers: Vec<(&ModInfo, &ModDependency)> = graph
+            .mods
+            .iter()
+            .filter_map(|m| {
+                m.dependencies
+                    .iter()
+                    .find(|d| d.mod_id == dep_id)
+                    .map(|d| (m, d))
+            })
+            .collect();
+        let required_by: Vec<String> = requirers.iter().map(|(m, _)| m.name.clone()).collect();
+
+        match graph.find(&dep_id) {
+            None => graph.issues.push(DependencyIssue::Missing {
+                mod_id: dep_id.clone(),
+                version_req: requirers.iter().find_map(|(_, d)| d.version_req.clone()),
+                required_by,
+            }),
+            Some(installed) => {
+                // Nested/provided IDs don't carry their own version, so only check the primary
+                if installed.mod_id != dep_id || installed.version.is_empty() {
+                    continue;
+                }
+                let unmet = requirers.iter().find_map(|(_, d)| {
+                    d.version_req
+                        .as_deref()
+                        .filter(|req| !version_satisfies(&installed.version, req))
+                });
+                if let Some(req) = unmet {
+                    graph.issues.push(DependencyIssue::Outdated {
+                        mod_id: dep_id.clone(),
+                        installed: installed.version.clone(),
+                        version_req: req.to_string(),
+                        required_by,
+                    });
+                }
+            }
+        }
+    }
+
+    // A library is unused if everything depending on it, even optionally, is itself an
+    // unused library, so keep marking until nothing changes (catches library-only chains)
+    let mut orphaned: HashSet<String> = HashSet::new();
+    loop {
+        let newly: Vec<String> = graph
+            .mods
+            .iter()
+            .filter(|m| m.library && !orphaned.contains(&m.file_name))
+            .filter(|m| {
+                graph
+                    .users(m)
+                    .iter()
+                    .all(|d| orphaned.contains(&d.file_name))
+            })
+            .map(|m| m.file_name.clone())
+            .collect();
+        if newly.is_empty() {
+            break;
+        }
+        orphaned.extend(newly);
+    }
+    let orphans: Vec<DependencyIssue> = graph
+        .mods
+        .iter()
+        .filter(|m| orphaned.contains(&m.file_name))
+        .map(|m| DependencyIssue::Orphaned {
+            mod_id: m.mod_id.clone(),
+            file_name: m.file_name.clone(),
+        })
+        .collect();
+    graph.issues.extend(orphans);
+
+    graph
+}
+
+// ── Version matching ───────────────────────────────────────────────────────
+
+/// Compare dotted versions component by component (numeric where both sides are numbers)
+fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    let split = |v: &str| -> Vec<String> {
+        v.split(['.', '-', '+', '_'])
+            .filter(|s| !s.is_empty())
+            .map(String::from)
+            .collect()
+    };
+    let (a, b) = (split(a), split(b));
+    for (x, y) in a.iter().zip(b.iter()) {
+        let ord = match (x.parse::<u64>(), y.parse::<u64>()) {
+            (Ok(x), Ok(y)) => x.cmp(&y),
+            _ => x.cmp(y),
+        };
+        if ord != std::cmp::Ordering::Equal {
+            return ord;
+        }
+    }
+    a.len().cmp(&b.len())
+}
+
+/// Check a version against a Forge Maven range (`[1.2,2.0)`) or Fabric predicates
+/// (`>=1.2 <2`, `^1.2`, `~1.2`, `*`, alternatives joined with `||`).
+/// Anything unparseable counts as satisfied so we never invent problems.
+pub fn version_satisfies(version: &str, req: &str) -> bool {
+    use std::cmp::Ordering::*;
+    let req = req.trim();
+    if req.is_empty() || req == "*" || req.contains("${") {
+        return true;
+    }
+
+    if req.starts_with('[') || req.starts_with('(') {
+        let inclusive_low = req.starts_with('[');
+        let inclusive_high = req.ends_with(']');
+        let inner = &req[1..req.len().saturating_sub(1)];
+        let (low, high) = match inner.split_once(',') {
+            Some((l, h)) => (l.trim(), h.trim()),
+            // [1.0] means exactly 1.0
+            None => (inner.trim(), inner.trim()),
+        };
+        if !low.is_empty() {
+            match compare_versions(version, low) {
+                Less => return false,
+                Equal if !inclusive_low => return false,
+                _ => {}
+            }
+        }
+        if !high.is_empty() {
+            match compare_versions(version, high) {
+                Greater => return false,
+                Equal if !inclusive_high => return false,
+                _ => {}
+            }
+        }
+        return true;
+    }
+
+    req.split("||").any(|alt| {
+        alt.split_whitespace().all(|pred| {
+            let (op, target) = match pred.find(|c: char| c.is_ascii_alphanumeric()) {
+                Some(i) => pred.split_at(i),
+                None => return true,
+            };
+            let target = target.trim_end_matches(".x").trim_end_matches(".*");
+            let ord = compare_versions(version, target);
+            let leading = |n: usize| -> Vec<&str> { target.split('.').take(n).collect() };
+            let same_prefix = |n: usize| {
+                let want = leading(n);
+                version.split('.').take(want.len()).eq(want.iter().copied())
+            };
+            match op {
+                ">=" => ord != Less,
+                ">" => ord == Greater,
+                "<=" => ord != Greater,
+                "<" => ord == Less,
+                "^" => ord != Less && same_prefix(1),
+                "~" => ord != Less && same_prefix(2),
+                "=" | "" => ord == Equal || same_prefix(target.split('.').count()),
+                _ => true,
+            }
+        })
+    })
+}
+
+// ── Adding and removing jars ───────────────────────────────────────────────
+
+/// Copy jars from anywhere on this machine into `mods_dir`, replacing any with
+/// the same name. Anything that isn't a jar is skipped. Returns the names copied.
+pub fn add_mods(mods_dir: &Path, paths: &[PathBuf]) -> Result<Vec<String>> {
+    std::fs::create_dir_all(mods_dir)
+        .with_context(|| format!("Failed to create {}", mods_dir.display()))?;
+
+    let mut added = Vec::new();
+    for path in paths {
+        let is_jar = path
+            .extension()
+            .is_some_and(|e| e.eq_ignore_ascii_case("jar"));
+        let Some(name) = path.file_name().filter(|_| is_jar && path.is_file()) else {
+            tracing::warn!("Skipping {}: not a jar", path.display());
+            continue;
+        };
+        let name = name.to_string_lossy().to_string();
+        std::fs::copy(path, mods_dir.join(&name))
+            .with_context(|| format!("Failed to copy {}", name))?;
+        tracing::info!("Added {} to {}", name, mods_dir.display());
+        added.push(name);
+    }
+    Ok(added)
+}
+
+/// Move jars out of `mods_dir` into a timestamped folder under `archive_dir`, so a
+/// wrongly-flagged library can be put back by hand. Returns how many were moved.
+pub fn remove_mods(mods_dir: &Path, archive_dir: &Path, file_names: &[String]) -> Result<usize> {
+    let dest_dir = archive_dir.join(chrono::Local::now().format("%Y%m%d_%H%M%S").to_string());
+    std::fs::create_dir_all(&dest_dir)
+        .with_context(|| format!("Failed to create {}", dest_dir.display()))?;
+
+    let mut moved = 0;
+    for name in file_names {
+        // Only plain file names; never follow anything out of the mods folder
+        if name.contains(['/', '\\']) || name.starts_with('.') {
+            continue;
+        }
+        let src = mods_dir.join(name);
+        if !src.is_file() {
+            continue;
+        }
+        std::fs::rename(&src, dest_dir.join(name))
+            .with_context(|| format!("Failed to move {}", name))?;
+        tracing::info!("Moved {} to {}", name, dest_dir.display());
+        moved += 1;
+    }
+    Ok(moved)
+}
+
+// ── Installing missing dependencies ────────────────────────────────────────
+
+/// Find a Modrinth release of `mod_id` for this loader and MC version and drop it in `mods_dir`.
+/// Mod IDs usually match the Modrinth slug, sometimes with `_` in place of `-`.
+pub async fn install_from_modrinth(
+    token: Option<&str>,
+    mods_dir: &Path,
+    mod_id: &str,
+    loader: &ModLoader,
+    minecraft_version: &str,
+) -> Result<String> {
+    let loader_name =
+        crate::modrinth::loader_name(loader).context("Vanilla servers don't load mods")?;
+
+    let mut candidates = vec![mod_id.to_string()];
+    if mod_id.contains('_') {
+        candidates.push(mod_id.replace('_', "-"));
+    }
+
+    let mut versions = Vec::new();
+    for slug in &candidates {
+        if let Ok(found) = crate::modrinth::get_project_versions(token, slug).await {
+            versions = found;
+            break;
+        }
+    }
+    if versions.is_empty() {
+        anyhow::bail!("'{}' was not found on Modrinth", mod_id);
+    }
+
+    // Versions come back newest first
+    let version = versions
+        .iter()
+        .find(|v| {
+            v.loaders.iter().any(|l| l == loader_name)
+                && v.game_versions.iter().any(|g| g == minecraft_version)
+        })
+        .with_context(|| {
+            format!(
+                "No {} build of '{}' for Minecraft {} on Modrinth",
+                loader_name, mod_id, minecraft_version
+            )
+        })?;
+    let file = version
+        .files
+        .iter()
+        .find(|f| f.primary)
+        .or_else(|| version.files.first())
+        .context("Modrinth version has no files")?;
+
+    let resp = crate::http::client()
+        .get(&file.url)
+        .send()
+        .await?
+        .error_for_status()?;
+    let bytes = resp.bytes().await?;
+
+    std::fs::create_dir_all(mods_dir)?;
+    let dest = mods_dir.join(&file.filename);
+    let part = dest.with_extension("jar.part");
+    std::fs::write(&part, &bytes)?;
+    std::fs::rename(&part, &dest)?;
+
+    tracing::info!("Installed {} into {}", file.filename, mods_dir.display());
+    Ok(file.filename.clone())
+}
@@ -0,0 +1,89 @@
+//! Getting a message to the user outside the app window: desktop notifications
+//! through the system's own notifier, and chat webhooks.
+
+use anyhow::{bail, Context, Result};
+use std::process::{Command, Stdio};
+
+/// Show a desktop notification. Uses `notify-send` on Linux, AppleScript on
+/// macOS and a PowerShell toast on Windows; returns once the notifier started.
+pub fn desktop(title: &str, body: &str) -> Result<()> {
+    let mut command = desktop_command(title, body);
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .context("Couldn't run the system notifier")?;
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn desktop_command(title: &str, body: &str) -> Command {
+    let mut command = Command::new("notify-send");
+    command.args(["--app-name=DrakonixAnvil", title, body]);
+    command
+}
+
+#[cfg(target_os = "macos")]
+fn desktop_command(title: &str, body: &str) -> Command {
+    let quote = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+    let mut command = Command::new("osascript");
+    command.args([
+        "-e",
+        &format!(
+            "display notification \"{}\" with title \"{}\"",
+            quote(body),
+            quote(title)
+        ),
+    ]);
+    command
+}
+
+#[cfg(target_os = "windows")]
+fn desktop_command(title: &str, body: &str) -> Command {
+    // Text goes through environment variables so nothing needs escaping
+    const SCRIPT: &str = "\
+        [Windows.UI.Notifications.ToastNotificationManager, Windows.UI.Notifications, ContentType = WindowsRuntime] > $null; \
+        $xml = [Windows.UI.Notifications.ToastNotificationManager]::GetTemplateContent([Windows.UI.Notifications.ToastTemplateType]::ToastText02); \
+        $text = $xml.GetElementsByTagName('text'); \
+        $text.Item(0).AppendChild($xml.CreateTextNode($env:DRAKONIX_TITLE)) > $null; \
+        $text.Item(1).AppendChild($xml.CreateTextNode($env:DRAKONIX_BODY)) > $null; \
+        $toast = [Windows.UI.Notifications.ToastNotification]::new($xml); \
+        [Windows.UI.Notifications.ToastNotificationManager]::CreateToastNotifier('DrakonixAnvil').Show($toast)";
+    let mut command = Command::new("powershell");
+    command
+        .args(["-NoProfile", "-WindowStyle", "Hidden", "-Command", SCRIPT])
+        .env("DRAKONIX_TITLE", title)
+        .env("DRAKONIX_BODY", body);
+    command
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn desktop_command(_title: &str, body: &str) -> Command {
+    let mut command = Command::new("echo");
+    command.arg(body);
+    command
+}
+
+/// Post `text` to a chat webhook. Discord reads `content` and Slack (and most
+/// Slack-compatible services) read `text`, so both are sent.
+pub async fn webhook(url: &str, text: &str) -> Result<()> {
+    post_json(url, &serde_json::json!({ "content": text, "text": text })).await
+}
+
+/// Post a JSON body to a webhook
+pub async fn post_json(url: &str, body: &serde_json::Value) -> Result<()> {
+    let url = url.trim();
+    if !url.starts_with("https://") && !url.starts_with("http://") {
+        bail!("Webhook URL must start with https://");
+    }
+    crate::http::client()
+        .post(url)
+        .json(body)
+        .send()
+        .await
+        .context("Failed to reach the webhook")?
+        .error_for_status()
+        .context("Webhook refused the message")?;
+    Ok(())
+}
@@ -1,73 +1,1130 @@
+use crate::curseforge::{self, CfFile};
+use crate::local_pack;
+use crate::server::ModLoader;
 use anyhow::{Context, Result};
-use std::io::Read;
-use std::path::Path;
+use serde::Deserialize;
+use sha1::{Digest, Sha1};
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Sender;
+use std::time::{Duration, Instant};
+
+/// How many attempts in a row may fail without receiving any new bytes before the
+/// download gives up. Attempts that got further don't count, so a long download on
+/// a flaky CDN keeps resuming as long as it's moving.
+const MAX_DOWNLOAD_ATTEMPTS: u32 = 5;
+
+/// Longest wait between attempts
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// A connection that delivers nothing for this long is dropped and resumed
+const STALL_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Minimum bytes between progress updates, so the UI channel isn't flooded
+const PROGRESS_INTERVAL_BYTES: u64 = 512 * 1024;
+
+/// Minimum time between extraction progress updates
+const EXTRACT_PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How many times a download that fails its checksum or won't open as a zip is
+/// fetched again from scratch
+const MAX_CORRUPT_RETRIES: u32 = 2;
+
+/// Written into the data directory once a pack is extracted
+const PACK_MARKER: &str = ".pack_installed";
+
+/// Whether a server pack has already been installed into `data_path`
+pub fn is_pack_installed(data_path: &Path) -> bool {
+    data_path.join(PACK_MARKER).exists()
+}
+
+/// Progress update for a pack download
+#[derive(Debug, Clone)]
+pub struct DownloadProgress {
+    pub downloaded: u64,
+    /// Total size if the server reported a Content-Length
+    pub total: Option<u64>,
+}
+
+/// What a pack install reports while it runs
+#[derive(Debug, Clone)]
+pub enum PackProgress {
+    Download(DownloadProgress),
+    /// Downloading the mods listed in a pack manifest
+    Mods {
+        current: usize,
+        total: usize,
+        file: String,
+    },
+    /// Unpacking the zip into the data directory
+    Extracting {
+        current: usize,
+        total: usize,
+        file: String,
+    },
+    /// Something the install is recovering from, e.g. a corrupt download being
+    /// fetched again
+    Notice(String),
+}
+
+/// Options controlling how a server pack is fetched
+#[derive(Debug, Clone, Default)]
+pub struct PackDownloadOptions {
+    /// Expected SHA-1 of the pack zip (hex). Download is rejected on mismatch.
+    pub sha1: Option<String>,
+    /// Bandwidth cap in KiB/s (0 = unlimited)
+    pub limit_kbps: u32,
+    /// Folder where finished downloads are kept for reuse by other servers and
+    /// reinstalls. `None` downloads every time.
+    pub cache_dir: Option<PathBuf>,
+}
+
+/// Cached copy of a pack, named after its URL and expected SHA-1. Packs without a
+/// known checksum are keyed by URL alone, which suits CDN links that never change.
+fn cached_pack_path(cache_dir: &Path, pack_url: &str, sha1: Option<&str>) -> PathBuf {
+    let mut hasher = Sha256::new();
+    hasher.update(pack_url.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(sha1.unwrap_or_default().trim().to_lowercase().as_bytes());
+    let key = hex::encode(hasher.finalize());
+    cache_dir.join(format!("{}.zip", &key[..32]))
+}
+
+/// Total size of the packs in the download cache
+pub fn cache_size(cache_dir: &Path) -> u64 {
+    fs::read_dir(cache_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|e| e.metadata().ok())
+                .filter(|m| m.is_file())
+                .map(|m| m.len())
+                .sum()
+        })
+        .unwrap_or(0)
+}
+
+/// Delete every cached pack; returns the bytes freed
+pub fn clear_cache(cache_dir: &Path) -> Result<u64> {
+    let size = cache_size(cache_dir);
+    if cache_dir.exists() {
+        fs::remove_dir_all(cache_dir).context("Failed to clear the download cache")?;
+    }
+    Ok(size)
+}
+
+/// Partial download lives next to the data dir so it survives restarts but isn't backed up
+fn partial_path(data_path: &Path) -> PathBuf {
+    data_path.with_file_name("server-pack.zip.part")
+}
+
+/// Records which URL the partial file belongs to, so a changed pack URL never resumes stale bytes
+fn partial_url_path(data_path: &Path) -> PathBuf {
+    data_path.with_file_name("server-pack.zip.url")
+}
+
+/// ETag or Last-Modified of the file a partial download came from. Resuming sends it
+/// as `If-Range`, so if the file changed upstream the server sends it whole instead
+/// of bytes that don't fit onto the old ones.
+fn validator_path(dest: &Path) -> PathBuf {
+    dest.with_extension("validator")
+}
+
+/// An HTTP error response, kept apart from network errors so permanent failures
+/// aren't retried
+#[derive(Debug)]
+struct HttpStatusError {
+    status: reqwest::StatusCode,
+    /// From a `Retry-After` header given in seconds
+    retry_after: Option<Duration>,
+}
+
+impl std::fmt::Display for HttpStatusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "HTTP {}", self.status)
+    }
+}
+
+impl std::error::Error for HttpStatusError {}
+
+impl HttpStatusError {
+    /// Client errors won't change on a retry, apart from timeouts and rate limits
+    fn is_permanent(&self) -> bool {
+        self.status.is_client_error()
+            && self.status != reqwest::StatusCode::REQUEST_TIMEOUT
+            && self.status != reqwest::StatusCode::TOO_MANY_REQUESTS
+    }
+}
 
 /// Download a modpack zip from a URL and extract it into the server's data directory.
 /// Skips extraction if a marker file exists (pack already installed).
-pub async fn install_forge_pack(data_path: &Path, pack_url: &str) -> Result<()> {
-    let marker = data_path.join(".pack_installed");
-    if marker.exists() {
+/// Interrupted downloads are resumed with HTTP Range requests on the next attempt.
+/// With a cache folder set, a pack that was downloaded before is extracted from there.
+pub async fn install_forge_pack(
+    data_path: &Path,
+    pack_url: &str,
+    options: &PackDownloadOptions,
+    progress_tx: Option<Sender<PackProgress>>,
+) -> Result<()> {
+    if is_pack_installed(data_path) {
         tracing::info!("Pack already installed (marker exists), skipping download");
         return Ok(());
     }
 
-    tracing::info!("Downloading server pack from {}...", pack_url);
+    let (zip_path, digest) = fetch_pack(data_path, pack_url, options, progress_tx.as_ref()).await?;
+    extract_pack(data_path, &zip_path, "", progress_tx.as_ref())?;
+    write_marker(data_path, pack_url, &digest, None);
+    remove_partial(data_path);
+    Ok(())
+}
 
-    let response = reqwest::get(pack_url)
+/// Install a CurseForge pack's server pack on the host, unwrapping the folder
+/// most server packs put everything in. Returns the loader found in the pack's
+/// libraries or Forge jar; `None` leaves the server's own loader settings in
+/// charge. An already installed pack just reports what was found before.
+pub async fn install_cf_server_pack(
+    data_path: &Path,
+    api_key: &str,
+    server_pack_file_id: u64,
+    options: &PackDownloadOptions,
+    progress_tx: Option<Sender<PackProgress>>,
+) -> Result<Option<PackLoader>> {
+    if is_pack_installed(data_path) {
+        tracing::info!("Pack already installed (marker exists), skipping download");
+        return Ok(installed_loader(data_path));
+    }
+
+    let pack = curseforge::get_file(api_key, server_pack_file_id)
         .await
-        .context("Failed to download server pack")?;
+        .context("Failed to look up the server pack file")?;
+    let pack_url = pack.url();
+    let options = PackDownloadOptions {
+        sha1: pack.sha1().map(str::to_string),
+        ..options.clone()
+    };
+    let (zip_path, digest) =
+        fetch_pack(data_path, &pack_url, &options, progress_tx.as_ref()).await?;
+    let root = {
+        let file = File::open(&zip_path).context("Failed to open downloaded server pack")?;
+        let archive = zip::ZipArchive::new(file).context("Failed to open server pack as zip")?;
+        let names: Vec<String> = archive.file_names().map(String::from).collect();
+        local_pack::single_root(&names)
+    };
+    extract_pack(data_path, &zip_path, &root, progress_tx.as_ref())?;
+    remove_partial(data_path);
+
+    let loader = local_pack::list_pack(data_path, "server pack")
+        .ok()
+        .and_then(|listing| {
+            let loader = local_pack::detect_loader(&listing)?;
+            Some(PackLoader {
+                minecraft_version: local_pack::detect_minecraft_version(&listing)?,
+                loader_version: local_pack::detect_loader_version(&listing, &loader)?,
+                loader,
+            })
+        });
+    write_marker(data_path, &pack_url, &digest, loader.as_ref());
+    Ok(loader)
+}
 
-    if !response.status().is_success() {
-        anyhow::bail!("Failed to download server pack: HTTP {}", response.status());
+/// Mod loader and Minecraft version a CurseForge pack manifest asks for
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackLoader {
+    pub minecraft_version: String,
+    pub loader: ModLoader,
+    pub loader_version: String,
+}
+
+impl PackLoader {
+    /// From a manifest loader ID such as `forge-47.2.0` or `fabric-0.15.7`
+    fn parse(minecraft_version: &str, id: &str) -> Result<Self> {
+        let (name, version) = id
+            .split_once('-')
+            .with_context(|| format!("Unrecognized mod loader '{}'", id))?;
+        let loader = match name {
+            "forge" => ModLoader::Forge,
+            "neoforge" => ModLoader::NeoForge,
+            "fabric" => ModLoader::Fabric,
+            _ => anyhow::bail!("Mod loader '{}' isn't supported", name),
+        };
+        Ok(Self {
+            minecraft_version: minecraft_version.to_string(),
+            loader,
+            loader_version: version.to_string(),
+        })
     }
 
-    let bytes = response
-        .bytes()
+    /// Manifest loader ID, e.g. `forge-47.2.0`
+    pub fn id(&self) -> String {
+        let name = match self.loader {
+            ModLoader::Forge => "forge",
+            ModLoader::NeoForge => "neoforge",
+            ModLoader::Fabric => "fabric",
+            ModLoader::Vanilla => "vanilla",
+        };
+        format!("{}-{}", name, self.loader_version)
+    }
+
+    /// Container env that installs this loader, replacing the TYPE and VERSION
+    /// taken from the server config
+    pub fn docker_env(&self) -> Vec<String> {
+        let (type_str, version_var) = match self.loader {
+            ModLoader::Forge => ("FORGE", Some("FORGE_VERSION")),
+            ModLoader::NeoForge => ("NEOFORGE", Some("NEOFORGE_VERSION")),
+            ModLoader::Fabric => ("FABRIC", Some("FABRIC_LOADER_VERSION")),
+            ModLoader::Vanilla => ("VANILLA", None),
+        };
+        let mut env = vec![
+            format!("TYPE={}", type_str),
+            format!("VERSION={}", self.minecraft_version),
+        ];
+        if let Some(var) = version_var {
+            env.push(format!("{}={}", var, self.loader_version));
+        }
+        env
+    }
+}
+
+/// `manifest.json` at the root of a CurseForge client pack
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CfManifest {
+    minecraft: CfManifestMinecraft,
+    files: Vec<CfManifestFile>,
+    #[serde(default = "default_overrides")]
+    overrides: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CfManifestMinecraft {
+    version: String,
+    mod_loaders: Vec<CfManifestLoader>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CfManifestLoader {
+    id: String,
+    #[serde(default)]
+    primary: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct CfManifestFile {
+    #[serde(rename = "fileID")]
+    file_id: u64,
+    #[serde(default = "default_required")]
+    required: bool,
+}
+
+fn default_overrides() -> String {
+    "overrides".to_string()
+}
+
+fn default_required() -> bool {
+    true
+}
+
+/// Mods that only work in the game client but aren't always tagged that way on
+/// CurseForge, matched against the start of the jar name
+const CLIENT_ONLY_MODS: &[&str] = &[
+    "3dskinlayers",
+    "betterf3",
+    "ding-",
+    "drippyloadingscreen",
+    "embeddium",
+    "entityculling",
+    "fancymenu",
+    "iris-",
+    "legendarytooltips",
+    "notenoughanimations",
+    "oculus",
+    "optifine",
+    "rubidium",
+    "skinlayers3d",
+    "sodium-",
+    "toastcontrol",
+];
+
+fn is_client_only(file: &CfFile) -> bool {
+    let name = file.file_name.to_lowercase();
+    file.is_client_only() || CLIENT_ONLY_MODS.iter().any(|m| name.starts_with(m))
+}
+
+/// Install a CurseForge pack on the host from its client zip: every mod in the
+/// manifest is downloaded into `mods/` (client-only ones left out) and the
+/// overrides are laid over the data directory. For packs AUTO_CURSEFORGE can't
+/// install. Returns the loader the container has to install; an already
+/// installed pack just reports its loader again.
+pub async fn install_cf_manifest(
+    data_path: &Path,
+    api_key: &str,
+    pack_file_id: u64,
+    options: &PackDownloadOptions,
+    progress_tx: Option<Sender<PackProgress>>,
+) -> Result<PackLoader> {
+    if let Some(loader) = installed_loader(data_path) {
+        tracing::info!("Pack already installed (marker exists), skipping download");
+        return Ok(loader);
+    }
+
+    let pack = curseforge::get_file(api_key, pack_file_id)
         .await
-        .context("Failed to read server pack response body")?;
+        .context("Failed to look up the modpack file")?;
+    let pack_url = pack.url();
+    let options = PackDownloadOptions {
+        sha1: pack.sha1().map(str::to_string),
+        ..options.clone()
+    };
+    let (zip_path, digest) =
+        fetch_pack(data_path, &pack_url, &options, progress_tx.as_ref()).await?;
+    let loader = install_cf_client_pack(
+        data_path,
+        api_key,
+        &zip_path,
+        &pack_url,
+        &digest,
+        &options,
+        progress_tx,
+    )
+    .await?;
+    remove_partial(data_path);
+    Ok(loader)
+}
 
-    tracing::info!(
-        "Downloaded {} bytes, extracting to {}...",
-        bytes.len(),
-        data_path.display()
+/// Install a CurseForge client zip that's already on this machine, e.g. one
+/// imported from a file. A relative `path` is inside the server's data folder.
+pub async fn install_cf_client_zip(
+    data_path: &Path,
+    api_key: &str,
+    path: &str,
+    options: &PackDownloadOptions,
+    progress_tx: Option<Sender<PackProgress>>,
+) -> Result<PackLoader> {
+    if let Some(loader) = installed_loader(data_path) {
+        tracing::info!("Pack already installed (marker exists), skipping install");
+        return Ok(loader);
+    }
+
+    let zip_path = data_path.join(path);
+    let digest = sha1_file(&zip_path).with_context(|| format!("Failed to read {}", path))?;
+    install_cf_client_pack(
+        data_path,
+        api_key,
+        &zip_path,
+        path,
+        &digest,
+        options,
+        progress_tx,
+    )
+    .await
+}
+
+/// Download the mods a CurseForge client zip lists and extract its overrides.
+/// `source` and `digest` are recorded in the marker.
+async fn install_cf_client_pack(
+    data_path: &Path,
+    api_key: &str,
+    zip_path: &Path,
+    source: &str,
+    digest: &str,
+    options: &PackDownloadOptions,
+    progress_tx: Option<Sender<PackProgress>>,
+) -> Result<PackLoader> {
+    let manifest: CfManifest = {
+        let file = File::open(zip_path).context("Failed to open the modpack")?;
+        let mut archive = zip::ZipArchive::new(file)?;
+        let entry = archive
+            .by_name("manifest.json")
+            .context("The modpack has no manifest.json; is it a client pack?")?;
+        serde_json::from_reader(entry).context("Failed to read the modpack's manifest.json")?
+    };
+    let primary = manifest
+        .minecraft
+        .mod_loaders
+        .iter()
+        .find(|l| l.primary)
+        .or(manifest.minecraft.mod_loaders.first())
+        .context("The modpack manifest names no mod loader")?;
+    let loader = PackLoader::parse(&manifest.minecraft.version, &primary.id)?;
+
+    let ids: Vec<u64> = manifest
+        .files
+        .iter()
+        .filter(|f| f.required)
+        .map(|f| f.file_id)
+        .collect();
+    let files = curseforge::get_files(api_key, &ids)
+        .await
+        .context("Failed to look up the modpack's mods")?;
+    let missing: Vec<String> = ids
+        .iter()
+        .filter(|id| !files.iter().any(|f| f.id == **id))
+        .map(u64::to_string)
+        .collect();
+    if !missing.is_empty() {
+        anyhow::bail!(
+            "CurseForge no longer has these mod files: {}",
+            missing.join(", ")
+        );
+    }
+
+    let mods_dir = data_path.join("mods");
+    fs::create_dir_all(&mods_dir).context("Failed to create mods folder")?;
+    let mut left_out = Vec::new();
+    for (i, file) in files.iter().enumerate() {
+        if let Some(tx) = &progress_tx {
+            tx.send(PackProgress::Mods {
+                current: i + 1,
+                total: files.len(),
+                file: file.file_name.clone(),
+            })
+            .ok();
+        }
+        // Resource packs and shaders in a manifest only matter to the client
+        if !file.file_name.ends_with(".jar") || is_client_only(file) {
+            left_out.push(file.file_name.clone());
+            continue;
+        }
+        if Path::new(&file.file_name).file_name() != Some(file.file_name.as_ref()) {
+            tracing::warn!("Skipping mod with unsafe file name: {:?}", file.file_name);
+            continue;
+        }
+        install_file(
+            &[file.url()],
+            &mods_dir.join(&file.file_name),
+            file.sha1(),
+            options.limit_kbps,
+        )
+        .await?;
+    }
+
+    let overrides = format!("{}/", manifest.overrides.trim_end_matches('/'));
+    extract_pack(data_path, zip_path, &overrides, progress_tx.as_ref())?;
+    write_marker(data_path, source, digest, Some(&loader));
+    report_left_out(&left_out, progress_tx.as_ref());
+    Ok(loader)
+}
+
+/// `modrinth.index.json` at the root of a `.mrpack`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MrIndex {
+    name: String,
+    #[serde(default)]
+    version_id: String,
+    files: Vec<MrIndexFile>,
+    dependencies: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MrIndexFile {
+    path: String,
+    hashes: MrIndexHashes,
+    #[serde(default)]
+    env: Option<MrIndexEnv>,
+    downloads: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MrIndexHashes {
+    sha1: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct MrIndexEnv {
+    /// `required`, `optional` or `unsupported`
+    server: String,
+}
+
+impl MrIndex {
+    fn loader(&self) -> Result<PackLoader> {
+        let minecraft = self
+            .dependencies
+            .get("minecraft")
+            .context("The .mrpack names no Minecraft version")?;
+        let (loader, version) = [
+            ("neoforge", ModLoader::NeoForge),
+            ("forge", ModLoader::Forge),
+            ("fabric-loader", ModLoader::Fabric),
+        ]
+        .into_iter()
+        .find_map(|(key, loader)| Some((loader, self.dependencies.get(key)?)))
+        .context("The .mrpack uses a mod loader that isn't supported")?;
+        Ok(PackLoader {
+            minecraft_version: minecraft.clone(),
+            loader,
+            loader_version: version.clone(),
+        })
+    }
+}
+
+fn read_mrpack_index(path: &Path) -> Result<MrIndex> {
+    let file = File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+    let mut archive = zip::ZipArchive::new(file).context("The .mrpack is not a valid zip")?;
+    let entry = archive
+        .by_name("modrinth.index.json")
+        .context("The .mrpack has no modrinth.index.json")?;
+    serde_json::from_reader(entry).context("Failed to read the .mrpack's modrinth.index.json")
+}
+
+/// Name, version and loader of a `.mrpack` on disk, for filling in a server's
+/// modpack details
+#[derive(Debug, Clone)]
+pub struct MrPackInfo {
+    pub name: String,
+    pub version: String,
+    pub loader: PackLoader,
+}
+
+pub fn read_mrpack_info(path: &Path) -> Result<MrPackInfo> {
+    let index = read_mrpack_index(path)?;
+    Ok(MrPackInfo {
+        loader: index.loader()?,
+        name: index.name,
+        version: index.version_id,
+    })
+}
+
+/// Whether a `.mrpack` source is a download link rather than a file on this machine
+pub fn is_url(path_or_url: &str) -> bool {
+    path_or_url.starts_with("https://") || path_or_url.starts_with("http://")
+}
+
+/// Install a Modrinth `.mrpack` (a local file or a URL) on the host: every file
+/// in its index is downloaded and hash-checked, files the server doesn't support
+/// are left out, and `overrides/` then `server-overrides/` are laid over the
+/// data directory. Returns the loader the container has to install.
+pub async fn install_mrpack(
+    data_path: &Path,
+    path_or_url: &str,
+    options: &PackDownloadOptions,
+    progress_tx: Option<Sender<PackProgress>>,
+) -> Result<PackLoader> {
+    if let Some(loader) = installed_loader(data_path) {
+        tracing::info!("Pack already installed (marker exists), skipping download");
+        return Ok(loader);
+    }
+
+    let (zip_path, digest) = if is_url(path_or_url) {
+        fetch_pack(data_path, path_or_url, options, progress_tx.as_ref()).await?
+    } else {
+        // A relative path is inside the server's data folder, where imports are copied
+        let path = data_path.join(path_or_url);
+        let digest = sha1_file(&path).with_context(|| format!("Failed to read {}", path_or_url))?;
+        (path, digest)
+    };
+    let index = read_mrpack_index(&zip_path)?;
+    let loader = index.loader()?;
+
+    let mut left_out = Vec::new();
+    for (i, file) in index.files.iter().enumerate() {
+        if let Some(tx) = &progress_tx {
+            tx.send(PackProgress::Mods {
+                current: i + 1,
+                total: index.files.len(),
+                file: file.path.clone(),
+            })
+            .ok();
+        }
+        if file.env.as_ref().is_some_and(|e| e.server == "unsupported") {
+            left_out.push(file.path.clone());
+            continue;
+        }
+        let relative = Path::new(&file.path);
+        let safe = relative
+            .components()
+            .all(|c| matches!(c, std::path::Component::Normal(_)));
+        if !safe || file.path.is_empty() {
+            tracing::warn!("Skipping pack file with unsafe path: {:?}", file.path);
+            continue;
+        }
+        install_file(
+            &file.downloads,
+            &data_path.join(relative),
+            Some(&file.hashes.sha1),
+            options.limit_kbps,
+        )
+        .await?;
+    }
+
+    extract_pack(data_path, &zip_path, "overrides/", progress_tx.as_ref())?;
+    extract_pack(
+        data_path,
+        &zip_path,
+        "server-overrides/",
+        progress_tx.as_ref(),
+    )?;
+    write_marker(data_path, path_or_url, &digest, Some(&loader));
+    remove_partial(data_path);
+    report_left_out(&left_out, progress_tx.as_ref());
+    Ok(loader)
+}
+
+/// Download one file of a pack (or a single mod) into place, trying each URL in
+/// turn. A copy left by an earlier attempt that matches the checksum is kept.
+pub async fn install_file(
+    urls: &[String],
+    dest: &Path,
+    sha1: Option<&str>,
+    limit_kbps: u32,
+) -> Result<()> {
+    let name = dest
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    if dest.exists()
+        && sha1.is_some_and(|e| sha1_file(dest).is_ok_and(|d| d.eq_ignore_ascii_case(e)))
+    {
+        return Ok(());
+    }
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+
+    let part = dest.with_file_name(format!("{}.part", name));
+    let options = PackDownloadOptions {
+        sha1: sha1.map(str::to_string),
+        limit_kbps,
+        cache_dir: None,
+    };
+    let mut last_error = anyhow::anyhow!("no download URL");
+    for url in urls {
+        match download_verified(url, &part, &options, None).await {
+            Ok(_) => {
+                fs::remove_file(validator_path(&part)).ok();
+                return fs::rename(&part, dest)
+                    .with_context(|| format!("Failed to move {} into place", name));
+            }
+            Err(e) => last_error = e,
+        }
+    }
+    Err(last_error.context(format!("Failed to download {}", name)))
+}
+
+/// Tell the user which of a pack's files weren't installed because only the
+/// client uses them
+fn report_left_out(left_out: &[String], progress_tx: Option<&Sender<PackProgress>>) {
+    if left_out.is_empty() {
+        return;
+    }
+    let notice = format!(
+        "Left out {} client-only files: {}",
+        left_out.len(),
+        left_out.join(", ")
     );
+    tracing::info!("{}", notice);
+    if let Some(tx) = progress_tx {
+        tx.send(PackProgress::Notice(notice)).ok();
+    }
+}
+
+/// Loader recorded by an earlier [`install_cf_manifest`]
+pub fn installed_loader(data_path: &Path) -> Option<PackLoader> {
+    let marker = fs::read_to_string(data_path.join(PACK_MARKER)).ok()?;
+    let field = |key: &str| {
+        marker
+            .lines()
+            .find_map(|l| l.strip_prefix(key)?.strip_prefix('='))
+    };
+    PackLoader::parse(field("minecraft")?, field("loader")?).ok()
+}
+
+/// Record an installed pack so it isn't downloaded again on the next start
+fn write_marker(data_path: &Path, pack_url: &str, digest: &str, loader: Option<&PackLoader>) {
+    let mut marker = format!("{}\nsha1={}\n", pack_url, digest);
+    if let Some(loader) = loader {
+        marker.push_str(&format!(
+            "minecraft={}\nloader={}\n",
+            loader.minecraft_version,
+            loader.id()
+        ));
+    }
+    fs::write(data_path.join(PACK_MARKER), marker).ok();
+}
+
+/// Fetch a pack zip, verified, from the cache or the URL. Returns the zip's path
+/// (in the cache, or the partial download) and its SHA-1.
+async fn fetch_pack(
+    data_path: &Path,
+    pack_url: &str,
+    options: &PackDownloadOptions,
+    progress_tx: Option<&Sender<PackProgress>>,
+) -> Result<(PathBuf, String)> {
+    let part_path = partial_path(data_path);
+    let url_path = partial_url_path(data_path);
+    let cached = options
+        .cache_dir
+        .as_deref()
+        .map(|dir| cached_pack_path(dir, pack_url, options.sha1.as_deref()));
+
+    if let Some(cached) = cached.as_deref().filter(|c| c.exists()) {
+        match check_download(cached, options) {
+            Ok(digest) => {
+                tracing::info!("Using cached server pack {}", cached.display());
+                return Ok((cached.to_path_buf(), digest));
+            }
+            Err(e) => {
+                fs::remove_file(cached).ok();
+                let notice = format!("Cached pack is corrupt ({:#}), downloading again...", e);
+                tracing::warn!("{}", notice);
+                if let Some(tx) = progress_tx {
+                    tx.send(PackProgress::Notice(notice)).ok();
+                }
+            }
+        }
+    }
+
+    // Discard a partial download that belongs to a different URL
+    let previous_url = fs::read_to_string(&url_path).unwrap_or_default();
+    if part_path.exists() && previous_url.trim() != pack_url {
+        tracing::info!("Discarding partial download from a different pack URL");
+        fs::remove_file(&part_path).ok();
+        fs::remove_file(validator_path(&part_path)).ok();
+    }
+    if let Some(parent) = part_path.parent() {
+        fs::create_dir_all(parent).context("Failed to create server directory")?;
+    }
+    fs::write(&url_path, pack_url).ok();
+
+    tracing::info!("Downloading server pack from {}...", pack_url);
+    let digest = download_verified(pack_url, &part_path, options, progress_tx).await?;
+    let size = fs::metadata(&part_path).map(|m| m.len()).unwrap_or(0);
+    tracing::info!("Downloaded {} bytes", size);
+
+    // Keep the finished zip in the cache; extracting from the partial still works
+    // if it can't be moved there
+    if let Some(cached) = cached {
+        let moved = cached
+            .parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::rename(&part_path, &cached));
+        match moved {
+            Ok(()) => return Ok((cached, digest)),
+            Err(e) => tracing::warn!("Couldn't add the server pack to the download cache: {}", e),
+        }
+    }
+    Ok((part_path, digest))
+}
+
+/// Delete what's left of a pack's partial download once it's installed
+fn remove_partial(data_path: &Path) {
+    let part_path = partial_path(data_path);
+    fs::remove_file(validator_path(&part_path)).ok();
+    fs::remove_file(&part_path).ok();
+    fs::remove_file(partial_url_path(data_path)).ok();
+}
+
+/// Download `url` into `part_path` and check it. A download that fails its
+/// checksum or won't open as a zip is fetched again from scratch. Returns its SHA-1.
+async fn download_verified(
+    url: &str,
+    part_path: &Path,
+    options: &PackDownloadOptions,
+    progress_tx: Option<&Sender<PackProgress>>,
+) -> Result<String> {
+    let mut retries = 0;
+    loop {
+        download_resumable(url, part_path, options.limit_kbps, progress_tx).await?;
+
+        match check_download(part_path, options) {
+            Ok(digest) => return Ok(digest),
+            Err(e) => {
+                // Resuming a corrupt partial would only append to the bad bytes
+                fs::remove_file(part_path).ok();
+                fs::remove_file(validator_path(part_path)).ok();
+                if retries >= MAX_CORRUPT_RETRIES {
+                    return Err(e.context(format!(
+                        "Corrupt download, still bad after {} retries",
+                        retries
+                    )));
+                }
+                retries += 1;
+                let notice = format!("Corrupt download ({:#}), retrying...", e);
+                tracing::warn!("{}", notice);
+                if let Some(tx) = progress_tx {
+                    tx.send(PackProgress::Notice(notice)).ok();
+                }
+            }
+        }
+    }
+}
 
-    // Extract zip to data directory
-    let cursor = std::io::Cursor::new(bytes);
-    let mut archive = zip::ZipArchive::new(cursor).context("Failed to open server pack as zip")?;
+/// Extract a verified pack zip into the data directory. With a `prefix` such as
+/// `overrides/`, only that folder is extracted, with the prefix taken off.
+fn extract_pack(
+    data_path: &Path,
+    zip_path: &Path,
+    prefix: &str,
+    progress_tx: Option<&Sender<PackProgress>>,
+) -> Result<()> {
+    tracing::info!("Extracting server pack to {}...", data_path.display());
+    let file = File::open(zip_path).context("Failed to open downloaded server pack")?;
+    let mut archive = zip::ZipArchive::new(file).context("Failed to open server pack as zip")?;
 
-    for i in 0..archive.len() {
+    let total = archive.len();
+    let mut last_progress: Option<Instant> = None;
+    for i in 0..total {
         let mut file = archive.by_index(i)?;
+        if let Some(tx) = progress_tx {
+            if last_progress.is_none_or(|t| t.elapsed() >= EXTRACT_PROGRESS_INTERVAL)
+                || i + 1 == total
+            {
+                last_progress = Some(Instant::now());
+                tx.send(PackProgress::Extracting {
+                    current: i + 1,
+                    total,
+                    file: file.name().to_string(),
+                })
+                .ok();
+            }
+        }
         let Some(enclosed_name) = file.enclosed_name() else {
             tracing::warn!("Skipping zip entry with unsafe path: {:?}", file.name());
             continue;
         };
 
-        let out_path = data_path.join(enclosed_name);
+        let relative = match enclosed_name.strip_prefix(prefix) {
+            Ok(relative) if !relative.as_os_str().is_empty() => relative,
+            _ => continue,
+        };
+        let out_path = data_path.join(relative);
 
         if file.is_dir() {
-            std::fs::create_dir_all(&out_path)
+            fs::create_dir_all(&out_path)
                 .with_context(|| format!("Failed to create directory {}", out_path.display()))?;
         } else {
             // Ensure parent directory exists
             if let Some(parent) = out_path.parent() {
-                std::fs::create_dir_all(parent)?;
+                fs::create_dir_all(parent)?;
             }
 
-            let mut outfile = std::fs::File::create(&out_path)
+            let mut outfile = File::create(&out_path)
                 .with_context(|| format!("Failed to create file {}", out_path.display()))?;
-            let mut buf = Vec::new();
-            file.read_to_end(&mut buf)?;
-            std::io::Write::write_all(&mut outfile, &buf)?;
+            std::io::copy(&mut file, &mut outfile)
+                .with_context(|| format!("Failed to extract {}", out_path.display()))?;
         }
     }
 
-    // Write marker so we don't re-download on next start
-    std::fs::write(&marker, pack_url).ok();
-
     tracing::info!(
         "Server pack extracted successfully ({} entries)",
         archive.len()
     );
     Ok(())
 }
+
+/// Verify a finished download against the expected SHA-1. Without one, check
+/// that it at least opens as a zip. Returns its SHA-1.
+fn check_download(path: &Path, options: &PackDownloadOptions) -> Result<String> {
+    let digest = sha1_file(path)?;
+    match &options.sha1 {
+        Some(expected) => {
+            if !digest.eq_ignore_ascii_case(expected.trim()) {
+                anyhow::bail!(
+                    "checksum mismatch: expected SHA-1 {}, got {}",
+                    expected,
+                    digest
+                );
+            }
+            tracing::debug!("Checksum verified for {} ({})", path.display(), digest);
+        }
+        None => {
+            let file = File::open(path).context("Failed to open downloaded file")?;
+            zip::ZipArchive::new(file).context("not a valid zip")?;
+        }
+    }
+    Ok(digest)
+}
+
+/// Download `url` into `dest`, resuming from whatever is already on disk.
+/// Retries with exponential backoff when the connection drops or stalls
+/// mid-transfer, or the server has a temporary error.
+async fn download_resumable(
+    url: &str,
+    dest: &Path,
+    limit_kbps: u32,
+    progress_tx: Option<&Sender<PackProgress>>,
+) -> Result<()> {
+    let client = crate::http::client();
+    let on_disk = || fs::metadata(dest).map(|m| m.len()).unwrap_or(0);
+
+    let mut failures = 0;
+    loop {
+        let before = on_disk();
+        let Err(e) = download_attempt(&client, url, dest, limit_kbps, progress_tx).await else {
+            return Ok(());
+        };
+        let status = e.downcast_ref::<HttpStatusError>();
+        if status.is_some_and(HttpStatusError::is_permanent) {
+            return Err(e.context("Failed to download server pack"));
+        }
+
+        // Progress means the connection is worth resuming; start the count over
+        failures = if on_disk() > before { 1 } else { failures + 1 };
+        if failures >= MAX_DOWNLOAD_ATTEMPTS {
+            return Err(e.context(format!(
+                "Failed to download server pack after {} attempts without progress",
+                failures
+            )));
+        }
+        let delay = status
+            .and_then(|s| s.retry_after)
+            .unwrap_or(Duration::from_secs(2u64.pow(failures)))
+            .min(MAX_RETRY_DELAY);
+        tracing::warn!(
+            "Download interrupted (attempt {}/{}): {:#}. Resuming in {}s...",
+            failures,
+            MAX_DOWNLOAD_ATTEMPTS,
+            e,
+            delay.as_secs()
+        );
+        tokio::time::sleep(delay).await;
+    }
+}
+
+async fn download_attempt(
+    client: &reqwest::Client,
+    url: &str,
+    dest: &Path,
+    limit_kbps: u32,
+    progress_tx: Option<&Sender<PackProgress>>,
+) -> Result<()> {
+    let existing = fs::metadata(dest).map(|m| m.len()).unwrap_or(0);
+    let validator = fs::read_to_string(validator_path(dest)).unwrap_or_default();
+
+    let mut request = client.get(url);
+    if existing > 0 {
+        tracing::info!("Resuming download from byte {}", existing);
+        request = request.header(reqwest::header::RANGE, format!("bytes={}-", existing));
+        if !validator.trim().is_empty() {
+            request = request.header(reqwest::header::IF_RANGE, validator.trim());
+        }
+    }
+
+    let mut response = tokio::time::timeout(STALL_TIMEOUT, request.send())
+        .await
+        .context("No response from server")?
+        .context("Request failed")?;
+    let status = response.status();
+
+    // 416 means we already have every byte; the checksum step catches a bad partial
+    if status == reqwest::StatusCode::RANGE_NOT_SATISFIABLE && existing > 0 {
+        return Ok(());
+    }
+    if !status.is_success() {
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse().ok())
+            .map(Duration::from_secs);
+        return Err(HttpStatusError {
+            status,
+            retry_after,
+        }
+        .into());
+    }
+
+    // Servers that ignore Range send the whole file again with 200
+    let resumed = status == reqwest::StatusCode::PARTIAL_CONTENT;
+    let (mut file, mut downloaded) = if resumed {
+        let file = OpenOptions::new()
+            .append(true)
+            .open(dest)
+            .context("Failed to open partial download")?;
+        (file, existing)
+    } else {
+        let headers = response.headers();
+        let validator = headers
+            .get(reqwest::header::ETAG)
+            .filter(|etag| !etag.as_bytes().starts_with(b"W/"))
+            .or_else(|| headers.get(reqwest::header::LAST_MODIFIED))
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default();
+        fs::write(validator_path(dest), validator).ok();
+        (
+            File::create(dest).context("Failed to create download file")?,
+            0,
+        )
+    };
+    let total = response.content_length().map(|len| len + downloaded);
+
+    let started = Instant::now();
+    let mut received_this_attempt: u64 = 0;
+    let mut last_reported = downloaded;
+
+    while let Some(chunk) = tokio::time::timeout(STALL_TIMEOUT, response.chunk())
+        .await
+        .context("Download stalled")?
+        .context("Connection dropped")?
+    {
+        file.write_all(&chunk)
+            .context("Failed to write download file")?;
+        downloaded += chunk.len() as u64;
+        received_this_attempt += chunk.len() as u64;
+
+        if limit_kbps > 0 {
+            // Sleep until the average rate for this attempt is back under the cap
+            let allowed = Duration::from_secs_f64(
+                received_this_attempt as f64 / (limit_kbps as f64 * 1024.0),
+            );
+            let elapsed = started.elapsed();
+            if allowed > elapsed {
+                tokio::time::sleep(allowed - elapsed).await;
+            }
+        }
+
+        if let Some(tx) = progress_tx {
+            if downloaded - last_reported >= PROGRESS_INTERVAL_BYTES {
+                last_reported = downloaded;
+                tx.send(PackProgress::Download(DownloadProgress {
+                    downloaded,
+                    total,
+                }))
+                .ok();
+            }
+        }
+    }
+    file.flush().context("Failed to flush download file")?;
+
+    if let Some(tx) = progress_tx {
+        tx.send(PackProgress::Download(DownloadProgress {
+            downloaded,
+            total,
+        }))
+        .ok();
+    }
+
+    if let Some(total) = total {
+        if downloaded < total {
+            anyhow::bail!(
+                "Connection closed early ({} of {} bytes)",
+                downloaded,
+                total
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Hex-encoded SHA-1 of a file, read in chunks so large packs aren't loaded into memory
+fn sha1_file(path: &Path) -> Result<String> {
+    let mut file = File::open(path).context("Failed to open file for checksum")?;
+    let mut hasher = Sha1::new();
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let n = file
+            .read(&mut buf)
+            .context("Failed to read file for checksum")?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
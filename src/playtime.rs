@@ -0,0 +1,158 @@
+//! Per-server playtime statistics, built by periodically sampling who's online
+//! and stored as `playtime.json` next to the server's data directory.
+
+use crate::config::get_server_path;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+/// A gap between samples longer than this (app closed, machine asleep) isn't
+/// credited as playtime
+const MAX_SAMPLE_GAP: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlayerStats {
+    pub total_secs: u64,
+    pub sessions: u32,
+    /// RFC 3339
+    pub first_seen: String,
+    /// RFC 3339
+    pub last_seen: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PlaytimeStore {
+    /// Keyed by player name
+    #[serde(default)]
+    pub players: BTreeMap<String, PlayerStats>,
+    /// Who was online at the previous sample, and when it was taken; only
+    /// meaningful while the app runs
+    #[serde(skip)]
+    online: Vec<String>,
+    #[serde(skip)]
+    last_sample: Option<Instant>,
+    /// Whether `online` is known to be accurate: false after loading, until a
+    /// sample is taken or the server is seen stopped
+    #[serde(skip)]
+    online_known: bool,
+}
+
+/// Who came and went between two samples
+#[derive(Debug, Default)]
+pub struct SessionChanges {
+    pub joined: Vec<String>,
+    pub left: Vec<String>,
+}
+
+fn store_path(server_name: &str) -> std::path::PathBuf {
+    get_server_path(server_name).join("playtime.json")
+}
+
+impl PlaytimeStore {
+    /// Load the store, starting empty if there isn't one yet
+    pub fn load(server_name: &str) -> Self {
+        std::fs::read_to_string(store_path(server_name))
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, server_name: &str) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(store_path(server_name), json).context("Failed to write playtime.json")
+    }
+
+    /// Record one sample of the online players. Players also online at the previous
+    /// sample are credited with the time since then; anyone new starts a session.
+    /// Returns who joined and left, unless it isn't known who was on before.
+    pub fn record(&mut self, online: &[String]) -> Option<SessionChanges> {
+        let now = chrono::Local::now().to_rfc3339();
+        let credit = self
+            .last_sample
+            .map(|t| t.elapsed())
+            .filter(|elapsed| *elapsed <= MAX_SAMPLE_GAP)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        self.last_sample = Some(Instant::now());
+        for name in online {
+            let stats = self
+                .players
+                .entry(name.clone())
+                .or_insert_with(|| PlayerStats {
+                    first_seen: now.clone(),
+                    ..Default::default()
+                });
+            if self.online.contains(name) {
+                stats.total_secs += credit;
+            } else {
+                stats.sessions += 1;
+            }
+            stats.last_seen = now.clone();
+        }
+        let changes = self.online_known.then(|| SessionChanges {
+            joined: online
+                .iter()
+                .filter(|name| !self.online.contains(name))
+                .cloned()
+                .collect(),
+            left: self
+                .online
+                .iter()
+                .filter(|name| !online.contains(name))
+                .cloned()
+                .collect(),
+        });
+        self.online = online.to_vec();
+        self.online_known = true;
+        changes
+    }
+
+    /// Forget who was online, e.g. when the server stops, so the next sample
+    /// starts new sessions instead of crediting the downtime
+    pub fn end_sessions(&mut self) {
+        self.online.clear();
+        self.online_known = true;
+        self.last_sample = None;
+    }
+
+    pub fn is_online(&self, name: &str) -> bool {
+        self.online.iter().any(|n| n == name)
+    }
+
+    /// Players ordered by total playtime, most first
+    pub fn leaderboard(&self) -> Vec<(&String, &PlayerStats)> {
+        let mut rows: Vec<_> = self.players.iter().collect();
+        rows.sort_by_key(|(_, s)| std::cmp::Reverse(s.total_secs));
+        rows
+    }
+}
+
+/// e.g. `3d 4h`, `2h 15m`, `12m`
+pub fn format_duration(secs: u64) -> String {
+    let minutes = secs / 60;
+    let hours = minutes / 60;
+    let days = hours / 24;
+    if days > 0 {
+        format!("{}d {}h", days, hours % 24)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes % 60)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
+/// Human-friendly age of an RFC 3339 timestamp, e.g. `5 min ago`
+pub fn format_last_seen(timestamp: &str) -> String {
+    let Ok(then) = chrono::DateTime::parse_from_rfc3339(timestamp) else {
+        return "unknown".to_string();
+    };
+    let secs = (chrono::Local::now().fixed_offset() - then)
+        .num_seconds()
+        .max(0) as u64;
+    match secs {
+        0..=119 => "just now".to_string(),
+        120..=3599 => format!("{} min ago", secs / 60),
+        3600..=86_399 => format!("{} h ago", secs / 3600),
+        _ => then.format("%Y-%m-%d").to_string(),
+    }
+}
@@ -0,0 +1,133 @@
+//! Port bookkeeping: which host ports each server publishes, where they overlap,
+//! and whether something else on the host already holds them.
+
+use crate::i18n::{tr, tr_args};
+use crate::server::{ServerInstance, ServerStatus};
+
+/// What a published port is used for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PortKind {
+    Game,
+    Rcon,
+    Extra,
+}
+
+impl std::fmt::Display for PortKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PortKind::Game => write!(f, "{}", tr("ports-kind-game")),
+            PortKind::Rcon => write!(f, "{}", tr("ports-kind-rcon")),
+            PortKind::Extra => write!(f, "{}", tr("ports-kind-extra")),
+        }
+    }
+}
+
+/// One row in the ports overview
+#[derive(Debug, Clone)]
+pub struct PortUsage {
+    pub port: u16,
+    pub kind: PortKind,
+    pub server_name: String,
+    /// Whether the owning server currently has a live container holding the port
+    pub server_active: bool,
+    /// Other servers configured to use the same host port (regardless of status)
+    pub overlaps_with: Vec<String>,
+    /// Set when the port can't be bound on the host and the owning server isn't active
+    pub host_conflict: Option<String>,
+}
+
+impl PortUsage {
+    pub fn has_problem(&self) -> bool {
+        !self.overlaps_with.is_empty() || self.host_conflict.is_some()
+    }
+}
+
+/// Whether a server's container is (or is about to be) holding its ports
+pub fn is_active(status: &ServerStatus) -> bool {
+    matches!(
+        status,
+        ServerStatus::Running
+            | ServerStatus::Starting
+            | ServerStatus::Initializing
+            | ServerStatus::Stopping
+            | ServerStatus::Frozen
+    )
+}
+
+/// Try to bind a port on all interfaces.
+/// Returns Some(reason) if the host won't let us have it, None if it's free.
+pub fn probe_host_port(port: u16) -> Option<String> {
+    bind_error(port).map(|e| describe_bind_error(port, &e))
+}
+
+/// Why the host won't let us bind `port` on all interfaces, if it won't
+pub fn bind_error(port: u16) -> Option<std::io::Error> {
+    std::net::TcpListener::bind(format!("0.0.0.0:{}", port)).err()
+}
+
+/// What to tell the user about a port the host wouldn't bind
+pub fn describe_bind_error(port: u16, error: &std::io::Error) -> String {
+    let port = port.to_string();
+    match error.kind() {
+        std::io::ErrorKind::AddrInUse => tr_args("ports-host-in-use", &[("port", port.into())]),
+        std::io::ErrorKind::PermissionDenied => {
+            tr_args("ports-host-denied", &[("port", port.into())])
+        }
+        _ => tr_args(
+            "ports-host-bind-failed",
+            &[("port", port.into()), ("error", error.to_string().into())],
+        ),
+    }
+}
+
+/// Build the full port table for every configured server.
+/// When `probe_host` is set, ports of inactive servers are test-bound on the host.
+pub fn collect_port_usage(servers: &[ServerInstance], probe_host: bool) -> Vec<PortUsage> {
+    let mut rows: Vec<PortUsage> = Vec::new();
+
+    for server in servers {
+        let config = &server.config;
+        let mut ports = vec![
+            (config.port, PortKind::Game),
+            (config.rcon_port(), PortKind::Rcon),
+        ];
+        ports.extend(config.extra_ports.iter().map(|p| (*p, PortKind::Extra)));
+
+        for (port, kind) in ports {
+            rows.push(PortUsage {
+                port,
+                kind,
+                server_name: config.name.clone(),
+                server_active: is_active(&server.status),
+                overlaps_with: Vec::new(),
+                host_conflict: None,
+            });
+        }
+    }
+
+    // Flag overlaps between servers (and within one server, e.g. extra port == RCON port)
+    for i in 0..rows.len() {
+        let overlaps: Vec<String> = rows
+            .iter()
+            .enumerate()
+            .filter(|(j, other)| *j != i && other.port == rows[i].port)
+            .map(|(_, other)| {
+                if other.server_name == rows[i].server_name {
+                    format!("{} ({})", other.server_name, other.kind)
+                } else {
+                    other.server_name.clone()
+                }
+            })
+            .collect();
+        rows[i].overlaps_with = overlaps;
+    }
+
+    if probe_host {
+        for row in rows.iter_mut().filter(|r| !r.server_active) {
+            row.host_conflict = probe_host_port(row.port);
+        }
+    }
+
+    rows.sort_by(|a, b| a.port.cmp(&b.port).then(a.server_name.cmp(&b.server_name)));
+    rows
+}
@@ -0,0 +1,292 @@
+//! Preflight checklist run before a server is started: disk, RAM, Docker storage,
+//! ports, JVM flags, local modpack files, and API keys. Failures block the start; warnings can be overridden.
+
+use crate::i18n::{tr, tr_args};
+use crate::local_pack::{self, detect_loader, list_pack, PackListing};
+use crate::memory::HostMemory;
+use crate::server::{ModLoader, ModpackSource, ServerConfig};
+use std::path::{Component, Path};
+
+/// Below this much free space a start is refused
+pub const MIN_FREE_DISK_MB: u64 = 2048;
+
+/// Below this much free space a start is allowed but flagged
+pub const LOW_FREE_DISK_MB: u64 = 10 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+#[derive(Debug, Clone)]
+pub struct PreflightCheck {
+    pub name: String,
+    pub status: CheckStatus,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct PreflightReport {
+    pub server_name: String,
+    pub checks: Vec<PreflightCheck>,
+}
+
+impl PreflightReport {
+    pub fn has_failures(&self) -> bool {
+        self.checks.iter().any(|c| c.status == CheckStatus::Fail)
+    }
+
+    pub fn all_passed(&self) -> bool {
+        self.checks.iter().all(|c| c.status == CheckStatus::Pass)
+    }
+}
+
+/// Everything the checks need, gathered by app.rs (some of it requires Docker or app state)
+pub struct PreflightInputs<'a> {
+    pub config: &'a ServerConfig,
+    pub data_path: &'a Path,
+    pub host_memory: HostMemory,
+    /// Memory allocated to other active servers
+    pub others_mb: u64,
+    /// Each published port with the conflict found for it, if any
+    pub port_conflicts: Vec<(u16, Option<String>)>,
+    /// Docker's storage directory as reported by the daemon
+    pub docker_root_dir: Option<String>,
+    pub has_cf_key: bool,
+}
+
+pub fn run(inputs: &PreflightInputs<'_>) -> PreflightReport {
+    let mut checks = vec![
+        check_data_disk(inputs.data_path),
+        check_memory(inputs),
+        check_docker_storage(inputs.docker_root_dir.as_deref()),
+    ];
+    checks.extend(check_ports(&inputs.port_conflicts));
+    checks.push(check_jvm_flags(inputs.config));
+    if let Some(check) = check_local_source(inputs.config, inputs.data_path) {
+        checks.push(check);
+    }
+    if let Some(check) = check_cf_key(inputs.config, inputs.data_path, inputs.has_cf_key) {
+        checks.push(check);
+    }
+
+    PreflightReport {
+        server_name: inputs.config.name.clone(),
+        checks,
+    }
+}
+
+fn disk_check(name: String, path: &Path) -> PreflightCheck {
+    let (status, detail) = match crate::backup::available_space(path) {
+        Ok(bytes) => {
+            let mb = bytes / (1024 * 1024);
+            let args = [
+                ("free", crate::backup::format_bytes(bytes).into()),
+                ("path", path.display().to_string().into()),
+            ];
+            if mb < MIN_FREE_DISK_MB {
+                (CheckStatus::Fail, tr_args("preflight-disk-fail", &args))
+            } else if mb < LOW_FREE_DISK_MB {
+                (CheckStatus::Warn, tr_args("preflight-disk-low", &args))
+            } else {
+                (CheckStatus::Pass, tr_args("preflight-disk-ok", &args))
+            }
+        }
+        Err(e) => (
+            CheckStatus::Warn,
+            tr_args("preflight-disk-unknown", &[("error", e.to_string().into())]),
+        ),
+    };
+    PreflightCheck {
+        name,
+        status,
+        detail,
+    }
+}
+
+fn check_data_disk(data_path: &Path) -> PreflightCheck {
+    disk_check(tr("preflight-disk-data"), data_path)
+}
+
+fn check_docker_storage(root_dir: Option<&str>) -> PreflightCheck {
+    match root_dir {
+        // Only meaningful when Docker's storage lives on this machine's filesystem
+        Some(root) if Path::new(root).exists() => {
+            disk_check(tr("preflight-disk-docker"), Path::new(root))
+        }
+        Some(root) => PreflightCheck {
+            name: tr("preflight-disk-docker"),
+            status: CheckStatus::Pass,
+            detail: tr_args("preflight-docker-in-vm", &[("path", root.into())]),
+        },
+        None => PreflightCheck {
+            name: tr("preflight-disk-docker"),
+            status: CheckStatus::Warn,
+            detail: tr("preflight-docker-no-root"),
+        },
+    }
+}
+
+fn check_memory(inputs: &PreflightInputs<'_>) -> PreflightCheck {
+    let requested = inputs.config.memory_mb;
+    let host = inputs.host_memory;
+    let args = [
+        ("requested", requested.into()),
+        ("total", host.total_mb.into()),
+        ("free", host.available_mb.into()),
+        ("others", inputs.others_mb.into()),
+    ];
+    let (status, detail) = if host.total_mb == 0 {
+        (CheckStatus::Warn, tr("preflight-memory-unknown"))
+    } else if requested > host.total_mb {
+        (
+            CheckStatus::Fail,
+            tr_args("preflight-memory-over-host", &args),
+        )
+    } else if requested > host.available_mb {
+        (CheckStatus::Warn, tr_args("preflight-memory-low", &args))
+    } else {
+        (CheckStatus::Pass, tr_args("preflight-memory-ok", &args))
+    };
+    PreflightCheck {
+        name: tr("preflight-memory"),
+        status,
+        detail,
+    }
+}
+
+fn check_ports(conflicts: &[(u16, Option<String>)]) -> Vec<PreflightCheck> {
+    conflicts
+        .iter()
+        .map(|(port, conflict)| match conflict {
+            Some(reason) => PreflightCheck {
+                name: tr_args("preflight-port", &[("port", port.to_string().into())]),
+                status: CheckStatus::Fail,
+                detail: reason.clone(),
+            },
+            None => PreflightCheck {
+                name: tr_args("preflight-port", &[("port", port.to_string().into())]),
+                status: CheckStatus::Pass,
+                detail: tr("preflight-port-free"),
+            },
+        })
+        .collect()
+}
+
+fn check_jvm_flags(config: &ServerConfig) -> PreflightCheck {
+    let problems = crate::jvm::validate_args(&config.java_args, config.java_version);
+    if problems.is_empty() {
+        let gc = crate::jvm::GarbageCollector::effective(&config.java_args, config.java_version);
+        PreflightCheck {
+            name: tr("preflight-jvm"),
+            status: CheckStatus::Pass,
+            detail: tr_args(
+                "preflight-jvm-ok",
+                &[
+                    ("gc", gc.label().into()),
+                    ("java", config.java_version.to_string().into()),
+                ],
+            ),
+        }
+    } else {
+        PreflightCheck {
+            name: tr("preflight-jvm"),
+            status: CheckStatus::Fail,
+            detail: problems.join("; "),
+        }
+    }
+}
+
+fn check_cf_key(
+    config: &ServerConfig,
+    data_path: &Path,
+    has_cf_key: bool,
+) -> Option<PreflightCheck> {
+    // AUTO_CURSEFORGE and the host-side installs all go through the CurseForge API
+    let needs_key = match &config.modpack.source {
+        ModpackSource::CurseForge { .. }
+        | ModpackSource::CurseForgeManifest { .. }
+        | ModpackSource::CurseForgeServerPack { .. } => true,
+        ModpackSource::Local { path } => local_pack::is_cf_client_zip(&data_path.join(path)),
+        _ => false,
+    };
+    if !needs_key {
+        return None;
+    }
+    Some(if has_cf_key {
+        PreflightCheck {
+            name: tr("preflight-cf-key"),
+            status: CheckStatus::Pass,
+            detail: tr("preflight-cf-key-set"),
+        }
+    } else {
+        PreflightCheck {
+            name: tr("preflight-cf-key"),
+            status: CheckStatus::Fail,
+            detail: tr("preflight-cf-key-missing"),
+        }
+    })
+}
+
+fn check_local_source(config: &ServerConfig, data_path: &Path) -> Option<PreflightCheck> {
+    let ModpackSource::Local { path } = &config.modpack.source else {
+        return None;
+    };
+    let (status, detail) = match inspect_local_pack(data_path, path) {
+        Ok(listing) => local_pack_status(&listing, &config.modpack.loader),
+        Err(e) => (CheckStatus::Fail, e),
+    };
+    Some(PreflightCheck {
+        name: tr("preflight-local"),
+        status,
+        detail,
+    })
+}
+
+fn inspect_local_pack(data_path: &Path, path: &str) -> Result<PackListing, String> {
+    let relative = Path::new(path);
+    let args = [
+        ("path", path.into()),
+        ("folder", data_path.display().to_string().into()),
+    ];
+    if relative.is_absolute() || relative.components().any(|c| c == Component::ParentDir) {
+        return Err(tr_args("preflight-local-outside", &args));
+    }
+    let full = data_path.join(relative);
+    if !full.exists() {
+        return Err(tr_args("preflight-local-missing", &args));
+    }
+
+    list_pack(&full, path)
+}
+
+fn local_pack_status(listing: &PackListing, declared: &ModLoader) -> (CheckStatus, String) {
+    let names = &listing.names;
+    let recognizable = listing.cf_manifest.is_some()
+        || listing.mr_index.is_some()
+        || names
+            .iter()
+            .any(|n| n.starts_with("mods/") || n.starts_with("libraries/"))
+        || names.iter().any(|n| n == "server.properties")
+        || listing.top_level_jar("");
+    if !recognizable {
+        return (CheckStatus::Fail, tr("preflight-local-unrecognized"));
+    }
+
+    let detected = detect_loader(listing);
+    let found = detected.as_ref().map(|l| format!("{:?}", l));
+    let args = [
+        ("found", found.unwrap_or_default().into()),
+        ("declared", format!("{:?}", declared).into()),
+        ("count", names.len().into()),
+    ];
+    let (status, id) = match (detected, declared) {
+        (Some(found), declared) if &found == declared => (CheckStatus::Pass, "preflight-local-ok"),
+        (Some(_), ModLoader::Vanilla) => (CheckStatus::Warn, "preflight-local-vanilla"),
+        (Some(_), _) => (CheckStatus::Fail, "preflight-local-wrong-loader"),
+        (None, _) => (CheckStatus::Pass, "preflight-local-unknown-loader"),
+    };
+    (status, tr_args(id, &args))
+}
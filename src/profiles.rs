@@ -0,0 +1,117 @@
+//! Profiles: independent sets of servers, settings and backups (e.g. "Home",
+//! "LAN party laptop", "Test"), switched from the top bar. The default profile
+//! is [`app_root`] itself; the others live in `profiles/<name>/` inside it.
+
+use crate::config::{self, app_root};
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+/// Name shown for the profile kept directly in [`app_root`]
+pub const DEFAULT_PROFILE: &str = "Default";
+
+/// Which profile to open on the next launch
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct ProfileState {
+    /// None for the default profile
+    active: Option<String>,
+}
+
+/// Profile open right now; None for the default
+static ACTIVE: RwLock<Option<String>> = RwLock::new(None);
+
+fn profiles_dir() -> PathBuf {
+    app_root().join("profiles")
+}
+
+fn state_path() -> PathBuf {
+    app_root().join("profiles.json")
+}
+
+/// Folder holding a profile's settings, and its data unless moved elsewhere
+fn profile_dir(name: Option<&str>) -> PathBuf {
+    match name {
+        Some(name) => profiles_dir().join(name),
+        None => app_root(),
+    }
+}
+
+/// Names of the profiles besides the default, sorted
+pub fn list() -> Vec<String> {
+    let mut names: Vec<String> = std::fs::read_dir(profiles_dir())
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.path().is_dir())
+                .map(|e| e.file_name().to_string_lossy().into_owned())
+                .collect()
+        })
+        .unwrap_or_default();
+    names.sort_by_key(|n| n.to_lowercase());
+    names
+}
+
+/// Profile open right now; None for the default
+pub fn active() -> Option<String> {
+    ACTIVE.read().ok().and_then(|a| a.clone())
+}
+
+/// Name of the open profile, for display
+pub fn active_label() -> String {
+    active().unwrap_or_else(|| DEFAULT_PROFILE.to_string())
+}
+
+/// Profile used last time, if its folder is still there
+pub fn saved() -> Option<String> {
+    let state: ProfileState = std::fs::read_to_string(state_path())
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default();
+    state.active.filter(|name| profile_dir(Some(name)).is_dir())
+}
+
+/// Point settings and data paths into profile `name` (None for the default).
+/// Its settings say where its data lives, if it was moved.
+pub fn open(name: Option<String>) {
+    config::set_profile_root(profile_dir(name.as_deref()));
+    let settings = config::load_settings();
+    config::set_data_root(settings.data_root.map(PathBuf::from));
+    if let Ok(mut active) = ACTIVE.write() {
+        *active = name;
+    }
+}
+
+/// Open profile `name` and remember it for the next launch
+pub fn switch(name: Option<String>) -> Result<()> {
+    let json = serde_json::to_string_pretty(&ProfileState {
+        active: name.clone(),
+    })?;
+    std::fs::write(state_path(), json).context("Failed to save the active profile")?;
+    open(name);
+    Ok(())
+}
+
+/// Make an empty profile called `name`; returns the name as saved
+pub fn create(name: &str) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        anyhow::bail!("Give the profile a name");
+    }
+    if name.eq_ignore_ascii_case(DEFAULT_PROFILE) {
+        anyhow::bail!("'{}' is the name of the default profile", DEFAULT_PROFILE);
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_alphanumeric() || matches!(c, ' ' | '-' | '_'))
+    {
+        anyhow::bail!("Profile names can only use letters, numbers, spaces, '-' and '_'");
+    }
+    if list().iter().any(|n| n.eq_ignore_ascii_case(name)) {
+        anyhow::bail!("There is already a profile called '{}'", name);
+    }
+    let dir = profile_dir(Some(name));
+    std::fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    Ok(name.to_string())
+}
@@ -0,0 +1,137 @@
+//! GameSpy 4 (GS4) Query client, the UDP protocol behind `enable-query`. Unlike
+//! the status ping, whose player sample is cut to a dozen names, a full stat
+//! answer lists every online player, plus the server software and its plugins.
+//! See https://wiki.vg/Query
+
+use anyhow::{bail, Context, Result};
+use std::time::Duration;
+use tokio::net::UdpSocket;
+
+const MAGIC: [u8; 2] = [0xFE, 0xFD];
+const TYPE_HANDSHAKE: u8 = 9;
+const TYPE_STAT: u8 = 0;
+/// Servers only look at the low four bits of each byte of the session ID
+const SESSION_ID: i32 = 0x0102_0304;
+/// Padding before the key/value section of a full stat answer
+const KV_PADDING: &[u8] = b"splitnum\x00\x80\x00";
+/// Padding before the player list
+const PLAYERS_PADDING: &[u8] = b"\x01player_\x00\x00";
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueryStats {
+    pub motd: String,
+    pub version: String,
+    /// Server software as reported in `plugins`, e.g. "Paper on Bukkit 1.20.4"
+    pub server_mod: Option<String>,
+    /// Plugin names with versions; mod loaders usually report none
+    pub plugins: Vec<String>,
+    pub map: String,
+    pub players_online: i64,
+    pub players_max: i64,
+    /// Every online player's name
+    pub players: Vec<String>,
+}
+
+/// Ask the server at `address` for its full stat, within `timeout` overall
+pub async fn full_stat(address: &str, timeout: Duration) -> Result<QueryStats> {
+    tokio::time::timeout(timeout, query(address))
+        .await
+        .context("Query timed out (is enable-query on?)")?
+}
+
+async fn query(address: &str) -> Result<QueryStats> {
+    let socket = UdpSocket::bind("0.0.0.0:0").await?;
+    socket
+        .connect(address)
+        .await
+        .with_context(|| format!("Couldn't reach {}", address))?;
+
+    socket.send(&packet(TYPE_HANDSHAKE, &[])).await?;
+    let mut buf = vec![0u8; 4096];
+    let n = socket.recv(&mut buf).await?;
+    let token = parse_challenge(&buf[..n])?;
+
+    let mut payload = token.to_be_bytes().to_vec();
+    // Four bytes of padding ask for the full stat rather than the basic one
+    payload.extend_from_slice(&[0; 4]);
+    socket.send(&packet(TYPE_STAT, &payload)).await?;
+    let n = socket.recv(&mut buf).await?;
+    parse_full_stat(&buf[..n])
+}
+
+fn packet(kind: u8, payload: &[u8]) -> Vec<u8> {
+    let mut packet = MAGIC.to_vec();
+    packet.push(kind);
+    packet.extend_from_slice(&SESSION_ID.to_be_bytes());
+    packet.extend_from_slice(payload);
+    packet
+}
+
+/// Strip an answer's type and session ID, checking it's the expected type
+fn answer_body(answer: &[u8], kind: u8) -> Result<&[u8]> {
+    if answer.len() < 5 || answer[0] != kind {
+        bail!("Unexpected query answer");
+    }
+    Ok(&answer[5..])
+}
+
+/// The challenge token comes back as a null-terminated decimal string
+fn parse_challenge(answer: &[u8]) -> Result<i32> {
+    let body = answer_body(answer, TYPE_HANDSHAKE)?;
+    let text = String::from_utf8_lossy(body);
+    text.trim_end_matches('\0')
+        .trim()
+        .parse()
+        .context("Bad challenge token in query answer")
+}
+
+fn parse_full_stat(answer: &[u8]) -> Result<QueryStats> {
+    let body = answer_body(answer, TYPE_STAT)?;
+    let body = body.strip_prefix(KV_PADDING).unwrap_or(body);
+    let split = find(body, PLAYERS_PADDING).context("Query answer has no player list")?;
+    let (kv, players) = (&body[..split], &body[split + PLAYERS_PADDING.len()..]);
+
+    let mut fields = kv.split(|b| *b == 0).map(|s| String::from_utf8_lossy(s));
+    let mut stats = QueryStats::default();
+    while let (Some(key), Some(value)) = (fields.next(), fields.next()) {
+        if key.is_empty() {
+            break;
+        }
+        match key.as_ref() {
+            "hostname" => stats.motd = value.into_owned(),
+            "version" => stats.version = value.into_owned(),
+            "plugins" => (stats.server_mod, stats.plugins) = parse_plugins(&value),
+            "map" => stats.map = value.into_owned(),
+            "numplayers" => stats.players_online = value.parse().unwrap_or_default(),
+            "maxplayers" => stats.players_max = value.parse().unwrap_or_default(),
+            _ => {}
+        }
+    }
+    stats.players = players
+        .split(|b| *b == 0)
+        .take_while(|s| !s.is_empty())
+        .map(|s| String::from_utf8_lossy(s).into_owned())
+        .collect();
+    Ok(stats)
+}
+
+/// `"Paper on Bukkit 1.20.4: WorldEdit 7.2; LuckPerms 5.4"` → server software and
+/// plugins. Vanilla and most modded servers send an empty string.
+fn parse_plugins(value: &str) -> (Option<String>, Vec<String>) {
+    let value = value.trim();
+    if value.is_empty() {
+        return (None, Vec::new());
+    }
+    let (server_mod, plugins) = value.split_once(':').unwrap_or((value, ""));
+    let plugins = plugins
+        .split(';')
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .map(str::to_string)
+        .collect();
+    (Some(server_mod.trim().to_string()), plugins)
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
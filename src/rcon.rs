@@ -8,10 +8,16 @@
 //! - 4 bytes: packet type (little-endian)
 //! - N bytes: payload (null-terminated string)
 //! - 2 bytes: padding (two null bytes)
+//!
+//! The client is async (tokio) so slow commands never block the UI thread.
+//! `RconSession` keeps one connection open for the lifetime of a console
+//! and reconnects on the next command if it drops.
 
-use std::io::{Read, Write};
-use std::net::TcpStream;
 use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::sync::mpsc;
+use tokio::time::timeout;
 
 /// Packet types
 const SERVERDATA_AUTH: i32 = 3;
@@ -20,6 +26,14 @@ const SERVERDATA_AUTH_RESPONSE: i32 = 2; // Same as EXECCOMMAND, used for docume
 const SERVERDATA_EXECCOMMAND: i32 = 2;
 const SERVERDATA_RESPONSE_VALUE: i32 = 0;
 
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+/// Some commands (e.g. `save-all flush`, `forge tps`) take a while on big packs
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Largest packet the server sends: 4096 bytes of payload plus header and padding
+const MAX_PACKET_LEN: usize = 4096 + 10;
+
 /// RCON connection to a Minecraft server
 pub struct RconClient {
     stream: TcpStream,
@@ -51,7 +65,7 @@ impl std::fmt::Display for RconError {
 
 impl RconClient {
     /// Connect to an RCON server and authenticate
-    pub fn connect(address: &str, password: &str) -> Result<Self, RconError> {
+    pub async fn connect(address: &str, password: &str) -> Result<Self, RconError> {
         tracing::debug!("RCON: Connecting to {}", address);
 
         // Parse address
@@ -60,21 +74,12 @@ impl RconClient {
             .map_err(|e| RconError::ConnectionFailed(format!("Invalid address: {}", e)))?;
 
         // Connect with timeout
-        let stream = TcpStream::connect_timeout(&addr, Duration::from_secs(5)).map_err(|e| {
-            tracing::error!("RCON: Connection failed: {}", e);
-            RconError::ConnectionFailed(e.to_string())
-        })?;
-
-        // Set timeouts
-        stream
-            .set_read_timeout(Some(Duration::from_secs(10)))
+        let stream = timeout(CONNECT_TIMEOUT, TcpStream::connect(addr))
+            .await
+            .map_err(|_| RconError::Timeout)?
             .map_err(|e| {
-                RconError::ConnectionFailed(format!("Failed to set read timeout: {}", e))
-            })?;
-        stream
-            .set_write_timeout(Some(Duration::from_secs(5)))
-            .map_err(|e| {
-                RconError::ConnectionFailed(format!("Failed to set write timeout: {}", e))
+                tracing::error!("RCON: Connection failed: {}", e);
+                RconError::ConnectionFailed(e.to_string())
             })?;
 
         tracing::debug!("RCON: Connected, authenticating...");
@@ -85,19 +90,18 @@ impl RconClient {
         };
 
         // Authenticate
-        client.authenticate(password)?;
+        client.authenticate(password).await?;
 
         tracing::info!("RCON: Authenticated successfully");
         Ok(client)
     }
 
     /// Authenticate with the server
-    fn authenticate(&mut self, password: &str) -> Result<(), RconError> {
-        let auth_id = self.request_id;
-        self.send_packet(SERVERDATA_AUTH, password)?;
+    async fn authenticate(&mut self, password: &str) -> Result<(), RconError> {
+        let auth_id = self.send_packet(SERVERDATA_AUTH, password).await?;
 
         // Read auth response
-        let (resp_id, resp_type, _payload) = self.receive_packet()?;
+        let (resp_id, resp_type, _payload) = self.receive_packet().await?;
 
         tracing::debug!("RCON: Auth response - id: {}, type: {}", resp_id, resp_type);
 
@@ -110,7 +114,7 @@ impl RconClient {
         // Some servers send an empty RESPONSE_VALUE before the AUTH_RESPONSE
         if resp_type == SERVERDATA_RESPONSE_VALUE {
             // Read the actual auth response
-            let (resp_id2, _resp_type2, _payload2) = self.receive_packet()?;
+            let (resp_id2, _resp_type2, _payload2) = self.receive_packet().await?;
             if resp_id2 == -1 {
                 return Err(RconError::AuthFailed);
             }
@@ -128,33 +132,41 @@ impl RconClient {
     }
 
     /// Send a command and get the response
-    pub fn command(&mut self, cmd: &str) -> Result<String, RconError> {
+    pub async fn command(&mut self, cmd: &str) -> Result<String, RconError> {
         tracing::debug!("RCON: Sending command: {}", cmd);
 
-        self.send_packet(SERVERDATA_EXECCOMMAND, cmd)?;
-
-        let (resp_id, resp_type, payload) = self.receive_packet()?;
+        let id = self.send_packet(SERVERDATA_EXECCOMMAND, cmd).await?;
 
-        tracing::debug!(
-            "RCON: Response - id: {}, type: {}, len: {}",
-            resp_id,
-            resp_type,
-            payload.len()
-        );
+        // Skip any reply that isn't for this request (e.g. a trailing auth packet)
+        loop {
+            let (resp_id, resp_type, payload) = self.receive_packet().await?;
 
-        if resp_type != SERVERDATA_RESPONSE_VALUE {
-            tracing::warn!(
-                "RCON: Unexpected response type: {} (expected {})",
+            tracing::debug!(
+                "RCON: Response - id: {}, type: {}, len: {}",
+                resp_id,
                 resp_type,
-                SERVERDATA_RESPONSE_VALUE
+                payload.len()
             );
-        }
 
-        Ok(payload)
+            if resp_id != id {
+                tracing::debug!("RCON: Discarding stale response for id {}", resp_id);
+                continue;
+            }
+
+            if resp_type != SERVERDATA_RESPONSE_VALUE {
+                tracing::warn!(
+                    "RCON: Unexpected response type: {} (expected {})",
+                    resp_type,
+                    SERVERDATA_RESPONSE_VALUE
+                );
+            }
+
+            return Ok(payload);
+        }
     }
 
-    /// Send a packet to the server
-    fn send_packet(&mut self, packet_type: i32, payload: &str) -> Result<(), RconError> {
+    /// Send a packet to the server, returning the request ID used
+    async fn send_packet(&mut self, packet_type: i32, payload: &str) -> Result<i32, RconError> {
         let request_id = self.request_id;
         self.request_id += 1;
 
@@ -179,32 +191,28 @@ impl RconClient {
 
         tracing::trace!("RCON: Sending {} bytes", packet.len());
 
-        self.stream.write_all(&packet).map_err(|e| {
-            tracing::error!("RCON: Send failed: {}", e);
-            RconError::SendFailed(e.to_string())
-        })?;
-
-        self.stream
-            .flush()
-            .map_err(|e| RconError::SendFailed(format!("Flush failed: {}", e)))?;
+        timeout(WRITE_TIMEOUT, self.stream.write_all(&packet))
+            .await
+            .map_err(|_| RconError::Timeout)?
+            .map_err(|e| {
+                tracing::error!("RCON: Send failed: {}", e);
+                RconError::SendFailed(e.to_string())
+            })?;
 
-        Ok(())
+        Ok(request_id)
     }
 
     /// Receive a packet from the server
-    fn receive_packet(&mut self) -> Result<(i32, i32, String), RconError> {
+    async fn receive_packet(&mut self) -> Result<(i32, i32, String), RconError> {
         // Read length (4 bytes)
         let mut len_buf = [0u8; 4];
-        self.stream.read_exact(&mut len_buf).map_err(|e| {
-            tracing::error!("RCON: Failed to read length: {}", e);
-            if e.kind() == std::io::ErrorKind::TimedOut
-                || e.kind() == std::io::ErrorKind::WouldBlock
-            {
-                RconError::Timeout
-            } else {
+        timeout(READ_TIMEOUT, self.stream.read_exact(&mut len_buf))
+            .await
+            .map_err(|_| RconError::Timeout)?
+            .map_err(|e| {
+                tracing::error!("RCON: Failed to read length: {}", e);
                 RconError::ReceiveFailed(format!("Failed to read length: {}", e))
-            }
-        })?;
+            })?;
 
         let length = i32::from_le_bytes(len_buf) as usize;
         tracing::trace!("RCON: Receiving packet of {} bytes", length);
@@ -215,7 +223,7 @@ impl RconClient {
                 length
             )));
         }
-        if length > 4096 {
+        if length > MAX_PACKET_LEN {
             return Err(RconError::InvalidResponse(format!(
                 "Packet too large: {}",
                 length
@@ -224,10 +232,13 @@ impl RconClient {
 
         // Read rest of packet
         let mut buf = vec![0u8; length];
-        self.stream.read_exact(&mut buf).map_err(|e| {
-            tracing::error!("RCON: Failed to read packet body: {}", e);
-            RconError::ReceiveFailed(format!("Failed to read body: {}", e))
-        })?;
+        timeout(READ_TIMEOUT, self.stream.read_exact(&mut buf))
+            .await
+            .map_err(|_| RconError::Timeout)?
+            .map_err(|e| {
+                tracing::error!("RCON: Failed to read packet body: {}", e);
+                RconError::ReceiveFailed(format!("Failed to read body: {}", e))
+            })?;
 
         // Parse packet
         let request_id = i32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]);
@@ -245,3 +256,185 @@ impl RconClient {
         Ok((request_id, packet_type, payload))
     }
 }
+
+/// Connect, run a single command, and disconnect
+pub async fn send_command(
+    address: &str,
+    password: &str,
+    command: &str,
+) -> Result<String, RconError> {
+    let mut client = RconClient::connect(address, password).await?;
+    client.command(command).await
+}
+
+/// Stop the server writing world files and flush everything pending to disk, so its
+/// data directory can be copied consistently. Undo with [`resume_saving`].
+pub async fn pause_saving(address: &str, password: &str) -> Result<(), RconError> {
+    let mut client = RconClient::connect(address, password).await?;
+    client.command("save-off").await?;
+    // The reply to a flush only comes once every chunk has been written
+    if let Err(e) = client.command("save-all flush").await {
+        let _ = resume_saving(address, password).await;
+        return Err(e);
+    }
+    Ok(())
+}
+
+/// Switch world saving back on after [`pause_saving`]
+pub async fn resume_saving(address: &str, password: &str) -> Result<(), RconError> {
+    send_command(address, password, "save-on").await.map(|_| ())
+}
+
+// ── Persistent console session ─────────────────────────────────────────────
+
+/// What a session reports back to the UI
+#[derive(Debug, Clone)]
+pub enum RconEvent {
+    Connected,
+    /// Output of a command (may be empty)
+    Response(String),
+    /// A command couldn't be run; `auth` is set when the password was rejected
+    Error {
+        command: Option<String>,
+        message: String,
+        auth: bool,
+    },
+}
+
+/// Handle to a background task that owns one RCON connection.
+/// Dropping the handle closes the connection.
+pub struct RconSession {
+    commands: mpsc::UnboundedSender<String>,
+}
+
+impl RconSession {
+    /// Spawn the session task and connect right away. Events are delivered through `on_event`.
+    pub fn start(
+        runtime: &tokio::runtime::Handle,
+        address: String,
+        password: String,
+        on_event: impl Fn(RconEvent) + Send + 'static,
+    ) -> Self {
+        let (tx, mut rx) = mpsc::unbounded_channel::<String>();
+
+        runtime.spawn(async move {
+            let mut client = match RconClient::connect(&address, &password).await {
+                Ok(c) => {
+                    on_event(RconEvent::Connected);
+                    Some(c)
+                }
+                Err(e) => {
+                    on_event(connect_error(None, e));
+                    None
+                }
+            };
+
+            // Ends when the session handle is dropped
+            while let Some(command) = rx.recv().await {
+                if client.is_none() {
+                    match RconClient::connect(&address, &password).await {
+                        Ok(c) => {
+                            on_event(RconEvent::Connected);
+                            client = Some(c);
+                        }
+                        Err(e) => {
+                            on_event(connect_error(Some(command), e));
+                            continue;
+                        }
+                    }
+                }
+                let Some(conn) = client.as_mut() else {
+                    continue;
+                };
+
+                match conn.command(&command).await {
+                    Ok(output) => on_event(RconEvent::Response(output)),
+                    Err(e) => {
+                        // Don't resend: the server may already have run it. Reconnect next time.
+                        client = None;
+                        on_event(RconEvent::Error {
+                            command: Some(command),
+                            message: format!("{} (will reconnect on the next command)", e),
+                            auth: false,
+                        });
+                    }
+                }
+            }
+            tracing::debug!("RCON: Session for {} closed", address);
+        });
+
+        Self { commands: tx }
+    }
+
+    /// Queue a command; its result arrives as an `RconEvent`
+    pub fn send(&self, command: &str) -> bool {
+        self.commands.send(command.to_string()).is_ok()
+    }
+}
+
+fn connect_error(command: Option<String>, e: RconError) -> RconEvent {
+    RconEvent::Error {
+        command,
+        auth: matches!(e, RconError::AuthFailed),
+        message: e.to_string(),
+    }
+}
+
+/// Pull player names out of the reply to `list`. Returns `None` for any other output.
+///
+/// Handles both "There are 2 of a max of 20 players online: Steve, Alex" and the
+/// pre-1.13 "There are 2/20 players online:" followed by the names on the next line.
+pub fn parse_player_list(output: &str) -> Option<Vec<String>> {
+    let (head, names) = output.split_once(':')?;
+    if !head.contains("players online") {
+        return None;
+    }
+
+    let mut players = Vec::new();
+    for entry in names.split([',', '\n']) {
+        // Drop § formatting codes some servers put around names
+        let name = strip_formatting(entry);
+        // `list uuids` appends "(uuid)" after each name
+        if let Some(name) = name.split_whitespace().next() {
+            players.push(name.to_string());
+        }
+    }
+    Some(players)
+}
+
+/// Ticks per second out of the reply to `forge tps`, `neoforge tps` or Paper's `tps`:
+/// the overall figure where there's one per dimension, else the 1 minute average.
+///
+/// Handles "Overall: Mean tick time: 1.2 ms. Mean TPS: 20.000" (Forge),
+/// "Overall: 20.000 TPS (1.2 ms/tick)" (NeoForge) and
+/// "TPS from last 1m, 5m, 15m: *20.0, 19.9, 19.8" (Paper, `*` when capped).
+pub fn parse_tps(output: &str) -> Option<f64> {
+    let text = strip_formatting(output).replace('*', "");
+    let line = text
+        .lines()
+        .find(|l| l.trim_start().starts_with("Overall"))
+        .or_else(|| text.lines().find(|l| l.contains("TPS")))?;
+
+    let number = if let Some((_, rest)) = line.split_once("Mean TPS:") {
+        rest.split_whitespace().next()?
+    } else if let Some((_, rest)) = line.split_once("TPS from last") {
+        rest.split_once(':')?.1.split(',').next()?
+    } else {
+        line.split_once(" TPS")?.0.split_whitespace().last()?
+    };
+    number.trim().parse().ok()
+}
+
+/// `text` without § colour and format codes
+fn strip_formatting(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '§' {
+            chars.next();
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
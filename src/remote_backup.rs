@@ -0,0 +1,862 @@
+//! Offsite copies of backups: uploads finished archives to an S3-compatible bucket
+//! (AWS, Backblaze B2, MinIO...), a WebDAV folder or an SFTP host, and trims old
+//! uploads.
+//!
+//! Objects are stored as `<server name>/<backup file>`. S3 requests are signed with
+//! AWS Signature V4 and use path-style URLs, which every S3-compatible store accepts.
+//! SFTP runs on libssh2, which blocks, so its sessions live on tokio's blocking pool.
+//! It's behind the `sftp` cargo feature, since libssh2 and OpenSSL are built from C.
+
+use crate::backup;
+#[cfg(feature = "sftp")]
+use crate::config::get_sftp_known_hosts_path;
+use crate::config::{RemoteBackupKind, RemoteBackupSettings};
+use anyhow::{Context, Result};
+use hmac::{Hmac, Mac};
+use regex::Regex;
+use reqwest::{Method, Url};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+#[cfg(feature = "sftp")]
+use std::io::{Read, Write};
+#[cfg(feature = "sftp")]
+use std::net::TcpStream;
+use std::path::Path;
+#[cfg(feature = "sftp")]
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::io::AsyncReadExt;
+
+/// Archives up to this size go up in one request; larger ones as a multipart upload
+/// of parts this size (S3 caps single uploads at 5 GB)
+const PART_SIZE: u64 = 64 * 1024 * 1024;
+/// Read size when streaming a file to WebDAV or SFTP
+const STREAM_CHUNK: usize = 1024 * 1024;
+/// Longest wait for any single SSH operation
+#[cfg(feature = "sftp")]
+const SFTP_TIMEOUT_MS: u32 = 60_000;
+
+/// Called with (bytes sent, total bytes) as an upload goes
+pub type Progress = Arc<dyn Fn(u64, u64) + Send + Sync>;
+
+/// Whether the settings name a destination with the fields it needs filled in
+pub fn is_configured(settings: &RemoteBackupSettings) -> bool {
+    match settings.kind {
+        RemoteBackupKind::Disabled => false,
+        RemoteBackupKind::S3 => [
+            &settings.s3_endpoint,
+            &settings.s3_region,
+            &settings.s3_bucket,
+            &settings.s3_access_key,
+            &settings.s3_secret_key,
+        ]
+        .iter()
+        .all(|f| !f.trim().is_empty()),
+        RemoteBackupKind::WebDav => !settings.webdav_url.trim().is_empty(),
+        RemoteBackupKind::Sftp => {
+            !settings.sftp_host.trim().is_empty()
+                && !settings.sftp_username.trim().is_empty()
+                && (!settings.sftp_password.is_empty() || !settings.sftp_key_path.trim().is_empty())
+        }
+    }
+}
+
+/// Upload one backup archive of `server_name`
+pub async fn upload(
+    settings: &RemoteBackupSettings,
+    server_name: &str,
+    path: &Path,
+    progress: Progress,
+) -> Result<()> {
+    let filename = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .context("Backup path has no file name")?;
+    match settings.kind {
+        RemoteBackupKind::Disabled => anyhow::bail!("No remote backup destination is set up"),
+        RemoteBackupKind::S3 => {
+            S3::new(settings)?
+                .upload(&format!("{}/{}", server_name, filename), path, progress)
+                .await
+        }
+        RemoteBackupKind::WebDav => {
+            WebDav::new(settings)?
+                .upload(server_name, &filename, path, progress)
+                .await
+        }
+        RemoteBackupKind::Sftp => {
+            let settings = settings.clone();
+            let server_name = server_name.to_string();
+            let path = path.to_path_buf();
+            tokio::task::spawn_blocking(move || {
+                Sftp::connect(&settings)?.upload(&server_name, &filename, &path, progress)
+            })
+            .await?
+        }
+    }
+}
+
+/// Delete all but the newest `keep_last` uploads of a server, keeping the full backups
+/// that kept incrementals need. Returns how many went.
+pub async fn prune(settings: &RemoteBackupSettings, server_name: &str) -> Result<usize> {
+    if settings.keep_last == 0 {
+        return Ok(0);
+    }
+    if settings.kind == RemoteBackupKind::Sftp {
+        let settings = settings.clone();
+        let server_name = server_name.to_string();
+        return tokio::task::spawn_blocking(move || {
+            let sftp = Sftp::connect(&settings)?;
+            let names = sftp.list(&server_name)?;
+            let excess = oldest(names, settings.keep_last);
+            for name in &excess {
+                sftp.delete(&server_name, name)?;
+            }
+            Ok(excess.len())
+        })
+        .await?;
+    }
+    let names = match settings.kind {
+        RemoteBackupKind::S3 => S3::new(settings)?.list(server_name).await?,
+        RemoteBackupKind::WebDav => WebDav::new(settings)?.list(server_name).await?,
+        RemoteBackupKind::Disabled | RemoteBackupKind::Sftp => return Ok(0),
+    };
+    let excess = oldest(names, settings.keep_last);
+    for name in &excess {
+        match settings.kind {
+            RemoteBackupKind::S3 => {
+                S3::new(settings)?
+                    .delete(&format!("{}/{}", server_name, name))
+                    .await?
+            }
+            RemoteBackupKind::WebDav => WebDav::new(settings)?.delete(server_name, name).await?,
+            RemoteBackupKind::Disabled | RemoteBackupKind::Sftp => {}
+        }
+    }
+    Ok(excess.len())
+}
+
+/// The uploads beyond the newest `keep_last`. As in [`backup::prune_backups`], a full
+/// backup stays while a kept incremental builds on it; an incremental's base is the
+/// newest full backup before it, which is the one it was taken against.
+fn oldest(mut names: Vec<String>, keep_last: u32) -> Vec<String> {
+    // Backup names start with their timestamp, so name order is age order
+    names.sort();
+    let excess = names.len().saturating_sub(keep_last as usize);
+    let needed: HashSet<String> = names[excess..]
+        .iter()
+        .filter(|name| backup::is_incremental_backup(name))
+        .filter_map(|incremental| {
+            names
+                .iter()
+                .take_while(|name| *name < incremental)
+                .filter(|name| !backup::is_incremental_backup(name))
+                .last()
+                .cloned()
+        })
+        .collect();
+    names.truncate(excess);
+    names.retain(|name| !needed.contains(name));
+    names
+}
+
+/// Fail with the server's reply unless the request succeeded
+async fn check(response: reqwest::Response, what: &str) -> Result<reqwest::Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let body = response.text().await.unwrap_or_default();
+    let detail: String = body.chars().take(300).collect();
+    anyhow::bail!("{} failed with HTTP {}: {}", what, status, detail.trim())
+}
+
+/// Percent-encode everything but RFC 3986 unreserved characters (and `/` unless
+/// `encode_slash`), as both SigV4 and WebDAV paths expect
+fn uri_encode(value: &str, encode_slash: bool) -> String {
+    let mut out = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                out.push(byte as char)
+            }
+            b'/' if !encode_slash => out.push('/'),
+            _ => out.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    out
+}
+
+fn xml_unescape(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Text of every `<tag>` element, ignoring any namespace prefix
+fn xml_values(xml: &str, tag: &str) -> Vec<String> {
+    let pattern = format!(r"<(?:\w+:)?{0}(?:\s[^>]*)?>([^<]*)</(?:\w+:)?{0}>", tag);
+    let Ok(re) = Regex::new(&pattern) else {
+        return Vec::new();
+    };
+    re.captures_iter(xml).map(|c| xml_unescape(&c[1])).collect()
+}
+
+// ---------------------------------------------------------------------------
+// S3-compatible storage
+// ---------------------------------------------------------------------------
+
+struct S3<'a> {
+    settings: &'a RemoteBackupSettings,
+    endpoint: Url,
+    client: reqwest::Client,
+}
+
+impl<'a> S3<'a> {
+    fn new(settings: &'a RemoteBackupSettings) -> Result<Self> {
+        let endpoint = Url::parse(settings.s3_endpoint.trim().trim_end_matches('/'))
+            .context("Invalid S3 endpoint URL")?;
+        let client = crate::http::client_builder()
+            .build()
+            .context("Failed to build HTTP client")?;
+        Ok(Self {
+            settings,
+            endpoint,
+            client,
+        })
+    }
+
+    /// Send a signed request for `key` in the bucket (`""` for the bucket itself)
+    async fn send(
+        &self,
+        method: Method,
+        key: &str,
+        query: &[(&str, &str)],
+        body: Vec<u8>,
+    ) -> Result<reqwest::Response> {
+        let host = match self.endpoint.port() {
+            Some(port) => format!("{}:{}", self.endpoint.host_str().unwrap_or_default(), port),
+            None => self.endpoint.host_str().unwrap_or_default().to_string(),
+        };
+        let base_path = self.endpoint.path().trim_end_matches('/');
+        let mut canonical_uri = format!(
+            "{}/{}",
+            base_path,
+            uri_encode(self.settings.s3_bucket.trim(), true)
+        );
+        if !key.is_empty() {
+            canonical_uri = format!("{}/{}", canonical_uri, uri_encode(key, false));
+        }
+        let mut query: Vec<(String, String)> = query
+            .iter()
+            .map(|(k, v)| (uri_encode(k, true), uri_encode(v, true)))
+            .collect();
+        query.sort();
+        let canonical_query = query
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<_>>()
+            .join("&");
+
+        let now = chrono::Utc::now();
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let date = now.format("%Y%m%d").to_string();
+        let payload_hash = hex::encode(Sha256::digest(&body));
+        let authorization = self.authorization(
+            method.as_str(),
+            &host,
+            &canonical_uri,
+            &canonical_query,
+            &payload_hash,
+            &amz_date,
+            &date,
+        );
+
+        let mut url = format!("{}://{}{}", self.endpoint.scheme(), host, canonical_uri);
+        if !canonical_query.is_empty() {
+            url = format!("{}?{}", url, canonical_query);
+        }
+        let response = self
+            .client
+            .request(method, url)
+            .header("x-amz-date", amz_date)
+            .header("x-amz-content-sha256", payload_hash)
+            .header("authorization", authorization)
+            .body(body)
+            .send()
+            .await
+            .context("S3 request failed")?;
+        Ok(response)
+    }
+
+    /// AWS Signature V4 `Authorization` header over host, date and payload hash
+    #[allow(clippy::too_many_arguments)]
+    fn authorization(
+        &self,
+        method: &str,
+        host: &str,
+        canonical_uri: &str,
+        canonical_query: &str,
+        payload_hash: &str,
+        amz_date: &str,
+        date: &str,
+    ) -> String {
+        let region = self.settings.s3_region.trim();
+        let signed_headers = "host;x-amz-content-sha256;x-amz-date";
+        let canonical_request = format!(
+            "{}\n{}\n{}\nhost:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\n\n{}\n{}",
+            method,
+            canonical_uri,
+            canonical_query,
+            host,
+            payload_hash,
+            amz_date,
+            signed_headers,
+            payload_hash
+        );
+        let scope = format!("{}/{}/s3/aws4_request", date, region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            amz_date,
+            scope,
+            hex::encode(Sha256::digest(canonical_request.as_bytes()))
+        );
+
+        let secret = format!("AWS4{}", self.settings.s3_secret_key.trim());
+        let mut key = hmac_sha256(secret.as_bytes(), date.as_bytes());
+        for part in [region, "s3", "aws4_request"] {
+            key = hmac_sha256(&key, part.as_bytes());
+        }
+        let signature = hex::encode(hmac_sha256(&key, string_to_sign.as_bytes()));
+
+        format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            self.settings.s3_access_key.trim(),
+            scope,
+            signed_headers,
+            signature
+        )
+    }
+
+    async fn upload(&self, key: &str, path: &Path, progress: Progress) -> Result<()> {
+        let mut file = tokio::fs::File::open(path)
+            .await
+            .context("Failed to open backup for upload")?;
+        let total = file.metadata().await?.len();
+
+        if total <= PART_SIZE {
+            let mut body = Vec::with_capacity(total as usize);
+            file.read_to_end(&mut body).await?;
+            check(self.send(Method::PUT, key, &[], body).await?, "Upload").await?;
+            progress(total, total);
+            return Ok(());
+        }
+
+        let response = check(
+            self.send(Method::POST, key, &[("uploads", "")], Vec::new())
+                .await?,
+            "Starting multipart upload",
+        )
+        .await?;
+        let upload_id = xml_values(&response.text().await?, "UploadId")
+            .into_iter()
+            .next()
+            .context("S3 didn't return an upload ID")?;
+
+        match self
+            .upload_parts(key, &upload_id, &mut file, total, progress)
+            .await
+        {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                // Otherwise the store keeps (and bills for) the parts already sent
+                let _ = self
+                    .send(Method::DELETE, key, &[("uploadId", &upload_id)], Vec::new())
+                    .await;
+                Err(e)
+            }
+        }
+    }
+
+    async fn upload_parts(
+        &self,
+        key: &str,
+        upload_id: &str,
+        file: &mut tokio::fs::File,
+        total: u64,
+        progress: Progress,
+    ) -> Result<()> {
+        let mut etags = Vec::new();
+        let mut sent = 0u64;
+        while sent < total {
+            let mut part = Vec::with_capacity(PART_SIZE.min(total - sent) as usize);
+            (&mut *file).take(PART_SIZE).read_to_end(&mut part).await?;
+            if part.is_empty() {
+                anyhow::bail!("Backup file shrank during upload");
+            }
+            let len = part.len() as u64;
+            let number = (etags.len() + 1).to_string();
+            let response = check(
+                self.send(
+                    Method::PUT,
+                    key,
+                    &[("partNumber", &number), ("uploadId", upload_id)],
+                    part,
+                )
+                .await?,
+                "Uploading part",
+            )
+            .await?;
+            let etag = response
+                .headers()
+                .get("etag")
+                .and_then(|v| v.to_str().ok())
+                .context("S3 didn't return a part ETag")?
+                .to_string();
+            etags.push(etag);
+            sent += len;
+            progress(sent, total);
+        }
+
+        let parts: String = etags
+            .iter()
+            .enumerate()
+            .map(|(i, etag)| {
+                format!(
+                    "<Part><PartNumber>{}</PartNumber><ETag>{}</ETag></Part>",
+                    i + 1,
+                    etag
+                )
+            })
+            .collect();
+        let body = format!(
+            "<CompleteMultipartUpload>{}</CompleteMultipartUpload>",
+            parts
+        );
+        let response = check(
+            self.send(
+                Method::POST,
+                key,
+                &[("uploadId", upload_id)],
+                body.into_bytes(),
+            )
+            .await?,
+            "Completing multipart upload",
+        )
+        .await?;
+        // Completion can fail after a 200, with the error in the body
+        let text = response.text().await.unwrap_or_default();
+        if text.contains("<Error>") {
+            let message = xml_values(&text, "Message").join("; ");
+            anyhow::bail!("Completing multipart upload failed: {}", message);
+        }
+        Ok(())
+    }
+
+    /// Backup file names stored for a server
+    async fn list(&self, server_name: &str) -> Result<Vec<String>> {
+        let prefix = format!("{}/", server_name);
+        let mut names = Vec::new();
+        let mut token: Option<String> = None;
+        loop {
+            let mut query = vec![("list-type", "2"), ("prefix", prefix.as_str())];
+            if let Some(token) = &token {
+                query.push(("continuation-token", token.as_str()));
+            }
+            let response = check(
+                self.send(Method::GET, "", &query, Vec::new()).await?,
+                "Listing backups",
+            )
+            .await?;
+            let xml = response.text().await?;
+            names.extend(
+                xml_values(&xml, "Key")
+                    .into_iter()
+                    .filter_map(|k| k.strip_prefix(&prefix).map(str::to_string))
+                    .filter(|n| !n.contains('/') && n.ends_with(".zip")),
+            );
+            token = xml_values(&xml, "NextContinuationToken").into_iter().next();
+            if token.is_none() {
+                return Ok(names);
+            }
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        check(
+            self.send(Method::DELETE, key, &[], Vec::new()).await?,
+            "Deleting old backup",
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC takes keys of any length");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+// ---------------------------------------------------------------------------
+// WebDAV
+// ---------------------------------------------------------------------------
+
+struct WebDav<'a> {
+    settings: &'a RemoteBackupSettings,
+    client: reqwest::Client,
+}
+
+impl<'a> WebDav<'a> {
+    fn new(settings: &'a RemoteBackupSettings) -> Result<Self> {
+        let client = crate::http::client_builder()
+            .build()
+            .context("Failed to build HTTP client")?;
+        Ok(Self { settings, client })
+    }
+
+    /// URL of the server's folder, or of a file in it
+    fn url(&self, server_name: &str, filename: Option<&str>) -> String {
+        let base = self.settings.webdav_url.trim().trim_end_matches('/');
+        match filename {
+            Some(filename) => format!(
+                "{}/{}/{}",
+                base,
+                uri_encode(server_name, true),
+                uri_encode(filename, true)
+            ),
+            None => format!("{}/{}/", base, uri_encode(server_name, true)),
+        }
+    }
+
+    fn request(&self, method: Method, url: String) -> reqwest::RequestBuilder {
+        let request = self.client.request(method, url);
+        if self.settings.webdav_username.is_empty() {
+            request
+        } else {
+            request.basic_auth(
+                &self.settings.webdav_username,
+                Some(&self.settings.webdav_password),
+            )
+        }
+    }
+
+    async fn upload(
+        &self,
+        server_name: &str,
+        filename: &str,
+        path: &Path,
+        progress: Progress,
+    ) -> Result<()> {
+        // 405 means the folder is already there
+        let mkcol = Method::from_bytes(b"MKCOL").expect("valid method");
+        let response = self
+            .request(mkcol, self.url(server_name, None))
+            .send()
+            .await
+            .context("WebDAV request failed")?;
+        if !response.status().is_success() && response.status().as_u16() != 405 {
+            check(response, "Creating the server's folder").await?;
+        }
+
+        let file = tokio::fs::File::open(path)
+            .await
+            .context("Failed to open backup for upload")?;
+        let total = file.metadata().await?.len();
+        let stream = futures_util::stream::unfold((file, 0u64), move |(mut file, sent)| {
+            let progress = progress.clone();
+            async move {
+                let mut buf = vec![0u8; STREAM_CHUNK];
+                match file.read(&mut buf).await {
+                    Ok(0) => None,
+                    Ok(n) => {
+                        buf.truncate(n);
+                        let sent = sent + n as u64;
+                        progress(sent, total);
+                        Some((Ok::<_, std::io::Error>(buf), (file, sent)))
+                    }
+                    // The request is abandoned at the first error, so the stream isn't polled again
+                    Err(e) => Some((Err(e), (file, sent))),
+                }
+            }
+        });
+
+        let response = self
+            .request(Method::PUT, self.url(server_name, Some(filename)))
+            .header("content-length", total)
+            .body(reqwest::Body::wrap_stream(stream))
+            .send()
+            .await
+            .context("WebDAV upload failed")?;
+        check(response, "Upload").await?;
+        Ok(())
+    }
+
+    /// Backup file names stored for a server
+    async fn list(&self, server_name: &str) -> Result<Vec<String>> {
+        let propfind = Method::from_bytes(b"PROPFIND").expect("valid method");
+        let response = self
+            .request(propfind, self.url(server_name, None))
+            .header("depth", "1")
+            .send()
+            .await
+            .context("WebDAV request failed")?;
+        let xml = check(response, "Listing backups").await?.text().await?;
+        Ok(xml_values(&xml, "href")
+            .into_iter()
+            .filter_map(|href| {
+                let name = href.trim_end_matches('/').rsplit('/').next()?.to_string();
+                let name = percent_decode(&name);
+                name.ends_with(".zip").then_some(name)
+            })
+            .collect())
+    }
+
+    async fn delete(&self, server_name: &str, filename: &str) -> Result<()> {
+        let response = self
+            .request(Method::DELETE, self.url(server_name, Some(filename)))
+            .send()
+            .await
+            .context("WebDAV request failed")?;
+        check(response, "Deleting old backup").await?;
+        Ok(())
+    }
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            if let Some(Ok(byte)) = value.get(i + 1..i + 3).map(|h| u8::from_str_radix(h, 16)) {
+                out.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).to_string()
+}
+
+// ---------------------------------------------------------------------------
+// SFTP
+// ---------------------------------------------------------------------------
+
+#[cfg(feature = "sftp")]
+struct Sftp {
+    sftp: ssh2::Sftp,
+    /// Folder holding a subfolder per server; empty for the login directory
+    root: String,
+    // Dropped after `sftp`, which needs the session to close cleanly
+    _session: ssh2::Session,
+}
+
+#[cfg(feature = "sftp")]
+impl Sftp {
+    fn connect(settings: &RemoteBackupSettings) -> Result<Self> {
+        let host = settings.sftp_host.trim();
+        let port = settings.sftp_port;
+        let tcp = TcpStream::connect((host, port))
+            .with_context(|| format!("Failed to connect to {}:{}", host, port))?;
+        let mut session = ssh2::Session::new().context("Failed to start SSH session")?;
+        session.set_tcp_stream(tcp);
+        session.set_timeout(SFTP_TIMEOUT_MS);
+        session.handshake().context("SSH handshake failed")?;
+        verify_host_key(&session, host, port)?;
+
+        let user = settings.sftp_username.trim();
+        let key_path = settings.sftp_key_path.trim();
+        if key_path.is_empty() {
+            session.userauth_password(user, &settings.sftp_password)
+        } else {
+            let passphrase = Some(settings.sftp_password.as_str()).filter(|p| !p.is_empty());
+            session.userauth_pubkey_file(user, None, Path::new(key_path), passphrase)
+        }
+        .context("SSH login failed")?;
+
+        let sftp = session
+            .sftp()
+            .context("The host doesn't offer SFTP for this account")?;
+        Ok(Self {
+            sftp,
+            root: settings.sftp_dir.trim().trim_end_matches('/').to_string(),
+            _session: session,
+        })
+    }
+
+    /// Remote folder for a server's backups. Built with `/`, since the remote side
+    /// doesn't follow the local path separator.
+    fn server_dir(&self, server_name: &str) -> String {
+        if self.root.is_empty() {
+            server_name.to_string()
+        } else {
+            format!("{}/{}", self.root, server_name)
+        }
+    }
+
+    /// Create `dir` and any missing parents
+    fn create_dir_all(&self, dir: &str) -> Result<()> {
+        let mut current = if dir.starts_with('/') {
+            String::from("/")
+        } else {
+            String::new()
+        };
+        for part in dir.split('/').filter(|p| !p.is_empty()) {
+            if !current.is_empty() && !current.ends_with('/') {
+                current.push('/');
+            }
+            current.push_str(part);
+            if self.sftp.stat(Path::new(&current)).is_err() {
+                self.sftp
+                    .mkdir(Path::new(&current), 0o755)
+                    .with_context(|| format!("Failed to create remote folder {}", current))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn upload(
+        &self,
+        server_name: &str,
+        filename: &str,
+        path: &Path,
+        progress: Progress,
+    ) -> Result<()> {
+        let dir = self.server_dir(server_name);
+        self.create_dir_all(&dir)?;
+        let mut file = std::fs::File::open(path).context("Failed to open backup for upload")?;
+        let total = file.metadata()?.len();
+
+        // Written under a temporary name, so an interrupted upload is never listed
+        // (or kept by pruning) as a backup
+        let partial = PathBuf::from(format!("{}/{}.part", dir, filename));
+        let target = PathBuf::from(format!("{}/{}", dir, filename));
+        let result = (|| -> Result<()> {
+            let mut remote = self
+                .sftp
+                .create(&partial)
+                .context("Failed to create remote file")?;
+            let mut buf = vec![0u8; STREAM_CHUNK];
+            let mut sent = 0u64;
+            loop {
+                let n = file.read(&mut buf)?;
+                if n == 0 {
+                    break;
+                }
+                remote.write_all(&buf[..n]).context("SFTP upload failed")?;
+                sent += n as u64;
+                progress(sent, total);
+            }
+            drop(remote);
+            self.sftp
+                .rename(&partial, &target, None)
+                .context("Failed to move the upload into place")
+        })();
+        if result.is_err() {
+            let _ = self.sftp.unlink(&partial);
+        }
+        result
+    }
+
+    /// Backup file names stored for a server
+    fn list(&self, server_name: &str) -> Result<Vec<String>> {
+        let entries = self
+            .sftp
+            .readdir(Path::new(&self.server_dir(server_name)))
+            .context("Listing backups failed")?;
+        Ok(entries
+            .into_iter()
+            .filter(|(_, stat)| stat.is_file())
+            .filter_map(|(path, _)| Some(path.file_name()?.to_string_lossy().to_string()))
+            .filter(|name| name.ends_with(".zip"))
+            .collect())
+    }
+
+    fn delete(&self, server_name: &str, filename: &str) -> Result<()> {
+        let path = format!("{}/{}", self.server_dir(server_name), filename);
+        self.sftp
+            .unlink(Path::new(&path))
+            .context("Deleting old backup failed")
+    }
+}
+
+/// Check the host's key against `~/.ssh/known_hosts` and the app's own list. A host
+/// seen for the first time is trusted and remembered; a changed key is refused.
+#[cfg(feature = "sftp")]
+fn verify_host_key(session: &ssh2::Session, host: &str, port: u16) -> Result<()> {
+    use ssh2::{CheckResult, KnownHostFileKind};
+
+    let (key, key_type) = session.host_key().context("The host sent no SSH key")?;
+    let mut known = session.known_hosts()?;
+    let app_file = get_sftp_known_hosts_path();
+    let user_file = std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(".ssh").join("known_hosts"));
+    for file in user_file.iter().chain(std::iter::once(&app_file)) {
+        if file.exists() {
+            // A file libssh2 can't parse just doesn't vouch for anything
+            let _ = known.read_file(file, KnownHostFileKind::OpenSSH);
+        }
+    }
+
+    match known.check_port(host, port, key) {
+        CheckResult::Match => Ok(()),
+        CheckResult::Mismatch => anyhow::bail!(
+            "The SSH host key of {} has changed. If that's expected, remove its line from {} \
+             (or ~/.ssh/known_hosts) and try again.",
+            host,
+            app_file.display()
+        ),
+        CheckResult::NotFound | CheckResult::Failure => {
+            let entry = if port == 22 {
+                host.to_string()
+            } else {
+                format!("[{}]:{}", host, port)
+            };
+            // Only the app's own file is rewritten, so start from it alone
+            let mut remembered = session.known_hosts()?;
+            if app_file.exists() {
+                let _ = remembered.read_file(&app_file, KnownHostFileKind::OpenSSH);
+            }
+            remembered.add(&entry, key, "added by DrakonixAnvil", key_type.into())?;
+            if let Some(parent) = app_file.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            remembered
+                .write_file(&app_file, KnownHostFileKind::OpenSSH)
+                .context("Failed to remember the SSH host key")?;
+            tracing::info!("Trusting new SSH host key for {}", entry);
+            Ok(())
+        }
+    }
+}
+
+/// Stand-in for builds without the `sftp` feature: connecting fails, so there's never
+/// a session to use
+#[cfg(not(feature = "sftp"))]
+enum Sftp {}
+
+#[cfg(not(feature = "sftp"))]
+impl Sftp {
+    fn connect(_settings: &RemoteBackupSettings) -> Result<Self> {
+        anyhow::bail!("This build has no SFTP support; rebuild with `--features sftp`")
+    }
+
+    fn upload(&self, _: &str, _: &str, _: &Path, _: Progress) -> Result<()> {
+        match *self {}
+    }
+
+    fn list(&self, _: &str) -> Result<Vec<String>> {
+        match *self {}
+    }
+
+    fn delete(&self, _: &str, _: &str) -> Result<()> {
+        match *self {}
+    }
+}
@@ -0,0 +1,148 @@
+//! Combined modpack search across CurseForge and Modrinth. Both APIs are queried
+//! concurrently and packs published on both platforms are merged into one result.
+
+use crate::curseforge::{self, CfMod, CfSortField};
+use crate::modrinth::{self, MrProject, MrSortIndex};
+use crate::server::ModLoader;
+
+/// One modpack, found on one or both platforms
+#[derive(Debug, Clone)]
+pub struct UnifiedResult {
+    pub title: String,
+    pub summary: String,
+    pub icon_url: Option<String>,
+    pub cf: Option<CfMod>,
+    pub mr: Option<MrProject>,
+}
+
+impl UnifiedResult {
+    /// Downloads summed across platforms, used for ordering
+    pub fn total_downloads(&self) -> u64 {
+        self.cf.as_ref().map(|m| m.download_count).unwrap_or(0)
+            + self.mr.as_ref().map(|p| p.downloads).unwrap_or(0)
+    }
+
+    /// Stable identity for selection (either platform's ID is enough)
+    pub fn key(&self) -> String {
+        match (&self.cf, &self.mr) {
+            (Some(m), _) => format!("cf:{}", m.id),
+            (None, Some(p)) => format!("mr:{}", p.project_id),
+            (None, None) => String::new(),
+        }
+    }
+}
+
+/// Reduce a pack name or slug to lowercase alphanumerics so "All the Mods 9"
+/// and "all-the-mods-9" compare equal
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+/// Merge both result lists, pairing packs whose slug or title match, ordered by downloads
+pub fn merge_results(cf: Vec<CfMod>, mr: Vec<MrProject>) -> Vec<UnifiedResult> {
+    let mut merged: Vec<UnifiedResult> = cf
+        .into_iter()
+        .map(|m| UnifiedResult {
+            title: m.name.clone(),
+            summary: m.summary.clone(),
+            icon_url: m.logo.as_ref().map(|l| l.thumbnail_url.clone()),
+            cf: Some(m),
+            mr: None,
+        })
+        .collect();
+
+    for project in mr {
+        let slug = normalize(&project.slug);
+        let title = normalize(&project.title);
+        let existing = merged.iter_mut().find(|r| {
+            r.mr.is_none()
+                && r.cf
+                    .as_ref()
+                    .is_some_and(|m| normalize(&m.slug) == slug || normalize(&m.name) == title)
+        });
+        match existing {
+            Some(r) => {
+                if r.icon_url.is_none() {
+                    r.icon_url = project.icon_url.clone();
+                }
+                r.mr = Some(project);
+            }
+            None => merged.push(UnifiedResult {
+                title: project.title.clone(),
+                summary: project.description.clone(),
+                icon_url: project.icon_url.clone(),
+                cf: None,
+                mr: Some(project),
+            }),
+        }
+    }
+
+    merged.sort_by_key(|r| std::cmp::Reverse(r.total_downloads()));
+    merged
+}
+
+/// Query both platforms at once. CurseForge is skipped without an API key.
+/// Returns the merged results plus one message per platform that failed.
+pub async fn search_all(
+    cf_api_key: Option<&str>,
+    mr_token: Option<&str>,
+    query: &str,
+    game_version: &str,
+    loader: Option<&ModLoader>,
+) -> (Vec<UnifiedResult>, Vec<String>) {
+    let mr_loader = match loader {
+        Some(ModLoader::Forge) => "forge",
+        Some(ModLoader::Fabric) => "fabric",
+        Some(ModLoader::NeoForge) => "neoforge",
+        _ => "",
+    };
+
+    let cf_search = async {
+        match cf_api_key {
+            Some(key) => Some(
+                curseforge::search_modpacks(
+                    key,
+                    query,
+                    game_version,
+                    loader,
+                    CfSortField::Popularity,
+                    0,
+                )
+                .await,
+            ),
+            None => None,
+        }
+    };
+    let mr_search = modrinth::search_modpacks(
+        mr_token,
+        query,
+        game_version,
+        mr_loader,
+        MrSortIndex::Relevance,
+        0,
+    );
+
+    let (cf_result, mr_result) = tokio::join!(cf_search, mr_search);
+
+    let mut errors = Vec::new();
+    let cf_mods = match cf_result {
+        Some(Ok((mods, _))) => mods,
+        Some(Err(e)) => {
+            errors.push(format!("CurseForge: {}", e));
+            Vec::new()
+        }
+        None => Vec::new(),
+    };
+    let mr_projects = match mr_result {
+        Ok((projects, _)) => projects,
+        Err(e) => {
+            errors.push(format!("Modrinth: {}", e));
+            Vec::new()
+        }
+    };
+
+    (merge_results(cf_mods, mr_projects), errors)
+}
@@ -1,4 +1,9 @@
+use crate::alerts::AlertRules;
+use crate::config::BackupCompression;
+use crate::jvm::GcBenchmark;
+use crate::query::QueryStats;
 use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServerConfig {
@@ -17,12 +22,160 @@ pub struct ServerConfig {
     /// Extra Docker environment variables (e.g. CF_EXCLUDE_MODS, CF_FORCE_SYNCHRONIZE)
     #[serde(default)]
     pub extra_env: Vec<String>,
+    /// Additional TCP ports published 1:1 on the host (e.g. Dynmap, BlueMap, voice chat)
+    #[serde(default)]
+    pub extra_ports: Vec<u16>,
+    /// Host interface the game and extra ports listen on (e.g. a VPN or Tailscale
+    /// IP); None listens on all interfaces
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bind_address: Option<IpAddr>,
+    /// When the Minecraft EULA was accepted for this server (RFC 3339).
+    /// EULA=TRUE is only passed to the container once this is set.
+    #[serde(default)]
+    pub eula_accepted_at: Option<String>,
+    /// Write GC pauses to logs/gc.log so runs with different collectors can be compared
+    #[serde(default)]
+    pub gc_logging: bool,
+    /// Pause statistics recorded when the server stops (most recent last)
+    #[serde(default)]
+    pub gc_benchmarks: Vec<GcBenchmark>,
+    /// Seconds from Starting to Running for recent starts (most recent last)
+    #[serde(default)]
+    pub startup_history: Vec<u64>,
+    /// Buttons shown above the console input
+    #[serde(default = "default_quick_commands")]
+    pub quick_commands: Vec<QuickCommand>,
+    /// Messages sent on a timer via RCON while the server is running
+    #[serde(default)]
+    pub broadcasts: Vec<ScheduledBroadcast>,
+    /// Servers sharing a group name (e.g. a proxy network with a shared economy)
+    /// are snapshotted together and restored as one consistent set
+    #[serde(default)]
+    pub backup_group: Option<String>,
+    /// File name of the newest crash report the user has opened; anything newer
+    /// flags the server on the dashboard
+    #[serde(default)]
+    pub crash_report_seen: Option<String>,
+    /// Automatic backups and how many of them are kept
+    #[serde(default)]
+    pub backup_schedule: BackupSchedule,
+    /// Upload this server's backups to the remote destination set in Settings
+    #[serde(default)]
+    pub remote_backup: bool,
+    /// Paths left out of backups, as globs relative to the data folder (e.g. `logs/**`)
+    #[serde(default)]
+    pub backup_excludes: Vec<String>,
+    /// Archive of a starting world, downloaded and extracted by the container
+    /// when the data folder has no world yet
+    #[serde(default)]
+    pub world_url: Option<String>,
+    /// Conditions that raise an alert (Settings → Alerts)
+    #[serde(default)]
+    pub alerts: AlertRules,
 }
 
 fn default_java_version() -> u8 {
     21
 }
 
+/// A console button that sends one RCON command
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct QuickCommand {
+    pub label: String,
+    pub command: String,
+}
+
+/// A recurring announcement, e.g. a rules reminder or restart notice
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScheduledBroadcast {
+    /// Full command to run, usually `say ...` or `tellraw @a ...`
+    pub command: String,
+    pub interval_minutes: u32,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+impl Default for ScheduledBroadcast {
+    fn default() -> Self {
+        Self {
+            command: "say ".to_string(),
+            interval_minutes: 30,
+            enabled: true,
+        }
+    }
+}
+
+/// When automatic backups are taken
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum BackupFrequency {
+    /// Every N hours after the previous scheduled backup
+    EveryHours(u32),
+    /// Once a day at this local time
+    DailyAt { hour: u8, minute: u8 },
+}
+
+/// Which scheduled backups survive pruning. A backup is kept if any rule keeps it.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct BackupRetention {
+    /// The newest N, whatever their age
+    pub keep_last: u32,
+    /// The newest backup of each of the last N days
+    pub keep_daily_days: u32,
+    /// The newest backup of each of the last N weeks
+    pub keep_weekly_weeks: u32,
+}
+
+impl Default for BackupRetention {
+    fn default() -> Self {
+        Self {
+            keep_last: 5,
+            keep_daily_days: 7,
+            keep_weekly_weeks: 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct BackupSchedule {
+    pub enabled: bool,
+    pub frequency: BackupFrequency,
+    #[serde(default)]
+    pub retention: BackupRetention,
+    /// Overrides the global backup compression for scheduled backups
+    #[serde(default)]
+    pub compression: Option<BackupCompression>,
+}
+
+impl Default for BackupSchedule {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            frequency: BackupFrequency::EveryHours(6),
+            retention: BackupRetention::default(),
+            compression: None,
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+pub fn default_quick_commands() -> Vec<QuickCommand> {
+    [
+        ("Save All", "save-all"),
+        ("Day", "time set day"),
+        ("Clear Weather", "weather clear"),
+        ("Whitelist On", "whitelist on"),
+    ]
+    .into_iter()
+    .map(|(label, command)| QuickCommand {
+        label: label.to_string(),
+        command: command.to_string(),
+    })
+    .collect()
+}
+
 /// Generate a memorable 4-word RCON password (like "correct-horse-battery-staple")
 fn generate_rcon_password() -> String {
     use rand::seq::SliceRandom;
@@ -96,11 +249,28 @@ pub enum ModpackSource {
         slug: String,
         file_id: u64,
     },
+    /// Same pack as `CurseForge`, but installed on the host from its client manifest
+    /// before the container starts, with client-only mods left out. For packs
+    /// AUTO_CURSEFORGE can't install (old packs, missing server files).
+    CurseForgeManifest {
+        slug: String,
+        file_id: u64,
+    },
+    /// Same pack's server pack (the file's `serverPackFileId`), downloaded and
+    /// extracted on the host. The container installs the loader found in it.
+    CurseForgeServerPack {
+        slug: String,
+        file_id: u64,
+        server_pack_file_id: u64,
+    },
     /// Installs a specific Forge version, then overlays a server pack zip (mods, configs, etc.)
     /// via GENERIC_PACK_URL. For older packs whose server zips lack a Forge jar or start script.
     ForgeWithPack {
         forge_version: String,
         pack_url: String,
+        /// Expected SHA-1 of the pack zip; verified after download when set
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pack_sha1: Option<String>,
     },
     #[serde(alias = "FTB")]
     Ftb {
@@ -114,12 +284,17 @@ pub enum ModpackSource {
     DirectDownload {
         url: String,
     },
+    /// Modrinth `.mrpack` (a file on this machine or a URL), installed on the host
+    /// before the container starts. For packs not published as server packs.
+    MrPackFile {
+        path_or_url: String,
+    },
     Local {
         path: String,
     },
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ServerProperties {
     pub motd: String,
     pub max_players: u32,
@@ -128,6 +303,29 @@ pub struct ServerProperties {
     pub pvp: bool,
     pub online_mode: bool,
     pub white_list: bool,
+    /// Render distance in chunks sent to clients
+    #[serde(default = "default_view_distance")]
+    pub view_distance: u32,
+}
+
+fn default_view_distance() -> u32 {
+    10
+}
+
+/// Matches the vanilla server.properties defaults
+impl Default for ServerProperties {
+    fn default() -> Self {
+        Self {
+            motd: String::new(),
+            max_players: 20,
+            difficulty: Difficulty::default(),
+            gamemode: GameMode::default(),
+            pvp: true,
+            online_mode: true,
+            white_list: false,
+            view_distance: default_view_distance(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
@@ -195,6 +393,17 @@ pub struct ServerInstance {
     pub status: ServerStatus,
 }
 
+/// What a running server answered to a status ping
+#[derive(Debug, Clone)]
+pub struct LiveStatus {
+    pub players_online: i64,
+    pub players_max: i64,
+    pub motd: String,
+    pub version: String,
+    /// Full player list, server software and plugins from the query port, if it answered
+    pub query: Option<QueryStats>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
 pub enum ServerStatus {
     #[default]
@@ -204,6 +413,7 @@ pub enum ServerStatus {
     Initializing, // Container running, MC server initializing (not yet accepting connections)
     Running,      // MC server accepting connections
     Stopping,
+    Frozen, // Container paused by the panic button after a save + snapshot
     Error(String),
 }
 
@@ -219,6 +429,21 @@ impl ServerConfig {
             rcon_password: generate_rcon_password(),
             java_version: default_java_version(),
             extra_env: vec![],
+            extra_ports: vec![],
+            bind_address: None,
+            eula_accepted_at: None,
+            gc_logging: false,
+            gc_benchmarks: vec![],
+            startup_history: vec![],
+            quick_commands: default_quick_commands(),
+            broadcasts: vec![],
+            backup_group: None,
+            crash_report_seen: None,
+            backup_schedule: BackupSchedule::default(),
+            remote_backup: false,
+            backup_excludes: vec![],
+            world_url: None,
+            alerts: AlertRules::default(),
         }
     }
 
@@ -239,15 +464,44 @@ impl ServerConfig {
         // RCON port is game port + 10 to avoid conflicts between servers
         self.port + 10
     }
+
+    /// Every host port this server publishes: game, RCON, then any extra ports
+    pub fn all_ports(&self) -> Vec<u16> {
+        let mut ports = vec![self.port, self.rcon_port()];
+        ports.extend(self.extra_ports.iter().copied());
+        ports
+    }
+
+    /// Host IP the game and extra ports are published on, for Docker
+    pub fn host_ip(&self) -> String {
+        self.bind_address
+            .unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED))
+            .to_string()
+    }
+
+    /// Bound to one specific interface rather than all of them
+    pub fn bound_address(&self) -> Option<IpAddr> {
+        self.bind_address.filter(|ip| !ip.is_unspecified())
+    }
+
+    /// `host:port` the app itself uses to reach the game port
+    pub fn local_address(&self) -> String {
+        let ip = self
+            .bound_address()
+            .unwrap_or(IpAddr::V4(Ipv4Addr::LOCALHOST));
+        SocketAddr::new(ip, self.port).to_string()
+    }
 }
 
 impl ServerConfig {
     /// Build Docker environment variables for the itzg/minecraft-server image
     pub fn build_docker_env(&self) -> Vec<String> {
-        let mut env = vec![
-            "EULA=TRUE".to_string(),
-            format!("MEMORY={}M", self.memory_mb),
-        ];
+        let eula = if self.eula_accepted_at.is_some() {
+            "EULA=TRUE"
+        } else {
+            "EULA=FALSE"
+        };
+        let mut env = vec![eula.to_string(), format!("MEMORY={}M", self.memory_mb)];
 
         // Set TYPE and loader-specific vars based on ModpackSource
         match &self.modpack.source {
@@ -269,6 +523,19 @@ impl ServerConfig {
                 }
                 // Note: CF_API_KEY should be set via global config, not here
             }
+            ModpackSource::CurseForgeManifest { .. }
+            | ModpackSource::CurseForgeServerPack { .. }
+            | ModpackSource::MrPackFile { .. } => {
+                // Mods are laid out on the host by pack_installer, which also
+                // replaces this with the loader version from the pack manifest
+                let type_str = match self.modpack.loader {
+                    ModLoader::Forge => "FORGE",
+                    ModLoader::Fabric => "FABRIC",
+                    ModLoader::NeoForge => "NEOFORGE",
+                    ModLoader::Vanilla => "VANILLA",
+                };
+                env.push(format!("TYPE={}", type_str));
+            }
             ModpackSource::ForgeWithPack { forge_version, .. } => {
                 // Pack extraction is handled on the host by pack_installer before
                 // the container starts. We only tell itzg to install Forge.
@@ -314,13 +581,19 @@ impl ServerConfig {
         }
 
         // Set JVM_OPTS if java_args are configured
-        if !self.java_args.is_empty() {
-            env.push(format!("JVM_OPTS={}", self.java_args.join(" ")));
+        let mut jvm_opts = self.java_args.clone();
+        if self.gc_logging && self.java_version > 8 {
+            jvm_opts.push(crate::jvm::gc_log_args());
+        }
+        if !jvm_opts.is_empty() {
+            env.push(format!("JVM_OPTS={}", jvm_opts.join(" ")));
         }
 
         // RCON settings (enabled by default in itzg/minecraft-server)
         env.push("ENABLE_RCON=true".to_string());
         env.push(format!("RCON_PASSWORD={}", self.rcon_password));
+        // GS4 query on the game port (UDP), for the full player list and plugins
+        env.push("ENABLE_QUERY=true".to_string());
 
         // Server properties
         let sp = &self.server_properties;
@@ -333,6 +606,12 @@ impl ServerConfig {
         env.push(format!("PVP={}", sp.pvp));
         env.push(format!("ONLINE_MODE={}", sp.online_mode));
         env.push(format!("ENABLE_WHITELIST={}", sp.white_list));
+        env.push(format!("VIEW_DISTANCE={}", sp.view_distance));
+
+        // Starting world; itzg skips the download once a world exists
+        if let Some(url) = &self.world_url {
+            env.push(format!("WORLD={}", url));
+        }
 
         // Extra env vars (e.g. CF_EXCLUDE_MODS for client-only mods)
         env.extend(self.extra_env.iter().cloned());
@@ -340,5 +619,3 @@ impl ServerConfig {
         env
     }
 }
-
-
@@ -0,0 +1,183 @@
+//! Keyboard shortcuts for common actions, rebindable in Settings and listed in Help.
+
+use eframe::egui;
+use serde::{Deserialize, Serialize};
+
+/// An action that can be bound to a key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shortcut {
+    NewServer,
+    ShowLogs,
+    FocusConsole,
+    SendCommand,
+    RefreshLogs,
+}
+
+impl Shortcut {
+    pub const ALL: [Shortcut; 5] = [
+        Shortcut::NewServer,
+        Shortcut::ShowLogs,
+        Shortcut::FocusConsole,
+        Shortcut::SendCommand,
+        Shortcut::RefreshLogs,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Shortcut::NewServer => "New server",
+            Shortcut::ShowLogs => "Switch to Logs",
+            Shortcut::FocusConsole => "Focus console input",
+            Shortcut::SendCommand => "Send console command",
+            Shortcut::RefreshLogs => "Refresh logs",
+        }
+    }
+
+    fn default_binding(self) -> KeyBinding {
+        match self {
+            Shortcut::NewServer => KeyBinding::command(egui::Key::N),
+            Shortcut::ShowLogs => KeyBinding::command(egui::Key::L),
+            Shortcut::FocusConsole => KeyBinding::command(egui::Key::K),
+            Shortcut::SendCommand => KeyBinding::command(egui::Key::Enter),
+            Shortcut::RefreshLogs => KeyBinding {
+                command: false,
+                shift: false,
+                alt: false,
+                key: egui::Key::F5.name().to_string(),
+            },
+        }
+    }
+}
+
+/// A key plus modifiers, stored by key name so settings stay readable
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyBinding {
+    /// Ctrl, or Cmd on macOS
+    pub command: bool,
+    pub shift: bool,
+    pub alt: bool,
+    /// egui key name, e.g. `N` or `F5`
+    pub key: String,
+}
+
+impl KeyBinding {
+    fn command(key: egui::Key) -> Self {
+        Self {
+            command: true,
+            shift: false,
+            alt: false,
+            key: key.name().to_string(),
+        }
+    }
+
+    /// None when the stored key name isn't one egui knows
+    pub fn to_egui(&self) -> Option<egui::KeyboardShortcut> {
+        let key = egui::Key::from_name(&self.key)?;
+        let mut modifiers = egui::Modifiers::NONE;
+        if self.command {
+            modifiers = modifiers | egui::Modifiers::COMMAND;
+        }
+        if self.shift {
+            modifiers = modifiers | egui::Modifiers::SHIFT;
+        }
+        if self.alt {
+            modifiers = modifiers | egui::Modifiers::ALT;
+        }
+        Some(egui::KeyboardShortcut::new(modifiers, key))
+    }
+
+    /// e.g. `Ctrl+N`, or `⌘N` on macOS
+    pub fn label(&self, ctx: &egui::Context) -> String {
+        match self.to_egui() {
+            Some(shortcut) => ctx.format_shortcut(&shortcut),
+            None => format!("{} (unknown key)", self.key),
+        }
+    }
+
+    /// The first key pressed this frame, with its modifiers, for rebinding.
+    /// Bare modifier presses don't count since egui doesn't report them as keys.
+    pub fn capture(input: &egui::InputState) -> Option<Self> {
+        input.events.iter().find_map(|event| match event {
+            egui::Event::Key {
+                key,
+                pressed: true,
+                modifiers,
+                ..
+            } => Some(Self {
+                command: modifiers.command,
+                shift: modifiers.shift,
+                alt: modifiers.alt,
+                key: key.name().to_string(),
+            }),
+            _ => None,
+        })
+    }
+}
+
+/// Bindings for every [`Shortcut`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShortcutSettings {
+    pub new_server: KeyBinding,
+    pub show_logs: KeyBinding,
+    pub focus_console: KeyBinding,
+    pub send_command: KeyBinding,
+    pub refresh_logs: KeyBinding,
+}
+
+impl Default for ShortcutSettings {
+    fn default() -> Self {
+        Self {
+            new_server: Shortcut::NewServer.default_binding(),
+            show_logs: Shortcut::ShowLogs.default_binding(),
+            focus_console: Shortcut::FocusConsole.default_binding(),
+            send_command: Shortcut::SendCommand.default_binding(),
+            refresh_logs: Shortcut::RefreshLogs.default_binding(),
+        }
+    }
+}
+
+impl ShortcutSettings {
+    pub fn get(&self, shortcut: Shortcut) -> &KeyBinding {
+        match shortcut {
+            Shortcut::NewServer => &self.new_server,
+            Shortcut::ShowLogs => &self.show_logs,
+            Shortcut::FocusConsole => &self.focus_console,
+            Shortcut::SendCommand => &self.send_command,
+            Shortcut::RefreshLogs => &self.refresh_logs,
+        }
+    }
+
+    pub fn set(&mut self, shortcut: Shortcut, binding: KeyBinding) {
+        let slot = match shortcut {
+            Shortcut::NewServer => &mut self.new_server,
+            Shortcut::ShowLogs => &mut self.show_logs,
+            Shortcut::FocusConsole => &mut self.focus_console,
+            Shortcut::SendCommand => &mut self.send_command,
+            Shortcut::RefreshLogs => &mut self.refresh_logs,
+        };
+        *slot = binding;
+    }
+
+    pub fn reset(&mut self, shortcut: Shortcut) {
+        self.set(shortcut, shortcut.default_binding());
+    }
+
+    pub fn is_default(&self, shortcut: Shortcut) -> bool {
+        *self.get(shortcut) == shortcut.default_binding()
+    }
+
+    /// Another action already using `binding`, so a rebind can warn about it
+    pub fn conflict(&self, shortcut: Shortcut, binding: &KeyBinding) -> Option<Shortcut> {
+        Shortcut::ALL
+            .into_iter()
+            .find(|other| *other != shortcut && self.get(*other) == binding)
+    }
+
+    /// Whether `shortcut` was pressed this frame; the key press is used up so
+    /// widgets like text fields don't also act on it
+    pub fn pressed(&self, ctx: &egui::Context, shortcut: Shortcut) -> bool {
+        self.get(shortcut)
+            .to_egui()
+            .is_some_and(|s| ctx.input_mut(|i| i.consume_shortcut(&s)))
+    }
+}
@@ -0,0 +1,124 @@
+//! Startup progress for servers that are still initializing, estimated from the
+//! milestones itzg's image, Forge/NeoForge and Fabric print to the container log.
+
+use regex::Regex;
+use std::time::{Duration, Instant};
+
+/// Progress of one server's startup, kept by the app while it's Initializing
+#[derive(Debug, Clone)]
+pub struct StartupProgress {
+    pub stage: String,
+    /// 0.0 ..= 1.0 across the whole startup
+    pub fraction: f32,
+    pub started: Instant,
+}
+
+impl Default for StartupProgress {
+    fn default() -> Self {
+        Self {
+            stage: "Starting container".to_string(),
+            fraction: 0.0,
+            started: Instant::now(),
+        }
+    }
+}
+
+impl StartupProgress {
+    /// Take a newer milestone. Progress never goes backwards, since the log tail
+    /// can drop earlier lines.
+    pub fn update(&mut self, milestone: Milestone) {
+        if milestone.fraction >= self.fraction {
+            self.stage = milestone.stage;
+            self.fraction = milestone.fraction;
+        }
+    }
+
+    /// Remaining time extrapolated from the time taken so far; `None` until
+    /// there's enough progress to go on
+    pub fn eta(&self) -> Option<Duration> {
+        if self.fraction < 0.05 || self.fraction >= 1.0 {
+            return None;
+        }
+        let elapsed = self.started.elapsed().as_secs_f32();
+        Some(Duration::from_secs_f32(
+            elapsed * (1.0 - self.fraction) / self.fraction,
+        ))
+    }
+}
+
+/// Starts kept per server for the typical startup time
+pub const STARTUP_HISTORY_LEN: usize = 10;
+
+/// Median of recorded startup times, so one slow first start (downloading the
+/// pack) doesn't skew it for long
+pub fn typical_startup(history: &[u64]) -> Option<Duration> {
+    let mut sorted = history.to_vec();
+    sorted.sort_unstable();
+    let mid = sorted.len() / 2;
+    let median = match sorted.len() {
+        0 => return None,
+        n if n % 2 == 0 => (sorted[mid - 1] + sorted[mid]) / 2,
+        _ => sorted[mid],
+    };
+    Some(Duration::from_secs(median))
+}
+
+/// The furthest point of startup found in the log
+#[derive(Debug, Clone, PartialEq)]
+pub struct Milestone {
+    pub stage: String,
+    pub fraction: f32,
+}
+
+/// Find the latest startup milestone in a chunk of container log
+pub fn parse(logs: &str) -> Option<Milestone> {
+    let download = Regex::new(r"(?i)download\w*\D*?(\d+)\s*(?:/|of)\s*(\d+)").ok()?;
+    let spawn = Regex::new(r"Preparing spawn area: (\d+)%").ok()?;
+
+    let mut best: Option<Milestone> = None;
+    for line in logs.lines() {
+        let milestone = if line.contains("Done (") && line.contains(")!") {
+            Some(("Done".to_string(), 1.0))
+        } else if let Some(c) = spawn.captures(line) {
+            let percent: f32 = c[1].parse().unwrap_or(0.0);
+            Some((
+                format!("Preparing spawn area {}%", percent),
+                0.8 + 0.18 * (percent / 100.0).min(1.0),
+            ))
+        } else if line.contains("Preparing level") {
+            Some(("Preparing world".to_string(), 0.8))
+        } else if line.contains("Loading") && line.contains(" mods") {
+            Some(("Loading mods".to_string(), 0.6))
+        } else if line.contains("Starting the Minecraft server")
+            || line.contains("Starting minecraft server version")
+        {
+            Some(("Starting Minecraft".to_string(), 0.5))
+        } else if line.contains("Installing Forge")
+            || line.contains("Installing NeoForge")
+            || line.contains("Installing Fabric")
+            || line.contains("Running installer")
+        {
+            Some(("Installing mod loader".to_string(), 0.42))
+        } else if let Some(c) = download.captures(line) {
+            let done: f32 = c[1].parse().unwrap_or(0.0);
+            let total: f32 = c[2].parse().unwrap_or(0.0);
+            (total > 0.0 && done <= total).then(|| {
+                (
+                    format!("Downloading {}/{}", done, total),
+                    0.05 + 0.35 * done / total,
+                )
+            })
+        } else if line.contains("[init]") {
+            Some(("Preparing server files".to_string(), 0.02))
+        } else {
+            None
+        };
+
+        if let Some((stage, fraction)) = milestone {
+            if best.as_ref().is_none_or(|b| fraction >= b.fraction) {
+                best = Some(Milestone { stage, fraction });
+            }
+        }
+    }
+    best
+}
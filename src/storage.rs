@@ -0,0 +1,278 @@
+//! Moving DrakonixAnvil's data (servers, backups, caches, logs) to another folder,
+//! picked in Settings → Storage. Settings stay in the profile's folder so the app
+//! can always find where the data went.
+
+use crate::backup::{self, BackupProgress};
+use crate::config::profile_root;
+use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::Sender;
+
+/// Files that stay in the profile's folder when the data moves. The default
+/// profile's folder also holds the other profiles.
+const KEPT: &[&str] = &[
+    "settings.json",
+    "sftp_known_hosts",
+    "profiles",
+    "profiles.json",
+];
+
+/// Name of the data folder made inside the folder the user picks
+const FOLDER_NAME: &str = "DrakonixAnvilData";
+
+/// What moving the data folder will do, for the confirmation view
+#[derive(Debug, Clone)]
+pub struct MovePlan {
+    pub from: PathBuf,
+    pub to: PathBuf,
+    /// Top-level files and folders moved, by name
+    pub entries: Vec<String>,
+    /// Bytes to move
+    pub size: u64,
+    /// Free space at the destination, if it could be read
+    pub available: Option<u64>,
+    /// What's under each entry, in the order of `entries`, to check copies against
+    sizes: Vec<TreeSize>,
+}
+
+impl MovePlan {
+    /// Whether the data fits at the destination. A move within one disk needs
+    /// no space, but that can't be known until the rename is tried.
+    pub fn fits(&self) -> bool {
+        self.available.is_none_or(|free| free >= self.size)
+    }
+
+    /// The setting to save once the data is in `to`: None when it's the default folder
+    pub fn setting(&self) -> Option<String> {
+        let default = std::path::absolute(profile_root()).ok();
+        (default.as_ref() != Some(&self.to)).then(|| self.to.to_string_lossy().into_owned())
+    }
+}
+
+/// Folder the data goes into when `picked` is chosen: a `DrakonixAnvilData`
+/// folder inside it, unless it already is one
+pub fn target_for(picked: &Path) -> PathBuf {
+    if picked.file_name().is_some_and(|n| n == FOLDER_NAME) {
+        picked.to_path_buf()
+    } else {
+        picked.join(FOLDER_NAME)
+    }
+}
+
+/// Files, folders and symlinks under a path (the path included), and the bytes in its files
+#[derive(Debug, Clone, Copy, Default)]
+struct TreeSize {
+    items: u64,
+    bytes: u64,
+}
+
+fn tree_size(path: &Path) -> TreeSize {
+    let mut size = TreeSize::default();
+    for entry in walkdir::WalkDir::new(path).into_iter().flatten() {
+        size.items += 1;
+        if entry.file_type().is_file() {
+            size.bytes += entry.metadata().map(|m| m.len()).unwrap_or(0);
+        }
+    }
+    size
+}
+
+/// Copy `from` to `to` as it is: folders (empty ones too), files, and symlinks as
+/// symlinks rather than what they point at
+fn copy_tree(
+    from: &Path,
+    to: &Path,
+    progress_tx: Option<&Sender<BackupProgress>>,
+    total: usize,
+) -> Result<TreeSize> {
+    let mut copied = TreeSize::default();
+    for entry in walkdir::WalkDir::new(from) {
+        let entry = entry?;
+        let rel = entry.path().strip_prefix(from)?;
+        let target = if rel.as_os_str().is_empty() {
+            to.to_path_buf()
+        } else {
+            to.join(rel)
+        };
+        let file_type = entry.file_type();
+        if let Some(tx) = progress_tx {
+            let _ = tx.send(BackupProgress {
+                current: copied.items as usize + 1,
+                total,
+                current_file: rel.to_string_lossy().into_owned(),
+            });
+        }
+        if file_type.is_dir() {
+            std::fs::create_dir_all(&target)
+                .with_context(|| format!("Failed to create {}", target.display()))?;
+        } else if file_type.is_symlink() {
+            copy_symlink(entry.path(), &target)
+                .with_context(|| format!("Failed to copy the link {}", entry.path().display()))?;
+        } else {
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            copied.bytes += std::fs::copy(entry.path(), &target)
+                .with_context(|| format!("Failed to copy {}", entry.path().display()))?;
+        }
+        copied.items += 1;
+    }
+    Ok(copied)
+}
+
+#[cfg(unix)]
+fn copy_symlink(link: &Path, target: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(std::fs::read_link(link)?, target)
+}
+
+#[cfg(windows)]
+fn copy_symlink(link: &Path, target: &Path) -> std::io::Result<()> {
+    let points_at = std::fs::read_link(link)?;
+    if std::fs::metadata(link).is_ok_and(|m| m.is_dir()) {
+        std::os::windows::fs::symlink_dir(points_at, target)
+    } else {
+        std::os::windows::fs::symlink_file(points_at, target)
+    }
+}
+
+/// Check that the data in `from` can move to `to` and work out how much there is.
+/// `to` must be empty apart from settings files, and neither folder may hold the other.
+pub fn plan(from: &Path, to: &Path) -> Result<MovePlan> {
+    let from = std::path::absolute(from).context("Failed to resolve the current data folder")?;
+    let to = std::path::absolute(to).context("Failed to resolve the new data folder")?;
+    if from == to {
+        anyhow::bail!("The data is already in {}", to.display());
+    }
+    if to.starts_with(&from) || from.starts_with(&to) {
+        anyhow::bail!("The new folder can't be inside the current one, or contain it");
+    }
+    if let Ok(existing) = std::fs::read_dir(&to) {
+        let clutter = existing
+            .flatten()
+            .any(|e| !KEPT.iter().any(|k| e.file_name() == *k));
+        if clutter {
+            anyhow::bail!(
+                "{} already has files in it; pick an empty folder",
+                to.display()
+            );
+        }
+    }
+
+    let mut entries: Vec<String> = std::fs::read_dir(&from)
+        .with_context(|| format!("Failed to read {}", from.display()))?
+        .flatten()
+        .map(|e| e.file_name().to_string_lossy().into_owned())
+        .filter(|name| !KEPT.contains(&name.as_str()))
+        .collect();
+    entries.sort();
+    let sizes: Vec<TreeSize> = entries
+        .iter()
+        .map(|name| tree_size(&from.join(name)))
+        .collect();
+    let size = sizes.iter().map(|s| s.bytes).sum();
+    let available = backup::available_space(&to).ok();
+    Ok(MovePlan {
+        from,
+        to,
+        entries,
+        size,
+        available,
+        sizes,
+    })
+}
+
+/// Move everything in the plan. Each entry is renamed when both folders are on
+/// one disk, otherwise copied. If a copy fails or comes up short of what the plan
+/// counted, everything is put back and the data stays where it was. Returns the
+/// entries whose old copy couldn't be deleted afterwards (e.g. a log file still
+/// open), which are left behind.
+pub fn move_data(
+    plan: &MovePlan,
+    progress_tx: Option<Sender<BackupProgress>>,
+) -> Result<Vec<String>> {
+    std::fs::create_dir_all(&plan.to)
+        .with_context(|| format!("Failed to create {}", plan.to.display()))?;
+
+    let total = plan.sizes.iter().map(|s| s.items as usize).sum();
+    let mut renamed = Vec::new();
+    let mut copied = Vec::new();
+    for (name, planned) in plan.entries.iter().zip(&plan.sizes) {
+        let from = plan.from.join(name);
+        let to = plan.to.join(name);
+        if std::fs::rename(&from, &to).is_ok() {
+            renamed.push(name);
+            continue;
+        }
+        copied.push(name);
+        let result = copy_tree(&from, &to, progress_tx.as_ref(), total).and_then(|done| {
+            // Logs may grow while they're copied, but nothing may go missing
+            if done.items < planned.items || done.bytes < planned.bytes {
+                anyhow::bail!(
+                    "only {} of {} items ({} of {} bytes) were copied",
+                    done.items,
+                    planned.items,
+                    done.bytes,
+                    planned.bytes
+                );
+            }
+            Ok(())
+        });
+        if let Err(e) = result {
+            for name in renamed {
+                let _ = std::fs::rename(plan.to.join(name), plan.from.join(name));
+            }
+            for name in copied {
+                let _ = remove(&plan.to.join(name));
+            }
+            return Err(e).with_context(|| format!("Failed to copy {}", name));
+        }
+    }
+
+    let mut left_behind = Vec::new();
+    for name in copied {
+        let path = plan.from.join(name);
+        if let Err(e) = remove(&path) {
+            tracing::warn!("Couldn't delete old {}: {}", path.display(), e);
+            left_behind.push(name.clone());
+        }
+    }
+    Ok(left_behind)
+}
+
+/// Delete a file, symlink or folder tree
+fn remove(path: &Path) -> std::io::Result<()> {
+    if path.is_symlink() {
+        // Windows removes links to folders as folders
+        std::fs::remove_file(path).or_else(|_| std::fs::remove_dir(path))
+    } else if path.is_dir() {
+        std::fs::remove_dir_all(path)
+    } else {
+        std::fs::remove_file(path)
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn copies_keep_symlinks_and_empty_folders() {
+        let root = std::env::temp_dir().join(format!("anvil-storage-{}", std::process::id()));
+        let from = root.join("from");
+        std::fs::create_dir_all(from.join("world/empty")).unwrap();
+        std::fs::write(from.join("world/level.dat"), b"level").unwrap();
+        std::os::unix::fs::symlink("level.dat", from.join("world/link.dat")).unwrap();
+
+        let to = root.join("to");
+        let copied = copy_tree(&from, &to, None, 0).unwrap();
+        let planned = tree_size(&from);
+        assert_eq!((copied.items, copied.bytes), (planned.items, planned.bytes));
+        assert!(to.join("world/empty").is_dir());
+        assert_eq!(
+            std::fs::read_link(to.join("world/link.dat")).unwrap(),
+            Path::new("level.dat")
+        );
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,211 @@
+//! Template health checks: confirms each template's pack still resolves (download
+//! URLs answer, Forge versions exist, CurseForge slugs and files, Modrinth and FTB
+//! versions) so broken templates are flagged before a server is created from them.
+
+use crate::preflight::CheckStatus;
+use crate::server::ModpackSource;
+use crate::templates::ModpackTemplate;
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+
+/// Every Forge release, used to confirm `ForgeWithPack` versions
+const FORGE_METADATA_URL: &str =
+    "https://maven.minecraftforge.net/net/minecraftforge/forge/maven-metadata.xml";
+
+#[derive(Debug, Clone)]
+pub struct TemplateHealth {
+    pub status: CheckStatus,
+    /// What failed, or what was checked
+    pub detail: String,
+}
+
+impl TemplateHealth {
+    fn ok(detail: impl Into<String>) -> Self {
+        Self {
+            status: CheckStatus::Pass,
+            detail: detail.into(),
+        }
+    }
+
+    fn warn(detail: impl Into<String>) -> Self {
+        Self {
+            status: CheckStatus::Warn,
+            detail: detail.into(),
+        }
+    }
+
+    fn fail(detail: impl Into<String>) -> Self {
+        Self {
+            status: CheckStatus::Fail,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Check every template, keyed by template name. Templates are checked one after
+/// another to stay gentle on the APIs.
+pub async fn check_all(
+    templates: Vec<ModpackTemplate>,
+    cf_api_key: Option<String>,
+    mr_token: Option<String>,
+) -> HashMap<String, TemplateHealth> {
+    // Only fetched when a template needs it
+    let mut forge_versions: Option<Result<Vec<String>, String>> = None;
+    let mut results = HashMap::new();
+    for template in templates {
+        if matches!(template.source, ModpackSource::ForgeWithPack { .. })
+            && forge_versions.is_none()
+        {
+            forge_versions = Some(fetch_forge_versions().await.map_err(|e| format!("{:#}", e)));
+        }
+        let health = check(
+            &template,
+            cf_api_key.as_deref(),
+            mr_token.as_deref(),
+            forge_versions.as_ref(),
+        )
+        .await;
+        results.insert(template.name.clone(), health);
+    }
+    results
+}
+
+async fn check(
+    template: &ModpackTemplate,
+    cf_api_key: Option<&str>,
+    mr_token: Option<&str>,
+    forge_versions: Option<&Result<Vec<String>, String>>,
+) -> TemplateHealth {
+    let health = match &template.source {
+        ModpackSource::CurseForge { slug, file_id }
+        | ModpackSource::CurseForgeManifest { slug, file_id } => {
+            check_curseforge(cf_api_key, slug, &[*file_id]).await
+        }
+        ModpackSource::CurseForgeServerPack {
+            slug,
+            file_id,
+            server_pack_file_id,
+        } => check_curseforge(cf_api_key, slug, &[*file_id, *server_pack_file_id]).await,
+        ModpackSource::ForgeWithPack {
+            forge_version,
+            pack_url,
+            ..
+        } => match forge_versions {
+            Some(Ok(versions)) if !versions.iter().any(|v| forge_matches(v, forge_version)) => {
+                TemplateHealth::fail(format!("Forge {} doesn't exist", forge_version))
+            }
+            Some(Err(e)) => TemplateHealth::warn(format!("Couldn't list Forge versions: {}", e)),
+            _ => check_url(pack_url).await,
+        },
+        ModpackSource::Ftb {
+            pack_id,
+            version_id,
+        } => match crate::ftb::get_pack(*pack_id).await {
+            Ok(pack) if pack.versions.iter().any(|v| v.id == *version_id) => {
+                TemplateHealth::ok(format!("FTB {} version found", pack.name))
+            }
+            Ok(pack) => {
+                TemplateHealth::fail(format!("FTB {} has no version {}", pack.name, version_id))
+            }
+            Err(e) => TemplateHealth::fail(format!("FTB pack {}: {:#}", pack_id, e)),
+        },
+        ModpackSource::Modrinth {
+            project_id,
+            version_id,
+        } => match crate::modrinth::get_project_versions(mr_token, project_id).await {
+            Ok(versions) if versions.iter().any(|v| &v.id == version_id) => {
+                TemplateHealth::ok("Modrinth version found")
+            }
+            Ok(_) => TemplateHealth::fail(format!(
+                "Modrinth project {} has no version {}",
+                project_id, version_id
+            )),
+            Err(e) => TemplateHealth::fail(format!("Modrinth project {}: {:#}", project_id, e)),
+        },
+        ModpackSource::DirectDownload { url } => check_url(url).await,
+        ModpackSource::MrPackFile { path_or_url } if is_url(path_or_url) => {
+            check_url(path_or_url).await
+        }
+        ModpackSource::MrPackFile { path_or_url: path } | ModpackSource::Local { path } => {
+            if std::path::Path::new(path).exists() {
+                TemplateHealth::ok("Found on this machine")
+            } else {
+                TemplateHealth::fail(format!("{} doesn't exist", path))
+            }
+        }
+    };
+
+    // A broken starting world matters less than a broken pack
+    match &template.world_url {
+        Some(url) if health.status == CheckStatus::Pass => {
+            let world = check_url(url).await;
+            if world.status == CheckStatus::Pass {
+                health
+            } else {
+                TemplateHealth::warn(format!("World download: {}", world.detail))
+            }
+        }
+        _ => health,
+    }
+}
+
+async fn check_curseforge(api_key: Option<&str>, slug: &str, file_ids: &[u64]) -> TemplateHealth {
+    let Some(api_key) = api_key.filter(|k| !k.is_empty()) else {
+        return TemplateHealth::warn("Not checked: no CurseForge API key in Settings");
+    };
+    if let Err(e) = crate::curseforge::get_modpack_by_slug(api_key, slug).await {
+        return TemplateHealth::fail(format!("{:#}", e));
+    }
+    // File id 0 means "latest"
+    for &file_id in file_ids.iter().filter(|&&id| id != 0) {
+        if let Err(e) = crate::curseforge::get_file(api_key, file_id).await {
+            return TemplateHealth::fail(format!("CurseForge file {}: {:#}", file_id, e));
+        }
+    }
+    TemplateHealth::ok(format!("CurseForge {} found", slug))
+}
+
+/// HEAD the URL, falling back to GET for servers that don't answer HEAD
+async fn check_url(url: &str) -> TemplateHealth {
+    let client = crate::http::client();
+    let mut resp = client.head(url).send().await;
+    if resp.as_ref().is_ok_and(|r| {
+        matches!(
+            r.status(),
+            reqwest::StatusCode::METHOD_NOT_ALLOWED | reqwest::StatusCode::FORBIDDEN
+        )
+    }) {
+        resp = client.get(url).send().await;
+    }
+    match resp {
+        Ok(r) if r.status().is_success() => TemplateHealth::ok("Download URL answers"),
+        Ok(r) => TemplateHealth::fail(format!("HTTP {} from {}", r.status(), url)),
+        Err(e) => TemplateHealth::fail(format!("{}: {}", url, e)),
+    }
+}
+
+fn is_url(s: &str) -> bool {
+    s.starts_with("http://") || s.starts_with("https://")
+}
+
+async fn fetch_forge_versions() -> Result<Vec<String>> {
+    let xml = crate::http::client()
+        .get(FORGE_METADATA_URL)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await
+        .context("Failed to read Forge version list")?;
+    Ok(xml
+        .split("<version>")
+        .skip(1)
+        .filter_map(|s| s.split('<').next())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Maven versions look like `1.20.1-47.2.0` or `1.7.10-10.13.4.1614-1.7.10`
+fn forge_matches(maven_version: &str, forge_version: &str) -> bool {
+    maven_version == forge_version || maven_version.split('-').nth(1) == Some(forge_version)
+}
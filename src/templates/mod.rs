@@ -1,5 +1,9 @@
-use crate::server::{ModLoader, ModpackSource};
+use crate::config::{get_template_index_cache_path, get_templates_path};
+use crate::jvm::JvmPreset;
+use crate::server::{ModLoader, ModpackSource, ServerProperties};
+use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModpackTemplate {
@@ -14,6 +18,13 @@ pub struct ModpackTemplate {
     pub default_java_args: Vec<String>,
     /// Extra Docker env vars for pack-specific needs (e.g. CF_EXCLUDE_MODS for client-only mods)
     pub default_extra_env: Vec<String>,
+    /// Archive of a pregenerated or starting world, extracted into the data folder
+    /// before the first start
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub world_url: Option<String>,
+    /// server.properties for new servers; `None` keeps the vanilla defaults
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server_properties: Option<ServerProperties>,
 }
 
 impl ModpackTemplate {
@@ -30,26 +41,10 @@ impl ModpackTemplate {
             },
             recommended_memory_mb: 6144,
             java_version: 21,
-            default_java_args: vec![
-                "-XX:+UseG1GC".to_string(),
-                "-XX:+ParallelRefProcEnabled".to_string(),
-                "-XX:MaxGCPauseMillis=200".to_string(),
-                "-XX:+UnlockExperimentalVMOptions".to_string(),
-                "-XX:+DisableExplicitGC".to_string(),
-                "-XX:G1NewSizePercent=30".to_string(),
-                "-XX:G1MaxNewSizePercent=40".to_string(),
-                "-XX:G1HeapRegionSize=8M".to_string(),
-                "-XX:G1ReservePercent=20".to_string(),
-                "-XX:G1HeapWastePercent=5".to_string(),
-                "-XX:G1MixedGCCountTarget=4".to_string(),
-                "-XX:InitiatingHeapOccupancyPercent=15".to_string(),
-                "-XX:G1MixedGCLiveThresholdPercent=90".to_string(),
-                "-XX:G1RSetUpdatingPauseTimePercent=5".to_string(),
-                "-XX:SurvivorRatio=32".to_string(),
-                "-XX:+PerfDisableSharedMem".to_string(),
-                "-XX:MaxTenuringThreshold=1".to_string(),
-            ],
+            default_java_args: JvmPreset::Aikar.args(21, 6144),
             default_extra_env: vec![],
+            world_url: None,
+            server_properties: Some(skyblock_properties("FTB StoneBlock 4")),
         }
     }
 
@@ -66,12 +61,10 @@ impl ModpackTemplate {
             },
             recommended_memory_mb: 8192,
             java_version: 17,
-            default_java_args: vec![
-                "-XX:+UseG1GC".to_string(),
-                "-XX:+ParallelRefProcEnabled".to_string(),
-                "-XX:MaxGCPauseMillis=200".to_string(),
-            ],
+            default_java_args: JvmPreset::G1Conservative.args(17, 8192),
             default_extra_env: vec![],
+            world_url: None,
+            server_properties: None,
         }
     }
 
@@ -89,6 +82,8 @@ impl ModpackTemplate {
             java_version: 21,
             default_java_args: vec![],
             default_extra_env: vec![],
+            world_url: None,
+            server_properties: None,
         }
     }
 
@@ -105,23 +100,17 @@ impl ModpackTemplate {
             source: ModpackSource::ForgeWithPack {
                 forge_version: "10.13.4.1614".to_string(),
                 pack_url: "https://mediafilez.forgecdn.net/files/3016/706/Agrarian%2BSkies%2B2%2B%282.0.6%29-Server.zip".to_string(),
+                pack_sha1: None,
             },
             recommended_memory_mb: 4096,
             java_version: 8,
-            default_java_args: vec![
-                "-XX:+UseG1GC".to_string(),
-                "-XX:+ParallelRefProcEnabled".to_string(),
-                "-XX:MaxGCPauseMillis=200".to_string(),
-                "-XX:+UnlockExperimentalVMOptions".to_string(),
-                "-XX:+DisableExplicitGC".to_string(),
-                "-XX:G1NewSizePercent=20".to_string(),
-                "-XX:G1ReservePercent=20".to_string(),
-                "-XX:G1HeapRegionSize=32M".to_string(),
-            ],
+            default_java_args: JvmPreset::Aikar.args(8, 4096),
             default_extra_env: vec![
                 // Skyblock pack: use the included starting platform map instead of a generated world
                 "LEVEL=maps/Default Platform - Normal".to_string(),
             ],
+            world_url: None,
+            server_properties: Some(skyblock_properties("Agrarian Skies 2")),
         }
     }
 
@@ -137,22 +126,15 @@ impl ModpackTemplate {
                 forge_version: "47.2.20".to_string(),
                 pack_url: "https://mediafilez.forgecdn.net/files/5410/874/server-1.0.3.zip"
                     .to_string(),
+                pack_sha1: None,
             },
             recommended_memory_mb: 8192,
             java_version: 17,
-            default_java_args: vec![
-                "-XX:+UseG1GC".to_string(),
-                "-XX:+ParallelRefProcEnabled".to_string(),
-                "-XX:MaxGCPauseMillis=200".to_string(),
-                "-XX:+UnlockExperimentalVMOptions".to_string(),
-                "-XX:+DisableExplicitGC".to_string(),
-                "-XX:G1NewSizePercent=30".to_string(),
-                "-XX:G1MaxNewSizePercent=40".to_string(),
-                "-XX:G1HeapRegionSize=8M".to_string(),
-                "-XX:G1ReservePercent=20".to_string(),
-            ],
+            default_java_args: JvmPreset::Aikar.args(17, 8192),
             // SkyblockBuilder + DefaultWorldType mods handle skyblock world gen via config
             default_extra_env: vec![],
+            world_url: None,
+            server_properties: Some(skyblock_properties("ATM 9: To the Sky")),
         }
     }
 
@@ -169,20 +151,14 @@ impl ModpackTemplate {
                 pack_url:
                     "https://dist.creeper.host/FTB2/modpacks/Regrowth/1_0_2/RegrowthServer.zip"
                         .to_string(),
+                pack_sha1: None,
             },
             recommended_memory_mb: 4096,
             java_version: 8,
-            default_java_args: vec![
-                "-XX:+UseG1GC".to_string(),
-                "-XX:+ParallelRefProcEnabled".to_string(),
-                "-XX:MaxGCPauseMillis=200".to_string(),
-                "-XX:+UnlockExperimentalVMOptions".to_string(),
-                "-XX:+DisableExplicitGC".to_string(),
-                "-XX:G1NewSizePercent=20".to_string(),
-                "-XX:G1ReservePercent=20".to_string(),
-                "-XX:G1HeapRegionSize=32M".to_string(),
-            ],
+            default_java_args: JvmPreset::Aikar.args(8, 4096),
             default_extra_env: vec![],
+            world_url: None,
+            server_properties: None,
         }
     }
 
@@ -196,20 +172,14 @@ impl ModpackTemplate {
             source: ModpackSource::ForgeWithPack {
                 forge_version: "12.18.3.2511".to_string(),
                 pack_url: "https://mediafilez.forgecdn.net/files/2522/475/PO%20Lite%20Server%20v.1.3.6.zip".to_string(),
+                pack_sha1: None,
             },
             recommended_memory_mb: 4096,
             java_version: 8,
-            default_java_args: vec![
-                "-XX:+UseG1GC".to_string(),
-                "-XX:+ParallelRefProcEnabled".to_string(),
-                "-XX:MaxGCPauseMillis=200".to_string(),
-                "-XX:+UnlockExperimentalVMOptions".to_string(),
-                "-XX:+DisableExplicitGC".to_string(),
-                "-XX:G1NewSizePercent=20".to_string(),
-                "-XX:G1ReservePercent=20".to_string(),
-                "-XX:G1HeapRegionSize=32M".to_string(),
-            ],
+            default_java_args: JvmPreset::Aikar.args(8, 4096),
             default_extra_env: vec![],
+            world_url: None,
+            server_properties: None,
         }
     }
 
@@ -226,18 +196,14 @@ impl ModpackTemplate {
                 pack_url:
                     "https://mediafilez.forgecdn.net/files/3565/687/SkyFactory-4_Server_4_2_4.zip"
                         .to_string(),
+                pack_sha1: None,
             },
             recommended_memory_mb: 4096,
             java_version: 8,
-            default_java_args: vec![
-                "-XX:+UseG1GC".to_string(),
-                "-XX:+UnlockExperimentalVMOptions".to_string(),
-                "-XX:G1NewSizePercent=20".to_string(),
-                "-XX:G1ReservePercent=20".to_string(),
-                "-XX:MaxGCPauseMillis=50".to_string(),
-                "-XX:G1HeapRegionSize=32M".to_string(),
-            ],
+            default_java_args: JvmPreset::Aikar.args(8, 4096),
             default_extra_env: vec![],
+            world_url: None,
+            server_properties: Some(skyblock_properties("SkyFactory 4")),
         }
     }
 
@@ -253,22 +219,15 @@ impl ModpackTemplate {
                 pack_url:
                     "https://mediafilez.forgecdn.net/files/5420/427/Submerged_server_pack.zip"
                         .to_string(),
+                pack_sha1: None,
             },
             recommended_memory_mb: 8192,
             java_version: 17,
-            default_java_args: vec![
-                "-XX:+UseG1GC".to_string(),
-                "-XX:+ParallelRefProcEnabled".to_string(),
-                "-XX:MaxGCPauseMillis=200".to_string(),
-                "-XX:+UnlockExperimentalVMOptions".to_string(),
-                "-XX:+DisableExplicitGC".to_string(),
-                "-XX:G1NewSizePercent=30".to_string(),
-                "-XX:G1MaxNewSizePercent=40".to_string(),
-                "-XX:G1HeapRegionSize=8M".to_string(),
-                "-XX:G1ReservePercent=20".to_string(),
-            ],
+            default_java_args: JvmPreset::Aikar.args(17, 8192),
             // SkyblockBuilder + DefaultWorldType mods handle skyblock world gen via config
             default_extra_env: vec![],
+            world_url: None,
+            server_properties: None,
         }
     }
 
@@ -285,4 +244,172 @@ impl ModpackTemplate {
             Self::vanilla(),
         ]
     }
+
+    /// Built-in templates, updated from the remote index when `use_index` is set,
+    /// followed by the user's own. A later template with an earlier one's name
+    /// replaces it.
+    pub fn all_templates(use_index: bool) -> Vec<Self> {
+        let mut templates = Self::builtin_templates();
+        if use_index {
+            merge(&mut templates, load_cached_index());
+        }
+        merge(&mut templates, load_user_templates());
+        templates
+    }
+
+    /// Parse a shared template file
+    pub fn from_json(json: &str) -> Result<Self> {
+        let template: Self = serde_json::from_str(json).context("Not a valid template file")?;
+        if template.name.trim().is_empty() {
+            anyhow::bail!("The template has no name");
+        }
+        Ok(template)
+    }
+
+    /// Write the template as JSON for sharing
+    pub fn export(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Read a template file someone shared
+    pub fn import(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        Self::from_json(&json)
+    }
+
+    /// Download a template file from a URL
+    pub async fn fetch(url: &str) -> Result<Self> {
+        let resp = crate::http::client()
+            .get(url)
+            .send()
+            .await
+            .with_context(|| format!("Failed to download {}", url))?;
+        if !resp.status().is_success() {
+            anyhow::bail!("Template download failed: HTTP {}", resp.status());
+        }
+        Self::from_json(&resp.text().await?)
+    }
+
+    /// Keep the template in the data folder so it shows up with the built-in ones
+    pub fn save_user(&self) -> Result<()> {
+        let dir = get_templates_path();
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create {}", dir.display()))?;
+        self.export(&user_template_path(&self.name))
+    }
+}
+
+/// Skyblock packs are played by small co-op groups on a shared island, and a
+/// shorter view distance saves server work since there's little terrain to send
+fn skyblock_properties(motd: &str) -> ServerProperties {
+    ServerProperties {
+        motd: motd.to_string(),
+        max_players: 8,
+        pvp: false,
+        view_distance: 8,
+        ..ServerProperties::default()
+    }
+}
+
+fn merge(templates: &mut Vec<ModpackTemplate>, extra: Vec<ModpackTemplate>) {
+    for template in extra {
+        match templates.iter_mut().find(|t| t.name == template.name) {
+            Some(existing) => *existing = template,
+            None => templates.push(template),
+        }
+    }
+}
+
+/// Remote catalog of featured templates
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemplateIndex {
+    #[serde(default)]
+    pub templates: Vec<ModpackTemplate>,
+}
+
+/// Templates from the last index fetched; none if it was never fetched
+pub fn load_cached_index() -> Vec<ModpackTemplate> {
+    std::fs::read_to_string(get_template_index_cache_path())
+        .ok()
+        .and_then(|json| serde_json::from_str::<TemplateIndex>(&json).ok())
+        .map(|index| index.templates)
+        .unwrap_or_default()
+}
+
+/// Download the template index and keep it for [`ModpackTemplate::all_templates`].
+/// Returns how many templates it has.
+pub async fn fetch_index(url: &str) -> Result<usize> {
+    let resp = crate::http::client()
+        .get(url)
+        .send()
+        .await
+        .with_context(|| format!("Failed to download {}", url))?;
+    if !resp.status().is_success() {
+        anyhow::bail!("Template index download failed: HTTP {}", resp.status());
+    }
+    let json = resp.text().await?;
+    let index: TemplateIndex = serde_json::from_str(&json).context("Not a valid template index")?;
+    if let Some(unnamed) = index
+        .templates
+        .iter()
+        .position(|t| t.name.trim().is_empty())
+    {
+        anyhow::bail!("Template {} in the index has no name", unnamed + 1);
+    }
+
+    let path = get_template_index_cache_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, json).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(index.templates.len())
+}
+
+/// File a user template is kept in, named after the template
+fn user_template_path(name: &str) -> PathBuf {
+    let file: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    get_templates_path().join(format!("{}.json", file))
+}
+
+/// Templates imported from files or URLs. Files that don't parse are skipped.
+pub fn load_user_templates() -> Vec<ModpackTemplate> {
+    let Ok(entries) = std::fs::read_dir(get_templates_path()) else {
+        return Vec::new();
+    };
+    let mut templates: Vec<ModpackTemplate> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "json"))
+        .filter_map(|path| match ModpackTemplate::import(&path) {
+            Ok(template) => Some(template),
+            Err(e) => {
+                tracing::warn!("Skipping template {}: {:#}", path.display(), e);
+                None
+            }
+        })
+        .collect();
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    templates
+}
+
+/// Names of the user's own templates
+pub fn user_template_names() -> Vec<String> {
+    load_user_templates().into_iter().map(|t| t.name).collect()
+}
+
+/// Delete a user template; a built-in one it replaced comes back
+pub fn remove_user_template(name: &str) -> Result<()> {
+    let path = user_template_path(name);
+    std::fs::remove_file(&path).with_context(|| format!("Failed to delete {}", path.display()))
 }
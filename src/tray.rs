@@ -0,0 +1,213 @@
+//! System tray icon with Start/Stop items for each server and a way back to the
+//! window, so the app can sit minimized while it supervises running servers.
+//! Uses the native tray on Windows and macOS, and StatusNotifierItem over D-Bus
+//! on Linux (no GTK needed).
+
+use crate::i18n::{tr, tr_args};
+use eframe::egui;
+use std::sync::mpsc::{self, Receiver};
+use tray_icon::menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem};
+use tray_icon::{Icon, MouseButton, MouseButtonState, TrayIcon, TrayIconBuilder, TrayIconEvent};
+
+/// Something picked in the tray
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrayAction {
+    /// Bring the window back (also a left click on the icon)
+    Show,
+    Start(String),
+    Stop(String),
+    Quit,
+}
+
+/// A server as listed in the tray menu
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrayServer {
+    pub name: String,
+    pub running: bool,
+    /// Starting, stopping or pulling, so neither Start nor Stop applies
+    pub busy: bool,
+    /// Preflight checks are running before the start
+    pub checking: bool,
+}
+
+/// One menu item: ID, label and whether it can be clicked
+type Entry = (String, String, bool);
+
+pub struct Tray {
+    icon: TrayIcon,
+    actions: Receiver<TrayAction>,
+    /// What the menu was last built from, so it's only rebuilt on changes
+    entries: Vec<Entry>,
+}
+
+impl Tray {
+    /// Put the icon in the tray. None (after logging why) where there's no tray,
+    /// e.g. a Linux desktop without a StatusNotifierItem host.
+    pub fn new(ctx: &egui::Context) -> Option<Self> {
+        let (tx, actions) = mpsc::channel();
+
+        // Events arrive on other threads; waking the UI makes a minimized window act on them
+        let menu_tx = tx.clone();
+        let menu_ctx = ctx.clone();
+        MenuEvent::set_event_handler(Some(move |event: MenuEvent| {
+            if let Some(action) = parse_id(event.id.as_ref()) {
+                let _ = menu_tx.send(action);
+                menu_ctx.request_repaint();
+            }
+        }));
+        let click_ctx = ctx.clone();
+        TrayIconEvent::set_event_handler(Some(move |event: TrayIconEvent| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                let _ = tx.send(TrayAction::Show);
+                click_ctx.request_repaint();
+            }
+        }));
+
+        let icon = match app_icon() {
+            Ok(icon) => icon,
+            Err(e) => {
+                tracing::warn!("Couldn't draw the tray icon: {}", e);
+                return None;
+            }
+        };
+        let built = TrayIconBuilder::new()
+            .with_id("drakonix-anvil")
+            .with_title("DrakonixAnvil")
+            .with_tooltip("DrakonixAnvil")
+            .with_icon(icon)
+            .with_menu_on_left_click(false)
+            .build();
+        match built {
+            Ok(icon) => Some(Self {
+                icon,
+                actions,
+                entries: Vec::new(),
+            }),
+            Err(e) => {
+                tracing::warn!("No tray icon: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Rebuild the menu if servers were added, removed or changed state, or the
+    /// language changed
+    pub fn update(&mut self, servers: &[TrayServer]) {
+        let entries = entries(servers);
+        if entries == self.entries {
+            return;
+        }
+        let menu = Menu::new();
+        for (id, label, enabled) in &entries {
+            let appended = if id.is_empty() {
+                menu.append(&PredefinedMenuItem::separator())
+            } else {
+                menu.append(&MenuItem::with_id(id.as_str(), label, *enabled, None))
+            };
+            if let Err(e) = appended {
+                tracing::warn!("Couldn't build the tray menu: {}", e);
+                return;
+            }
+        }
+        self.icon.set_menu(Some(Box::new(menu)));
+
+        let running = servers.iter().filter(|s| s.running).count();
+        let tooltip = tr_args("tray-tooltip", &[("count", running.into())]);
+        if let Err(e) = self.icon.set_tooltip(Some(tooltip)) {
+            tracing::warn!("Couldn't set the tray tooltip: {}", e);
+        }
+        self.entries = entries;
+    }
+
+    /// The next thing picked in the tray, if any
+    pub fn try_recv(&self) -> Option<TrayAction> {
+        self.actions.try_recv().ok()
+    }
+}
+
+/// Show, the servers, Quit; an empty ID stands for a separator
+fn entries(servers: &[TrayServer]) -> Vec<Entry> {
+    let mut entries = vec![("show".to_string(), tr("tray-show"), true)];
+    if !servers.is_empty() {
+        entries.push((String::new(), String::new(), false));
+    }
+    for server in servers {
+        let args = [("name", server.name.as_str().into())];
+        entries.push(if server.checking {
+            (
+                format!("start:{}", server.name),
+                tr_args("tray-checking", &args),
+                false,
+            )
+        } else if server.running {
+            (
+                format!("stop:{}", server.name),
+                tr_args("tray-stop", &args),
+                !server.busy,
+            )
+        } else {
+            (
+                format!("start:{}", server.name),
+                tr_args("tray-start", &args),
+                !server.busy,
+            )
+        });
+    }
+    entries.push((String::new(), String::new(), false));
+    entries.push(("quit".to_string(), tr("tray-quit"), true));
+    entries
+}
+
+fn parse_id(id: &str) -> Option<TrayAction> {
+    match id {
+        "show" => Some(TrayAction::Show),
+        "quit" => Some(TrayAction::Quit),
+        _ => {
+            if let Some(name) = id.strip_prefix("start:") {
+                Some(TrayAction::Start(name.to_string()))
+            } else {
+                id.strip_prefix("stop:")
+                    .map(|name| TrayAction::Stop(name.to_string()))
+            }
+        }
+    }
+}
+
+/// A 32×32 orange anvil on a dark rounded square, drawn here so there's no image
+/// file to ship
+fn app_icon() -> Result<Icon, tray_icon::BadIcon> {
+    const SIZE: u32 = 32;
+    const BACKGROUND: [u8; 4] = [40, 44, 52, 255];
+    const ANVIL: [u8; 4] = [230, 126, 34, 255];
+    // (x0, y0, x1, y1), end exclusive: horn, face, waist, foot
+    const SHAPES: [(u32, u32, u32, u32); 4] = [
+        (3, 10, 8, 13),
+        (8, 9, 27, 15),
+        (13, 15, 21, 21),
+        (9, 21, 25, 25),
+    ];
+
+    let mut rgba = Vec::with_capacity((SIZE * SIZE * 4) as usize);
+    for y in 0..SIZE {
+        for x in 0..SIZE {
+            // Cut the corners off the background
+            let corner = |c: u32| c.min(SIZE - 1 - c);
+            let (cx, cy) = (corner(x), corner(y));
+            let outside = cx < 3 && cy < 3 && (3 - cx).pow(2) + (3 - cy).pow(2) > 9;
+            let anvil = SHAPES
+                .iter()
+                .any(|&(x0, y0, x1, y1)| (x0..x1).contains(&x) && (y0..y1).contains(&y));
+            rgba.extend_from_slice(match (outside, anvil) {
+                (true, _) => &[0, 0, 0, 0],
+                (false, true) => &ANVIL,
+                (false, false) => &BACKGROUND,
+            });
+        }
+    }
+    Icon::from_rgba(rgba, SIZE, SIZE)
+}
@@ -0,0 +1,66 @@
+use crate::alerts::AlertRules;
+use crate::i18n::tr;
+use eframe::egui;
+
+/// Checkbox and threshold for each alert a server can raise.
+/// Returns true if anything changed.
+pub fn alert_rules_editor(ui: &mut egui::Ui, rules: &mut AlertRules) -> bool {
+    let mut changed = ui
+        .checkbox(&mut rules.container_exited, tr("alerts-container-exited"))
+        .changed();
+    ui.horizontal(|ui| {
+        changed |= ui
+            .checkbox(&mut rules.low_tps, tr("alerts-low-tps"))
+            .changed();
+        ui.add_enabled_ui(rules.low_tps, |ui| {
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut rules.tps_below)
+                        .range(1.0..=20.0)
+                        .speed(0.1)
+                        .fixed_decimals(1),
+                )
+                .changed();
+            ui.label(tr("alerts-low-tps-for"));
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut rules.tps_minutes)
+                        .range(1..=120)
+                        .suffix(" min"),
+                )
+                .changed();
+        });
+    })
+    .response
+    .on_hover_text(tr("alerts-low-tps-hint"));
+    ui.horizontal(|ui| {
+        changed |= ui
+            .checkbox(&mut rules.high_memory, tr("alerts-high-memory"))
+            .changed();
+        ui.add_enabled_ui(rules.high_memory, |ui| {
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut rules.memory_percent)
+                        .range(50..=100)
+                        .suffix("%"),
+                )
+                .changed();
+            ui.label(tr("alerts-high-memory-of"));
+        });
+    });
+    ui.horizontal(|ui| {
+        changed |= ui
+            .checkbox(&mut rules.no_backup, tr("alerts-no-backup"))
+            .changed();
+        ui.add_enabled_ui(rules.no_backup, |ui| {
+            changed |= ui
+                .add(
+                    egui::DragValue::new(&mut rules.backup_hours)
+                        .range(1..=24 * 30)
+                        .suffix(" h"),
+                )
+                .changed();
+        });
+    });
+    changed
+}
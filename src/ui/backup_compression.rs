@@ -0,0 +1,48 @@
+use crate::config::BackupCompression;
+use crate::i18n::tr;
+use eframe::egui;
+
+const METHODS: [BackupCompression; 3] = [
+    BackupCompression::Store,
+    BackupCompression::Deflate { level: 6 },
+    BackupCompression::Zstd { level: 3 },
+];
+
+/// Method picker, plus a level slider for the methods that take one.
+/// Returns true if the choice changed.
+pub fn compression_picker(
+    ui: &mut egui::Ui,
+    id_salt: &str,
+    compression: &mut BackupCompression,
+) -> bool {
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        egui::ComboBox::from_id_salt(id_salt)
+            .selected_text(compression.label())
+            .show_ui(ui, |ui| {
+                for method in METHODS {
+                    let selected =
+                        std::mem::discriminant(compression) == std::mem::discriminant(&method);
+                    if ui.selectable_label(selected, method.label()).clicked() && !selected {
+                        *compression = method;
+                        changed = true;
+                    }
+                }
+            });
+        match compression {
+            BackupCompression::Store => {}
+            BackupCompression::Deflate { level } => {
+                changed |= ui
+                    .add(egui::Slider::new(level, 1..=9).text(tr("compression-level")))
+                    .changed();
+            }
+            BackupCompression::Zstd { level } => {
+                changed |= ui
+                    .add(egui::Slider::new(level, 1..=22).text(tr("compression-level")))
+                    .on_hover_text(tr("compression-zstd-hint"))
+                    .changed();
+            }
+        }
+    });
+    changed
+}
@@ -0,0 +1,351 @@
+use crate::backup::{self, format_bytes, BackupEntry};
+use crate::i18n::{tr, tr_args};
+use chrono::NaiveDateTime;
+use eframe::egui;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+/// What the user chose in the backup contents view
+#[derive(Debug, Clone, PartialEq)]
+pub enum BackupContentsAction {
+    Back,
+    /// Restore these paths (files or folders) from the backup
+    RestoreSelected(Vec<String>),
+}
+
+/// A file or folder in the tree, with totals for everything below a folder
+struct TreeNode {
+    name: String,
+    path: String,
+    is_dir: bool,
+    size: u64,
+    files: usize,
+    /// Newest modification time of the file, or of anything below a folder
+    modified: Option<NaiveDateTime>,
+    children: Vec<TreeNode>,
+}
+
+/// Text of the file open in the preview pane
+struct Preview {
+    path: String,
+    /// The text and whether it was cut off, or why it can't be shown
+    text: Result<(String, bool), String>,
+}
+
+impl TreeNode {
+    fn new(name: &str, path: String, is_dir: bool) -> Self {
+        Self {
+            name: name.to_string(),
+            path,
+            is_dir,
+            size: 0,
+            files: 0,
+            modified: None,
+            children: Vec::new(),
+        }
+    }
+
+    fn insert(&mut self, entry: &BackupEntry) {
+        let relative = if self.path.is_empty() {
+            entry.path.as_str()
+        } else {
+            &entry.path[self.path.len() + 1..]
+        };
+        let (head, rest) = match relative.split_once('/') {
+            Some((head, rest)) => (head, Some(rest)),
+            None => (relative, None),
+        };
+        if !entry.is_dir {
+            self.size += entry.size;
+            self.files += 1;
+            self.modified = self.modified.max(entry.modified);
+        }
+        let path = if self.path.is_empty() {
+            head.to_string()
+        } else {
+            format!("{}/{}", self.path, head)
+        };
+        let index = match self.children.iter().position(|c| c.name == head) {
+            Some(index) => index,
+            None => {
+                let is_dir = rest.is_some() || entry.is_dir;
+                self.children.push(TreeNode::new(head, path, is_dir));
+                self.children.len() - 1
+            }
+        };
+        let child = &mut self.children[index];
+        match rest {
+            Some(_) => child.insert(entry),
+            None if !entry.is_dir => {
+                child.size = entry.size;
+                child.files = 1;
+                child.modified = entry.modified;
+            }
+            None => {}
+        }
+    }
+
+    /// Folders first, then files, each by name
+    fn sort(&mut self) {
+        self.children.sort_by(|a, b| {
+            b.is_dir
+                .cmp(&a.is_dir)
+                .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+        });
+        for child in &mut self.children {
+            child.sort();
+        }
+    }
+}
+
+/// Expandable tree of a backup's files for looking through it before a restore,
+/// with text previews and checkboxes for a selective restore
+pub struct BackupContentsView {
+    backup_path: PathBuf,
+    root: TreeNode,
+    /// Checked paths; anything below a checked folder comes with it
+    selected: BTreeSet<String>,
+    preview: Option<Preview>,
+}
+
+impl BackupContentsView {
+    pub fn new(backup_path: &Path, entries: &[BackupEntry]) -> Self {
+        let mut root = TreeNode::new("", String::new(), true);
+        for entry in entries {
+            root.insert(entry);
+        }
+        root.sort();
+        Self {
+            backup_path: backup_path.to_path_buf(),
+            root,
+            selected: BTreeSet::new(),
+            preview: None,
+        }
+    }
+
+    /// `busy` disables the restore button while another restore runs
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        server_name: &str,
+        filename: &str,
+        busy: bool,
+    ) -> Option<BackupContentsAction> {
+        let mut action = None;
+
+        ui.horizontal(|ui| {
+            ui.heading(tr_args("contents-title", &[("file", filename.into())]));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button(tr("button-back")).clicked() {
+                    action = Some(BackupContentsAction::Back);
+                }
+            });
+        });
+        ui.label(tr_args(
+            "contents-hint",
+            &[
+                ("files", self.root.files.into()),
+                ("size", format_bytes(self.root.size).into()),
+                ("server", server_name.into()),
+            ],
+        ));
+        ui.small(tr("contents-stop-first"));
+
+        ui.horizontal(|ui| {
+            let (count, size) = self.selection_totals();
+            let button = egui::Button::new(tr_args(
+                "contents-restore-selected",
+                &[("count", count.into())],
+            ));
+            if ui
+                .add_enabled(!busy && !self.selected.is_empty(), button)
+                .on_hover_text(format_bytes(size))
+                .clicked()
+            {
+                action = Some(BackupContentsAction::RestoreSelected(
+                    self.selected.iter().cloned().collect(),
+                ));
+            }
+            if ui
+                .add_enabled(
+                    !self.selected.is_empty(),
+                    egui::Button::new(tr("contents-clear-selection")),
+                )
+                .clicked()
+            {
+                self.selected.clear();
+            }
+        });
+        ui.separator();
+
+        if self.root.children.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.add_space(50.0);
+                ui.label(tr("contents-empty"));
+            });
+            return action;
+        }
+
+        if let Some(preview) = &self.preview {
+            let mut close = false;
+            egui::SidePanel::right("backup_contents_preview")
+                .resizable(true)
+                .default_width(450.0)
+                .show_inside(ui, |ui| close = show_preview(ui, preview));
+            if close {
+                self.preview = None;
+            }
+        }
+
+        let mut open = None;
+        egui::ScrollArea::vertical()
+            .id_salt("backup_contents_tree")
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                for child in &self.root.children {
+                    show_node(ui, child, &mut self.selected, false, &mut open);
+                }
+            });
+        if let Some(path) = open {
+            // Small reads capped at PREVIEW_LIMIT, so done right here
+            let text =
+                backup::read_backup_text(&self.backup_path, &path).map_err(|e| e.to_string());
+            self.preview = Some(Preview { path, text });
+        }
+
+        action
+    }
+
+    /// Files and bytes covered by the current selection
+    fn selection_totals(&self) -> (usize, u64) {
+        fn walk(node: &TreeNode, selected: &BTreeSet<String>) -> (usize, u64) {
+            if selected.contains(&node.path) {
+                return (node.files, node.size);
+            }
+            node.children.iter().fold((0, 0), |(files, size), child| {
+                let (f, s) = walk(child, selected);
+                (files + f, size + s)
+            })
+        }
+        walk(&self.root, &self.selected)
+    }
+}
+
+/// Pane showing the previewed file; returns whether it was closed
+fn show_preview(ui: &mut egui::Ui, preview: &Preview) -> bool {
+    let mut close = false;
+    ui.horizontal(|ui| {
+        ui.strong(&preview.path);
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            close = ui
+                .small_button("✖")
+                .on_hover_text(tr("contents-close-preview"))
+                .clicked();
+        });
+    });
+    match &preview.text {
+        Ok((text, truncated)) => {
+            if *truncated {
+                ui.small(tr_args(
+                    "contents-truncated",
+                    &[("size", format_bytes(backup::PREVIEW_LIMIT).into())],
+                ));
+            }
+            ui.separator();
+            egui::ScrollArea::both()
+                .id_salt("backup_contents_preview_text")
+                .auto_shrink([false, false])
+                .show(ui, |ui| {
+                    ui.add(
+                        egui::TextEdit::multiline(&mut text.as_str())
+                            .code_editor()
+                            .desired_width(f32::INFINITY),
+                    );
+                });
+        }
+        Err(e) => {
+            ui.separator();
+            ui.label(e);
+        }
+    }
+    close
+}
+
+/// Draw a node and, for an expanded folder, everything below it. A click on a
+/// file's preview button puts its path in `open`.
+fn show_node(
+    ui: &mut egui::Ui,
+    node: &TreeNode,
+    selected: &mut BTreeSet<String>,
+    inherited: bool,
+    open: &mut Option<String>,
+) {
+    if !node.is_dir {
+        ui.horizontal(|ui| {
+            // Lines files up with the folder names beside their expand arrows
+            ui.add_space(ui.spacing().indent);
+            node_row(ui, node, selected, inherited);
+            if ui
+                .small_button("🔍")
+                .on_hover_text(tr("contents-preview"))
+                .clicked()
+            {
+                *open = Some(node.path.clone());
+            }
+        });
+        return;
+    }
+
+    let id = ui.make_persistent_id(("backup_contents", &node.path));
+    egui::collapsing_header::CollapsingState::load_with_default_open(ui.ctx(), id, false)
+        .show_header(ui, |ui| node_row(ui, node, selected, inherited))
+        .body(|ui| {
+            let inherited = inherited || selected.contains(&node.path);
+            for child in &node.children {
+                show_node(ui, child, selected, inherited, open);
+            }
+        });
+}
+
+/// Checkbox, name, size and modification time of one node. Below a checked folder the boxes show as
+/// checked and can't be changed.
+fn node_row(ui: &mut egui::Ui, node: &TreeNode, selected: &mut BTreeSet<String>, inherited: bool) {
+    let mut checked = inherited || selected.contains(&node.path);
+    let label = if node.is_dir {
+        format!("{}/", node.name)
+    } else {
+        node.name.clone()
+    };
+    let changed = ui
+        .add_enabled(!inherited, egui::Checkbox::new(&mut checked, label))
+        .changed();
+    if changed {
+        if checked {
+            // The folder covers its contents, so picks inside it are dropped
+            let prefix = format!("{}/", node.path);
+            selected.retain(|p| !p.starts_with(&prefix));
+            selected.insert(node.path.clone());
+        } else {
+            selected.remove(&node.path);
+        }
+    }
+    let details = if node.is_dir {
+        tr_args(
+            "contents-folder-size",
+            &[
+                ("files", node.files.into()),
+                ("size", format_bytes(node.size).into()),
+            ],
+        )
+    } else {
+        format_bytes(node.size)
+    };
+    match node.modified {
+        Some(modified) => ui.small(format!(
+            "{}, {}",
+            details,
+            modified.format("%Y-%m-%d %H:%M")
+        )),
+        None => ui.small(details),
+    };
+}
@@ -0,0 +1,114 @@
+use crate::backup::format_bytes;
+use crate::backup_overview::BackupOverview;
+use crate::i18n::{tr, tr_args};
+use crate::playtime::format_duration;
+use eframe::egui;
+
+/// What the user chose in the backups overview
+#[derive(Debug, Clone, PartialEq)]
+pub enum BackupOverviewAction {
+    Refresh,
+    /// Open a server's backups list
+    Open(String),
+}
+
+pub struct BackupOverviewView;
+
+impl BackupOverviewView {
+    pub fn show(ui: &mut egui::Ui, overview: &BackupOverview) -> Option<BackupOverviewAction> {
+        let mut action = None;
+
+        ui.horizontal(|ui| {
+            ui.heading(tr("backups-title"));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button(tr("button-refresh")).clicked() {
+                    action = Some(BackupOverviewAction::Refresh);
+                }
+            });
+        });
+        let total = format_bytes(overview.total_bytes);
+        ui.label(match overview.available {
+            Some(bytes) => tr_args(
+                "backups-total-free",
+                &[
+                    ("total", total.into()),
+                    ("free", format_bytes(bytes).into()),
+                ],
+            ),
+            None => tr_args("backups-total", &[("total", total.into())]),
+        });
+        ui.separator();
+
+        if overview.rows.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.add_space(50.0);
+                ui.label(tr("dashboard-empty"));
+            });
+            return action;
+        }
+
+        let warnings = overview.rows.iter().filter(|r| r.warning.is_some()).count();
+        if warnings > 0 {
+            ui.colored_label(
+                egui::Color32::YELLOW,
+                tr_args("backups-stale", &[("count", warnings.into())]),
+            );
+        } else {
+            ui.colored_label(egui::Color32::GREEN, tr("backups-all-recent"));
+        }
+        ui.add_space(8.0);
+
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                egui::Grid::new("backup_overview_table")
+                    .num_columns(6)
+                    .striped(true)
+                    .spacing([20.0, 6.0])
+                    .show(ui, |ui| {
+                        ui.strong(tr("backups-col-server"));
+                        ui.strong(tr("backups-col-backups"));
+                        ui.strong(tr("backups-col-size"));
+                        ui.strong(tr("backups-col-newest"));
+                        ui.strong(tr("backups-col-schedule"));
+                        ui.strong(tr("backups-col-issues"));
+                        ui.end_row();
+
+                        for row in &overview.rows {
+                            if row.configured {
+                                if ui.link(&row.server_name).clicked() {
+                                    action =
+                                        Some(BackupOverviewAction::Open(row.server_name.clone()));
+                                }
+                            } else {
+                                ui.label(&row.server_name)
+                                    .on_hover_text(tr("backups-deleted-hint"));
+                            }
+                            ui.label(row.count.to_string());
+                            ui.label(format_bytes(row.total_bytes));
+                            match row.newest_age {
+                                Some(age) => ui.label(tr_args(
+                                    "backups-ago",
+                                    &[("duration", format_duration(age.as_secs()).into())],
+                                )),
+                                None => ui.label("—"),
+                            };
+                            if !row.configured {
+                                ui.colored_label(egui::Color32::GRAY, tr("backups-deleted"));
+                            } else if row.scheduled {
+                                ui.colored_label(egui::Color32::GREEN, tr("backups-schedule-on"));
+                            } else {
+                                ui.colored_label(egui::Color32::GRAY, tr("backups-schedule-off"));
+                            }
+                            match &row.warning {
+                                Some(warning) => ui.colored_label(egui::Color32::YELLOW, warning),
+                                None => ui.label("—"),
+                            };
+                            ui.end_row();
+                        }
+                    });
+            });
+
+        action
+    }
+}
@@ -1,4 +1,6 @@
 use crate::curseforge::{self, CfFile, CfMod, CfSortField};
+use crate::i18n::{tr, tr_args};
+use crate::jvm::JvmPreset;
 use crate::server::{ModLoader, ModpackSource};
 use crate::templates::ModpackTemplate;
 use eframe::egui;
@@ -37,12 +39,12 @@ impl CfSearchState {
         }
     }
 
-    fn loader_label(&self) -> &'static str {
+    fn loader_label(&self) -> String {
         match self.loader_filter_idx {
-            1 => "Forge",
-            2 => "Fabric",
-            3 => "NeoForge",
-            _ => "Any",
+            1 => "Forge".to_string(),
+            2 => "Fabric".to_string(),
+            3 => "NeoForge".to_string(),
+            _ => tr("browse-any"),
         }
     }
 }
@@ -79,12 +81,24 @@ pub struct CfCallbacks<'a> {
     pub has_api_key: bool,
 }
 
+/// Which of a CurseForge file's downloads a server is built from
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum CfInstallMethod {
+    /// The client zip, installed by the image's AUTO_CURSEFORGE from its manifest
+    #[default]
+    ClientManifest,
+    /// The file's separate server pack, extracted on the host
+    ServerPack,
+}
+
 // ── CfBrowseWidget ─────────────────────────────────────────────────────────
 
 #[derive(Default)]
 pub struct CfBrowseWidget {
     pub state: CfBrowseState,
     pub template: Option<ModpackTemplate>,
+    /// Kept across searches; files without a server pack use the client manifest
+    pub install_method: CfInstallMethod,
 }
 
 impl CfBrowseWidget {
@@ -104,14 +118,12 @@ impl CfBrowseWidget {
             if !callbacks.has_api_key {
                 ui.add_space(40.0);
                 ui.vertical_centered(|ui| {
-                    ui.colored_label(egui::Color32::YELLOW, "CurseForge API key required");
+                    ui.colored_label(egui::Color32::YELLOW, tr("cf-key-required"));
                     ui.add_space(8.0);
-                    ui.label(
-                        "Set your CurseForge API key in Settings to search for modpacks.",
-                    );
+                    ui.label(tr("cf-key-required-hint"));
                     ui.add_space(4.0);
                     ui.horizontal(|ui| {
-                        ui.label("Get a free key at");
+                        ui.label(tr("cf-key-get"));
                         ui.hyperlink("https://console.curseforge.com/");
                     });
                 });
@@ -122,48 +134,40 @@ impl CfBrowseWidget {
             let mut trigger_search = false;
 
             ui.horizontal(|ui| {
-                ui.label("Search:");
+                ui.label(tr("browse-search"));
                 let resp = ui.add(
                     egui::TextEdit::singleline(&mut self.state.search.query)
                         .desired_width(200.0)
-                        .hint_text("e.g. All The Mods"),
+                        .hint_text(tr("cf-search-hint")),
                 );
                 if resp.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
                     trigger_search = true;
                 }
-                if ui.button("Search").clicked() {
+                if ui.button(tr("browse-search-button")).clicked() {
                     trigger_search = true;
                 }
             });
 
             // ── Filters ───────────────────────────────────────────────────
             ui.horizontal(|ui| {
-                ui.label("MC Version:");
+                ui.label(tr("browse-mc-version"));
                 ui.add(
                     egui::TextEdit::singleline(&mut self.state.search.mc_version_filter)
                         .desired_width(60.0)
-                        .hint_text("e.g. 1.20.1"),
+                        .hint_text(tr("browse-mc-version-hint")),
                 );
 
-                ui.label("Loader:");
+                ui.label(tr("browse-loader"));
                 egui::ComboBox::from_id_salt("cf_loader_filter")
                     .selected_text(self.state.search.loader_label())
                     .show_ui(ui, |ui| {
                         ui.selectable_value(
                             &mut self.state.search.loader_filter_idx,
                             0,
-                            "Any",
-                        );
-                        ui.selectable_value(
-                            &mut self.state.search.loader_filter_idx,
-                            1,
-                            "Forge",
-                        );
-                        ui.selectable_value(
-                            &mut self.state.search.loader_filter_idx,
-                            2,
-                            "Fabric",
+                            tr("browse-any"),
                         );
+                        ui.selectable_value(&mut self.state.search.loader_filter_idx, 1, "Forge");
+                        ui.selectable_value(&mut self.state.search.loader_filter_idx, 2, "Fabric");
                         ui.selectable_value(
                             &mut self.state.search.loader_filter_idx,
                             3,
@@ -171,16 +175,12 @@ impl CfBrowseWidget {
                         );
                     });
 
-                ui.label("Sort:");
+                ui.label(tr("browse-sort"));
                 egui::ComboBox::from_id_salt("cf_sort_field")
                     .selected_text(self.state.search.sort_field.label())
                     .show_ui(ui, |ui| {
                         for sf in CfSortField::ALL {
-                            ui.selectable_value(
-                                &mut self.state.search.sort_field,
-                                sf,
-                                sf.label(),
-                            );
+                            ui.selectable_value(&mut self.state.search.sort_field, sf, sf.label());
                         }
                     });
             });
@@ -208,21 +208,27 @@ impl CfBrowseWidget {
             if self.state.loading_search {
                 ui.horizontal(|ui| {
                     ui.spinner();
-                    ui.label("Searching CurseForge...");
+                    ui.label(tr_args(
+                        "browse-searching",
+                        &[("site", "CurseForge".into())],
+                    ));
                 });
                 return;
             }
 
             if let Some(err) = &self.state.search_error.clone() {
-                ui.colored_label(egui::Color32::RED, format!("Error: {}", err));
+                ui.colored_label(
+                    egui::Color32::RED,
+                    tr_args("browse-error", &[("error", err.into())]),
+                );
                 return;
             }
 
             if self.state.results.is_empty() && self.state.total_count == 0 {
                 if self.state.search.query.is_empty() {
-                    ui.label("Enter a search term and click Search to find modpacks.");
+                    ui.label(tr("browse-enter-term"));
                 } else {
-                    ui.label("No results found.");
+                    ui.label(tr("browse-no-results"));
                 }
                 return;
             }
@@ -284,11 +290,15 @@ impl CfBrowseWidget {
                                                 ui.vertical(|ui| {
                                                     ui.horizontal(|ui| {
                                                         ui.strong(&cf_mod.name);
-                                                        ui.small(format!(
-                                                            "({} downloads)",
-                                                            curseforge::format_downloads(
-                                                                cf_mod.download_count,
-                                                            )
+                                                        ui.small(tr_args(
+                                                            "browse-downloads-short",
+                                                            &[(
+                                                                "count",
+                                                                curseforge::format_downloads(
+                                                                    cf_mod.download_count,
+                                                                )
+                                                                .into(),
+                                                            )],
                                                         ));
                                                     });
                                                     ui.label(&cf_mod.summary);
@@ -337,7 +347,7 @@ impl CfBrowseWidget {
                                         if ui
                                             .add_enabled(
                                                 page > 1,
-                                                egui::Button::new("< Prev"),
+                                                egui::Button::new(tr("browse-prev")),
                                             )
                                             .clicked()
                                         {
@@ -348,12 +358,15 @@ impl CfBrowseWidget {
                                             (callbacks.on_search)(self.state.search.clone());
                                         }
 
-                                        ui.label(format!("Page {} / {}", page, total_pages));
+                                        ui.label(tr_args(
+                                            "browse-page",
+                                            &[("page", page.into()), ("pages", total_pages.into())],
+                                        ));
 
                                         if ui
                                             .add_enabled(
                                                 page < total_pages,
-                                                egui::Button::new("Next >"),
+                                                egui::Button::new(tr("browse-next")),
                                             )
                                             .clicked()
                                         {
@@ -425,9 +438,12 @@ impl CfBrowseWidget {
                     ui.heading(&selected.name);
                     ui.add_space(4.0);
                     ui.horizontal(|ui| {
-                        ui.label(format!(
-                            "{} downloads",
-                            curseforge::format_downloads(selected.download_count)
+                        ui.label(tr_args(
+                            "browse-downloads",
+                            &[(
+                                "count",
+                                curseforge::format_downloads(selected.download_count).into(),
+                            )],
                         ));
                     });
                     ui.add_space(4.0);
@@ -440,7 +456,7 @@ impl CfBrowseWidget {
                         .collect();
                     if !mc_versions.is_empty() {
                         ui.horizontal_wrapped(|ui| {
-                            ui.strong("MC Versions: ");
+                            ui.strong(tr("cf-mc-versions"));
                             ui.label(mc_versions.join(", "));
                         });
                         ui.add_space(4.0);
@@ -452,7 +468,7 @@ impl CfBrowseWidget {
                     if self.state.loading_description {
                         ui.horizontal(|ui| {
                             ui.spinner();
-                            ui.label("Loading description...");
+                            ui.label(tr("browse-loading-description"));
                         });
                     } else if let Some(desc) = &self.state.description {
                         ui.label(desc);
@@ -465,34 +481,36 @@ impl CfBrowseWidget {
                     ui.add_space(4.0);
 
                     // ── Version picker ──
-                    ui.strong("Version Selection");
+                    ui.strong(tr("browse-version-selection"));
                     ui.add_space(4.0);
 
                     if self.state.loading_versions {
                         ui.horizontal(|ui| {
                             ui.spinner();
-                            ui.label("Loading versions...");
+                            ui.label(tr("browse-loading-versions"));
                         });
                     } else if let Some(err) = &self.state.versions_error.clone() {
-                        ui.colored_label(egui::Color32::RED, format!("Error: {}", err));
+                        ui.colored_label(
+                            egui::Color32::RED,
+                            tr_args("browse-error", &[("error", err.into())]),
+                        );
                     } else if self.state.versions.is_empty() {
-                        ui.label("No versions found.");
+                        ui.label(tr("browse-no-versions"));
                     } else {
                         // ── MC Version dropdown ──
                         ui.horizontal(|ui| {
-                            ui.label("Minecraft Version:");
+                            ui.label(tr("browse-minecraft-version"));
                             let mc_label = self
                                 .state
                                 .selected_mc_version
-                                .as_deref()
-                                .unwrap_or("Select...");
+                                .clone()
+                                .unwrap_or_else(|| tr("browse-select"));
                             egui::ComboBox::from_id_salt("cf_mc_version_picker")
                                 .selected_text(mc_label)
                                 .show_ui(ui, |ui| {
                                     for ver in &self.state.mc_versions.clone() {
-                                        let is_sel =
-                                            self.state.selected_mc_version.as_deref()
-                                                == Some(ver.as_str());
+                                        let is_sel = self.state.selected_mc_version.as_deref()
+                                            == Some(ver.as_str());
                                         if ui.selectable_label(is_sel, ver).clicked() {
                                             self.state.selected_mc_version = Some(ver.clone());
                                             self.state.selected_file_idx = None;
@@ -524,13 +542,12 @@ impl CfBrowseWidget {
                             .state
                             .selected_file_idx
                             .and_then(|idx| self.state.versions.get(idx))
-                            .map(|f| f.display_name.as_str())
-                            .unwrap_or("Select...");
+                            .map_or_else(|| tr("browse-select"), |f| f.display_name.clone());
 
                         let mut clicked_file_idx: Option<usize> = None;
 
                         ui.horizontal(|ui| {
-                            ui.label("Pack Version:");
+                            ui.label(tr("browse-pack-version"));
                             egui::ComboBox::from_id_salt("cf_pack_version_picker")
                                 .selected_text(file_label)
                                 .width(300.0)
@@ -553,7 +570,21 @@ impl CfBrowseWidget {
                         }
 
                         if filtered_files.is_empty() {
-                            ui.small("No files for this Minecraft version.");
+                            ui.small(tr("cf-no-files-for-mc"));
+                        }
+
+                        // ── Install method ──
+                        if let Some(file) = self
+                            .state
+                            .selected_file_idx
+                            .and_then(|idx| self.state.versions.get(idx))
+                            .cloned()
+                        {
+                            ui.add_space(8.0);
+                            if self.show_install_method(ui, &file) {
+                                self.build_cf_template(&selected, &file);
+                                built = true;
+                            }
                         }
                     }
                 });
@@ -562,6 +593,55 @@ impl CfBrowseWidget {
         built
     }
 
+    /// Whether the selected file has a server pack, and the choice between it and
+    /// the client manifest. Returns true when the choice changed.
+    fn show_install_method(&mut self, ui: &mut egui::Ui, file: &CfFile) -> bool {
+        let before = self.install_method;
+        match file.server_pack_file_id {
+            Some(id) => {
+                ui.label(tr_args(
+                    "cf-has-server-pack",
+                    &[("file", id.to_string().into())],
+                ));
+            }
+            None => {
+                ui.label(tr("cf-no-server-pack"));
+            }
+        }
+        ui.horizontal(|ui| {
+            ui.label(tr("cf-install-from"));
+            ui.radio_value(
+                &mut self.install_method,
+                CfInstallMethod::ClientManifest,
+                tr("cf-client-manifest"),
+            )
+            .on_hover_text(tr("cf-client-manifest-hint"));
+            ui.add_enabled_ui(file.server_pack_file_id.is_some(), |ui| {
+                ui.radio_value(
+                    &mut self.install_method,
+                    CfInstallMethod::ServerPack,
+                    tr("cf-server-pack"),
+                )
+                .on_hover_text(tr("cf-server-pack-hint"))
+                .on_disabled_hover_text(tr("cf-server-pack-missing"));
+            });
+        });
+        self.install_method != before
+    }
+
+    /// Show `cf_mod` as the only result and select it, as if the user had searched
+    /// for `query` and clicked it. The caller dispatches the version/description fetches.
+    pub fn preselect(&mut self, query: &str, cf_mod: CfMod) {
+        self.state = CfBrowseState::default();
+        self.state.search.query = query.to_string();
+        self.state.results = vec![cf_mod.clone()];
+        self.state.total_count = 1;
+        self.state.selected_mod = Some(cf_mod);
+        self.state.loading_versions = true;
+        self.state.loading_description = true;
+        self.template = None;
+    }
+
     // ── Build template from CF data ────────────────────────────────────
 
     pub fn build_cf_template(&mut self, cf_mod: &CfMod, cf_file: &CfFile) {
@@ -590,9 +670,20 @@ impl CfBrowseWidget {
             ModLoader::Forge
         };
 
-        // AUTO_CURSEFORGE needs the client modpack file (which has the manifest),
-        // not the server pack file. Always use the main file id.
+        // AUTO_CURSEFORGE needs the client modpack file (which has the manifest);
+        // the server pack, when chosen, has an ID of its own
         let file_id = cf_file.id;
+        let slug = cf_mod.slug.clone();
+        let source = match cf_file.server_pack_file_id {
+            Some(server_pack_file_id) if self.install_method == CfInstallMethod::ServerPack => {
+                ModpackSource::CurseForgeServerPack {
+                    slug,
+                    file_id,
+                    server_pack_file_id,
+                }
+            }
+            _ => ModpackSource::CurseForge { slug, file_id },
+        };
 
         let java_version = curseforge::infer_java_version(&mc_version);
         let memory = curseforge::default_memory_mb(&mc_version);
@@ -603,14 +694,13 @@ impl CfBrowseWidget {
             version: cf_file.display_name.clone(),
             minecraft_version: mc_version,
             loader,
-            source: ModpackSource::CurseForge {
-                slug: cf_mod.slug.clone(),
-                file_id,
-            },
+            source,
             recommended_memory_mb: memory,
             java_version,
-            default_java_args: curseforge::default_java_args(),
+            default_java_args: JvmPreset::Aikar.args(java_version, memory),
             default_extra_env: vec![],
+            world_url: None,
+            server_properties: None,
         };
 
         self.template = Some(template);
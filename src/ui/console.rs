@@ -0,0 +1,463 @@
+use crate::i18n::tr;
+use crate::server::{default_quick_commands, QuickCommand};
+use eframe::egui;
+
+/// Commands whose arguments are usually player names
+const PLAYER_COMMANDS: &[&str] = &[
+    "op",
+    "deop",
+    "tp",
+    "teleport",
+    "kick",
+    "ban",
+    "pardon",
+    "whitelist",
+    "msg",
+    "tell",
+    "w",
+    "gamemode",
+    "give",
+    "kill",
+    "clear",
+    "effect",
+    "xp",
+    "experience",
+    "spawnpoint",
+];
+
+/// Most names shown under the input at once
+const MAX_SUGGESTIONS: usize = 8;
+
+/// Byte offset and text of the word being typed, if it is a player-name argument
+fn player_word(input: &str) -> Option<(usize, &str)> {
+    let mut words = input.trim_start_matches('/').split_whitespace();
+    let command = words.next()?.to_lowercase();
+    if !PLAYER_COMMANDS.contains(&command.as_str()) {
+        return None;
+    }
+
+    let start = input.rfind(char::is_whitespace)? + 1;
+    // `whitelist add <player>`: the first argument is the subcommand, not a name
+    if command == "whitelist" && input[..start].split_whitespace().count() < 2 {
+        return None;
+    }
+    Some((start, &input[start..]))
+}
+
+/// Online players matching the word under the cursor
+fn suggestions<'a>(input: &str, players: &'a [String]) -> Vec<&'a str> {
+    let Some((_, prefix)) = player_word(input) else {
+        return Vec::new();
+    };
+    let prefix = prefix.to_lowercase();
+    players
+        .iter()
+        .filter(|p| p.to_lowercase().starts_with(&prefix) && p.len() > prefix.len())
+        .map(|p| p.as_str())
+        .take(MAX_SUGGESTIONS)
+        .collect()
+}
+
+/// Replace the word being typed with `name`
+fn insert_name(input: &str, name: &str) -> String {
+    let start = player_word(input).map(|(s, _)| s).unwrap_or(input.len());
+    format!("{}{} ", &input[..start], name)
+}
+
+/// Tab completion: a unique match is filled in, otherwise the shared prefix of all matches
+fn complete(input: &str, players: &[String]) -> Option<String> {
+    let matches = suggestions(input, players);
+    let (first, rest) = matches.split_first()?;
+    if rest.is_empty() {
+        return Some(insert_name(input, first));
+    }
+
+    let (start, typed) = player_word(input)?;
+    let common = rest.iter().fold(first.len(), |len, other| {
+        first[..len]
+            .char_indices()
+            .zip(other.chars())
+            .find(|((_, a), b)| !a.eq_ignore_ascii_case(b))
+            .map(|((i, _), _)| i)
+            .unwrap_or(len.min(other.len()))
+    });
+    (common > typed.len()).then(|| format!("{}{}", &input[..start], &first[..common]))
+}
+
+/// Command line with player-name completion (Tab, or click a suggested name)
+pub struct CommandLine;
+
+impl CommandLine {
+    /// Height to reserve below the output for the input row and suggestions
+    pub fn height(input: &str, players: &[String]) -> f32 {
+        if suggestions(input, players).is_empty() {
+            35.0
+        } else {
+            60.0
+        }
+    }
+
+    fn id() -> egui::Id {
+        egui::Id::new("console_command_line")
+    }
+
+    /// Move keyboard focus to the input row
+    pub fn focus(ctx: &egui::Context) {
+        ctx.memory_mut(|m| m.request_focus(Self::id()));
+    }
+
+    /// Show the input row. Returns true when the user asked to send the command.
+    pub fn show(ui: &mut egui::Ui, input: &mut String, players: &[String]) -> bool {
+        let id = Self::id();
+        let mut send = false;
+        let mut completed = None;
+
+        let matches: Vec<String> = suggestions(input, players)
+            .into_iter()
+            .map(String::from)
+            .collect();
+        if !matches.is_empty() {
+            ui.horizontal_wrapped(|ui| {
+                ui.small(tr("console-players"));
+                for name in &matches {
+                    if ui.small_button(name).clicked() {
+                        completed = Some(insert_name(input, name));
+                    }
+                }
+                ui.small(tr("console-tab-hint"));
+            });
+        }
+
+        ui.horizontal(|ui| {
+            ui.label(">");
+            let response = ui.add(
+                egui::TextEdit::singleline(input)
+                    .id(id)
+                    .lock_focus(true)
+                    .desired_width(ui.available_width() - 70.0)
+                    .font(egui::TextStyle::Monospace)
+                    .hint_text(tr("console-input-hint")),
+            );
+
+            // Keep focus on Tab and use it for completion instead
+            if response.has_focus() && ui.input(|i| i.key_pressed(egui::Key::Tab)) {
+                completed = complete(input, players);
+            }
+
+            // Send on Enter key
+            if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                send = true;
+            }
+
+            if ui.button(tr("console-send")).clicked() {
+                send = true;
+            }
+        });
+
+        if let Some(text) = completed {
+            *input = text;
+            let ctx = ui.ctx();
+            if let Some(mut state) = egui::TextEdit::load_state(ctx, id) {
+                let end = egui::text::CCursor::new(input.chars().count());
+                state
+                    .cursor
+                    .set_char_range(Some(egui::text::CCursorRange::one(end)));
+                state.store(ctx, id);
+            }
+            ctx.memory_mut(|m| m.request_focus(id));
+        }
+
+        send
+    }
+}
+
+/// What the user did with the quick-command buttons
+#[derive(Debug, Clone, PartialEq)]
+pub enum QuickCommandAction {
+    Send(String),
+    /// The edited button list to store on the server
+    Save(Vec<QuickCommand>),
+}
+
+/// Row of user-editable buttons that each send one command
+pub struct QuickCommandBar;
+
+impl QuickCommandBar {
+    /// Height of the button row, for reserving space below the output
+    pub const HEIGHT: f32 = 30.0;
+
+    /// Show the buttons. `draft` holds the list being edited while the editor is open.
+    pub fn show(
+        ui: &mut egui::Ui,
+        commands: &[QuickCommand],
+        draft: &mut Option<Vec<QuickCommand>>,
+    ) -> Option<QuickCommandAction> {
+        let mut action = None;
+
+        ui.horizontal_wrapped(|ui| {
+            for quick in commands {
+                if ui
+                    .button(&quick.label)
+                    .on_hover_text(&quick.command)
+                    .clicked()
+                {
+                    action = Some(QuickCommandAction::Send(quick.command.clone()));
+                }
+            }
+            if ui
+                .small_button("✏")
+                .on_hover_text(tr("console-quick-edit"))
+                .clicked()
+            {
+                *draft = Some(commands.to_vec());
+            }
+        });
+
+        let mut open = draft.is_some();
+        let mut close = false;
+        if let Some(edit) = draft.as_mut() {
+            egui::Window::new(tr("console-quick-title"))
+                .open(&mut open)
+                .collapsible(false)
+                .resizable(false)
+                .show(ui.ctx(), |ui| {
+                    egui::Grid::new("quick_commands_grid")
+                        .num_columns(3)
+                        .spacing([8.0, 4.0])
+                        .show(ui, |ui| {
+                            ui.strong(tr("console-quick-label"));
+                            ui.strong(tr("console-quick-command"));
+                            ui.end_row();
+
+                            let mut remove = None;
+                            for (i, quick) in edit.iter_mut().enumerate() {
+                                ui.add(
+                                    egui::TextEdit::singleline(&mut quick.label)
+                                        .desired_width(120.0),
+                                );
+                                ui.add(
+                                    egui::TextEdit::singleline(&mut quick.command)
+                                        .desired_width(220.0)
+                                        .font(egui::TextStyle::Monospace),
+                                );
+                                if ui.small_button("✖").clicked() {
+                                    remove = Some(i);
+                                }
+                                ui.end_row();
+                            }
+                            if let Some(i) = remove {
+                                edit.remove(i);
+                            }
+                        });
+
+                    ui.horizontal(|ui| {
+                        if ui.button(tr("console-quick-add")).clicked() {
+                            edit.push(QuickCommand::default());
+                        }
+                        if ui.button(tr("console-quick-reset")).clicked() {
+                            *edit = default_quick_commands();
+                        }
+                    });
+                    ui.separator();
+                    ui.horizontal(|ui| {
+                        if ui.button(tr("button-save")).clicked() {
+                            edit.retain(|q| {
+                                !q.label.trim().is_empty() && !q.command.trim().is_empty()
+                            });
+                            action = Some(QuickCommandAction::Save(edit.clone()));
+                            close = true;
+                        }
+                        if ui.button(tr("button-cancel")).clicked() {
+                            close = true;
+                        }
+                    });
+                });
+        }
+        if !open || close {
+            *draft = None;
+        }
+
+        action
+    }
+}
+
+// ── Formatted output ───────────────────────────────────────────────────────
+
+/// Style carried by Minecraft formatting codes. Bold and obfuscated have no egui equivalent
+/// and are dropped.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct McStyle {
+    color: Option<egui::Color32>,
+    italic: bool,
+    underline: bool,
+    strikethrough: bool,
+}
+
+/// Colour for a legacy code (`§0`..`§f`) or JSON colour name
+fn mc_color(code: &str) -> Option<egui::Color32> {
+    let rgb = match code {
+        "0" | "black" => 0x000000,
+        "1" | "dark_blue" => 0x0000AA,
+        "2" | "dark_green" => 0x00AA00,
+        "3" | "dark_aqua" => 0x00AAAA,
+        "4" | "dark_red" => 0xAA0000,
+        "5" | "dark_purple" => 0xAA00AA,
+        "6" | "gold" => 0xFFAA00,
+        "7" | "gray" => 0xAAAAAA,
+        "8" | "dark_gray" => 0x555555,
+        "9" | "blue" => 0x5555FF,
+        "a" | "green" => 0x55FF55,
+        "b" | "aqua" => 0x55FFFF,
+        "c" | "red" => 0xFF5555,
+        "d" | "light_purple" => 0xFF55FF,
+        "e" | "yellow" => 0xFFFF55,
+        "f" | "white" => 0xFFFFFF,
+        hex => u32::from_str_radix(hex.strip_prefix('#')?, 16).ok()?,
+    };
+    let [_, r, g, b] = u32::to_be_bytes(rgb);
+    Some(egui::Color32::from_rgb(r, g, b))
+}
+
+/// Split text with `§` codes into styled runs. Handles Spigot's `§x§R§R§G§G§B§B` hex colours.
+fn parse_legacy(text: &str, base: McStyle, out: &mut Vec<(String, McStyle)>) {
+    let mut style = base;
+    let mut run = String::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '§' {
+            run.push(c);
+            continue;
+        }
+        let Some(code) = chars.next().map(|c| c.to_ascii_lowercase()) else {
+            break;
+        };
+        if !run.is_empty() {
+            out.push((std::mem::take(&mut run), style));
+        }
+        match code {
+            'x' => {
+                let hex: String = (0..6)
+                    .filter_map(|_| {
+                        chars.next_if_eq(&'§')?;
+                        chars.next()
+                    })
+                    .collect();
+                style.color = mc_color(&format!("#{}", hex)).or(style.color);
+            }
+            'o' => style.italic = true,
+            'n' => style.underline = true,
+            'm' => style.strikethrough = true,
+            'r' => style = base,
+            'k' | 'l' => {}
+            // A colour code also clears formatting, as in the client
+            c => {
+                if let Some(color) = mc_color(&c.to_string()) {
+                    style = McStyle {
+                        color: Some(color),
+                        ..McStyle::default()
+                    };
+                }
+            }
+        }
+    }
+    if !run.is_empty() {
+        out.push((run, style));
+    }
+}
+
+/// Flatten a JSON text component (as printed for `tellraw`-style output) into styled runs
+fn parse_json(value: &serde_json::Value, inherited: McStyle, out: &mut Vec<(String, McStyle)>) {
+    match value {
+        serde_json::Value::String(text) => parse_legacy(text, inherited, out),
+        serde_json::Value::Array(parts) => {
+            for part in parts {
+                parse_json(part, inherited, out);
+            }
+        }
+        serde_json::Value::Object(obj) => {
+            let mut style = inherited;
+            if let Some(color) = obj.get("color").and_then(|c| c.as_str()).and_then(mc_color) {
+                style.color = Some(color);
+            }
+            let flag = |key: &str, current: bool| {
+                obj.get(key).and_then(|v| v.as_bool()).unwrap_or(current)
+            };
+            style.italic = flag("italic", style.italic);
+            style.underline = flag("underlined", style.underline);
+            style.strikethrough = flag("strikethrough", style.strikethrough);
+
+            let text = obj
+                .get("text")
+                .or_else(|| obj.get("translate"))
+                .and_then(|t| t.as_str())
+                .unwrap_or_default();
+            parse_legacy(text, style, out);
+            if let Some(extra) = obj.get("extra") {
+                parse_json(extra, style, out);
+            }
+        }
+        other => out.push((other.to_string(), inherited)),
+    }
+}
+
+/// Lay out one console line, rendering formatting codes or a JSON text component as colours
+pub fn formatted_line(ui: &egui::Ui, line: &str) -> egui::text::LayoutJob {
+    let mut runs = Vec::new();
+    let trimmed = line.trim_start();
+    let json = (trimmed.starts_with('{') || trimmed.starts_with('['))
+        .then(|| serde_json::from_str::<serde_json::Value>(trimmed).ok())
+        .flatten();
+    match json {
+        Some(value) => parse_json(&value, McStyle::default(), &mut runs),
+        None => parse_legacy(line, McStyle::default(), &mut runs),
+    }
+
+    layout_runs(
+        runs,
+        egui::TextStyle::Monospace.resolve(ui.style()),
+        ui.visuals().text_color(),
+    )
+}
+
+/// Lay out an MOTD the way the multiplayer server list shows it: gray unless coloured
+pub fn motd_preview(ui: &egui::Ui, motd: &str) -> egui::text::LayoutJob {
+    let mut runs = Vec::new();
+    parse_legacy(motd, McStyle::default(), &mut runs);
+    layout_runs(
+        runs,
+        egui::TextStyle::Body.resolve(ui.style()),
+        egui::Color32::from_rgb(0xAA, 0xAA, 0xAA),
+    )
+}
+
+fn layout_runs(
+    runs: Vec<(String, McStyle)>,
+    font_id: egui::FontId,
+    default_color: egui::Color32,
+) -> egui::text::LayoutJob {
+    let mut job = egui::text::LayoutJob::default();
+    for (text, style) in runs {
+        let color = style.color.unwrap_or(default_color);
+        let stroke = |on: bool| {
+            if on {
+                egui::Stroke::new(1.0, color)
+            } else {
+                egui::Stroke::NONE
+            }
+        };
+        job.append(
+            &text,
+            0.0,
+            egui::TextFormat {
+                font_id: font_id.clone(),
+                color,
+                italics: style.italic,
+                underline: stroke(style.underline),
+                strikethrough: stroke(style.strikethrough),
+                ..Default::default()
+            },
+        );
+    }
+    job
+}
@@ -0,0 +1,153 @@
+use crate::crash::CrashReport;
+use crate::i18n::{tr, tr_args};
+use eframe::egui;
+use std::path::{Path, PathBuf};
+
+/// What the user chose in the crash report viewer
+#[derive(Debug, Clone, PartialEq)]
+pub enum CrashAction {
+    Back,
+    Select(PathBuf),
+    /// Open the selected report in the system's text editor
+    OpenFile(PathBuf),
+}
+
+pub struct CrashReportView;
+
+impl CrashReportView {
+    /// `reports` is newest first; `selected` is the parsed report being shown
+    pub fn show(
+        ui: &mut egui::Ui,
+        server_name: &str,
+        reports: &[PathBuf],
+        selected: Option<&CrashReport>,
+    ) -> Option<CrashAction> {
+        let mut action = None;
+
+        ui.horizontal(|ui| {
+            ui.heading(tr_args("crash-title", &[("server", server_name.into())]));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button(tr("button-back")).clicked() {
+                    action = Some(CrashAction::Back);
+                }
+            });
+        });
+        ui.separator();
+
+        if reports.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.add_space(50.0);
+                ui.label(tr("crash-none"));
+            });
+            return action;
+        }
+
+        ui.horizontal_top(|ui| {
+            ui.vertical(|ui| {
+                ui.set_width(260.0);
+                ui.strong(tr_args("crash-count", &[("count", reports.len().into())]));
+                egui::ScrollArea::vertical()
+                    .id_salt("crash_report_list")
+                    .show(ui, |ui| {
+                        for path in reports {
+                            let is_selected = selected.is_some_and(|r| r.path == *path);
+                            if ui.selectable_label(is_selected, file_name(path)).clicked()
+                                && !is_selected
+                            {
+                                action = Some(CrashAction::Select(path.clone()));
+                            }
+                        }
+                    });
+            });
+            ui.separator();
+            ui.vertical(|ui| match selected {
+                Some(report) => {
+                    if let Some(a) = Self::summary(ui, report) {
+                        action = Some(a);
+                    }
+                }
+                None => {
+                    ui.label(tr("crash-select"));
+                }
+            });
+        });
+
+        action
+    }
+
+    fn summary(ui: &mut egui::Ui, report: &CrashReport) -> Option<CrashAction> {
+        let mut action = None;
+        egui::ScrollArea::vertical()
+            .id_salt("crash_report_summary")
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.strong(file_name(&report.path));
+                    if ui.button(tr("crash-open-file")).clicked() {
+                        action = Some(CrashAction::OpenFile(report.path.clone()));
+                    }
+                });
+                ui.add_space(6.0);
+
+                egui::Grid::new("crash_summary_grid")
+                    .num_columns(2)
+                    .spacing([12.0, 6.0])
+                    .show(ui, |ui| {
+                        let unknown = tr("crash-unknown");
+                        ui.label(tr("crash-time"));
+                        ui.label(report.time.as_deref().unwrap_or(&unknown));
+                        ui.end_row();
+                        ui.label(tr("crash-description"));
+                        ui.label(report.description.as_deref().unwrap_or(&unknown));
+                        ui.end_row();
+                        ui.label(tr("crash-suspected-mods"));
+                        if report.suspected_mods.is_empty() {
+                            ui.label(tr("crash-no-suspects"));
+                        } else {
+                            ui.colored_label(
+                                egui::Color32::YELLOW,
+                                report.suspected_mods.join(", "),
+                            );
+                        }
+                        ui.end_row();
+                    });
+
+                if let Some(exception) = &report.exception {
+                    ui.add_space(8.0);
+                    ui.strong(tr("crash-exception"));
+                    ui.colored_label(egui::Color32::from_rgb(235, 90, 90), exception);
+                    for frame in &report.stack_head {
+                        ui.monospace(format!("    {}", frame));
+                    }
+                }
+
+                if !report.registry_names.is_empty() {
+                    ui.add_space(8.0);
+                    ui.strong(tr("crash-registry-names"));
+                    ui.horizontal_wrapped(|ui| {
+                        for name in &report.registry_names {
+                            ui.monospace(name);
+                            ui.add_space(6.0);
+                        }
+                    });
+                }
+
+                ui.add_space(8.0);
+                egui::CollapsingHeader::new(tr("crash-full-report"))
+                    .id_salt("crash_full_report")
+                    .show(ui, |ui| {
+                        ui.add(
+                            egui::TextEdit::multiline(&mut report.raw.as_str())
+                                .font(egui::TextStyle::Monospace)
+                                .desired_width(f32::INFINITY),
+                        );
+                    });
+            });
+        action
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
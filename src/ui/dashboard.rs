@@ -1,26 +1,362 @@
-use crate::server::{ServerInstance, ServerStatus};
+use crate::backup::format_bytes;
+use crate::disk_usage::ServerUsage;
+use crate::i18n::{tr, tr_args};
+use crate::known_errors::Diagnosis;
+use crate::pack_installer::PackProgress;
+use crate::playtime::format_duration;
+use crate::server::{LiveStatus, ModLoader, ServerConfig, ServerInstance, ServerStatus};
+use crate::startup::{self, StartupProgress};
+use crate::ui::formatted_line;
 use eframe::egui;
+use std::collections::{HashMap, HashSet};
+use std::net::SocketAddr;
 
 /// Progress info: (server_name, current, total, current_file)
 pub type ProgressInfo = Option<(String, usize, usize, String)>;
 
+/// Download progress info: (server_name, downloaded_bytes, total_bytes)
+pub type DownloadInfo = Option<(String, u64, Option<u64>)>;
+
+/// Server pack install progress: (server_name, progress)
+pub type PackInstallInfo = Option<(String, PackProgress)>;
+
+/// Status groups the server list can be narrowed to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusFilter {
+    Running,
+    Stopped,
+    /// On its way up or down
+    Busy,
+    /// Errored or frozen
+    Problem,
+}
+
+impl StatusFilter {
+    const ALL: [StatusFilter; 4] = [
+        StatusFilter::Running,
+        StatusFilter::Stopped,
+        StatusFilter::Busy,
+        StatusFilter::Problem,
+    ];
+
+    fn label(self) -> String {
+        match self {
+            StatusFilter::Running => tr("server-status-running"),
+            StatusFilter::Stopped => tr("server-status-stopped"),
+            StatusFilter::Busy => tr("dashboard-filter-busy"),
+            StatusFilter::Problem => tr("dashboard-filter-problem"),
+        }
+    }
+
+    fn matches(self, status: &ServerStatus) -> bool {
+        match self {
+            StatusFilter::Running => *status == ServerStatus::Running,
+            StatusFilter::Stopped => *status == ServerStatus::Stopped,
+            StatusFilter::Busy => matches!(
+                status,
+                ServerStatus::Pulling
+                    | ServerStatus::Starting
+                    | ServerStatus::Initializing
+                    | ServerStatus::Stopping
+            ),
+            StatusFilter::Problem => {
+                matches!(status, ServerStatus::Error(_) | ServerStatus::Frozen)
+            }
+        }
+    }
+}
+
+/// Order of the server list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DashboardSort {
+    /// The order servers were created in
+    #[default]
+    Added,
+    Name,
+    /// Problems first, stopped servers last
+    Status,
+    Port,
+    /// Largest allocation first
+    Memory,
+}
+
+impl DashboardSort {
+    const ALL: [DashboardSort; 5] = [
+        DashboardSort::Added,
+        DashboardSort::Name,
+        DashboardSort::Status,
+        DashboardSort::Port,
+        DashboardSort::Memory,
+    ];
+
+    fn label(self) -> String {
+        match self {
+            DashboardSort::Added => tr("dashboard-sort-added"),
+            DashboardSort::Name => tr("dashboard-sort-name"),
+            DashboardSort::Status => tr("dashboard-sort-status"),
+            DashboardSort::Port => tr("dashboard-sort-port"),
+            DashboardSort::Memory => tr("dashboard-sort-memory"),
+        }
+    }
+}
+
+/// Search, filters and sort order of the server list, kept by app.rs between frames
+#[derive(Debug, Clone, Default)]
+pub struct DashboardFilter {
+    /// Matched against the server name, modpack name and Minecraft version
+    pub query: String,
+    pub status: Option<StatusFilter>,
+    pub loader: Option<ModLoader>,
+    pub mc_version: Option<String>,
+    /// Backup group
+    pub group: Option<String>,
+    pub sort: DashboardSort,
+}
+
+impl DashboardFilter {
+    fn is_active(&self) -> bool {
+        !self.query.trim().is_empty()
+            || self.status.is_some()
+            || self.loader.is_some()
+            || self.mc_version.is_some()
+            || self.group.is_some()
+    }
+
+    fn clear(&mut self) {
+        *self = Self {
+            sort: self.sort,
+            ..Self::default()
+        };
+    }
+
+    fn matches(&self, server: &ServerInstance) -> bool {
+        let config = &server.config;
+        let query = self.query.trim().to_lowercase();
+        (query.is_empty()
+            || config.name.to_lowercase().contains(&query)
+            || config.modpack.name.to_lowercase().contains(&query)
+            || config.modpack.minecraft_version.contains(&query))
+            && self.status.is_none_or(|s| s.matches(&server.status))
+            && self
+                .loader
+                .as_ref()
+                .is_none_or(|l| *l == config.modpack.loader)
+            && self
+                .mc_version
+                .as_ref()
+                .is_none_or(|v| *v == config.modpack.minecraft_version)
+            && self
+                .group
+                .as_ref()
+                .is_none_or(|g| config.backup_group.as_ref() == Some(g))
+    }
+
+    /// Servers that pass the filters, in the chosen order
+    fn apply<'s>(&self, servers: &'s [ServerInstance]) -> Vec<&'s ServerInstance> {
+        let mut shown: Vec<&ServerInstance> = servers.iter().filter(|s| self.matches(s)).collect();
+        match self.sort {
+            DashboardSort::Added => {}
+            DashboardSort::Name => shown.sort_by_key(|s| s.config.name.to_lowercase()),
+            DashboardSort::Status => shown.sort_by_key(|s| status_rank(&s.status)),
+            DashboardSort::Port => shown.sort_by_key(|s| s.config.port),
+            DashboardSort::Memory => shown.sort_by_key(|s| std::cmp::Reverse(s.config.memory_mb)),
+        }
+        shown
+    }
+
+    /// Search box, filter dropdowns and sort order. Only values some server
+    /// actually has are offered.
+    fn show(&mut self, ui: &mut egui::Ui, servers: &[ServerInstance], shown: usize) {
+        let mut loaders: Vec<ModLoader> = Vec::new();
+        let mut versions: Vec<String> = Vec::new();
+        let mut groups: Vec<String> = Vec::new();
+        for server in servers {
+            let config = &server.config;
+            if !loaders.contains(&config.modpack.loader) {
+                loaders.push(config.modpack.loader.clone());
+            }
+            if !versions.contains(&config.modpack.minecraft_version) {
+                versions.push(config.modpack.minecraft_version.clone());
+            }
+            if let Some(group) = &config.backup_group {
+                if !groups.contains(group) {
+                    groups.push(group.clone());
+                }
+            }
+        }
+        versions.sort();
+        groups.sort();
+
+        ui.horizontal_wrapped(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut self.query)
+                    .hint_text(tr("dashboard-search-hint"))
+                    .desired_width(220.0),
+            );
+
+            let any_status = tr("dashboard-any-status");
+            egui::ComboBox::from_id_salt("dashboard_status_filter")
+                .selected_text(self.status.map_or(any_status.clone(), |s| s.label()))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.status, None, any_status);
+                    for status in StatusFilter::ALL {
+                        ui.selectable_value(&mut self.status, Some(status), status.label());
+                    }
+                });
+
+            let any_loader = tr("dashboard-any-loader");
+            egui::ComboBox::from_id_salt("dashboard_loader_filter")
+                .selected_text(
+                    self.loader
+                        .as_ref()
+                        .map_or(any_loader.clone(), |l| format!("{:?}", l)),
+                )
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.loader, None, any_loader);
+                    for loader in loaders {
+                        let label = format!("{:?}", loader);
+                        ui.selectable_value(&mut self.loader, Some(loader), label);
+                    }
+                });
+
+            let any_version = tr("dashboard-any-version");
+            egui::ComboBox::from_id_salt("dashboard_version_filter")
+                .selected_text(self.mc_version.clone().unwrap_or(any_version.clone()))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.mc_version, None, any_version);
+                    for version in versions {
+                        let label = format!("MC {}", version);
+                        ui.selectable_value(&mut self.mc_version, Some(version), label);
+                    }
+                });
+
+            if !groups.is_empty() || self.group.is_some() {
+                let any_group = tr("dashboard-any-group");
+                egui::ComboBox::from_id_salt("dashboard_group_filter")
+                    .selected_text(self.group.clone().unwrap_or(any_group.clone()))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut self.group, None, any_group);
+                        for group in groups {
+                            let label = group.clone();
+                            ui.selectable_value(&mut self.group, Some(group), label);
+                        }
+                    });
+            }
+
+            ui.separator();
+            ui.label(tr("dashboard-sort"));
+            egui::ComboBox::from_id_salt("dashboard_sort")
+                .selected_text(self.sort.label())
+                .show_ui(ui, |ui| {
+                    for sort in DashboardSort::ALL {
+                        ui.selectable_value(&mut self.sort, sort, sort.label());
+                    }
+                });
+
+            if self.is_active() {
+                ui.separator();
+                ui.small(tr_args(
+                    "dashboard-showing",
+                    &[("shown", shown.into()), ("total", servers.len().into())],
+                ));
+                if ui.small_button(tr("dashboard-clear-filters")).clicked() {
+                    self.clear();
+                }
+            }
+        });
+    }
+}
+
+/// Indicator color and label key for a server status
+pub(super) fn status_style(status: &ServerStatus) -> (egui::Color32, &'static str) {
+    match status {
+        ServerStatus::Running => (egui::Color32::GREEN, "server-status-running"),
+        ServerStatus::Pulling => (egui::Color32::YELLOW, "server-status-pulling"),
+        ServerStatus::Starting => (egui::Color32::YELLOW, "server-status-starting"),
+        ServerStatus::Initializing => (
+            egui::Color32::from_rgb(255, 165, 0),
+            "server-status-initializing",
+        ), // Orange
+        ServerStatus::Stopping => (egui::Color32::YELLOW, "server-status-stopping"),
+        ServerStatus::Frozen => (egui::Color32::LIGHT_BLUE, "server-status-frozen"),
+        ServerStatus::Stopped => (egui::Color32::GRAY, "server-status-stopped"),
+        ServerStatus::Error(_) => (egui::Color32::RED, "server-status-error"),
+    }
+}
+
+/// Sort key for `DashboardSort::Status`
+fn status_rank(status: &ServerStatus) -> u8 {
+    match status {
+        ServerStatus::Error(_) => 0,
+        ServerStatus::Frozen => 1,
+        ServerStatus::Running => 2,
+        ServerStatus::Pulling
+        | ServerStatus::Starting
+        | ServerStatus::Initializing
+        | ServerStatus::Stopping => 3,
+        ServerStatus::Stopped => 4,
+    }
+}
+
+/// Everyone online, when the server answers queries
+fn player_names(ui: &mut egui::Ui, live: &LiveStatus) {
+    if let Some(query) = live.query.as_ref().filter(|q| !q.players.is_empty()) {
+        ui.separator();
+        ui.label(query.players.join(", "));
+    }
+}
+
 /// Callbacks for server actions on the dashboard
 pub struct DashboardCallbacks<'a> {
     pub on_create_server: &'a mut dyn FnMut(),
     pub on_start_server: &'a mut dyn FnMut(&str),
     pub on_stop_server: &'a mut dyn FnMut(&str),
+    pub on_panic_server: &'a mut dyn FnMut(&str),
+    pub on_unfreeze_server: &'a mut dyn FnMut(&str),
     pub on_edit_server: &'a mut dyn FnMut(&str),
     pub on_delete_server: &'a mut dyn FnMut(&str),
     pub on_view_logs: &'a mut dyn FnMut(&str),
     pub on_backup_server: &'a mut dyn FnMut(&str),
     pub on_view_backups: &'a mut dyn FnMut(&str),
+    pub on_view_mods: &'a mut dyn FnMut(&str),
     pub on_open_console: &'a mut dyn FnMut(&str),
+    pub on_view_details: &'a mut dyn FnMut(&str),
+    pub on_view_crashes: &'a mut dyn FnMut(&str),
     pub on_adopt_server: &'a mut dyn FnMut(&str),
     pub on_delete_orphan: &'a mut dyn FnMut(&str),
     pub on_export_server: &'a mut dyn FnMut(&str),
+    pub on_export_world: &'a mut dyn FnMut(&str),
+    pub on_export_client_pack: &'a mut dyn FnMut(&str),
     pub on_open_folder: &'a mut dyn FnMut(&str),
     pub on_import_server: &'a mut dyn FnMut(),
+    pub on_cancel_restore: &'a mut dyn FnMut(),
+    pub on_toggle_compact: &'a mut dyn FnMut(),
+    /// An address was copied: server name, `host:port`, and whether it's the public one
+    pub on_address_copied: &'a mut dyn FnMut(&str, &str, bool),
     pub orphaned_dirs: &'a [String],
+    /// Servers with a crash report the user hasn't opened yet
+    pub crashed_servers: &'a [String],
+    /// Parsed startup progress of Initializing servers
+    pub startup_progress: &'a HashMap<String, StartupProgress>,
+    /// Likely causes found in the logs of servers in Error
+    pub log_diagnoses: &'a HashMap<String, Vec<Diagnosis>>,
+    /// Backup being sent to remote storage
+    pub upload_progress: &'a DownloadInfo,
+    /// Search, filters and sort order of the list
+    pub filter: &'a mut DashboardFilter,
+    /// Dense table instead of cards
+    pub compact: bool,
+    /// When each running server's container started
+    pub running_since: &'a HashMap<String, chrono::DateTime<chrono::Utc>>,
+    /// Last status ping answer from each running server
+    pub live_status: &'a HashMap<String, LiveStatus>,
+    /// Space used by each server's folder and backups, once measured
+    pub disk_usage: &'a HashMap<String, ServerUsage>,
+    /// This machine's LAN IP, and the public IP when detection is on
+    pub lan_ip: Option<&'a str>,
+    pub public_ip: Option<&'a str>,
+    /// Servers whose preflight checks are still running before a start
+    pub checking: &'a HashSet<String>,
 }
 
 pub struct DashboardView;
@@ -32,18 +368,26 @@ impl DashboardView {
         backup_progress: &ProgressInfo,
         restore_progress: &ProgressInfo,
         export_progress: &ProgressInfo,
+        pack_progress: &PackInstallInfo,
         cb: &mut DashboardCallbacks<'_>,
     ) {
         let orphaned_dirs = cb.orphaned_dirs;
         ui.horizontal(|ui| {
-            ui.heading("Servers");
+            ui.heading(tr("nav-servers"));
             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                if ui.button("+ New Server").clicked() {
+                if ui.button(tr("dashboard-new-server")).clicked() {
                     (cb.on_create_server)();
                 }
-                if ui.button("Import Server").clicked() {
+                if ui.button(tr("dashboard-import")).clicked() {
                     (cb.on_import_server)();
                 }
+                if ui
+                    .selectable_label(cb.compact, tr("dashboard-compact"))
+                    .on_hover_text(tr("dashboard-compact-hint"))
+                    .clicked()
+                {
+                    (cb.on_toggle_compact)();
+                }
             });
         });
         ui.separator();
@@ -52,14 +396,40 @@ impl DashboardView {
         if servers.is_empty() && orphaned_dirs.is_empty() {
             ui.vertical_centered(|ui| {
                 ui.add_space(50.0);
-                ui.label("No servers configured yet.");
-                ui.label("Click 'Create New Server' to get started!");
+                ui.label(tr("dashboard-empty"));
+                ui.label(tr("dashboard-empty-hint"));
             });
         } else {
+            let shown = cb.filter.apply(servers);
+            if !servers.is_empty() {
+                cb.filter.show(ui, servers, shown.len());
+                ui.add_space(6.0);
+            }
+            if shown.is_empty() && !servers.is_empty() {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(30.0);
+                    ui.label(tr("dashboard-no-matches"));
+                });
+            }
             egui::ScrollArea::vertical().show(ui, |ui| {
-                for server in servers {
-                    Self::server_card(ui, server, backup_progress, restore_progress, export_progress, cb);
-                    ui.add_space(10.0);
+                if cb.compact {
+                    if !shown.is_empty() {
+                        Self::server_table(ui, &shown, cb);
+                        ui.add_space(10.0);
+                    }
+                } else {
+                    for server in &shown {
+                        Self::server_card(
+                            ui,
+                            server,
+                            backup_progress,
+                            restore_progress,
+                            export_progress,
+                            pack_progress,
+                            cb,
+                        );
+                        ui.add_space(10.0);
+                    }
                 }
 
                 // Orphaned server directories
@@ -69,12 +439,12 @@ impl DashboardView {
                     ui.add_space(8.0);
                     ui.colored_label(
                         egui::Color32::YELLOW,
-                        format!("Orphaned Server Directories ({})", orphaned_dirs.len()),
-                    );
-                    ui.small(
-                        "These folders exist in DrakonixAnvilData/servers/ but aren't tracked. \
-                         They may be from deleted servers.",
+                        tr_args(
+                            "dashboard-orphans",
+                            &[("count", orphaned_dirs.len().into())],
+                        ),
                     );
+                    ui.small(tr("dashboard-orphans-hint"));
                     ui.add_space(8.0);
 
                     for dir_name in orphaned_dirs {
@@ -86,6 +456,182 @@ impl DashboardView {
         }
     }
 
+    /// One row per server: status, players, port, memory and uptime, with
+    /// start/stop and console buttons. Progress bars stay on the cards.
+    fn server_table(
+        ui: &mut egui::Ui,
+        servers: &[&ServerInstance],
+        cb: &mut DashboardCallbacks<'_>,
+    ) {
+        let now = chrono::Utc::now();
+        egui::Grid::new("dashboard_table")
+            .num_columns(8)
+            .striped(true)
+            .spacing([16.0, 6.0])
+            .show(ui, |ui| {
+                ui.label("");
+                ui.strong(tr("dashboard-col-server"));
+                ui.strong(tr("dashboard-col-status"));
+                ui.strong(tr("dashboard-col-players"));
+                ui.strong(tr("dashboard-col-port"));
+                ui.strong(tr("dashboard-col-memory"));
+                ui.strong(tr("dashboard-col-uptime"));
+                ui.label("");
+                ui.end_row();
+
+                for server in servers {
+                    let config = &server.config;
+                    let checking = cb.checking.contains(&config.name);
+                    let (color, status_id) = if checking {
+                        (egui::Color32::YELLOW, "server-status-checking")
+                    } else {
+                        status_style(&server.status)
+                    };
+                    ui.colored_label(color, "●");
+                    if ui
+                        .link(&config.name)
+                        .on_hover_text(tr("dashboard-open-details"))
+                        .clicked()
+                    {
+                        (cb.on_view_details)(&config.name);
+                    }
+                    let status = ui.label(tr(status_id));
+                    if let ServerStatus::Error(err) = &server.status {
+                        status.on_hover_text(err);
+                    }
+                    match cb.live_status.get(&config.name) {
+                        Some(live) => ui
+                            .label(format!("{}/{}", live.players_online, live.players_max))
+                            .on_hover_ui(|ui| {
+                                ui.label(formatted_line(ui, &live.motd));
+                                ui.label(&live.version);
+                                player_names(ui, live);
+                            }),
+                        None => ui.label("—"),
+                    };
+                    ui.label(config.port.to_string());
+                    ui.label(format!("{} MB", config.memory_mb));
+                    match cb.running_since.get(&config.name) {
+                        Some(since) => {
+                            let secs = (now - *since).num_seconds().max(0) as u64;
+                            ui.label(format_duration(secs))
+                        }
+                        None => ui.label("—"),
+                    };
+                    ui.horizontal(|ui| match &server.status {
+                        ServerStatus::Running => {
+                            if ui.small_button(tr("action-stop")).clicked() {
+                                (cb.on_stop_server)(&config.name);
+                            }
+                            if ui.small_button(tr("action-console")).clicked() {
+                                (cb.on_open_console)(&config.name);
+                            }
+                        }
+                        ServerStatus::Frozen => {
+                            if ui.small_button(tr("action-unfreeze")).clicked() {
+                                (cb.on_unfreeze_server)(&config.name);
+                            }
+                        }
+                        ServerStatus::Stopped | ServerStatus::Error(_) => {
+                            if ui
+                                .add_enabled(
+                                    !checking,
+                                    egui::Button::new(tr("action-start")).small(),
+                                )
+                                .clicked()
+                            {
+                                (cb.on_start_server)(&config.name);
+                            }
+                            if ui.small_button(tr("action-edit")).clicked() {
+                                (cb.on_edit_server)(&config.name);
+                            }
+                        }
+                        _ => {
+                            ui.spinner();
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// Menu copying the LAN and public `host:port` to the clipboard, or only the
+    /// bind address when the server listens on one interface
+    fn copy_address_menu(
+        ui: &mut egui::Ui,
+        config: &ServerConfig,
+        cb: &mut DashboardCallbacks<'_>,
+    ) {
+        let name = config.name.as_str();
+        let port = config.port;
+        ui.menu_button(tr("dashboard-copy-address"), |ui| {
+            let mut copied = None;
+            if let Some(ip) = config.bound_address() {
+                let address = SocketAddr::new(ip, port).to_string();
+                if ui
+                    .button(tr_args(
+                        "dashboard-copy-bound",
+                        &[("address", address.as_str().into())],
+                    ))
+                    .on_hover_text(tr("dashboard-copy-bound-hint"))
+                    .clicked()
+                {
+                    copied = Some((address, false));
+                }
+            } else {
+                match cb.lan_ip {
+                    Some(ip) => {
+                        let address = format!("{}:{}", ip, port);
+                        if ui
+                            .button(tr_args(
+                                "dashboard-copy-lan",
+                                &[("address", address.as_str().into())],
+                            ))
+                            .clicked()
+                        {
+                            copied = Some((address, false));
+                        }
+                    }
+                    None => {
+                        let address = format!("localhost:{}", port);
+                        if ui
+                            .button(tr_args(
+                                "dashboard-copy-local",
+                                &[("address", address.as_str().into())],
+                            ))
+                            .clicked()
+                        {
+                            copied = Some((address, false));
+                        }
+                    }
+                }
+                match cb.public_ip {
+                    Some(ip) => {
+                        let address = format!("{}:{}", ip, port);
+                        if ui
+                            .button(tr_args(
+                                "dashboard-copy-public",
+                                &[("address", address.as_str().into())],
+                            ))
+                            .on_hover_text(tr("dashboard-copy-public-hint"))
+                            .clicked()
+                        {
+                            copied = Some((address, true));
+                        }
+                    }
+                    None => {
+                        ui.weak(tr("dashboard-public-ip-off"));
+                    }
+                }
+            }
+            if let Some((address, public)) = copied {
+                ui.ctx().copy_text(address.clone());
+                (cb.on_address_copied)(name, &address, public);
+                ui.close_menu();
+            }
+        });
+    }
+
     fn orphan_row(ui: &mut egui::Ui, dir_name: &str, cb: &mut DashboardCallbacks<'_>) {
         egui::Frame::none()
             .fill(ui.style().visuals.extreme_bg_color)
@@ -100,14 +646,14 @@ impl DashboardView {
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                         if ui
                             .add(
-                                egui::Button::new("Delete")
+                                egui::Button::new(tr("button-delete"))
                                     .fill(egui::Color32::from_rgb(100, 30, 30)),
                             )
                             .clicked()
                         {
                             (cb.on_delete_orphan)(dir_name);
                         }
-                        if ui.button("Adopt").clicked() {
+                        if ui.button(tr("dashboard-adopt")).clicked() {
                             (cb.on_adopt_server)(dir_name);
                         }
                     });
@@ -121,6 +667,7 @@ impl DashboardView {
         backup_progress: &ProgressInfo,
         restore_progress: &ProgressInfo,
         export_progress: &ProgressInfo,
+        pack_progress: &PackInstallInfo,
         cb: &mut DashboardCallbacks<'_>,
     ) {
         // Check if this server has an active backup or restore
@@ -133,6 +680,13 @@ impl DashboardView {
         let this_server_export = export_progress
             .as_ref()
             .filter(|(name, _, _, _)| name == &server.config.name);
+        // Server pack install happens while still in the Pulling phase
+        let this_server_pack = pack_progress
+            .as_ref()
+            .filter(|(name, _)| name == &server.config.name)
+            .filter(|_| server.status == ServerStatus::Pulling)
+            .map(|(_, progress)| progress);
+        let checking = cb.checking.contains(&server.config.name);
         egui::Frame::none()
             .fill(ui.style().visuals.extreme_bg_color)
             .rounding(8.0)
@@ -140,16 +694,12 @@ impl DashboardView {
             .show(ui, |ui| {
                 ui.horizontal(|ui| {
                     // Status indicator
-                    let (color, status_text) = match &server.status {
-                        ServerStatus::Running => (egui::Color32::GREEN, "Running"),
-                        ServerStatus::Pulling => (egui::Color32::YELLOW, "Pulling Image"),
-                        ServerStatus::Starting => (egui::Color32::YELLOW, "Starting"),
-                        ServerStatus::Initializing => {
-                            (egui::Color32::from_rgb(255, 165, 0), "Initializing")
-                        } // Orange
-                        ServerStatus::Stopping => (egui::Color32::YELLOW, "Stopping"),
-                        ServerStatus::Stopped => (egui::Color32::GRAY, "Stopped"),
-                        ServerStatus::Error(_) => (egui::Color32::RED, "Error"),
+                    let (color, status_id) = if this_server_pack.is_some() {
+                        (egui::Color32::YELLOW, "server-status-installing-pack")
+                    } else if checking {
+                        (egui::Color32::YELLOW, "server-status-checking")
+                    } else {
+                        status_style(&server.status)
                     };
 
                     ui.colored_label(color, "●");
@@ -157,29 +707,189 @@ impl DashboardView {
 
                     // Server info
                     ui.vertical(|ui| {
-                        ui.strong(&server.config.name);
-                        ui.label(format!(
-                            "{} - Port {}",
-                            server.config.modpack.name, server.config.port
+                        if ui
+                            .add(
+                                egui::Label::new(egui::RichText::new(&server.config.name).strong())
+                                    .sense(egui::Sense::click()),
+                            )
+                            .on_hover_text(tr("dashboard-open-details"))
+                            .clicked()
+                        {
+                            (cb.on_view_details)(&server.config.name);
+                        }
+                        ui.label(tr_args(
+                            "dashboard-modpack-port",
+                            &[
+                                ("modpack", server.config.modpack.name.as_str().into()),
+                                ("port", server.config.port.to_string().into()),
+                            ],
+                        ));
+                        Self::copy_address_menu(ui, &server.config, cb);
+                        ui.small(tr_args(
+                            "dashboard-status",
+                            &[("status", tr(status_id).into())],
                         ));
-                        ui.small(format!("Status: {}", status_text));
+                        if let Some(since) = cb.running_since.get(&server.config.name) {
+                            let secs = (chrono::Utc::now() - *since).num_seconds().max(0) as u64;
+                            ui.small(tr_args(
+                                "dashboard-uptime",
+                                &[("duration", format_duration(secs).into())],
+                            ));
+                        }
+                        if let Some(usage) = cb.disk_usage.get(&server.config.name) {
+                            ui.small(tr_args(
+                                "dashboard-disk",
+                                &[
+                                    ("data", format_bytes(usage.data_bytes).into()),
+                                    ("backups", format_bytes(usage.backup_bytes).into()),
+                                ],
+                            ));
+                        }
+                        if let Some(live) = cb.live_status.get(&server.config.name) {
+                            let label = ui.label(tr_args(
+                                "dashboard-live",
+                                &[
+                                    ("online", live.players_online.into()),
+                                    ("max", live.players_max.into()),
+                                    ("version", live.version.as_str().into()),
+                                ],
+                            ));
+                            if live.query.as_ref().is_some_and(|q| !q.players.is_empty()) {
+                                label.on_hover_ui(|ui| player_names(ui, live));
+                            }
+                            if let Some(motd) = live.motd.lines().find(|l| !l.trim().is_empty()) {
+                                ui.label(formatted_line(ui, motd));
+                            }
+                        }
                         if let ServerStatus::Error(err) = &server.status {
-                            ui.colored_label(egui::Color32::RED, format!("Error: {}", err));
+                            ui.colored_label(
+                                egui::Color32::RED,
+                                tr_args("dashboard-error", &[("error", err.as_str().into())]),
+                            );
+                            for diagnosis in cb
+                                .log_diagnoses
+                                .get(&server.config.name)
+                                .into_iter()
+                                .flatten()
+                            {
+                                ui.colored_label(
+                                    egui::Color32::YELLOW,
+                                    tr_args(
+                                        "dashboard-likely-cause",
+                                        &[("title", diagnosis.title.as_str().into())],
+                                    ),
+                                )
+                                .on_hover_text(&diagnosis.explanation);
+                                ui.small(tr_args(
+                                    "dashboard-fix",
+                                    &[("fix", diagnosis.fix.as_str().into())],
+                                ));
+                            }
+                        }
+                        if cb.crashed_servers.contains(&server.config.name)
+                            && ui
+                                .link(
+                                    egui::RichText::new(tr("dashboard-new-crash"))
+                                        .color(egui::Color32::RED),
+                                )
+                                .on_hover_text(tr("dashboard-open-crashes"))
+                                .clicked()
+                        {
+                            (cb.on_view_crashes)(&server.config.name);
+                        }
+                        if let Some((_, sent, Some(total))) = cb
+                            .upload_progress
+                            .as_ref()
+                            .filter(|(name, _, _)| name == &server.config.name)
+                        {
+                            let fraction = *sent as f32 / (*total).max(1) as f32;
+                            ui.add(egui::ProgressBar::new(fraction).desired_width(160.0).text(
+                                tr_args(
+                                    "dashboard-uploading",
+                                    &[
+                                        ("sent", format_bytes(*sent).into()),
+                                        ("total", format_bytes(*total).into()),
+                                    ],
+                                ),
+                            ));
                         }
                     });
 
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                         match &server.status {
                             ServerStatus::Running => {
-                                if ui.button("Stop").clicked() {
+                                if ui.button(tr("action-stop")).clicked() {
                                     (cb.on_stop_server)(&server.config.name);
                                 }
-                                if ui.button("Console").clicked() {
+                                if ui.button(tr("action-console")).clicked() {
                                     (cb.on_open_console)(&server.config.name);
                                 }
-                                if ui.button("Logs").clicked() {
+                                if ui.button(tr("action-players")).clicked() {
+                                    (cb.on_view_details)(&server.config.name);
+                                }
+                                if ui.button(tr("action-logs")).clicked() {
+                                    (cb.on_view_logs)(&server.config.name);
+                                }
+                                if let Some((_, current, total, _)) = this_server_backup {
+                                    let progress = if *total > 0 {
+                                        *current as f32 / *total as f32
+                                    } else {
+                                        0.0
+                                    };
+                                    ui.add(
+                                        egui::ProgressBar::new(progress)
+                                            .desired_width(100.0)
+                                            .text(format!("{}/{}", current, total)),
+                                    );
+                                } else if ui
+                                    .button(tr("action-backup"))
+                                    .on_hover_text(tr("action-backup-live-hint"))
+                                    .clicked()
+                                {
+                                    (cb.on_backup_server)(&server.config.name);
+                                }
+                                if ui
+                                    .add(
+                                        egui::Button::new(tr("action-panic"))
+                                            .fill(egui::Color32::from_rgb(140, 30, 30)),
+                                    )
+                                    .on_hover_text(tr("action-panic-hint"))
+                                    .clicked()
+                                {
+                                    (cb.on_panic_server)(&server.config.name);
+                                }
+                            }
+                            ServerStatus::Frozen => {
+                                if ui.button(tr("action-unfreeze")).clicked() {
+                                    (cb.on_unfreeze_server)(&server.config.name);
+                                }
+                                if ui.button(tr("action-stop")).clicked() {
+                                    (cb.on_stop_server)(&server.config.name);
+                                }
+                                if ui.button(tr("action-backups")).clicked() {
+                                    (cb.on_view_backups)(&server.config.name);
+                                }
+                                if ui.button(tr("action-logs")).clicked() {
                                     (cb.on_view_logs)(&server.config.name);
                                 }
+                                if let Some((_, current, total, _)) = this_server_backup {
+                                    let progress = if *total > 0 {
+                                        *current as f32 / *total as f32
+                                    } else {
+                                        0.0
+                                    };
+                                    ui.add(
+                                        egui::ProgressBar::new(progress).desired_width(120.0).text(
+                                            tr_args(
+                                                "progress-snapshot",
+                                                &[
+                                                    ("current", (*current).into()),
+                                                    ("total", (*total).into()),
+                                                ],
+                                            ),
+                                        ),
+                                    );
+                                }
                             }
                             ServerStatus::Stopped | ServerStatus::Error(_) => {
                                 // Show restore progress if in progress
@@ -189,16 +899,31 @@ impl DashboardView {
                                     } else {
                                         0.0
                                     };
+                                    if ui.button(tr("button-cancel")).clicked() {
+                                        (cb.on_cancel_restore)();
+                                    }
                                     ui.add(
-                                        egui::ProgressBar::new(progress)
-                                            .desired_width(120.0)
-                                            .text(format!("Restoring {}/{}", current, total)),
+                                        egui::ProgressBar::new(progress).desired_width(120.0).text(
+                                            tr_args(
+                                                "progress-restoring",
+                                                &[
+                                                    ("current", (*current).into()),
+                                                    ("total", (*total).into()),
+                                                ],
+                                            ),
+                                        ),
                                     );
                                 } else {
-                                    if ui.button("Start").clicked() {
+                                    if ui
+                                        .add_enabled(
+                                            !checking,
+                                            egui::Button::new(tr("action-start")),
+                                        )
+                                        .clicked()
+                                    {
                                         (cb.on_start_server)(&server.config.name);
                                     }
-                                    if ui.button("Edit").clicked() {
+                                    if ui.button(tr("action-edit")).clicked() {
                                         (cb.on_edit_server)(&server.config.name);
                                     }
                                     // Show progress bar if backup in progress, otherwise show Backup button
@@ -213,12 +938,17 @@ impl DashboardView {
                                                 .desired_width(100.0)
                                                 .text(format!("{}/{}", current, total)),
                                         );
-                                    } else if ui.button("Backup").clicked() {
+                                    } else if ui.button(tr("action-backup")).clicked() {
                                         (cb.on_backup_server)(&server.config.name);
                                     }
-                                    if ui.button("Backups").clicked() {
+                                    if ui.button(tr("action-backups")).clicked() {
                                         (cb.on_view_backups)(&server.config.name);
                                     }
+                                    if server.config.modpack.loader != ModLoader::Vanilla
+                                        && ui.button(tr("action-mods")).clicked()
+                                    {
+                                        (cb.on_view_mods)(&server.config.name);
+                                    }
                                     if let Some((_, current, total, _)) = this_server_export {
                                         let progress = if *total > 0 {
                                             *current as f32 / *total as f32
@@ -228,20 +958,52 @@ impl DashboardView {
                                         ui.add(
                                             egui::ProgressBar::new(progress)
                                                 .desired_width(100.0)
-                                                .text(format!("Exporting {}/{}", current, total)),
+                                                .text(tr_args(
+                                                    "progress-exporting",
+                                                    &[
+                                                        ("current", (*current).into()),
+                                                        ("total", (*total).into()),
+                                                    ],
+                                                )),
                                         );
-                                    } else if ui.button("Export").clicked() {
-                                        (cb.on_export_server)(&server.config.name);
+                                    } else {
+                                        ui.menu_button(tr("action-export"), |ui| {
+                                            if ui
+                                                .button(tr("action-export-bundle"))
+                                                .on_hover_text(tr("action-export-bundle-hint"))
+                                                .clicked()
+                                            {
+                                                ui.close_menu();
+                                                (cb.on_export_server)(&server.config.name);
+                                            }
+                                            if ui
+                                                .button(tr("action-export-world"))
+                                                .on_hover_text(tr("action-export-world-hint"))
+                                                .clicked()
+                                            {
+                                                ui.close_menu();
+                                                (cb.on_export_world)(&server.config.name);
+                                            }
+                                            if server.config.modpack.loader != ModLoader::Vanilla
+                                                && ui
+                                                    .button(tr("action-export-client"))
+                                                    .on_hover_text(tr("action-export-client-hint"))
+                                                    .clicked()
+                                            {
+                                                ui.close_menu();
+                                                (cb.on_export_client_pack)(&server.config.name);
+                                            }
+                                        });
                                     }
-                                    if ui.button("Open Folder").clicked() {
+                                    if ui.button(tr("action-open-folder")).clicked() {
                                         (cb.on_open_folder)(&server.config.name);
                                     }
-                                    if ui.button("Logs").clicked() {
+                                    if ui.button(tr("action-logs")).clicked() {
                                         (cb.on_view_logs)(&server.config.name);
                                     }
                                     if ui
                                         .add(
-                                            egui::Button::new("Delete")
+                                            egui::Button::new(tr("button-delete"))
                                                 .fill(egui::Color32::from_rgb(100, 30, 30)),
                                         )
                                         .clicked()
@@ -253,17 +1015,63 @@ impl DashboardView {
                             ServerStatus::Pulling
                             | ServerStatus::Starting
                             | ServerStatus::Initializing => {
-                                ui.spinner();
-                                if ui.button("Stop").clicked() {
+                                let startup = cb
+                                    .startup_progress
+                                    .get(&server.config.name)
+                                    .filter(|p| p.fraction > 0.0);
+                                if startup.is_none() {
+                                    ui.spinner();
+                                }
+                                if ui.button(tr("action-stop")).clicked() {
                                     (cb.on_stop_server)(&server.config.name);
                                 }
-                                if ui.button("Logs").clicked() {
+                                if ui.button(tr("action-logs")).clicked() {
                                     (cb.on_view_logs)(&server.config.name);
                                 }
+                                if let (ServerStatus::Initializing, Some(progress)) =
+                                    (&server.status, startup)
+                                {
+                                    let percent = (progress.fraction * 100.0).round();
+                                    let mut args = vec![
+                                        ("percent", percent.into()),
+                                        ("stage", progress.stage.as_str().into()),
+                                    ];
+                                    let text = match progress.eta() {
+                                        Some(eta) => {
+                                            args.push(("eta", format_eta(eta).into()));
+                                            tr_args("progress-startup-eta", &args)
+                                        }
+                                        None => tr_args("progress-startup", &args),
+                                    };
+                                    ui.add(
+                                        egui::ProgressBar::new(progress.fraction)
+                                            .desired_width(260.0)
+                                            .text(text),
+                                    );
+                                }
+                                if let Some(progress) = this_server_pack {
+                                    pack_progress_bar(ui, progress);
+                                }
+                                let usual =
+                                    startup::typical_startup(&server.config.startup_history);
+                                if let (
+                                    ServerStatus::Starting | ServerStatus::Initializing,
+                                    Some(usual),
+                                ) = (&server.status, usual)
+                                {
+                                    ui.small(tr_args(
+                                        "progress-startup-usual",
+                                        &[("eta", format_eta(usual).into())],
+                                    ))
+                                    .on_hover_text(tr_args(
+                                        "progress-startup-usual-hint",
+                                        &[("count", server.config.startup_history.len().into())],
+                                    ));
+                                }
                             }
                             ServerStatus::Stopping => {
                                 ui.spinner();
-                                if ui.button("Logs").clicked() {
+                                if ui.button(tr("action-logs")).clicked() {
                                     (cb.on_view_logs)(&server.config.name);
                                 }
                             }
@@ -273,3 +1081,73 @@ impl DashboardView {
             });
     }
 }
+
+/// Bar for a server pack's download or extraction
+fn pack_progress_bar(ui: &mut egui::Ui, progress: &PackProgress) {
+    let (fraction, text, hover) = match progress {
+        PackProgress::Download(p) => match p.total {
+            Some(total) if total > 0 => (
+                p.downloaded as f32 / total as f32,
+                tr_args(
+                    "pack-downloading",
+                    &[
+                        ("done", format_bytes(p.downloaded).into()),
+                        ("total", format_bytes(total).into()),
+                    ],
+                ),
+                None,
+            ),
+            _ => (
+                0.0,
+                tr_args(
+                    "pack-downloading-unknown",
+                    &[("done", format_bytes(p.downloaded).into())],
+                ),
+                None,
+            ),
+        },
+        PackProgress::Mods {
+            current,
+            total,
+            file,
+        } => (
+            *current as f32 / (*total).max(1) as f32,
+            tr_args(
+                "pack-installing-mods",
+                &[("current", (*current).into()), ("total", (*total).into())],
+            ),
+            Some(file.as_str()),
+        ),
+        PackProgress::Extracting {
+            current,
+            total,
+            file,
+        } => (
+            *current as f32 / (*total).max(1) as f32,
+            tr_args(
+                "pack-extracting",
+                &[("current", (*current).into()), ("total", (*total).into())],
+            ),
+            Some(file.as_str()),
+        ),
+        PackProgress::Notice(_) => return,
+    };
+    let response = ui.add(
+        egui::ProgressBar::new(fraction)
+            .desired_width(260.0)
+            .text(text),
+    );
+    if let Some(file) = hover {
+        response.on_hover_text(file);
+    }
+}
+
+/// e.g. `45s`, `3m 20s`
+fn format_eta(eta: std::time::Duration) -> String {
+    let secs = eta.as_secs();
+    if secs < 60 {
+        format!("{}s", secs)
+    } else {
+        format!("{}m {}s", secs / 60, secs % 60)
+    }
+}
@@ -0,0 +1,78 @@
+use crate::diagnostics::DiagnosticsReport;
+use crate::i18n::{tr, tr_args};
+use crate::preflight::CheckStatus;
+use eframe::egui;
+
+pub struct DiagnosticsView;
+
+impl DiagnosticsView {
+    /// Returns true when the user asks for a new run
+    pub fn show(ui: &mut egui::Ui, report: Option<&DiagnosticsReport>, running: bool) -> bool {
+        let mut rerun = false;
+
+        ui.horizontal(|ui| {
+            ui.heading(tr("diagnostics-title"));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui
+                    .add_enabled(!running, egui::Button::new(tr("diagnostics-run")))
+                    .clicked()
+                {
+                    rerun = true;
+                }
+                if running {
+                    ui.spinner();
+                }
+            });
+        });
+        ui.label(tr("diagnostics-hint"));
+        ui.separator();
+
+        let Some(report) = report else {
+            if !running {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(50.0);
+                    ui.label(tr("diagnostics-none"));
+                });
+            }
+            return rerun;
+        };
+
+        let ran_at = report.ran_at.format("%Y-%m-%d %H:%M:%S").to_string();
+        ui.small(tr_args(
+            if report.automatic {
+                "diagnostics-last-run-auto"
+            } else {
+                "diagnostics-last-run"
+            },
+            &[("time", ran_at.into())],
+        ));
+        ui.add_space(10.0);
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            egui::Grid::new("diagnostics_grid")
+                .num_columns(3)
+                .spacing([12.0, 6.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    for check in &report.checks {
+                        let (icon, color) = match check.status {
+                            CheckStatus::Pass => ("✓", egui::Color32::GREEN),
+                            CheckStatus::Warn => ("⚠", egui::Color32::YELLOW),
+                            CheckStatus::Fail => ("✗", egui::Color32::RED),
+                        };
+                        ui.colored_label(color, icon);
+                        ui.strong(&check.name);
+                        ui.vertical(|ui| {
+                            ui.label(&check.detail);
+                            if let Some(hint) = &check.hint {
+                                ui.small(hint);
+                            }
+                        });
+                        ui.end_row();
+                    }
+                });
+        });
+
+        rerun
+    }
+}
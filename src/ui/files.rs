@@ -0,0 +1,242 @@
+use crate::backup::format_bytes;
+use crate::files::FileEntry;
+use crate::i18n::tr;
+use eframe::egui;
+use std::path::PathBuf;
+
+/// What the user chose on the Files tab. Names are relative to the folder shown.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilesAction {
+    /// Show this folder (relative to the data folder)
+    Open(PathBuf),
+    Refresh,
+    Upload(Vec<PathBuf>),
+    Download {
+        name: String,
+        dest: PathBuf,
+    },
+    Rename {
+        from: String,
+        to: String,
+    },
+    Delete(String),
+    /// Open the shown folder in the system file manager
+    OpenInOs,
+}
+
+/// Files tab: browse the server's data folder
+#[derive(Default)]
+pub struct FilesView {
+    /// Folder shown, relative to the data folder
+    pub path: PathBuf,
+    /// Listing of `path`, or why it couldn't be read (None until loaded)
+    pub entries: Option<Result<Vec<FileEntry>, String>>,
+    /// A listing or file operation is in flight
+    pub busy: bool,
+    /// Entry being renamed and the new name typed so far
+    renaming: Option<(String, String)>,
+    /// Entry waiting for the delete to be confirmed
+    confirm_delete: Option<String>,
+}
+
+impl FilesView {
+    /// Start over at the top of a (different) server's data folder
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui, running: bool) -> Option<FilesAction> {
+        let mut action = None;
+
+        // Breadcrumbs: data / world / region
+        ui.horizontal_wrapped(|ui| {
+            if ui.link("data").clicked() {
+                action = Some(FilesAction::Open(PathBuf::new()));
+            }
+            let mut crumb = PathBuf::new();
+            for part in self.path.iter() {
+                crumb.push(part);
+                ui.label("/");
+                if ui.link(part.to_string_lossy()).clicked() {
+                    action = Some(FilesAction::Open(crumb.clone()));
+                }
+            }
+        });
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(
+                    self.path.parent().is_some(),
+                    egui::Button::new(tr("files-up")),
+                )
+                .clicked()
+            {
+                let parent = self.path.parent().unwrap_or(&self.path).to_path_buf();
+                action = Some(FilesAction::Open(parent));
+            }
+            if ui
+                .add_enabled(!self.busy, egui::Button::new(tr("button-refresh")))
+                .clicked()
+            {
+                action = Some(FilesAction::Refresh);
+            }
+            if ui
+                .add_enabled(!self.busy, egui::Button::new(tr("files-upload-files")))
+                .clicked()
+            {
+                if let Some(paths) = rfd::FileDialog::new().pick_files() {
+                    action = Some(FilesAction::Upload(paths));
+                }
+            }
+            if ui
+                .add_enabled(!self.busy, egui::Button::new(tr("files-upload-folder")))
+                .clicked()
+            {
+                if let Some(path) = rfd::FileDialog::new().pick_folder() {
+                    action = Some(FilesAction::Upload(vec![path]));
+                }
+            }
+            if ui.button(tr("files-open-in-os")).clicked() {
+                action = Some(FilesAction::OpenInOs);
+            }
+            if self.busy {
+                ui.spinner();
+            }
+        });
+        if running {
+            ui.colored_label(egui::Color32::YELLOW, tr("files-running-warning"));
+        }
+        ui.add_space(6.0);
+
+        let entries = match &self.entries {
+            None => {
+                ui.spinner();
+                return action;
+            }
+            Some(Err(e)) => {
+                ui.colored_label(egui::Color32::RED, e);
+                return action;
+            }
+            Some(Ok(entries)) => entries,
+        };
+        if entries.is_empty() {
+            ui.weak(tr("files-empty"));
+            return action;
+        }
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            egui::Grid::new("files_grid")
+                .num_columns(4)
+                .spacing([16.0, 4.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.strong(tr("files-col-name"));
+                    ui.strong(tr("files-col-size"));
+                    ui.strong(tr("files-col-modified"));
+                    ui.label("");
+                    ui.end_row();
+
+                    for entry in entries {
+                        let renaming_this = self
+                            .renaming
+                            .as_ref()
+                            .is_some_and(|(name, _)| name == &entry.name);
+                        if renaming_this {
+                            let Some((_, new_name)) = self.renaming.as_mut() else {
+                                continue;
+                            };
+                            let response = ui.text_edit_singleline(new_name);
+                            if response.lost_focus()
+                                && ui.input(|i| i.key_pressed(egui::Key::Enter))
+                            {
+                                action = Some(FilesAction::Rename {
+                                    from: entry.name.clone(),
+                                    to: new_name.clone(),
+                                });
+                            }
+                        } else if entry.is_dir {
+                            if ui.link(format!("📁 {}", entry.name)).clicked() {
+                                action = Some(FilesAction::Open(self.path.join(&entry.name)));
+                            }
+                        } else {
+                            ui.label(format!("📄 {}", entry.name));
+                        }
+                        ui.label(format_bytes(entry.size));
+                        match entry.modified {
+                            Some(time) => ui.label(
+                                chrono::DateTime::<chrono::Local>::from(time)
+                                    .format("%Y-%m-%d %H:%M")
+                                    .to_string(),
+                            ),
+                            None => ui.label("—"),
+                        };
+
+                        ui.horizontal(|ui| {
+                            if renaming_this {
+                                if ui.small_button(tr("button-save")).clicked() {
+                                    if let Some((from, to)) = &self.renaming {
+                                        action = Some(FilesAction::Rename {
+                                            from: from.clone(),
+                                            to: to.clone(),
+                                        });
+                                    }
+                                }
+                                if ui.small_button(tr("button-cancel")).clicked() {
+                                    self.renaming = None;
+                                }
+                                return;
+                            }
+                            if self.confirm_delete.as_ref() == Some(&entry.name) {
+                                ui.colored_label(egui::Color32::RED, tr("files-delete-confirm"));
+                                if ui.small_button(tr("button-yes")).clicked() {
+                                    action = Some(FilesAction::Delete(entry.name.clone()));
+                                }
+                                if ui.small_button(tr("button-no")).clicked() {
+                                    self.confirm_delete = None;
+                                }
+                                return;
+                            }
+                            if !entry.is_dir && ui.small_button(tr("files-download")).clicked() {
+                                if let Some(dest) = rfd::FileDialog::new()
+                                    .set_file_name(&entry.name)
+                                    .save_file()
+                                {
+                                    action = Some(FilesAction::Download {
+                                        name: entry.name.clone(),
+                                        dest,
+                                    });
+                                }
+                            }
+                            if ui
+                                .add_enabled(
+                                    !self.busy,
+                                    egui::Button::new(tr("files-rename")).small(),
+                                )
+                                .clicked()
+                            {
+                                self.renaming = Some((entry.name.clone(), entry.name.clone()));
+                                self.confirm_delete = None;
+                            }
+                            if ui
+                                .add_enabled(
+                                    !self.busy,
+                                    egui::Button::new(tr("button-delete")).small(),
+                                )
+                                .clicked()
+                            {
+                                self.confirm_delete = Some(entry.name.clone());
+                                self.renaming = None;
+                            }
+                        });
+                        ui.end_row();
+                    }
+                });
+        });
+
+        // Any action ends an in-progress rename or delete prompt
+        if action.is_some() {
+            self.renaming = None;
+            self.confirm_delete = None;
+        }
+        action
+    }
+}
@@ -0,0 +1,350 @@
+use crate::curseforge;
+use crate::ftb::{FtbPack, FtbVersion};
+use crate::i18n::{tr, tr_args};
+use crate::jvm::JvmPreset;
+use crate::server::ModpackSource;
+use crate::templates::ModpackTemplate;
+use eframe::egui;
+
+// ── Types ──────────────────────────────────────────────────────────────────
+
+/// All FTB browse state lives here.
+#[derive(Debug, Clone, Default)]
+pub struct FtbBrowseState {
+    pub query: String,
+    pub results: Vec<FtbPack>,
+    /// A search has finished at least once (so an empty list means no hits)
+    pub searched: bool,
+    pub loading_search: bool,
+    pub search_error: Option<String>,
+    /// Index into `results`
+    pub selected_pack: Option<usize>,
+    /// Index into the selected pack's `versions`
+    pub selected_version: Option<usize>,
+}
+
+// ── FtbBrowseWidget ────────────────────────────────────────────────────────
+
+#[derive(Default)]
+pub struct FtbBrowseWidget {
+    pub state: FtbBrowseState,
+    pub template: Option<ModpackTemplate>,
+}
+
+impl FtbBrowseWidget {
+    /// Show the FTB browse UI. `on_search` is called with the query to run.
+    /// Returns `true` when `self.template` was just built this frame (user picked a version).
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        id_salt: &str,
+        on_search: &mut dyn FnMut(String),
+    ) -> bool {
+        let mut template_built = false;
+
+        ui.push_id(id_salt, |ui| {
+            // ── Search bar ────────────────────────────────────────────────
+            let mut trigger_search = false;
+            ui.horizontal(|ui| {
+                ui.label(tr("browse-search"));
+                let resp = ui.add(
+                    egui::TextEdit::singleline(&mut self.state.query)
+                        .desired_width(200.0)
+                        .hint_text("e.g. StoneBlock"),
+                );
+                if resp.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                    trigger_search = true;
+                }
+                if ui.button(tr("browse-search-button")).clicked() {
+                    trigger_search = true;
+                }
+                ui.small(tr("ftb-search-hint"));
+            });
+
+            if trigger_search {
+                self.state.loading_search = true;
+                self.state.search_error = None;
+                self.state.selected_pack = None;
+                self.state.selected_version = None;
+                self.template = None;
+                on_search(self.state.query.clone());
+            }
+
+            ui.separator();
+
+            if self.state.loading_search {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label(tr_args("browse-searching", &[("site", "FTB".into())]));
+                });
+                return;
+            }
+            if let Some(err) = &self.state.search_error {
+                ui.colored_label(
+                    egui::Color32::RED,
+                    tr_args("browse-error", &[("error", err.into())]),
+                );
+                return;
+            }
+            if self.state.results.is_empty() {
+                if self.state.searched {
+                    ui.label(tr("browse-no-results"));
+                } else {
+                    ui.label(tr("ftb-empty"));
+                }
+                return;
+            }
+
+            // ── Split layout: results list (left) + preview panel (right) ──
+            let available = ui.available_height();
+            let has_preview = self.state.selected_pack.is_some();
+            let total_width = ui.available_width();
+            let left_width = if has_preview {
+                (total_width * 0.4).max(250.0)
+            } else {
+                total_width
+            };
+
+            ui.horizontal_top(|ui| {
+                ui.allocate_ui_with_layout(
+                    egui::vec2(left_width, available),
+                    egui::Layout::top_down(egui::Align::LEFT),
+                    |ui| {
+                        egui::ScrollArea::vertical()
+                            .id_salt(format!("{}_ftb_results_scroll", id_salt))
+                            .auto_shrink([false, false])
+                            .max_height(available)
+                            .show(ui, |ui| self.show_results(ui));
+                    },
+                );
+
+                if has_preview {
+                    ui.separator();
+                    let right_width = ui.available_width();
+                    ui.allocate_ui_with_layout(
+                        egui::vec2(right_width, available),
+                        egui::Layout::top_down(egui::Align::LEFT),
+                        |ui| {
+                            if self.show_preview_panel(ui, id_salt, available) {
+                                template_built = true;
+                            }
+                        },
+                    );
+                }
+            });
+        });
+
+        template_built
+    }
+
+    fn show_results(&mut self, ui: &mut egui::Ui) {
+        let mut clicked = None;
+        for (idx, pack) in self.state.results.iter().enumerate() {
+            let frame_fill = if self.state.selected_pack == Some(idx) {
+                egui::Color32::from_rgb(40, 60, 80)
+            } else {
+                ui.style().visuals.extreme_bg_color
+            };
+
+            let resp = egui::Frame::none()
+                .fill(frame_fill)
+                .rounding(6.0)
+                .inner_margin(8.0)
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        match pack.icon_url() {
+                            Some(icon_url) => {
+                                ui.add(
+                                    egui::Image::new(icon_url)
+                                        .max_width(64.0)
+                                        .max_height(64.0)
+                                        .rounding(4.0),
+                                );
+                            }
+                            None => {
+                                ui.allocate_space(egui::vec2(64.0, 64.0));
+                            }
+                        }
+                        ui.vertical(|ui| {
+                            ui.horizontal(|ui| {
+                                ui.strong(&pack.name);
+                                ui.small(tr_args(
+                                    "browse-installs-short",
+                                    &[(
+                                        "count",
+                                        curseforge::format_downloads(pack.installs).into(),
+                                    )],
+                                ));
+                            });
+                            ui.label(&pack.synopsis);
+                            if !pack.tags.is_empty() {
+                                let tags: Vec<&str> =
+                                    pack.tags.iter().take(5).map(|t| t.name.as_str()).collect();
+                                ui.small(tags.join(", "));
+                            }
+                        });
+                    });
+                })
+                .response;
+
+            if resp.interact(egui::Sense::click()).clicked() {
+                clicked = Some(idx);
+            }
+            ui.add_space(3.0);
+        }
+
+        if let Some(idx) = clicked {
+            self.state.selected_pack = Some(idx);
+            self.state.selected_version = None;
+            self.template = None;
+        }
+    }
+
+    // ── Preview panel (right side) ──────────────────────────────────
+    // Returns true if a template was built this frame.
+
+    fn show_preview_panel(
+        &mut self,
+        ui: &mut egui::Ui,
+        id_salt: &str,
+        available_height: f32,
+    ) -> bool {
+        let Some(pack) = self
+            .state
+            .selected_pack
+            .and_then(|idx| self.state.results.get(idx))
+            .cloned()
+        else {
+            return false;
+        };
+
+        // Newest first
+        let mut versions: Vec<(usize, &FtbVersion)> = pack.versions.iter().enumerate().collect();
+        versions.sort_by_key(|(_, v)| std::cmp::Reverse(v.updated));
+
+        let mut clicked_version = None;
+        egui::ScrollArea::vertical()
+            .id_salt(format!("{}_ftb_preview_scroll", id_salt))
+            .auto_shrink([false, false])
+            .max_height(available_height)
+            .show(ui, |ui| {
+                if let Some(icon_url) = pack.icon_url() {
+                    ui.add(
+                        egui::Image::new(icon_url)
+                            .max_width(128.0)
+                            .max_height(128.0)
+                            .rounding(8.0),
+                    );
+                    ui.add_space(8.0);
+                }
+                ui.heading(&pack.name);
+                ui.label(tr_args(
+                    "browse-installs",
+                    &[("count", curseforge::format_downloads(pack.installs).into())],
+                ));
+                ui.add_space(4.0);
+                ui.separator();
+                ui.add_space(4.0);
+
+                // FTB descriptions are markdown; show as plain text, truncated
+                let desc = if pack.description.is_empty() {
+                    &pack.synopsis
+                } else {
+                    &pack.description
+                };
+                let truncated: String = desc.chars().take(2000).collect();
+                ui.label(truncated);
+
+                ui.add_space(12.0);
+                ui.separator();
+                ui.add_space(4.0);
+                ui.strong(tr("browse-version-selection"));
+                ui.add_space(4.0);
+
+                if versions.is_empty() {
+                    ui.label(tr("browse-no-versions"));
+                    return;
+                }
+
+                let selected_label = self
+                    .state
+                    .selected_version
+                    .and_then(|idx| pack.versions.get(idx))
+                    .map(version_label)
+                    .unwrap_or_else(|| tr("browse-select"));
+                ui.horizontal(|ui| {
+                    ui.label(tr("browse-pack-version"));
+                    egui::ComboBox::from_id_salt(format!("{}_ftb_version_picker", id_salt))
+                        .selected_text(selected_label)
+                        .width(300.0)
+                        .show_ui(ui, |ui| {
+                            for (idx, version) in &versions {
+                                let is_sel = self.state.selected_version == Some(*idx);
+                                if ui
+                                    .selectable_label(is_sel, version_label(version))
+                                    .clicked()
+                                {
+                                    clicked_version = Some(*idx);
+                                }
+                            }
+                        });
+                });
+            });
+
+        if let Some(idx) = clicked_version {
+            self.state.selected_version = Some(idx);
+            self.build_ftb_template(&pack, &pack.versions[idx]);
+            return true;
+        }
+        false
+    }
+
+    // ── Build template from FTB data ─────────────────────────────────────
+
+    pub fn build_ftb_template(&mut self, pack: &FtbPack, version: &FtbVersion) {
+        let mc_version = version.minecraft_version().to_string();
+        let java_version = version
+            .java_version()
+            .unwrap_or_else(|| curseforge::infer_java_version(&mc_version));
+        let memory = version
+            .specs
+            .as_ref()
+            .map(|s| s.recommended.max(s.minimum))
+            .filter(|mb| *mb > 0)
+            .unwrap_or_else(|| curseforge::default_memory_mb(&mc_version));
+
+        self.template = Some(ModpackTemplate {
+            name: pack.name.clone(),
+            description: pack.synopsis.clone(),
+            version: version.name.clone(),
+            minecraft_version: mc_version,
+            loader: version.loader(),
+            source: ModpackSource::Ftb {
+                pack_id: pack.id,
+                version_id: version.id,
+            },
+            recommended_memory_mb: memory,
+            java_version,
+            default_java_args: JvmPreset::Aikar.args(java_version, memory),
+            default_extra_env: vec![],
+            world_url: None,
+            server_properties: None,
+        });
+    }
+}
+
+/// e.g. `1.7.3 (MC 1.21.1, 2025-01-14)`, with the release type when it isn't a release
+fn version_label(version: &FtbVersion) -> String {
+    let kind = if version.kind.is_empty() || version.kind.eq_ignore_ascii_case("release") {
+        String::new()
+    } else {
+        format!(" [{}]", version.kind)
+    };
+    format!(
+        "{}{} (MC {}, {})",
+        version.name,
+        kind,
+        version.minecraft_version(),
+        version.date()
+    )
+}
@@ -0,0 +1,177 @@
+use crate::curseforge;
+use crate::i18n::tr;
+use crate::jvm::JvmPreset;
+use crate::local_pack::{self, LocalPackInfo, LocalPackKind};
+use crate::server::{ModLoader, ModpackSource};
+use crate::templates::ModpackTemplate;
+use eframe::egui;
+use std::path::{Path, PathBuf};
+
+/// "From Local File/Folder" tab of the create view: pick a pack on this machine,
+/// check what was detected in it, and turn it into a template
+#[derive(Default)]
+pub struct LocalImportWidget {
+    picked: Option<PathBuf>,
+    /// What was read from the picked pack, or why it couldn't be read
+    info: Option<Result<LocalPackInfo, String>>,
+    name: String,
+    minecraft_version: String,
+    loader: Option<ModLoader>,
+}
+
+impl LocalImportWidget {
+    /// Draw the tab. Returns true when a pack was just picked and read.
+    pub fn show(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut picked = None;
+        ui.label(tr("local-import-hint"));
+        ui.horizontal(|ui| {
+            if ui.button(tr("local-import-choose-file")).clicked() {
+                picked = rfd::FileDialog::new()
+                    .add_filter(tr("local-import-filter"), &["zip", "mrpack"])
+                    .pick_file();
+            }
+            if ui.button(tr("local-import-choose-folder")).clicked() {
+                picked = rfd::FileDialog::new().pick_folder();
+            }
+            if let Some(path) = &self.picked {
+                ui.label(path.display().to_string());
+            }
+        });
+        let mut read = false;
+        if let Some(path) = picked {
+            self.pick(path);
+            read = matches!(self.info, Some(Ok(_)));
+        }
+        ui.add_space(8.0);
+
+        match &self.info {
+            None => {
+                ui.weak(tr("local-import-nothing"));
+            }
+            Some(Err(e)) => {
+                ui.colored_label(egui::Color32::RED, e);
+            }
+            Some(Ok(info)) => {
+                let kind = info.kind;
+                egui::Grid::new("local_import_fields")
+                    .num_columns(2)
+                    .spacing([10.0, 8.0])
+                    .show(ui, |ui| {
+                        ui.label(tr("local-import-detected"));
+                        ui.label(kind.label());
+                        ui.end_row();
+
+                        ui.label(tr("local-import-name"));
+                        ui.add(egui::TextEdit::singleline(&mut self.name).desired_width(300.0));
+                        ui.end_row();
+
+                        ui.label(tr("local-import-mc-version"));
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.minecraft_version)
+                                .desired_width(100.0)
+                                .hint_text(tr("local-import-mc-version-hint")),
+                        );
+                        ui.end_row();
+
+                        ui.label(tr("local-import-loader"));
+                        egui::ComboBox::from_id_salt("local_import_loader")
+                            .selected_text(
+                                self.loader
+                                    .as_ref()
+                                    .map_or(tr("local-import-choose-loader"), |l| {
+                                        format!("{:?}", l)
+                                    }),
+                            )
+                            .show_ui(ui, |ui| {
+                                for loader in [
+                                    ModLoader::Forge,
+                                    ModLoader::NeoForge,
+                                    ModLoader::Fabric,
+                                    ModLoader::Vanilla,
+                                ] {
+                                    let label = format!("{:?}", loader);
+                                    ui.selectable_value(&mut self.loader, Some(loader), label);
+                                }
+                            });
+                        ui.end_row();
+                    });
+                match kind {
+                    LocalPackKind::CurseForgeClient => {
+                        ui.small(tr("local-import-cf-client-hint"));
+                    }
+                    LocalPackKind::Mrpack => {
+                        ui.small(tr("local-import-mrpack-hint"));
+                    }
+                    LocalPackKind::ServerPack | LocalPackKind::ServerFolder => {}
+                }
+                if self.minecraft_version.trim().is_empty() || self.loader.is_none() {
+                    ui.colored_label(egui::Color32::YELLOW, tr("local-import-incomplete"));
+                }
+            }
+        }
+        read
+    }
+
+    /// Read a pack chosen outside the tab (e.g. dropped onto the window)
+    pub fn pick(&mut self, path: PathBuf) {
+        let info = local_pack::inspect(&path);
+        if let Ok(info) = &info {
+            self.name = info.name.clone();
+            self.minecraft_version = info.minecraft_version.clone().unwrap_or_default();
+            self.loader = info.loader.clone();
+        }
+        self.picked = Some(path);
+        self.info = Some(info);
+    }
+
+    /// The picked pack on this machine, to be copied into the new server
+    pub fn source_path(&self) -> Option<&Path> {
+        self.picked.as_deref()
+    }
+
+    /// Template for the picked pack, once everything needed is known. The source
+    /// points at where the import lands in the server's data folder.
+    pub fn template(&self) -> Option<ModpackTemplate> {
+        let Some(Ok(info)) = &self.info else {
+            return None;
+        };
+        let loader = self.loader.clone()?;
+        let minecraft_version = self.minecraft_version.trim().to_string();
+        if minecraft_version.is_empty() || self.name.trim().is_empty() {
+            return None;
+        }
+        let file_name = self
+            .picked
+            .as_ref()?
+            .file_name()?
+            .to_string_lossy()
+            .into_owned();
+        let source = match info.kind {
+            LocalPackKind::ServerFolder => ModpackSource::Local {
+                path: ".".to_string(),
+            },
+            LocalPackKind::Mrpack => ModpackSource::MrPackFile {
+                path_or_url: file_name,
+            },
+            LocalPackKind::ServerPack | LocalPackKind::CurseForgeClient => {
+                ModpackSource::Local { path: file_name }
+            }
+        };
+        let java_version = curseforge::infer_java_version(&minecraft_version);
+        let memory = curseforge::default_memory_mb(&minecraft_version);
+        Some(ModpackTemplate {
+            name: self.name.trim().to_string(),
+            description: format!("{} from this machine", info.kind.label()),
+            version: info.version.clone(),
+            minecraft_version,
+            loader,
+            source,
+            recommended_memory_mb: memory,
+            java_version,
+            default_java_args: JvmPreset::Aikar.args(java_version, memory),
+            default_extra_env: vec![],
+            world_url: None,
+            server_properties: None,
+        })
+    }
+}
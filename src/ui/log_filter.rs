@@ -0,0 +1,263 @@
+use crate::i18n::{tr, tr_args};
+use eframe::egui;
+use regex::{Regex, RegexBuilder};
+
+/// Severity named in a log line's prefix
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+}
+
+impl LogLevel {
+    /// Level from a line's prefix, e.g. `[12:00:00] [Server thread/WARN]: ...` from
+    /// Minecraft or `[12:00:00] ERROR: ...` from the app. `None` for lines that don't
+    /// name one, such as stack trace continuations.
+    pub fn detect(line: &str) -> Option<LogLevel> {
+        let end = line.char_indices().nth(80).map_or(line.len(), |(i, _)| i);
+        line[..end]
+            .split(|c: char| !c.is_ascii_alphabetic())
+            .find_map(|word| match word {
+                "ERROR" | "FATAL" | "SEVERE" => Some(LogLevel::Error),
+                "WARN" | "WARNING" => Some(LogLevel::Warn),
+                "INFO" | "DEBUG" | "TRACE" => Some(LogLevel::Info),
+                _ => None,
+            })
+    }
+
+    /// Colour for lines at this level; `None` keeps the normal text colour
+    fn color(self) -> Option<egui::Color32> {
+        match self {
+            LogLevel::Error => Some(egui::Color32::from_rgb(235, 90, 90)),
+            LogLevel::Warn => Some(egui::Color32::from_rgb(230, 200, 80)),
+            LogLevel::Info => None,
+        }
+    }
+}
+
+/// Java stack trace lines: `\tat ...`, `Caused by: ...` and `... 12 more`
+fn is_stack_trace(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    (trimmed.starts_with("at ") && trimmed.len() < line.len())
+        || trimmed.starts_with("Caused by:")
+        || trimmed.starts_with("Suppressed:")
+        || (trimmed.starts_with("... ") && trimmed.trim_end().ends_with(" more"))
+}
+
+const STACK_TRACE_COLOR: egui::Color32 = egui::Color32::from_rgb(190, 140, 220);
+
+/// Search box and level toggles shared by the log views
+pub struct LogFilter {
+    query: String,
+    use_regex: bool,
+    only_matching: bool,
+    show_error: bool,
+    show_warn: bool,
+    show_info: bool,
+    /// Colour lines by level; off shows the log as plain text
+    colorize: bool,
+    /// Keep refreshing and scrolled to the newest line
+    follow: bool,
+    /// Compiled search for the (query, regex mode) it was built from
+    compiled: Option<(String, bool, Result<Regex, String>)>,
+}
+
+impl Default for LogFilter {
+    fn default() -> Self {
+        Self {
+            query: String::new(),
+            use_regex: false,
+            only_matching: false,
+            show_error: true,
+            show_warn: true,
+            show_info: true,
+            colorize: true,
+            follow: true,
+            compiled: None,
+        }
+    }
+}
+
+impl LogFilter {
+    /// The search as a case-insensitive regex; plain-text queries are escaped.
+    /// `None` when the box is empty.
+    fn search(&mut self) -> Option<Result<Regex, String>> {
+        let query = self.query.trim();
+        if query.is_empty() {
+            return None;
+        }
+        let stale = self
+            .compiled
+            .as_ref()
+            .is_none_or(|(q, r, _)| q != query || *r != self.use_regex);
+        if stale {
+            let pattern = if self.use_regex {
+                query.to_string()
+            } else {
+                regex::escape(query)
+            };
+            let compiled = RegexBuilder::new(&pattern)
+                .case_insensitive(true)
+                .build()
+                .map_err(|e| e.to_string());
+            self.compiled = Some((query.to_string(), self.use_regex, compiled));
+        }
+        self.compiled.as_ref().map(|(_, _, c)| c.clone())
+    }
+
+    pub fn follow(&self) -> bool {
+        self.follow
+    }
+
+    fn level_shown(&self, level: LogLevel) -> bool {
+        match level {
+            LogLevel::Error => self.show_error,
+            LogLevel::Warn => self.show_warn,
+            LogLevel::Info => self.show_info,
+        }
+    }
+
+    /// Draw the filter bar and the filtered log below it. Lines without a level
+    /// (stack traces and the like) follow the line they continue.
+    pub fn show<'a>(
+        &mut self,
+        ui: &mut egui::Ui,
+        id_salt: &str,
+        lines: impl IntoIterator<Item = &'a str>,
+    ) {
+        ui.horizontal(|ui| {
+            ui.label(tr("log-search"));
+            ui.add(
+                egui::TextEdit::singleline(&mut self.query)
+                    .hint_text(if self.use_regex {
+                        tr("log-search-regex-hint")
+                    } else {
+                        tr("log-search-text-hint")
+                    })
+                    .desired_width(220.0),
+            );
+            if !self.query.is_empty() && ui.small_button("✖").clicked() {
+                self.query.clear();
+            }
+            ui.checkbox(&mut self.use_regex, tr("log-regex"));
+            ui.checkbox(&mut self.only_matching, tr("log-only-matching"));
+            ui.separator();
+            ui.checkbox(&mut self.show_error, "ERROR");
+            ui.checkbox(&mut self.show_warn, "WARN");
+            ui.checkbox(&mut self.show_info, "INFO");
+            ui.separator();
+            ui.checkbox(&mut self.colorize, tr("log-colors"))
+                .on_hover_text(tr("log-colors-hint"));
+            ui.checkbox(&mut self.follow, tr("log-follow"))
+                .on_hover_text(tr("log-follow-hint"));
+        });
+
+        let search = match self.search() {
+            Some(Ok(regex)) => Some(regex),
+            Some(Err(e)) => {
+                ui.colored_label(
+                    egui::Color32::RED,
+                    tr_args("log-invalid-regex", &[("error", e.into())]),
+                );
+                None
+            }
+            None => None,
+        };
+
+        let mut total = 0;
+        let mut matching = 0;
+        let mut level = LogLevel::Info;
+        let mut visible: Vec<&str> = Vec::new();
+        for line in lines {
+            total += 1;
+            level = LogLevel::detect(line).unwrap_or(level);
+            if !self.level_shown(level) {
+                continue;
+            }
+            let is_match = search.as_ref().is_some_and(|re| re.is_match(line));
+            if is_match {
+                matching += 1;
+            }
+            if self.only_matching && search.is_some() && !is_match {
+                continue;
+            }
+            visible.push(line);
+        }
+
+        ui.horizontal(|ui| {
+            if search.is_some() {
+                ui.small(tr_args(
+                    "log-matching",
+                    &[("matching", matching.into()), ("total", total.into())],
+                ));
+            } else {
+                ui.small(tr_args("log-lines", &[("total", total.into())]));
+            }
+            // Rows are separate labels, so copying more than one line goes through here
+            if ui
+                .small_button(tr("log-copy"))
+                .on_hover_text(tr("log-copy-hint"))
+                .clicked()
+            {
+                ui.ctx().copy_text(visible.join("\n"));
+            }
+        });
+        ui.separator();
+
+        // Only the rows in view are laid out, so huge startup logs stay responsive
+        let row_height = ui.text_style_height(&egui::TextStyle::Monospace);
+        egui::ScrollArea::both()
+            .id_salt(id_salt)
+            .auto_shrink([false, false])
+            .stick_to_bottom(self.follow)
+            .show_rows(ui, row_height, visible.len(), |ui, rows| {
+                for line in &visible[rows] {
+                    let job = line_job(ui, line, search.as_ref(), self.colorize);
+                    ui.add(egui::Label::new(job).wrap_mode(egui::TextWrapMode::Extend));
+                }
+            });
+    }
+}
+
+/// Lay out one log line, coloured by level when `colorize` is set and with every
+/// search match highlighted
+fn line_job(
+    ui: &egui::Ui,
+    line: &str,
+    search: Option<&Regex>,
+    colorize: bool,
+) -> egui::text::LayoutJob {
+    let default_color = ui.visuals().text_color();
+    let color = if !colorize {
+        default_color
+    } else if is_stack_trace(line) {
+        STACK_TRACE_COLOR
+    } else {
+        LogLevel::detect(line)
+            .and_then(LogLevel::color)
+            .unwrap_or(default_color)
+    };
+    let plain = egui::TextFormat {
+        font_id: egui::TextStyle::Monospace.resolve(ui.style()),
+        color,
+        ..Default::default()
+    };
+    let hit = egui::TextFormat {
+        background: egui::Color32::from_rgb(120, 95, 20),
+        color: egui::Color32::WHITE,
+        ..plain.clone()
+    };
+
+    let mut job = egui::text::LayoutJob::default();
+    let mut last = 0;
+    if let Some(re) = search {
+        for m in re.find_iter(line).filter(|m| !m.is_empty()) {
+            job.append(&line[last..m.start()], 0.0, plain.clone());
+            job.append(m.as_str(), 0.0, hit.clone());
+            last = m.end();
+        }
+    }
+    job.append(&line[last..], 0.0, plain);
+    job
+}
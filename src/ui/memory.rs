@@ -0,0 +1,99 @@
+use crate::i18n::tr_args;
+use crate::memory::{MemoryBudget, HOST_RESERVE_MB};
+use eframe::egui;
+
+pub struct MemoryAdvisor;
+
+impl MemoryAdvisor {
+    /// Memory slider with a host-capacity bar and an over-allocation warning.
+    /// Edits `memory_mb` in place and returns true if it changed.
+    pub fn show(ui: &mut egui::Ui, memory_mb: &mut String, budget: &MemoryBudget) -> bool {
+        if !budget.is_known() {
+            return false;
+        }
+
+        let mut changed = false;
+        let host = budget.host_total_mb;
+        let mut value = memory_mb.parse::<u64>().unwrap_or(0).min(host);
+
+        ui.horizontal(|ui| {
+            let slider = egui::Slider::new(&mut value, 512..=host)
+                .step_by(256.0)
+                .suffix(" MB")
+                .show_value(false);
+            if ui.add(slider).changed() {
+                *memory_mb = value.to_string();
+                changed = true;
+            }
+            ui.small(tr_args("memory-host", &[("mb", host.into())]));
+        });
+
+        Self::capacity_bar(ui, value, budget);
+
+        let requested = memory_mb.parse::<u64>().unwrap_or(0);
+        if let Some(warning) = budget.warning(requested) {
+            ui.colored_label(egui::Color32::YELLOW, format!("⚠ {}", warning));
+            let suggested = budget.suggested_mb(requested);
+            if suggested < requested
+                && ui
+                    .button(tr_args("memory-use-suggested", &[("mb", suggested.into())]))
+                    .clicked()
+            {
+                *memory_mb = suggested.to_string();
+                changed = true;
+            }
+        }
+
+        changed
+    }
+
+    /// Bar showing other servers' allocation, this server, and the system reserve line
+    fn capacity_bar(ui: &mut egui::Ui, this_mb: u64, budget: &MemoryBudget) {
+        let host = budget.host_total_mb as f32;
+        let (rect, response) =
+            ui.allocate_exact_size(egui::vec2(300.0, 10.0), egui::Sense::hover());
+        let painter = ui.painter();
+        let x_at = |mb: u64| rect.left() + rect.width() * (mb as f32 / host).min(1.0);
+
+        painter.rect_filled(rect, 2.0, ui.style().visuals.extreme_bg_color);
+
+        let others_end = x_at(budget.others_mb);
+        painter.rect_filled(
+            egui::Rect::from_min_max(rect.min, egui::pos2(others_end, rect.bottom())),
+            2.0,
+            egui::Color32::GRAY,
+        );
+
+        let over = budget.warning(this_mb).is_some();
+        let this_end = x_at(budget.others_mb + this_mb);
+        painter.rect_filled(
+            egui::Rect::from_min_max(
+                egui::pos2(others_end, rect.top()),
+                egui::pos2(this_end, rect.bottom()),
+            ),
+            2.0,
+            if over {
+                egui::Color32::from_rgb(200, 60, 60)
+            } else {
+                egui::Color32::from_rgb(60, 120, 200)
+            },
+        );
+
+        let reserve_x = x_at(budget.host_total_mb.saturating_sub(HOST_RESERVE_MB));
+        painter.vline(
+            reserve_x,
+            rect.y_range(),
+            egui::Stroke::new(2.0, egui::Color32::YELLOW),
+        );
+
+        response.on_hover_text(tr_args(
+            "memory-bar-hint",
+            &[
+                ("others", budget.others_mb.into()),
+                ("this", this_mb.into()),
+                ("reserve", HOST_RESERVE_MB.into()),
+                ("total", budget.host_total_mb.into()),
+            ],
+        ));
+    }
+}
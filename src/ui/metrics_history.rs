@@ -0,0 +1,152 @@
+use crate::backup::format_bytes;
+use crate::i18n::{tr, tr_args};
+use crate::metrics_history::{downsample, HistoryPoint, HistoryRange};
+use eframe::egui;
+
+const CHART_HEIGHT: f32 = 70.0;
+const CHART_MAX_WIDTH: f32 = 640.0;
+/// Slices each chart is averaged into, whatever the range
+const CHART_BUCKETS: usize = 144;
+
+const PLAYERS_COLOR: egui::Color32 = egui::Color32::from_rgb(60, 120, 200);
+const TPS_COLOR: egui::Color32 = egui::Color32::from_rgb(80, 180, 90);
+const MEMORY_COLOR: egui::Color32 = egui::Color32::from_rgb(200, 140, 60);
+
+pub struct MetricsHistoryView;
+
+impl MetricsHistoryView {
+    /// Players, TPS and memory charts over the chosen range; `points` is None
+    /// while the history loads
+    pub fn show(ui: &mut egui::Ui, points: Option<&[HistoryPoint]>, range: &mut HistoryRange) {
+        ui.horizontal(|ui| {
+            ui.strong(tr("history-title"));
+            for r in HistoryRange::ALL {
+                ui.selectable_value(range, r, r.label());
+            }
+        });
+        let Some(points) = points else {
+            ui.spinner();
+            return;
+        };
+
+        let to = chrono::Utc::now().timestamp();
+        let from = to - range.duration().as_secs() as i64;
+        if !points.iter().any(|p| p.at >= from) {
+            ui.weak(tr("history-empty"));
+            return;
+        }
+
+        let players = downsample(points, from, to, CHART_BUCKETS, |p| {
+            p.players.map(|n| n as f64)
+        });
+        let tps = downsample(points, from, to, CHART_BUCKETS, |p| p.tps);
+        let memory = downsample(points, from, to, CHART_BUCKETS, |p| {
+            p.memory_bytes.map(|b| b as f64)
+        });
+        let span = (from, to);
+        chart(
+            ui,
+            &tr("history-players"),
+            &players,
+            1.0,
+            PLAYERS_COLOR,
+            span,
+            |v| format!("{:.0}", v),
+        );
+        if tps.iter().any(Option::is_some) {
+            chart(ui, &tr("history-tps"), &tps, 20.0, TPS_COLOR, span, |v| {
+                format!("{:.1}", v)
+            });
+        } else {
+            ui.small(tr("history-tps-unreported"));
+        }
+        chart(
+            ui,
+            &tr("history-memory"),
+            &memory,
+            1.0,
+            MEMORY_COLOR,
+            span,
+            |v| format_bytes(v as u64),
+        );
+    }
+}
+
+/// Line chart of one series from zero up to its peak (at least `min_top`), with
+/// the bucket under the pointer shown on hover
+fn chart(
+    ui: &mut egui::Ui,
+    title: &str,
+    values: &[Option<f64>],
+    min_top: f64,
+    color: egui::Color32,
+    (from, to): (i64, i64),
+    format: impl Fn(f64) -> String,
+) {
+    let peak = values.iter().flatten().fold(0.0_f64, |a, &b| a.max(b));
+    let latest = values.iter().rev().flatten().next().copied();
+    ui.add_space(6.0);
+    ui.label(tr_args(
+        "history-now-peak",
+        &[
+            ("title", title.into()),
+            ("now", latest.map_or("—".to_string(), &format).into()),
+            ("peak", format(peak).into()),
+        ],
+    ));
+
+    let width = ui.available_width().min(CHART_MAX_WIDTH);
+    let (rect, response) =
+        ui.allocate_exact_size(egui::vec2(width, CHART_HEIGHT), egui::Sense::hover());
+    let painter = ui.painter();
+    painter.rect_filled(rect, 2.0, ui.style().visuals.extreme_bg_color);
+
+    let top = peak.max(min_top);
+    let step = rect.width() / values.len() as f32;
+    let point_at = |i: usize, v: f64| {
+        egui::pos2(
+            rect.left() + step * (i as f32 + 0.5),
+            rect.bottom() - rect.height() * (v / top) as f32,
+        )
+    };
+    let stroke = egui::Stroke::new(1.5, color);
+
+    // Consecutive buckets are joined; a bucket without samples breaks the line
+    let mut run = Vec::new();
+    for (i, value) in values.iter().enumerate() {
+        if let Some(v) = value {
+            run.push(point_at(i, *v));
+        }
+        if value.is_none() || i + 1 == values.len() {
+            if run.len() == 1 {
+                painter.circle_filled(run[0], 1.5, color);
+            } else if run.len() > 1 {
+                painter.add(egui::Shape::line(std::mem::take(&mut run), stroke));
+            }
+            run.clear();
+        }
+    }
+
+    let Some(pos) = response.hover_pos() else {
+        return;
+    };
+    let i = (((pos.x - rect.left()) / step) as usize).min(values.len() - 1);
+    let Some(value) = values[i] else {
+        return;
+    };
+    painter.vline(
+        point_at(i, value).x,
+        rect.y_range(),
+        egui::Stroke::new(1.0, egui::Color32::GRAY),
+    );
+    let bucket_secs = (to - from) / values.len() as i64;
+    let at = from + bucket_secs * i as i64 + bucket_secs / 2;
+    let when = chrono::DateTime::from_timestamp(at, 0)
+        .map(|t| {
+            t.with_timezone(&chrono::Local)
+                .format("%a %H:%M")
+                .to_string()
+        })
+        .unwrap_or_default();
+    response.on_hover_text_at_pointer(format!("{}: {}", when, format(value)));
+}
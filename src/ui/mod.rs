@@ -1,33 +1,82 @@
+mod alerts;
+mod backup_compression;
+mod backup_contents;
+mod backup_overview;
 mod cf_browse;
+mod console;
+mod crash_report;
 mod dashboard;
+mod diagnostics;
+mod files;
+mod ftb_browse;
+mod local_import;
+mod log_filter;
+mod memory;
+mod metrics_history;
+mod mod_browse;
+mod mods;
+mod motd;
 mod mr_browse;
+mod players;
+mod ports;
+mod preflight;
 mod server_create;
+mod server_details;
 mod server_edit;
+mod unified_search;
+mod whitelist;
 
+pub use alerts::alert_rules_editor;
+pub use backup_compression::compression_picker;
+pub use backup_contents::{BackupContentsAction, BackupContentsView};
+pub use backup_overview::{BackupOverviewAction, BackupOverviewView};
 pub use cf_browse::{CfBrowseWidget, CfCallbacks, CfSearchState};
-pub use dashboard::{DashboardCallbacks, DashboardView};
+pub use console::{formatted_line, motd_preview, CommandLine, QuickCommandAction, QuickCommandBar};
+pub use crash_report::{CrashAction, CrashReportView};
+pub use dashboard::{DashboardCallbacks, DashboardFilter, DashboardView};
+pub use diagnostics::DiagnosticsView;
+pub use files::{FilesAction, FilesView};
+pub use log_filter::LogFilter;
+pub use metrics_history::MetricsHistoryView;
+pub use mod_browse::{ModBrowseAction, ModBrowseView};
+pub use mods::{ModsAction, ModsView};
+pub use motd::motd_editor;
 pub use mr_browse::{MrBrowseWidget, MrCallbacks, MrSearchState};
-pub use server_create::{CreateViewCallbacks, ServerCreateView};
-pub use server_edit::{ServerEditResult, ServerEditView};
+pub use players::{PlayerAction, PlayersView};
+pub use ports::PortsView;
+pub use preflight::{PreflightAction, PreflightView};
+pub use server_create::{CreateViewCallbacks, ServerCreateView, TemplateAction};
+pub use server_details::{DetailsTab, ServerDetailsView};
+pub use server_edit::{ScheduleResult, ServerEditResult, ServerEditView};
+pub use unified_search::{UnifiedCallbacks, UnifiedSearchState};
+pub use whitelist::{WhitelistAction, WhitelistView};
 
 #[derive(Debug, Clone, PartialEq, Default)]
 pub enum View {
     #[default]
     Dashboard,
     CreateServer,
-    EditServer(String),
-    #[allow(dead_code)] // Will be used when server details view is implemented
-    ServerDetails(String),
-    ContainerLogs(String),
+    ServerDetails(String), // Server name - tabbed page: overview, console, logs, players, mods, backups, settings
+    CrashReports(String),  // Server name - crash report list and parsed summary
     ConfirmDelete(String),
-    Backups(String), // Server name - list and restore backups
+    Mods(String),      // Server name - installed mods and their dependency graph
+    ModBrowse(String), // Server name - search CurseForge/Modrinth for mods to install
     ConfirmRestore(String, std::path::PathBuf), // Server name, backup path
+    BackupPlayers(String, std::path::PathBuf), // Server name, backup path - per-player restore
+    BackupContents(String, std::path::PathBuf), // Server name, backup path - file tree, selective restore
+    RestoreAsNew(String, std::path::PathBuf), // Server name, backup path - restore into a new server
+    ConfirmRestoreGroup(String, std::path::PathBuf), // Server name, backup path of a group snapshot
     ConfirmDeleteBackup(String, std::path::PathBuf), // Server name, backup path
     ConfirmRemoveContainer(String), // Server name - confirm old container removal before recreate
     ConfirmImport(std::path::PathBuf), // Path to .drakonixanvil-server.zip to preview and import
-    Console(String), // Server name - RCON console
+    ConfirmEula(String), // Server name - start is pending until the Minecraft EULA is accepted
+    ConfirmMoveData,     // Move the data folder to the destination in data_move_plan
     Logs,
     DockerLogs,
+    Ports,
+    BackupOverview,    // Backup usage and freshness across all servers
+    Preflight(String), // Server name - checklist shown when a start was held back
+    Diagnostics,
     Settings,
     Help,
 }
@@ -0,0 +1,348 @@
+use crate::curseforge;
+use crate::i18n::{tr, tr_args};
+use crate::mod_browse::{normalize_id, ModHit, ModRelease, ModSite};
+use eframe::egui;
+use std::collections::HashSet;
+
+/// Mods shown per page of search results
+const PAGE_SIZE: u64 = 20;
+
+/// What the user chose in the add mods view
+#[derive(Debug, Clone)]
+pub enum ModBrowseAction {
+    Back,
+    /// Run the search in `site`/`query`/`offset`
+    Search,
+    /// Load the builds of a mod (by ID) that fit the server
+    FetchReleases(String),
+    /// Download a build and its missing dependencies; carries the mod's title for messages
+    Install(ModRelease, String),
+}
+
+/// Search Modrinth or CurseForge for mods that fit a server's loader and Minecraft
+/// version, and pick a build to install
+#[derive(Default)]
+pub struct ModBrowseView {
+    pub site: ModSite,
+    pub query: String,
+    pub offset: u64,
+    results: Vec<ModHit>,
+    total: u64,
+    searching: bool,
+    search_error: Option<String>,
+    selected: Option<ModHit>,
+    releases: Vec<ModRelease>,
+    loading_releases: bool,
+    releases_error: Option<String>,
+    selected_release: usize,
+    /// Titles of mods being downloaded
+    pub installing: Vec<String>,
+}
+
+impl ModBrowseView {
+    /// Empty view for `site`, waiting on the first search
+    pub fn new(site: ModSite) -> Self {
+        Self {
+            site,
+            searching: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn set_results(&mut self, result: Result<(Vec<ModHit>, u64), String>) {
+        self.searching = false;
+        match result {
+            Ok((results, total)) => {
+                self.results = results;
+                self.total = total;
+                self.search_error = None;
+            }
+            Err(e) => {
+                self.results.clear();
+                self.total = 0;
+                self.search_error = Some(e);
+            }
+        }
+    }
+
+    /// Builds for the selected mod; results for a mod no longer selected are dropped
+    pub fn set_releases(&mut self, id: &str, result: Result<Vec<ModRelease>, String>) {
+        if self.selected.as_ref().is_none_or(|s| s.id != id) {
+            return;
+        }
+        self.loading_releases = false;
+        self.selected_release = 0;
+        match result {
+            Ok(releases) => {
+                self.releases = releases;
+                self.releases_error = None;
+            }
+            Err(e) => {
+                self.releases.clear();
+                self.releases_error = Some(e);
+            }
+        }
+    }
+
+    fn start_search(&mut self) -> Option<ModBrowseAction> {
+        self.searching = true;
+        self.search_error = None;
+        self.selected = None;
+        self.releases.clear();
+        Some(ModBrowseAction::Search)
+    }
+
+    /// `target` describes what mods must run on (e.g. "Forge 1.20.1"); `installed`
+    /// holds normalized IDs of mods already on the server
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        server_name: &str,
+        target: &str,
+        has_cf_key: bool,
+        installed: &HashSet<String>,
+    ) -> Option<ModBrowseAction> {
+        let mut action = None;
+
+        ui.horizontal(|ui| {
+            ui.heading(tr_args(
+                "mod-browse-title",
+                &[("server", server_name.into())],
+            ));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button(tr("button-back")).clicked() {
+                    action = Some(ModBrowseAction::Back);
+                }
+            });
+        });
+        ui.label(tr_args("mod-browse-hint", &[("target", target.into())]));
+        ui.add_space(4.0);
+
+        ui.horizontal(|ui| {
+            let site_before = self.site;
+            ui.selectable_value(&mut self.site, ModSite::Modrinth, "Modrinth");
+            ui.add_enabled_ui(has_cf_key, |ui| {
+                ui.selectable_value(&mut self.site, ModSite::CurseForge, "CurseForge")
+                    .on_disabled_hover_text(tr("mod-browse-no-cf-key"));
+            });
+            ui.separator();
+            let resp = ui.add(
+                egui::TextEdit::singleline(&mut self.query)
+                    .desired_width(250.0)
+                    .hint_text(tr("mod-browse-search-hint")),
+            );
+            let enter = resp.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            if ui.button(tr("browse-search-button")).clicked() || enter || self.site != site_before
+            {
+                self.offset = 0;
+                action = self.start_search();
+            }
+        });
+        ui.separator();
+
+        if let Some(hit) = self.selected.clone() {
+            egui::SidePanel::right("mod_browse_details")
+                .resizable(true)
+                .default_width(380.0)
+                .show_inside(ui, |ui| {
+                    if let Some(a) = self.details(ui, &hit, installed) {
+                        action = Some(a);
+                    }
+                });
+        }
+
+        if self.searching {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(tr_args(
+                    "browse-searching",
+                    &[("site", self.site.label().into())],
+                ));
+            });
+            return action;
+        }
+        if let Some(e) = &self.search_error {
+            ui.colored_label(
+                egui::Color32::RED,
+                tr_args("browse-error", &[("error", e.into())]),
+            );
+            return action;
+        }
+        if self.results.is_empty() {
+            ui.label(tr("mod-browse-empty"));
+            return action;
+        }
+
+        let mut fetch = None;
+        egui::ScrollArea::vertical()
+            .id_salt("mod_browse_results")
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                for hit in &self.results {
+                    let selected = self.selected.as_ref().is_some_and(|s| s.id == hit.id);
+                    let fill = if selected {
+                        egui::Color32::from_rgb(40, 60, 80)
+                    } else {
+                        ui.style().visuals.extreme_bg_color
+                    };
+                    let resp = egui::Frame::none()
+                        .fill(fill)
+                        .rounding(6.0)
+                        .inner_margin(8.0)
+                        .show(ui, |ui| {
+                            ui.set_width(ui.available_width());
+                            ui.horizontal(|ui| {
+                                match &hit.icon_url {
+                                    Some(url) => {
+                                        ui.add(
+                                            egui::Image::new(url)
+                                                .max_width(40.0)
+                                                .max_height(40.0)
+                                                .rounding(4.0),
+                                        );
+                                    }
+                                    None => {
+                                        ui.allocate_space(egui::vec2(40.0, 40.0));
+                                    }
+                                }
+                                ui.vertical(|ui| {
+                                    ui.horizontal(|ui| {
+                                        ui.strong(&hit.title);
+                                        ui.small(tr_args(
+                                            "browse-downloads-short",
+                                            &[(
+                                                "count",
+                                                curseforge::format_downloads(hit.downloads).into(),
+                                            )],
+                                        ));
+                                        if installed.contains(&normalize_id(&hit.slug)) {
+                                            ui.colored_label(
+                                                egui::Color32::GREEN,
+                                                tr("mod-browse-installed"),
+                                            );
+                                        }
+                                    });
+                                    ui.label(&hit.summary);
+                                });
+                            });
+                        })
+                        .response;
+                    if resp.interact(egui::Sense::click()).clicked() && !selected {
+                        fetch = Some(hit.clone());
+                    }
+                    ui.add_space(3.0);
+                }
+
+                ui.add_space(8.0);
+                let page = self.offset / PAGE_SIZE + 1;
+                let pages = self.total.div_ceil(PAGE_SIZE).max(1);
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(page > 1, egui::Button::new(tr("browse-prev")))
+                        .clicked()
+                    {
+                        self.offset = self.offset.saturating_sub(PAGE_SIZE);
+                        action = Some(ModBrowseAction::Search);
+                    }
+                    ui.label(tr_args(
+                        "browse-page",
+                        &[("page", page.into()), ("pages", pages.into())],
+                    ));
+                    if ui
+                        .add_enabled(page < pages, egui::Button::new(tr("browse-next")))
+                        .clicked()
+                    {
+                        self.offset += PAGE_SIZE;
+                        action = Some(ModBrowseAction::Search);
+                    }
+                });
+            });
+        if matches!(action, Some(ModBrowseAction::Search)) {
+            action = self.start_search();
+        }
+
+        if let Some(hit) = fetch {
+            let id = hit.id.clone();
+            self.selected = Some(hit);
+            self.releases.clear();
+            self.releases_error = None;
+            self.loading_releases = true;
+            action = Some(ModBrowseAction::FetchReleases(id));
+        }
+
+        action
+    }
+
+    /// Side panel for the selected mod: its builds for the server and the install button
+    fn details(
+        &mut self,
+        ui: &mut egui::Ui,
+        hit: &ModHit,
+        installed: &HashSet<String>,
+    ) -> Option<ModBrowseAction> {
+        let mut action = None;
+        ui.heading(&hit.title);
+        ui.label(&hit.summary);
+        ui.add_space(8.0);
+
+        if self.loading_releases {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(tr("browse-loading-versions"));
+            });
+            return None;
+        }
+        if let Some(e) = &self.releases_error {
+            ui.colored_label(egui::Color32::RED, e);
+            return None;
+        }
+        let Some(release) = self.releases.get(self.selected_release) else {
+            ui.label(tr("mod-browse-no-build"));
+            return None;
+        };
+
+        egui::ComboBox::from_id_salt("mod_browse_release")
+            .selected_text(&release.name)
+            .width(ui.available_width() - 16.0)
+            .show_ui(ui, |ui| {
+                for (i, r) in self.releases.iter().enumerate() {
+                    let date = r.date.get(..10).unwrap_or(&r.date);
+                    ui.selectable_value(
+                        &mut self.selected_release,
+                        i,
+                        format!("{} ({})", r.name, date),
+                    );
+                }
+            });
+        let release = &self.releases[self.selected_release];
+        ui.small(&release.file_name);
+        if !release.dependencies.is_empty() {
+            ui.small(tr_args(
+                "mod-browse-dependencies",
+                &[("count", release.dependencies.len().into())],
+            ));
+        }
+        if release.client_only {
+            ui.colored_label(egui::Color32::YELLOW, tr("mod-browse-client-only"));
+        }
+        if installed.contains(&normalize_id(&hit.slug)) {
+            ui.colored_label(egui::Color32::YELLOW, tr("mod-browse-already-installed"));
+        }
+        ui.add_space(8.0);
+
+        let busy = self.installing.contains(&hit.title);
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(!busy, egui::Button::new(tr("mod-browse-install")))
+                .clicked()
+            {
+                action = Some(ModBrowseAction::Install(release.clone(), hit.title.clone()));
+            }
+            if busy {
+                ui.spinner();
+                ui.label(tr("mod-browse-installing"));
+            }
+        });
+        action
+    }
+}
@@ -0,0 +1,411 @@
+use crate::backup::format_bytes;
+use crate::i18n::{tr, tr_args};
+use crate::mods::{version_satisfies, DependencyIssue, ModGraph};
+use eframe::egui;
+use std::path::PathBuf;
+
+/// What the user chose on the mods screen
+#[derive(Debug, Clone, PartialEq)]
+pub enum ModsAction {
+    Back,
+    Rescan,
+    /// Install a missing dependency by mod ID
+    Install(String),
+    InstallAllMissing,
+    /// Move unused library jars (file names) out of the mods folder
+    RemoveUnused(Vec<String>),
+    /// Move one jar out of the mods folder
+    Delete(String),
+    /// Copy jars from this machine into the mods folder
+    Add(Vec<PathBuf>),
+    /// Open the full dependency screen
+    Dependencies,
+    /// Search CurseForge/Modrinth for mods to install
+    Browse,
+}
+
+pub struct ModsView;
+
+impl ModsView {
+    pub fn show(
+        ui: &mut egui::Ui,
+        server_name: &str,
+        graph: Option<&ModGraph>,
+        scanning: bool,
+        installing: &[String],
+    ) -> Option<ModsAction> {
+        let mut action = None;
+
+        ui.horizontal(|ui| {
+            ui.heading(tr_args("mods-title", &[("server", server_name.into())]));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui
+                    .add_enabled(!scanning, egui::Button::new(tr("mods-rescan")))
+                    .clicked()
+                {
+                    action = Some(ModsAction::Rescan);
+                }
+                if ui.button(tr("button-back")).clicked() {
+                    action = Some(ModsAction::Back);
+                }
+            });
+        });
+        ui.separator();
+
+        let graph = match graph {
+            Some(g) if !scanning => g,
+            _ => {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label(tr("mods-reading"));
+                });
+                return action;
+            }
+        };
+
+        if graph.mods.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.add_space(50.0);
+                ui.label(tr("mods-none"));
+                ui.add_space(10.0);
+                ui.label(tr("mods-none-hint"));
+            });
+            return action;
+        }
+
+        ui.label(tr_args(
+            "mods-summary",
+            &[
+                ("mods", graph.mods.len().into()),
+                ("issues", graph.issues.len().into()),
+            ],
+        ));
+        ui.add_space(8.0);
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            // ── Fix list ──────────────────────────────────────────────
+            ui.strong(tr("mods-issues"));
+            ui.add_space(4.0);
+            if graph.issues.is_empty() {
+                ui.colored_label(egui::Color32::GREEN, tr("mods-all-satisfied"));
+            }
+            for issue in &graph.issues {
+                if let Some(a) = Self::issue_row(ui, issue, installing) {
+                    action = Some(a);
+                }
+            }
+            if graph.missing_count() > 1
+                && ui
+                    .add_enabled(
+                        installing.is_empty(),
+                        egui::Button::new(tr_args(
+                            "mods-install-all-missing",
+                            &[("count", graph.missing_count().into())],
+                        )),
+                    )
+                    .clicked()
+            {
+                action = Some(ModsAction::InstallAllMissing);
+            }
+            let orphaned = graph.orphaned_files();
+            if orphaned.len() > 1
+                && ui
+                    .button(tr_args(
+                        "mods-remove-all-unused",
+                        &[("count", orphaned.len().into())],
+                    ))
+                    .on_hover_text(tr("mods-remove-all-unused-hint"))
+                    .clicked()
+            {
+                action = Some(ModsAction::RemoveUnused(orphaned));
+            }
+            if !graph.unreadable.is_empty() {
+                ui.add_space(4.0);
+                ui.small(tr_args(
+                    "mods-unreadable",
+                    &[("files", graph.unreadable.join(", ").into())],
+                ));
+            }
+
+            ui.add_space(12.0);
+            ui.separator();
+
+            // ── Dependency graph ──────────────────────────────────────
+            ui.strong(tr("mods-graph"));
+            ui.add_space(4.0);
+            for m in &graph.mods {
+                let has_problem = m.dependencies.iter().any(|d| match graph.find(&d.mod_id) {
+                    None => true,
+                    Some(dep) => d.version_req.as_deref().is_some_and(|r| {
+                        dep.mod_id == d.mod_id && !version_satisfies(&dep.version, r)
+                    }),
+                });
+                let title = if has_problem {
+                    egui::RichText::new(format!("{} {}", m.name, m.version))
+                        .color(egui::Color32::from_rgb(230, 90, 90))
+                } else {
+                    egui::RichText::new(format!("{} {}", m.name, m.version))
+                };
+
+                egui::CollapsingHeader::new(title)
+                    .id_salt(&m.file_name)
+                    .show(ui, |ui| {
+                        ui.small(format!("{} ({})", m.mod_id, m.file_name));
+                        if m.dependencies.is_empty() {
+                            ui.label(tr("mods-depends-on-nothing"));
+                        } else {
+                            ui.label(tr("mods-depends-on"));
+                            for dep in &m.dependencies {
+                                let req = dep.version_req.as_deref().unwrap_or("any");
+                                let (icon, color, detail) = match graph.find(&dep.mod_id) {
+                                    None => ("✗", egui::Color32::RED, tr("mods-dep-missing")),
+                                    Some(found)
+                                        if found.mod_id == dep.mod_id
+                                            && !version_satisfies(&found.version, req) =>
+                                    {
+                                        (
+                                            "⚠",
+                                            egui::Color32::YELLOW,
+                                            tr_args(
+                                                "mods-dep-installed",
+                                                &[("version", found.version.as_str().into())],
+                                            ),
+                                        )
+                                    }
+                                    Some(found) => (
+                                        "✓",
+                                        egui::Color32::GREEN,
+                                        tr_args(
+                                            "mods-dep-via",
+                                            &[("file", found.file_name.as_str().into())],
+                                        ),
+                                    ),
+                                };
+                                ui.horizontal(|ui| {
+                                    ui.add_space(12.0);
+                                    ui.colored_label(color, icon);
+                                    let shown_req = dep
+                                        .version_req
+                                        .clone()
+                                        .unwrap_or_else(|| tr("mods-any-version"));
+                                    ui.label(format!("{} {} ({})", dep.mod_id, shown_req, detail));
+                                });
+                            }
+                        }
+
+                        let dependents = graph.dependents(m);
+                        if !dependents.is_empty() {
+                            let names: Vec<&str> =
+                                dependents.iter().map(|d| d.name.as_str()).collect();
+                            ui.label(tr_args(
+                                "mods-required-by",
+                                &[("mods", names.join(", ").into())],
+                            ));
+                        }
+                    });
+            }
+        });
+
+        action
+    }
+
+    /// Mods tab of the server details page: every jar in the mods folder with its
+    /// name, version and size, plus adding jars (button or drag-and-drop) and deleting them
+    pub fn manager(
+        ui: &mut egui::Ui,
+        graph: Option<&ModGraph>,
+        scanning: bool,
+        running: bool,
+    ) -> Option<ModsAction> {
+        let mut action = None;
+
+        ui.horizontal(|ui| {
+            if ui.button(tr("mods-browse")).clicked() {
+                action = Some(ModsAction::Browse);
+            }
+            if ui.button(tr("mods-add-jars")).clicked() {
+                if let Some(paths) = rfd::FileDialog::new()
+                    .add_filter(tr("mods-jar-filter"), &["jar"])
+                    .pick_files()
+                {
+                    action = Some(ModsAction::Add(paths));
+                }
+            }
+            if ui
+                .add_enabled(!scanning, egui::Button::new(tr("mods-rescan")))
+                .clicked()
+            {
+                action = Some(ModsAction::Rescan);
+            }
+            if ui
+                .button(tr("mods-dependencies"))
+                .on_hover_text(tr("mods-dependencies-hint"))
+                .clicked()
+            {
+                action = Some(ModsAction::Dependencies);
+            }
+        });
+        ui.small(tr("mods-drop-hint"));
+        if running {
+            ui.small(tr("mods-running-hint"));
+        }
+
+        let dropped: Vec<PathBuf> = ui.ctx().input(|i| {
+            i.raw
+                .dropped_files
+                .iter()
+                .filter_map(|f| f.path.clone())
+                .collect()
+        });
+        if !dropped.is_empty() {
+            action = Some(ModsAction::Add(dropped));
+        }
+        if ui.ctx().input(|i| !i.raw.hovered_files.is_empty()) {
+            ui.colored_label(egui::Color32::LIGHT_BLUE, tr("mods-drop-here"));
+        }
+        ui.separator();
+
+        let graph = match graph {
+            Some(g) if !scanning => g,
+            _ => {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label(tr("mods-reading"));
+                });
+                return action;
+            }
+        };
+
+        // Readable jars by name, then the ones without metadata
+        let mut rows: Vec<(String, String, &str)> = graph
+            .mods
+            .iter()
+            .map(|m| (m.name.clone(), m.version.clone(), m.file_name.as_str()))
+            .collect();
+        rows.sort_by_key(|(name, _, _)| name.to_lowercase());
+        rows.extend(
+            graph
+                .unreadable
+                .iter()
+                .map(|f| ("—".to_string(), String::new(), f.as_str())),
+        );
+        if rows.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.add_space(50.0);
+                ui.label(tr("mods-folder-empty"));
+            });
+            return action;
+        }
+        let total: u64 = graph.sizes.values().sum();
+        ui.label(tr_args(
+            "mods-jar-count",
+            &[
+                ("count", rows.len().into()),
+                ("size", format_bytes(total).into()),
+            ],
+        ));
+        ui.add_space(4.0);
+
+        egui::ScrollArea::vertical()
+            .id_salt("mods_manager_list")
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                egui::Grid::new("mods_manager_grid")
+                    .num_columns(5)
+                    .spacing([16.0, 4.0])
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.strong(tr("mods-col-name"));
+                        ui.strong(tr("mods-col-version"));
+                        ui.strong(tr("mods-col-file"));
+                        ui.strong(tr("mods-col-size"));
+                        ui.label("");
+                        ui.end_row();
+                        for (name, version, file_name) in rows {
+                            ui.label(name);
+                            ui.label(version);
+                            ui.small(file_name);
+                            ui.label(
+                                graph
+                                    .sizes
+                                    .get(file_name)
+                                    .map_or(String::new(), |s| format_bytes(*s)),
+                            );
+                            if ui
+                                .add_enabled(!running, egui::Button::new(tr("button-delete")))
+                                .on_hover_text(tr("mods-delete-hint"))
+                                .on_disabled_hover_text(tr("mods-stop-first"))
+                                .clicked()
+                            {
+                                action = Some(ModsAction::Delete(file_name.to_string()));
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+
+        action
+    }
+
+    fn issue_row(
+        ui: &mut egui::Ui,
+        issue: &DependencyIssue,
+        installing: &[String],
+    ) -> Option<ModsAction> {
+        let mut action = None;
+        ui.horizontal(|ui| match issue {
+            DependencyIssue::Missing {
+                mod_id,
+                version_req,
+                required_by,
+            } => {
+                ui.colored_label(egui::Color32::RED, "✗");
+                ui.strong(tr_args("mods-issue-missing", &[("id", mod_id.into())]));
+                if let Some(req) = version_req {
+                    ui.label(req);
+                }
+                ui.small(tr_args(
+                    "mods-issue-required-by",
+                    &[("mods", required_by.join(", ").into())],
+                ));
+                if installing.contains(mod_id) {
+                    ui.spinner();
+                } else if ui.button(tr("mod-browse-install")).clicked() {
+                    action = Some(ModsAction::Install(mod_id.clone()));
+                }
+            }
+            DependencyIssue::Outdated {
+                mod_id,
+                installed,
+                version_req,
+                required_by,
+            } => {
+                ui.colored_label(egui::Color32::YELLOW, "⚠");
+                ui.strong(tr_args("mods-issue-outdated", &[("id", mod_id.into())]));
+                ui.label(tr_args(
+                    "mods-issue-needs",
+                    &[
+                        ("installed", installed.into()),
+                        ("needed", version_req.into()),
+                    ],
+                ));
+                ui.small(tr_args(
+                    "mods-issue-required-by",
+                    &[("mods", required_by.join(", ").into())],
+                ));
+            }
+            DependencyIssue::Orphaned { mod_id, file_name } => {
+                ui.colored_label(egui::Color32::GRAY, "○");
+                ui.strong(tr_args("mods-issue-unused", &[("id", mod_id.into())]));
+                ui.small(tr_args(
+                    "mods-issue-unused-file",
+                    &[("file", file_name.into())],
+                ));
+                if ui.button(tr("button-remove")).clicked() {
+                    action = Some(ModsAction::RemoveUnused(vec![file_name.clone()]));
+                }
+            }
+        });
+        action
+    }
+}
@@ -0,0 +1,175 @@
+use crate::i18n::tr;
+use crate::ui::motd_preview;
+use eframe::egui;
+use egui::text::{CCursor, CCursorRange};
+use egui::Color32;
+
+/// Colour codes with the message ID of their name and the colour they show as
+const COLORS: [(char, &str, Color32); 16] = [
+    ('0', "motd-color-black", Color32::from_rgb(0x00, 0x00, 0x00)),
+    (
+        '1',
+        "motd-color-dark-blue",
+        Color32::from_rgb(0x00, 0x00, 0xAA),
+    ),
+    (
+        '2',
+        "motd-color-dark-green",
+        Color32::from_rgb(0x00, 0xAA, 0x00),
+    ),
+    (
+        '3',
+        "motd-color-dark-aqua",
+        Color32::from_rgb(0x00, 0xAA, 0xAA),
+    ),
+    (
+        '4',
+        "motd-color-dark-red",
+        Color32::from_rgb(0xAA, 0x00, 0x00),
+    ),
+    (
+        '5',
+        "motd-color-dark-purple",
+        Color32::from_rgb(0xAA, 0x00, 0xAA),
+    ),
+    ('6', "motd-color-gold", Color32::from_rgb(0xFF, 0xAA, 0x00)),
+    ('7', "motd-color-gray", Color32::from_rgb(0xAA, 0xAA, 0xAA)),
+    (
+        '8',
+        "motd-color-dark-gray",
+        Color32::from_rgb(0x55, 0x55, 0x55),
+    ),
+    ('9', "motd-color-blue", Color32::from_rgb(0x55, 0x55, 0xFF)),
+    ('a', "motd-color-green", Color32::from_rgb(0x55, 0xFF, 0x55)),
+    ('b', "motd-color-aqua", Color32::from_rgb(0x55, 0xFF, 0xFF)),
+    ('c', "motd-color-red", Color32::from_rgb(0xFF, 0x55, 0x55)),
+    (
+        'd',
+        "motd-color-light-purple",
+        Color32::from_rgb(0xFF, 0x55, 0xFF),
+    ),
+    (
+        'e',
+        "motd-color-yellow",
+        Color32::from_rgb(0xFF, 0xFF, 0x55),
+    ),
+    ('f', "motd-color-white", Color32::from_rgb(0xFF, 0xFF, 0xFF)),
+];
+
+/// Format codes with the message ID of what they do
+const FORMATS: [(char, &str); 6] = [
+    ('l', "motd-format-bold"),
+    ('o', "motd-format-italic"),
+    ('n', "motd-format-underline"),
+    ('m', "motd-format-strikethrough"),
+    ('k', "motd-format-obfuscated"),
+    ('r', "motd-format-reset"),
+];
+
+/// Background of the multiplayer server list, for the preview
+const LIST_BACKGROUND: Color32 = Color32::from_rgb(0x1E, 0x1E, 0x1E);
+
+/// MOTD text box with a palette of colour and format codes and a preview.
+/// `&` codes typed by hand (e.g. `&6Gold`) become `§` codes, which is what
+/// server.properties stores. Returns true if the MOTD changed.
+pub fn motd_editor(ui: &mut egui::Ui, id_salt: &str, motd: &mut String) -> bool {
+    let id = ui.make_persistent_id(id_salt);
+    let mut changed = false;
+
+    ui.vertical(|ui| {
+        let response = ui.add(
+            egui::TextEdit::singleline(motd)
+                .id(id)
+                .desired_width(360.0)
+                .hint_text("A Minecraft Server"),
+        );
+        if response.changed() {
+            *motd = escape_codes(motd);
+            changed = true;
+        }
+
+        let mut insert = None;
+        ui.horizontal_wrapped(|ui| {
+            ui.spacing_mut().item_spacing.x = 2.0;
+            for (code, name, color) in COLORS {
+                let button = egui::Button::new("  ").fill(color).small();
+                if ui
+                    .add(button)
+                    .on_hover_text(format!("{} (§{})", tr(name), code))
+                    .clicked()
+                {
+                    insert = Some(code);
+                }
+            }
+            ui.add_space(8.0);
+            for (code, name) in FORMATS {
+                let label = egui::RichText::new(code.to_string()).monospace();
+                let label = match code {
+                    'o' => label.italics(),
+                    'n' => label.underline(),
+                    'm' => label.strikethrough(),
+                    _ => label,
+                };
+                if ui
+                    .add(egui::Button::new(label).small())
+                    .on_hover_text(format!("{} (§{})", tr(name), code))
+                    .clicked()
+                {
+                    insert = Some(code);
+                }
+            }
+        });
+
+        // Insert at the cursor (or the end), then put the cursor after the code
+        if let Some(code) = insert {
+            let mut state = egui::TextEdit::load_state(ui.ctx(), id).unwrap_or_default();
+            let chars = motd.chars().count();
+            let at = state
+                .cursor
+                .char_range()
+                .map_or(chars, |r| r.primary.index.min(chars));
+            let byte = motd.char_indices().nth(at).map_or(motd.len(), |(i, _)| i);
+            motd.insert_str(byte, &format!("§{}", code));
+            state
+                .cursor
+                .set_char_range(Some(CCursorRange::one(CCursor::new(at + 2))));
+            state.store(ui.ctx(), id);
+            ui.memory_mut(|m| m.request_focus(id));
+            changed = true;
+        }
+
+        egui::Frame::none()
+            .fill(LIST_BACKGROUND)
+            .inner_margin(egui::Margin::symmetric(8.0, 6.0))
+            .show(ui, |ui| {
+                ui.set_min_width(360.0);
+                if motd.is_empty() {
+                    ui.label(
+                        egui::RichText::new("A Minecraft Server")
+                            .color(Color32::from_rgb(0xAA, 0xAA, 0xAA)),
+                    );
+                } else {
+                    ui.label(motd_preview(ui, motd));
+                }
+            });
+        ui.small(tr("motd-hint"));
+    });
+    changed
+}
+
+/// Turn `&` + a code character into the `§` code; other `&`s are left alone
+fn escape_codes(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let is_code = chars
+            .peek()
+            .is_some_and(|n| n.is_ascii_hexdigit() || "klmnorKLMNOR".contains(*n));
+        if c == '&' && is_code {
+            out.push('§');
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
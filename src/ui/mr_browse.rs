@@ -1,4 +1,6 @@
 use crate::curseforge;
+use crate::i18n::{tr, tr_args};
+use crate::jvm::JvmPreset;
 use crate::modrinth::{self, MrProject, MrSortIndex, MrVersion};
 use crate::server::{ModLoader, ModpackSource};
 use crate::templates::ModpackTemplate;
@@ -38,12 +40,12 @@ impl MrSearchState {
         }
     }
 
-    fn loader_label(&self) -> &'static str {
+    fn loader_label(&self) -> String {
         match self.loader_filter_idx {
-            1 => "Forge",
-            2 => "Fabric",
-            3 => "NeoForge",
-            _ => "Any",
+            1 => "Forge".to_string(),
+            2 => "Fabric".to_string(),
+            3 => "NeoForge".to_string(),
+            _ => tr("browse-any"),
         }
     }
 }
@@ -105,48 +107,40 @@ impl MrBrowseWidget {
             let mut trigger_search = false;
 
             ui.horizontal(|ui| {
-                ui.label("Search:");
+                ui.label(tr("browse-search"));
                 let resp = ui.add(
                     egui::TextEdit::singleline(&mut self.state.search.query)
                         .desired_width(200.0)
-                        .hint_text("e.g. Cobblemon"),
+                        .hint_text(tr("mr-search-hint")),
                 );
                 if resp.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
                     trigger_search = true;
                 }
-                if ui.button("Search").clicked() {
+                if ui.button(tr("browse-search-button")).clicked() {
                     trigger_search = true;
                 }
             });
 
             // ── Filters ───────────────────────────────────────────────────
             ui.horizontal(|ui| {
-                ui.label("MC Version:");
+                ui.label(tr("browse-mc-version"));
                 ui.add(
                     egui::TextEdit::singleline(&mut self.state.search.mc_version_filter)
                         .desired_width(60.0)
-                        .hint_text("e.g. 1.20.1"),
+                        .hint_text(tr("browse-mc-version-hint")),
                 );
 
-                ui.label("Loader:");
+                ui.label(tr("browse-loader"));
                 egui::ComboBox::from_id_salt(format!("{}_mr_loader_filter", id_salt))
                     .selected_text(self.state.search.loader_label())
                     .show_ui(ui, |ui| {
                         ui.selectable_value(
                             &mut self.state.search.loader_filter_idx,
                             0,
-                            "Any",
-                        );
-                        ui.selectable_value(
-                            &mut self.state.search.loader_filter_idx,
-                            1,
-                            "Forge",
-                        );
-                        ui.selectable_value(
-                            &mut self.state.search.loader_filter_idx,
-                            2,
-                            "Fabric",
+                            tr("browse-any"),
                         );
+                        ui.selectable_value(&mut self.state.search.loader_filter_idx, 1, "Forge");
+                        ui.selectable_value(&mut self.state.search.loader_filter_idx, 2, "Fabric");
                         ui.selectable_value(
                             &mut self.state.search.loader_filter_idx,
                             3,
@@ -154,16 +148,12 @@ impl MrBrowseWidget {
                         );
                     });
 
-                ui.label("Sort:");
+                ui.label(tr("browse-sort"));
                 egui::ComboBox::from_id_salt(format!("{}_mr_sort_field", id_salt))
                     .selected_text(self.state.search.sort_index.label())
                     .show_ui(ui, |ui| {
                         for si in MrSortIndex::ALL {
-                            ui.selectable_value(
-                                &mut self.state.search.sort_index,
-                                si,
-                                si.label(),
-                            );
+                            ui.selectable_value(&mut self.state.search.sort_index, si, si.label());
                         }
                     });
             });
@@ -191,21 +181,24 @@ impl MrBrowseWidget {
             if self.state.loading_search {
                 ui.horizontal(|ui| {
                     ui.spinner();
-                    ui.label("Searching Modrinth...");
+                    ui.label(tr_args("browse-searching", &[("site", "Modrinth".into())]));
                 });
                 return;
             }
 
             if let Some(err) = &self.state.search_error.clone() {
-                ui.colored_label(egui::Color32::RED, format!("Error: {}", err));
+                ui.colored_label(
+                    egui::Color32::RED,
+                    tr_args("browse-error", &[("error", err.into())]),
+                );
                 return;
             }
 
             if self.state.results.is_empty() && self.state.total_count == 0 {
                 if self.state.search.query.is_empty() {
-                    ui.label("Enter a search term and click Search to find modpacks.");
+                    ui.label(tr("browse-enter-term"));
                 } else {
-                    ui.label("No results found.");
+                    ui.label(tr("browse-no-results"));
                 }
                 return;
             }
@@ -238,9 +231,7 @@ impl MrBrowseWidget {
                                         .state
                                         .selected_project
                                         .as_ref()
-                                        .is_some_and(|p| {
-                                            p.project_id == project.project_id
-                                        });
+                                        .is_some_and(|p| p.project_id == project.project_id);
 
                                     let frame_fill = if is_selected {
                                         egui::Color32::from_rgb(40, 60, 80)
@@ -263,19 +254,21 @@ impl MrBrowseWidget {
                                                             .rounding(4.0),
                                                     );
                                                 } else {
-                                                    ui.allocate_space(egui::vec2(
-                                                        64.0, 64.0,
-                                                    ));
+                                                    ui.allocate_space(egui::vec2(64.0, 64.0));
                                                 }
 
                                                 ui.vertical(|ui| {
                                                     ui.horizontal(|ui| {
                                                         ui.strong(&project.title);
-                                                        ui.small(format!(
-                                                            "({} downloads)",
-                                                            curseforge::format_downloads(
-                                                                project.downloads,
-                                                            )
+                                                        ui.small(tr_args(
+                                                            "browse-downloads-short",
+                                                            &[(
+                                                                "count",
+                                                                curseforge::format_downloads(
+                                                                    project.downloads,
+                                                                )
+                                                                .into(),
+                                                            )],
                                                         ));
                                                     });
                                                     ui.label(&project.description);
@@ -294,8 +287,7 @@ impl MrBrowseWidget {
                                         .response;
 
                                     if resp.interact(egui::Sense::click()).clicked() {
-                                        self.state.selected_project =
-                                            Some(project.clone());
+                                        self.state.selected_project = Some(project.clone());
                                         self.state.versions.clear();
                                         self.state.mc_versions.clear();
                                         self.state.selected_mc_version = None;
@@ -305,8 +297,7 @@ impl MrBrowseWidget {
                                         self.state.description = None;
                                         self.state.loading_description = true;
                                         self.template = None;
-                                        fetch_project_id =
-                                            Some(project.slug.clone());
+                                        fetch_project_id = Some(project.slug.clone());
                                     }
 
                                     ui.add_space(3.0);
@@ -316,49 +307,40 @@ impl MrBrowseWidget {
                                 if self.state.total_count > 0 {
                                     ui.add_space(8.0);
                                     ui.separator();
-                                    let page =
-                                        (self.state.search.page_offset / 20) + 1;
-                                    let total_pages =
-                                        self.state.total_count.div_ceil(20);
+                                    let page = (self.state.search.page_offset / 20) + 1;
+                                    let total_pages = self.state.total_count.div_ceil(20);
 
                                     ui.horizontal(|ui| {
                                         if ui
                                             .add_enabled(
                                                 page > 1,
-                                                egui::Button::new("< Prev"),
+                                                egui::Button::new(tr("browse-prev")),
                                             )
                                             .clicked()
                                         {
-                                            self.state.search.page_offset = self
-                                                .state
-                                                .search
-                                                .page_offset
-                                                .saturating_sub(20);
+                                            self.state.search.page_offset =
+                                                self.state.search.page_offset.saturating_sub(20);
                                             self.state.loading_search = true;
                                             self.state.search_error = None;
-                                            (callbacks.on_search)(
-                                                self.state.search.clone(),
-                                            );
+                                            (callbacks.on_search)(self.state.search.clone());
                                         }
 
-                                        ui.label(format!(
-                                            "Page {} / {}",
-                                            page, total_pages
+                                        ui.label(tr_args(
+                                            "browse-page",
+                                            &[("page", page.into()), ("pages", total_pages.into())],
                                         ));
 
                                         if ui
                                             .add_enabled(
                                                 page < total_pages,
-                                                egui::Button::new("Next >"),
+                                                egui::Button::new(tr("browse-next")),
                                             )
                                             .clicked()
                                         {
                                             self.state.search.page_offset += 20;
                                             self.state.loading_search = true;
                                             self.state.search_error = None;
-                                            (callbacks.on_search)(
-                                                self.state.search.clone(),
-                                            );
+                                            (callbacks.on_search)(self.state.search.clone());
                                         }
                                     });
                                 }
@@ -428,9 +410,12 @@ impl MrBrowseWidget {
                     ui.heading(&selected.title);
                     ui.add_space(4.0);
                     ui.horizontal(|ui| {
-                        ui.label(format!(
-                            "{} downloads",
-                            curseforge::format_downloads(selected.downloads)
+                        ui.label(tr_args(
+                            "browse-downloads",
+                            &[(
+                                "count",
+                                curseforge::format_downloads(selected.downloads).into(),
+                            )],
                         ));
                     });
                     ui.add_space(4.0);
@@ -438,7 +423,7 @@ impl MrBrowseWidget {
                     // ── Categories ──
                     if !selected.categories.is_empty() {
                         ui.horizontal_wrapped(|ui| {
-                            ui.strong("Categories: ");
+                            ui.strong(tr("mr-categories"));
                             ui.label(selected.categories.join(", "));
                         });
                         ui.add_space(4.0);
@@ -450,7 +435,7 @@ impl MrBrowseWidget {
                     if self.state.loading_description {
                         ui.horizontal(|ui| {
                             ui.spinner();
-                            ui.label("Loading description...");
+                            ui.label(tr("browse-loading-description"));
                         });
                     } else if let Some(desc) = &self.state.description {
                         // Modrinth descriptions are markdown; show as plain text
@@ -470,27 +455,30 @@ impl MrBrowseWidget {
                     ui.add_space(4.0);
 
                     // ── Version picker ──
-                    ui.strong("Version Selection");
+                    ui.strong(tr("browse-version-selection"));
                     ui.add_space(4.0);
 
                     if self.state.loading_versions {
                         ui.horizontal(|ui| {
                             ui.spinner();
-                            ui.label("Loading versions...");
+                            ui.label(tr("browse-loading-versions"));
                         });
                     } else if let Some(err) = &self.state.versions_error.clone() {
-                        ui.colored_label(egui::Color32::RED, format!("Error: {}", err));
+                        ui.colored_label(
+                            egui::Color32::RED,
+                            tr_args("browse-error", &[("error", err.into())]),
+                        );
                     } else if self.state.versions.is_empty() {
-                        ui.label("No versions found.");
+                        ui.label(tr("browse-no-versions"));
                     } else {
                         // ── MC Version dropdown ──
                         ui.horizontal(|ui| {
-                            ui.label("Minecraft Version:");
+                            ui.label(tr("browse-minecraft-version"));
                             let mc_label = self
                                 .state
                                 .selected_mc_version
-                                .as_deref()
-                                .unwrap_or("Select...");
+                                .clone()
+                                .unwrap_or_else(|| tr("browse-select"));
                             egui::ComboBox::from_id_salt(format!(
                                 "{}_mr_mc_version_picker",
                                 id_salt
@@ -498,14 +486,10 @@ impl MrBrowseWidget {
                             .selected_text(mc_label)
                             .show_ui(ui, |ui| {
                                 for ver in &self.state.mc_versions.clone() {
-                                    let is_sel = self
-                                        .state
-                                        .selected_mc_version
-                                        .as_deref()
+                                    let is_sel = self.state.selected_mc_version.as_deref()
                                         == Some(ver.as_str());
                                     if ui.selectable_label(is_sel, ver).clicked() {
-                                        self.state.selected_mc_version =
-                                            Some(ver.clone());
+                                        self.state.selected_mc_version = Some(ver.clone());
                                         self.state.selected_version_idx = None;
                                         self.template = None;
                                     }
@@ -550,12 +534,12 @@ impl MrBrowseWidget {
                                     v.name.as_str()
                                 }
                             })
-                            .unwrap_or("Select...");
+                            .map_or_else(|| tr("browse-select"), str::to_string);
 
                         let mut clicked_version_idx: Option<usize> = None;
 
                         ui.horizontal(|ui| {
-                            ui.label("Pack Version:");
+                            ui.label(tr("browse-pack-version"));
                             egui::ComboBox::from_id_salt(format!(
                                 "{}_mr_pack_version_picker",
                                 id_salt
@@ -564,8 +548,7 @@ impl MrBrowseWidget {
                             .width(300.0)
                             .show_ui(ui, |ui| {
                                 for (orig_idx, label) in &filtered_versions {
-                                    let is_sel =
-                                        self.state.selected_version_idx == Some(*orig_idx);
+                                    let is_sel = self.state.selected_version_idx == Some(*orig_idx);
                                     if ui.selectable_label(is_sel, label).clicked() {
                                         clicked_version_idx = Some(*orig_idx);
                                     }
@@ -581,7 +564,7 @@ impl MrBrowseWidget {
                         }
 
                         if filtered_versions.is_empty() {
-                            ui.small("No versions for this Minecraft version.");
+                            ui.small(tr("mr-no-versions-for-mc"));
                         }
                     }
                 });
@@ -590,6 +573,19 @@ impl MrBrowseWidget {
         built
     }
 
+    /// Show `project` as the only result and select it, as if the user had searched
+    /// for `query` and clicked it. The caller dispatches the version/description fetches.
+    pub fn preselect(&mut self, query: &str, project: MrProject) {
+        self.state = MrBrowseState::default();
+        self.state.search.query = query.to_string();
+        self.state.results = vec![project.clone()];
+        self.state.total_count = 1;
+        self.state.selected_project = Some(project);
+        self.state.loading_versions = true;
+        self.state.loading_description = true;
+        self.template = None;
+    }
+
     // ── Build template from Modrinth data ────────────────────────────────
 
     pub fn build_mr_template(&mut self, project: &MrProject, version: &MrVersion) {
@@ -625,8 +621,10 @@ impl MrBrowseWidget {
             },
             recommended_memory_mb: memory,
             java_version,
-            default_java_args: curseforge::default_java_args(),
+            default_java_args: JvmPreset::Aikar.args(java_version, memory),
             default_extra_env: vec![],
+            world_url: None,
+            server_properties: None,
         };
 
         self.template = Some(template);
@@ -0,0 +1,230 @@
+use crate::heads::PlayerHeads;
+use crate::i18n::{tr, tr_args};
+use crate::playtime::{self, PlaytimeStore};
+use eframe::egui;
+
+/// Small pixel-art head shown next to a player name
+pub(super) fn player_head(ui: &mut egui::Ui, heads: &PlayerHeads, name: &str) {
+    ui.add(
+        egui::Image::new(heads.uri(name))
+            .fit_to_exact_size(egui::vec2(20.0, 20.0))
+            .texture_options(egui::TextureOptions::NEAREST),
+    );
+}
+
+/// What the user chose on the players tab. Everything except `Refresh` is sent over RCON.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlayerAction {
+    Refresh,
+    Kick(String),
+    Ban(String),
+    Op(String),
+    Deop(String),
+    Whisper(String),
+}
+
+impl PlayerAction {
+    /// The RCON command for this action. `message` is the whisper text, or the
+    /// kick/ban reason when not empty.
+    pub fn command(&self, message: &str) -> Option<String> {
+        let message = message.trim();
+        let with_reason = |cmd: &str, player: &str| {
+            if message.is_empty() {
+                format!("{} {}", cmd, player)
+            } else {
+                format!("{} {} {}", cmd, player, message)
+            }
+        };
+        match self {
+            PlayerAction::Refresh => None,
+            PlayerAction::Kick(p) => Some(with_reason("kick", p)),
+            PlayerAction::Ban(p) => Some(with_reason("ban", p)),
+            PlayerAction::Op(p) => Some(format!("op {}", p)),
+            PlayerAction::Deop(p) => Some(format!("deop {}", p)),
+            PlayerAction::Whisper(p) => Some(format!("tell {} {}", p, message)),
+        }
+    }
+}
+
+pub struct PlayersView;
+
+impl PlayersView {
+    /// `players` is `None` until the first `list` answer arrives
+    pub fn show(
+        ui: &mut egui::Ui,
+        running: bool,
+        players: Option<&[String]>,
+        ops: &[String],
+        error: Option<&str>,
+        message: &mut String,
+        heads: &PlayerHeads,
+    ) -> Option<PlayerAction> {
+        let mut action = None;
+
+        if !running {
+            ui.vertical_centered(|ui| {
+                ui.add_space(50.0);
+                ui.label(tr("players-start-server"));
+            });
+            return None;
+        }
+
+        ui.horizontal(|ui| {
+            ui.label(tr("players-message"));
+            ui.add(
+                egui::TextEdit::singleline(message)
+                    .hint_text(tr("players-message-hint"))
+                    .desired_width(320.0),
+            );
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button(tr("button-refresh")).clicked() {
+                    action = Some(PlayerAction::Refresh);
+                }
+            });
+        });
+        if let Some(error) = error {
+            ui.colored_label(egui::Color32::RED, format!("RCON: {}", error));
+        }
+        ui.separator();
+
+        let Some(players) = players else {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(tr("players-asking"));
+            });
+            return action;
+        };
+        if players.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.add_space(50.0);
+                ui.label(tr("players-nobody"));
+            });
+            return action;
+        }
+
+        ui.label(tr_args(
+            "players-online",
+            &[("count", players.len().into())],
+        ));
+        ui.add_space(4.0);
+        egui::ScrollArea::vertical()
+            .id_salt("online_players")
+            .max_height(220.0)
+            .show(ui, |ui| {
+                egui::Grid::new("online_players_grid")
+                    .num_columns(2)
+                    .spacing([16.0, 6.0])
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for player in players {
+                            let is_op = ops.iter().any(|o| o.eq_ignore_ascii_case(player));
+                            ui.horizontal(|ui| {
+                                player_head(ui, heads, player);
+                                ui.strong(player);
+                                if is_op {
+                                    ui.small("OP");
+                                }
+                            });
+                            ui.horizontal(|ui| {
+                                if ui
+                                    .add_enabled(
+                                        !message.trim().is_empty(),
+                                        egui::Button::new(tr("players-whisper")),
+                                    )
+                                    .on_disabled_hover_text(tr("players-whisper-hint"))
+                                    .clicked()
+                                {
+                                    action = Some(PlayerAction::Whisper(player.clone()));
+                                }
+                                if ui.button(tr("players-kick")).clicked() {
+                                    action = Some(PlayerAction::Kick(player.clone()));
+                                }
+                                if ui
+                                    .add(
+                                        egui::Button::new(tr("players-ban"))
+                                            .fill(egui::Color32::from_rgb(100, 30, 30)),
+                                    )
+                                    .on_hover_text(tr("players-ban-hint"))
+                                    .clicked()
+                                {
+                                    action = Some(PlayerAction::Ban(player.clone()));
+                                }
+                                if is_op {
+                                    if ui.button(tr("players-deop")).clicked() {
+                                        action = Some(PlayerAction::Deop(player.clone()));
+                                    }
+                                } else if ui.button(tr("players-op")).clicked() {
+                                    action = Some(PlayerAction::Op(player.clone()));
+                                }
+                            });
+                            ui.end_row();
+                        }
+                    });
+            });
+
+        action
+    }
+
+    /// Operators from ops.json, shown whether or not the server is running
+    pub fn operators(ui: &mut egui::Ui, ops: &[String], heads: &PlayerHeads) {
+        if ops.is_empty() {
+            return;
+        }
+        ui.add_space(12.0);
+        ui.separator();
+        ui.strong(tr("players-operators"));
+        ui.add_space(4.0);
+        ui.horizontal_wrapped(|ui| {
+            for op in ops {
+                player_head(ui, heads, op);
+                ui.label(op);
+                ui.add_space(8.0);
+            }
+        });
+    }
+
+    /// Playtime leaderboard and last-seen times
+    pub fn playtime(ui: &mut egui::Ui, store: &PlaytimeStore, heads: &PlayerHeads) {
+        ui.add_space(12.0);
+        ui.separator();
+        ui.strong(tr("players-playtime"));
+        ui.add_space(4.0);
+        let rows = store.leaderboard();
+        if rows.is_empty() {
+            ui.label(tr("players-playtime-empty"));
+            return;
+        }
+
+        egui::ScrollArea::vertical()
+            .id_salt("playtime")
+            .show(ui, |ui| {
+                egui::Grid::new("playtime_grid")
+                    .num_columns(5)
+                    .spacing([16.0, 4.0])
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.strong("#");
+                        ui.strong(tr("players-col-player"));
+                        ui.strong(tr("players-col-playtime"));
+                        ui.strong(tr("players-col-sessions"));
+                        ui.strong(tr("players-col-last-seen"));
+                        ui.end_row();
+                        for (rank, (name, stats)) in rows.iter().enumerate() {
+                            ui.label((rank + 1).to_string());
+                            ui.horizontal(|ui| {
+                                player_head(ui, heads, name);
+                                ui.label(name.as_str());
+                            });
+                            ui.label(playtime::format_duration(stats.total_secs));
+                            ui.label(stats.sessions.to_string());
+                            if store.is_online(name) {
+                                ui.colored_label(egui::Color32::GREEN, tr("players-is-online"));
+                            } else {
+                                ui.label(playtime::format_last_seen(&stats.last_seen));
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+}
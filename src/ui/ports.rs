@@ -0,0 +1,91 @@
+use crate::i18n::{tr, tr_args};
+use crate::ports::PortUsage;
+use eframe::egui;
+
+pub struct PortsView;
+
+impl PortsView {
+    /// Show the port overview table. Returns true when the user asked for a refresh.
+    pub fn show(ui: &mut egui::Ui, rows: &[PortUsage]) -> bool {
+        let mut refresh = false;
+
+        ui.horizontal(|ui| {
+            ui.heading(tr("ports-title"));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button(tr("button-refresh")).clicked() {
+                    refresh = true;
+                }
+            });
+        });
+        ui.label(tr("ports-hint"));
+        ui.separator();
+
+        if rows.is_empty() {
+            ui.vertical_centered(|ui| {
+                ui.add_space(50.0);
+                ui.label(tr("dashboard-empty"));
+            });
+            return refresh;
+        }
+
+        let problems = rows.iter().filter(|r| r.has_problem()).count();
+        if problems > 0 {
+            ui.colored_label(
+                egui::Color32::YELLOW,
+                tr_args("ports-problems", &[("count", problems.into())]),
+            );
+        } else {
+            ui.colored_label(egui::Color32::GREEN, tr("ports-ok"));
+        }
+        ui.add_space(8.0);
+
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                egui::Grid::new("ports_table")
+                    .num_columns(5)
+                    .striped(true)
+                    .spacing([20.0, 6.0])
+                    .show(ui, |ui| {
+                        ui.strong(tr("ports-col-port"));
+                        ui.strong(tr("ports-col-server"));
+                        ui.strong(tr("ports-col-purpose"));
+                        ui.strong(tr("ports-col-state"));
+                        ui.strong(tr("ports-col-issues"));
+                        ui.end_row();
+
+                        for row in rows {
+                            ui.monospace(row.port.to_string());
+                            ui.label(&row.server_name);
+                            ui.label(row.kind.to_string());
+                            if row.server_active {
+                                ui.colored_label(egui::Color32::GREEN, tr("ports-in-use"));
+                            } else {
+                                ui.colored_label(egui::Color32::GRAY, tr("ports-reserved"));
+                            }
+
+                            ui.vertical(|ui| {
+                                if !row.overlaps_with.is_empty() {
+                                    ui.colored_label(
+                                        egui::Color32::RED,
+                                        tr_args(
+                                            "ports-overlaps",
+                                            &[("servers", row.overlaps_with.join(", ").into())],
+                                        ),
+                                    );
+                                }
+                                if let Some(conflict) = &row.host_conflict {
+                                    ui.colored_label(egui::Color32::YELLOW, conflict);
+                                }
+                                if !row.has_problem() {
+                                    ui.label("—");
+                                }
+                            });
+                            ui.end_row();
+                        }
+                    });
+            });
+
+        refresh
+    }
+}
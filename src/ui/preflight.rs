@@ -0,0 +1,70 @@
+use crate::i18n::{tr, tr_args};
+use crate::preflight::{CheckStatus, PreflightReport};
+use eframe::egui;
+
+/// What the user chose on the preflight screen
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PreflightAction {
+    Back,
+    Recheck,
+    StartAnyway,
+}
+
+pub struct PreflightView;
+
+impl PreflightView {
+    pub fn show(ui: &mut egui::Ui, report: &PreflightReport) -> Option<PreflightAction> {
+        let mut action = None;
+
+        ui.heading(tr_args(
+            "preflight-title",
+            &[("server", report.server_name.as_str().into())],
+        ));
+        ui.add_space(5.0);
+        if report.has_failures() {
+            ui.colored_label(egui::Color32::RED, tr("preflight-failed"));
+        } else {
+            ui.colored_label(egui::Color32::YELLOW, tr("preflight-warnings"));
+        }
+        ui.add_space(10.0);
+
+        egui::Grid::new("preflight_grid")
+            .num_columns(3)
+            .spacing([12.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                for check in &report.checks {
+                    let (icon, color) = match check.status {
+                        CheckStatus::Pass => ("✓", egui::Color32::GREEN),
+                        CheckStatus::Warn => ("⚠", egui::Color32::YELLOW),
+                        CheckStatus::Fail => ("✗", egui::Color32::RED),
+                    };
+                    ui.colored_label(color, icon);
+                    ui.strong(&check.name);
+                    ui.label(&check.detail);
+                    ui.end_row();
+                }
+            });
+
+        ui.add_space(20.0);
+        ui.horizontal(|ui| {
+            if ui.button(tr("button-back")).clicked() {
+                action = Some(PreflightAction::Back);
+            }
+            if ui.button(tr("preflight-recheck")).clicked() {
+                action = Some(PreflightAction::Recheck);
+            }
+            if ui
+                .add_enabled(
+                    !report.has_failures(),
+                    egui::Button::new(tr("preflight-start-anyway")),
+                )
+                .clicked()
+            {
+                action = Some(PreflightAction::StartAnyway);
+            }
+        });
+
+        action
+    }
+}
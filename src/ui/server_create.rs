@@ -1,7 +1,18 @@
+use crate::i18n::{tr, tr_args};
+use crate::jvm::JvmPreset;
+use crate::memory::MemoryBudget;
+use crate::preflight::CheckStatus;
+use crate::template_check::TemplateHealth;
 use crate::templates::ModpackTemplate;
 use crate::ui::cf_browse::{CfBrowseWidget, CfCallbacks};
+use crate::ui::ftb_browse::FtbBrowseWidget;
+use crate::ui::local_import::LocalImportWidget;
+use crate::ui::memory::MemoryAdvisor;
 use crate::ui::mr_browse::{MrBrowseWidget, MrCallbacks};
+use crate::ui::unified_search::{UnifiedCallbacks, UnifiedPick, UnifiedSearchWidget};
 use eframe::egui;
+use std::collections::HashMap;
+use std::path::PathBuf;
 
 // ── Types ──────────────────────────────────────────────────────────────────
 
@@ -9,13 +20,36 @@ use eframe::egui;
 pub enum CreateTab {
     #[default]
     Featured,
+    SearchAll,
     SearchCurseForge,
     SearchModrinth,
+    SearchFtb,
+    Local,
+}
+
+/// Sharing and managing templates from the create view
+#[derive(Debug, Clone)]
+pub enum TemplateAction {
+    /// Pick a template file to add
+    ImportFile,
+    /// Download a template file and add it
+    AddFromUrl(String),
+    /// Save the template as a file to share
+    Export(Box<ModpackTemplate>),
+    /// Delete one of the user's own templates, by name
+    Remove(String),
+    /// Confirm every template's pack still downloads
+    CheckHealth,
 }
 
 /// Callbacks from the create view back to app.rs.
 pub struct CreateViewCallbacks<'a> {
     pub on_create: &'a mut dyn FnMut(String, ModpackTemplate, u16, u64),
+    /// Like `on_create`, with the local pack to copy into the new server
+    pub on_import: &'a mut dyn FnMut(String, ModpackTemplate, u16, u64, PathBuf),
+    pub on_template: &'a mut dyn FnMut(TemplateAction),
+    /// Run an FTB search for the query
+    pub on_ftb_search: &'a mut dyn FnMut(String),
     pub on_cancel: &'a mut dyn FnMut(),
 }
 
@@ -26,14 +60,34 @@ pub struct ServerCreateView {
     pub server_name: String,
     pub port: String,
     pub memory_mb: String,
+    /// Host RAM and other servers' allocation, refreshed by app.rs each frame
+    pub memory_budget: MemoryBudget,
+    /// JVM flags preset; None keeps the template's own flags
+    pub jvm_preset: Option<JvmPreset>,
     // Tab
     pub active_tab: CreateTab,
     // Featured
     pub selected_template_idx: Option<usize>,
+    /// Names of the user's own templates (removable), refreshed by app.rs
+    pub user_templates: Vec<String>,
+    /// URL typed into "Add from URL"
+    pub template_url: String,
+    /// A template is being downloaded
+    pub template_url_loading: bool,
+    /// Results of the last template health check, by template name
+    pub template_health: HashMap<String, TemplateHealth>,
+    /// A template health check is running
+    pub template_check_running: bool,
     // CurseForge
     pub cf: CfBrowseWidget,
     // Modrinth
     pub mr: MrBrowseWidget,
+    // FTB
+    pub ftb: FtbBrowseWidget,
+    // Both platforms
+    pub unified: UnifiedSearchWidget,
+    // From this machine
+    pub local: LocalImportWidget,
 }
 
 impl Default for ServerCreateView {
@@ -42,10 +96,20 @@ impl Default for ServerCreateView {
             server_name: String::new(),
             port: "25565".to_string(),
             memory_mb: "4096".to_string(),
+            memory_budget: MemoryBudget::default(),
+            jvm_preset: None,
             active_tab: CreateTab::Featured,
             selected_template_idx: None,
+            user_templates: Vec::new(),
+            template_url: String::new(),
+            template_url_loading: false,
+            template_health: HashMap::new(),
+            template_check_running: false,
             cf: CfBrowseWidget::default(),
             mr: MrBrowseWidget::default(),
+            ftb: FtbBrowseWidget::default(),
+            unified: UnifiedSearchWidget::default(),
+            local: LocalImportWidget::default(),
         }
     }
 }
@@ -57,24 +121,57 @@ impl ServerCreateView {
         templates: &[ModpackTemplate],
         cf_callbacks: &mut CfCallbacks<'_>,
         mr_callbacks: &mut MrCallbacks<'_>,
+        unified_callbacks: &mut UnifiedCallbacks<'_>,
         callbacks: &mut CreateViewCallbacks<'_>,
     ) {
-        ui.heading("Create New Server");
+        ui.heading(tr("create-title"));
         ui.add_space(10.0);
 
+        let selected_java = self
+            .resolve_selected_template(templates)
+            .map(|t| t.java_version);
+
         // ── Common fields ──────────────────────────────────────────────
         egui::Grid::new("create_common_fields")
             .num_columns(6)
             .spacing([10.0, 8.0])
             .show(ui, |ui| {
-                ui.label("Server Name:");
+                ui.label(tr("create-name"));
                 ui.add(egui::TextEdit::singleline(&mut self.server_name).desired_width(300.0));
-                ui.label("Port:");
+                ui.label(tr("create-port"));
                 ui.add(egui::TextEdit::singleline(&mut self.port).desired_width(60.0));
-                ui.label("Memory (MB):");
+                ui.label(tr("create-memory"));
                 ui.add(egui::TextEdit::singleline(&mut self.memory_mb).desired_width(60.0));
                 ui.end_row();
+
+                ui.label(tr("create-jvm-preset"));
+                egui::ComboBox::from_id_salt("create_jvm_preset")
+                    .selected_text(self.jvm_preset.map_or_else(
+                        || tr("create-jvm-template-default"),
+                        |p| p.label().to_string(),
+                    ))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(
+                            &mut self.jvm_preset,
+                            None,
+                            tr("create-jvm-template-default"),
+                        );
+                        for preset in JvmPreset::ALL {
+                            let enabled = selected_java.is_none_or(|j| preset.supports_java(j));
+                            ui.add_enabled_ui(enabled, |ui| {
+                                ui.selectable_value(
+                                    &mut self.jvm_preset,
+                                    Some(preset),
+                                    preset.label(),
+                                )
+                            })
+                            .response
+                            .on_disabled_hover_text(tr("create-jvm-unsupported"));
+                        }
+                    });
+                ui.end_row();
             });
+        MemoryAdvisor::show(ui, &mut self.memory_mb, &self.memory_budget);
 
         ui.add_space(8.0);
         ui.separator();
@@ -83,15 +180,27 @@ impl ServerCreateView {
         // ── Tabs ───────────────────────────────────────────────────────
         ui.horizontal(|ui| {
             if ui
-                .selectable_label(self.active_tab == CreateTab::Featured, "Featured")
+                .selectable_label(
+                    self.active_tab == CreateTab::Featured,
+                    tr("create-tab-featured"),
+                )
                 .clicked()
             {
                 self.active_tab = CreateTab::Featured;
             }
+            if ui
+                .selectable_label(
+                    self.active_tab == CreateTab::SearchAll,
+                    tr("create-tab-search-all"),
+                )
+                .clicked()
+            {
+                self.active_tab = CreateTab::SearchAll;
+            }
             if ui
                 .selectable_label(
                     self.active_tab == CreateTab::SearchCurseForge,
-                    "Search CurseForge",
+                    tr_args("create-tab-search", &[("site", "CurseForge".into())]),
                 )
                 .clicked()
             {
@@ -100,12 +209,27 @@ impl ServerCreateView {
             if ui
                 .selectable_label(
                     self.active_tab == CreateTab::SearchModrinth,
-                    "Search Modrinth",
+                    tr_args("create-tab-search", &[("site", "Modrinth".into())]),
                 )
                 .clicked()
             {
                 self.active_tab = CreateTab::SearchModrinth;
             }
+            if ui
+                .selectable_label(
+                    self.active_tab == CreateTab::SearchFtb,
+                    tr_args("create-tab-search", &[("site", "FTB".into())]),
+                )
+                .clicked()
+            {
+                self.active_tab = CreateTab::SearchFtb;
+            }
+            if ui
+                .selectable_label(self.active_tab == CreateTab::Local, tr("create-tab-local"))
+                .clicked()
+            {
+                self.active_tab = CreateTab::Local;
+            }
         });
         ui.separator();
 
@@ -120,7 +244,7 @@ impl ServerCreateView {
 
             if let Some(t) = &selected_template {
                 ui.horizontal(|ui| {
-                    ui.strong("Selected:");
+                    ui.strong(tr("create-selected"));
                     ui.label(format!(
                         "{} (MC {}, {:?}, Java {})",
                         t.name, t.minecraft_version, t.loader, t.java_version
@@ -130,7 +254,7 @@ impl ServerCreateView {
 
             ui.add_space(4.0);
             ui.horizontal(|ui| {
-                if ui.button("Cancel").clicked() {
+                if ui.button(tr("button-cancel")).clicked() {
                     should_cancel = true;
                 }
 
@@ -142,11 +266,29 @@ impl ServerCreateView {
                     && selected_template.is_some();
 
                 if ui
-                    .add_enabled(can_create, egui::Button::new("Create Server"))
+                    .add_enabled(can_create, egui::Button::new(tr("create-button")))
                     .clicked()
                 {
                     should_create = true;
                 }
+
+                ui.add_space(20.0);
+                if let Some(t) = &selected_template {
+                    if ui
+                        .button(tr("create-export-template"))
+                        .on_hover_text(tr("create-export-template-hint"))
+                        .clicked()
+                    {
+                        (callbacks.on_template)(TemplateAction::Export(Box::new(t.clone())));
+                    }
+                    if self.active_tab == CreateTab::Featured
+                        && self.user_templates.contains(&t.name)
+                        && ui.button(tr("create-remove-template")).clicked()
+                    {
+                        (callbacks.on_template)(TemplateAction::Remove(t.name.clone()));
+                        self.selected_template_idx = None;
+                    }
+                }
             });
             ui.add_space(4.0);
         });
@@ -154,7 +296,28 @@ impl ServerCreateView {
         // ── Tab content (fills remaining space) ─────────────────────
         match self.active_tab {
             CreateTab::Featured => {
-                self.show_featured_tab(ui, templates);
+                self.show_featured_tab(ui, templates, callbacks);
+            }
+            CreateTab::SearchAll => {
+                // Picking a platform hands the pack to that platform's tab for version selection
+                let query = self.unified.search.query.clone();
+                match self.unified.show(ui, "create_all", unified_callbacks) {
+                    Some(UnifiedPick::CurseForge(cf_mod)) => {
+                        let mod_id = cf_mod.id;
+                        self.cf.preselect(&query, cf_mod);
+                        self.active_tab = CreateTab::SearchCurseForge;
+                        (cf_callbacks.on_fetch_versions)(mod_id);
+                        (cf_callbacks.on_fetch_description)(mod_id);
+                    }
+                    Some(UnifiedPick::Modrinth(project)) => {
+                        let slug = project.slug.clone();
+                        self.mr.preselect(&query, project);
+                        self.active_tab = CreateTab::SearchModrinth;
+                        (mr_callbacks.on_fetch_versions)(slug.clone());
+                        (mr_callbacks.on_fetch_description)(slug);
+                    }
+                    None => {}
+                }
             }
             CreateTab::SearchCurseForge => {
                 if self.cf.show(ui, "create_cf", cf_callbacks) {
@@ -171,6 +334,20 @@ impl ServerCreateView {
                     }
                 }
             }
+            CreateTab::SearchFtb => {
+                if self.ftb.show(ui, "create_ftb", callbacks.on_ftb_search) {
+                    if let Some(t) = &self.ftb.template {
+                        self.memory_mb = t.recommended_memory_mb.to_string();
+                    }
+                }
+            }
+            CreateTab::Local => {
+                if self.local.show(ui) {
+                    if let Some(t) = self.local.template() {
+                        self.memory_mb = t.recommended_memory_mb.to_string();
+                    }
+                }
+            }
         }
 
         // ── Act on bottom bar clicks ────────────────────────────────
@@ -178,17 +355,84 @@ impl ServerCreateView {
             (callbacks.on_cancel)();
         }
         if should_create {
-            if let Some(template) = create_template {
+            if let Some(mut template) = create_template {
                 let port = self.port.parse().unwrap_or(25565);
                 let memory = self.memory_mb.parse().unwrap_or(4096);
-                (callbacks.on_create)(self.server_name.clone(), template, port, memory);
+                if let Some(preset) = self
+                    .jvm_preset
+                    .filter(|p| p.supports_java(template.java_version))
+                {
+                    template.default_java_args = preset.args(template.java_version, memory);
+                }
+                match self
+                    .local
+                    .source_path()
+                    .filter(|_| self.active_tab == CreateTab::Local)
+                {
+                    Some(path) => (callbacks.on_import)(
+                        self.server_name.clone(),
+                        template,
+                        port,
+                        memory,
+                        path.to_path_buf(),
+                    ),
+                    None => (callbacks.on_create)(self.server_name.clone(), template, port, memory),
+                }
             }
         }
     }
 
     // ── Featured tab ───────────────────────────────────────────────────
 
-    fn show_featured_tab(&mut self, ui: &mut egui::Ui, templates: &[ModpackTemplate]) {
+    fn show_featured_tab(
+        &mut self,
+        ui: &mut egui::Ui,
+        templates: &[ModpackTemplate],
+        callbacks: &mut CreateViewCallbacks<'_>,
+    ) {
+        ui.horizontal(|ui| {
+            if ui
+                .button(tr("create-import-template"))
+                .on_hover_text(tr("create-import-template-hint"))
+                .clicked()
+            {
+                (callbacks.on_template)(TemplateAction::ImportFile);
+            }
+            ui.separator();
+            ui.add(
+                egui::TextEdit::singleline(&mut self.template_url)
+                    .hint_text("https://.../template.json")
+                    .desired_width(300.0),
+            );
+            let can_add = !self.template_url_loading && !self.template_url.trim().is_empty();
+            if ui
+                .add_enabled(can_add, egui::Button::new(tr("create-add-from-url")))
+                .clicked()
+            {
+                (callbacks.on_template)(TemplateAction::AddFromUrl(
+                    self.template_url.trim().to_string(),
+                ));
+            }
+            if self.template_url_loading {
+                ui.spinner();
+            }
+            ui.separator();
+            if ui
+                .add_enabled(
+                    !self.template_check_running,
+                    egui::Button::new(tr("create-check-templates")),
+                )
+                .on_hover_text(tr("create-check-templates-hint"))
+                .clicked()
+            {
+                (callbacks.on_template)(TemplateAction::CheckHealth);
+            }
+            if self.template_check_running {
+                ui.spinner();
+            }
+        });
+        ui.add_space(4.0);
+
         egui::ScrollArea::vertical()
             .auto_shrink([false, false])
             .max_height(ui.available_height())
@@ -208,7 +452,17 @@ impl ServerCreateView {
                         .show(ui, |ui| {
                             ui.horizontal(|ui| {
                                 ui.vertical(|ui| {
-                                    ui.strong(&template.name);
+                                    ui.horizontal(|ui| {
+                                        ui.strong(&template.name);
+                                        if self.user_templates.contains(&template.name) {
+                                            ui.small(tr("create-yours"));
+                                        }
+                                        if let Some(health) =
+                                            self.template_health.get(&template.name)
+                                        {
+                                            show_health(ui, health);
+                                        }
+                                    });
                                     ui.label(&template.description);
                                     ui.horizontal(|ui| {
                                         ui.small(format!("MC {}", template.minecraft_version));
@@ -228,6 +482,7 @@ impl ServerCreateView {
                         self.selected_template_idx = Some(idx);
                         self.cf.template = None; // Clear CF selection
                         self.mr.template = None; // Clear MR selection
+                        self.ftb.template = None; // Clear FTB selection
                         self.memory_mb = template.recommended_memory_mb.to_string();
                     }
 
@@ -236,19 +491,48 @@ impl ServerCreateView {
             });
     }
 
-    /// Determine the currently-selected template (Featured, CF, or Modrinth).
+    /// Determine the currently-selected template (Featured, CF, Modrinth, or local).
+    /// The combined search tab never holds a template itself.
     fn resolve_selected_template(&self, templates: &[ModpackTemplate]) -> Option<ModpackTemplate> {
         match self.active_tab {
             CreateTab::Featured => self
                 .selected_template_idx
                 .and_then(|idx| templates.get(idx))
                 .cloned(),
+            CreateTab::SearchAll => None,
             CreateTab::SearchCurseForge => self.cf.template.clone(),
             CreateTab::SearchModrinth => self.mr.template.clone(),
+            CreateTab::SearchFtb => self.ftb.template.clone(),
+            CreateTab::Local => self.local.template(),
         }
     }
 
+    /// Switch to the Local tab with a pack already chosen, e.g. one dropped onto the window
+    pub fn open_local_pack(&mut self, path: std::path::PathBuf) {
+        self.active_tab = CreateTab::Local;
+        self.local.pick(path);
+        if let Some(t) = self.local.template() {
+            self.memory_mb = t.recommended_memory_mb.to_string();
+        }
+    }
+
+    /// Clear the form; user templates and health check results stay
     pub fn reset(&mut self) {
-        *self = Self::default();
+        *self = Self {
+            user_templates: std::mem::take(&mut self.user_templates),
+            template_health: std::mem::take(&mut self.template_health),
+            template_check_running: self.template_check_running,
+            ..Self::default()
+        };
     }
 }
+
+/// Flag a template whose pack no longer resolves; passing ones stay quiet
+fn show_health(ui: &mut egui::Ui, health: &TemplateHealth) {
+    let (color, label) = match health.status {
+        CheckStatus::Pass => return,
+        CheckStatus::Warn => (egui::Color32::YELLOW, tr("create-template-warning")),
+        CheckStatus::Fail => (egui::Color32::RED, tr("create-template-broken")),
+    };
+    ui.colored_label(color, label).on_hover_text(&health.detail);
+}
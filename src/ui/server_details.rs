@@ -0,0 +1,219 @@
+use super::dashboard::status_style;
+use crate::backup::format_bytes;
+use crate::disk_usage::ServerUsage;
+use crate::i18n::{tr, tr_args};
+use crate::playtime::format_duration;
+use crate::server::{LiveStatus, ServerInstance, ServerStatus};
+use crate::startup;
+use crate::uptime::UptimeLog;
+use eframe::egui;
+
+/// Span of the reliability summary on the Overview tab
+const RELIABILITY_WINDOW: std::time::Duration = std::time::Duration::from_secs(7 * 24 * 3600);
+
+/// Tabs of the server details page, each server's home for everything about it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DetailsTab {
+    #[default]
+    Overview,
+    Console,
+    Logs,
+    Players,
+    Whitelist,
+    Mods,
+    Files,
+    Backups,
+    Schedules,
+    Settings,
+}
+
+impl DetailsTab {
+    const ALL: [DetailsTab; 10] = [
+        DetailsTab::Overview,
+        DetailsTab::Console,
+        DetailsTab::Logs,
+        DetailsTab::Players,
+        DetailsTab::Whitelist,
+        DetailsTab::Mods,
+        DetailsTab::Files,
+        DetailsTab::Backups,
+        DetailsTab::Schedules,
+        DetailsTab::Settings,
+    ];
+
+    /// Message ID of the tab's label
+    fn label(self) -> &'static str {
+        match self {
+            DetailsTab::Overview => "details-tab-overview",
+            DetailsTab::Console => "details-tab-console",
+            DetailsTab::Logs => "details-tab-logs",
+            DetailsTab::Players => "details-tab-players",
+            DetailsTab::Whitelist => "details-tab-whitelist",
+            DetailsTab::Mods => "details-tab-mods",
+            DetailsTab::Files => "details-tab-files",
+            DetailsTab::Backups => "details-tab-backups",
+            DetailsTab::Schedules => "details-tab-schedules",
+            DetailsTab::Settings => "details-tab-settings",
+        }
+    }
+}
+
+pub struct ServerDetailsView;
+
+impl ServerDetailsView {
+    /// Heading and tab bar. Returns true when Back is clicked.
+    pub fn header(ui: &mut egui::Ui, server_name: &str, tab: &mut DetailsTab) -> bool {
+        let mut back = false;
+        ui.horizontal(|ui| {
+            ui.heading(tr_args("details-title", &[("server", server_name.into())]));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button(tr("button-back")).clicked() {
+                    back = true;
+                }
+            });
+        });
+        ui.horizontal(|ui| {
+            for t in DetailsTab::ALL {
+                ui.selectable_value(tab, t, tr(t.label()));
+            }
+        });
+        ui.separator();
+        back
+    }
+
+    /// Returns true when the crash reports button is clicked
+    pub fn overview(
+        ui: &mut egui::Ui,
+        server: &ServerInstance,
+        usage: Option<&ServerUsage>,
+        live: Option<&LiveStatus>,
+        uptime: &UptimeLog,
+        crash_reports: usize,
+    ) -> bool {
+        let config = &server.config;
+        let status = match &server.status {
+            ServerStatus::Error(e) => tr_args("dashboard-error", &[("error", e.as_str().into())]),
+            other => tr(status_style(other).1),
+        };
+        egui::Grid::new("server_overview_grid")
+            .num_columns(2)
+            .spacing([20.0, 6.0])
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(tr("details-status"));
+                ui.label(status);
+                ui.end_row();
+                ui.label(tr("details-modpack"));
+                ui.label(format!(
+                    "{} {}",
+                    config.modpack.name, config.modpack.version
+                ));
+                ui.end_row();
+                ui.label(tr("details-minecraft"));
+                ui.label(format!(
+                    "{} ({:?})",
+                    config.modpack.minecraft_version, config.modpack.loader
+                ));
+                ui.end_row();
+                ui.label(tr("details-port"));
+                ui.label(config.port.to_string());
+                ui.end_row();
+                ui.label(tr("details-rcon-port"));
+                ui.label(config.rcon_port().to_string());
+                ui.end_row();
+                ui.label(tr("details-memory"));
+                ui.label(tr_args(
+                    "details-memory-mb",
+                    &[("mb", config.memory_mb.into())],
+                ));
+                ui.end_row();
+                ui.label(tr("details-java"));
+                ui.label(config.java_version.to_string());
+                ui.end_row();
+                if let Some(usual) = startup::typical_startup(&config.startup_history) {
+                    ui.label(tr("details-startup"));
+                    let secs = usual.as_secs();
+                    ui.label(tr_args(
+                        "details-startup-usual",
+                        &[
+                            ("minutes", (secs / 60).into()),
+                            ("seconds", (secs % 60).into()),
+                            ("count", config.startup_history.len().into()),
+                        ],
+                    ));
+                    ui.end_row();
+                }
+                if !uptime.sessions.is_empty() {
+                    let now = chrono::Utc::now().timestamp();
+                    let week = uptime.summary(now, RELIABILITY_WINDOW);
+                    ui.label(tr("details-last-week"));
+                    ui.label(tr_args(
+                        "details-last-week-summary",
+                        &[
+                            (
+                                "percent",
+                                format!("{:.1}", week.availability * 100.0).into(),
+                            ),
+                            ("starts", week.starts.into()),
+                            ("crashes", week.crashes.into()),
+                            ("longest", format_duration(week.longest_secs).into()),
+                        ],
+                    ))
+                    .on_hover_text(tr_args(
+                        "details-last-week-hint",
+                        &[("up", format_duration(week.up_secs).into())],
+                    ));
+                    ui.end_row();
+                }
+                if let Some(usage) = usage {
+                    ui.label(tr("details-disk"));
+                    ui.label(tr_args(
+                        "details-disk-usage",
+                        &[
+                            ("data", format_bytes(usage.data_bytes).into()),
+                            ("backups", format_bytes(usage.backup_bytes).into()),
+                        ],
+                    ));
+                    ui.end_row();
+                }
+                if let Some(query) = live.and_then(|l| l.query.as_ref()) {
+                    ui.label(tr("details-online"));
+                    if query.players.is_empty() {
+                        ui.label(tr("details-nobody"));
+                    } else {
+                        ui.label(query.players.join(", "));
+                    }
+                    ui.end_row();
+                    if let Some(server_mod) = &query.server_mod {
+                        ui.label(tr("details-software"));
+                        ui.label(server_mod);
+                        ui.end_row();
+                    }
+                    if !query.plugins.is_empty() {
+                        ui.label(tr_args(
+                            "details-plugins",
+                            &[("count", query.plugins.len().into())],
+                        ));
+                        ui.label(query.plugins.join(", "));
+                        ui.end_row();
+                    }
+                }
+                ui.label(tr("details-container"));
+                ui.monospace(server.container_id.as_deref().map_or_else(
+                    || tr("details-no-container"),
+                    |id| id[..id.len().min(12)].to_string(),
+                ));
+                ui.end_row();
+            });
+
+        if crash_reports == 0 {
+            return false;
+        }
+        ui.add_space(12.0);
+        ui.button(tr_args(
+            "details-crash-reports",
+            &[("count", crash_reports.into())],
+        ))
+        .clicked()
+    }
+}
@@ -1,10 +1,24 @@
+use crate::backup;
+use crate::config::BackupCompression;
+use crate::curseforge;
+use crate::docker::CONTAINER_PORTS;
+use crate::i18n::{tr, tr_args};
+use crate::jvm::{self, GarbageCollector, GcBenchmark, JvmPreset};
+use crate::memory::MemoryBudget;
+use crate::pack_installer;
 use crate::server::{
-    Difficulty, GameMode, ModLoader, ModpackInfo, ModpackSource, ServerConfig, ServerProperties,
+    BackupFrequency, BackupSchedule, Difficulty, GameMode, ModLoader, ModpackInfo, ModpackSource,
+    ScheduledBroadcast, ServerConfig, ServerProperties,
 };
 use crate::templates::ModpackTemplate;
+use crate::ui::backup_compression::compression_picker;
 use crate::ui::cf_browse::{CfBrowseWidget, CfCallbacks};
+use crate::ui::memory::MemoryAdvisor;
+use crate::ui::motd_editor;
 use crate::ui::mr_browse::{MrBrowseWidget, MrCallbacks};
 use eframe::egui;
+use std::collections::HashSet;
+use std::net::IpAddr;
 
 pub struct ServerEditResult {
     pub port: u16,
@@ -14,13 +28,35 @@ pub struct ServerEditResult {
     pub modpack: ModpackInfo,
     pub java_version: u8,
     pub extra_env: Vec<String>,
+    pub world_url: Option<String>,
+    pub extra_ports: Vec<u16>,
+    pub bind_address: Option<IpAddr>,
+    pub gc_logging: bool,
+    pub backup_group: Option<String>,
+    pub backup_excludes: Vec<String>,
+}
+
+/// Timers from the Schedules tab; they apply without recreating the container
+pub struct ScheduleResult {
+    pub broadcasts: Vec<ScheduledBroadcast>,
+    pub backup_schedule: BackupSchedule,
+    pub remote_backup: bool,
 }
 
 pub struct ServerEditView {
     pub server_name: String,
     pub port: String,
     pub memory_mb: String,
+    /// Host RAM and other servers' allocation, refreshed by app.rs each frame
+    pub memory_budget: MemoryBudget,
+    /// Extra published ports, comma-separated
+    pub extra_ports: String,
+    /// Host IP to publish ports on, empty for all interfaces
+    pub bind_address: String,
     pub java_args: String,
+    pub gc_logging: bool,
+    /// Recorded pause stats, shown read-only for comparing collectors
+    pub gc_benchmarks: Vec<GcBenchmark>,
     // Server properties
     pub motd: String,
     pub max_players: String,
@@ -29,6 +65,16 @@ pub struct ServerEditView {
     pub pvp: bool,
     pub online_mode: bool,
     pub white_list: bool,
+    pub view_distance: String,
+    // Scheduled announcements
+    pub broadcasts: Vec<ScheduledBroadcast>,
+    /// Coordinated backup group name, empty for none
+    pub backup_group: String,
+    pub backup_schedule: BackupSchedule,
+    /// Upload backups to the remote destination from Settings
+    pub remote_backup: bool,
+    /// Backup exclude globs, one per line
+    pub backup_excludes: String,
     // Modpack info
     pub modpack_name: String,
     pub modpack_version: String,
@@ -38,13 +84,20 @@ pub struct ServerEditView {
     // Java version & extra env
     pub java_version: String,
     pub extra_env: String,
+    /// Starting world archive URL, empty for a generated world
+    pub world_url: String,
     // Template picker
     pub selected_template_idx: Option<usize>,
     // CurseForge browse
     pub cf: CfBrowseWidget,
     // Modrinth browse
     pub mr: MrBrowseWidget,
+    /// Path or URL typed into the .mrpack section
+    pub mrpack_input: String,
+    pub mrpack_error: Option<String>,
     pub dirty: bool,
+    /// Unsaved changes on the Schedules tab
+    pub schedules_dirty: bool,
 }
 
 impl Default for ServerEditView {
@@ -54,7 +107,12 @@ impl Default for ServerEditView {
             server_name: String::new(),
             port: "25565".to_string(),
             memory_mb: "4096".to_string(),
+            memory_budget: MemoryBudget::default(),
+            extra_ports: String::new(),
+            bind_address: String::new(),
             java_args: String::new(),
+            gc_logging: false,
+            gc_benchmarks: Vec::new(),
             motd: defaults.motd,
             max_players: defaults.max_players.to_string(),
             difficulty: defaults.difficulty,
@@ -62,6 +120,12 @@ impl Default for ServerEditView {
             pvp: defaults.pvp,
             online_mode: defaults.online_mode,
             white_list: defaults.white_list,
+            view_distance: defaults.view_distance.to_string(),
+            broadcasts: Vec::new(),
+            backup_group: String::new(),
+            backup_schedule: BackupSchedule::default(),
+            remote_backup: false,
+            backup_excludes: String::new(),
             modpack_name: String::new(),
             modpack_version: String::new(),
             minecraft_version: String::new(),
@@ -71,10 +135,14 @@ impl Default for ServerEditView {
             },
             java_version: "21".to_string(),
             extra_env: String::new(),
+            world_url: String::new(),
             selected_template_idx: None,
             cf: CfBrowseWidget::default(),
             mr: MrBrowseWidget::default(),
+            mrpack_input: String::new(),
+            mrpack_error: None,
             dirty: false,
+            schedules_dirty: false,
         }
     }
 }
@@ -86,15 +154,25 @@ impl ServerEditView {
         self.server_name = config.name.clone();
         self.port = config.port.to_string();
         self.memory_mb = config.memory_mb.to_string();
+        self.extra_ports = config
+            .extra_ports
+            .iter()
+            .map(|p| p.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.bind_address = config
+            .bind_address
+            .map(|ip| ip.to_string())
+            .unwrap_or_default();
         self.java_args = config.java_args.join("\n");
-        let sp = &config.server_properties;
-        self.motd = sp.motd.clone();
-        self.max_players = sp.max_players.to_string();
-        self.difficulty = sp.difficulty.clone();
-        self.gamemode = sp.gamemode.clone();
-        self.pvp = sp.pvp;
-        self.online_mode = sp.online_mode;
-        self.white_list = sp.white_list;
+        self.gc_logging = config.gc_logging;
+        self.gc_benchmarks = config.gc_benchmarks.clone();
+        self.load_properties(&config.server_properties);
+        self.broadcasts = config.broadcasts.clone();
+        self.backup_group = config.backup_group.clone().unwrap_or_default();
+        self.backup_schedule = config.backup_schedule;
+        self.remote_backup = config.remote_backup;
+        self.backup_excludes = config.backup_excludes.join("\n");
         // Modpack
         self.modpack_name = config.modpack.name.clone();
         self.modpack_version = config.modpack.version.clone();
@@ -104,10 +182,17 @@ impl ServerEditView {
         // Java version & extra env
         self.java_version = config.java_version.to_string();
         self.extra_env = config.extra_env.join("\n");
+        self.world_url = config.world_url.clone().unwrap_or_default();
         self.selected_template_idx = None;
         self.cf.reset();
         self.mr.reset();
+        self.mrpack_input = match &config.modpack.source {
+            ModpackSource::MrPackFile { path_or_url } => path_or_url.clone(),
+            _ => String::new(),
+        };
+        self.mrpack_error = None;
         self.dirty = false;
+        self.schedules_dirty = false;
     }
 
     pub fn show(
@@ -117,225 +202,297 @@ impl ServerEditView {
         cf_callbacks: &mut CfCallbacks<'_>,
         mr_callbacks: &mut MrCallbacks<'_>,
         on_save: &mut impl FnMut(ServerEditResult),
-        on_cancel: &mut impl FnMut(),
+        on_revert: &mut impl FnMut(),
     ) {
-        ui.heading(format!("Edit Server: {}", self.server_name));
-        ui.add_space(20.0);
+        ui.add_space(10.0);
 
         egui::ScrollArea::vertical()
             .auto_shrink([false, false])
             .show(ui, |ui| {
+                // ── Modpack section ──────────────────────────────────────
+                egui::CollapsingHeader::new(tr("edit-modpack"))
+                    .default_open(true)
+                    .show(ui, |ui| {
+                        ui.label(tr_args(
+                            "edit-current",
+                            &[
+                                ("name", self.modpack_name.clone().into()),
+                                ("version", self.modpack_version.clone().into()),
+                                ("mc", self.minecraft_version.clone().into()),
+                                ("loader", format!("{:?}", self.loader).into()),
+                            ],
+                        ));
+                        ui.add_space(5.0);
+                        ui.label(tr_args(
+                            "edit-source",
+                            &[("source", format_source(&self.source).into())],
+                        ));
+                        if let ModpackSource::CurseForge { slug, file_id }
+                        | ModpackSource::CurseForgeManifest { slug, file_id } = &self.source
+                        {
+                            let mut on_host =
+                                matches!(self.source, ModpackSource::CurseForgeManifest { .. });
+                            let toggled = ui
+                                .checkbox(&mut on_host, tr("edit-install-on-host"))
+                                .on_hover_text(tr("edit-install-on-host-hint"))
+                                .changed();
+                            if toggled {
+                                let (slug, file_id) = (slug.clone(), *file_id);
+                                self.source = if on_host {
+                                    ModpackSource::CurseForgeManifest { slug, file_id }
+                                } else {
+                                    ModpackSource::CurseForge { slug, file_id }
+                                };
+                                self.dirty = true;
+                            }
+                        }
+                        ui.add_space(10.0);
 
-        // ── Modpack section ──────────────────────────────────────
-        egui::CollapsingHeader::new("Modpack")
-            .default_open(true)
-            .show(ui, |ui| {
-                ui.label(format!(
-                    "Current: {} v{} (MC {}, {:?})",
-                    self.modpack_name, self.modpack_version, self.minecraft_version, self.loader
-                ));
-                ui.add_space(5.0);
-                ui.label(format!("Source: {}", format_source(&self.source)));
-                ui.add_space(10.0);
+                        // Template picker
+                        ui.horizontal(|ui| {
+                            ui.label(tr("edit-apply-builtin"));
+                            let selected_label = match self.selected_template_idx {
+                                Some(idx) => templates
+                                    .get(idx)
+                                    .map_or_else(|| "—".into(), |t| t.name.clone()),
+                                None => tr("edit-select-template"),
+                            };
+                            egui::ComboBox::from_id_salt("template_picker")
+                                .selected_text(selected_label)
+                                .show_ui(ui, |ui| {
+                                    for (i, t) in templates.iter().enumerate() {
+                                        if ui
+                                            .selectable_value(
+                                                &mut self.selected_template_idx,
+                                                Some(i),
+                                                &t.name,
+                                            )
+                                            .on_hover_text(&t.description)
+                                            .changed()
+                                        {
+                                            // selection changed — don't apply yet
+                                        }
+                                    }
+                                });
 
-                // Template picker
-                ui.horizontal(|ui| {
-                    ui.label("Apply builtin template:");
-                    let selected_label = match self.selected_template_idx {
-                        Some(idx) => templates.get(idx).map_or("—", |t| t.name.as_str()),
-                        None => "— select —",
-                    };
-                    egui::ComboBox::from_id_salt("template_picker")
-                        .selected_text(selected_label)
-                        .show_ui(ui, |ui| {
-                            for (i, t) in templates.iter().enumerate() {
-                                if ui
-                                    .selectable_value(
-                                        &mut self.selected_template_idx,
-                                        Some(i),
-                                        &t.name,
-                                    )
-                                    .on_hover_text(&t.description)
-                                    .changed()
+                            if self.selected_template_idx.is_some()
+                                && ui.button(tr("edit-apply-template")).clicked()
+                            {
+                                if let Some(t) =
+                                    self.selected_template_idx.and_then(|i| templates.get(i))
                                 {
-                                    // selection changed — don't apply yet
+                                    self.apply_template(t);
                                 }
                             }
                         });
 
-                    if self.selected_template_idx.is_some()
-                        && ui.button("Apply Template").clicked()
-                    {
-                        if let Some(t) = self
-                            .selected_template_idx
-                            .and_then(|i| templates.get(i))
-                        {
-                            self.apply_template(t);
-                        }
-                    }
-                });
+                        ui.add_space(10.0);
+
+                        // ── CurseForge search section ────────────────────
+                        egui::CollapsingHeader::new(tr("edit-search-curseforge"))
+                            .default_open(false)
+                            .show(ui, |ui| {
+                                self.cf.show(ui, "edit_cf", cf_callbacks);
+
+                                ui.add_space(8.0);
+                                let has_cf_template = self.cf.template.is_some();
+                                if ui
+                                    .add_enabled(
+                                        has_cf_template,
+                                        egui::Button::new(tr("edit-apply-curseforge")),
+                                    )
+                                    .clicked()
+                                {
+                                    if let Some(t) = &self.cf.template.clone() {
+                                        self.apply_template(t);
+                                    }
+                                }
+                            });
+
+                        // ── Modrinth search section ──────────────────────
+                        egui::CollapsingHeader::new(tr("edit-search-modrinth"))
+                            .default_open(false)
+                            .show(ui, |ui| {
+                                self.mr.show(ui, "edit_mr", mr_callbacks);
+
+                                ui.add_space(8.0);
+                                let has_mr_template = self.mr.template.is_some();
+                                if ui
+                                    .add_enabled(
+                                        has_mr_template,
+                                        egui::Button::new(tr("edit-apply-modrinth")),
+                                    )
+                                    .clicked()
+                                {
+                                    if let Some(t) = &self.mr.template.clone() {
+                                        self.apply_template(t);
+                                    }
+                                }
+                            });
+
+                        // ── .mrpack section ──────────────────────────────
+                        egui::CollapsingHeader::new(tr("edit-install-mrpack"))
+                            .default_open(matches!(self.source, ModpackSource::MrPackFile { .. }))
+                            .show(ui, |ui| self.show_mrpack(ui));
+                    });
 
                 ui.add_space(10.0);
 
-                // ── CurseForge search section ────────────────────
-                egui::CollapsingHeader::new("Search CurseForge")
-                    .default_open(false)
+                // ── Port / Memory grid ───────────────────────────────────
+                egui::Grid::new("edit_server_grid")
+                    .num_columns(2)
+                    .spacing([20.0, 10.0])
                     .show(ui, |ui| {
-                        self.cf.show(ui, "edit_cf", cf_callbacks);
+                        ui.label(tr("create-port"));
+                        if ui.text_edit_singleline(&mut self.port).changed() {
+                            self.dirty = true;
+                        }
+                        ui.end_row();
 
-                        ui.add_space(8.0);
-                        let has_cf_template = self.cf.template.is_some();
+                        ui.label(tr("create-memory"));
                         if ui
-                            .add_enabled(
-                                has_cf_template,
-                                egui::Button::new("Apply CurseForge Pack"),
+                            .add(
+                                egui::TextEdit::singleline(&mut self.memory_mb).desired_width(80.0),
                             )
-                            .clicked()
+                            .changed()
                         {
-                            if let Some(t) = &self.cf.template.clone() {
-                                self.apply_template(t);
-                            }
+                            self.dirty = true;
                         }
-                    });
+                        ui.end_row();
 
-                // ── Modrinth search section ──────────────────────
-                egui::CollapsingHeader::new("Search Modrinth")
-                    .default_open(false)
-                    .show(ui, |ui| {
-                        self.mr.show(ui, "edit_mr", mr_callbacks);
+                        ui.label(tr("edit-extra-ports"));
+                        if ui
+                            .add(
+                                egui::TextEdit::singleline(&mut self.extra_ports)
+                                    .desired_width(200.0)
+                                    .hint_text(tr("edit-extra-ports-example")),
+                            )
+                            .changed()
+                        {
+                            self.dirty = true;
+                        }
+                        ui.end_row();
 
-                        ui.add_space(8.0);
-                        let has_mr_template = self.mr.template.is_some();
+                        ui.label(tr("edit-bind-address"));
                         if ui
-                            .add_enabled(
-                                has_mr_template,
-                                egui::Button::new("Apply Modrinth Pack"),
+                            .add(
+                                egui::TextEdit::singleline(&mut self.bind_address)
+                                    .desired_width(200.0)
+                                    .hint_text(tr("edit-bind-address-all")),
                             )
-                            .clicked()
+                            .changed()
                         {
-                            if let Some(t) = &self.mr.template.clone() {
-                                self.apply_template(t);
-                            }
+                            self.dirty = true;
                         }
+                        ui.end_row();
                     });
-            });
-
-        ui.add_space(10.0);
-
-        // ── Port / Memory grid ───────────────────────────────────
-        egui::Grid::new("edit_server_grid")
-            .num_columns(2)
-            .spacing([20.0, 10.0])
-            .show(ui, |ui| {
-                ui.label("Port:");
-                if ui.text_edit_singleline(&mut self.port).changed() {
+                ui.small(tr("edit-extra-ports-hint"));
+                ui.small(tr("edit-bind-address-hint"));
+                ui.add_space(5.0);
+                if MemoryAdvisor::show(ui, &mut self.memory_mb, &self.memory_budget) {
                     self.dirty = true;
                 }
-                ui.end_row();
 
-                ui.label("Memory (MB):");
-                if ui
-                    .add(egui::TextEdit::singleline(&mut self.memory_mb).desired_width(80.0))
-                    .changed()
-                {
-                    self.dirty = true;
-                }
-                ui.end_row();
-            });
-
-        ui.add_space(20.0);
-        ui.label("Java Options (one per line):");
-        ui.add_space(5.0);
-
-        let text_edit = egui::TextEdit::multiline(&mut self.java_args)
-            .desired_width(f32::INFINITY)
-            .desired_rows(6)
-            .font(egui::TextStyle::Monospace);
-
-        if ui.add(text_edit).changed() {
-            self.dirty = true;
-        }
-
-        ui.add_space(10.0);
-        ui.small("Common options: -XX:+UseG1GC, -XX:MaxGCPauseMillis=200, etc.");
+                ui.add_space(20.0);
+                ui.horizontal(|ui| {
+                    ui.label(tr("create-jvm-preset"));
+                    let java_version = self.java_version.parse::<u8>().unwrap_or(21);
+                    let memory_mb = self.memory_mb.parse::<u64>().unwrap_or(4096);
+                    let current_args: Vec<String> = self
+                        .java_args
+                        .lines()
+                        .map(|l| l.trim().to_string())
+                        .filter(|l| !l.is_empty())
+                        .collect();
+                    let current = JvmPreset::detect(&current_args, java_version, memory_mb);
+                    egui::ComboBox::from_id_salt("edit_jvm_preset")
+                        .selected_text(
+                            current
+                                .map_or_else(|| tr("edit-jvm-custom"), |p| p.label().to_string()),
+                        )
+                        .show_ui(ui, |ui| {
+                            for preset in JvmPreset::ALL {
+                                let enabled = preset.supports_java(java_version);
+                                let resp = ui
+                                    .add_enabled(
+                                        enabled,
+                                        egui::SelectableLabel::new(
+                                            current == Some(preset),
+                                            preset.label(),
+                                        ),
+                                    )
+                                    .on_disabled_hover_text(tr("edit-jvm-unsupported"));
+                                if resp.clicked() {
+                                    self.java_args =
+                                        preset.args(java_version, memory_mb).join("\n");
+                                    self.dirty = true;
+                                }
+                            }
+                        });
+                    ui.small(tr("edit-jvm-preset-hint"));
+                });
 
-        ui.add_space(20.0);
+                ui.add_space(10.0);
+                ui.label(tr("edit-java-options"));
+                ui.add_space(5.0);
 
-        // ── Java Version & Extra Env ─────────────────────────────
-        egui::Grid::new("java_env_grid")
-            .num_columns(2)
-            .spacing([20.0, 10.0])
-            .show(ui, |ui| {
-                ui.label("Java Version:");
-                egui::ComboBox::from_id_salt("java_version_combo")
-                    .selected_text(&self.java_version)
-                    .show_ui(ui, |ui| {
-                        for &v in JAVA_VERSIONS {
-                            if ui
-                                .selectable_value(&mut self.java_version, v.to_string(), v)
-                                .changed()
-                            {
-                                self.dirty = true;
-                            }
-                        }
-                    });
-                ui.end_row();
-            });
+                let text_edit = egui::TextEdit::multiline(&mut self.java_args)
+                    .desired_width(f32::INFINITY)
+                    .desired_rows(6)
+                    .font(egui::TextStyle::Monospace);
 
-        ui.add_space(10.0);
-        ui.label("Extra Environment Variables (one per line, KEY=VALUE):");
-        ui.add_space(5.0);
+                if ui.add(text_edit).changed() {
+                    self.dirty = true;
+                }
 
-        let env_edit = egui::TextEdit::multiline(&mut self.extra_env)
-            .desired_width(f32::INFINITY)
-            .desired_rows(4)
-            .font(egui::TextStyle::Monospace);
+                ui.add_space(10.0);
+                ui.small(tr("edit-java-options-hint"));
 
-        if ui.add(env_edit).changed() {
-            self.dirty = true;
-        }
+                let java_version = self.java_version.parse::<u8>().unwrap_or(21);
+                let parsed_args: Vec<String> = self
+                    .java_args
+                    .lines()
+                    .map(|l| l.trim().to_string())
+                    .filter(|l| !l.is_empty())
+                    .collect();
+                let jvm_problems = jvm::validate_args(&parsed_args, java_version);
+                ui.horizontal(|ui| {
+                    ui.label(tr("edit-gc"));
+                    ui.strong(GarbageCollector::effective(&parsed_args, java_version).label());
+                });
+                for problem in &jvm_problems {
+                    ui.colored_label(egui::Color32::RED, format!("✗ {}", problem));
+                }
 
-        ui.add_space(10.0);
-        ui.small("e.g. CF_EXCLUDE_MODS=optifine, CF_FORCE_SYNCHRONIZE=true");
+                ui.add_space(5.0);
+                ui.add_enabled_ui(java_version > 8, |ui| {
+                    if ui
+                        .checkbox(&mut self.gc_logging, tr("edit-gc-logging"))
+                        .on_hover_text(tr("edit-gc-logging-hint"))
+                        .on_disabled_hover_text(tr("edit-gc-logging-unsupported"))
+                        .changed()
+                    {
+                        self.dirty = true;
+                    }
+                });
+                if !self.gc_benchmarks.is_empty() {
+                    show_gc_benchmarks(ui, &self.gc_benchmarks);
+                }
 
-        ui.add_space(20.0);
+                ui.add_space(20.0);
 
-        // ── Server Properties section ────────────────────────────
-        let max_players_valid = self.max_players.parse::<u32>().is_ok();
-        egui::CollapsingHeader::new("Server Properties")
-            .default_open(true)
-            .show(ui, |ui| {
-                egui::Grid::new("server_properties_grid")
+                // ── Java Version & Extra Env ─────────────────────────────
+                egui::Grid::new("java_env_grid")
                     .num_columns(2)
                     .spacing([20.0, 10.0])
                     .show(ui, |ui| {
-                        ui.label("MOTD:");
-                        if ui.text_edit_singleline(&mut self.motd).changed() {
-                            self.dirty = true;
-                        }
-                        ui.end_row();
-
-                        ui.label("Max Players:");
-                        let response = ui.add(
-                            egui::TextEdit::singleline(&mut self.max_players).desired_width(80.0),
-                        );
-                        if response.changed() {
-                            self.dirty = true;
-                        }
-                        if !max_players_valid {
-                            ui.colored_label(egui::Color32::RED, "Invalid");
-                        }
-                        ui.end_row();
-
-                        ui.label("Difficulty:");
-                        let current_label = format!("{:?}", self.difficulty);
-                        egui::ComboBox::from_id_salt("difficulty_combo")
-                            .selected_text(&current_label)
+                        ui.label(tr("edit-java-version"));
+                        egui::ComboBox::from_id_salt("java_version_combo")
+                            .selected_text(&self.java_version)
                             .show_ui(ui, |ui| {
-                                for variant in &Difficulty::ALL {
-                                    let label = format!("{:?}", variant);
+                                for &v in JAVA_VERSIONS {
                                     if ui
-                                        .selectable_value(&mut self.difficulty, variant.clone(), &label)
+                                        .selectable_value(&mut self.java_version, v.to_string(), v)
                                         .changed()
                                     {
                                         self.dirty = true;
@@ -343,122 +500,332 @@ impl ServerEditView {
                                 }
                             });
                         ui.end_row();
+                    });
 
-                        ui.label("Game Mode:");
-                        let current_label = format!("{:?}", self.gamemode);
-                        egui::ComboBox::from_id_salt("gamemode_combo")
-                            .selected_text(&current_label)
-                            .show_ui(ui, |ui| {
-                                for variant in &GameMode::ALL {
-                                    let label = format!("{:?}", variant);
-                                    if ui
-                                        .selectable_value(&mut self.gamemode, variant.clone(), &label)
-                                        .changed()
-                                    {
+                ui.add_space(10.0);
+                ui.label(tr("edit-extra-env"));
+                ui.add_space(5.0);
+
+                let env_edit = egui::TextEdit::multiline(&mut self.extra_env)
+                    .desired_width(f32::INFINITY)
+                    .desired_rows(4)
+                    .font(egui::TextStyle::Monospace);
+
+                if ui.add(env_edit).changed() {
+                    self.dirty = true;
+                }
+
+                ui.add_space(10.0);
+                ui.small(tr("edit-extra-env-example"));
+
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    ui.label(tr("edit-world-url"));
+                    if ui
+                        .add(
+                            egui::TextEdit::singleline(&mut self.world_url)
+                                .hint_text("https://.../world.zip")
+                                .desired_width(f32::INFINITY),
+                        )
+                        .changed()
+                    {
+                        self.dirty = true;
+                    }
+                });
+                ui.small(tr("edit-world-url-hint"));
+
+                ui.add_space(20.0);
+
+                // ── Server Properties section ────────────────────────────
+                let max_players_valid = self.max_players.parse::<u32>().is_ok();
+                let view_distance_valid = self
+                    .view_distance
+                    .parse::<u32>()
+                    .is_ok_and(|d| (2..=32).contains(&d));
+                egui::CollapsingHeader::new(tr("edit-server-properties"))
+                    .default_open(true)
+                    .show(ui, |ui| {
+                        egui::Grid::new("server_properties_grid")
+                            .num_columns(2)
+                            .spacing([20.0, 10.0])
+                            .show(ui, |ui| {
+                                ui.label(tr("edit-motd"));
+                                if motd_editor(ui, "edit_motd", &mut self.motd) {
+                                    self.dirty = true;
+                                }
+                                ui.end_row();
+
+                                ui.label(tr("edit-max-players"));
+                                let response = ui.add(
+                                    egui::TextEdit::singleline(&mut self.max_players)
+                                        .desired_width(80.0),
+                                );
+                                if response.changed() {
+                                    self.dirty = true;
+                                }
+                                if !max_players_valid {
+                                    ui.colored_label(egui::Color32::RED, tr("edit-invalid"));
+                                }
+                                ui.end_row();
+
+                                ui.label(tr("edit-difficulty"));
+                                let current_label = format!("{:?}", self.difficulty);
+                                egui::ComboBox::from_id_salt("difficulty_combo")
+                                    .selected_text(&current_label)
+                                    .show_ui(ui, |ui| {
+                                        for variant in &Difficulty::ALL {
+                                            let label = format!("{:?}", variant);
+                                            if ui
+                                                .selectable_value(
+                                                    &mut self.difficulty,
+                                                    variant.clone(),
+                                                    &label,
+                                                )
+                                                .changed()
+                                            {
+                                                self.dirty = true;
+                                            }
+                                        }
+                                    });
+                                ui.end_row();
+
+                                ui.label(tr("edit-gamemode"));
+                                let current_label = format!("{:?}", self.gamemode);
+                                egui::ComboBox::from_id_salt("gamemode_combo")
+                                    .selected_text(&current_label)
+                                    .show_ui(ui, |ui| {
+                                        for variant in &GameMode::ALL {
+                                            let label = format!("{:?}", variant);
+                                            if ui
+                                                .selectable_value(
+                                                    &mut self.gamemode,
+                                                    variant.clone(),
+                                                    &label,
+                                                )
+                                                .changed()
+                                            {
+                                                self.dirty = true;
+                                            }
+                                        }
+                                    });
+                                ui.end_row();
+
+                                ui.label(tr("edit-pvp"));
+                                if ui.checkbox(&mut self.pvp, "").changed() {
+                                    self.dirty = true;
+                                }
+                                ui.end_row();
+
+                                ui.label(tr("edit-online-mode"));
+                                if ui.checkbox(&mut self.online_mode, "").changed() {
+                                    self.dirty = true;
+                                }
+                                ui.end_row();
+
+                                ui.label(tr("edit-whitelist"));
+                                if ui.checkbox(&mut self.white_list, "").changed() {
+                                    self.dirty = true;
+                                }
+                                ui.end_row();
+
+                                ui.label(tr("edit-view-distance"));
+                                ui.horizontal(|ui| {
+                                    let response = ui.add(
+                                        egui::TextEdit::singleline(&mut self.view_distance)
+                                            .desired_width(80.0),
+                                    );
+                                    if response.changed() {
                                         self.dirty = true;
                                     }
-                                }
+                                    ui.label(tr("edit-chunks"));
+                                    if !view_distance_valid {
+                                        ui.colored_label(egui::Color32::RED, "2-32");
+                                    }
+                                });
+                                ui.end_row();
                             });
-                        ui.end_row();
-
-                        ui.label("PVP:");
-                        if ui.checkbox(&mut self.pvp, "").changed() {
-                            self.dirty = true;
-                        }
-                        ui.end_row();
+                    });
 
-                        ui.label("Online Mode:");
-                        if ui.checkbox(&mut self.online_mode, "").changed() {
+                egui::CollapsingHeader::new(tr("edit-backup-excludes"))
+                    .default_open(!self.backup_excludes.trim().is_empty())
+                    .show(ui, |ui| {
+                        ui.label(tr("edit-backup-excludes-hint"));
+                        ui.small(tr("edit-backup-excludes-syntax"));
+                        if ui
+                            .add(
+                                egui::TextEdit::multiline(&mut self.backup_excludes)
+                                    .hint_text("logs/**\ncrash-reports/**")
+                                    .font(egui::TextStyle::Monospace)
+                                    .desired_rows(4)
+                                    .desired_width(400.0),
+                            )
+                            .changed()
+                        {
                             self.dirty = true;
                         }
-                        ui.end_row();
-
-                        ui.label("Whitelist:");
-                        if ui.checkbox(&mut self.white_list, "").changed() {
+                        if ui
+                            .button(tr("edit-backup-excludes-suggested"))
+                            .on_hover_text(backup::SUGGESTED_EXCLUDES.join("\n"))
+                            .clicked()
+                        {
+                            for pattern in backup::SUGGESTED_EXCLUDES {
+                                if !self.backup_excludes.lines().any(|l| l.trim() == *pattern) {
+                                    if !self.backup_excludes.is_empty()
+                                        && !self.backup_excludes.ends_with('\n')
+                                    {
+                                        self.backup_excludes.push('\n');
+                                    }
+                                    self.backup_excludes.push_str(pattern);
+                                }
+                            }
                             self.dirty = true;
                         }
-                        ui.end_row();
+                        ui.small(tr("edit-backup-excludes-restore"));
                     });
-            });
 
-        ui.add_space(30.0);
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    ui.label(tr("edit-backup-group"));
+                    if ui
+                        .add(
+                            egui::TextEdit::singleline(&mut self.backup_group)
+                                .hint_text(tr("edit-backup-group-none"))
+                                .desired_width(160.0),
+                        )
+                        .on_hover_text(tr("edit-backup-group-hint"))
+                        .changed()
+                    {
+                        self.dirty = true;
+                    }
+                });
 
-        ui.horizontal(|ui| {
-            if ui.button("Cancel").clicked() {
-                on_cancel();
-            }
+                ui.add_space(30.0);
 
-            ui.add_space(20.0);
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(self.dirty, egui::Button::new(tr("edit-revert")))
+                        .on_hover_text(tr("edit-revert-hint"))
+                        .clicked()
+                    {
+                        on_revert();
+                    }
 
-            let port_valid = self.port.parse::<u16>().is_ok();
-            let memory_valid = self.memory_mb.parse::<u64>().is_ok();
-            let java_version_valid = self.java_version.parse::<u8>().is_ok();
-            let can_save =
-                port_valid && memory_valid && max_players_valid && java_version_valid && self.dirty;
+                    ui.add_space(20.0);
 
-            if ui
-                .add_enabled(can_save, egui::Button::new("Save Changes"))
-                .clicked()
-            {
-                let port = self.port.parse().unwrap_or(25565);
-                let memory_mb = self.memory_mb.parse().unwrap_or(4096);
-                let java_args: Vec<String> = self
-                    .java_args
-                    .lines()
-                    .map(|s| s.trim().to_string())
-                    .filter(|s| !s.is_empty())
-                    .collect();
-                let server_properties = ServerProperties {
-                    motd: self.motd.clone(),
-                    max_players: self.max_players.parse().unwrap_or(20),
-                    difficulty: self.difficulty.clone(),
-                    gamemode: self.gamemode.clone(),
-                    pvp: self.pvp,
-                    online_mode: self.online_mode,
-                    white_list: self.white_list,
-                };
-                let modpack = ModpackInfo {
-                    name: self.modpack_name.clone(),
-                    version: self.modpack_version.clone(),
-                    minecraft_version: self.minecraft_version.clone(),
-                    loader: self.loader.clone(),
-                    source: self.source.clone(),
-                };
-                let java_version = self.java_version.parse().unwrap_or(21);
-                let extra_env: Vec<String> = self
-                    .extra_env
-                    .lines()
-                    .map(|s| s.trim().to_string())
-                    .filter(|s| !s.is_empty())
-                    .collect();
-                on_save(ServerEditResult {
-                    port,
-                    memory_mb,
-                    java_args,
-                    server_properties,
-                    modpack,
-                    java_version,
-                    extra_env,
-                });
-            }
+                    let port_valid = self.port.parse::<u16>().is_ok();
+                    let memory_valid = self.memory_mb.parse::<u64>().is_ok();
+                    let java_version_valid = self.java_version.parse::<u8>().is_ok();
+                    let extra_ports = parse_port_list(&self.extra_ports);
+                    let extra_ports_valid = extra_ports.is_some();
+                    let bind_address = parse_bind_address(&self.bind_address);
+                    let bind_address_valid = bind_address.is_some();
+                    let jvm_valid = jvm_problems.is_empty();
+                    let can_save = port_valid
+                        && memory_valid
+                        && jvm_valid
+                        && max_players_valid
+                        && view_distance_valid
+                        && java_version_valid
+                        && extra_ports_valid
+                        && bind_address_valid
+                        && self.dirty;
 
-            if !port_valid {
-                ui.colored_label(egui::Color32::RED, "Invalid port number");
-            }
-            if !memory_valid {
-                ui.colored_label(egui::Color32::RED, "Invalid memory value");
-            }
-        });
+                    if ui
+                        .add_enabled(can_save, egui::Button::new(tr("edit-save")))
+                        .clicked()
+                    {
+                        let port = self.port.parse().unwrap_or(25565);
+                        let memory_mb = self.memory_mb.parse().unwrap_or(4096);
+                        let java_args: Vec<String> = self
+                            .java_args
+                            .lines()
+                            .map(|s| s.trim().to_string())
+                            .filter(|s| !s.is_empty())
+                            .collect();
+                        let server_properties = ServerProperties {
+                            motd: self.motd.clone(),
+                            max_players: self.max_players.parse().unwrap_or(20),
+                            difficulty: self.difficulty.clone(),
+                            gamemode: self.gamemode.clone(),
+                            pvp: self.pvp,
+                            online_mode: self.online_mode,
+                            white_list: self.white_list,
+                            view_distance: self.view_distance.parse().unwrap_or(10),
+                        };
+                        let modpack = ModpackInfo {
+                            name: self.modpack_name.clone(),
+                            version: self.modpack_version.clone(),
+                            minecraft_version: self.minecraft_version.clone(),
+                            loader: self.loader.clone(),
+                            source: self.source.clone(),
+                        };
+                        let java_version = self.java_version.parse().unwrap_or(21);
+                        let extra_env: Vec<String> = self
+                            .extra_env
+                            .lines()
+                            .map(|s| s.trim().to_string())
+                            .filter(|s| !s.is_empty())
+                            .collect();
+                        on_save(ServerEditResult {
+                            port,
+                            memory_mb,
+                            java_args,
+                            server_properties,
+                            modpack,
+                            java_version,
+                            extra_env,
+                            world_url: Some(self.world_url.trim().to_string())
+                                .filter(|u| !u.is_empty()),
+                            extra_ports: extra_ports.unwrap_or_default(),
+                            bind_address: bind_address.flatten(),
+                            gc_logging: self.gc_logging,
+                            backup_group: Some(self.backup_group.trim().to_string())
+                                .filter(|g| !g.is_empty()),
+                            backup_excludes: self
+                                .backup_excludes
+                                .lines()
+                                .map(|s| s.trim().to_string())
+                                .filter(|s| !s.is_empty())
+                                .collect(),
+                        });
+                    }
 
-        ui.add_space(20.0);
-        ui.separator();
-        ui.add_space(10.0);
-        ui.small("Note: Changes will take effect the next time the server starts.");
-        ui.small("The container will be recreated with the new settings.");
+                    if !port_valid {
+                        ui.colored_label(egui::Color32::RED, tr("edit-invalid-port"));
+                    }
+                    if !memory_valid {
+                        ui.colored_label(egui::Color32::RED, tr("edit-invalid-memory"));
+                    }
+                    if !extra_ports_valid {
+                        ui.colored_label(egui::Color32::RED, tr("edit-invalid-extra-ports"));
+                    }
+                    if !bind_address_valid {
+                        ui.colored_label(egui::Color32::RED, tr("edit-invalid-bind-address"));
+                    }
+                    if !jvm_valid {
+                        ui.colored_label(egui::Color32::RED, tr("edit-invalid-java-options"));
+                    }
+                });
 
+                ui.add_space(20.0);
+                ui.separator();
+                ui.add_space(10.0);
+                ui.small(tr("edit-note-next-start"));
+                ui.small(tr("edit-note-recreate"));
             }); // end ScrollArea
     }
 
+    fn load_properties(&mut self, sp: &ServerProperties) {
+        self.motd = sp.motd.clone();
+        self.max_players = sp.max_players.to_string();
+        self.difficulty = sp.difficulty.clone();
+        self.gamemode = sp.gamemode.clone();
+        self.pvp = sp.pvp;
+        self.online_mode = sp.online_mode;
+        self.white_list = sp.white_list;
+        self.view_distance = sp.view_distance.to_string();
+    }
+
     /// Apply a modpack template (builtin or CurseForge) to this edit view.
     fn apply_template(&mut self, t: &ModpackTemplate) {
         self.modpack_name = t.name.clone();
@@ -470,36 +837,413 @@ impl ServerEditView {
         self.java_version = t.java_version.to_string();
         self.java_args = t.default_java_args.join("\n");
         self.extra_env = t.default_extra_env.join("\n");
+        self.world_url = t.world_url.clone().unwrap_or_default();
+        if let Some(sp) = &t.server_properties {
+            self.load_properties(sp);
+        }
         self.dirty = true;
     }
 
-    pub fn reset(&mut self) {
-        *self = Self::default();
+    /// Pick a Modrinth `.mrpack` by path or URL. Local files fill in the pack's
+    /// name, versions and loader from their index.
+    fn show_mrpack(&mut self, ui: &mut egui::Ui) {
+        ui.label(tr("edit-mrpack-hint"));
+        ui.horizontal(|ui| {
+            ui.label(tr("edit-mrpack-input"));
+            ui.add(
+                egui::TextEdit::singleline(&mut self.mrpack_input)
+                    .desired_width(320.0)
+                    .hint_text(tr("edit-mrpack-input-hint")),
+            );
+            if ui.button(tr("edit-browse")).clicked() {
+                if let Some(path) = rfd::FileDialog::new()
+                    .add_filter(tr("edit-mrpack-filter"), &["mrpack"])
+                    .pick_file()
+                {
+                    self.mrpack_input = path.display().to_string();
+                }
+            }
+        });
+        let input = self.mrpack_input.trim().to_string();
+        if ui
+            .add_enabled(!input.is_empty(), egui::Button::new(tr("edit-use-mrpack")))
+            .clicked()
+        {
+            self.mrpack_error = None;
+            if !pack_installer::is_url(&input) {
+                match pack_installer::read_mrpack_info(std::path::Path::new(&input)) {
+                    Ok(info) => {
+                        self.modpack_name = info.name;
+                        self.modpack_version = info.version;
+                        self.java_version =
+                            curseforge::infer_java_version(&info.loader.minecraft_version)
+                                .to_string();
+                        self.minecraft_version = info.loader.minecraft_version;
+                        self.loader = info.loader.loader;
+                    }
+                    Err(e) => {
+                        self.mrpack_error = Some(format!("{:#}", e));
+                        return;
+                    }
+                }
+            }
+            self.source = ModpackSource::MrPackFile { path_or_url: input };
+            self.dirty = true;
+        }
+        if let Some(e) = &self.mrpack_error {
+            ui.colored_label(egui::Color32::RED, e);
+        }
+    }
+
+    /// Schedules tab: recurring announcements and automatic backups
+    pub fn show_schedules(&mut self, ui: &mut egui::Ui) -> Option<ScheduleResult> {
+        let mut result = None;
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                egui::CollapsingHeader::new(tr("edit-broadcasts"))
+                    .default_open(true)
+                    .show(ui, |ui| {
+                        if self.show_broadcasts(ui) {
+                            self.schedules_dirty = true;
+                        }
+                    });
+
+                egui::CollapsingHeader::new(tr("edit-auto-backups"))
+                    .default_open(true)
+                    .show(ui, |ui| {
+                        if self.show_backup_schedule(ui) {
+                            self.schedules_dirty = true;
+                        }
+                        ui.add_space(5.0);
+                        if ui
+                            .checkbox(&mut self.remote_backup, tr("edit-remote-backup"))
+                            .on_hover_text(tr("edit-remote-backup-hint"))
+                            .changed()
+                        {
+                            self.schedules_dirty = true;
+                        }
+                    });
+
+                ui.add_space(20.0);
+                ui.small(tr("edit-schedules-note"));
+                ui.add_space(5.0);
+                if ui
+                    .add_enabled(
+                        self.schedules_dirty,
+                        egui::Button::new(tr("edit-save-schedules")),
+                    )
+                    .clicked()
+                {
+                    self.schedules_dirty = false;
+                    result = Some(ScheduleResult {
+                        broadcasts: self
+                            .broadcasts
+                            .iter()
+                            .filter(|b| !b.command.trim().is_empty())
+                            .cloned()
+                            .collect(),
+                        backup_schedule: self.backup_schedule,
+                        remote_backup: self.remote_backup,
+                    });
+                }
+            });
+        result
     }
+
+    /// Editor for the backup schedule and retention rules. Returns true if anything changed.
+    fn show_backup_schedule(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = false;
+        let schedule = &mut self.backup_schedule;
+        changed |= ui
+            .checkbox(&mut schedule.enabled, tr("edit-backup-auto"))
+            .changed();
+        ui.add_space(5.0);
+
+        ui.add_enabled_ui(schedule.enabled, |ui| {
+            ui.horizontal(|ui| {
+                let every = matches!(schedule.frequency, BackupFrequency::EveryHours(_));
+                if ui.radio(every, tr("edit-backup-every")).clicked() && !every {
+                    schedule.frequency = BackupFrequency::EveryHours(6);
+                    changed = true;
+                }
+                if let BackupFrequency::EveryHours(hours) = &mut schedule.frequency {
+                    changed |= ui
+                        .add(egui::DragValue::new(hours).range(1..=168).suffix(" h"))
+                        .changed();
+                }
+            });
+            ui.horizontal(|ui| {
+                let daily = matches!(schedule.frequency, BackupFrequency::DailyAt { .. });
+                if ui.radio(daily, tr("edit-backup-daily")).clicked() && !daily {
+                    schedule.frequency = BackupFrequency::DailyAt { hour: 3, minute: 0 };
+                    changed = true;
+                }
+                if let BackupFrequency::DailyAt { hour, minute } = &mut schedule.frequency {
+                    let two_digits = |n: f64, _| format!("{:02}", n as u32);
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(hour)
+                                .range(0..=23)
+                                .custom_formatter(two_digits),
+                        )
+                        .changed();
+                    ui.label(":");
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(minute)
+                                .range(0..=59)
+                                .custom_formatter(two_digits),
+                        )
+                        .changed();
+                }
+            });
+
+            ui.add_space(5.0);
+            let retention = &mut schedule.retention;
+            egui::Grid::new("backup_retention_grid")
+                .num_columns(2)
+                .spacing([8.0, 4.0])
+                .show(ui, |ui| {
+                    ui.label(tr("edit-keep-last"));
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut retention.keep_last)
+                                .range(1..=100)
+                                .suffix(format!(" {}", tr("edit-keep-last-unit"))),
+                        )
+                        .changed();
+                    ui.end_row();
+                    ui.label(tr("edit-keep-daily"));
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut retention.keep_daily_days)
+                                .range(0..=90)
+                                .suffix(format!(" {}", tr("edit-keep-daily-unit"))),
+                        )
+                        .changed();
+                    ui.end_row();
+                    ui.label(tr("edit-keep-weekly"));
+                    changed |= ui
+                        .add(
+                            egui::DragValue::new(&mut retention.keep_weekly_weeks)
+                                .range(0..=52)
+                                .suffix(format!(" {}", tr("edit-keep-weekly-unit"))),
+                        )
+                        .changed();
+                    ui.end_row();
+                });
+            ui.small(tr("edit-prune-note"));
+
+            ui.add_space(5.0);
+            let mut own_compression = schedule.compression.is_some();
+            if ui
+                .checkbox(&mut own_compression, tr("edit-own-compression"))
+                .on_hover_text(tr("edit-own-compression-hint"))
+                .changed()
+            {
+                schedule.compression = own_compression.then(BackupCompression::default);
+                changed = true;
+            }
+            if let Some(compression) = &mut schedule.compression {
+                changed |= compression_picker(ui, "schedule_compression", compression);
+            }
+        });
+        changed
+    }
+
+    /// Editor for recurring announcements. Returns true if anything changed.
+    fn show_broadcasts(&mut self, ui: &mut egui::Ui) -> bool {
+        let mut changed = false;
+        ui.small(tr("edit-broadcasts-hint"));
+        ui.add_space(5.0);
+
+        let mut remove = None;
+        egui::Grid::new("broadcasts_grid")
+            .num_columns(4)
+            .spacing([8.0, 4.0])
+            .show(ui, |ui| {
+                for (i, broadcast) in self.broadcasts.iter_mut().enumerate() {
+                    changed |= ui.checkbox(&mut broadcast.enabled, "").changed();
+                    changed |= ui
+                        .add(
+                            egui::TextEdit::singleline(&mut broadcast.command)
+                                .desired_width(320.0)
+                                .font(egui::TextStyle::Monospace)
+                                .hint_text("say ... / tellraw @a {...}"),
+                        )
+                        .changed();
+                    ui.horizontal(|ui| {
+                        ui.label(tr("edit-broadcast-every"));
+                        changed |= ui
+                            .add(
+                                egui::DragValue::new(&mut broadcast.interval_minutes)
+                                    .range(1..=1440)
+                                    .suffix(" min"),
+                            )
+                            .changed();
+                    });
+                    if ui.small_button("✖").clicked() {
+                        remove = Some(i);
+                    }
+                    ui.end_row();
+                }
+            });
+        if let Some(i) = remove {
+            self.broadcasts.remove(i);
+            changed = true;
+        }
+        if ui.button(tr("edit-add-broadcast")).clicked() {
+            self.broadcasts.push(ScheduledBroadcast::default());
+            changed = true;
+        }
+        changed
+    }
+}
+
+/// Per-collector summary of recorded runs, so collectors can be compared on this server
+fn show_gc_benchmarks(ui: &mut egui::Ui, benchmarks: &[GcBenchmark]) {
+    let mut collectors: Vec<&str> = benchmarks.iter().map(|b| b.collector.as_str()).collect();
+    collectors.sort();
+    collectors.dedup();
+
+    ui.add_space(5.0);
+    ui.label(tr("edit-gc-history"));
+    egui::Grid::new("gc_benchmarks_grid")
+        .num_columns(6)
+        .spacing([16.0, 4.0])
+        .striped(true)
+        .show(ui, |ui| {
+            ui.strong(tr("edit-gc-collector"));
+            ui.strong(tr("edit-gc-runs"));
+            ui.strong(tr("edit-gc-pauses"));
+            ui.strong(tr("edit-gc-avg"));
+            ui.strong("p99");
+            ui.strong(tr("edit-gc-max"));
+            ui.end_row();
+            for collector in collectors {
+                let runs: Vec<&GcBenchmark> = benchmarks
+                    .iter()
+                    .filter(|b| b.collector == collector)
+                    .collect();
+                let pauses: u64 = runs.iter().map(|b| b.pauses).sum();
+                let avg = runs
+                    .iter()
+                    .map(|b| b.avg_pause_ms * b.pauses as f64)
+                    .sum::<f64>()
+                    / pauses.max(1) as f64;
+                let p99 = runs.iter().map(|b| b.p99_pause_ms).fold(0.0, f64::max);
+                let max = runs.iter().map(|b| b.max_pause_ms).fold(0.0, f64::max);
+                ui.label(collector);
+                ui.label(runs.len().to_string());
+                ui.label(pauses.to_string());
+                ui.label(format!("{:.1} ms", avg));
+                ui.label(format!("{:.1} ms", p99));
+                ui.label(format!("{:.1} ms", max));
+                ui.end_row();
+            }
+        });
+}
+
+/// Parse a comma/space separated list of ports. Returns None if any entry is invalid,
+/// repeated, or one of the container's own game and RCON ports.
+fn parse_port_list(input: &str) -> Option<Vec<u16>> {
+    let ports: Vec<u16> = input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            s.parse::<u16>()
+                .ok()
+                .filter(|p| *p != 0 && !CONTAINER_PORTS.contains(p))
+        })
+        .collect::<Option<_>>()?;
+    let unique: HashSet<u16> = ports.iter().copied().collect();
+    (unique.len() == ports.len()).then_some(ports)
+}
+
+/// Parse the bind address field: `Some(None)` when empty (all interfaces),
+/// None if it isn't an IP address
+fn parse_bind_address(input: &str) -> Option<Option<IpAddr>> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Some(None);
+    }
+    input.parse().ok().map(Some)
 }
 
 fn format_source(source: &ModpackSource) -> String {
     match source {
         ModpackSource::CurseForge { slug, file_id } => {
             if *file_id == 0 {
-                format!("CurseForge: {} (latest)", slug)
+                tr_args("edit-source-cf-latest", &[("slug", slug.clone().into())])
             } else {
-                format!("CurseForge: {} (file {})", slug, file_id)
+                tr_args(
+                    "edit-source-cf-file",
+                    &[
+                        ("slug", slug.clone().into()),
+                        ("file", file_id.to_string().into()),
+                    ],
+                )
             }
         }
+        ModpackSource::CurseForgeManifest { slug, file_id } => {
+            if *file_id == 0 {
+                tr_args(
+                    "edit-source-cf-host-latest",
+                    &[("slug", slug.clone().into())],
+                )
+            } else {
+                tr_args(
+                    "edit-source-cf-host-file",
+                    &[
+                        ("slug", slug.clone().into()),
+                        ("file", file_id.to_string().into()),
+                    ],
+                )
+            }
+        }
+        ModpackSource::CurseForgeServerPack {
+            slug,
+            server_pack_file_id,
+            ..
+        } => tr_args(
+            "edit-source-cf-server-pack",
+            &[
+                ("slug", slug.clone().into()),
+                ("file", server_pack_file_id.to_string().into()),
+            ],
+        ),
         ModpackSource::ForgeWithPack {
             forge_version,
             pack_url,
-        } => format!("Forge {} + pack ({})", forge_version, pack_url),
+            ..
+        } => tr_args(
+            "edit-source-forge",
+            &[
+                ("forge", forge_version.clone().into()),
+                ("url", pack_url.clone().into()),
+            ],
+        ),
         ModpackSource::Ftb {
             pack_id,
             version_id,
-        } => format!("FTB (pack {}, version {})", pack_id, version_id),
+        } => tr_args(
+            "edit-source-ftb",
+            &[
+                ("pack", pack_id.to_string().into()),
+                ("version", version_id.to_string().into()),
+            ],
+        ),
         ModpackSource::Modrinth {
             project_id,
             version_id,
         } => format!("Modrinth: {} v{}", project_id, version_id),
-        ModpackSource::DirectDownload { url } => format!("Direct: {}", url),
-        ModpackSource::Local { path } => format!("Local: {}", path),
+        ModpackSource::DirectDownload { url } => {
+            tr_args("edit-source-direct", &[("url", url.clone().into())])
+        }
+        ModpackSource::MrPackFile { path_or_url } => format!(".mrpack: {}", path_or_url),
+        ModpackSource::Local { path } => {
+            tr_args("edit-source-local", &[("path", path.clone().into())])
+        }
     }
 }
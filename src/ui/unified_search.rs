@@ -0,0 +1,246 @@
+use crate::curseforge::{self, CfMod};
+use crate::i18n::{tr, tr_args};
+use crate::modrinth::MrProject;
+use crate::search::UnifiedResult;
+use crate::server::ModLoader;
+use eframe::egui;
+
+const CF_COLOR: egui::Color32 = egui::Color32::from_rgb(241, 100, 54);
+const MR_COLOR: egui::Color32 = egui::Color32::from_rgb(27, 217, 106);
+
+// ── Types ──────────────────────────────────────────────────────────────────
+
+/// Search filters shared by both platforms.
+#[derive(Debug, Clone, Default)]
+pub struct UnifiedSearchState {
+    pub query: String,
+    pub mc_version_filter: String,
+    pub loader_filter_idx: usize, // 0 = Any, 1 = Forge, 2 = Fabric, 3 = NeoForge
+}
+
+impl UnifiedSearchState {
+    pub fn selected_loader(&self) -> Option<ModLoader> {
+        match self.loader_filter_idx {
+            1 => Some(ModLoader::Forge),
+            2 => Some(ModLoader::Fabric),
+            3 => Some(ModLoader::NeoForge),
+            _ => None,
+        }
+    }
+
+    fn loader_label(&self) -> String {
+        match self.loader_filter_idx {
+            1 => "Forge".to_string(),
+            2 => "Fabric".to_string(),
+            3 => "NeoForge".to_string(),
+            _ => tr("browse-any"),
+        }
+    }
+}
+
+/// Which platform the user chose to install a result from
+#[derive(Debug, Clone)]
+pub enum UnifiedPick {
+    CurseForge(CfMod),
+    Modrinth(MrProject),
+}
+
+/// Callbacks for triggering the combined search from the widget.
+pub struct UnifiedCallbacks<'a> {
+    pub on_search: &'a mut dyn FnMut(UnifiedSearchState),
+    pub has_cf_key: bool,
+}
+
+// ── UnifiedSearchWidget ────────────────────────────────────────────────────
+
+#[derive(Default)]
+pub struct UnifiedSearchWidget {
+    pub search: UnifiedSearchState,
+    pub results: Vec<UnifiedResult>,
+    pub loading: bool,
+    /// Per-platform failures from the last search (the other platform may still have results)
+    pub errors: Vec<String>,
+    /// Whether a search has completed since the widget was created
+    pub searched: bool,
+}
+
+impl UnifiedSearchWidget {
+    /// Show the combined search UI.
+    ///
+    /// Returns the result and platform the user picked to install from, if any.
+    pub fn show(
+        &mut self,
+        ui: &mut egui::Ui,
+        id_salt: &str,
+        callbacks: &mut UnifiedCallbacks<'_>,
+    ) -> Option<UnifiedPick> {
+        let mut pick = None;
+
+        ui.push_id(id_salt, |ui| {
+            // ── Search bar ────────────────────────────────────────────────
+            let mut trigger_search = false;
+
+            ui.horizontal(|ui| {
+                ui.label(tr("browse-search"));
+                let resp = ui.add(
+                    egui::TextEdit::singleline(&mut self.search.query)
+                        .desired_width(200.0)
+                        .hint_text(tr("cf-search-hint")),
+                );
+                if resp.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                    trigger_search = true;
+                }
+                if ui.button(tr("browse-search-button")).clicked() {
+                    trigger_search = true;
+                }
+            });
+
+            ui.horizontal(|ui| {
+                ui.label(tr("browse-mc-version"));
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.search.mc_version_filter)
+                        .desired_width(60.0)
+                        .hint_text(tr("browse-mc-version-hint")),
+                );
+
+                ui.label(tr("browse-loader"));
+                egui::ComboBox::from_id_salt(format!("{}_loader_filter", id_salt))
+                    .selected_text(self.search.loader_label())
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(
+                            &mut self.search.loader_filter_idx,
+                            0,
+                            tr("browse-any"),
+                        );
+                        ui.selectable_value(&mut self.search.loader_filter_idx, 1, "Forge");
+                        ui.selectable_value(&mut self.search.loader_filter_idx, 2, "Fabric");
+                        ui.selectable_value(&mut self.search.loader_filter_idx, 3, "NeoForge");
+                    });
+            });
+
+            if !callbacks.has_cf_key {
+                ui.small(tr("unified-no-cf-key"));
+            }
+
+            if trigger_search {
+                self.loading = true;
+                self.errors.clear();
+                (callbacks.on_search)(self.search.clone());
+            }
+
+            ui.separator();
+
+            if self.loading {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label(tr_args(
+                        "browse-searching",
+                        &[("site", "CurseForge & Modrinth".into())],
+                    ));
+                });
+                return;
+            }
+
+            for err in &self.errors {
+                ui.colored_label(
+                    egui::Color32::RED,
+                    tr_args("browse-error", &[("error", err.into())]),
+                );
+            }
+
+            if self.results.is_empty() {
+                if !self.searched {
+                    ui.label(tr("unified-empty"));
+                } else if self.errors.is_empty() {
+                    ui.label(tr("browse-no-results"));
+                }
+                return;
+            }
+
+            egui::ScrollArea::vertical()
+                .id_salt(format!("{}_results_scroll", id_salt))
+                .auto_shrink([false, false])
+                .show(ui, |ui| {
+                    for result in &self.results {
+                        if let Some(p) = Self::result_row(ui, result, callbacks.has_cf_key) {
+                            pick = Some(p);
+                        }
+                        ui.add_space(3.0);
+                    }
+                });
+        });
+
+        pick
+    }
+
+    fn result_row(
+        ui: &mut egui::Ui,
+        result: &UnifiedResult,
+        has_cf_key: bool,
+    ) -> Option<UnifiedPick> {
+        let mut pick = None;
+
+        egui::Frame::none()
+            .fill(ui.style().visuals.extreme_bg_color)
+            .rounding(6.0)
+            .inner_margin(8.0)
+            .show(ui, |ui| {
+                ui.push_id(result.key(), |ui| {
+                    ui.horizontal(|ui| {
+                        if let Some(icon_url) = &result.icon_url {
+                            ui.add(
+                                egui::Image::new(icon_url)
+                                    .max_width(64.0)
+                                    .max_height(64.0)
+                                    .rounding(4.0),
+                            );
+                        } else {
+                            ui.allocate_space(egui::vec2(64.0, 64.0));
+                        }
+
+                        ui.vertical(|ui| {
+                            ui.horizontal(|ui| {
+                                ui.strong(&result.title);
+                                ui.small(tr_args(
+                                    "browse-downloads-short",
+                                    &[(
+                                        "count",
+                                        curseforge::format_downloads(result.total_downloads())
+                                            .into(),
+                                    )],
+                                ));
+                                if result.cf.is_some() {
+                                    ui.colored_label(CF_COLOR, "CurseForge");
+                                }
+                                if result.mr.is_some() {
+                                    ui.colored_label(MR_COLOR, "Modrinth");
+                                }
+                            });
+                            ui.label(&result.summary);
+
+                            ui.horizontal(|ui| {
+                                if let Some(cf_mod) = &result.cf {
+                                    if ui
+                                        .add_enabled(
+                                            has_cf_key,
+                                            egui::Button::new(tr("unified-install-cf")),
+                                        )
+                                        .clicked()
+                                    {
+                                        pick = Some(UnifiedPick::CurseForge(cf_mod.clone()));
+                                    }
+                                }
+                                if let Some(project) = &result.mr {
+                                    if ui.button(tr("unified-install-mr")).clicked() {
+                                        pick = Some(UnifiedPick::Modrinth(project.clone()));
+                                    }
+                                }
+                            });
+                        });
+                    });
+                });
+            });
+
+        pick
+    }
+}
@@ -0,0 +1,111 @@
+use super::players::player_head;
+use crate::heads::PlayerHeads;
+use crate::i18n::{tr, tr_args};
+use crate::whitelist::WhitelistEntry;
+use eframe::egui;
+
+/// What the user chose on the whitelist tab
+#[derive(Debug, Clone, PartialEq)]
+pub enum WhitelistAction {
+    Reload,
+    /// Add the names pasted into the input box
+    Add,
+    Remove(String),
+}
+
+pub struct WhitelistView;
+
+impl WhitelistView {
+    /// `entries` is `None` when whitelist.json couldn't be read; `busy` while a
+    /// lookup or RCON change is in flight
+    pub fn show(
+        ui: &mut egui::Ui,
+        entries: Option<&[WhitelistEntry]>,
+        enforced: bool,
+        running: bool,
+        busy: bool,
+        input: &mut String,
+        heads: &PlayerHeads,
+    ) -> Option<WhitelistAction> {
+        let mut action = None;
+
+        if !enforced {
+            ui.colored_label(egui::Color32::YELLOW, tr("whitelist-not-enforced"));
+        }
+        if running {
+            ui.small(tr("whitelist-running"));
+        } else {
+            ui.small(tr("whitelist-stopped"));
+        }
+        ui.add_space(6.0);
+
+        ui.label(tr("whitelist-add-label"));
+        ui.add(
+            egui::TextEdit::multiline(input)
+                .desired_width(f32::INFINITY)
+                .desired_rows(3)
+                .hint_text("Notch\njeb_"),
+        );
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(
+                    !busy && !input.trim().is_empty(),
+                    egui::Button::new(tr("button-add")),
+                )
+                .clicked()
+            {
+                action = Some(WhitelistAction::Add);
+            }
+            if busy {
+                ui.spinner();
+            }
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui
+                    .add_enabled(!busy, egui::Button::new(tr("button-reload")))
+                    .clicked()
+                {
+                    action = Some(WhitelistAction::Reload);
+                }
+            });
+        });
+        ui.separator();
+
+        let Some(entries) = entries else {
+            ui.colored_label(egui::Color32::RED, tr("whitelist-unreadable"));
+            return action;
+        };
+        if entries.is_empty() {
+            ui.label(tr("whitelist-empty"));
+            return action;
+        }
+
+        ui.label(tr_args(
+            "whitelist-count",
+            &[("count", entries.len().into())],
+        ));
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            egui::Grid::new("whitelist_grid")
+                .num_columns(3)
+                .spacing([16.0, 6.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    for entry in entries {
+                        ui.horizontal(|ui| {
+                            player_head(ui, heads, &entry.name);
+                            ui.strong(&entry.name);
+                        });
+                        ui.monospace(&entry.uuid);
+                        if ui
+                            .add_enabled(!busy, egui::Button::new(tr("button-remove")))
+                            .clicked()
+                        {
+                            action = Some(WhitelistAction::Remove(entry.name.clone()));
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+
+        action
+    }
+}
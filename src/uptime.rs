@@ -0,0 +1,122 @@
+//! Per-server uptime sessions: when each run started, when it ended and whether
+//! it ended in a crash. Stored as `uptime.json` next to the server's data
+//! directory, for the reliability summary on the Overview tab.
+
+use crate::config::get_server_path;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Sessions that ended longer ago than this are dropped
+const RETENTION: Duration = Duration::from_secs(90 * 24 * 3600);
+/// Start times this close together are the same run, e.g. the app's own
+/// timestamp versus the one Docker reports
+const SAME_START_SECS: i64 = 60;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Session {
+    /// Unix seconds
+    pub started: i64,
+    /// Unix seconds; None while the server is up
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ended: Option<i64>,
+    #[serde(default)]
+    pub crashed: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UptimeLog {
+    /// Oldest first
+    #[serde(default)]
+    pub sessions: Vec<Session>,
+}
+
+/// Starts, crashes and time up within a window
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Reliability {
+    pub starts: usize,
+    pub crashes: usize,
+    pub up_secs: u64,
+    /// Share of the window (or of the time since tracking began, if shorter)
+    /// the server was up
+    pub availability: f64,
+    pub longest_secs: u64,
+}
+
+fn log_path(server_name: &str) -> std::path::PathBuf {
+    get_server_path(server_name).join("uptime.json")
+}
+
+impl UptimeLog {
+    /// Load the log, starting empty if there isn't one yet
+    pub fn load(server_name: &str) -> Self {
+        std::fs::read_to_string(log_path(server_name))
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, server_name: &str) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(log_path(server_name), json).context("Failed to write uptime.json")
+    }
+
+    /// The server is up and has been since `at`. Continues the open session if
+    /// it's the same run (taking the earlier start time); otherwise that session
+    /// ended unseen, while the app was closed, and is closed at `at`. Returns
+    /// whether anything changed.
+    pub fn started(&mut self, at: i64) -> bool {
+        if let Some(open) = self.sessions.last_mut().filter(|s| s.ended.is_none()) {
+            if at <= open.started + SAME_START_SECS {
+                let earlier = at < open.started;
+                open.started = open.started.min(at);
+                return earlier;
+            }
+            open.ended = Some(at);
+        }
+        self.sessions.push(Session {
+            started: at,
+            ended: None,
+            crashed: false,
+        });
+        let cutoff = at - RETENTION.as_secs() as i64;
+        self.sessions
+            .retain(|s| s.ended.is_none_or(|ended| ended >= cutoff));
+        true
+    }
+
+    /// The server went down at `at`. Returns false if no session was open.
+    pub fn ended(&mut self, at: i64, crashed: bool) -> bool {
+        let Some(open) = self.sessions.last_mut().filter(|s| s.ended.is_none()) else {
+            return false;
+        };
+        open.ended = Some(at.max(open.started));
+        open.crashed = crashed;
+        true
+    }
+
+    /// Reliability over the `window` before `now`
+    pub fn summary(&self, now: i64, window: Duration) -> Reliability {
+        let from = now - window.as_secs() as i64;
+        let mut summary = Reliability::default();
+        for session in &self.sessions {
+            let ended = session.ended.unwrap_or(now);
+            if ended < from {
+                continue;
+            }
+            if session.started >= from {
+                summary.starts += 1;
+            }
+            if session.crashed {
+                summary.crashes += 1;
+            }
+            let up = (ended - session.started.max(from)).max(0) as u64;
+            summary.up_secs += up;
+            summary.longest_secs = summary.longest_secs.max(up);
+        }
+        let tracked_from = self.sessions.first().map_or(now, |s| s.started.max(from));
+        let span = (now - tracked_from).max(1) as f64;
+        summary.availability = (summary.up_secs as f64 / span).min(1.0);
+        summary
+    }
+}
@@ -0,0 +1,176 @@
+//! Event notifications for chat webhooks: which events each webhook gets, and
+//! the message for each, formatted as a Discord embed or Slack mrkdwn
+//! depending on where the webhook points.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEvent {
+    ServerStarted,
+    ServerStopped,
+    ServerCrashed,
+    PlayerJoined,
+    PlayerLeft,
+    BackupCompleted,
+    BackupFailed,
+}
+
+impl WebhookEvent {
+    pub const ALL: [WebhookEvent; 7] = [
+        WebhookEvent::ServerStarted,
+        WebhookEvent::ServerStopped,
+        WebhookEvent::ServerCrashed,
+        WebhookEvent::PlayerJoined,
+        WebhookEvent::PlayerLeft,
+        WebhookEvent::BackupCompleted,
+        WebhookEvent::BackupFailed,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            WebhookEvent::ServerStarted => "Server started",
+            WebhookEvent::ServerStopped => "Server stopped",
+            WebhookEvent::ServerCrashed => "Server crashed",
+            WebhookEvent::PlayerJoined => "Player joined",
+            WebhookEvent::PlayerLeft => "Player left",
+            WebhookEvent::BackupCompleted => "Backup completed",
+            WebhookEvent::BackupFailed => "Backup failed",
+        }
+    }
+
+    fn emoji(self) -> &'static str {
+        match self {
+            WebhookEvent::ServerStarted => "🟢",
+            WebhookEvent::ServerStopped => "⚪",
+            WebhookEvent::ServerCrashed => "🔴",
+            WebhookEvent::PlayerJoined => "➡️",
+            WebhookEvent::PlayerLeft => "⬅️",
+            WebhookEvent::BackupCompleted => "💾",
+            WebhookEvent::BackupFailed => "⚠️",
+        }
+    }
+
+    /// Discord embed side bar color
+    fn color(self) -> u32 {
+        match self {
+            WebhookEvent::ServerStarted | WebhookEvent::BackupCompleted => 0x3ba55c,
+            WebhookEvent::ServerStopped => 0x99aab5,
+            WebhookEvent::ServerCrashed | WebhookEvent::BackupFailed => 0xed4245,
+            WebhookEvent::PlayerJoined | WebhookEvent::PlayerLeft => 0x5865f2,
+        }
+    }
+}
+
+/// A webhook and the events posted to it
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct Webhook {
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+}
+
+impl Default for Webhook {
+    /// Server lifecycle and failed backups; player and backup-done events are
+    /// chattier, so they're opt-in
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            events: vec![
+                WebhookEvent::ServerStarted,
+                WebhookEvent::ServerStopped,
+                WebhookEvent::ServerCrashed,
+                WebhookEvent::BackupFailed,
+            ],
+        }
+    }
+}
+
+impl Webhook {
+    pub fn wants(&self, event: WebhookEvent) -> bool {
+        !self.url.trim().is_empty() && self.events.contains(&event)
+    }
+}
+
+/// One occurrence of an event
+#[derive(Debug, Clone)]
+pub struct EventMessage {
+    pub event: WebhookEvent,
+    pub server_name: String,
+    /// Extra line under the headline, e.g. the crash reason or backup file
+    pub detail: Option<String>,
+}
+
+impl EventMessage {
+    pub fn new(event: WebhookEvent, server_name: &str, detail: Option<String>) -> Self {
+        Self {
+            event,
+            server_name: server_name.to_string(),
+            detail,
+        }
+    }
+
+    fn headline(&self, bold: impl Fn(&str) -> String) -> String {
+        let server = bold(&self.server_name);
+        match self.event {
+            WebhookEvent::ServerStarted => format!("{} is up", server),
+            WebhookEvent::ServerStopped => format!("{} has stopped", server),
+            WebhookEvent::ServerCrashed => format!("{} crashed", server),
+            WebhookEvent::PlayerJoined => {
+                format!("{} joined {}", self.detail_or_someone(), server)
+            }
+            WebhookEvent::PlayerLeft => format!("{} left {}", self.detail_or_someone(), server),
+            WebhookEvent::BackupCompleted => format!("{} was backed up", server),
+            WebhookEvent::BackupFailed => format!("Backing up {} failed", server),
+        }
+    }
+
+    fn detail_or_someone(&self) -> &str {
+        self.detail.as_deref().unwrap_or("Someone")
+    }
+
+    /// Detail shown under the headline; a player's name is already in it
+    fn body(&self) -> Option<&str> {
+        match self.event {
+            WebhookEvent::PlayerJoined | WebhookEvent::PlayerLeft => None,
+            _ => self.detail.as_deref(),
+        }
+    }
+
+    /// Request body for `url`: an embed for Discord, mrkdwn text for Slack, and
+    /// both `content` and `text` for anything else
+    pub fn payload(&self, url: &str) -> serde_json::Value {
+        let emoji = self.event.emoji();
+        if is_discord(url) {
+            let mut embed = serde_json::json!({
+                "title": format!("{} {}", emoji, self.headline(str::to_string)),
+                "color": self.event.color(),
+                "timestamp": chrono::Utc::now().to_rfc3339(),
+            });
+            if let Some(body) = self.body() {
+                embed["description"] = body.into();
+            }
+            return serde_json::json!({ "username": "DrakonixAnvil", "embeds": [embed] });
+        }
+        if is_slack(url) {
+            let mut text = format!("{} {}", emoji, self.headline(|s| format!("*{}*", s)));
+            if let Some(body) = self.body() {
+                text.push_str(&format!("\n>{}", body));
+            }
+            return serde_json::json!({ "text": text });
+        }
+        let mut text = format!("{} {}", emoji, self.headline(str::to_string));
+        if let Some(body) = self.body() {
+            text.push_str(&format!(": {}", body));
+        }
+        serde_json::json!({ "content": text, "text": text })
+    }
+}
+
+fn is_discord(url: &str) -> bool {
+    url.contains("discord.com/api/webhooks") || url.contains("discordapp.com/api/webhooks")
+}
+
+fn is_slack(url: &str) -> bool {
+    url.contains("hooks.slack.com")
+}
@@ -0,0 +1,125 @@
+//! Whitelist editing: reads and writes `whitelist.json` in a server's data directory
+//! and resolves usernames to UUIDs through the Mojang API.
+
+use crate::config::get_server_data_path;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Bulk username lookup; accepts at most `LOOKUP_BATCH` names per request
+const BULK_LOOKUP_URL: &str =
+    "https://api.minecraftservices.com/minecraft/profile/lookup/bulk/byname";
+const LOOKUP_BATCH: usize = 10;
+
+/// One entry of `whitelist.json`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct WhitelistEntry {
+    pub uuid: String,
+    pub name: String,
+}
+
+#[derive(Deserialize)]
+struct MojangProfile {
+    id: String,
+    name: String,
+}
+
+/// Read the whitelist. A missing file is an empty whitelist.
+pub fn load(server_name: &str) -> Result<Vec<WhitelistEntry>> {
+    let path = get_server_data_path(server_name).join("whitelist.json");
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let json = std::fs::read_to_string(&path).context("Failed to read whitelist.json")?;
+    serde_json::from_str(&json).context("whitelist.json is not valid JSON")
+}
+
+/// Write the whitelist. Only safe while the server is stopped; a running server
+/// overwrites the file from memory.
+pub fn save(server_name: &str, entries: &[WhitelistEntry]) -> Result<()> {
+    let data_path = get_server_data_path(server_name);
+    std::fs::create_dir_all(&data_path)?;
+    let json = serde_json::to_string_pretty(entries)?;
+    std::fs::write(data_path.join("whitelist.json"), json).context("Failed to write whitelist.json")
+}
+
+/// Split pasted text (one name per line, or separated by commas/spaces) into
+/// valid, de-duplicated Minecraft usernames. Returns the names and anything rejected.
+pub fn parse_names(input: &str) -> (Vec<String>, Vec<String>) {
+    let mut names: Vec<String> = Vec::new();
+    let mut invalid = Vec::new();
+    for word in input.split(|c: char| c == ',' || c == ';' || c.is_whitespace()) {
+        let word = word.trim();
+        if word.is_empty() {
+            continue;
+        }
+        let valid = (3..=16).contains(&word.len())
+            && word.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid {
+            invalid.push(word.to_string());
+        } else if !names.iter().any(|n| n.eq_ignore_ascii_case(word)) {
+            names.push(word.to_string());
+        }
+    }
+    (names, invalid)
+}
+
+/// Resolve usernames to whitelist entries with correctly cased names and dashed UUIDs.
+/// Returns the entries found and the names Mojang doesn't know.
+pub async fn lookup(names: &[String]) -> Result<(Vec<WhitelistEntry>, Vec<String>)> {
+    let client = crate::http::client();
+    let mut found = Vec::new();
+    for batch in names.chunks(LOOKUP_BATCH) {
+        let response = client
+            .post(BULK_LOOKUP_URL)
+            .json(batch)
+            .send()
+            .await
+            .context("Mojang API request failed")?;
+        if !response.status().is_success() {
+            anyhow::bail!("Mojang API returned {}", response.status());
+        }
+        let profiles: Vec<MojangProfile> = response
+            .json()
+            .await
+            .context("Unexpected Mojang API response")?;
+        found.extend(profiles.into_iter().map(|p| WhitelistEntry {
+            uuid: dashed_uuid(&p.id),
+            name: p.name,
+        }));
+    }
+
+    let missing = names
+        .iter()
+        .filter(|n| !found.iter().any(|e| e.name.eq_ignore_ascii_case(n)))
+        .cloned()
+        .collect();
+    Ok((found, missing))
+}
+
+/// Mojang returns UUIDs without dashes; whitelist.json wants the 8-4-4-4-12 form
+fn dashed_uuid(id: &str) -> String {
+    if id.len() != 32 {
+        return id.to_string();
+    }
+    format!(
+        "{}-{}-{}-{}-{}",
+        &id[..8],
+        &id[8..12],
+        &id[12..16],
+        &id[16..20],
+        &id[20..]
+    )
+}
+
+/// Add entries not already present (matched by UUID). Returns how many were new.
+pub fn merge(entries: &mut Vec<WhitelistEntry>, new: Vec<WhitelistEntry>) -> usize {
+    let mut added = 0;
+    for entry in new {
+        if !entries.iter().any(|e| e.uuid == entry.uuid) {
+            entries.push(entry);
+            added += 1;
+        }
+    }
+    entries.sort_by_key(|e| e.name.to_lowercase());
+    added
+}